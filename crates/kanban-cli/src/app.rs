@@ -132,7 +132,11 @@ async fn create_empty_storage_file(
     Ok(())
 }
 
-async fn dispatch_subcommand(ctx: &mut CliContext, cmd: Commands) -> anyhow::Result<()> {
+async fn dispatch_subcommand(
+    ctx: &mut CliContext,
+    file: &str,
+    cmd: Commands,
+) -> anyhow::Result<()> {
     match cmd {
         Commands::Board(board_cmd) => {
             handlers::board::handle(ctx, board_cmd.action).await?;
@@ -155,6 +159,12 @@ async fn dispatch_subcommand(ctx: &mut CliContext, cmd: Commands) -> anyhow::Res
         Commands::Import(args) => {
             handlers::export::handle_import(ctx, args).await?;
         }
+        Commands::Watch(args) => {
+            handlers::watch::handle(ctx, file, args).await?;
+        }
+        Commands::Git(git_cmd) => {
+            handlers::git::handle(ctx, file, git_cmd).await?;
+        }
         Commands::Completions { .. } | Commands::Migrate(_) | Commands::Init { .. } => {
             unreachable!()
         }
@@ -265,6 +275,7 @@ impl CliApp {
     {
         let store_manager = StoreManager::new(self.registry);
         let (Cli { command, file }, mut cmd) = parse_cli(&store_manager, args)?;
+        let output = output::settings_for(command.as_ref().and_then(Commands::output_args))?;
 
         if let Some(Commands::Completions { shell }) = command {
             clap_complete::generate(shell, &mut cmd, "kanban", &mut std::io::stdout());
@@ -350,29 +361,34 @@ Provide the file path in one of these ways:
                             CliContext::load(&store_manager, &effective_file, config).await?;
                         let created = ctx.create_board(name, None)?;
                         ctx.save().await?;
-                        output::output_success(&created);
+                        output::output_success(&output, &created);
                     }
                     None => {
                         if !std::path::Path::new(&effective_file).exists() {
                             create_empty_storage_file(&store_manager, &effective_file, &config)
                                 .await?;
                         }
-                        output::output_success(InitFileResult {
-                            file: &effective_file,
-                        });
+                        output::output_success(
+                            &output,
+                            InitFileResult {
+                                file: &effective_file,
+                            },
+                        );
                     }
                 }
             }
             Some(cmd) => {
                 init_tracing_cli();
                 if !std::path::Path::new(&effective_file).exists() {
-                    return crate::output::output_error(&format!(
-                        "Board file not found: '{}'",
-                        effective_file
-                    ));
+                    return crate::output::output_error(
+                        &output,
+                        &format!("Board file not found: '{}'", effective_file),
+                    );
                 }
-                let mut ctx = CliContext::load(&store_manager, &effective_file, config).await?;
-                dispatch_subcommand(&mut ctx, cmd).await?;
+                let mut ctx = CliContext::load(&store_manager, &effective_file, config)
+                    .await?
+                    .with_output(output);
+                dispatch_subcommand(&mut ctx, &effective_file, cmd).await?;
             }
         }
 
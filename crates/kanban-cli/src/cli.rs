@@ -31,6 +31,10 @@ pub enum Commands {
     Export(ExportArgs),
     /// Import board data
     Import(ImportArgs),
+    /// Stream board changes as JSON lines (or per-column counts with --summary)
+    Watch(WatchArgs),
+    /// Git hooks that move cards from commit messages and merges
+    Git(GitCommand),
     /// Generate shell completions
     Completions {
         #[arg(value_enum)]
@@ -46,9 +50,53 @@ pub enum Commands {
     },
 }
 
+/// Rendering mode for command results.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputMode {
+    /// Column-aligned table (default when stdout is a terminal)
+    Table,
+    /// Single-line JSON response envelope (default when piped)
+    Json,
+    /// One JSON object per line, one line per result row
+    Jsonl,
+    /// Comma-separated values with a header row
+    Csv,
+    /// One line per result row rendered from `--format`
+    Template,
+}
+
+/// Output flags shared by every entity subcommand.
+#[derive(Args)]
+pub struct OutputArgs {
+    /// Output mode. Defaults to `table` on a terminal and `json` otherwise.
+    #[arg(long = "output", value_enum, global = true, env = "KANBAN_OUTPUT")]
+    pub mode: Option<OutputMode>,
+    /// Row template for `--output template`, e.g. '{{identifier}} {{title}} [{{priority}}]'.
+    /// Implies `--output template` when given alone.
+    #[arg(long, global = true)]
+    pub format: Option<String>,
+}
+
+impl Commands {
+    /// Output flags for entity subcommands; `None` for commands that always
+    /// emit the JSON envelope.
+    pub fn output_args(&self) -> Option<&OutputArgs> {
+        match self {
+            Commands::Board(c) => Some(&c.output),
+            Commands::Column(c) => Some(&c.output),
+            Commands::Card(c) => Some(&c.output),
+            Commands::Relation(c) => Some(&c.output),
+            Commands::Sprint(c) => Some(&c.output),
+            _ => None,
+        }
+    }
+}
+
 // Board commands
 #[derive(Args)]
 pub struct BoardCommand {
+    #[command(flatten)]
+    pub output: OutputArgs,
     #[command(subcommand)]
     pub action: BoardAction,
 }
@@ -61,6 +109,10 @@ pub enum BoardAction {
         name: String,
         #[arg(long)]
         card_prefix: Option<String>,
+        /// Template to create the board from: a built-in name, a file in the
+        /// templates directory, or a path to a template file
+        #[arg(long)]
+        template: Option<String>,
     },
     /// List all boards
     List {
@@ -81,6 +133,33 @@ pub enum BoardAction {
         /// Board UUID or name
         board: String,
     },
+    /// Copy a board's columns, and optionally its cards and sprints, into a new board
+    Clone {
+        /// Board UUID or name
+        board: String,
+        /// Name of the new board
+        #[arg(long)]
+        name: String,
+        /// Also copy the board's cards and the relations between them
+        #[arg(long)]
+        include_cards: bool,
+        /// Also copy the board's sprints
+        #[arg(long)]
+        include_sprints: bool,
+    },
+    /// Save a board's columns and settings as a reusable template
+    SaveTemplate {
+        /// Board UUID or name
+        board: String,
+        /// Template name, used as the file name in the templates directory
+        #[arg(long)]
+        name: String,
+        /// Also save the board's cards and the relations between them as seed cards
+        #[arg(long)]
+        include_cards: bool,
+    },
+    /// List the available board templates
+    Templates,
 }
 
 #[derive(Args)]
@@ -106,6 +185,8 @@ pub struct BoardUpdateArgs {
 // Column commands
 #[derive(Args)]
 pub struct ColumnCommand {
+    #[command(flatten)]
+    pub output: OutputArgs,
     #[command(subcommand)]
     pub action: ColumnAction,
 }
@@ -170,6 +251,8 @@ pub struct ColumnUpdateArgs {
 // Card commands
 #[derive(Args)]
 pub struct CardCommand {
+    #[command(flatten)]
+    pub output: OutputArgs,
     #[command(subcommand)]
     pub action: CardAction,
 }
@@ -238,6 +321,35 @@ pub enum CardAction {
         /// Card UUID or identifier like KAN-5 or 5
         card: String,
     },
+    /// Show a card's branch, its ahead/behind state and commits mentioning it
+    GitStatus {
+        /// Card UUID or identifier like KAN-5 or 5
+        card: String,
+        /// Path inside the git repository (defaults to the current directory)
+        #[arg(long)]
+        repo: Option<String>,
+    },
+    /// List the cards whose description or checklist mentions a card
+    Refs {
+        /// Card UUID or identifier like KAN-5 or 5
+        card: String,
+        /// List the cards this card mentions instead
+        #[arg(long)]
+        outgoing: bool,
+        /// Relate each listed card to this one with a mentioned-in link
+        #[arg(long)]
+        link: bool,
+    },
+    /// Manage the checklist inside a card
+    Checklist {
+        #[command(subcommand)]
+        action: ChecklistAction,
+    },
+    /// Manage the files attached to a card
+    Attachment {
+        #[command(subcommand)]
+        action: AttachmentAction,
+    },
     /// Archive multiple cards
     #[command(name = "archive-cards")]
     ArchiveCards {
@@ -267,6 +379,119 @@ pub enum CardAction {
     },
 }
 
+#[derive(Subcommand)]
+pub enum ChecklistAction {
+    /// List a card's checklist items with their progress
+    List {
+        /// Card UUID or identifier like KAN-5 or 5
+        card: String,
+    },
+    /// Add an item to a card's checklist
+    Add {
+        /// Card UUID or identifier like KAN-5 or 5
+        card: String,
+        /// Item text
+        text: String,
+        /// 1-based position to insert at (defaults to the end)
+        #[arg(long)]
+        position: Option<usize>,
+    },
+    /// Mark an item done, or not done when it already is
+    Toggle {
+        /// Card UUID or identifier like KAN-5 or 5
+        card: String,
+        /// Item position (1-based) or UUID prefix
+        item: String,
+    },
+    /// Change an item's text
+    Edit {
+        /// Card UUID or identifier like KAN-5 or 5
+        card: String,
+        /// Item position (1-based) or UUID prefix
+        item: String,
+        /// New item text
+        text: String,
+    },
+    /// Move an item to another position
+    Move {
+        /// Card UUID or identifier like KAN-5 or 5
+        card: String,
+        /// Item position (1-based) or UUID prefix
+        item: String,
+        /// 1-based target position
+        #[arg(long)]
+        position: usize,
+    },
+    /// Remove an item
+    Remove {
+        /// Card UUID or identifier like KAN-5 or 5
+        card: String,
+        /// Item position (1-based) or UUID prefix
+        item: String,
+    },
+    /// Replace an item with a child card in the same column
+    Convert {
+        /// Card UUID or identifier like KAN-5 or 5
+        card: String,
+        /// Item position (1-based) or UUID prefix
+        item: String,
+    },
+    /// Move Markdown task lines (`- [ ] ...`) from the description into the checklist
+    Import {
+        /// Card UUID or identifier like KAN-5 or 5
+        card: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum AttachmentAction {
+    /// List a card's attachments
+    List {
+        /// Card UUID or identifier like KAN-5 or 5
+        card: String,
+    },
+    /// Attach a copy of a file to a card
+    Add {
+        /// Card UUID or identifier like KAN-5 or 5
+        card: String,
+        /// File to attach
+        path: String,
+        /// Name to show instead of the file's own name
+        #[arg(long)]
+        name: Option<String>,
+    },
+    /// Extract an attachment to a temporary file and open it with the system viewer
+    Open {
+        /// Card UUID or identifier like KAN-5 or 5
+        card: String,
+        /// Attachment position (1-based), file name or UUID prefix
+        attachment: String,
+    },
+    /// Write an attachment to a file
+    Extract {
+        /// Card UUID or identifier like KAN-5 or 5
+        card: String,
+        /// Attachment position (1-based), file name or UUID prefix
+        attachment: String,
+        /// File or directory to write to (defaults to the attachment's name
+        /// in the current directory)
+        dest: Option<String>,
+    },
+    /// Detach a file from a card; its content is kept until `gc`
+    Remove {
+        /// Card UUID or identifier like KAN-5 or 5
+        card: String,
+        /// Attachment position (1-based), file name or UUID prefix
+        attachment: String,
+    },
+    /// Delete stored content no card or undo step refers to any more
+    Gc {
+        /// Report what would be deleted without deleting it
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
 // Relation commands
 
 /// Sort key for `kanban relation parents` / `children` output.
@@ -350,6 +575,8 @@ mod sort_key_tests {
 
 #[derive(Args)]
 pub struct RelationCommand {
+    #[command(flatten)]
+    pub output: OutputArgs,
     #[command(subcommand)]
     pub action: RelationAction,
 }
@@ -473,6 +700,8 @@ pub struct CardUpdateArgs {
 // Sprint commands
 #[derive(Args)]
 pub struct SprintCommand {
+    #[command(flatten)]
+    pub output: OutputArgs,
     #[command(subcommand)]
     pub action: SprintAction,
 }
@@ -579,16 +808,168 @@ pub struct MigrateArgs {
     pub source_backend: Option<String>,
 }
 
+// Watch command
+#[derive(Args)]
+#[command(after_help = "EXAMPLES:
+    kanban watch
+    kanban watch --board \"My Project\" --summary
+    kanban watch --card KAN-5 --once")]
+pub struct WatchArgs {
+    /// Only report changes on this board (UUID or name)
+    #[arg(long)]
+    pub board: Option<String>,
+    /// Only report changes to this card (UUID or identifier like KAN-5)
+    #[arg(long)]
+    pub card: Option<String>,
+    /// Print card counts per column instead of change events
+    #[arg(long)]
+    pub summary: bool,
+    /// Exit after the first batch of changes (with --summary: print once and exit)
+    #[arg(long)]
+    pub once: bool,
+    /// Seconds between fallback polls for writes the file watcher misses
+    #[arg(long, default_value_t = 2)]
+    pub poll_interval: u64,
+}
+
+// Git commands
+#[derive(Args)]
+#[command(after_help = "\
+Commit message keywords:
+    Closes/Fixes/Resolves KAN-42    move the card to its completion column
+    WIP/Starts KAN-9                move the card to an in-progress column
+    Refs/See/Relates to KAN-7       note the commit in the card description
+Merging a branch named after a card (KAN-42/...) completes the card.")]
+pub struct GitCommand {
+    /// Path inside the git repository (defaults to the current directory)
+    #[arg(long, global = true)]
+    pub repo: Option<String>,
+    #[command(subcommand)]
+    pub action: GitAction,
+}
+
+#[derive(Subcommand)]
+pub enum GitAction {
+    /// Write commit-msg, post-commit and post-merge hooks that update this board file
+    InstallHooks {
+        /// Replace existing hooks not written by kanban
+        #[arg(long)]
+        force: bool,
+    },
+    /// Check the card references in a commit message file (run by the commit-msg hook)
+    CommitMsg {
+        /// Commit message file git passes to the hook
+        file: String,
+    },
+    /// Apply the keywords in the last commit (run by the post-commit hook)
+    PostCommit,
+    /// Apply merged commits and complete merged card branches (run by the post-merge hook)
+    PostMerge,
+}
+
 // Export/Import commands
 #[derive(Args)]
 pub struct ExportArgs {
     /// Board UUID or name; if omitted, exports all boards
     #[arg(long)]
     pub board: Option<String>,
+    /// Output format: versioned JSON, a Markdown checklist, one CSV row per
+    /// card, an iCalendar feed of due dates and sprints, or a JSON bundle
+    /// that also carries attachment content
+    #[arg(long, value_enum, default_value = "json")]
+    pub format: ExportFormatArg,
+}
+
+/// Format for `kanban export`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormatArg {
+    Json,
+    #[value(alias = "markdown")]
+    Md,
+    Csv,
+    #[value(alias = "ical")]
+    Ics,
+    Bundle,
+}
+
+impl From<ExportFormatArg> for kanban_domain::BoardExportFormat {
+    fn from(format: ExportFormatArg) -> Self {
+        match format {
+            ExportFormatArg::Json => Self::Json,
+            ExportFormatArg::Md => Self::Markdown,
+            ExportFormatArg::Csv => Self::Csv,
+            ExportFormatArg::Ics => Self::Ics,
+            ExportFormatArg::Bundle => Self::Bundle,
+        }
+    }
 }
 
 #[derive(Args)]
 pub struct ImportArgs {
+    /// File to import; same as `--file`
+    #[arg(value_name = "FILE", required_unless_present = "file")]
+    pub path: Option<String>,
+    #[arg(long, conflicts_with = "path")]
+    pub file: Option<String>,
+    /// Give every imported entity a fresh id and renumber cards, so an
+    /// export can be imported next to its original
+    #[arg(long)]
+    pub copy: bool,
+    /// Input format; defaults to csv for `.csv` files and json otherwise
+    #[arg(long, value_enum)]
+    pub format: Option<ImportFormatArg>,
+    /// CSV only: board UUID or name that identifiers are matched against
+    /// and new cards are created on
     #[arg(long)]
-    pub file: String,
+    pub board: Option<String>,
+    /// CSV only: read a header into a card field, e.g. `--map Task=title`;
+    /// `HEADER=-` ignores a column. Repeatable
+    #[arg(long = "map", value_name = "HEADER=FIELD")]
+    pub mappings: Vec<String>,
+    /// Create a new board from another tool's export
+    #[arg(long, value_enum, conflicts_with_all = ["copy", "format", "board", "mappings"])]
+    pub from: Option<ForeignSourceArg>,
+    /// With --from: name for the new board instead of the one in the export
+    #[arg(long, requires = "from")]
+    pub name: Option<String>,
+    /// With --from: report what would be created without changing anything
+    #[arg(long, requires = "from")]
+    pub dry_run: bool,
+}
+
+impl ImportArgs {
+    pub fn file(&self) -> &str {
+        self.path
+            .as_deref()
+            .or(self.file.as_deref())
+            .unwrap_or_default()
+    }
+}
+
+/// Tool whose export `kanban import --from` reads.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ForeignSourceArg {
+    /// Trello board JSON
+    Trello,
+    /// `gh issue list --json ...` or `gh project item-list --format json`
+    Github,
+    /// `task export`
+    Taskwarrior,
+}
+
+impl From<ForeignSourceArg> for kanban_domain::ForeignSource {
+    fn from(source: ForeignSourceArg) -> Self {
+        match source {
+            ForeignSourceArg::Trello => Self::Trello,
+            ForeignSourceArg::Github => Self::Github,
+            ForeignSourceArg::Taskwarrior => Self::Taskwarrior,
+        }
+    }
+}
+
+/// Format for `kanban import`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ImportFormatArg {
+    Json,
+    Csv,
 }
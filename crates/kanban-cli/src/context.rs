@@ -1,16 +1,22 @@
+use crate::output::OutputSettings;
 use kanban_core::AppConfig;
 use kanban_domain::KanbanResult;
 use kanban_domain::{
-    ArchivedCard, Board, BoardUpdate, Card, CardListFilter, CardSummary, CardUpdate, Column,
-    ColumnUpdate, CreateCardOptions, GraphOperations, KanbanOperations, Sprint, SprintUpdate,
+    ArchivedCard, Attachment, AttachmentGcReport, Board, BoardExportFormat, BoardTemplate,
+    BoardUpdate, Card, CardListFilter, CardSummary, CardUpdate, ChecklistItem, CloneOptions,
+    Column, ColumnUpdate, CreateCardOptions, CsvImportSummary, CsvMapping, ForeignImportOptions,
+    ForeignImportReport, ForeignSource, GraphOperations, KanbanOperations, Snapshot, Sprint,
+    SprintUpdate,
 };
 use kanban_service::{KanbanContext, StoreManager};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 pub use kanban_service::BatchOperationResult;
 
 pub struct CliContext {
     inner: KanbanContext,
+    output: OutputSettings,
 }
 
 impl CliContext {
@@ -28,13 +34,34 @@ impl CliContext {
         let backend = store_manager.make_backend(file_path, &config).await?;
         Ok(Self {
             inner: KanbanContext::open(backend, config).await?,
+            output: OutputSettings::default(),
         })
     }
 
+    /// Renders this invocation's output with `settings` instead of the
+    /// JSON envelope.
+    pub fn with_output(mut self, settings: OutputSettings) -> Self {
+        self.output = settings;
+        self
+    }
+
+    /// How handlers render their results.
+    pub fn output(&self) -> &OutputSettings {
+        &self.output
+    }
+
     pub async fn save(&self) -> KanbanResult<()> {
         self.inner.save().await
     }
 
+    pub async fn reload(&mut self) -> KanbanResult<()> {
+        self.inner.reload().await
+    }
+
+    pub fn snapshot(&self) -> KanbanResult<Snapshot> {
+        self.inner.snapshot()
+    }
+
     pub fn archive_cards_detailed(&mut self, ids: Vec<Uuid>) -> BatchOperationResult {
         self.inner.archive_cards_detailed(ids)
     }
@@ -43,6 +70,45 @@ impl CliContext {
         self.inner.move_cards_detailed(ids, column_id)
     }
 
+    pub fn card_git_status(
+        &self,
+        id: Uuid,
+        repo: &kanban_service::git::GitRepository,
+    ) -> KanbanResult<kanban_service::git::CardGitStatus> {
+        self.inner.card_git_status(id, repo)
+    }
+
+    pub fn apply_git_hooks(
+        &mut self,
+        commits: &[kanban_service::git::GitCommit],
+        merged_branches: &[String],
+    ) -> KanbanResult<kanban_service::git_hooks::GitSyncReport> {
+        kanban_service::git_hooks::apply(&mut self.inner, commits, merged_branches)
+    }
+
+    /// Run `commands` as one undo step.
+    pub fn execute(&mut self, commands: Vec<kanban_domain::commands::Command>) -> KanbanResult<()> {
+        self.inner.execute(commands)
+    }
+
+    /// Display identifiers (e.g. `KAN-5`) for `cards`, keyed by card id.
+    pub fn card_identifiers(&self, cards: &[CardSummary]) -> KanbanResult<HashMap<Uuid, String>> {
+        let boards = self.inner.boards()?;
+        let columns = self.inner.columns()?;
+        let sprints = self.inner.sprints()?;
+        let default_prefix = self.inner.app_config().effective_default_card_prefix();
+        let mut identifiers = HashMap::with_capacity(cards.len());
+        for card in cards {
+            let board = columns
+                .iter()
+                .find(|c| c.id == card.column_id)
+                .and_then(|column| boards.iter().find(|b| b.id == column.board_id));
+            let Some(board) = board else { continue };
+            identifiers.insert(card.id, card.identifier(board, &sprints, default_prefix));
+        }
+        Ok(identifiers)
+    }
+
     pub fn assign_cards_to_sprint_detailed(
         &mut self,
         ids: Vec<Uuid>,
@@ -73,6 +139,24 @@ impl KanbanOperations for CliContext {
         self.inner.delete_board(id)
     }
 
+    fn create_board_from_template(
+        &mut self,
+        name: String,
+        template: &BoardTemplate,
+    ) -> KanbanResult<Board> {
+        self.inner.create_board_from_template(name, template)
+    }
+
+    fn board_template(
+        &self,
+        board_id: Uuid,
+        template_name: String,
+        include_cards: bool,
+    ) -> KanbanResult<BoardTemplate> {
+        self.inner
+            .board_template(board_id, template_name, include_cards)
+    }
+
     fn create_column(
         &mut self,
         board_id: Uuid,
@@ -181,6 +265,79 @@ impl KanbanOperations for CliContext {
         self.inner.get_card_git_checkout(id)
     }
 
+    fn add_checklist_item(
+        &mut self,
+        card_id: Uuid,
+        text: String,
+        position: Option<usize>,
+    ) -> KanbanResult<ChecklistItem> {
+        self.inner.add_checklist_item(card_id, text, position)
+    }
+
+    fn update_checklist_item(
+        &mut self,
+        card_id: Uuid,
+        item_id: Uuid,
+        text: Option<String>,
+        done: Option<bool>,
+    ) -> KanbanResult<ChecklistItem> {
+        self.inner
+            .update_checklist_item(card_id, item_id, text, done)
+    }
+
+    fn move_checklist_item(
+        &mut self,
+        card_id: Uuid,
+        item_id: Uuid,
+        position: usize,
+    ) -> KanbanResult<Card> {
+        self.inner.move_checklist_item(card_id, item_id, position)
+    }
+
+    fn remove_checklist_item(&mut self, card_id: Uuid, item_id: Uuid) -> KanbanResult<Card> {
+        self.inner.remove_checklist_item(card_id, item_id)
+    }
+
+    fn convert_checklist_item_to_card(
+        &mut self,
+        card_id: Uuid,
+        item_id: Uuid,
+    ) -> KanbanResult<Card> {
+        self.inner.convert_checklist_item_to_card(card_id, item_id)
+    }
+
+    fn import_checklist_from_description(
+        &mut self,
+        card_id: Uuid,
+    ) -> KanbanResult<Vec<ChecklistItem>> {
+        self.inner.import_checklist_from_description(card_id)
+    }
+
+    fn add_attachment(
+        &mut self,
+        card_id: Uuid,
+        name: String,
+        content: &[u8],
+    ) -> KanbanResult<Attachment> {
+        self.inner.add_attachment(card_id, name, content)
+    }
+
+    fn read_attachment(
+        &self,
+        card_id: Uuid,
+        attachment_id: Uuid,
+    ) -> KanbanResult<(Attachment, Vec<u8>)> {
+        self.inner.read_attachment(card_id, attachment_id)
+    }
+
+    fn remove_attachment(&mut self, card_id: Uuid, attachment_id: Uuid) -> KanbanResult<Card> {
+        self.inner.remove_attachment(card_id, attachment_id)
+    }
+
+    fn collect_attachment_garbage(&mut self, dry_run: bool) -> KanbanResult<AttachmentGcReport> {
+        self.inner.collect_attachment_garbage(dry_run)
+    }
+
     fn archive_cards(&mut self, ids: Vec<Uuid>) -> KanbanResult<usize> {
         self.inner.archive_cards(ids)
     }
@@ -250,9 +407,48 @@ impl KanbanOperations for CliContext {
         self.inner.export_board(board_id)
     }
 
+    fn export_board_as(
+        &self,
+        board_id: Option<Uuid>,
+        format: BoardExportFormat,
+    ) -> KanbanResult<String> {
+        self.inner.export_board_as(board_id, format)
+    }
+
     fn import_board(&mut self, data: &str) -> KanbanResult<Board> {
         self.inner.import_board(data)
     }
+
+    fn import_board_as_copy(&mut self, data: &str) -> KanbanResult<Board> {
+        self.inner.import_board_as_copy(data)
+    }
+
+    fn clone_board(
+        &mut self,
+        board_id: Uuid,
+        name: String,
+        options: CloneOptions,
+    ) -> KanbanResult<Board> {
+        self.inner.clone_board(board_id, name, options)
+    }
+
+    fn import_cards_csv(
+        &mut self,
+        board_id: Option<Uuid>,
+        data: &str,
+        mapping: &CsvMapping,
+    ) -> KanbanResult<CsvImportSummary> {
+        self.inner.import_cards_csv(board_id, data, mapping)
+    }
+
+    fn import_foreign(
+        &mut self,
+        source: ForeignSource,
+        data: &str,
+        options: ForeignImportOptions,
+    ) -> KanbanResult<ForeignImportReport> {
+        self.inner.import_foreign(source, data, options)
+    }
 }
 
 impl GraphOperations for CliContext {
@@ -0,0 +1,147 @@
+use crate::cli::AttachmentAction;
+use crate::context::CliContext;
+use crate::output;
+use kanban_domain::attachment::resolve_attachment;
+use kanban_domain::{Attachment, Card, KanbanOperations};
+use kanban_service::attachment_files::{extract_attachment, open_attachment};
+use serde::Serialize;
+use std::path::Path;
+use uuid::Uuid;
+
+/// `card attachment list` row: the attachment with its 1-based position,
+/// the number the other subcommands accept.
+#[derive(Serialize)]
+struct AttachmentRow {
+    position: usize,
+    #[serde(flatten)]
+    attachment: Attachment,
+    display_size: String,
+}
+
+#[derive(Serialize)]
+struct AttachmentListing {
+    card_id: Uuid,
+    items: Vec<AttachmentRow>,
+}
+
+impl From<Card> for AttachmentListing {
+    fn from(card: Card) -> Self {
+        Self {
+            card_id: card.id,
+            items: card
+                .attachments
+                .into_iter()
+                .enumerate()
+                .map(|(i, attachment)| AttachmentRow {
+                    position: i + 1,
+                    display_size: attachment.display_size(),
+                    attachment,
+                })
+                .collect(),
+        }
+    }
+}
+
+pub async fn handle(ctx: &mut CliContext, action: AttachmentAction) -> anyhow::Result<()> {
+    match action {
+        AttachmentAction::List { card } => {
+            let card = match load_card(ctx, &card) {
+                Ok(c) => c,
+                Err(e) => return output::output_error(ctx.output(), &e),
+            };
+            output::output_success(ctx.output(), AttachmentListing::from(card));
+        }
+        AttachmentAction::Add { card, path, name } => {
+            let card = match load_card(ctx, &card) {
+                Ok(c) => c,
+                Err(e) => return output::output_error(ctx.output(), &e),
+            };
+            let content = match std::fs::read(&path) {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    return output::output_error(
+                        ctx.output(),
+                        &format!("Failed to read {}: {}", path, e),
+                    )
+                }
+            };
+            let name = name.unwrap_or_else(|| {
+                Path::new(&path)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or(path.clone())
+            });
+            let attachment = match ctx.add_attachment(card.id, name, &content) {
+                Ok(a) => a,
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+            };
+            ctx.save().await?;
+            output::output_success(ctx.output(), &attachment);
+        }
+        AttachmentAction::Open { card, attachment } => {
+            let (card_id, attachment_id) = match resolve(ctx, &card, &attachment) {
+                Ok(ids) => ids,
+                Err(e) => return output::output_error(ctx.output(), &e),
+            };
+            let opened = ctx
+                .read_attachment(card_id, attachment_id)
+                .and_then(|(attachment, content)| open_attachment(&attachment, &content));
+            match opened {
+                Ok(path) => {
+                    output::output_success(ctx.output(), serde_json::json!({"opened": path}))
+                }
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+            }
+        }
+        AttachmentAction::Extract {
+            card,
+            attachment,
+            dest,
+        } => {
+            let (card_id, attachment_id) = match resolve(ctx, &card, &attachment) {
+                Ok(ids) => ids,
+                Err(e) => return output::output_error(ctx.output(), &e),
+            };
+            let extracted =
+                ctx.read_attachment(card_id, attachment_id)
+                    .and_then(|(attachment, content)| {
+                        extract_attachment(&attachment, &content, dest.as_deref().map(Path::new))
+                    });
+            match extracted {
+                Ok(path) => output::output_success(ctx.output(), serde_json::json!({"path": path})),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+            }
+        }
+        AttachmentAction::Remove { card, attachment } => {
+            let (card_id, attachment_id) = match resolve(ctx, &card, &attachment) {
+                Ok(ids) => ids,
+                Err(e) => return output::output_error(ctx.output(), &e),
+            };
+            let card = ctx.remove_attachment(card_id, attachment_id)?;
+            ctx.save().await?;
+            output::output_success(ctx.output(), AttachmentListing::from(card));
+        }
+        AttachmentAction::Gc { dry_run } => {
+            let report = match ctx.collect_attachment_garbage(dry_run) {
+                Ok(r) => r,
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+            };
+            output::output_success(ctx.output(), &report);
+        }
+    }
+    Ok(())
+}
+
+fn load_card(ctx: &CliContext, raw: &str) -> Result<Card, String> {
+    let id = ctx.resolve_card_id(raw).map_err(|e| e.to_string())?;
+    ctx.get_card(id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Card not found: '{}'", raw))
+}
+
+fn resolve(ctx: &CliContext, card: &str, attachment: &str) -> Result<(Uuid, Uuid), String> {
+    let card = load_card(ctx, card)?;
+    let attachment_id =
+        resolve_attachment(&card.attachments, attachment).map_err(|e| e.to_string())?;
+    Ok((card.id, attachment_id))
+}
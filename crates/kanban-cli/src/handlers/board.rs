@@ -2,42 +2,112 @@ use crate::cli::{BoardAction, BoardUpdateArgs};
 use crate::context::CliContext;
 use crate::output;
 use kanban_core::{resolve_page_params, PaginatedList};
-use kanban_domain::{BoardUpdate, FieldUpdate, KanbanOperations};
+use kanban_domain::{BoardUpdate, CloneOptions, FieldUpdate, KanbanOperations};
+use kanban_service::templates;
 
 pub async fn handle(ctx: &mut CliContext, action: BoardAction) -> anyhow::Result<()> {
     match action {
-        BoardAction::Create { name, card_prefix } => {
-            let board = ctx.create_board(name, card_prefix)?;
+        BoardAction::Create {
+            name,
+            card_prefix,
+            template,
+        } => {
+            let board = match template {
+                Some(template) => {
+                    let mut template = match templates::load(&template) {
+                        Ok(t) => t,
+                        Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+                    };
+                    if card_prefix.is_some() {
+                        template.card_prefix = card_prefix;
+                    }
+                    ctx.create_board_from_template(name, &template)?
+                }
+                None => ctx.create_board(name, card_prefix)?,
+            };
             ctx.save().await?;
-            output::output_success(&board);
+            output::output_success(ctx.output(), &board);
         }
         BoardAction::List { page, page_size } => {
             let boards = ctx.list_boards()?;
             let (page, page_size) = resolve_page_params(page, page_size)?;
-            output::output_success(PaginatedList::paginate(boards, page, page_size)?);
+            output::output_success(
+                ctx.output(),
+                PaginatedList::paginate(boards, page, page_size)?,
+            );
         }
         BoardAction::Get { board } => {
             let uuid = match ctx.resolve_board_id(&board) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             match ctx.get_board(uuid)? {
-                Some(b) => output::output_success(&b),
-                None => return output::output_error(&format!("Board not found: {}", board)),
+                Some(b) => output::output_success(ctx.output(), &b),
+                None => {
+                    return output::output_error(
+                        ctx.output(),
+                        &format!("Board not found: {}", board),
+                    )
+                }
             }
         }
         BoardAction::Update(args) => {
             let board = handle_update(ctx, args).await?;
-            output::output_success(&board);
+            output::output_success(ctx.output(), &board);
         }
         BoardAction::Delete { board } => {
             let uuid = match ctx.resolve_board_id(&board) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             ctx.delete_board(uuid)?;
             ctx.save().await?;
-            output::output_success(serde_json::json!({"deleted": uuid.to_string()}));
+            output::output_success(
+                ctx.output(),
+                serde_json::json!({"deleted": uuid.to_string()}),
+            );
+        }
+        BoardAction::Clone {
+            board,
+            name,
+            include_cards,
+            include_sprints,
+        } => {
+            let uuid = match ctx.resolve_board_id(&board) {
+                Ok(u) => u,
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+            };
+            let options = CloneOptions {
+                include_cards,
+                include_sprints,
+            };
+            let board = ctx.clone_board(uuid, name, options)?;
+            ctx.save().await?;
+            output::output_success(ctx.output(), &board);
+        }
+        BoardAction::SaveTemplate {
+            board,
+            name,
+            include_cards,
+        } => {
+            let uuid = match ctx.resolve_board_id(&board) {
+                Ok(u) => u,
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+            };
+            let template = ctx.board_template(uuid, name, include_cards)?;
+            let path = templates::save(&template)?;
+            output::output_success(
+                ctx.output(),
+                serde_json::json!({
+                    "name": template.name,
+                    "path": path.display().to_string(),
+                    "columns": template.columns.len(),
+                    "cards": template.cards.len(),
+                }),
+            );
+        }
+        BoardAction::Templates => {
+            output::output_success(ctx.output(), templates::list());
         }
     }
     Ok(())
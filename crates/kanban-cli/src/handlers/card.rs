@@ -2,36 +2,48 @@ use crate::cli::{CardAction, CardCreateArgs, CardListArgs, CardUpdateArgs};
 use crate::context::CliContext;
 use crate::output;
 use kanban_core::{parse_datetime_input, resolve_page_params, PaginatedList};
+use kanban_domain::commands::{AddRelates, Command, DependencyCommand};
 use kanban_domain::{
-    ArchivedCardSummary, CardListFilter, CardPriority, CardStatus, CardUpdate, CreateCardOptions,
-    FieldUpdate, KanbanOperations, SprintStatus,
+    find_card_backlinks, find_card_references, index_cards_by_identifier, ArchivedCardSummary,
+    CardListFilter, CardPriority, CardStatus, CardSummary, CardUpdate, CreateCardOptions,
+    FieldUpdate, GraphOperations, KanbanOperations, RelatesKind, SprintStatus,
 };
 
+use serde::Serialize;
 use uuid::Uuid;
 
+/// `card list` row: the summary plus its display identifier so table and
+/// template output can show `KAN-5` without a second lookup.
+#[derive(Serialize)]
+struct CardListItem {
+    identifier: String,
+    #[serde(flatten)]
+    card: CardSummary,
+}
+
 pub async fn handle(ctx: &mut CliContext, action: CardAction) -> anyhow::Result<()> {
     match action {
         CardAction::Create(args) => {
             let board_uuid = match ctx.resolve_board_id(&args.board) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let column_uuid = match ctx.resolve_column_id(&args.column, board_uuid) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let sprint_uuid = match resolve_assign_sprint(ctx, board_uuid, &args.assign_sprint) {
                 Ok(s) => s,
-                Err(e) => return output::output_error(&e),
+                Err(e) => return output::output_error(ctx.output(), &e),
             };
             let mut options = match build_create_options(&args) {
                 Ok(o) => o,
-                Err(e) => return output::output_error(&e),
+                Err(e) => return output::output_error(ctx.output(), &e),
             };
             options.sprint_id = sprint_uuid;
             let card = ctx.create_card(board_uuid, column_uuid, args.title, options)?;
             ctx.save().await?;
-            output::output_success(&card);
+            output::output_success(ctx.output(), &card);
         }
         CardAction::List(args) => {
             let (page, page_size) = resolve_page_params(args.page, args.page_size)?;
@@ -39,7 +51,7 @@ pub async fn handle(ctx: &mut CliContext, action: CardAction) -> anyhow::Result<
                 let board_id = match &args.board {
                     Some(raw) => match ctx.resolve_board_id(raw) {
                         Ok(u) => Some(u),
-                        Err(e) => return output::output_error(&e.to_string()),
+                        Err(e) => return output::output_error(ctx.output(), &e.to_string()),
                     },
                     None => None,
                 };
@@ -51,43 +63,67 @@ pub async fn handle(ctx: &mut CliContext, action: CardAction) -> anyhow::Result<
                     })?;
                 let summaries: Vec<ArchivedCardSummary> =
                     archived.iter().map(ArchivedCardSummary::from).collect();
-                output::output_success(PaginatedList::paginate(summaries, page, page_size)?);
+                output::output_success(
+                    ctx.output(),
+                    PaginatedList::paginate(summaries, page, page_size)?,
+                );
             } else {
                 let filter = match build_filter(ctx, &args) {
                     Ok(f) => f,
-                    Err(e) => return output::output_error(&e),
+                    Err(e) => return output::output_error(ctx.output(), &e),
                 };
                 let summaries = ctx.list_cards(filter)?;
-                output::output_success(PaginatedList::paginate(summaries, page, page_size)?);
+                let mut identifiers = ctx.card_identifiers(&summaries)?;
+                let items: Vec<CardListItem> = summaries
+                    .into_iter()
+                    .map(|card| CardListItem {
+                        identifier: identifiers.remove(&card.id).unwrap_or_default(),
+                        card,
+                    })
+                    .collect();
+                output::output_success(
+                    ctx.output(),
+                    PaginatedList::paginate(items, page, page_size)?,
+                );
             }
         }
         CardAction::Get { card } => {
             if let Ok(uuid) = Uuid::parse_str(&card) {
                 match ctx.get_card(uuid)? {
-                    Some(c) => output::output_success(&c),
-                    None => return output::output_error(&format!("Card not found: '{}'", card)),
+                    Some(c) => output::output_success(ctx.output(), &c),
+                    None => {
+                        return output::output_error(
+                            ctx.output(),
+                            &format!("Card not found: '{}'", card),
+                        )
+                    }
                 }
             } else {
                 let cards = ctx.find_cards_by_identifier(&card)?;
                 match cards.as_slice() {
-                    [] => return output::output_error(&format!("Card not found: '{}'", card)),
-                    [c] => output::output_success(c),
-                    _ => output::output_success(&cards),
+                    [] => {
+                        return output::output_error(
+                            ctx.output(),
+                            &format!("Card not found: '{}'", card),
+                        )
+                    }
+                    [c] => output::output_success(ctx.output(), c),
+                    _ => output::output_success(ctx.output(), &cards),
                 }
             }
         }
         CardAction::Update(args) => {
             let uuid = match ctx.resolve_card_id(&args.card) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let updates = match build_card_update(&args) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e),
+                Err(e) => return output::output_error(ctx.output(), &e),
             };
             let card = ctx.update_card(uuid, updates)?;
             ctx.save().await?;
-            output::output_success(&card);
+            output::output_success(ctx.output(), &card);
         }
         CardAction::Move {
             card,
@@ -96,145 +132,235 @@ pub async fn handle(ctx: &mut CliContext, action: CardAction) -> anyhow::Result<
         } => {
             let uuid = match ctx.resolve_card_id(&card) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let column_uuid = match resolve_column_for_card(ctx, &column, uuid) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e),
+                Err(e) => return output::output_error(ctx.output(), &e),
             };
             let moved = ctx.move_card(uuid, column_uuid, position)?;
             ctx.save().await?;
-            output::output_success(&moved);
+            output::output_success(ctx.output(), &moved);
         }
         CardAction::Archive { card } => {
             let uuid = match ctx.resolve_card_id(&card) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             ctx.archive_card(uuid)?;
             ctx.save().await?;
-            output::output_success(serde_json::json!({"archived": uuid.to_string()}));
+            output::output_success(
+                ctx.output(),
+                serde_json::json!({"archived": uuid.to_string()}),
+            );
         }
         CardAction::Restore { card, column } => {
             let uuid = match ctx.resolve_card_id(&card) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let column_uuid = match column {
                 Some(raw) => match resolve_column_for_card(ctx, &raw, uuid) {
                     Ok(u) => Some(u),
-                    Err(e) => return output::output_error(&e),
+                    Err(e) => return output::output_error(ctx.output(), &e),
                 },
                 None => None,
             };
             let restored = ctx.restore_card(uuid, column_uuid)?;
             ctx.save().await?;
-            output::output_success(&restored);
+            output::output_success(ctx.output(), &restored);
         }
         CardAction::Delete { card } => {
             let uuid = match ctx.resolve_card_id(&card) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             ctx.delete_card(uuid)?;
             ctx.save().await?;
-            output::output_success(serde_json::json!({"deleted": uuid.to_string()}));
+            output::output_success(
+                ctx.output(),
+                serde_json::json!({"deleted": uuid.to_string()}),
+            );
         }
         CardAction::AssignSprint { card, sprint } => {
             let uuid = match ctx.resolve_card_id(&card) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let sprint_uuid = match resolve_sprint_for_card(ctx, &sprint, uuid) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e),
+                Err(e) => return output::output_error(ctx.output(), &e),
             };
             let assigned = ctx.assign_card_to_sprint(uuid, sprint_uuid)?;
             ctx.save().await?;
-            output::output_success(&assigned);
+            output::output_success(ctx.output(), &assigned);
         }
         CardAction::UnassignSprint { card } => {
             let uuid = match ctx.resolve_card_id(&card) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let unassigned = ctx.unassign_card_from_sprint(uuid)?;
             ctx.save().await?;
-            output::output_success(&unassigned);
+            output::output_success(ctx.output(), &unassigned);
         }
         CardAction::BranchName { card } => {
             let uuid = match ctx.resolve_card_id(&card) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let branch = ctx.get_card_branch_name(uuid)?;
-            output::output_success(serde_json::json!({"branch_name": branch}));
+            output::output_success(ctx.output(), serde_json::json!({"branch_name": branch}));
         }
         CardAction::GitCheckout { card } => {
             let uuid = match ctx.resolve_card_id(&card) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let cmd = ctx.get_card_git_checkout(uuid)?;
-            output::output_success(serde_json::json!({"command": cmd}));
+            output::output_success(ctx.output(), serde_json::json!({"command": cmd}));
+        }
+        CardAction::GitStatus { card, repo } => {
+            let uuid = match ctx.resolve_card_id(&card) {
+                Ok(u) => u,
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+            };
+            let path = std::path::PathBuf::from(repo.as_deref().unwrap_or("."));
+            let repository = match kanban_service::git::GitRepository::discover(&path) {
+                Ok(r) => r,
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+            };
+            let status = ctx.card_git_status(uuid, &repository)?;
+            output::output_success(ctx.output(), &status);
+        }
+        CardAction::Refs {
+            card,
+            outgoing,
+            link,
+        } => {
+            let uuid = match ctx.resolve_card_id(&card) {
+                Ok(u) => u,
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+            };
+            let snapshot = ctx.snapshot()?;
+            let (cards, columns, boards, sprints) = (
+                &snapshot.cards,
+                &snapshot.columns,
+                &snapshot.boards,
+                &snapshot.sprints,
+            );
+            let index = index_cards_by_identifier(cards, columns, boards, sprints);
+            let found = if outgoing {
+                match cards.iter().find(|c| c.id == uuid) {
+                    Some(c) => find_card_references(c, &index),
+                    None => {
+                        return output::output_error(
+                            ctx.output(),
+                            &format!("Card not found: '{}'", card),
+                        )
+                    }
+                }
+            } else {
+                find_card_backlinks(uuid, cards, &index)
+            };
+            let summaries: Vec<CardSummary> = found.into_iter().map(CardSummary::from).collect();
+            if link {
+                let related = ctx.list_related_to(uuid)?;
+                let batch: Vec<Command> = summaries
+                    .iter()
+                    .filter(|c| !related.contains(&c.id))
+                    .map(|other| {
+                        Command::Dependency(DependencyCommand::AddRelates(AddRelates {
+                            source: uuid,
+                            target: other.id,
+                            kind: RelatesKind::MentionedIn,
+                            as_archived: false,
+                        }))
+                    })
+                    .collect();
+                if !batch.is_empty() {
+                    ctx.execute(batch)?;
+                    ctx.save().await?;
+                }
+            }
+            let mut identifiers = ctx.card_identifiers(&summaries)?;
+            let items: Vec<CardListItem> = summaries
+                .into_iter()
+                .map(|card| CardListItem {
+                    identifier: identifiers.remove(&card.id).unwrap_or_default(),
+                    card,
+                })
+                .collect();
+            output::output_success(ctx.output(), &items);
         }
+        CardAction::Checklist { action } => return super::checklist::handle(ctx, action).await,
+        CardAction::Attachment { action } => return super::attachment::handle(ctx, action).await,
         CardAction::ArchiveCards { cards } => {
             let uuids = match ctx.resolve_card_ids(&cards) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let result = ctx.archive_cards_detailed(uuids);
             ctx.save().await?;
-            output::output_success(serde_json::json!({
-                "succeeded_count": result.succeeded.len(),
-                "failed_count": result.failed.len(),
-                "succeeded": result.succeeded,
-                "failed": result.failed
-            }));
+            output::output_success(
+                ctx.output(),
+                serde_json::json!({
+                    "succeeded_count": result.succeeded.len(),
+                    "failed_count": result.failed.len(),
+                    "succeeded": result.succeeded,
+                    "failed": result.failed
+                }),
+            );
         }
         CardAction::MoveCards { cards, column } => {
             let uuids = match ctx.resolve_card_ids(&cards) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let shared_board = match ctx.require_same_board(&uuids) {
                 Ok(b) => b,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let column_uuid = match ctx.resolve_column_id(&column, shared_board) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let result = ctx.move_cards_detailed(uuids, column_uuid);
             ctx.save().await?;
-            output::output_success(serde_json::json!({
-                "succeeded_count": result.succeeded.len(),
-                "failed_count": result.failed.len(),
-                "succeeded": result.succeeded,
-                "failed": result.failed
-            }));
+            output::output_success(
+                ctx.output(),
+                serde_json::json!({
+                    "succeeded_count": result.succeeded.len(),
+                    "failed_count": result.failed.len(),
+                    "succeeded": result.succeeded,
+                    "failed": result.failed
+                }),
+            );
         }
         CardAction::AssignCardsToSprint { cards, sprint } => {
             let uuids = match ctx.resolve_card_ids(&cards) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let shared_board = match ctx.require_same_board(&uuids) {
                 Ok(b) => b,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let sprint_uuid = match ctx.resolve_sprint_id(&sprint, shared_board) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let result = ctx.assign_cards_to_sprint_detailed(uuids, sprint_uuid);
             ctx.save().await?;
-            output::output_success(serde_json::json!({
-                "succeeded_count": result.succeeded.len(),
-                "failed_count": result.failed.len(),
-                "succeeded": result.succeeded,
-                "failed": result.failed
-            }));
+            output::output_success(
+                ctx.output(),
+                serde_json::json!({
+                    "succeeded_count": result.succeeded.len(),
+                    "failed_count": result.failed.len(),
+                    "succeeded": result.succeeded,
+                    "failed": result.failed
+                }),
+            );
         }
     }
     Ok(())
@@ -0,0 +1,158 @@
+use crate::cli::ChecklistAction;
+use crate::context::CliContext;
+use crate::output;
+use kanban_domain::checklist::resolve_checklist_item;
+use kanban_domain::{Card, ChecklistItem, ChecklistProgress, KanbanOperations};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// `card checklist list` row: the item with its 1-based position, the
+/// number the other subcommands accept.
+#[derive(Serialize)]
+struct ChecklistRow {
+    position: usize,
+    #[serde(flatten)]
+    item: ChecklistItem,
+}
+
+#[derive(Serialize)]
+struct ChecklistListing {
+    card_id: Uuid,
+    progress: ChecklistProgress,
+    items: Vec<ChecklistRow>,
+}
+
+impl From<Card> for ChecklistListing {
+    fn from(card: Card) -> Self {
+        Self {
+            card_id: card.id,
+            progress: ChecklistProgress::of(&card.checklist),
+            items: card
+                .checklist
+                .into_iter()
+                .enumerate()
+                .map(|(i, item)| ChecklistRow {
+                    position: i + 1,
+                    item,
+                })
+                .collect(),
+        }
+    }
+}
+
+pub async fn handle(ctx: &mut CliContext, action: ChecklistAction) -> anyhow::Result<()> {
+    match action {
+        ChecklistAction::List { card } => {
+            let card = match load_card(ctx, &card) {
+                Ok(c) => c,
+                Err(e) => return output::output_error(ctx.output(), &e),
+            };
+            output::output_success(ctx.output(), ChecklistListing::from(card));
+        }
+        ChecklistAction::Add {
+            card,
+            text,
+            position,
+        } => {
+            let card = match load_card(ctx, &card) {
+                Ok(c) => c,
+                Err(e) => return output::output_error(ctx.output(), &e),
+            };
+            let item = match ctx.add_checklist_item(card.id, text, position.map(to_index)) {
+                Ok(i) => i,
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+            };
+            ctx.save().await?;
+            output::output_success(ctx.output(), &item);
+        }
+        ChecklistAction::Toggle { card, item } => {
+            let (card_id, item_id) = match resolve(ctx, &card, &item) {
+                Ok(ids) => ids,
+                Err(e) => return output::output_error(ctx.output(), &e),
+            };
+            let item = ctx.toggle_checklist_item(card_id, item_id)?;
+            ctx.save().await?;
+            output::output_success(ctx.output(), &item);
+        }
+        ChecklistAction::Edit { card, item, text } => {
+            let (card_id, item_id) = match resolve(ctx, &card, &item) {
+                Ok(ids) => ids,
+                Err(e) => return output::output_error(ctx.output(), &e),
+            };
+            let item = match ctx.update_checklist_item(card_id, item_id, Some(text), None) {
+                Ok(i) => i,
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+            };
+            ctx.save().await?;
+            output::output_success(ctx.output(), &item);
+        }
+        ChecklistAction::Move {
+            card,
+            item,
+            position,
+        } => {
+            let (card_id, item_id) = match resolve(ctx, &card, &item) {
+                Ok(ids) => ids,
+                Err(e) => return output::output_error(ctx.output(), &e),
+            };
+            let card = ctx.move_checklist_item(card_id, item_id, to_index(position))?;
+            ctx.save().await?;
+            output::output_success(ctx.output(), ChecklistListing::from(card));
+        }
+        ChecklistAction::Remove { card, item } => {
+            let (card_id, item_id) = match resolve(ctx, &card, &item) {
+                Ok(ids) => ids,
+                Err(e) => return output::output_error(ctx.output(), &e),
+            };
+            let card = ctx.remove_checklist_item(card_id, item_id)?;
+            ctx.save().await?;
+            output::output_success(ctx.output(), ChecklistListing::from(card));
+        }
+        ChecklistAction::Convert { card, item } => {
+            let (card_id, item_id) = match resolve(ctx, &card, &item) {
+                Ok(ids) => ids,
+                Err(e) => return output::output_error(ctx.output(), &e),
+            };
+            let child = match ctx.convert_checklist_item_to_card(card_id, item_id) {
+                Ok(c) => c,
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+            };
+            ctx.save().await?;
+            output::output_success(ctx.output(), &child);
+        }
+        ChecklistAction::Import { card } => {
+            let card = match load_card(ctx, &card) {
+                Ok(c) => c,
+                Err(e) => return output::output_error(ctx.output(), &e),
+            };
+            let items = ctx.import_checklist_from_description(card.id)?;
+            ctx.save().await?;
+            output::output_success(
+                ctx.output(),
+                serde_json::json!({
+                    "imported": items.len(),
+                    "items": items,
+                }),
+            );
+        }
+    }
+    Ok(())
+}
+
+/// 1-based CLI position to a 0-based index.
+fn to_index(position: usize) -> usize {
+    position.saturating_sub(1)
+}
+
+fn load_card(ctx: &CliContext, raw: &str) -> Result<Card, String> {
+    let id = ctx.resolve_card_id(raw).map_err(|e| e.to_string())?;
+    ctx.get_card(id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Card not found: '{}'", raw))
+}
+
+fn resolve(ctx: &CliContext, card: &str, item: &str) -> Result<(Uuid, Uuid), String> {
+    let card = load_card(ctx, card)?;
+    let item_id = resolve_checklist_item(&card.checklist, item).map_err(|e| e.to_string())?;
+    Ok((card.id, item_id))
+}
@@ -13,11 +13,11 @@ pub async fn handle(ctx: &mut CliContext, action: ColumnAction) -> anyhow::Resul
         } => {
             let board_uuid = match ctx.resolve_board_id(&board) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let column = ctx.create_column(board_uuid, name, position)?;
             ctx.save().await?;
-            output::output_success(&column);
+            output::output_success(ctx.output(), &column);
         }
         ColumnAction::List {
             board,
@@ -26,43 +26,54 @@ pub async fn handle(ctx: &mut CliContext, action: ColumnAction) -> anyhow::Resul
         } => {
             let board_uuid = match ctx.resolve_board_id(&board) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let columns = ctx.list_columns(board_uuid)?;
             let (page, page_size) = resolve_page_params(page, page_size)?;
-            output::output_success(PaginatedList::paginate(columns, page, page_size)?);
+            output::output_success(
+                ctx.output(),
+                PaginatedList::paginate(columns, page, page_size)?,
+            );
         }
         ColumnAction::Get { column } => {
             let uuid = match ctx.resolve_column_id_global(&column) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             match ctx.get_column(uuid)? {
-                Some(c) => output::output_success(&c),
-                None => return output::output_error(&format!("Column not found: {}", column)),
+                Some(c) => output::output_success(ctx.output(), &c),
+                None => {
+                    return output::output_error(
+                        ctx.output(),
+                        &format!("Column not found: {}", column),
+                    )
+                }
             }
         }
         ColumnAction::Update(args) => {
             let column = handle_update(ctx, args).await?;
-            output::output_success(&column);
+            output::output_success(ctx.output(), &column);
         }
         ColumnAction::Delete { column } => {
             let uuid = match ctx.resolve_column_id_global(&column) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             ctx.delete_column(uuid)?;
             ctx.save().await?;
-            output::output_success(serde_json::json!({"deleted": uuid.to_string()}));
+            output::output_success(
+                ctx.output(),
+                serde_json::json!({"deleted": uuid.to_string()}),
+            );
         }
         ColumnAction::Reorder { column, position } => {
             let uuid = match ctx.resolve_column_id_global(&column) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let c = ctx.reorder_column(uuid, position)?;
             ctx.save().await?;
-            output::output_success(&c);
+            output::output_success(ctx.output(), &c);
         }
     }
     Ok(())
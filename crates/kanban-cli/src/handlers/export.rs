@@ -1,26 +1,88 @@
-use crate::cli::{ExportArgs, ImportArgs};
+use crate::cli::{ExportArgs, ImportArgs, ImportFormatArg};
 use crate::context::CliContext;
 use crate::output;
-use kanban_domain::KanbanOperations;
+use kanban_domain::{CsvMapping, ForeignImportOptions, KanbanOperations};
 
 pub async fn handle_export(ctx: &CliContext, args: ExportArgs) -> anyhow::Result<()> {
     let board_uuid = match args.board {
         Some(raw) => match ctx.resolve_board_id(&raw) {
             Ok(u) => Some(u),
-            Err(e) => return output::output_error(&e.to_string()),
+            Err(e) => return output::output_error(ctx.output(), &e.to_string()),
         },
         None => None,
     };
-    let json = ctx.export_board(board_uuid)?;
-    println!("{}", json);
+    let exported = ctx.export_board_as(board_uuid, args.format.into())?;
+    print!("{}", exported);
+    if !exported.ends_with('\n') {
+        println!();
+    }
     Ok(())
 }
 
 pub async fn handle_import(ctx: &mut CliContext, args: ImportArgs) -> anyhow::Result<()> {
-    let data = std::fs::read_to_string(&args.file)
-        .map_err(|e| anyhow::anyhow!("Failed to read file {}: {}", args.file, e))?;
-    let board = ctx.import_board(&data)?;
+    let data = std::fs::read_to_string(args.file())
+        .map_err(|e| anyhow::anyhow!("Failed to read file {}: {}", args.file(), e))?;
+    if let Some(source) = args.from {
+        let options = ForeignImportOptions {
+            name: args.name,
+            dry_run: args.dry_run,
+        };
+        let report = match ctx.import_foreign(source.into(), &data, options) {
+            Ok(r) => r,
+            Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+        };
+        if !report.dry_run {
+            ctx.save().await?;
+        }
+        output::output_success(ctx.output(), report);
+        return Ok(());
+    }
+    let format = args
+        .format
+        .unwrap_or(if args.file().to_lowercase().ends_with(".csv") {
+            ImportFormatArg::Csv
+        } else {
+            ImportFormatArg::Json
+        });
+    if format == ImportFormatArg::Csv {
+        return import_csv(ctx, args, &data).await;
+    }
+    let board = if args.copy {
+        ctx.import_board_as_copy(&data)?
+    } else {
+        ctx.import_board(&data)?
+    };
+    ctx.save().await?;
+    output::output_success(ctx.output(), &board);
+    Ok(())
+}
+
+async fn import_csv(ctx: &mut CliContext, args: ImportArgs, data: &str) -> anyhow::Result<()> {
+    if args.copy {
+        return output::output_error(ctx.output(), "--copy only applies to JSON imports");
+    }
+    let board_uuid = match args.board {
+        Some(raw) => match ctx.resolve_board_id(&raw) {
+            Ok(u) => Some(u),
+            Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+        },
+        None => None,
+    };
+    let mapping = args
+        .mappings
+        .iter()
+        .try_fold(CsvMapping::default(), |mapping, entry| {
+            mapping.parse_entry(entry)
+        });
+    let mapping = match mapping {
+        Ok(m) => m,
+        Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+    };
+    let summary = match ctx.import_cards_csv(board_uuid, data, &mapping) {
+        Ok(s) => s,
+        Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+    };
     ctx.save().await?;
-    output::output_success(&board);
+    output::output_success(ctx.output(), summary);
     Ok(())
 }
@@ -0,0 +1,67 @@
+use crate::cli::{GitAction, GitCommand};
+use crate::context::CliContext;
+use crate::output;
+use kanban_domain::KanbanOperations;
+use kanban_service::git::GitRepository;
+use kanban_service::git_hooks;
+use std::path::Path;
+
+pub async fn handle(ctx: &mut CliContext, file: &str, cmd: GitCommand) -> anyhow::Result<()> {
+    let repo = match GitRepository::discover(Path::new(cmd.repo.as_deref().unwrap_or("."))) {
+        Ok(r) => r,
+        Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+    };
+    match cmd.action {
+        GitAction::InstallHooks { force } => {
+            let data_file = std::fs::canonicalize(file)?;
+            let kanban = std::env::current_exe()?;
+            let hooks = match git_hooks::install(&repo, &kanban, &data_file, force) {
+                Ok(h) => h,
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+            };
+            output::output_success(
+                ctx.output(),
+                serde_json::json!({
+                    "repository": repo.root(),
+                    "file": data_file,
+                    "hooks": hooks,
+                }),
+            );
+        }
+        GitAction::CommitMsg { file: message_file } => {
+            let message = git_hooks::strip_comments(&std::fs::read_to_string(message_file)?);
+            let references = git_hooks::parse_references(&message);
+            let mut unknown = Vec::new();
+            for reference in &references {
+                if ctx.find_cards_by_identifier(&reference.identifier)?.len() != 1 {
+                    eprintln!(
+                        "kanban: {} does not name a card on this board file",
+                        reference.identifier
+                    );
+                    unknown.push(reference.identifier.clone());
+                }
+            }
+            output::output_success(
+                ctx.output(),
+                serde_json::json!({
+                    "references": references,
+                    "unknown": unknown,
+                }),
+            );
+        }
+        GitAction::PostCommit => {
+            let commit = repo.commit("HEAD")?;
+            let report = ctx.apply_git_hooks(&[commit], &[])?;
+            ctx.save().await?;
+            output::output_success(ctx.output(), &report);
+        }
+        GitAction::PostMerge => {
+            let commits = repo.commits("ORIG_HEAD..HEAD")?;
+            let merged = repo.merged_branches("ORIG_HEAD")?;
+            let report = ctx.apply_git_hooks(&commits, &merged)?;
+            ctx.save().await?;
+            output::output_success(ctx.output(), &report);
+        }
+    }
+    Ok(())
+}
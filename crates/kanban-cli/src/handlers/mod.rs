@@ -1,7 +1,11 @@
+pub mod attachment;
 pub mod board;
 pub mod card;
+pub mod checklist;
 pub mod column;
 pub mod export;
+pub mod git;
 pub mod migrate;
 pub mod relation;
 pub mod sprint;
+pub mod watch;
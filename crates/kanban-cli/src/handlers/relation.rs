@@ -121,10 +121,10 @@ pub async fn handle(ctx: &mut CliContext, action: RelationAction) -> anyhow::Res
     let result: KanbanCliResult<serde_json::Value> = run(ctx, action).await;
     match result {
         Ok(value) => {
-            output::output_success(value);
+            output::output_success(ctx.output(), value);
             Ok(())
         }
-        Err(e) => output::output_error(&e.to_string()),
+        Err(e) => output::output_error(ctx.output(), &e.to_string()),
     }
 }
 
@@ -13,11 +13,11 @@ pub async fn handle(ctx: &mut CliContext, action: SprintAction) -> anyhow::Resul
         } => {
             let board_uuid = match ctx.resolve_board_id(&board) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let sprint = ctx.create_sprint(board_uuid, prefix, name)?;
             ctx.save().await?;
-            output::output_success(&sprint);
+            output::output_success(ctx.output(), &sprint);
         }
         SprintAction::List {
             board,
@@ -26,28 +26,36 @@ pub async fn handle(ctx: &mut CliContext, action: SprintAction) -> anyhow::Resul
         } => {
             let board_uuid = match ctx.resolve_board_id(&board) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let sprints = ctx.list_sprints(board_uuid)?;
             let (page, page_size) = resolve_page_params(page, page_size)?;
-            output::output_success(PaginatedList::paginate(sprints, page, page_size)?);
+            output::output_success(
+                ctx.output(),
+                PaginatedList::paginate(sprints, page, page_size)?,
+            );
         }
         SprintAction::Get { sprint } => {
             let uuid = match ctx.resolve_sprint_id_global(&sprint) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             match ctx.get_sprint(uuid)? {
-                Some(s) => output::output_success(&s),
-                None => return output::output_error(&format!("Sprint not found: {}", sprint)),
+                Some(s) => output::output_success(ctx.output(), &s),
+                None => {
+                    return output::output_error(
+                        ctx.output(),
+                        &format!("Sprint not found: {}", sprint),
+                    )
+                }
             }
         }
         SprintAction::Update(args) => {
             let sprint = match handle_update(ctx, args).await {
                 Ok(s) => s,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
-            output::output_success(&sprint);
+            output::output_success(ctx.output(), &sprint);
         }
         SprintAction::Activate {
             sprint,
@@ -55,43 +63,46 @@ pub async fn handle(ctx: &mut CliContext, action: SprintAction) -> anyhow::Resul
         } => {
             let uuid = match ctx.resolve_sprint_id_global(&sprint) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let activated = ctx.activate_sprint(uuid, duration_days)?;
             ctx.save().await?;
-            output::output_success(&activated);
+            output::output_success(ctx.output(), &activated);
         }
         SprintAction::Complete { sprint } => {
             let uuid = match ctx.resolve_sprint_id_global(&sprint) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let completed = ctx.complete_sprint(uuid)?;
             ctx.save().await?;
-            output::output_success(&completed);
+            output::output_success(ctx.output(), &completed);
         }
         SprintAction::Cancel { sprint } => {
             let uuid = match ctx.resolve_sprint_id_global(&sprint) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let cancelled = ctx.cancel_sprint(uuid)?;
             ctx.save().await?;
-            output::output_success(&cancelled);
+            output::output_success(ctx.output(), &cancelled);
         }
         SprintAction::Delete { sprint } => {
             let uuid = match ctx.resolve_sprint_id_global(&sprint) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             ctx.delete_sprint(uuid)?;
             ctx.save().await?;
-            output::output_success(serde_json::json!({"deleted": uuid.to_string()}));
+            output::output_success(
+                ctx.output(),
+                serde_json::json!({"deleted": uuid.to_string()}),
+            );
         }
         SprintAction::CarryOver { from, to } => {
             let from_uuid = match ctx.resolve_sprint_id_global(&from) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             // `--to` is scoped to the same board as `--from`.
             let from_sprint = ctx
@@ -99,11 +110,11 @@ pub async fn handle(ctx: &mut CliContext, action: SprintAction) -> anyhow::Resul
                 .ok_or_else(|| anyhow::anyhow!("Source sprint not found: {}", from_uuid))?;
             let to_uuid = match ctx.resolve_sprint_id(&to, from_sprint.board_id) {
                 Ok(u) => u,
-                Err(e) => return output::output_error(&e.to_string()),
+                Err(e) => return output::output_error(ctx.output(), &e.to_string()),
             };
             let count = ctx.carry_over_sprint_cards(from_uuid, to_uuid)?;
             ctx.save().await?;
-            output::output_success(serde_json::json!({ "carried_over": count }));
+            output::output_success(ctx.output(), serde_json::json!({ "carried_over": count }));
         }
     }
     Ok(())
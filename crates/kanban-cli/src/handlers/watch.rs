@@ -0,0 +1,261 @@
+use crate::cli::WatchArgs;
+use crate::context::CliContext;
+use crate::output;
+use chrono::{DateTime, Utc};
+use kanban_domain::{
+    diff_snapshots, CardSummary, ChangedEntity, KanbanOperations, Snapshot, SnapshotChange,
+};
+use kanban_persistence::{ChangeDetector, FileWatcher};
+use serde::Serialize;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use uuid::Uuid;
+
+/// One JSON line emitted by `kanban watch`.
+#[derive(Serialize)]
+struct WatchEvent {
+    at: DateTime<Utc>,
+    #[serde(flatten)]
+    change: SnapshotChange,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    identifier: Option<String>,
+    /// Card title, or board/column name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+struct WatchFilter {
+    board_id: Option<Uuid>,
+    card_id: Option<Uuid>,
+}
+
+impl WatchFilter {
+    fn matches(&self, change: &SnapshotChange) -> bool {
+        if let Some(card_id) = self.card_id {
+            return change.entity == ChangedEntity::Card && change.id == card_id;
+        }
+        match self.board_id {
+            Some(board_id) => change.board_id == Some(board_id),
+            None => true,
+        }
+    }
+}
+
+pub async fn handle(ctx: &mut CliContext, file: &str, args: WatchArgs) -> anyhow::Result<()> {
+    let board_id = match &args.board {
+        Some(raw) => match ctx.resolve_board_id(raw) {
+            Ok(u) => Some(u),
+            Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+        },
+        None => None,
+    };
+    let card_id = match &args.card {
+        Some(raw) => match ctx.resolve_card_id(raw) {
+            Ok(u) => Some(u),
+            Err(e) => return output::output_error(ctx.output(), &e.to_string()),
+        },
+        None => None,
+    };
+    let filter = WatchFilter { board_id, card_id };
+
+    let mut previous = ctx.snapshot()?;
+    if args.summary {
+        println!("{}", column_summary(&previous, board_id));
+        if args.once {
+            return Ok(());
+        }
+    }
+
+    let watcher = FileWatcher::new();
+    let mut rx = watcher.subscribe();
+    watcher.start_watching(file.into()).await?;
+    let mut poll = tokio::time::interval(Duration::from_secs(args.poll_interval.max(1)));
+    poll.tick().await;
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    let mut events_open = true;
+
+    loop {
+        tokio::select! {
+            event = rx.recv(), if events_open => match event {
+                // A lagged receiver missed events, so something changed.
+                Ok(_) | Err(RecvError::Lagged(_)) => {}
+                Err(RecvError::Closed) => {
+                    // The watcher is gone; keep going on the poll timer alone.
+                    tracing::debug!("watch event channel closed; polling only");
+                    events_open = false;
+                    continue;
+                }
+            },
+            _ = poll.tick() => {}
+            _ = &mut ctrl_c => break,
+        }
+        if let Err(e) = ctx.reload().await {
+            // A writer may be mid-rename; the next event or poll retries.
+            tracing::debug!("watch reload failed: {e}");
+            continue;
+        }
+        let current = ctx.snapshot()?;
+        let changes: Vec<SnapshotChange> = diff_snapshots(&previous, &current)
+            .into_iter()
+            .filter(|c| filter.matches(c))
+            .collect();
+        if !changes.is_empty() {
+            if args.summary {
+                println!("{}", column_summary(&current, board_id));
+            } else {
+                for event in describe(ctx, &previous, &current, changes)? {
+                    println!("{}", serde_json::to_string(&event)?);
+                }
+            }
+            if args.once {
+                break;
+            }
+        }
+        previous = current;
+    }
+    watcher.stop_watching().await?;
+    Ok(())
+}
+
+fn describe(
+    ctx: &CliContext,
+    previous: &Snapshot,
+    current: &Snapshot,
+    changes: Vec<SnapshotChange>,
+) -> anyhow::Result<Vec<WatchEvent>> {
+    let find_card = |id: Uuid| {
+        [current, previous].into_iter().find_map(|s| {
+            s.cards
+                .iter()
+                .chain(s.archived_cards.iter().map(|a| &a.card))
+                .find(|c| c.id == id)
+        })
+    };
+    let cards: Vec<CardSummary> = changes
+        .iter()
+        .filter(|c| c.entity == ChangedEntity::Card)
+        .filter_map(|c| find_card(c.id))
+        .map(CardSummary::from)
+        .collect();
+    let mut identifiers = ctx.card_identifiers(&cards)?;
+    let at = Utc::now();
+    Ok(changes
+        .into_iter()
+        .map(|change| {
+            let name = match change.entity {
+                ChangedEntity::Card => find_card(change.id).map(|c| c.title.clone()),
+                ChangedEntity::Board => [current, previous]
+                    .into_iter()
+                    .find_map(|s| s.boards.iter().find(|b| b.id == change.id))
+                    .map(|b| b.name.clone()),
+                ChangedEntity::Column => [current, previous]
+                    .into_iter()
+                    .find_map(|s| s.columns.iter().find(|c| c.id == change.id))
+                    .map(|c| c.name.clone()),
+                ChangedEntity::Sprint => None,
+            };
+            WatchEvent {
+                at,
+                identifier: identifiers.remove(&change.id),
+                name,
+                change,
+            }
+        })
+        .collect())
+}
+
+/// Status-bar line of card counts per column, e.g. `Todo 3 | Doing 1 | Done 4`.
+/// Without a board filter each board is prefixed with its name.
+fn column_summary(snapshot: &Snapshot, board_id: Option<Uuid>) -> String {
+    let mut boards: Vec<_> = snapshot
+        .boards
+        .iter()
+        .filter(|b| board_id.is_none() || board_id == Some(b.id))
+        .collect();
+    boards.sort_by_key(|b| b.position);
+    boards
+        .iter()
+        .map(|board| {
+            let mut columns: Vec<_> = snapshot
+                .columns
+                .iter()
+                .filter(|c| c.board_id == board.id)
+                .collect();
+            columns.sort_by_key(|c| c.position);
+            let counts = columns
+                .iter()
+                .map(|column| {
+                    let count = snapshot
+                        .cards
+                        .iter()
+                        .filter(|card| card.column_id == column.id)
+                        .count();
+                    format!("{} {}", column.name, count)
+                })
+                .collect::<Vec<_>>()
+                .join(" | ");
+            if board_id.is_some() {
+                counts
+            } else {
+                format!("{}: {}", board.name, counts)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kanban_domain::{Board, Card, Column};
+
+    fn snapshot() -> (Snapshot, Board) {
+        let mut board = Board::new("Ops", None::<String>);
+        let todo = Column::new(board.id, "Todo", 0);
+        let done = Column::new(board.id, "Done", 1);
+        let cards = vec![
+            Card::new(&mut board, todo.id, "a", 0),
+            Card::new(&mut board, todo.id, "b", 1),
+            Card::new(&mut board, done.id, "c", 0),
+        ];
+        let snapshot = Snapshot {
+            boards: vec![board.clone()],
+            columns: vec![done, todo],
+            cards,
+            ..Default::default()
+        };
+        (snapshot, board)
+    }
+
+    #[test]
+    fn test_column_summary_for_single_board_omits_name() {
+        let (snapshot, board) = snapshot();
+        assert_eq!(column_summary(&snapshot, Some(board.id)), "Todo 2 | Done 1");
+    }
+
+    #[test]
+    fn test_column_summary_without_filter_prefixes_board_name() {
+        let (snapshot, _) = snapshot();
+        assert_eq!(column_summary(&snapshot, None), "Ops: Todo 2 | Done 1");
+    }
+
+    #[test]
+    fn test_card_filter_ignores_other_entities() {
+        let card_id = Uuid::new_v4();
+        let filter = WatchFilter {
+            board_id: None,
+            card_id: Some(card_id),
+        };
+        let change = |entity, id| SnapshotChange {
+            entity,
+            change: kanban_domain::ChangeKind::Updated,
+            id,
+            board_id: None,
+            fields: vec![],
+        };
+        assert!(filter.matches(&change(ChangedEntity::Card, card_id)));
+        assert!(!filter.matches(&change(ChangedEntity::Card, Uuid::new_v4())));
+        assert!(!filter.matches(&change(ChangedEntity::Board, card_id)));
+    }
+}
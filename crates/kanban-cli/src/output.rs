@@ -1,4 +1,27 @@
+use crate::cli::{OutputArgs, OutputMode};
 use serde::Serialize;
+use serde_json::Value;
+use std::io::IsTerminal;
+
+const MAX_CELL_WIDTH: usize = 48;
+
+/// Columns shown first, in this order, when present. Remaining columns
+/// follow alphabetically.
+const LEADING_COLUMNS: &[&str] = &[
+    "identifier",
+    "card_number",
+    "name",
+    "title",
+    "status",
+    "priority",
+    "points",
+    "due_date",
+    "position",
+];
+
+/// Bookkeeping columns dropped from tables to keep rows terminal-width.
+/// CSV and JSON-lines keep them.
+const TABLE_HIDDEN_COLUMNS: &[&str] = &["created_at", "updated_at", "completed_at"];
 
 #[derive(Serialize)]
 pub struct CliResponse<T: Serialize> {
@@ -10,27 +33,499 @@ pub struct CliResponse<T: Serialize> {
     pub error: Option<String>,
 }
 
-pub fn output_success<T: Serialize>(data: T) {
-    let response = CliResponse {
-        success: true,
-        api_version: env!("CARGO_PKG_VERSION"),
-        data: Some(data),
-        error: None,
-    };
-    println!("{}", serde_json::to_string(&response).unwrap());
+/// Resolved rendering options for the current invocation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutputSettings {
+    pub mode: OutputMode,
+    pub template: Option<String>,
+    pub color: bool,
+}
+
+impl Default for OutputSettings {
+    fn default() -> Self {
+        Self {
+            mode: OutputMode::Json,
+            template: None,
+            color: false,
+        }
+    }
+}
+
+impl OutputSettings {
+    /// Resolves CLI flags into settings. `stdout_is_tty` picks the default
+    /// mode when `--output` is absent so piped invocations keep emitting the
+    /// JSON envelope.
+    pub fn resolve(args: &OutputArgs, stdout_is_tty: bool) -> Result<Self, String> {
+        let mode = match (args.mode, &args.format) {
+            (Some(OutputMode::Template) | None, Some(_)) => OutputMode::Template,
+            (Some(OutputMode::Template), None) => {
+                return Err("--output template requires --format '<template>'".to_string())
+            }
+            (Some(mode), Some(_)) => {
+                return Err(format!(
+                    "--format only applies to --output template, not {}",
+                    mode_name(mode)
+                ))
+            }
+            (Some(mode), None) => mode,
+            (None, None) if stdout_is_tty => OutputMode::Table,
+            (None, None) => OutputMode::Json,
+        };
+        let color = mode == OutputMode::Table
+            && stdout_is_tty
+            && !matches!(std::env::var_os("NO_COLOR"), Some(v) if !v.is_empty());
+        Ok(Self {
+            mode,
+            template: args.format.clone(),
+            color,
+        })
+    }
+}
+
+/// Settings for a subcommand's output flags. Commands without them
+/// (`init`, `import`, …) keep the JSON envelope.
+pub fn settings_for(args: Option<&OutputArgs>) -> anyhow::Result<OutputSettings> {
+    match args {
+        Some(args) => OutputSettings::resolve(args, std::io::stdout().is_terminal())
+            .map_err(|e| anyhow::anyhow!(e)),
+        None => Ok(OutputSettings::default()),
+    }
+}
+
+pub fn output_success<T: Serialize>(settings: &OutputSettings, data: T) {
+    if settings.mode == OutputMode::Json {
+        let response = CliResponse {
+            success: true,
+            api_version: env!("CARGO_PKG_VERSION"),
+            data: Some(data),
+            error: None,
+        };
+        println!("{}", serde_json::to_string(&response).unwrap());
+        return;
+    }
+    let value = serde_json::to_value(&data).unwrap();
+    let rendered = render(&value, settings);
+    if !rendered.is_empty() {
+        println!("{}", rendered);
+    }
 }
 
 /// Outputs an error response to stderr and returns an error for proper propagation.
 ///
 /// Returns an `anyhow::Error` to allow callers to handle the error appropriately
 /// and enable proper cleanup. The CLI's main function handles the exit code.
-pub fn output_error(message: &str) -> anyhow::Result<()> {
-    let response: CliResponse<()> = CliResponse {
-        success: false,
-        api_version: env!("CARGO_PKG_VERSION"),
-        data: None,
-        error: Some(message.to_string()),
-    };
-    eprintln!("{}", serde_json::to_string(&response).unwrap());
+/// Human-oriented modes skip the JSON envelope and leave the message to
+/// `main`'s `Error:` line.
+pub fn output_error(settings: &OutputSettings, message: &str) -> anyhow::Result<()> {
+    if matches!(settings.mode, OutputMode::Json | OutputMode::Jsonl) {
+        let response: CliResponse<()> = CliResponse {
+            success: false,
+            api_version: env!("CARGO_PKG_VERSION"),
+            data: None,
+            error: Some(message.to_string()),
+        };
+        eprintln!("{}", serde_json::to_string(&response).unwrap());
+    }
     anyhow::bail!("{}", message)
 }
+
+/// Renders a response payload in one of the non-envelope modes.
+pub fn render(value: &Value, settings: &OutputSettings) -> String {
+    let (rows, is_list) = rows_of(value);
+    match settings.mode {
+        OutputMode::Json => serde_json::to_string(value).unwrap(),
+        OutputMode::Jsonl => rows
+            .iter()
+            .map(|row| serde_json::to_string(row).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputMode::Csv => render_csv(&rows),
+        OutputMode::Template => {
+            let template = settings.template.as_deref().unwrap_or_default();
+            rows.iter()
+                .map(|row| render_template(template, row))
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+        OutputMode::Table if is_list => render_table(&rows, settings.color),
+        OutputMode::Table => render_record(value, settings.color),
+    }
+}
+
+/// Splits a payload into result rows. Paginated lists and arrays yield one
+/// row per item; anything else is a single row.
+fn rows_of(value: &Value) -> (Vec<&Value>, bool) {
+    match value {
+        Value::Array(items) => (items.iter().collect(), true),
+        Value::Object(map) => match map.get("items") {
+            Some(Value::Array(items)) => (items.iter().collect(), true),
+            _ => (vec![value], false),
+        },
+        _ => (vec![value], false),
+    }
+}
+
+fn columns_of(rows: &[&Value]) -> Vec<String> {
+    let mut columns: Vec<String> = Vec::new();
+    for row in rows {
+        if let Value::Object(map) = row {
+            for key in map.keys() {
+                if !columns.contains(key) {
+                    columns.push(key.clone());
+                }
+            }
+        }
+    }
+    columns.sort_by_key(|c| {
+        let rank = LEADING_COLUMNS
+            .iter()
+            .position(|l| l == c)
+            .unwrap_or(LEADING_COLUMNS.len());
+        (rank, c.clone())
+    });
+    columns
+}
+
+fn table_columns_of(rows: &[&Value]) -> Vec<String> {
+    columns_of(rows)
+        .into_iter()
+        .filter(|c| !TABLE_HIDDEN_COLUMNS.contains(&c.as_str()) && !c.ends_with("_id"))
+        .collect()
+}
+
+fn lookup<'a>(row: &'a Value, path: &str) -> Option<&'a Value> {
+    if path == "." {
+        return Some(row);
+    }
+    path.split('.')
+        .try_fold(row, |current, segment| current.get(segment))
+}
+
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Bool(_) | Value::Number(_) => value.to_string(),
+        Value::Array(items) if items.iter().all(|v| !v.is_object() && !v.is_array()) => {
+            items.iter().map(cell_text).collect::<Vec<_>>().join(", ")
+        }
+        Value::Array(_) | Value::Object(_) => value.to_string(),
+    }
+}
+
+fn row_cells(row: &Value, columns: &[String]) -> Vec<String> {
+    if columns.is_empty() {
+        return vec![cell_text(row)];
+    }
+    columns
+        .iter()
+        .map(|c| row.get(c).map(cell_text).unwrap_or_default())
+        .collect()
+}
+
+fn render_template(template: &str, row: &Value) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        match after.find("}}") {
+            Some(end) => {
+                let key = after[..end].trim();
+                if let Some(value) = lookup(row, key) {
+                    out.push_str(&cell_text(value));
+                }
+                rest = &after[end + 2..];
+            }
+            None => {
+                out.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn render_csv(rows: &[&Value]) -> String {
+    let columns = columns_of(rows);
+    let header = if columns.is_empty() {
+        vec!["value".to_string()]
+    } else {
+        columns.clone()
+    };
+    std::iter::once(header)
+        .chain(rows.iter().map(|row| row_cells(row, &columns)))
+        .map(|cells| {
+            cells
+                .iter()
+                .map(|c| csv_escape(c))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn truncate_cell(text: &str) -> String {
+    let flat: String = text
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect();
+    if flat.chars().count() <= MAX_CELL_WIDTH {
+        flat
+    } else {
+        let mut cut: String = flat.chars().take(MAX_CELL_WIDTH - 1).collect();
+        cut.push('…');
+        cut
+    }
+}
+
+fn paint(text: &str, column: &str, raw: &str, color: bool) -> String {
+    if !color {
+        return text.to_string();
+    }
+    let code = match (column, raw) {
+        ("status", "Done") | ("status", "Completed") => "32",
+        ("status", "InProgress") | ("status", "Active") => "33",
+        ("status", "Blocked") | ("status", "Cancelled") => "31",
+        ("priority", "Critical") => "1;31",
+        ("priority", "High") => "31",
+        ("priority", "Medium") => "33",
+        _ => return text.to_string(),
+    };
+    format!("\x1b[{code}m{text}\x1b[0m")
+}
+
+fn pad(text: &str, width: usize) -> String {
+    let len = text.chars().count();
+    format!("{}{}", text, " ".repeat(width.saturating_sub(len)))
+}
+
+fn render_table(rows: &[&Value], color: bool) -> String {
+    if rows.is_empty() {
+        return String::new();
+    }
+    let columns = table_columns_of(rows);
+    let header: Vec<String> = if columns.is_empty() {
+        vec!["VALUE".to_string()]
+    } else {
+        columns.iter().map(|c| c.to_uppercase()).collect()
+    };
+    let body: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            row_cells(row, &columns)
+                .iter()
+                .map(|c| truncate_cell(c))
+                .collect()
+        })
+        .collect();
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            body.iter()
+                .map(|r| r[i].chars().count())
+                .chain(std::iter::once(header[i].chars().count()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let last = header.len() - 1;
+    let format_line = |cells: &[String], painter: &dyn Fn(usize, String) -> String| {
+        cells
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let padded = if i == last {
+                    c.clone()
+                } else {
+                    pad(c, widths[i])
+                };
+                painter(i, padded)
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+    let mut lines = vec![format_line(&header, &|_, text| {
+        if color {
+            format!("\x1b[1m{text}\x1b[0m")
+        } else {
+            text
+        }
+    })];
+    for cells in &body {
+        lines.push(format_line(cells, &|i, text| {
+            let column = columns.get(i).map(String::as_str).unwrap_or_default();
+            paint(&text, column, cells[i].as_str(), color)
+        }));
+    }
+    lines.join("\n")
+}
+
+fn render_record(value: &Value, color: bool) -> String {
+    let Value::Object(map) = value else {
+        return cell_text(value);
+    };
+    let width = map.keys().map(|k| k.chars().count()).max().unwrap_or(0);
+    map.iter()
+        .map(|(key, v)| {
+            let label = pad(key, width);
+            let label = if color {
+                format!("\x1b[1m{label}\x1b[0m")
+            } else {
+                label
+            };
+            let text = cell_text(v);
+            format!("{label}  {}", paint(&text, key, &text, color))
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn mode_name(mode: OutputMode) -> &'static str {
+    match mode {
+        OutputMode::Table => "table",
+        OutputMode::Json => "json",
+        OutputMode::Jsonl => "jsonl",
+        OutputMode::Csv => "csv",
+        OutputMode::Template => "template",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn settings(mode: OutputMode) -> OutputSettings {
+        OutputSettings {
+            mode,
+            template: None,
+            color: false,
+        }
+    }
+
+    fn args(mode: Option<OutputMode>, format: Option<&str>) -> OutputArgs {
+        OutputArgs {
+            mode,
+            format: format.map(str::to_string),
+        }
+    }
+
+    fn page() -> Value {
+        json!({
+            "items": [
+                {"identifier": "KAN-1", "title": "First", "priority": "High", "points": 3, "column_id": "c1"},
+                {"identifier": "KAN-22", "title": "Second, with comma", "priority": "Low", "points": null, "column_id": "c1"}
+            ],
+            "total": 2, "page": 1, "page_size": 50, "total_pages": 1
+        })
+    }
+
+    #[test]
+    fn test_resolve_defaults_to_json_when_piped() {
+        let resolved = OutputSettings::resolve(&args(None, None), false).unwrap();
+        assert_eq!(resolved.mode, OutputMode::Json);
+    }
+
+    #[test]
+    fn test_resolve_defaults_to_table_on_tty() {
+        let resolved = OutputSettings::resolve(&args(None, None), true).unwrap();
+        assert_eq!(resolved.mode, OutputMode::Table);
+    }
+
+    #[test]
+    fn test_resolve_format_alone_implies_template() {
+        let resolved = OutputSettings::resolve(&args(None, Some("{{title}}")), false).unwrap();
+        assert_eq!(resolved.mode, OutputMode::Template);
+    }
+
+    #[test]
+    fn test_resolve_rejects_template_without_format() {
+        assert!(OutputSettings::resolve(&args(Some(OutputMode::Template), None), false).is_err());
+    }
+
+    #[test]
+    fn test_resolve_rejects_format_with_other_mode() {
+        let err = OutputSettings::resolve(&args(Some(OutputMode::Csv), Some("{{x}}")), false)
+            .unwrap_err();
+        assert!(err.contains("csv"));
+    }
+
+    #[test]
+    fn test_table_aligns_columns() {
+        let out = render(&page(), &settings(OutputMode::Table));
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("IDENTIFIER  TITLE"));
+        assert!(!lines[0].contains("COLUMN_ID"));
+        let title_col = lines[0].find("TITLE").unwrap();
+        assert_eq!(lines[1].find("First").unwrap(), title_col);
+        assert_eq!(lines[2].find("Second").unwrap(), title_col);
+    }
+
+    #[test]
+    fn test_table_without_color_has_no_escape_codes() {
+        let out = render(&page(), &settings(OutputMode::Table));
+        assert!(!out.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_table_single_record_renders_key_value_lines() {
+        let out = render(
+            &json!({"id": "abc", "name": "Board"}),
+            &settings(OutputMode::Table),
+        );
+        assert_eq!(out, "id    abc\nname  Board");
+    }
+
+    #[test]
+    fn test_csv_quotes_fields_with_commas() {
+        let out = render(&page(), &settings(OutputMode::Csv));
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0], "identifier,title,priority,points,column_id");
+        assert_eq!(lines[2], "KAN-22,\"Second, with comma\",Low,,c1");
+    }
+
+    #[test]
+    fn test_jsonl_emits_one_object_per_item() {
+        let out = render(&page(), &settings(OutputMode::Jsonl));
+        let lines: Vec<Value> = out
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1]["identifier"], "KAN-22");
+    }
+
+    #[test]
+    fn test_template_substitutes_fields() {
+        let mut s = settings(OutputMode::Template);
+        s.template = Some("{{identifier}} {{ title }} [{{priority}}]{{missing}}".to_string());
+        let out = render(&page(), &s);
+        assert_eq!(out, "KAN-1 First [High]\nKAN-22 Second, with comma [Low]");
+    }
+
+    #[test]
+    fn test_template_supports_nested_paths() {
+        let row = json!({"card": {"title": "Nested"}});
+        assert_eq!(render_template("<{{card.title}}>", &row), "<Nested>");
+    }
+
+    #[test]
+    fn test_template_leaves_unterminated_placeholder_verbatim() {
+        assert_eq!(render_template("a {{b", &json!({})), "a {{b");
+    }
+}
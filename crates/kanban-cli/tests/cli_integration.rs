@@ -25,6 +25,33 @@ fn kanban_no_config(dir: &std::path::Path) -> Command {
     cmd
 }
 
+/// Run `cmd`, require success and return its parsed JSON output.
+fn run(cmd: &mut Command) -> Value {
+    parse_json_output(&String::from_utf8_lossy(
+        &cmd.assert().success().get_output().stdout,
+    ))
+}
+
+/// Run `kanban <file> args...` and return the response `data`.
+fn run_data(file: &std::path::Path, args: &[&str]) -> Value {
+    run(kanban().arg(file).args(args))["data"].clone()
+}
+
+/// Run git in `repo`, or return `None` when git is not installed.
+fn git(repo: &std::path::Path, args: &[&str]) -> Option<()> {
+    let status = std::process::Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(["-c", "commit.gpgsign=false"])
+        .args(args)
+        .output()
+        .ok()?
+        .status;
+    assert!(status.success(), "git {:?} failed", args);
+    Some(())
+}
+
 mod future_version_tests {
     use super::*;
 
@@ -1207,6 +1234,138 @@ mod card_tests {
             .starts_with("git checkout -b"));
     }
 
+    #[test]
+    fn test_card_git_status() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("test.json");
+        let (board_id, column_id) = setup_board_and_column(&file);
+        let repo = dir.path().join("repo");
+        std::fs::create_dir(&repo).unwrap();
+        if git(&repo, &["init", "--quiet", "--initial-branch=main"]).is_none() {
+            return;
+        }
+
+        let create_output = kanban()
+            .args([
+                file.to_str().unwrap(),
+                "card",
+                "create",
+                "--board",
+                &board_id,
+                "--column",
+                &column_id,
+                "--title",
+                "Login Form",
+            ])
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+        let card_id = extract_id(&parse_json_output(&String::from_utf8_lossy(&create_output)));
+        let git_status = || {
+            let output = kanban()
+                .args([
+                    file.to_str().unwrap(),
+                    "card",
+                    "git-status",
+                    &card_id,
+                    "--repo",
+                    repo.to_str().unwrap(),
+                ])
+                .assert()
+                .success()
+                .get_output()
+                .stdout
+                .clone();
+            parse_json_output(&String::from_utf8_lossy(&output))["data"].clone()
+        };
+
+        let status = git_status();
+        assert!(!status["branch_exists"].as_bool().unwrap());
+        assert_eq!(status["commits"].as_array().unwrap().len(), 0);
+        let identifier = status["identifier"].as_str().unwrap().to_string();
+        let branch = status["branch"].as_str().unwrap().to_string();
+        assert!(branch.starts_with(&format!("{}/", identifier)));
+
+        git(
+            &repo,
+            &["commit", "--quiet", "--allow-empty", "-m", "Initial"],
+        );
+        git(&repo, &["checkout", "--quiet", "-b", &branch]);
+        let mention = format!("{}: add login form", identifier);
+        git(
+            &repo,
+            &["commit", "--quiet", "--allow-empty", "-m", &mention],
+        );
+        let near_miss = format!("{}0: unrelated", identifier);
+        git(
+            &repo,
+            &["commit", "--quiet", "--allow-empty", "-m", &near_miss],
+        );
+        git(&repo, &["checkout", "--quiet", "main"]);
+        git(
+            &repo,
+            &["commit", "--quiet", "--allow-empty", "-m", "Hotfix"],
+        );
+
+        let status = git_status();
+        assert!(status["branch_exists"].as_bool().unwrap());
+        assert_eq!(status["base"], "main");
+        assert_eq!(status["ahead"], 2);
+        assert_eq!(status["behind"], 1);
+        let commits = status["commits"].as_array().unwrap();
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0]["summary"], mention.as_str());
+    }
+
+    #[test]
+    fn test_card_git_status_outside_repository_fails() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("test.json");
+        let (board_id, column_id) = setup_board_and_column(&file);
+        let card_id = extract_id(&parse_json_output(&String::from_utf8_lossy(
+            &kanban()
+                .args([
+                    file.to_str().unwrap(),
+                    "card",
+                    "create",
+                    "--board",
+                    &board_id,
+                    "--column",
+                    &column_id,
+                    "--title",
+                    "Task",
+                ])
+                .assert()
+                .success()
+                .get_output()
+                .stdout,
+        )));
+        if std::process::Command::new("git")
+            .arg("--version")
+            .output()
+            .is_err()
+        {
+            return;
+        }
+
+        kanban()
+            .args([
+                file.to_str().unwrap(),
+                "card",
+                "git-status",
+                &card_id,
+                "--repo",
+                dir.path().to_str().unwrap(),
+            ])
+            .env("GIT_CEILING_DIRECTORIES", dir.path())
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("\"success\":false"))
+            .stderr(predicate::str::contains("not inside a git repository"));
+    }
+
     #[test]
     fn test_card_archive_cards() {
         let dir = tempdir().unwrap();
@@ -1532,6 +1691,70 @@ mod card_tests {
         assert_eq!(json["data"]["archived"], card_uuid);
     }
 
+    #[test]
+    fn test_card_refs_lists_backlinks_and_links_them() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("test.json");
+        let (board_id, column_id) = setup_board_and_column_with_prefix(&file, "KAN");
+        for (title, description) in [
+            ("Target", "Nothing to see"),
+            ("Mentions it", "Blocked until KAN-1 lands"),
+            ("Also mentions it", "Follows KAN-1"),
+            ("Unrelated", "See KAN-99"),
+        ] {
+            kanban()
+                .args([
+                    file.to_str().unwrap(),
+                    "card",
+                    "create",
+                    "--board",
+                    &board_id,
+                    "--column",
+                    &column_id,
+                    "--title",
+                    title,
+                    "--description",
+                    description,
+                ])
+                .assert()
+                .success();
+        }
+
+        let output = kanban()
+            .args([file.to_str().unwrap(), "card", "refs", "KAN-1", "--link"])
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+        let json = parse_json_output(&String::from_utf8_lossy(&output));
+        let refs = json["data"].as_array().unwrap();
+        assert_eq!(refs.len(), 2);
+        assert_eq!(refs[0]["identifier"], "KAN-2");
+        assert_eq!(refs[0]["title"], "Mentions it");
+        assert_eq!(refs[1]["identifier"], "KAN-3");
+        let saved = std::fs::read_to_string(&file).unwrap();
+        assert_eq!(saved.matches("MentionedIn").count(), 2);
+
+        let output = kanban()
+            .args([
+                file.to_str().unwrap(),
+                "card",
+                "refs",
+                "KAN-2",
+                "--outgoing",
+            ])
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+        let json = parse_json_output(&String::from_utf8_lossy(&output));
+        let refs = json["data"].as_array().unwrap();
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0]["identifier"], "KAN-1");
+    }
+
     fn setup_two_boards_same_prefix(
         file: &std::path::Path,
     ) -> (String, String, String, String, String, String) {
@@ -2460,78 +2683,401 @@ mod export_import_tests {
         let json = parse_json_output(&String::from_utf8_lossy(&output));
         assert!(json["success"].as_bool().unwrap());
     }
-}
-
-mod error_tests {
-    use super::*;
 
     #[test]
-    fn test_missing_required_args() {
+    fn test_import_copy_twice_into_same_file() {
         let dir = tempdir().unwrap();
         let file = dir.path().join("test.json");
+        let import_file = dir.path().join("import.json");
+        let f = file.to_str().unwrap();
 
+        kanban().args([f]).assert().success();
+        let board_json = parse_json_output(&String::from_utf8_lossy(
+            &kanban()
+                .args([f, "board", "create", "--name", "Original"])
+                .assert()
+                .success()
+                .get_output()
+                .stdout,
+        ));
+        let board_id = extract_id(&board_json);
         kanban()
-            .args([file.to_str().unwrap(), "board", "create"])
+            .args([
+                f, "column", "create", "--board", &board_id, "--name", "Todo",
+            ])
             .assert()
-            .failure()
-            .stderr(predicate::str::contains("--name"));
-    }
+            .success();
 
-    #[test]
-    fn test_invalid_uuid() {
-        let dir = tempdir().unwrap();
-        let file = dir.path().join("test.json");
+        let export_output = kanban()
+            .args([f, "export", "--board", &board_id])
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+        fs::write(&import_file, &export_output).unwrap();
 
         kanban()
-            .args([file.to_str().unwrap(), "board", "get", "not-a-uuid"])
+            .args([f, "import", "--file", import_file.to_str().unwrap()])
             .assert()
             .failure();
+        for _ in 0..2 {
+            let output = kanban()
+                .args([
+                    f,
+                    "import",
+                    "--file",
+                    import_file.to_str().unwrap(),
+                    "--copy",
+                ])
+                .assert()
+                .success()
+                .get_output()
+                .stdout
+                .clone();
+            let json = parse_json_output(&String::from_utf8_lossy(&output));
+            assert_eq!(json["data"]["name"], "Original");
+            assert_ne!(extract_id(&json), board_id);
+        }
+
+        let list = parse_json_output(&String::from_utf8_lossy(
+            &kanban()
+                .args([f, "board", "list"])
+                .assert()
+                .success()
+                .get_output()
+                .stdout,
+        ));
+        assert_eq!(list["data"]["items"].as_array().unwrap().len(), 3);
     }
 
     #[test]
-    fn test_nonexistent_board() {
+    fn test_board_clone() {
         let dir = tempdir().unwrap();
         let file = dir.path().join("test.json");
+        let f = file.to_str().unwrap();
 
-        kanban().args([file.to_str().unwrap()]).assert().success();
+        kanban().args([f]).assert().success();
+        let board_json = parse_json_output(&String::from_utf8_lossy(
+            &kanban()
+                .args([f, "board", "create", "--name", "Template"])
+                .assert()
+                .success()
+                .get_output()
+                .stdout,
+        ));
+        let board_id = extract_id(&board_json);
+        let column_json = parse_json_output(&String::from_utf8_lossy(
+            &kanban()
+                .args([
+                    f, "column", "create", "--board", &board_id, "--name", "Todo",
+                ])
+                .assert()
+                .success()
+                .get_output()
+                .stdout,
+        ));
+        let column_id = extract_id(&column_json);
         kanban()
             .args([
-                file.to_str().unwrap(),
-                "board",
-                "get",
-                "00000000-0000-0000-0000-000000000000",
+                f,
+                "card",
+                "create",
+                "--board",
+                &board_id,
+                "--column",
+                &column_id,
+                "--title",
+                "Set up CI",
             ])
             .assert()
-            .failure()
-            .stderr(predicate::str::contains("\"success\":false"))
-            .stderr(predicate::str::contains("not found"));
+            .success();
+
+        let clone_json = parse_json_output(&String::from_utf8_lossy(
+            &kanban()
+                .args([
+                    f,
+                    "board",
+                    "clone",
+                    "Template",
+                    "--name",
+                    "Project X",
+                    "--include-cards",
+                ])
+                .assert()
+                .success()
+                .get_output()
+                .stdout,
+        ));
+        assert_eq!(clone_json["data"]["name"], "Project X");
+        let clone_id = extract_id(&clone_json);
+        assert_ne!(clone_id, board_id);
+
+        let cards = parse_json_output(&String::from_utf8_lossy(
+            &kanban()
+                .args([f, "card", "list", "--board", &clone_id])
+                .assert()
+                .success()
+                .get_output()
+                .stdout,
+        ));
+        let items = cards["data"]["items"].as_array().unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0]["title"], "Set up CI");
+        assert_eq!(items[0]["card_number"], 1);
     }
 
     #[test]
-    fn test_column_list_requires_board_id() {
+    fn test_import_from_taskwarrior_dry_run_then_apply() {
         let dir = tempdir().unwrap();
         let file = dir.path().join("test.json");
+        let export = dir.path().join("tasks.json");
+        let f = file.to_str().unwrap();
+        fs::write(
+            &export,
+            r#"[
+                {"uuid": "a", "description": "Write docs", "status": "pending",
+                 "priority": "H", "project": "site", "depends": ["b"]},
+                {"uuid": "b", "description": "Build", "status": "completed",
+                 "end": "20241003T000000Z", "project": "site"}
+            ]"#,
+        )
+        .unwrap();
+        kanban().args([f]).assert().success();
+
+        let import = |extra: &[&str]| {
+            let mut args = vec![f, "import", "--from", "taskwarrior"];
+            args.extend_from_slice(extra);
+            args.push(export.to_str().unwrap());
+            parse_json_output(&String::from_utf8_lossy(
+                &kanban().args(&args).assert().success().get_output().stdout,
+            ))
+        };
 
-        kanban()
-            .args([file.to_str().unwrap(), "column", "list"])
-            .assert()
-            .failure()
-            .stderr(predicate::str::contains("--board"));
+        let report = import(&["--dry-run"]);
+        assert_eq!(report["data"]["dry_run"], true);
+        assert_eq!(report["data"]["board"], "site");
+        assert_eq!(report["data"]["cards"], 2);
+        assert_eq!(report["data"]["blocking_edges"], 1);
+        assert!(report["data"]["board_id"].is_null());
+        let list = parse_json_output(&String::from_utf8_lossy(
+            &kanban()
+                .args([f, "board", "list"])
+                .assert()
+                .success()
+                .get_output()
+                .stdout,
+        ));
+        assert!(list["data"]["items"].as_array().unwrap().is_empty());
+
+        let report = import(&["--name", "Website"]);
+        assert_eq!(report["data"]["board"], "Website");
+        let board_id = report["data"]["board_id"].as_str().unwrap().to_string();
+        let cards = parse_json_output(&String::from_utf8_lossy(
+            &kanban()
+                .args([f, "card", "list", "--board", &board_id])
+                .assert()
+                .success()
+                .get_output()
+                .stdout,
+        ));
+        let items = cards["data"]["items"].as_array().unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0]["title"], "Write docs");
+        assert_eq!(items[0]["priority"], "High");
     }
 
     #[test]
-    fn test_card_get_nonexistent_numeric_identifier() {
+    fn test_import_from_rejects_csv_options() {
         let dir = tempdir().unwrap();
         let file = dir.path().join("test.json");
-        kanban().args([file.to_str().unwrap()]).assert().success();
-
         kanban()
-            .args([file.to_str().unwrap(), "card", "get", "555"])
+            .args([
+                file.to_str().unwrap(),
+                "import",
+                "--from",
+                "trello",
+                "--copy",
+                "board.json",
+            ])
             .assert()
-            .failure()
-            .stderr(predicate::str::contains("\"success\":false"))
-            .stderr(predicate::str::contains("Card not found"))
-            .stderr(predicate::str::contains("555"));
+            .failure();
+    }
+}
+
+mod template_tests {
+    use super::*;
+
+    #[test]
+    fn test_board_create_from_builtin_template() {
+        let dir = tempdir().unwrap();
+        let f = dir.path().join("test.json");
+        let f = f.to_str().unwrap();
+        kanban_no_config(dir.path()).args([f]).assert().success();
+
+        let board = run(kanban_no_config(dir.path()).args([
+            f,
+            "board",
+            "create",
+            "--name",
+            "Sprinty",
+            "--template",
+            "scrum",
+        ]));
+        assert_eq!(board["data"]["sprint_prefix"], "sprint");
+        let board_id = extract_id(&board);
+
+        let columns =
+            run(kanban_no_config(dir.path()).args([f, "column", "list", "--board", &board_id]));
+        let names: Vec<&str> = columns["data"]["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| c["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["Backlog", "To Do", "In Progress", "Review", "Done"]);
+
+        kanban_no_config(dir.path())
+            .args([f, "board", "create", "--name", "X", "--template", "nope"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Unknown template 'nope'"));
+    }
+
+    #[test]
+    fn test_save_template_and_create_from_it() {
+        let dir = tempdir().unwrap();
+        let f = dir.path().join("test.json");
+        let f = f.to_str().unwrap();
+        kanban_no_config(dir.path()).args([f]).assert().success();
+
+        let board = run(kanban_no_config(dir.path()).args([
+            f,
+            "board",
+            "create",
+            "--name",
+            "Client A",
+            "--template",
+            "basic",
+        ]));
+        let board_id = extract_id(&board);
+        let columns =
+            run(kanban_no_config(dir.path()).args([f, "column", "list", "--board", &board_id]));
+        let todo = columns["data"]["items"][0]["id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        for title in ["Kick-off", "Send contract"] {
+            run(kanban_no_config(dir.path()).args([
+                f, "card", "create", "--board", &board_id, "--column", &todo, "--title", title,
+            ]));
+        }
+
+        let saved = run(kanban_no_config(dir.path()).args([
+            f,
+            "board",
+            "save-template",
+            "Client A",
+            "--name",
+            "client",
+            "--include-cards",
+        ]));
+        assert_eq!(saved["data"]["cards"], 2);
+        assert!(dir
+            .path()
+            .join(".config/kanban/templates/client.toml")
+            .exists());
+
+        let listed = run(kanban_no_config(dir.path()).args([f, "board", "templates"]));
+        assert_eq!(listed["data"].as_array().unwrap().last().unwrap(), "client");
+
+        let board = run(kanban_no_config(dir.path()).args([
+            f,
+            "board",
+            "create",
+            "--name",
+            "Client B",
+            "--template",
+            "client",
+        ]));
+        let new_id = extract_id(&board);
+        let cards = run(kanban_no_config(dir.path()).args([f, "card", "list", "--board", &new_id]));
+        let items = cards["data"]["items"].as_array().unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0]["card_number"], 1);
+    }
+}
+
+mod error_tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_required_args() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("test.json");
+
+        kanban()
+            .args([file.to_str().unwrap(), "board", "create"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("--name"));
+    }
+
+    #[test]
+    fn test_invalid_uuid() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("test.json");
+
+        kanban()
+            .args([file.to_str().unwrap(), "board", "get", "not-a-uuid"])
+            .assert()
+            .failure();
+    }
+
+    #[test]
+    fn test_nonexistent_board() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("test.json");
+
+        kanban().args([file.to_str().unwrap()]).assert().success();
+        kanban()
+            .args([
+                file.to_str().unwrap(),
+                "board",
+                "get",
+                "00000000-0000-0000-0000-000000000000",
+            ])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("\"success\":false"))
+            .stderr(predicate::str::contains("not found"));
+    }
+
+    #[test]
+    fn test_column_list_requires_board_id() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("test.json");
+
+        kanban()
+            .args([file.to_str().unwrap(), "column", "list"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("--board"));
+    }
+
+    #[test]
+    fn test_card_get_nonexistent_numeric_identifier() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("test.json");
+        kanban().args([file.to_str().unwrap()]).assert().success();
+
+        kanban()
+            .args([file.to_str().unwrap(), "card", "get", "555"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("\"success\":false"))
+            .stderr(predicate::str::contains("Card not found"))
+            .stderr(predicate::str::contains("555"));
     }
 
     #[test]
@@ -4324,3 +4870,844 @@ mod relation_tests {
         assert_eq!(json["data"].as_array().unwrap().len(), 0);
     }
 }
+
+mod output_mode_tests {
+    use super::*;
+
+    fn setup_card(file: &std::path::Path) {
+        kanban().args([file.to_str().unwrap()]).assert().success();
+        kanban()
+            .args([
+                file.to_str().unwrap(),
+                "board",
+                "create",
+                "--name",
+                "Ops",
+                "--card-prefix",
+                "OPS",
+            ])
+            .assert()
+            .success();
+        kanban()
+            .args([
+                file.to_str().unwrap(),
+                "column",
+                "create",
+                "--board",
+                "Ops",
+                "--name",
+                "Todo",
+            ])
+            .assert()
+            .success();
+        kanban()
+            .args([
+                file.to_str().unwrap(),
+                "card",
+                "create",
+                "--board",
+                "Ops",
+                "--column",
+                "Todo",
+                "--title",
+                "Rotate keys, again",
+                "--priority",
+                "high",
+            ])
+            .assert()
+            .success();
+    }
+
+    fn stdout_of(args: &[&str]) -> String {
+        let output = kanban()
+            .env_remove("KANBAN_OUTPUT")
+            .args(args)
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+        String::from_utf8_lossy(&output).into_owned()
+    }
+
+    #[test]
+    fn test_card_list_defaults_to_json_when_piped() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("test.json");
+        setup_card(&file);
+
+        let json = parse_json_output(&stdout_of(&[file.to_str().unwrap(), "card", "list"]));
+        assert!(json["success"].as_bool().unwrap());
+        assert_eq!(json["data"]["items"][0]["identifier"], "OPS-1");
+    }
+
+    #[test]
+    fn test_card_list_template_output() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("test.json");
+        setup_card(&file);
+
+        let out = stdout_of(&[
+            file.to_str().unwrap(),
+            "card",
+            "list",
+            "--output",
+            "template",
+            "--format",
+            "{{identifier}} {{title}} [{{priority}}]",
+        ]);
+        assert_eq!(out.trim_end(), "OPS-1 Rotate keys, again [High]");
+    }
+
+    #[test]
+    fn test_card_list_table_output_has_header_and_row() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("test.json");
+        setup_card(&file);
+
+        let out = stdout_of(&[file.to_str().unwrap(), "card", "list", "--output", "table"]);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("IDENTIFIER"));
+        assert!(lines[1].starts_with("OPS-1"));
+        assert!(
+            !out.contains('\x1b'),
+            "piped table output must not be colorized"
+        );
+    }
+
+    #[test]
+    fn test_card_list_csv_output_quotes_commas() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("test.json");
+        setup_card(&file);
+
+        let out = stdout_of(&[file.to_str().unwrap(), "card", "list", "--output", "csv"]);
+        let lines: Vec<&str> = out.lines().collect();
+        assert!(lines[0].starts_with("identifier,"));
+        assert!(lines[1].contains("\"Rotate keys, again\""));
+    }
+
+    #[test]
+    fn test_board_list_jsonl_output_emits_one_line_per_board() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("test.json");
+        setup_card(&file);
+
+        let out = stdout_of(&[file.to_str().unwrap(), "board", "list", "--output", "jsonl"]);
+        let lines: Vec<Value> = out
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0]["name"], "Ops");
+    }
+
+    #[test]
+    fn test_output_template_without_format_fails() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("test.json");
+        setup_card(&file);
+
+        kanban()
+            .args([
+                file.to_str().unwrap(),
+                "card",
+                "list",
+                "--output",
+                "template",
+            ])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("--format"));
+    }
+}
+
+mod watch_tests {
+    use super::*;
+    use std::io::Read;
+    use std::process::Stdio;
+    use std::time::{Duration, Instant};
+
+    fn setup(file: &std::path::Path) {
+        kanban().args([file.to_str().unwrap()]).assert().success();
+        kanban()
+            .args([file.to_str().unwrap(), "board", "create", "--name", "Ops"])
+            .assert()
+            .success();
+        for name in ["Todo", "Done"] {
+            kanban()
+                .args([
+                    file.to_str().unwrap(),
+                    "column",
+                    "create",
+                    "--board",
+                    "Ops",
+                    "--name",
+                    name,
+                ])
+                .assert()
+                .success();
+        }
+    }
+
+    #[test]
+    fn test_watch_summary_once_prints_counts_per_column() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("test.json");
+        setup(&file);
+
+        let output = kanban()
+            .args([
+                file.to_str().unwrap(),
+                "watch",
+                "--board",
+                "Ops",
+                "--summary",
+                "--once",
+            ])
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+        assert_eq!(
+            String::from_utf8_lossy(&output).trim_end(),
+            "Todo 0 | Done 0"
+        );
+    }
+
+    #[test]
+    fn test_watch_once_emits_card_created_event() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("test.json");
+        setup(&file);
+
+        let mut child = std::process::Command::new(assert_cmd::cargo::cargo_bin!("kanban"))
+            .args([
+                file.to_str().unwrap(),
+                "watch",
+                "--once",
+                "--poll-interval",
+                "1",
+            ])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap();
+        std::thread::sleep(Duration::from_millis(500));
+
+        kanban()
+            .args([
+                file.to_str().unwrap(),
+                "card",
+                "create",
+                "--board",
+                "Ops",
+                "--column",
+                "Todo",
+                "--title",
+                "Watched",
+            ])
+            .assert()
+            .success();
+
+        let deadline = Instant::now() + Duration::from_secs(20);
+        while child.try_wait().unwrap().is_none() {
+            assert!(Instant::now() < deadline, "watch --once did not exit");
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        let mut stdout = String::new();
+        child
+            .stdout
+            .take()
+            .unwrap()
+            .read_to_string(&mut stdout)
+            .unwrap();
+        let events: Vec<Value> = stdout
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        let created = events
+            .iter()
+            .find(|e| e["entity"] == "card")
+            .expect("card event");
+        assert_eq!(created["change"], "created");
+        assert_eq!(created["identifier"], "task-1");
+        assert_eq!(created["name"], "Watched");
+    }
+}
+
+mod text_export_tests {
+    use super::*;
+
+    /// A file with an `Ops` board (TODO/Doing/Complete) holding OPS-1.
+    fn setup(dir: &std::path::Path) -> String {
+        let f = dir.join("test.json").to_str().unwrap().to_string();
+        kanban_no_config(dir).args([&f]).assert().success();
+        let board = run(kanban_no_config(dir).args([
+            &f,
+            "board",
+            "create",
+            "--name",
+            "Ops",
+            "--card-prefix",
+            "OPS",
+            "--template",
+            "basic",
+        ]));
+        let board_id = extract_id(&board);
+        let columns = run(kanban_no_config(dir).args([&f, "column", "list", "--board", &board_id]));
+        let todo = columns["data"]["items"][0]["id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        run(kanban_no_config(dir).args([
+            &f,
+            "card",
+            "create",
+            "--board",
+            &board_id,
+            "--column",
+            &todo,
+            "--title",
+            "Rotate keys",
+            "--points",
+            "3",
+        ]));
+        f
+    }
+
+    #[test]
+    fn test_export_markdown() {
+        let dir = tempdir().unwrap();
+        let f = setup(dir.path());
+        kanban_no_config(dir.path())
+            .args([&f, "export", "--format", "md"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("# Ops\n"))
+            .stdout(predicate::str::contains("## TODO\n"))
+            .stdout(predicate::str::contains(
+                "- [ ] **OPS-1** Rotate keys · medium · 3 pts",
+            ));
+    }
+
+    #[test]
+    fn test_csv_export_edit_and_import() {
+        let dir = tempdir().unwrap();
+        let f = setup(dir.path());
+        let output = kanban_no_config(dir.path())
+            .args([&f, "export", "--format", "csv"])
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+        let csv = String::from_utf8(output).unwrap();
+        assert!(csv.starts_with("board,identifier,title,column,"), "{}", csv);
+        assert!(csv.contains("Ops,OPS-1,Rotate keys,TODO,todo,medium,3,"));
+
+        let edited = csv.replace(
+            "Rotate keys,TODO,todo,medium",
+            "Rotate keys,Doing,todo,high",
+        );
+        let csv_path = dir.path().join("cards.csv");
+        std::fs::write(&csv_path, edited).unwrap();
+        let summary = run(kanban_no_config(dir.path()).args([
+            &f,
+            "import",
+            "--file",
+            csv_path.to_str().unwrap(),
+        ]));
+        assert_eq!(summary["data"]["updated"], 1);
+        assert_eq!(summary["data"]["created"], 0);
+
+        let card = run(kanban_no_config(dir.path()).args([&f, "card", "get", "OPS-1"]));
+        assert_eq!(card["data"]["priority"], "High");
+
+        let sheet = dir.path().join("sheet.csv");
+        std::fs::write(&sheet, "Task,Owner\nWrite runbook,sam\n").unwrap();
+        let summary = run(kanban_no_config(dir.path()).args([
+            &f,
+            "import",
+            "--file",
+            sheet.to_str().unwrap(),
+            "--board",
+            "Ops",
+            "--map",
+            "Task=title",
+        ]));
+        assert_eq!(summary["data"]["created"], 1);
+
+        kanban_no_config(dir.path())
+            .args([
+                &f,
+                "import",
+                "--file",
+                sheet.to_str().unwrap(),
+                "--map",
+                "Task",
+            ])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("expected HEADER=FIELD"));
+    }
+
+    #[test]
+    fn test_ics_export_and_calendar_feed() {
+        let dir = tempdir().unwrap();
+        let f = setup(dir.path());
+        run(kanban_no_config(dir.path()).args([
+            &f,
+            "card",
+            "update",
+            "OPS-1",
+            "--due-date",
+            "2024-05-01",
+        ]));
+        kanban_no_config(dir.path())
+            .args([&f, "export", "--format", "ics", "--board", "Ops"])
+            .assert()
+            .success()
+            .stdout(predicate::str::starts_with("BEGIN:VCALENDAR\r\n"))
+            .stdout(predicate::str::contains("SUMMARY:OPS-1 Rotate keys\r\n"))
+            .stdout(predicate::str::contains("DUE:20240501T000000Z\r\n"));
+
+        let feed = dir.path().join("feeds/kanban.ics");
+        let config_dir = dir.path().join(".config/kanban");
+        fs::create_dir_all(&config_dir).unwrap();
+        fs::write(
+            config_dir.join("config.toml"),
+            format!("calendar_feed = {:?}\n", feed.to_str().unwrap()),
+        )
+        .unwrap();
+        assert!(!feed.exists());
+        run(kanban_no_config(dir.path()).args([
+            &f,
+            "card",
+            "update",
+            "OPS-1",
+            "--title",
+            "Rotate all keys",
+        ]));
+        let calendar = fs::read_to_string(&feed).unwrap();
+        assert!(
+            calendar.contains("SUMMARY:OPS-1 Rotate all keys"),
+            "{}",
+            calendar
+        );
+        assert_eq!(calendar.matches("BEGIN:VTODO").count(), 1);
+    }
+}
+
+mod git_hook_tests {
+    use super::*;
+
+    fn column_name(file: &std::path::Path, board_id: &str, card: &str) -> String {
+        let column_id = run_data(file, &["card", "get", card])["column_id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        run_data(file, &["column", "list", "--board", board_id])["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|c| c["id"] == column_id.as_str())
+            .unwrap()["name"]
+            .as_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_hooks_move_cards_from_commits_and_merges() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("board.json");
+        let repo = dir.path().join("repo");
+        std::fs::create_dir(&repo).unwrap();
+        if git(&repo, &["init", "--quiet", "--initial-branch=main"]).is_none() {
+            return;
+        }
+
+        kanban().arg(&file).assert().success();
+        let board = run_data(
+            &file,
+            &["board", "create", "--name", "App", "--card-prefix", "KAN"],
+        );
+        let board_id = board["id"].as_str().unwrap().to_string();
+        let mut todo = String::new();
+        for name in ["Todo", "In Progress", "Done"] {
+            let column = run_data(
+                &file,
+                &["column", "create", "--board", &board_id, "--name", name],
+            );
+            if name == "Todo" {
+                todo = column["id"].as_str().unwrap().to_string();
+            }
+        }
+        for title in ["Login", "Signup", "Docs"] {
+            run_data(
+                &file,
+                &[
+                    "card", "create", "--board", &board_id, "--column", &todo, "--title", title,
+                ],
+            );
+        }
+
+        let installed = run_data(
+            &file,
+            &["git", "install-hooks", "--repo", repo.to_str().unwrap()],
+        );
+        assert_eq!(installed["hooks"].as_array().unwrap().len(), 3);
+        kanban()
+            .arg(&file)
+            .args(["git", "install-hooks", "--repo", repo.to_str().unwrap()])
+            .assert()
+            .success();
+
+        git(
+            &repo,
+            &["commit", "--quiet", "--allow-empty", "-m", "WIP KAN-1"],
+        );
+        assert_eq!(column_name(&file, &board_id, "KAN-1"), "In Progress");
+        assert_eq!(
+            run_data(&file, &["card", "get", "KAN-1"])["status"],
+            "InProgress"
+        );
+
+        let message = "Finish login\n\nCloses KAN-1, refs KAN-3 and KAN-99";
+        git(
+            &repo,
+            &["commit", "--quiet", "--allow-empty", "-m", message],
+        );
+        assert_eq!(column_name(&file, &board_id, "KAN-1"), "Done");
+        let activity = run_data(&file, &["card", "get", "KAN-1"])["activity"].clone();
+        let messages: Vec<&str> = activity
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| {
+                assert_eq!(entry["source"], "git");
+                entry["message"].as_str().unwrap()
+            })
+            .collect();
+        assert_eq!(messages.len(), 2);
+        assert!(messages[0].starts_with("Started by commit "));
+        assert!(messages[1].ends_with(": Finish login"));
+        let referenced = run_data(&file, &["card", "get", "KAN-3"]);
+        assert!(referenced["description"].is_null());
+        assert!(referenced["activity"][0]["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("Referenced by commit "));
+
+        git(&repo, &["checkout", "--quiet", "-b", "KAN-2/signup"]);
+        git(
+            &repo,
+            &["commit", "--quiet", "--allow-empty", "-m", "Signup form"],
+        );
+        git(&repo, &["checkout", "--quiet", "main"]);
+        assert_eq!(column_name(&file, &board_id, "KAN-2"), "Todo");
+        git(
+            &repo,
+            &[
+                "merge",
+                "--quiet",
+                "--no-ff",
+                "-m",
+                "Merge signup",
+                "KAN-2/signup",
+            ],
+        );
+        assert_eq!(column_name(&file, &board_id, "KAN-2"), "Done");
+        assert_eq!(
+            run_data(&file, &["card", "get", "KAN-2"])["activity"][0]["message"],
+            "Completed by merging branch KAN-2/signup"
+        );
+        assert_eq!(column_name(&file, &board_id, "KAN-3"), "Todo");
+        assert_eq!(
+            run_data(&file, &["card", "get", "KAN-3"])["activity"]
+                .as_array()
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_install_hooks_keeps_foreign_hooks_without_force() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("board.json");
+        let repo = dir.path().join("repo");
+        std::fs::create_dir(&repo).unwrap();
+        if git(&repo, &["init", "--quiet"]).is_none() {
+            return;
+        }
+        kanban().arg(&file).assert().success();
+        let hook = repo.join(".git/hooks/post-commit");
+        std::fs::write(&hook, "#!/bin/sh\necho mine\n").unwrap();
+
+        kanban()
+            .arg(&file)
+            .args(["git", "install-hooks", "--repo", repo.to_str().unwrap()])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("--force"));
+        assert_eq!(
+            std::fs::read_to_string(&hook).unwrap(),
+            "#!/bin/sh\necho mine\n"
+        );
+
+        run_data(
+            &file,
+            &[
+                "git",
+                "install-hooks",
+                "--repo",
+                repo.to_str().unwrap(),
+                "--force",
+            ],
+        );
+        assert!(std::fs::read_to_string(&hook)
+            .unwrap()
+            .contains("git post-commit"));
+    }
+}
+
+mod checklist_tests {
+    use super::*;
+
+    fn texts(listing: &Value) -> Vec<String> {
+        listing["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| item["text"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_checklist_roundtrip_in_both_backends() {
+        for name in ["board.json", "board.db"] {
+            let dir = tempdir().unwrap();
+            let file = dir.path().join(name);
+            kanban().arg(&file).assert().success();
+            let board = run_data(&file, &["board", "create", "--name", "App"]);
+            let board_id = board["id"].as_str().unwrap().to_string();
+            let column = run_data(
+                &file,
+                &["column", "create", "--board", &board_id, "--name", "Todo"],
+            );
+            let column_id = column["id"].as_str().unwrap().to_string();
+            let card = run_data(
+                &file,
+                &[
+                    "card",
+                    "create",
+                    "--board",
+                    &board_id,
+                    "--column",
+                    &column_id,
+                    "--title",
+                    "Release",
+                    "--description",
+                    "Steps:\n- [ ] update changelog\n- [x] bump version",
+                ],
+            );
+            let card_id = card["id"].as_str().unwrap().to_string();
+
+            let imported = run_data(&file, &["card", "checklist", "import", &card_id]);
+            assert_eq!(imported["imported"], 2);
+            let card = run_data(&file, &["card", "get", &card_id]);
+            assert_eq!(card["description"], "Steps:");
+
+            run_data(
+                &file,
+                &["card", "checklist", "add", &card_id, "tag release"],
+            );
+            let toggled = run_data(&file, &["card", "checklist", "toggle", &card_id, "1"]);
+            assert_eq!(toggled["done"], true);
+            let moved = run_data(
+                &file,
+                &[
+                    "card",
+                    "checklist",
+                    "move",
+                    &card_id,
+                    "3",
+                    "--position",
+                    "1",
+                ],
+            );
+            assert_eq!(
+                texts(&moved),
+                ["tag release", "update changelog", "bump version"]
+            );
+
+            let listing = run_data(&file, &["card", "checklist", "list", &card_id]);
+            assert_eq!(listing["progress"]["done"], 2);
+            assert_eq!(listing["progress"]["total"], 3);
+            assert_eq!(listing["items"][1]["position"], 2);
+
+            let child = run_data(&file, &["card", "checklist", "convert", &card_id, "1"]);
+            assert_eq!(child["title"], "tag release");
+            let children = run_data(&file, &["relation", "children", &card_id]);
+            assert_eq!(children.as_array().unwrap().len(), 1, "{name}");
+
+            let summaries = run_data(&file, &["card", "list", "--board", &board_id]);
+            let summary = summaries["items"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|c| c["id"] == card_id.as_str())
+                .unwrap()
+                .clone();
+            assert_eq!(summary["checklist"]["done"], 2);
+            assert_eq!(summary["checklist"]["total"], 2);
+
+            kanban()
+                .arg(&file)
+                .args(["card", "checklist", "toggle", &card_id, "9"])
+                .assert()
+                .failure();
+        }
+    }
+}
+
+mod attachment_tests {
+    use super::*;
+
+    fn create_card(file: &std::path::Path) -> String {
+        kanban().arg(file).assert().success();
+        let board = run_data(file, &["board", "create", "--name", "App"]);
+        let board_id = board["id"].as_str().unwrap().to_string();
+        let column = run_data(
+            file,
+            &["column", "create", "--board", &board_id, "--name", "Todo"],
+        );
+        let column_id = column["id"].as_str().unwrap().to_string();
+        let card = run_data(
+            file,
+            &[
+                "card",
+                "create",
+                "--board",
+                &board_id,
+                "--column",
+                &column_id,
+                "--title",
+                "Crash on start",
+            ],
+        );
+        card["id"].as_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_attachment_roundtrip_in_both_backends() {
+        for name in ["board.json", "board.db"] {
+            let dir = tempdir().unwrap();
+            let file = dir.path().join(name);
+            let card_id = create_card(&file);
+            let log = dir.path().join("crash.log");
+            fs::write(&log, "panic at main.rs:3\n").unwrap();
+
+            let added = run_data(
+                &file,
+                &["card", "attachment", "add", &card_id, log.to_str().unwrap()],
+            );
+            assert_eq!(added["name"], "crash.log");
+            assert_eq!(added["size"], 19);
+            assert_eq!(added["mime_type"], "text/plain");
+            kanban()
+                .arg(&file)
+                .args(["card", "attachment", "add", &card_id])
+                .arg(&log)
+                .assert()
+                .failure();
+
+            let listing = run_data(&file, &["card", "attachment", "list", &card_id]);
+            assert_eq!(listing["items"][0]["position"], 1);
+            assert_eq!(listing["items"][0]["display_size"], "19 B");
+
+            let out_dir = dir.path().join("out");
+            fs::create_dir(&out_dir).unwrap();
+            let extracted = run_data(
+                &file,
+                &[
+                    "card",
+                    "attachment",
+                    "extract",
+                    &card_id,
+                    "crash.log",
+                    out_dir.to_str().unwrap(),
+                ],
+            );
+            assert_eq!(
+                extracted["path"],
+                out_dir.join("crash.log").to_str().unwrap()
+            );
+            assert_eq!(
+                fs::read_to_string(out_dir.join("crash.log")).unwrap(),
+                "panic at main.rs:3\n"
+            );
+
+            let card = run_data(&file, &["card", "attachment", "remove", &card_id, "1"]);
+            assert!(card["items"].as_array().unwrap().is_empty(), "{name}");
+            let dry_run = run_data(&file, &["card", "attachment", "gc", "--dry-run"]);
+            assert_eq!(dry_run["removed"].as_array().unwrap().len(), 1);
+            assert_eq!(dry_run["freed_bytes"], 19);
+            let collected = run_data(&file, &["card", "attachment", "gc"]);
+            assert_eq!(collected["removed"], dry_run["removed"]);
+            let again = run_data(&file, &["card", "attachment", "gc"]);
+            assert!(again["removed"].as_array().unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_bundle_export_carries_attachments_across_backends() {
+        let dir = tempdir().unwrap();
+        let source = dir.path().join("source.json");
+        let card_id = create_card(&source);
+        let shot = dir.path().join("shot.png");
+        fs::write(&shot, [0x89, b'P', b'N', b'G']).unwrap();
+        run_data(
+            &source,
+            &[
+                "card",
+                "attachment",
+                "add",
+                &card_id,
+                shot.to_str().unwrap(),
+            ],
+        );
+
+        let bundle = kanban()
+            .arg(&source)
+            .args(["export", "--format", "bundle"])
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+        let bundle_path = dir.path().join("export.bundle");
+        fs::write(&bundle_path, bundle).unwrap();
+
+        let target = dir.path().join("target.db");
+        kanban().arg(&target).assert().success();
+        run_data(&target, &["import", bundle_path.to_str().unwrap()]);
+        let extracted = dir.path().join("copy.png");
+        run_data(
+            &target,
+            &[
+                "card",
+                "attachment",
+                "extract",
+                &card_id,
+                "1",
+                extracted.to_str().unwrap(),
+            ],
+        );
+        assert_eq!(fs::read(extracted).unwrap(), [0x89, b'P', b'N', b'G']);
+    }
+}
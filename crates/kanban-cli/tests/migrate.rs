@@ -339,3 +339,36 @@ async fn test_migrate_rejects_unknown_backend() {
         "stderr: {stderr}"
     );
 }
+
+// multi_thread: sqlx connection pool spawns background tasks that deadlock on single-threaded runtime
+#[tokio::test(flavor = "multi_thread")]
+async fn test_migrate_copies_attachment_content() {
+    use assert_cmd::cargo_bin_cmd;
+
+    let dir = TempDir::new().unwrap();
+    let src_path = dir.path().join("source.json");
+    let dst_path = dir.path().join("dest.db");
+    let mut ctx = create_populated_json_context(&src_path).await;
+    let card = ctx.list_cards(Default::default()).unwrap().remove(0);
+    let attachment = ctx
+        .add_attachment(card.id, "trace.txt".into(), b"stack trace")
+        .unwrap();
+    ctx.save().await.unwrap();
+
+    cargo_bin_cmd!("kanban")
+        .args([
+            "migrate",
+            src_path.to_str().unwrap(),
+            "sqlite",
+            "--output",
+            dst_path.to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    let loaded = kanban_service::open_context(dst_path.to_str().unwrap(), AppConfig::default())
+        .await
+        .unwrap();
+    let (_, content) = loaded.read_attachment(card.id, attachment.id).unwrap();
+    assert_eq!(content, b"stack trace");
+}
@@ -1,9 +1,13 @@
 use crate::CoreResult;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 pub const DEFAULT_STORAGE_BACKEND: &str = "json";
 pub const DEFAULT_JSON_FILENAME: &str = "boards.json";
 pub const DEFAULT_SQLITE_FILENAME: &str = "boards.sqlite";
+pub const DEFAULT_PREVIEW_SIZE: u16 = 50;
+/// Bounds of `preview_size`, so neither pane can be squeezed out.
+pub const PREVIEW_SIZE_RANGE: std::ops::RangeInclusive<u16> = 20..=80;
 
 pub fn validate_branch_prefix(prefix: &str) -> bool {
     if prefix.is_empty() {
@@ -45,6 +49,52 @@ pub struct AppConfig {
     pub storage_backend: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub storage_location: Option<String>,
+    /// TUI color scheme: a built-in theme name or a theme file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+    /// Let the TUI take mouse input: clicks, the scroll wheel and dragging
+    /// cards between kanban columns.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mouse: Option<bool>,
+    /// How the TUI edits titles and descriptions: "inline" for the built-in
+    /// editor, "vi" for the same with vi keys, or "external" for $EDITOR.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_editor: Option<String>,
+    /// Where the TUI shows a live preview of the selected card next to the
+    /// card list: "right", "bottom" or "off".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preview: Option<String>,
+    /// Share of the main area, in percent, given to the card preview.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preview_size: Option<u16>,
+    /// iCalendar file rewritten with due dates and sprints after each save.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calendar_feed: Option<String>,
+    /// Make the MCP server refuse destructive tools unless the call carries
+    /// the confirmation token from a dry run of the same call.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mcp_confirm_destructive: Option<bool>,
+    /// TUI key overrides: context name -> action name -> key sequence(s).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub keymap: BTreeMap<String, BTreeMap<String, KeySequences>>,
+}
+
+/// Key sequences bound to one keymap action: either a single sequence
+/// (`"gg"`, `"Ctrl+a"`) or a list of alternatives.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KeySequences {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeySequences {
+    pub fn to_vec(&self) -> Vec<&str> {
+        match self {
+            Self::One(s) => vec![s.as_str()],
+            Self::Many(v) => v.iter().map(String::as_str).collect(),
+        }
+    }
 }
 
 impl AppConfig {
@@ -70,6 +120,30 @@ impl AppConfig {
         self.configuration_format.as_deref().unwrap_or("toml")
     }
 
+    pub fn effective_theme(&self) -> &str {
+        self.theme.as_deref().unwrap_or("dark")
+    }
+
+    pub fn effective_mouse(&self) -> bool {
+        self.mouse.unwrap_or(false)
+    }
+
+    pub fn effective_text_editor(&self) -> &str {
+        self.text_editor.as_deref().unwrap_or("inline")
+    }
+
+    pub fn effective_preview(&self) -> &str {
+        self.preview.as_deref().unwrap_or("off")
+    }
+
+    pub fn effective_preview_size(&self) -> u16 {
+        self.preview_size.unwrap_or(DEFAULT_PREVIEW_SIZE)
+    }
+
+    pub fn effective_mcp_confirm_destructive(&self) -> bool {
+        self.mcp_confirm_destructive.unwrap_or(false)
+    }
+
     pub fn effective_storage_location(&self) -> String {
         self.storage_location.clone().unwrap_or_else(|| {
             match self.effective_storage_backend() {
@@ -105,6 +179,32 @@ impl AppConfig {
                 )));
             }
         }
+        if let Some(ref v) = self.text_editor {
+            if !matches!(v.as_str(), "inline" | "vi" | "external") {
+                return Err(crate::CoreError::Validation(format!(
+                    "Invalid text_editor '{}': must be 'inline', 'vi' or 'external'",
+                    v
+                )));
+            }
+        }
+        if let Some(ref v) = self.preview {
+            if !matches!(v.as_str(), "right" | "bottom" | "off") {
+                return Err(crate::CoreError::Validation(format!(
+                    "Invalid preview '{}': must be 'right', 'bottom' or 'off'",
+                    v
+                )));
+            }
+        }
+        if let Some(v) = self.preview_size {
+            if !PREVIEW_SIZE_RANGE.contains(&v) {
+                return Err(crate::CoreError::Validation(format!(
+                    "Invalid preview_size {}: must be between {} and {}",
+                    v,
+                    PREVIEW_SIZE_RANGE.start(),
+                    PREVIEW_SIZE_RANGE.end()
+                )));
+            }
+        }
         if let Some(ref v) = self.default_card_prefix {
             if !validate_branch_prefix(v) {
                 return Err(crate::CoreError::Validation(format!(
@@ -299,6 +399,47 @@ mod tests {
         assert!(err.to_string().contains("editing_format"));
     }
 
+    #[test]
+    fn test_validate_values_text_editor() {
+        for mode in &["inline", "vi", "external"] {
+            let config = AppConfig {
+                text_editor: Some(mode.to_string()),
+                ..Default::default()
+            };
+            config.validate_values().unwrap();
+        }
+        let config = AppConfig {
+            text_editor: Some("emacs".into()),
+            ..Default::default()
+        };
+        let err = config.validate_values().unwrap_err();
+        assert!(err.to_string().contains("text_editor"));
+    }
+
+    #[test]
+    fn test_validate_values_preview() {
+        for layout in &["right", "bottom", "off"] {
+            let config = AppConfig {
+                preview: Some(layout.to_string()),
+                preview_size: Some(30),
+                ..Default::default()
+            };
+            config.validate_values().unwrap();
+        }
+        let config = AppConfig {
+            preview: Some("left".into()),
+            ..Default::default()
+        };
+        let err = config.validate_values().unwrap_err();
+        assert!(err.to_string().contains("preview"));
+        let config = AppConfig {
+            preview_size: Some(95),
+            ..Default::default()
+        };
+        let err = config.validate_values().unwrap_err();
+        assert!(err.to_string().contains("preview_size"));
+    }
+
     #[test]
     fn test_validate_values_valid_configuration_format_passes() {
         for fmt in &["json", "toml"] {
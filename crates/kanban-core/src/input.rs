@@ -1,6 +1,35 @@
+use std::ops::Range;
+
+/// Most undo steps kept per input.
+const UNDO_LIMIT: usize = 100;
+
+/// A text buffer with a cursor, an optional selection and undo history.
+///
+/// Offsets are byte offsets into the buffer and always sit on a char
+/// boundary. The selection runs between the anchor and the cursor; edits
+/// replace it. Typing and deleting in a run undo as one step, and any
+/// cursor motion ends the run.
 pub struct InputState {
     buffer: String,
     cursor_byte_offset: usize,
+    anchor: Option<usize>,
+    undo_stack: Vec<Snapshot>,
+    redo_stack: Vec<Snapshot>,
+    last_edit: Option<EditKind>,
+}
+
+#[derive(Clone)]
+struct Snapshot {
+    buffer: String,
+    cursor: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum EditKind {
+    Insert,
+    Delete,
+    /// Never merged with the edit before it.
+    Other,
 }
 
 impl InputState {
@@ -8,67 +37,201 @@ impl InputState {
         Self {
             buffer: String::new(),
             cursor_byte_offset: 0,
+            anchor: None,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            last_edit: None,
         }
     }
 
     pub fn insert_char(&mut self, c: char) {
+        let kind = if c == '\n' {
+            EditKind::Other
+        } else {
+            EditKind::Insert
+        };
+        self.begin_edit(kind);
+        self.remove_selection();
         self.buffer.insert(self.cursor_byte_offset, c);
         self.cursor_byte_offset += c.len_utf8();
     }
 
+    /// Inserts `text` at the cursor as one undo step, replacing the
+    /// selection.
+    pub fn insert_str(&mut self, text: &str) {
+        self.begin_edit(EditKind::Other);
+        self.remove_selection();
+        self.buffer.insert_str(self.cursor_byte_offset, text);
+        self.cursor_byte_offset += text.len();
+    }
+
     pub fn backspace(&mut self) {
+        if self.selection().is_some() {
+            self.delete_selection();
+            return;
+        }
         if self.cursor_byte_offset > 0 {
-            let prev = self.buffer[..self.cursor_byte_offset]
-                .chars()
-                .next_back()
-                .unwrap();
-            self.cursor_byte_offset -= prev.len_utf8();
+            self.begin_edit(EditKind::Delete);
+            self.cursor_byte_offset = self.prev_boundary(self.cursor_byte_offset);
             self.buffer.remove(self.cursor_byte_offset);
         }
     }
 
     pub fn delete(&mut self) {
+        if self.selection().is_some() {
+            self.delete_selection();
+            return;
+        }
         if self.cursor_byte_offset < self.buffer.len() {
+            self.begin_edit(EditKind::Delete);
             self.buffer.remove(self.cursor_byte_offset);
         }
     }
 
     pub fn move_left(&mut self) {
-        if self.cursor_byte_offset > 0 {
-            let prev = self.buffer[..self.cursor_byte_offset]
-                .chars()
-                .next_back()
-                .unwrap();
-            self.cursor_byte_offset -= prev.len_utf8();
-        }
+        self.last_edit = None;
+        self.cursor_byte_offset = self.prev_boundary(self.cursor_byte_offset);
     }
 
     pub fn move_right(&mut self) {
-        if self.cursor_byte_offset < self.buffer.len() {
-            let next = self.buffer[self.cursor_byte_offset..]
-                .chars()
-                .next()
-                .unwrap();
-            self.cursor_byte_offset += next.len_utf8();
+        self.last_edit = None;
+        self.cursor_byte_offset = self.next_boundary(self.cursor_byte_offset);
+    }
+
+    /// Moves to the start of the current or previous word.
+    pub fn move_word_left(&mut self) {
+        self.last_edit = None;
+        let before = &self.buffer[..self.cursor_byte_offset];
+        let mut chars = before.char_indices().rev().peekable();
+        while chars.next_if(|(_, c)| !is_word_char(*c)).is_some() {}
+        let mut offset = 0;
+        while let Some((i, _)) = chars.next_if(|(_, c)| is_word_char(*c)) {
+            offset = i;
         }
+        self.cursor_byte_offset = offset;
+    }
+
+    /// Moves to the start of the next word.
+    pub fn move_word_right(&mut self) {
+        self.last_edit = None;
+        let start = self.cursor_byte_offset;
+        let mut chars = self.buffer[start..].char_indices().peekable();
+        while chars.next_if(|(_, c)| is_word_char(*c)).is_some() {}
+        while chars.next_if(|(_, c)| !is_word_char(*c)).is_some() {}
+        self.cursor_byte_offset = chars.peek().map_or(self.buffer.len(), |(i, _)| start + i);
     }
 
     pub fn move_home(&mut self) {
+        self.last_edit = None;
         self.cursor_byte_offset = 0;
     }
 
     pub fn move_end(&mut self) {
+        self.last_edit = None;
+        self.cursor_byte_offset = self.buffer.len();
+    }
+
+    /// Moves to the start of the line the cursor is on.
+    pub fn move_line_start(&mut self) {
+        self.last_edit = None;
+        self.cursor_byte_offset = self.line_start(self.cursor_byte_offset);
+    }
+
+    /// Moves to the end of the line the cursor is on, before its newline.
+    pub fn move_line_end(&mut self) {
+        self.last_edit = None;
+        self.cursor_byte_offset = self.buffer[self.cursor_byte_offset..]
+            .find('\n')
+            .map_or(self.buffer.len(), |i| self.cursor_byte_offset + i);
+    }
+
+    /// Moves the cursor to `offset`, clamped into the buffer and back onto
+    /// a char boundary.
+    pub fn set_cursor(&mut self, offset: usize) {
+        self.last_edit = None;
+        let mut offset = offset.min(self.buffer.len());
+        while !self.buffer.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        self.cursor_byte_offset = offset;
+    }
+
+    /// Starts a selection at the cursor unless one is already open, so the
+    /// following motions extend it.
+    pub fn start_selection(&mut self) {
+        if self.anchor.is_none() {
+            self.anchor = Some(self.cursor_byte_offset);
+        }
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.anchor = None;
+    }
+
+    pub fn select_all(&mut self) {
+        self.last_edit = None;
+        self.anchor = Some(0);
         self.cursor_byte_offset = self.buffer.len();
     }
 
+    /// The selected byte range, if a selection is open and not empty.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.anchor?;
+        let cursor = self.cursor_byte_offset;
+        (anchor != cursor).then(|| anchor.min(cursor)..anchor.max(cursor))
+    }
+
+    pub fn selected_text(&self) -> Option<&str> {
+        self.selection().map(|range| &self.buffer[range])
+    }
+
+    /// Deletes the selected text as one undo step. Returns false when
+    /// nothing was selected.
+    pub fn delete_selection(&mut self) -> bool {
+        if self.selection().is_none() {
+            return false;
+        }
+        self.begin_edit(EditKind::Other);
+        self.remove_selection();
+        true
+    }
+
+    /// Restores the text before the last edit. Returns false when there is
+    /// nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(snapshot) = self.undo_stack.pop() else {
+            return false;
+        };
+        let current = self.snapshot();
+        self.redo_stack.push(current);
+        self.restore(snapshot);
+        true
+    }
+
+    /// Reapplies the last undone edit. Returns false when there is nothing
+    /// to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(snapshot) = self.redo_stack.pop() else {
+            return false;
+        };
+        let current = self.snapshot();
+        self.undo_stack.push(current);
+        self.restore(snapshot);
+        true
+    }
+
     pub fn clear(&mut self) {
         self.buffer.clear();
         self.cursor_byte_offset = 0;
+        self.reset_history();
     }
 
+    /// Replaces the text and puts the cursor at its end. Starts a fresh
+    /// undo history.
     pub fn set(&mut self, text: String) {
         self.buffer = text;
         self.cursor_byte_offset = self.buffer.len();
+        self.reset_history();
     }
 
     pub fn is_empty(&self) -> bool {
@@ -82,6 +245,74 @@ impl InputState {
     pub fn cursor_byte_offset(&self) -> usize {
         self.cursor_byte_offset
     }
+
+    /// Byte offset of the start of the line containing `offset`.
+    pub fn line_start(&self, offset: usize) -> usize {
+        self.buffer[..offset].rfind('\n').map_or(0, |i| i + 1)
+    }
+
+    fn prev_boundary(&self, offset: usize) -> usize {
+        self.buffer[..offset]
+            .chars()
+            .next_back()
+            .map_or(offset, |c| offset - c.len_utf8())
+    }
+
+    fn next_boundary(&self, offset: usize) -> usize {
+        self.buffer[offset..]
+            .chars()
+            .next()
+            .map_or(offset, |c| offset + c.len_utf8())
+    }
+
+    /// Records the text before an edit, unless the edit continues a run of
+    /// the same kind. Any edit drops the redo history.
+    fn begin_edit(&mut self, kind: EditKind) {
+        let continues_run =
+            kind != EditKind::Other && self.last_edit == Some(kind) && self.selection().is_none();
+        if !continues_run {
+            let snapshot = self.snapshot();
+            self.undo_stack.push(snapshot);
+            if self.undo_stack.len() > UNDO_LIMIT {
+                self.undo_stack.remove(0);
+            }
+        }
+        self.redo_stack.clear();
+        self.last_edit = Some(kind);
+    }
+
+    fn remove_selection(&mut self) {
+        if let Some(range) = self.selection() {
+            self.cursor_byte_offset = range.start;
+            self.buffer.replace_range(range, "");
+        }
+        self.anchor = None;
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            buffer: self.buffer.clone(),
+            cursor: self.cursor_byte_offset,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.buffer = snapshot.buffer;
+        self.cursor_byte_offset = snapshot.cursor;
+        self.anchor = None;
+        self.last_edit = None;
+    }
+
+    fn reset_history(&mut self) {
+        self.anchor = None;
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.last_edit = None;
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
 }
 
 impl Default for InputState {
@@ -402,4 +633,129 @@ mod tests {
         input.backspace();
         assert_eq!(input.as_str(), "hllo");
     }
+
+    #[test]
+    fn test_word_motions() {
+        let mut input = InputState::new();
+        input.set("foo bar_baz, qux".to_string());
+        input.move_word_left();
+        assert_eq!(input.cursor_byte_offset(), 13);
+        input.move_word_left();
+        assert_eq!(input.cursor_byte_offset(), 4);
+        input.move_word_left();
+        assert_eq!(input.cursor_byte_offset(), 0);
+        input.move_word_left();
+        assert_eq!(input.cursor_byte_offset(), 0);
+
+        input.move_word_right();
+        assert_eq!(input.cursor_byte_offset(), 4);
+        input.move_word_right();
+        assert_eq!(input.cursor_byte_offset(), 13);
+        input.move_word_right();
+        assert_eq!(input.cursor_byte_offset(), 16);
+    }
+
+    #[test]
+    fn test_line_motions() {
+        let mut input = InputState::new();
+        input.set("one\ntwo\nthree".to_string());
+        input.set_cursor(5);
+        input.move_line_start();
+        assert_eq!(input.cursor_byte_offset(), 4);
+        input.move_line_end();
+        assert_eq!(input.cursor_byte_offset(), 7);
+        input.move_end();
+        input.move_line_start();
+        assert_eq!(input.cursor_byte_offset(), 8);
+    }
+
+    #[test]
+    fn test_set_cursor_clamps_to_char_boundary() {
+        let mut input = InputState::new();
+        input.set("a\u{00e9}".to_string());
+        input.set_cursor(2);
+        assert_eq!(input.cursor_byte_offset(), 1);
+        input.set_cursor(99);
+        assert_eq!(input.cursor_byte_offset(), 3);
+    }
+
+    #[test]
+    fn test_typing_replaces_selection() {
+        let mut input = InputState::new();
+        input.set("hello world".to_string());
+        input.start_selection();
+        input.move_word_left();
+        assert_eq!(input.selected_text(), Some("world"));
+        input.insert_char('X');
+        assert_eq!(input.as_str(), "hello X");
+        assert_eq!(input.selection(), None);
+    }
+
+    #[test]
+    fn test_backspace_deletes_selection() {
+        let mut input = InputState::new();
+        input.set("hello world".to_string());
+        input.move_home();
+        input.start_selection();
+        input.move_word_right();
+        input.backspace();
+        assert_eq!(input.as_str(), "world");
+        assert_eq!(input.cursor_byte_offset(), 0);
+    }
+
+    #[test]
+    fn test_undo_groups_a_typed_run() {
+        let mut input = InputState::new();
+        input.set("a".to_string());
+        input.insert_char('b');
+        input.insert_char('c');
+        input.move_left();
+        input.insert_char('x');
+        assert_eq!(input.as_str(), "abxc");
+
+        assert!(input.undo());
+        assert_eq!(input.as_str(), "abc");
+        assert!(input.undo());
+        assert_eq!(input.as_str(), "a");
+        assert_eq!(input.cursor_byte_offset(), 1);
+        assert!(!input.undo());
+
+        assert!(input.redo());
+        assert_eq!(input.as_str(), "abc");
+        assert!(input.redo());
+        assert_eq!(input.as_str(), "abxc");
+        assert!(!input.redo());
+    }
+
+    #[test]
+    fn test_edit_after_undo_drops_redo() {
+        let mut input = InputState::new();
+        input.insert_char('a');
+        input.undo();
+        input.insert_char('b');
+        assert!(!input.redo());
+        assert_eq!(input.as_str(), "b");
+    }
+
+    #[test]
+    fn test_set_starts_fresh_history() {
+        let mut input = InputState::new();
+        input.insert_char('a');
+        input.set("b".to_string());
+        assert!(!input.undo());
+    }
+
+    #[test]
+    fn test_undo_history_is_bounded() {
+        let mut input = InputState::new();
+        for _ in 0..UNDO_LIMIT + 10 {
+            input.insert_str("x");
+        }
+        let mut steps = 0;
+        while input.undo() {
+            steps += 1;
+        }
+        assert_eq!(steps, UNDO_LIMIT);
+        assert_eq!(input.as_str().len(), 10);
+    }
 }
@@ -15,8 +15,8 @@ pub mod traits;
 pub mod version;
 
 pub use config::{
-    validate_branch_prefix, AppConfig, DEFAULT_JSON_FILENAME, DEFAULT_SQLITE_FILENAME,
-    DEFAULT_STORAGE_BACKEND,
+    validate_branch_prefix, AppConfig, KeySequences, DEFAULT_JSON_FILENAME, DEFAULT_PREVIEW_SIZE,
+    DEFAULT_SQLITE_FILENAME, DEFAULT_STORAGE_BACKEND, PREVIEW_SIZE_RANGE,
 };
 pub use datetime_input::parse_datetime_input;
 pub use error::{CoreError, CoreResult};
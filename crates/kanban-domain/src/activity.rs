@@ -0,0 +1,59 @@
+//! Activity log kept on a card.
+//!
+//! Entries record changes made on someone's behalf, such as a git hook
+//! moving a card for a commit, without touching the text the user wrote.
+//! Each entry names its `source` so the log shows who made the change.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// `source` of entries written by the git hooks.
+pub const GIT_ACTIVITY_SOURCE: &str = "git";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityEntry {
+    pub id: Uuid,
+    /// What made the change, e.g. `git`.
+    pub source: String,
+    pub message: String,
+    /// Whatever the source identifies the change by, such as a commit
+    /// hash, so the same change is not recorded twice.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    pub at: DateTime<Utc>,
+}
+
+impl ActivityEntry {
+    pub fn new(source: impl Into<String>, message: impl Into<String>, now: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            source: source.into(),
+            message: message.into(),
+            reference: None,
+            at: now,
+        }
+    }
+
+    pub fn with_reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_is_omitted_when_unset() {
+        let entry = ActivityEntry::new(GIT_ACTIVITY_SOURCE, "Closed", Utc::now());
+        let json = serde_json::to_value(&entry).unwrap();
+        assert!(json.get("reference").is_none());
+
+        let entry = entry.with_reference("abc1234");
+        let back: ActivityEntry =
+            serde_json::from_value(serde_json::to_value(&entry).unwrap()).unwrap();
+        assert_eq!(back, entry);
+    }
+}
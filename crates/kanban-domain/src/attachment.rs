@@ -0,0 +1,233 @@
+//! Files attached to a card.
+//!
+//! A card only keeps the attachment's metadata. The content lives in a
+//! content-addressed blob store owned by the storage backend and is looked
+//! up by `hash`, so two cards attaching the same screenshot share one blob.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::checklist::MIN_ID_PREFIX_LEN;
+use crate::{KanbanError, KanbanResult};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attachment {
+    pub id: Uuid,
+    /// File name shown to the user and used when extracting.
+    pub name: String,
+    pub size: u64,
+    pub mime_type: String,
+    /// Lowercase hex SHA-256 of the content; the blob store key.
+    pub hash: String,
+    pub added_at: DateTime<Utc>,
+}
+
+impl Attachment {
+    /// Metadata for content already stored under `hash`. The MIME type is
+    /// guessed from the extension of `name`.
+    pub fn new(
+        name: impl Into<String>,
+        size: u64,
+        hash: impl Into<String>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let name = name.into();
+        Self {
+            id: Uuid::new_v4(),
+            mime_type: mime_type_for(&name).to_string(),
+            name,
+            size,
+            hash: hash.into(),
+            added_at: now,
+        }
+    }
+
+    /// `name` reduced to its last path component, safe to join onto a
+    /// directory when extracting; names read from an imported file may
+    /// carry separators or `..`. Falls back to the id.
+    pub fn file_name(&self) -> String {
+        let base = self
+            .name
+            .rsplit(['/', '\\'])
+            .next()
+            .unwrap_or_default()
+            .trim();
+        if base.is_empty() || base == "." || base == ".." {
+            self.id.to_string()
+        } else {
+            base.to_string()
+        }
+    }
+
+    /// Size for display, e.g. `512 B` or `1.4 MiB`.
+    pub fn display_size(&self) -> String {
+        format_size(self.size)
+    }
+}
+
+/// Outcome of garbage-collecting stored attachment content.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttachmentGcReport {
+    pub dry_run: bool,
+    /// Hashes of the blobs deleted, or that would be on a dry run.
+    pub removed: Vec<String>,
+    pub freed_bytes: u64,
+    /// Blobs still referenced and therefore kept.
+    pub kept: usize,
+}
+
+/// MIME type for a file name, guessed from its extension.
+pub fn mime_type_for(name: &str) -> &'static str {
+    let extension = name
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "bmp" => "image/bmp",
+        "ico" => "image/x-icon",
+        "pdf" => "application/pdf",
+        "txt" | "log" => "text/plain",
+        "md" | "markdown" => "text/markdown",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "yaml" | "yml" => "application/yaml",
+        "toml" => "application/toml",
+        "zip" => "application/zip",
+        "gz" | "tgz" => "application/gzip",
+        "tar" => "application/x-tar",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "mov" => "video/quicktime",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Human-readable byte count using binary units.
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+/// Resolve `raw` to an attachment id: a 1-based position, an exact file
+/// name, a full UUID, or a unique UUID prefix of at least
+/// [`MIN_ID_PREFIX_LEN`] characters. A number past the end of the list is
+/// tried as a name and an id prefix before it is rejected.
+pub fn resolve_attachment(attachments: &[Attachment], raw: &str) -> KanbanResult<Uuid> {
+    let raw = raw.trim();
+    let position = raw.parse::<usize>().ok();
+    if let Some(attachment) = position
+        .and_then(|p| p.checked_sub(1))
+        .and_then(|i| attachments.get(i))
+    {
+        return Ok(attachment.id);
+    }
+    let by_name: Vec<&Attachment> = attachments.iter().filter(|a| a.name == raw).collect();
+    if let [attachment] = by_name.as_slice() {
+        return Ok(attachment.id);
+    }
+    let needle = raw.to_lowercase();
+    let by_id: Vec<&Attachment> = attachments
+        .iter()
+        .filter(|a| needle.len() >= MIN_ID_PREFIX_LEN && a.id.to_string().starts_with(&needle))
+        .collect();
+    match (by_name.len(), by_id.as_slice()) {
+        (0, [attachment]) => Ok(attachment.id),
+        (0, []) => Err(KanbanError::validation(match position {
+            Some(position) => format!(
+                "Card has {} attachment(s); no attachment {}",
+                attachments.len(),
+                position
+            ),
+            None => format!("No attachment '{}'", raw),
+        })),
+        _ => Err(KanbanError::validation(format!(
+            "Attachment '{}' is ambiguous; use its position or id",
+            raw
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attachments(names: &[&str]) -> Vec<Attachment> {
+        names
+            .iter()
+            .map(|name| Attachment::new(*name, 10, "00", Utc::now()))
+            .collect()
+    }
+
+    #[test]
+    fn test_mime_type_from_extension() {
+        assert_eq!(mime_type_for("screenshot.PNG"), "image/png");
+        assert_eq!(mime_type_for("server.log"), "text/plain");
+        assert_eq!(mime_type_for("core"), "application/octet-stream");
+        assert_eq!(mime_type_for("archive.tar.gz"), "application/gzip");
+    }
+
+    #[test]
+    fn test_file_name_strips_directories() {
+        let mut attachment = Attachment::new("../../etc/passwd", 1, "00", Utc::now());
+        assert_eq!(attachment.file_name(), "passwd");
+        attachment.name = "logs\\..".into();
+        assert_eq!(attachment.file_name(), attachment.id.to_string());
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+        assert_eq!(format_size(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn test_resolve_by_position_name_and_prefix() {
+        let list = attachments(&["a.png", "b.log", "b.log"]);
+        assert_eq!(resolve_attachment(&list, "2").unwrap(), list[1].id);
+        assert_eq!(resolve_attachment(&list, "a.png").unwrap(), list[0].id);
+        let prefix = &list[2].id.to_string()[..8];
+        assert_eq!(resolve_attachment(&list, prefix).unwrap(), list[2].id);
+        assert!(resolve_attachment(&list, "b.log").is_err());
+        // Nine digits run past the first hyphen of any id, so this number
+        // cannot fall back to an id prefix.
+        assert!(resolve_attachment(&list, "123456789").is_err());
+        assert!(resolve_attachment(&list, "missing.txt").is_err());
+        assert!(resolve_attachment(&list, "").is_err());
+    }
+
+    #[test]
+    fn test_resolve_number_past_the_end_as_name_or_id_prefix() {
+        let mut list = attachments(&["7", "notes.txt"]);
+        list[0].id = Uuid::parse_str("abcdef01-2345-4678-9abc-def012345678").unwrap();
+        list[1].id = Uuid::parse_str("12345678-9abc-4def-8123-456789abcdef").unwrap();
+        assert_eq!(resolve_attachment(&list, "2").unwrap(), list[1].id);
+        assert_eq!(resolve_attachment(&list, "7").unwrap(), list[0].id);
+        assert_eq!(resolve_attachment(&list, "12345678").unwrap(), list[1].id);
+        assert_eq!(resolve_attachment(&list, "1234").unwrap(), list[1].id);
+        let error = resolve_attachment(&list, "9").unwrap_err().to_string();
+        assert!(error.contains("no attachment 9"), "{}", error);
+        // Too short to count as an id prefix, though list[1]'s id starts with it.
+        let error = resolve_attachment(&list, "12").unwrap_err().to_string();
+        assert!(error.contains("no attachment 12"), "{}", error);
+    }
+}
@@ -3,7 +3,11 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use uuid::Uuid;
 
-use crate::{board::Board, column::ColumnId, field_update::FieldUpdate, sprint::Sprint, SprintLog};
+use crate::{
+    activity::ActivityEntry, attachment::Attachment, board::Board, checklist::ChecklistItem,
+    checklist::ChecklistProgress, column::ColumnId, field_update::FieldUpdate, sprint::Sprint,
+    SprintLog,
+};
 use kanban_core::GraphNode;
 
 pub type CardId = Uuid;
@@ -76,6 +80,13 @@ pub struct Card {
     pub completed_at: Option<DateTime<Utc>>,
     #[serde(default)]
     pub sprint_logs: Vec<SprintLog>,
+    #[serde(default)]
+    pub checklist: Vec<ChecklistItem>,
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+    /// Changes recorded on the card's behalf, oldest first.
+    #[serde(default)]
+    pub activity: Vec<ActivityEntry>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -96,6 +107,44 @@ pub struct CardSummary {
     pub updated_at: DateTime<Utc>,
     #[serde(default)]
     pub completed_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checklist: Option<ChecklistProgress>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub attachments: usize,
+}
+
+fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
+/// Display identifier such as `KAN-5` for card `card_number` of `board`.
+/// The prefix is resolved as sprint.card_prefix → board.card_prefix →
+/// default_prefix, where the sprint is `sprint_id` looked up in `sprints`.
+pub fn card_identifier(
+    card_number: u32,
+    sprint_id: Option<Uuid>,
+    board: &Board,
+    sprints: &[Sprint],
+    default_prefix: &str,
+) -> String {
+    let prefix = sprint_id
+        .and_then(|id| sprints.iter().find(|s| s.id == id))
+        .and_then(|sprint| sprint.card_prefix.as_deref())
+        .unwrap_or_else(|| board.effective_card_prefix(default_prefix));
+    format!("{}-{}", prefix, card_number)
+}
+
+impl CardSummary {
+    /// Display identifier such as `KAN-5`; see [`card_identifier`].
+    pub fn identifier(&self, board: &Board, sprints: &[Sprint], default_prefix: &str) -> String {
+        card_identifier(
+            self.card_number,
+            self.sprint_id,
+            board,
+            sprints,
+            default_prefix,
+        )
+    }
 }
 
 impl From<&Card> for CardSummary {
@@ -114,6 +163,8 @@ impl From<&Card> for CardSummary {
             created_at: card.created_at,
             updated_at: card.updated_at,
             completed_at: card.completed_at,
+            checklist: card.checklist_progress(),
+            attachments: card.attachments.len(),
         }
     }
 }
@@ -143,6 +194,9 @@ impl Card {
             updated_at: now,
             completed_at: None,
             sprint_logs: Vec::new(),
+            checklist: Vec::new(),
+            attachments: Vec::new(),
+            activity: Vec::new(),
         }
     }
 
@@ -188,20 +242,31 @@ impl Card {
         self.updated_at = Utc::now();
     }
 
-    /// Resolve the branch name prefix using two-level hierarchy:
-    /// sprint.card_prefix → board.card_prefix → default_prefix
+    /// Checklist progress, or `None` when the card has no checklist.
+    pub fn checklist_progress(&self) -> Option<ChecklistProgress> {
+        (!self.checklist.is_empty()).then(|| ChecklistProgress::of(&self.checklist))
+    }
+
+    /// Display identifier such as `KAN-5`; see [`card_identifier`].
+    pub fn identifier(&self, board: &Board, sprints: &[Sprint], default_prefix: &str) -> String {
+        card_identifier(
+            self.card_number,
+            self.sprint_id,
+            board,
+            sprints,
+            default_prefix,
+        )
+    }
+
+    /// Branch name of the form `<identifier>/<kebab-title>`, using the same
+    /// prefix resolution as [`Card::identifier`].
     pub fn branch_name(&self, board: &Board, sprints: &[Sprint], default_prefix: &str) -> String {
-        let prefix = if let Some(sprint_id) = self.sprint_id {
-            sprints
-                .iter()
-                .find(|s| s.id == sprint_id)
-                .and_then(|sprint| sprint.card_prefix.as_deref())
-                .unwrap_or_else(|| board.effective_card_prefix(default_prefix))
-        } else {
-            board.effective_card_prefix(default_prefix)
-        };
         let kebab_title = Self::to_kebab_case(&self.title);
-        let branch = format!("{}-{}/{}", prefix, self.card_number, kebab_title);
+        let branch = format!(
+            "{}/{}",
+            self.identifier(board, sprints, default_prefix),
+            kebab_title
+        );
         Self::truncate_branch_name(branch)
     }
 
@@ -563,6 +628,14 @@ mod tests {
             card_with_sprint.branch_name(&board, &sprints_with_card_prefix, "task"),
             "hotfix-1/test-card".to_string()
         );
+        assert_eq!(
+            CardSummary::from(&card_with_sprint).identifier(
+                &board,
+                &sprints_with_card_prefix,
+                "task"
+            ),
+            "hotfix-1"
+        );
     }
 
     #[test]
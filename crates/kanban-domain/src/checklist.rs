@@ -0,0 +1,248 @@
+//! Lightweight checklist items kept inside a card.
+//!
+//! Items are ordered sub-steps ("update changelog", "bump version") that
+//! are too small for their own card. Each can be converted into a child
+//! card once it grows.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use uuid::Uuid;
+
+use crate::{KanbanError, KanbanResult};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChecklistItem {
+    pub id: Uuid,
+    pub text: String,
+    #[serde(default)]
+    pub done: bool,
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl ChecklistItem {
+    pub fn new(text: impl Into<String>, now: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            text: text.into(),
+            done: false,
+            created_at: now,
+            completed_at: None,
+        }
+    }
+
+    pub fn set_done(&mut self, done: bool, now: DateTime<Utc>) {
+        if done && !self.done {
+            self.completed_at = Some(now);
+        } else if !done {
+            self.completed_at = None;
+        }
+        self.done = done;
+    }
+}
+
+/// Completed and total item counts, displayed as `3/5`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChecklistProgress {
+    pub done: usize,
+    pub total: usize,
+}
+
+impl ChecklistProgress {
+    pub fn of(items: &[ChecklistItem]) -> Self {
+        Self {
+            done: items.iter().filter(|item| item.done).count(),
+            total: items.len(),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.done == self.total
+    }
+}
+
+impl fmt::Display for ChecklistProgress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.done, self.total)
+    }
+}
+
+/// Shortest UUID prefix accepted when resolving items by id, so a small
+/// number past the end of a list never picks an item whose id happens to
+/// start with it.
+pub(crate) const MIN_ID_PREFIX_LEN: usize = 4;
+
+/// Resolve `raw` to an item id: a 1-based position, a full UUID, or a
+/// unique UUID prefix of at least [`MIN_ID_PREFIX_LEN`] characters. A
+/// number past the end of the list is tried as an id prefix before it is
+/// rejected.
+pub fn resolve_checklist_item(items: &[ChecklistItem], raw: &str) -> KanbanResult<Uuid> {
+    let raw = raw.trim();
+    let position = raw.parse::<usize>().ok();
+    if let Some(item) = position
+        .and_then(|p| p.checked_sub(1))
+        .and_then(|i| items.get(i))
+    {
+        return Ok(item.id);
+    }
+    let needle = raw.to_lowercase();
+    let matches: Vec<&ChecklistItem> = items
+        .iter()
+        .filter(|item| {
+            needle.len() >= MIN_ID_PREFIX_LEN && item.id.to_string().starts_with(&needle)
+        })
+        .collect();
+    match matches.as_slice() {
+        [item] => Ok(item.id),
+        [] => Err(KanbanError::validation(match position {
+            Some(position) => format!(
+                "Checklist has {} item(s); no item {}",
+                items.len(),
+                position
+            ),
+            None => format!("No checklist item '{}'", raw),
+        })),
+        _ => Err(KanbanError::validation(format!(
+            "Checklist item '{}' is ambiguous",
+            raw
+        ))),
+    }
+}
+
+/// A Markdown task line (`- [ ] text`, `* [x] text`) found in a description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownTask {
+    pub text: String,
+    pub done: bool,
+}
+
+/// Split `description` into its Markdown task items and the remaining
+/// text. Task lines inside fenced code blocks are left alone.
+pub fn extract_markdown_tasks(description: &str) -> (Vec<MarkdownTask>, String) {
+    let mut tasks = Vec::new();
+    let mut rest = Vec::new();
+    let mut in_fence = false;
+    for line in description.lines() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        }
+        match (!in_fence).then(|| parse_task_line(line)).flatten() {
+            Some(task) => tasks.push(task),
+            None => rest.push(line),
+        }
+    }
+    (tasks, rest.join("\n").trim().to_string())
+}
+
+fn parse_task_line(line: &str) -> Option<MarkdownTask> {
+    let line = line.trim_start();
+    let line = line
+        .strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))
+        .or_else(|| line.strip_prefix("+ "))?;
+    let (done, text) = if let Some(text) = line.strip_prefix("[ ]") {
+        (false, text)
+    } else if let Some(text) = line
+        .strip_prefix("[x]")
+        .or_else(|| line.strip_prefix("[X]"))
+    {
+        (true, text)
+    } else {
+        return None;
+    };
+    let text = text.trim();
+    (!text.is_empty()).then(|| MarkdownTask {
+        text: text.to_string(),
+        done,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(texts: &[&str]) -> Vec<ChecklistItem> {
+        texts
+            .iter()
+            .map(|text| ChecklistItem::new(*text, Utc::now()))
+            .collect()
+    }
+
+    #[test]
+    fn test_progress_counts_done_items() {
+        let mut list = items(&["a", "b", "c"]);
+        list[1].set_done(true, Utc::now());
+        let progress = ChecklistProgress::of(&list);
+        assert_eq!(progress.to_string(), "1/3");
+        assert!(!progress.is_complete());
+    }
+
+    #[test]
+    fn test_set_done_tracks_completion_time() {
+        let mut item = ChecklistItem::new("a", Utc::now());
+        item.set_done(true, Utc::now());
+        assert!(item.completed_at.is_some());
+        item.set_done(false, Utc::now());
+        assert!(item.completed_at.is_none());
+    }
+
+    #[test]
+    fn test_resolve_by_position_and_prefix() {
+        let list = items(&["a", "b"]);
+        assert_eq!(resolve_checklist_item(&list, "2").unwrap(), list[1].id);
+        let prefix = &list[0].id.to_string()[..8];
+        assert_eq!(resolve_checklist_item(&list, prefix).unwrap(), list[0].id);
+        assert!(resolve_checklist_item(&list, "0").is_err());
+        assert!(resolve_checklist_item(&list, "").is_err());
+    }
+
+    #[test]
+    fn test_resolve_number_past_the_end_as_id_prefix() {
+        let mut list = items(&["a", "b"]);
+        list[0].id = Uuid::parse_str("abcdef01-2345-4678-9abc-def012345678").unwrap();
+        list[1].id = Uuid::parse_str("12345678-9abc-4def-8123-456789abcdef").unwrap();
+        assert_eq!(resolve_checklist_item(&list, "1").unwrap(), list[0].id);
+        assert_eq!(
+            resolve_checklist_item(&list, "12345678").unwrap(),
+            list[1].id
+        );
+        let error = resolve_checklist_item(&list, "3").unwrap_err().to_string();
+        assert!(error.contains("no item 3"), "{}", error);
+    }
+
+    #[test]
+    fn test_short_number_past_the_end_never_matches_an_id() {
+        let mut list = items(&["a", "b"]);
+        list[1].id = Uuid::parse_str("31234567-9abc-4def-8123-456789abcdef").unwrap();
+        let error = resolve_checklist_item(&list, "3").unwrap_err().to_string();
+        assert!(error.contains("no item 3"), "{}", error);
+        assert!(resolve_checklist_item(&list, "312").is_err());
+        assert_eq!(resolve_checklist_item(&list, "3123").unwrap(), list[1].id);
+    }
+
+    #[test]
+    fn test_extract_markdown_tasks() {
+        let description = "Release steps:\n- [ ] update changelog\n* [x] bump version\n\
+                           - not a task\n```\n- [ ] in code\n```\n- [ ]   ";
+        let (tasks, rest) = extract_markdown_tasks(description);
+        assert_eq!(
+            tasks,
+            vec![
+                MarkdownTask {
+                    text: "update changelog".into(),
+                    done: false
+                },
+                MarkdownTask {
+                    text: "bump version".into(),
+                    done: true
+                },
+            ]
+        );
+        assert_eq!(
+            rest,
+            "Release steps:\n- not a task\n```\n- [ ] in code\n```\n- [ ]"
+        );
+    }
+}
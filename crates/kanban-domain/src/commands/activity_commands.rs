@@ -0,0 +1,197 @@
+use super::{Command, CommandContext};
+use crate::data_store::DataStore;
+use crate::{ActivityEntry, Card, KanbanError, KanbanResult};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Edits to the activity log of a card.
+///
+/// Recording an entry rides in the same batch as the change it describes,
+/// so undoing that batch takes the entry back out with it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum ActivityCommand {
+    Record(RecordActivity),
+    Remove(RemoveActivity),
+}
+
+impl ActivityCommand {
+    pub fn execute(&self, context: &CommandContext) -> KanbanResult<()> {
+        match self {
+            ActivityCommand::Record(c) => c.execute(context),
+            ActivityCommand::Remove(c) => c.execute(context),
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            ActivityCommand::Record(c) => c.description(),
+            ActivityCommand::Remove(c) => c.description(),
+        }
+    }
+
+    pub fn capture_inverse(&self, store: &dyn DataStore) -> KanbanResult<Vec<Command>> {
+        match self {
+            ActivityCommand::Record(c) => c.capture_inverse(store),
+            ActivityCommand::Remove(c) => c.capture_inverse(store),
+        }
+    }
+}
+
+fn entry_index(card: &Card, entry_id: Uuid) -> KanbanResult<usize> {
+    card.activity
+        .iter()
+        .position(|e| e.id == entry_id)
+        .ok_or_else(|| KanbanError::not_found("Activity entry", entry_id))
+}
+
+/// Insert `entry` at `position` (0-based), or append it when `None`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordActivity {
+    pub card_id: Uuid,
+    pub entry: ActivityEntry,
+    #[serde(default)]
+    pub position: Option<usize>,
+}
+
+impl RecordActivity {
+    pub fn execute(&self, context: &CommandContext) -> KanbanResult<()> {
+        let mut card = context.get_card(self.card_id)?;
+        if card.activity.iter().any(|e| e.id == self.entry.id) {
+            return Err(KanbanError::validation(format!(
+                "Activity entry {} already exists",
+                self.entry.id
+            )));
+        }
+        let position = self
+            .position
+            .unwrap_or(card.activity.len())
+            .min(card.activity.len());
+        card.activity.insert(position, self.entry.clone());
+        card.updated_at = Utc::now();
+        context.store.upsert_card(card)?;
+        Ok(())
+    }
+
+    pub fn description(&self) -> String {
+        format!(
+            "Record {} activity '{}'",
+            self.entry.source, self.entry.message
+        )
+    }
+
+    pub fn capture_inverse(&self, _store: &dyn DataStore) -> KanbanResult<Vec<Command>> {
+        Ok(vec![Command::Activity(ActivityCommand::Remove(
+            RemoveActivity {
+                card_id: self.card_id,
+                entry_id: self.entry.id,
+            },
+        ))])
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveActivity {
+    pub card_id: Uuid,
+    pub entry_id: Uuid,
+}
+
+impl RemoveActivity {
+    pub fn execute(&self, context: &CommandContext) -> KanbanResult<()> {
+        let mut card = context.get_card(self.card_id)?;
+        let index = entry_index(&card, self.entry_id)?;
+        card.activity.remove(index);
+        card.updated_at = Utc::now();
+        context.store.upsert_card(card)?;
+        Ok(())
+    }
+
+    pub fn description(&self) -> String {
+        "Remove activity entry".to_string()
+    }
+
+    pub fn capture_inverse(&self, store: &dyn DataStore) -> KanbanResult<Vec<Command>> {
+        let card = store
+            .get_card(self.card_id)?
+            .ok_or_else(|| KanbanError::not_found("Card", self.card_id))?;
+        let index = entry_index(&card, self.entry_id)?;
+        Ok(vec![Command::Activity(ActivityCommand::Record(
+            RecordActivity {
+                card_id: self.card_id,
+                entry: card.activity[index].clone(),
+                position: Some(index),
+            },
+        ))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_helpers::TestContext;
+    use super::*;
+    use crate::DataStore;
+
+    fn setup(tc: &TestContext) -> Card {
+        let mut board = crate::Board::new("B", None::<String>);
+        let card = Card::new(&mut board, Uuid::new_v4(), "C", 0);
+        tc.store.upsert_card(card.clone()).unwrap();
+        card
+    }
+
+    fn messages(tc: &TestContext, card_id: Uuid) -> Vec<String> {
+        let card = tc.store.get_card(card_id).unwrap().unwrap();
+        card.activity.into_iter().map(|e| e.message).collect()
+    }
+
+    fn record(tc: &TestContext, card_id: Uuid, message: &str) {
+        RecordActivity {
+            card_id,
+            entry: ActivityEntry::new("git", message, Utc::now()),
+            position: None,
+        }
+        .execute(&tc.as_command_context())
+        .unwrap();
+    }
+
+    #[test]
+    fn test_record_and_undo_activity() {
+        let tc = TestContext::new();
+        let card = setup(&tc);
+        let cmd = Command::Activity(ActivityCommand::Record(RecordActivity {
+            card_id: card.id,
+            entry: ActivityEntry::new("git", "Closed by abc1234", Utc::now()),
+            position: None,
+        }));
+        let inverse = cmd.capture_inverse(&tc.store).unwrap();
+        cmd.execute(&tc.as_command_context()).unwrap();
+        assert_eq!(messages(&tc, card.id), ["Closed by abc1234"]);
+        assert!(cmd.execute(&tc.as_command_context()).is_err());
+        for inv in inverse {
+            inv.execute(&tc.as_command_context()).unwrap();
+        }
+        assert!(messages(&tc, card.id).is_empty());
+    }
+
+    #[test]
+    fn test_remove_undo_restores_position() {
+        let tc = TestContext::new();
+        let card = setup(&tc);
+        record(&tc, card.id, "a");
+        record(&tc, card.id, "b");
+        record(&tc, card.id, "c");
+        let stored = tc.store.get_card(card.id).unwrap().unwrap();
+        let cmd = Command::Activity(ActivityCommand::Remove(RemoveActivity {
+            card_id: card.id,
+            entry_id: stored.activity[1].id,
+        }));
+        let inverse = cmd.capture_inverse(&tc.store).unwrap();
+        cmd.execute(&tc.as_command_context()).unwrap();
+        assert_eq!(messages(&tc, card.id), ["a", "c"]);
+        for inv in inverse {
+            inv.execute(&tc.as_command_context()).unwrap();
+        }
+        let restored = tc.store.get_card(card.id).unwrap().unwrap();
+        assert_eq!(restored.activity, stored.activity);
+    }
+}
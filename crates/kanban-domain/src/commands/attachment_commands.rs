@@ -0,0 +1,211 @@
+use super::{Command, CommandContext};
+use crate::data_store::DataStore;
+use crate::{Attachment, Card, KanbanError, KanbanResult};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Edits to the attachment list of a card.
+///
+/// Commands only touch the metadata on the card; content stays in the
+/// backend's blob store until garbage collection finds it unreferenced.
+/// That keeps removal undoable without copying the bytes into the undo
+/// stack.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum AttachmentCommand {
+    Add(AddAttachment),
+    Remove(RemoveAttachment),
+}
+
+impl AttachmentCommand {
+    pub fn execute(&self, context: &CommandContext) -> KanbanResult<()> {
+        match self {
+            AttachmentCommand::Add(c) => c.execute(context),
+            AttachmentCommand::Remove(c) => c.execute(context),
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            AttachmentCommand::Add(c) => c.description(),
+            AttachmentCommand::Remove(c) => c.description(),
+        }
+    }
+
+    pub fn capture_inverse(&self, store: &dyn DataStore) -> KanbanResult<Vec<Command>> {
+        match self {
+            AttachmentCommand::Add(c) => c.capture_inverse(store),
+            AttachmentCommand::Remove(c) => c.capture_inverse(store),
+        }
+    }
+}
+
+fn attachment_index(card: &Card, attachment_id: Uuid) -> KanbanResult<usize> {
+    card.attachments
+        .iter()
+        .position(|a| a.id == attachment_id)
+        .ok_or_else(|| KanbanError::not_found("Attachment", attachment_id))
+}
+
+/// Insert `attachment` at `position` (0-based), or append it when `None`.
+/// The same content cannot be attached to one card twice.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddAttachment {
+    pub card_id: Uuid,
+    pub attachment: Attachment,
+    #[serde(default)]
+    pub position: Option<usize>,
+}
+
+impl AddAttachment {
+    pub fn execute(&self, context: &CommandContext) -> KanbanResult<()> {
+        let mut card = context.get_card(self.card_id)?;
+        if self.attachment.name.trim().is_empty() {
+            return Err(KanbanError::validation("Attachment name cannot be empty"));
+        }
+        if let Some(existing) = card
+            .attachments
+            .iter()
+            .find(|a| a.id == self.attachment.id || a.hash == self.attachment.hash)
+        {
+            return Err(KanbanError::validation(format!(
+                "'{}' is already attached to this card as '{}'",
+                self.attachment.name, existing.name
+            )));
+        }
+        let position = self
+            .position
+            .unwrap_or(card.attachments.len())
+            .min(card.attachments.len());
+        card.attachments.insert(position, self.attachment.clone());
+        card.updated_at = Utc::now();
+        context.store.upsert_card(card)?;
+        Ok(())
+    }
+
+    pub fn description(&self) -> String {
+        format!("Attach '{}'", self.attachment.name)
+    }
+
+    pub fn capture_inverse(&self, _store: &dyn DataStore) -> KanbanResult<Vec<Command>> {
+        Ok(vec![Command::Attachment(AttachmentCommand::Remove(
+            RemoveAttachment {
+                card_id: self.card_id,
+                attachment_id: self.attachment.id,
+            },
+        ))])
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveAttachment {
+    pub card_id: Uuid,
+    pub attachment_id: Uuid,
+}
+
+impl RemoveAttachment {
+    pub fn execute(&self, context: &CommandContext) -> KanbanResult<()> {
+        let mut card = context.get_card(self.card_id)?;
+        let index = attachment_index(&card, self.attachment_id)?;
+        card.attachments.remove(index);
+        card.updated_at = Utc::now();
+        context.store.upsert_card(card)?;
+        Ok(())
+    }
+
+    pub fn description(&self) -> String {
+        "Remove attachment".to_string()
+    }
+
+    pub fn capture_inverse(&self, store: &dyn DataStore) -> KanbanResult<Vec<Command>> {
+        let card = store
+            .get_card(self.card_id)?
+            .ok_or_else(|| KanbanError::not_found("Card", self.card_id))?;
+        let index = attachment_index(&card, self.attachment_id)?;
+        Ok(vec![Command::Attachment(AttachmentCommand::Add(
+            AddAttachment {
+                card_id: self.card_id,
+                attachment: card.attachments[index].clone(),
+                position: Some(index),
+            },
+        ))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_helpers::TestContext;
+    use super::*;
+    use crate::DataStore;
+
+    fn setup(tc: &TestContext) -> Card {
+        let mut board = crate::Board::new("B", None::<String>);
+        let card = Card::new(&mut board, Uuid::new_v4(), "C", 0);
+        tc.store.upsert_card(card.clone()).unwrap();
+        card
+    }
+
+    fn names(tc: &TestContext, card_id: Uuid) -> Vec<String> {
+        let card = tc.store.get_card(card_id).unwrap().unwrap();
+        card.attachments.into_iter().map(|a| a.name).collect()
+    }
+
+    fn add(tc: &TestContext, card_id: Uuid, name: &str, hash: &str) -> KanbanResult<()> {
+        AddAttachment {
+            card_id,
+            attachment: Attachment::new(name, 1, hash, Utc::now()),
+            position: None,
+        }
+        .execute(&tc.as_command_context())
+    }
+
+    #[test]
+    fn test_add_and_undo_attachment() {
+        let tc = TestContext::new();
+        let card = setup(&tc);
+        let cmd = Command::Attachment(AttachmentCommand::Add(AddAttachment {
+            card_id: card.id,
+            attachment: Attachment::new("shot.png", 1, "aa", Utc::now()),
+            position: None,
+        }));
+        let inverse = cmd.capture_inverse(&tc.store).unwrap();
+        cmd.execute(&tc.as_command_context()).unwrap();
+        assert_eq!(names(&tc, card.id), ["shot.png"]);
+        for inv in inverse {
+            inv.execute(&tc.as_command_context()).unwrap();
+        }
+        assert!(names(&tc, card.id).is_empty());
+    }
+
+    #[test]
+    fn test_remove_undo_restores_position() {
+        let tc = TestContext::new();
+        let card = setup(&tc);
+        add(&tc, card.id, "a.log", "aa").unwrap();
+        add(&tc, card.id, "b.log", "bb").unwrap();
+        add(&tc, card.id, "c.log", "cc").unwrap();
+        let stored = tc.store.get_card(card.id).unwrap().unwrap();
+        let cmd = Command::Attachment(AttachmentCommand::Remove(RemoveAttachment {
+            card_id: card.id,
+            attachment_id: stored.attachments[1].id,
+        }));
+        let inverse = cmd.capture_inverse(&tc.store).unwrap();
+        cmd.execute(&tc.as_command_context()).unwrap();
+        assert_eq!(names(&tc, card.id), ["a.log", "c.log"]);
+        for inv in inverse {
+            inv.execute(&tc.as_command_context()).unwrap();
+        }
+        let restored = tc.store.get_card(card.id).unwrap().unwrap();
+        assert_eq!(restored.attachments, stored.attachments);
+    }
+
+    #[test]
+    fn test_same_content_cannot_be_attached_twice() {
+        let tc = TestContext::new();
+        let card = setup(&tc);
+        add(&tc, card.id, "a.png", "aa").unwrap();
+        assert!(add(&tc, card.id, "copy.png", "aa").is_err());
+        assert!(add(&tc, card.id, " ", "bb").is_err());
+    }
+}
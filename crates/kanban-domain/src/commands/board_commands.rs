@@ -372,6 +372,7 @@ pub struct ImportEntities {
     pub cards: Vec<Card>,
     pub archived_cards: Vec<ArchivedCard>,
     pub sprints: Vec<Sprint>,
+    /// Edges merged into the existing graph; identical edges are skipped.
     pub graph: Option<DependencyGraph>,
 }
 
@@ -463,7 +464,9 @@ impl ImportEntities {
             context.store.upsert_sprint(s.clone())?;
         }
         if let Some(ref graph) = self.graph {
-            context.store.set_graph(graph.clone())?;
+            let mut merged = context.store.get_graph()?;
+            merged.merge(graph)?;
+            context.store.set_graph(merged)?;
         }
         Ok(())
     }
@@ -481,12 +484,11 @@ impl ImportEntities {
     pub fn capture_inverse(&self, _store: &dyn DataStore) -> KanbanResult<Vec<Command>> {
         let mut commands: Vec<Command> = Vec::new();
 
-        // Cards first.
-        if !self.cards.is_empty() {
-            commands.push(Command::Card(crate::commands::CardCommand::Archive(
-                crate::commands::ArchiveCards {
-                    ids: self.cards.iter().map(|c| c.id).collect(),
-                },
+        // Cards first, deleted rather than archived: an archived card
+        // would keep its column from being deleted below.
+        for c in &self.cards {
+            commands.push(Command::Card(crate::commands::CardCommand::Delete(
+                crate::commands::DeleteCard { card_id: c.id },
             )));
         }
 
@@ -510,7 +512,7 @@ impl ImportEntities {
         }
 
         // Columns: per-column delete (must be empty by the time we get
-        // here — cards above were archived first).
+        // here — cards above were deleted first).
         for c in &self.columns {
             commands.push(Command::Column(crate::commands::ColumnCommand::Delete(
                 crate::commands::DeleteColumn { column_id: c.id },
@@ -654,6 +656,27 @@ mod tests {
         assert_eq!(tc.store.list_all_cards().unwrap().len(), 1);
     }
 
+    #[test]
+    fn test_import_entities_merges_graph_into_existing_edges() {
+        let tc = TestContext::new();
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut existing = DependencyGraph::new();
+        existing.set_block(a, b).unwrap();
+        tc.store.set_graph(existing).unwrap();
+
+        let mut imported = DependencyGraph::new();
+        imported.relate(b, c).unwrap();
+        let cmd = ImportEntities {
+            graph: Some(imported),
+            ..Default::default()
+        };
+        cmd.execute(&tc.as_command_context()).unwrap();
+
+        let graph = tc.store.get_graph().unwrap();
+        assert_eq!(graph.blocked(a), vec![b]);
+        assert_eq!(graph.related(c), vec![b]);
+    }
+
     #[test]
     fn test_update_board_card_prefix_allowed_before_first_card_succeeds() {
         let tc = TestContext::new();
@@ -220,6 +220,9 @@ impl CreateCard {
             updated_at: now,
             completed_at: None,
             sprint_logs: Vec::new(),
+            checklist: Vec::new(),
+            attachments: Vec::new(),
+            activity: Vec::new(),
         };
 
         if board.card_counter <= self.card_number {
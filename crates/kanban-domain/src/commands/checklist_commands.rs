@@ -0,0 +1,362 @@
+use super::{Command, CommandContext};
+use crate::data_store::DataStore;
+use crate::{Card, ChecklistItem, KanbanError, KanbanResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Edits to the checklist kept inside a card.
+///
+/// Every variant rewrites the owning card, so the checklist persists
+/// wherever the card does. Inverses restore the exact captured item
+/// (including `created_at` / `completed_at`) rather than replaying a
+/// toggle, so undo round-trips timestamps.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum ChecklistCommand {
+    AddItem(AddChecklistItem),
+    UpdateItem(UpdateChecklistItem),
+    MoveItem(MoveChecklistItem),
+    RemoveItem(RemoveChecklistItem),
+}
+
+impl ChecklistCommand {
+    pub fn execute(&self, context: &CommandContext) -> KanbanResult<()> {
+        match self {
+            ChecklistCommand::AddItem(c) => c.execute(context),
+            ChecklistCommand::UpdateItem(c) => c.execute(context),
+            ChecklistCommand::MoveItem(c) => c.execute(context),
+            ChecklistCommand::RemoveItem(c) => c.execute(context),
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            ChecklistCommand::AddItem(c) => c.description(),
+            ChecklistCommand::UpdateItem(c) => c.description(),
+            ChecklistCommand::MoveItem(c) => c.description(),
+            ChecklistCommand::RemoveItem(c) => c.description(),
+        }
+    }
+
+    pub fn capture_inverse(&self, store: &dyn DataStore) -> KanbanResult<Vec<Command>> {
+        match self {
+            ChecklistCommand::AddItem(c) => c.capture_inverse(store),
+            ChecklistCommand::UpdateItem(c) => c.capture_inverse(store),
+            ChecklistCommand::MoveItem(c) => c.capture_inverse(store),
+            ChecklistCommand::RemoveItem(c) => c.capture_inverse(store),
+        }
+    }
+}
+
+/// Index of `item_id` in `card`'s checklist.
+fn item_index(card: &Card, item_id: Uuid) -> KanbanResult<usize> {
+    card.checklist
+        .iter()
+        .position(|item| item.id == item_id)
+        .ok_or_else(|| KanbanError::not_found("Checklist item", item_id))
+}
+
+/// Inverse shared by every command that changes an existing item:
+/// remove whatever is there now and put the captured item back.
+fn restore_item(card: &Card, item_id: Uuid) -> KanbanResult<Vec<Command>> {
+    let index = item_index(card, item_id)?;
+    Ok(vec![
+        Command::Checklist(ChecklistCommand::RemoveItem(RemoveChecklistItem {
+            card_id: card.id,
+            item_id,
+        })),
+        Command::Checklist(ChecklistCommand::AddItem(AddChecklistItem {
+            card_id: card.id,
+            item: card.checklist[index].clone(),
+            position: Some(index),
+        })),
+    ])
+}
+
+fn load_card(store: &dyn DataStore, card_id: Uuid) -> KanbanResult<Card> {
+    store
+        .get_card(card_id)?
+        .ok_or_else(|| KanbanError::not_found("Card", card_id))
+}
+
+/// Insert `item` at `position` (0-based), or append it when `None`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddChecklistItem {
+    pub card_id: Uuid,
+    pub item: ChecklistItem,
+    #[serde(default)]
+    pub position: Option<usize>,
+}
+
+impl AddChecklistItem {
+    pub fn execute(&self, context: &CommandContext) -> KanbanResult<()> {
+        let mut card = context.get_card(self.card_id)?;
+        if self.item.text.trim().is_empty() {
+            return Err(KanbanError::validation(
+                "Checklist item text cannot be empty",
+            ));
+        }
+        if card.checklist.iter().any(|item| item.id == self.item.id) {
+            return Err(KanbanError::validation(format!(
+                "Checklist item {} already exists",
+                self.item.id
+            )));
+        }
+        let position = self
+            .position
+            .unwrap_or(card.checklist.len())
+            .min(card.checklist.len());
+        card.checklist.insert(position, self.item.clone());
+        card.updated_at = Utc::now();
+        context.store.upsert_card(card)?;
+        Ok(())
+    }
+
+    pub fn description(&self) -> String {
+        format!("Add checklist item '{}'", self.item.text)
+    }
+
+    pub fn capture_inverse(&self, _store: &dyn DataStore) -> KanbanResult<Vec<Command>> {
+        Ok(vec![Command::Checklist(ChecklistCommand::RemoveItem(
+            RemoveChecklistItem {
+                card_id: self.card_id,
+                item_id: self.item.id,
+            },
+        ))])
+    }
+}
+
+/// Change an item's text and/or done state. Marking an item done stamps
+/// `completed_at` with `timestamp`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateChecklistItem {
+    pub card_id: Uuid,
+    pub item_id: Uuid,
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub done: Option<bool>,
+    #[serde(default = "chrono::Utc::now")]
+    pub timestamp: DateTime<Utc>,
+}
+
+impl UpdateChecklistItem {
+    pub fn execute(&self, context: &CommandContext) -> KanbanResult<()> {
+        let mut card = context.get_card(self.card_id)?;
+        let index = item_index(&card, self.item_id)?;
+        let item = &mut card.checklist[index];
+        if let Some(text) = &self.text {
+            if text.trim().is_empty() {
+                return Err(KanbanError::validation(
+                    "Checklist item text cannot be empty",
+                ));
+            }
+            item.text = text.clone();
+        }
+        if let Some(done) = self.done {
+            item.set_done(done, self.timestamp);
+        }
+        card.updated_at = self.timestamp;
+        context.store.upsert_card(card)?;
+        Ok(())
+    }
+
+    pub fn description(&self) -> String {
+        match self.done {
+            Some(true) => "Check checklist item".to_string(),
+            Some(false) => "Uncheck checklist item".to_string(),
+            None => "Edit checklist item".to_string(),
+        }
+    }
+
+    pub fn capture_inverse(&self, store: &dyn DataStore) -> KanbanResult<Vec<Command>> {
+        restore_item(&load_card(store, self.card_id)?, self.item_id)
+    }
+}
+
+/// Move an item to `position` (0-based, clamped to the list).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveChecklistItem {
+    pub card_id: Uuid,
+    pub item_id: Uuid,
+    pub position: usize,
+}
+
+impl MoveChecklistItem {
+    pub fn execute(&self, context: &CommandContext) -> KanbanResult<()> {
+        let mut card = context.get_card(self.card_id)?;
+        let index = item_index(&card, self.item_id)?;
+        let item = card.checklist.remove(index);
+        let position = self.position.min(card.checklist.len());
+        card.checklist.insert(position, item);
+        card.updated_at = Utc::now();
+        context.store.upsert_card(card)?;
+        Ok(())
+    }
+
+    pub fn description(&self) -> String {
+        format!("Move checklist item to position {}", self.position + 1)
+    }
+
+    pub fn capture_inverse(&self, store: &dyn DataStore) -> KanbanResult<Vec<Command>> {
+        let card = load_card(store, self.card_id)?;
+        Ok(vec![Command::Checklist(ChecklistCommand::MoveItem(
+            MoveChecklistItem {
+                card_id: self.card_id,
+                item_id: self.item_id,
+                position: item_index(&card, self.item_id)?,
+            },
+        ))])
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveChecklistItem {
+    pub card_id: Uuid,
+    pub item_id: Uuid,
+}
+
+impl RemoveChecklistItem {
+    pub fn execute(&self, context: &CommandContext) -> KanbanResult<()> {
+        let mut card = context.get_card(self.card_id)?;
+        let index = item_index(&card, self.item_id)?;
+        card.checklist.remove(index);
+        card.updated_at = Utc::now();
+        context.store.upsert_card(card)?;
+        Ok(())
+    }
+
+    pub fn description(&self) -> String {
+        "Remove checklist item".to_string()
+    }
+
+    pub fn capture_inverse(&self, store: &dyn DataStore) -> KanbanResult<Vec<Command>> {
+        let card = load_card(store, self.card_id)?;
+        let index = item_index(&card, self.item_id)?;
+        Ok(vec![Command::Checklist(ChecklistCommand::AddItem(
+            AddChecklistItem {
+                card_id: self.card_id,
+                item: card.checklist[index].clone(),
+                position: Some(index),
+            },
+        ))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_helpers::TestContext;
+    use super::*;
+    use crate::DataStore;
+
+    fn setup(tc: &TestContext, texts: &[&str]) -> Card {
+        let mut board = crate::Board::new("B", None::<String>);
+        let mut card = Card::new(&mut board, Uuid::new_v4(), "C", 0);
+        card.checklist = texts
+            .iter()
+            .map(|text| ChecklistItem::new(*text, Utc::now()))
+            .collect();
+        tc.store.upsert_card(card.clone()).unwrap();
+        card
+    }
+
+    fn texts(tc: &TestContext, card_id: Uuid) -> Vec<String> {
+        let card = tc.store.get_card(card_id).unwrap().unwrap();
+        card.checklist.into_iter().map(|item| item.text).collect()
+    }
+
+    #[test]
+    fn test_add_item_at_position_and_undo() {
+        let tc = TestContext::new();
+        let card = setup(&tc, &["a", "c"]);
+        let cmd = Command::Checklist(ChecklistCommand::AddItem(AddChecklistItem {
+            card_id: card.id,
+            item: ChecklistItem::new("b", Utc::now()),
+            position: Some(1),
+        }));
+        let inverse = cmd.capture_inverse(&tc.store).unwrap();
+        cmd.execute(&tc.as_command_context()).unwrap();
+        assert_eq!(texts(&tc, card.id), ["a", "b", "c"]);
+        for inv in inverse {
+            inv.execute(&tc.as_command_context()).unwrap();
+        }
+        assert_eq!(texts(&tc, card.id), ["a", "c"]);
+    }
+
+    #[test]
+    fn test_update_item_undo_restores_exact_item() {
+        let tc = TestContext::new();
+        let card = setup(&tc, &["a", "b"]);
+        let before = card.checklist[1].clone();
+        let cmd = Command::Checklist(ChecklistCommand::UpdateItem(UpdateChecklistItem {
+            card_id: card.id,
+            item_id: before.id,
+            text: Some("B".into()),
+            done: Some(true),
+            timestamp: Utc::now(),
+        }));
+        let inverse = cmd.capture_inverse(&tc.store).unwrap();
+        cmd.execute(&tc.as_command_context()).unwrap();
+        let updated = tc.store.get_card(card.id).unwrap().unwrap();
+        assert!(updated.checklist[1].done);
+        assert!(updated.checklist[1].completed_at.is_some());
+        assert_eq!(updated.checklist[1].text, "B");
+        for inv in inverse {
+            inv.execute(&tc.as_command_context()).unwrap();
+        }
+        let restored = tc.store.get_card(card.id).unwrap().unwrap();
+        assert_eq!(restored.checklist[1], before);
+    }
+
+    #[test]
+    fn test_move_and_remove_items() {
+        let tc = TestContext::new();
+        let card = setup(&tc, &["a", "b", "c"]);
+        MoveChecklistItem {
+            card_id: card.id,
+            item_id: card.checklist[0].id,
+            position: 9,
+        }
+        .execute(&tc.as_command_context())
+        .unwrap();
+        assert_eq!(texts(&tc, card.id), ["b", "c", "a"]);
+
+        let cmd = Command::Checklist(ChecklistCommand::RemoveItem(RemoveChecklistItem {
+            card_id: card.id,
+            item_id: card.checklist[1].id,
+        }));
+        let inverse = cmd.capture_inverse(&tc.store).unwrap();
+        cmd.execute(&tc.as_command_context()).unwrap();
+        assert_eq!(texts(&tc, card.id), ["c", "a"]);
+        for inv in inverse {
+            inv.execute(&tc.as_command_context()).unwrap();
+        }
+        assert_eq!(texts(&tc, card.id), ["b", "c", "a"]);
+    }
+
+    #[test]
+    fn test_unknown_item_is_not_found() {
+        let tc = TestContext::new();
+        let card = setup(&tc, &["a"]);
+        let result = RemoveChecklistItem {
+            card_id: card.id,
+            item_id: Uuid::new_v4(),
+        }
+        .execute(&tc.as_command_context());
+        assert!(result.unwrap_err().is_not_found());
+    }
+
+    #[test]
+    fn test_add_empty_item_is_rejected() {
+        let tc = TestContext::new();
+        let card = setup(&tc, &[]);
+        let result = AddChecklistItem {
+            card_id: card.id,
+            item: ChecklistItem::new("  ", Utc::now()),
+            position: None,
+        }
+        .execute(&tc.as_command_context());
+        assert!(result.is_err());
+    }
+}
@@ -1,18 +1,24 @@
 use crate::data_store::DataStore;
-use crate::{DomainError, KanbanError, KanbanResult};
+use crate::{Attachment, DomainError, KanbanError, KanbanResult};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+pub mod activity_commands;
+pub mod attachment_commands;
 pub mod board_commands;
 pub mod card_commands;
 pub mod cascade_commands;
+pub mod checklist_commands;
 pub mod column_commands;
 pub mod dependency_commands;
 pub mod sprint_commands;
 
+pub use activity_commands::*;
+pub use attachment_commands::*;
 pub use board_commands::*;
 pub use card_commands::*;
 pub use cascade_commands::{CascadeCommand, SetArchivedCardsSprint};
+pub use checklist_commands::*;
 pub use column_commands::*;
 pub use dependency_commands::*;
 pub use sprint_commands::*;
@@ -27,6 +33,9 @@ pub enum Command {
     Sprint(SprintCommand),
     Dependency(DependencyCommand),
     Cascade(CascadeCommand),
+    Checklist(ChecklistCommand),
+    Attachment(AttachmentCommand),
+    Activity(ActivityCommand),
 }
 
 impl Command {
@@ -38,6 +47,9 @@ impl Command {
             Command::Sprint(cmd) => cmd.execute(context),
             Command::Dependency(cmd) => cmd.execute(context),
             Command::Cascade(cmd) => cmd.execute(context),
+            Command::Checklist(cmd) => cmd.execute(context),
+            Command::Attachment(cmd) => cmd.execute(context),
+            Command::Activity(cmd) => cmd.execute(context),
         }
     }
 
@@ -49,6 +61,71 @@ impl Command {
             Command::Sprint(cmd) => cmd.description(),
             Command::Dependency(cmd) => cmd.description(),
             Command::Cascade(cmd) => cmd.description(),
+            Command::Checklist(cmd) => cmd.description(),
+            Command::Attachment(cmd) => cmd.description(),
+            Command::Activity(cmd) => cmd.description(),
+        }
+    }
+
+    /// Cards this command names directly. Board, column and sprint
+    /// commands and cascades return nothing, even when they reach cards.
+    pub fn card_ids(&self) -> Vec<Uuid> {
+        match self {
+            Command::Card(cmd) => match cmd {
+                CardCommand::Create(c) => vec![c.id],
+                CardCommand::Update(c) => vec![c.card_id],
+                CardCommand::Move(c) => vec![c.card_id],
+                CardCommand::Restore(c) => vec![c.card_id],
+                CardCommand::Delete(c) => vec![c.card_id],
+                CardCommand::Archive(c) => c.ids.clone(),
+                CardCommand::AssignToSprint(c) => c.ids.clone(),
+                CardCommand::UnassignFromSprint(c) => vec![c.card_id],
+                CardCommand::ApplyMetadata(c) => vec![c.card_id],
+                CardCommand::RestoreSprintAttachment(c) => vec![c.card_id],
+                CardCommand::CompactPositions(_) => Vec::new(),
+            },
+            Command::Dependency(cmd) => match cmd {
+                DependencyCommand::AddSpawns(c) => vec![c.source, c.target],
+                DependencyCommand::AddBlocks(c) => vec![c.source, c.target],
+                DependencyCommand::AddRelates(c) => vec![c.source, c.target],
+                DependencyCommand::RemoveSpawns(c) => vec![c.source, c.target],
+                DependencyCommand::RemoveBlocks(c) => vec![c.source, c.target],
+                DependencyCommand::RemoveRelates(c) => vec![c.source, c.target],
+                DependencyCommand::CreateSubcard(c) => vec![c.id, c.parent_id],
+            },
+            Command::Checklist(cmd) => vec![match cmd {
+                ChecklistCommand::AddItem(c) => c.card_id,
+                ChecklistCommand::UpdateItem(c) => c.card_id,
+                ChecklistCommand::MoveItem(c) => c.card_id,
+                ChecklistCommand::RemoveItem(c) => c.card_id,
+            }],
+            Command::Attachment(cmd) => vec![match cmd {
+                AttachmentCommand::Add(c) => c.card_id,
+                AttachmentCommand::Remove(c) => c.card_id,
+            }],
+            Command::Activity(cmd) => vec![match cmd {
+                ActivityCommand::Record(c) => c.card_id,
+                ActivityCommand::Remove(c) => c.card_id,
+            }],
+            Command::Board(_) | Command::Column(_) | Command::Sprint(_) | Command::Cascade(_) => {
+                Vec::new()
+            }
+        }
+    }
+
+    /// Attachments the command would put back on a card: the one it adds,
+    /// or those on the cards an import re-inserts. Their stored content
+    /// must outlive the command.
+    pub fn attachments(&self) -> Vec<&Attachment> {
+        match self {
+            Command::Attachment(AttachmentCommand::Add(c)) => vec![&c.attachment],
+            Command::Board(BoardCommand::Import(c)) => c
+                .cards
+                .iter()
+                .chain(c.archived_cards.iter().map(|ac| &ac.card))
+                .flat_map(|card| &card.attachments)
+                .collect(),
+            _ => Vec::new(),
         }
     }
 
@@ -66,6 +143,9 @@ impl Command {
             Command::Sprint(cmd) => cmd.capture_inverse(store),
             Command::Dependency(cmd) => cmd.capture_inverse(store),
             Command::Cascade(cmd) => cmd.capture_inverse(store),
+            Command::Checklist(cmd) => cmd.capture_inverse(store),
+            Command::Attachment(cmd) => cmd.capture_inverse(store),
+            Command::Activity(cmd) => cmd.capture_inverse(store),
         }
     }
 }
@@ -815,6 +815,9 @@ mod tests {
             updated_at: Utc::now(),
             completed_at: None,
             sprint_logs: Vec::new(),
+            checklist: Vec::new(),
+            attachments: Vec::new(),
+            activity: Vec::new(),
         };
         let archived = crate::ArchivedCard::new(card, col.id, 0);
         tc.store.insert_archived_card(archived).unwrap();
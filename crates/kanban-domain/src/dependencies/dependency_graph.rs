@@ -1,7 +1,8 @@
 use kanban_core::{
-    Cascadable, DagGraph, Directed, EdgeSet, GraphError, Undirected, UndirectedGraph,
+    Cascadable, DagGraph, Directed, EdgeBase, EdgeSet, GraphError, Undirected, UndirectedGraph,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 use super::edges::{BlocksEdge, RelatesEdge, SpawnsEdge};
@@ -311,6 +312,85 @@ impl DependencyGraph {
         }
         Ok(graph)
     }
+
+    /// Copy of the graph keeping only the edges (active and archived)
+    /// for which `keep(source, target)` holds. Exports use this to
+    /// scope the graph to the cards of one board.
+    pub fn filter_edges<F>(&self, keep: F) -> Self
+    where
+        F: Fn(Uuid, Uuid) -> bool,
+    {
+        let spawns = self
+            .spawns_edges()
+            .iter()
+            .filter(|e| keep(e.base.source, e.base.target));
+        let blocks = self
+            .blocks_edges()
+            .iter()
+            .filter(|e| keep(e.base.source, e.base.target));
+        let relates = self
+            .relates_edges()
+            .iter()
+            .filter(|e| keep(e.base.source, e.base.target));
+        Self::from_validated_per_kind_edges(
+            spawns.cloned().collect(),
+            blocks.cloned().collect(),
+            relates.cloned().collect(),
+        )
+        .expect("a subset of a valid graph is valid")
+    }
+
+    /// Copy of the graph with every endpoint renamed through `ids`.
+    /// Edges with an endpoint missing from `ids` are dropped. `ids` must
+    /// be injective, as when every card is given a fresh id.
+    pub fn remap_nodes(&self, ids: &HashMap<Uuid, Uuid>) -> Self {
+        fn remap<E: Clone>(
+            edges: &[E],
+            base: fn(&mut E) -> &mut EdgeBase,
+            ids: &HashMap<Uuid, Uuid>,
+        ) -> Vec<E> {
+            edges
+                .iter()
+                .filter_map(|edge| {
+                    let mut edge = edge.clone();
+                    let b = base(&mut edge);
+                    b.source = *ids.get(&b.source)?;
+                    b.target = *ids.get(&b.target)?;
+                    Some(edge)
+                })
+                .collect()
+        }
+        Self::from_validated_per_kind_edges(
+            remap(self.spawns_edges(), |e| &mut e.base, ids),
+            remap(self.blocks_edges(), |e| &mut e.base, ids),
+            remap(self.relates_edges(), |e| &mut e.base, ids),
+        )
+        .expect("renaming nodes one-to-one keeps the graph valid")
+    }
+
+    /// Add every edge of `other` that is not already present, keeping
+    /// its metadata and archive state. Identical edges are skipped so
+    /// merging overlapping exports is idempotent; an edge that would
+    /// break an invariant of the combined graph fails the merge.
+    pub fn merge(&mut self, other: &Self) -> KanbanResult<()> {
+        let mut merged = Self::from_validated_per_kind_edges(
+            union_edges(self.spawns_edges(), other.spawns_edges()),
+            union_edges(self.blocks_edges(), other.blocks_edges()),
+            union_edges(self.relates_edges(), other.relates_edges()),
+        )?;
+        std::mem::swap(self, &mut merged);
+        Ok(())
+    }
+}
+
+fn union_edges<E: Clone + PartialEq>(ours: &[E], theirs: &[E]) -> Vec<E> {
+    let mut out = ours.to_vec();
+    for edge in theirs {
+        if !out.contains(edge) {
+            out.push(edge.clone());
+        }
+    }
+    out
 }
 
 /// Wrap a structural `GraphError` from a load path with the kind tag
@@ -719,4 +799,46 @@ mod tests {
         assert_eq!(g.children(a), vec![b]);
         assert_eq!(g.blocked(b), vec![c]);
     }
+
+    // --- Export helpers ---
+
+    #[test]
+    fn test_filter_edges_keeps_archived_edges_and_metadata() {
+        let (a, b, c) = ids();
+        let mut g = DependencyGraph::new();
+        g.set_block_with_severity(a, b, super::super::Severity::High)
+            .unwrap();
+        g.relate(b, c).unwrap();
+        g.archive_node(a);
+
+        let scoped = g.filter_edges(|s, t| s != c && t != c);
+        assert_eq!(scoped.blocks_edges(), g.blocks_edges());
+        assert!(scoped.relates_edges().is_empty());
+        assert_eq!(scoped.active_len(), 0);
+    }
+
+    #[test]
+    fn test_merge_skips_identical_edges() {
+        let (a, b, c) = ids();
+        let mut g = DependencyGraph::new();
+        g.set_parent(b, a).unwrap();
+        let mut other = g.clone();
+        other.relate(a, c).unwrap();
+
+        g.merge(&other).unwrap();
+        g.merge(&other).unwrap();
+        assert_eq!(g, other);
+    }
+
+    #[test]
+    fn test_merge_rejects_cycle_across_graphs() {
+        let (a, b, _) = ids();
+        let mut g = DependencyGraph::new();
+        g.set_block(a, b).unwrap();
+        let mut other = DependencyGraph::new();
+        other.set_block(b, a).unwrap();
+
+        assert!(g.merge(&other).is_err());
+        assert_eq!(g.blocks_edges().len(), 1);
+    }
 }
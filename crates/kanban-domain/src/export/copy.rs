@@ -0,0 +1,378 @@
+//! Copying boards under fresh ids.
+//!
+//! Importing the same export twice, or forking a board inside one store,
+//! needs every board, column, card, sprint and edge to get a new id so the
+//! copy can never collide with the original.
+
+use crate::{KanbanError, KanbanResult, Snapshot};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// What [`BoardCopier::clone_board`] copies besides the board's columns.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CloneOptions {
+    /// Copy the board's active cards and the edges between them.
+    #[serde(default)]
+    pub include_cards: bool,
+    /// Copy the board's sprints; otherwise the copy starts sprint
+    /// numbering from scratch.
+    #[serde(default)]
+    pub include_sprints: bool,
+}
+
+/// Produces copies of boards that can be imported next to the originals.
+pub struct BoardCopier;
+
+impl BoardCopier {
+    /// Give every board, column, card, archived card and sprint in
+    /// `snapshot` a fresh id and rewrite all references to them, including
+    /// sprint logs and graph edges.
+    ///
+    /// References to entities outside the snapshot are dropped: a card's
+    /// sprint becomes unset, its logs for that sprint are removed and edges
+    /// to outside cards are left out. Cards, active and archived, are
+    /// renumbered from 1 per board in their original order, and each
+    /// board's counters continue after the highest card and sprint number.
+    pub fn remap_ids(snapshot: Snapshot) -> Snapshot {
+        let Snapshot {
+            mut boards,
+            mut columns,
+            mut cards,
+            mut archived_cards,
+            mut sprints,
+            graph,
+        } = snapshot;
+
+        let fresh = |ids: &mut dyn Iterator<Item = Uuid>| -> HashMap<Uuid, Uuid> {
+            ids.map(|id| (id, Uuid::new_v4())).collect()
+        };
+        let board_ids = fresh(&mut boards.iter().map(|b| b.id));
+        let column_ids = fresh(&mut columns.iter().map(|c| c.id));
+        let sprint_ids = fresh(&mut sprints.iter().map(|s| s.id));
+        let card_ids = fresh(
+            &mut cards
+                .iter()
+                .map(|c| c.id)
+                .chain(archived_cards.iter().map(|ac| ac.card.id)),
+        );
+
+        let board_of_column: HashMap<Uuid, Uuid> =
+            columns.iter().map(|c| (c.id, c.board_id)).collect();
+
+        // Renumber before the column ids change so cards can be grouped by
+        // their original board.
+        let mut by_board: HashMap<Uuid, Vec<&mut crate::Card>> = HashMap::new();
+        for card in cards.iter_mut() {
+            if let Some(&board_id) = board_of_column.get(&card.column_id) {
+                by_board.entry(board_id).or_default().push(card);
+            }
+        }
+        for archived in archived_cards.iter_mut() {
+            if let Some(&board_id) = board_of_column.get(&archived.original_column_id) {
+                by_board
+                    .entry(board_id)
+                    .or_default()
+                    .push(&mut archived.card);
+            }
+        }
+        let mut card_counters: HashMap<Uuid, u32> = HashMap::new();
+        for (board_id, mut board_cards) in by_board {
+            board_cards.sort_by_key(|c| c.card_number);
+            for (card, number) in board_cards.iter_mut().zip(1u32..) {
+                card.card_number = number;
+            }
+            card_counters.insert(board_id, board_cards.len() as u32 + 1);
+        }
+
+        for board in &mut boards {
+            let board_sprints: Vec<_> = sprints.iter().filter(|s| s.board_id == board.id).collect();
+            if board_sprints.is_empty() {
+                board.sprint_counters.clear();
+                board.next_sprint_number = 1;
+                board.sprint_name_used_count = 0;
+            } else {
+                let next = board_sprints
+                    .iter()
+                    .map(|s| s.sprint_number)
+                    .max()
+                    .unwrap_or(0)
+                    + 1;
+                board.next_sprint_number = board.next_sprint_number.max(next);
+            }
+            board.card_counter = card_counters.get(&board.id).copied().unwrap_or(1);
+            board.active_sprint_id = board
+                .active_sprint_id
+                .and_then(|id| sprint_ids.get(&id).copied());
+            board.completion_column_id = board
+                .completion_column_id
+                .and_then(|id| column_ids.get(&id).copied());
+            board.id = board_ids[&board.id];
+        }
+
+        for column in &mut columns {
+            column.id = column_ids[&column.id];
+            if let Some(&id) = board_ids.get(&column.board_id) {
+                column.board_id = id;
+            }
+        }
+
+        for sprint in &mut sprints {
+            sprint.id = sprint_ids[&sprint.id];
+            if let Some(&id) = board_ids.get(&sprint.board_id) {
+                sprint.board_id = id;
+            }
+        }
+
+        let remap_card = |card: &mut crate::Card| {
+            card.id = card_ids[&card.id];
+            if let Some(&id) = column_ids.get(&card.column_id) {
+                card.column_id = id;
+            }
+            card.sprint_id = card.sprint_id.and_then(|id| sprint_ids.get(&id).copied());
+            card.sprint_logs
+                .retain(|log| sprint_ids.contains_key(&log.sprint_id));
+            for log in &mut card.sprint_logs {
+                log.sprint_id = sprint_ids[&log.sprint_id];
+            }
+        };
+        for card in &mut cards {
+            remap_card(card);
+        }
+        for archived in &mut archived_cards {
+            remap_card(&mut archived.card);
+            if let Some(&id) = column_ids.get(&archived.original_column_id) {
+                archived.original_column_id = id;
+            }
+        }
+
+        Snapshot {
+            boards,
+            columns,
+            cards,
+            archived_cards,
+            sprints,
+            graph: graph.remap_nodes(&card_ids),
+        }
+    }
+
+    /// A copy of board `board_id` from `source`, named `name` and placed
+    /// after the existing boards, ready to import into the same store.
+    ///
+    /// Columns are always copied; cards (without archived cards) and
+    /// sprints only when `options` asks for them.
+    pub fn clone_board(
+        source: &Snapshot,
+        board_id: Uuid,
+        name: &str,
+        options: CloneOptions,
+    ) -> KanbanResult<Snapshot> {
+        let mut board = source
+            .boards
+            .iter()
+            .find(|b| b.id == board_id)
+            .cloned()
+            .ok_or_else(|| KanbanError::not_found("Board", board_id))?;
+        let now = Utc::now();
+        board.name = name.to_string();
+        board.position = source
+            .boards
+            .iter()
+            .map(|b| b.position)
+            .max()
+            .map_or(0, |p| p + 1);
+        board.created_at = now;
+        board.updated_at = now;
+
+        let columns: Vec<_> = source
+            .columns
+            .iter()
+            .filter(|c| c.board_id == board_id)
+            .cloned()
+            .collect();
+        let cards: Vec<_> = if options.include_cards {
+            source
+                .cards
+                .iter()
+                .filter(|card| columns.iter().any(|c| c.id == card.column_id))
+                .cloned()
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let sprints: Vec<_> = if options.include_sprints {
+            source
+                .sprints
+                .iter()
+                .filter(|s| s.board_id == board_id)
+                .cloned()
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let graph = source.graph.filter_edges(|s, t| {
+            cards.iter().any(|c| c.id == s) && cards.iter().any(|c| c.id == t)
+        });
+
+        Ok(Self::remap_ids(Snapshot {
+            boards: vec![board],
+            columns,
+            cards,
+            archived_cards: Vec::new(),
+            sprints,
+            graph,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ArchivedCard, Board, Card, Column, DependencyGraph, Sprint, SprintLog};
+
+    fn source() -> Snapshot {
+        let mut board = Board::new("Source", None::<String>);
+        let todo = Column::new(board.id, "Todo", 0);
+        let done = Column::new(board.id, "Done", 1);
+        board.completion_column_id = Some(done.id);
+        let sprint = Sprint::new(
+            board.id,
+            board.allocate_sprint_number(),
+            None,
+            None::<String>,
+        );
+        board.active_sprint_id = Some(sprint.id);
+
+        let _deleted = Card::new(&mut board, todo.id, "Deleted", 0);
+        let mut a = Card::new(&mut board, todo.id, "A", 0);
+        a.sprint_id = Some(sprint.id);
+        a.sprint_logs.push(SprintLog::new(
+            sprint.id,
+            sprint.sprint_number,
+            None::<String>,
+            "active",
+        ));
+        let b = Card::new(&mut board, done.id, "B", 0);
+        let archived = ArchivedCard::new(Card::new(&mut board, todo.id, "Old", 1), todo.id, 1);
+
+        let mut graph = DependencyGraph::new();
+        graph.set_block(a.id, b.id).unwrap();
+        graph.relate(b.id, archived.card.id).unwrap();
+        graph.archive_node(archived.card.id);
+
+        Snapshot {
+            boards: vec![board],
+            columns: vec![todo, done],
+            cards: vec![a, b],
+            archived_cards: vec![archived],
+            sprints: vec![sprint],
+            graph,
+        }
+    }
+
+    fn all_ids(s: &Snapshot) -> Vec<Uuid> {
+        s.boards
+            .iter()
+            .map(|b| b.id)
+            .chain(s.columns.iter().map(|c| c.id))
+            .chain(s.cards.iter().map(|c| c.id))
+            .chain(s.archived_cards.iter().map(|ac| ac.card.id))
+            .chain(s.sprints.iter().map(|sp| sp.id))
+            .collect()
+    }
+
+    #[test]
+    fn test_remap_ids_replaces_every_id_and_keeps_references() {
+        let original = source();
+        let copy = BoardCopier::remap_ids(original.clone());
+
+        let old_ids = all_ids(&original);
+        assert!(all_ids(&copy).iter().all(|id| !old_ids.contains(id)));
+
+        let board = &copy.boards[0];
+        let (todo, done) = (&copy.columns[0], &copy.columns[1]);
+        let (a, b) = (&copy.cards[0], &copy.cards[1]);
+        let sprint = &copy.sprints[0];
+        assert!(copy.columns.iter().all(|c| c.board_id == board.id));
+        assert_eq!(board.completion_column_id, Some(done.id));
+        assert_eq!(board.active_sprint_id, Some(sprint.id));
+        assert_eq!(sprint.board_id, board.id);
+        assert_eq!((a.column_id, b.column_id), (todo.id, done.id));
+        assert_eq!(a.sprint_id, Some(sprint.id));
+        assert_eq!(a.sprint_logs[0].sprint_id, sprint.id);
+        assert_eq!(copy.archived_cards[0].original_column_id, todo.id);
+
+        assert_eq!(copy.graph.blocked(a.id), vec![b.id]);
+        assert_eq!(copy.graph.len(), 2);
+        assert_eq!(copy.graph.active_len(), 1);
+    }
+
+    #[test]
+    fn test_remap_ids_renumbers_cards_and_counters() {
+        let original = source();
+        assert_eq!(original.cards[0].card_number, 2);
+
+        let copy = BoardCopier::remap_ids(original);
+        let numbers: Vec<u32> = copy
+            .cards
+            .iter()
+            .map(|c| c.card_number)
+            .chain(copy.archived_cards.iter().map(|ac| ac.card.card_number))
+            .collect();
+        assert_eq!(numbers, vec![1, 2, 3]);
+        assert_eq!(copy.boards[0].card_counter, 4);
+        assert_eq!(copy.boards[0].next_sprint_number, 2);
+    }
+
+    #[test]
+    fn test_remap_ids_twice_yields_distinct_copies() {
+        let original = source();
+        let first = BoardCopier::remap_ids(original.clone());
+        let second = BoardCopier::remap_ids(original);
+        let first_ids = all_ids(&first);
+        assert!(all_ids(&second).iter().all(|id| !first_ids.contains(id)));
+    }
+
+    #[test]
+    fn test_clone_board_structure_only() {
+        let original = source();
+        let board_id = original.boards[0].id;
+        let copy =
+            BoardCopier::clone_board(&original, board_id, "Fork", CloneOptions::default()).unwrap();
+
+        let board = &copy.boards[0];
+        assert_eq!(board.name, "Fork");
+        assert_eq!(board.position, original.boards[0].position + 1);
+        assert_eq!(copy.columns.len(), 2);
+        assert!(copy.cards.is_empty() && copy.archived_cards.is_empty());
+        assert!(copy.sprints.is_empty() && copy.graph.is_empty());
+        assert_eq!(board.card_counter, 1);
+        assert_eq!(board.next_sprint_number, 1);
+        assert_eq!(board.active_sprint_id, None);
+    }
+
+    #[test]
+    fn test_clone_board_with_cards_without_sprints_clears_sprint_refs() {
+        let original = source();
+        let board_id = original.boards[0].id;
+        let options = CloneOptions {
+            include_cards: true,
+            include_sprints: false,
+        };
+        let copy = BoardCopier::clone_board(&original, board_id, "Fork", options).unwrap();
+
+        assert_eq!(copy.cards.len(), 2);
+        assert!(copy.cards.iter().all(|c| c.sprint_id.is_none()));
+        assert!(copy.cards.iter().all(|c| c.sprint_logs.is_empty()));
+        assert_eq!(copy.graph.len(), 1, "edge to the archived card is dropped");
+        assert_eq!(copy.boards[0].card_counter, 3);
+    }
+
+    #[test]
+    fn test_clone_board_unknown_board_is_not_found() {
+        let result =
+            BoardCopier::clone_board(&source(), Uuid::new_v4(), "Fork", CloneOptions::default());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,866 @@
+//! CSV export and import of cards.
+//!
+//! Export writes one row per card. Import matches rows back to cards by
+//! identifier (e.g. `KAN-5`) and turns the edited cells into commands, so
+//! a spreadsheet round trip becomes a single undoable batch. Rows without
+//! an identifier create new cards.
+
+use super::models::DefaultPrefixes;
+use crate::card_lifecycle::{target_column_for_status, target_status_for_column_move};
+use crate::commands::{
+    AssignCardsToSprint, CardCommand, Command, CreateCard, MoveCard, UnassignCardFromSprint,
+    UpdateCard,
+};
+use crate::{
+    Board, Card, CardPriority, CardStatus, CardUpdate, Column, CreateCardOptions, FieldUpdate,
+    KanbanError, KanbanResult, Snapshot, Sprint,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Card fields a CSV column can hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CsvField {
+    Board,
+    Identifier,
+    Title,
+    Column,
+    Status,
+    Priority,
+    Points,
+    Sprint,
+    DueDate,
+    Description,
+}
+
+impl CsvField {
+    /// Columns written by [`CsvExporter`], in order.
+    pub const ALL: [CsvField; 10] = [
+        CsvField::Board,
+        CsvField::Identifier,
+        CsvField::Title,
+        CsvField::Column,
+        CsvField::Status,
+        CsvField::Priority,
+        CsvField::Points,
+        CsvField::Sprint,
+        CsvField::DueDate,
+        CsvField::Description,
+    ];
+
+    /// Header written on export.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Board => "board",
+            Self::Identifier => "identifier",
+            Self::Title => "title",
+            Self::Column => "column",
+            Self::Status => "status",
+            Self::Priority => "priority",
+            Self::Points => "points",
+            Self::Sprint => "sprint",
+            Self::DueDate => "due_date",
+            Self::Description => "description",
+        }
+    }
+
+    /// Field for a header, accepting the export names and a few common
+    /// spreadsheet spellings. Case, spaces and dashes are ignored.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let key: String = name
+            .trim()
+            .to_lowercase()
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '_' | '-'))
+            .collect();
+        match key.as_str() {
+            "board" => Some(Self::Board),
+            "identifier" | "id" | "key" | "card" => Some(Self::Identifier),
+            "title" | "name" | "summary" => Some(Self::Title),
+            "column" | "list" | "lane" => Some(Self::Column),
+            "status" | "state" => Some(Self::Status),
+            "priority" => Some(Self::Priority),
+            "points" | "storypoints" | "estimate" => Some(Self::Points),
+            "sprint" | "iteration" => Some(Self::Sprint),
+            "duedate" | "due" => Some(Self::DueDate),
+            "description" | "notes" | "body" => Some(Self::Description),
+            _ => None,
+        }
+    }
+}
+
+/// How CSV headers map to card fields on import.
+///
+/// Headers are matched with [`CsvField::from_name`] unless overridden.
+/// Unknown headers are ignored.
+#[derive(Debug, Clone, Default)]
+pub struct CsvMapping {
+    overrides: Vec<(String, Option<CsvField>)>,
+}
+
+impl CsvMapping {
+    /// Read `header` into `field`, or ignore it when `field` is `None`.
+    pub fn map(mut self, header: impl Into<String>, field: Option<CsvField>) -> Self {
+        self.overrides.push((header.into(), field));
+        self
+    }
+
+    /// Parse a `HEADER=FIELD` override; `HEADER=-` ignores the column.
+    pub fn parse_entry(self, entry: &str) -> KanbanResult<Self> {
+        let (header, field) = entry.split_once('=').ok_or_else(|| {
+            KanbanError::validation(format!(
+                "Invalid mapping '{}': expected HEADER=FIELD",
+                entry
+            ))
+        })?;
+        let field = match field.trim() {
+            "-" => None,
+            name => Some(CsvField::from_name(name).ok_or_else(|| {
+                KanbanError::validation(format!(
+                    "Unknown card field '{}'; expected one of: {}",
+                    name,
+                    CsvField::ALL.map(CsvField::name).join(", ")
+                ))
+            })?),
+        };
+        Ok(self.map(header.trim(), field))
+    }
+
+    fn resolve(&self, header: &str) -> Option<CsvField> {
+        self.overrides
+            .iter()
+            .rev()
+            .find(|(h, _)| h.trim().eq_ignore_ascii_case(header.trim()))
+            .map(|(_, field)| *field)
+            .unwrap_or_else(|| CsvField::from_name(header))
+    }
+}
+
+/// Writes cards as CSV.
+pub struct CsvExporter;
+
+impl CsvExporter {
+    /// One row per card on `boards`, ordered by board, column and position,
+    /// under a header row of [`CsvField::ALL`].
+    pub fn export_cards(
+        boards: &[Board],
+        columns: &[Column],
+        cards: &[Card],
+        sprints: &[Sprint],
+        prefixes: DefaultPrefixes,
+    ) -> String {
+        let mut out = String::new();
+        write_row(&mut out, CsvField::ALL.iter().map(|f| f.name().to_string()));
+
+        for board in boards {
+            let mut board_columns: Vec<&Column> =
+                columns.iter().filter(|c| c.board_id == board.id).collect();
+            board_columns.sort_by_key(|c| c.position);
+            for column in board_columns {
+                let mut column_cards: Vec<&Card> =
+                    cards.iter().filter(|c| c.column_id == column.id).collect();
+                column_cards.sort_by_key(|c| c.position);
+                for card in column_cards {
+                    let sprint = card
+                        .sprint_id
+                        .and_then(|id| sprints.iter().find(|s| s.id == id))
+                        .map(|s| s.formatted_name(board, prefixes.sprint))
+                        .unwrap_or_default();
+                    write_row(
+                        &mut out,
+                        CsvField::ALL.iter().map(|field| match field {
+                            CsvField::Board => board.name.clone(),
+                            CsvField::Identifier => card.identifier(board, sprints, prefixes.card),
+                            CsvField::Title => card.title.clone(),
+                            CsvField::Column => column.name.clone(),
+                            CsvField::Status => card.status.to_string(),
+                            CsvField::Priority => card.priority.to_string(),
+                            CsvField::Points => {
+                                card.points.map(|p| p.to_string()).unwrap_or_default()
+                            }
+                            CsvField::Sprint => sprint.clone(),
+                            CsvField::DueDate => card
+                                .due_date
+                                .map(|d| d.format("%Y-%m-%d").to_string())
+                                .unwrap_or_default(),
+                            CsvField::Description => card.description.clone().unwrap_or_default(),
+                        }),
+                    );
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Commands produced from a CSV file, plus what they amount to.
+#[derive(Debug, Clone, Default)]
+pub struct CsvImportPlan {
+    pub commands: Vec<Command>,
+    pub updated: usize,
+    pub created: usize,
+    pub unchanged: usize,
+}
+
+impl CsvImportPlan {
+    pub fn summary(&self) -> CsvImportSummary {
+        CsvImportSummary {
+            updated: self.updated,
+            created: self.created,
+            unchanged: self.unchanged,
+        }
+    }
+}
+
+/// Row counts reported after a CSV import.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+pub struct CsvImportSummary {
+    pub updated: usize,
+    pub created: usize,
+    pub unchanged: usize,
+}
+
+/// Turns CSV rows into card commands.
+pub struct CsvImporter;
+
+impl CsvImporter {
+    /// Plan the changes `data` makes to the cards in `snapshot`.
+    ///
+    /// Rows with an identifier update that card; only cells that differ
+    /// from the card produce changes, and empty cells clear optional
+    /// fields. Rows without one create a card on the row's board (or
+    /// `board_id`) in the named column, defaulting to the first column.
+    /// When `board_id` is given, identifiers only match cards on that
+    /// board. Status and column changes keep the completion column
+    /// invariant, as moves in the app do.
+    pub fn plan(
+        snapshot: &Snapshot,
+        data: &str,
+        mapping: &CsvMapping,
+        board_id: Option<Uuid>,
+        prefixes: DefaultPrefixes,
+    ) -> KanbanResult<CsvImportPlan> {
+        let mut rows = parse(data)?.into_iter();
+        let header = rows
+            .next()
+            .ok_or_else(|| KanbanError::validation("CSV file is empty"))?;
+        let mut fields: Vec<Option<CsvField>> = Vec::with_capacity(header.len());
+        for name in &header {
+            let field = mapping.resolve(name);
+            if field.is_some() && fields.contains(&field) {
+                return Err(KanbanError::validation(format!(
+                    "More than one CSV column maps to '{}'",
+                    field.map(CsvField::name).unwrap_or_default()
+                )));
+            }
+            fields.push(field);
+        }
+        if !fields.contains(&Some(CsvField::Identifier)) && !fields.contains(&Some(CsvField::Title))
+        {
+            return Err(KanbanError::validation(
+                "CSV needs an identifier or a title column",
+            ));
+        }
+
+        let boards: Vec<&Board> = snapshot
+            .boards
+            .iter()
+            .filter(|b| board_id.is_none_or(|id| b.id == id))
+            .collect();
+        if let Some(id) = board_id {
+            if boards.is_empty() {
+                return Err(KanbanError::not_found("Board", id));
+            }
+        }
+
+        let mut planner = Planner {
+            snapshot,
+            boards,
+            prefixes,
+            plan: CsvImportPlan::default(),
+            next_position: HashMap::new(),
+            next_number: HashMap::new(),
+        };
+        for (index, cells) in rows.enumerate() {
+            // Row numbers as a spreadsheet shows them, counting the header.
+            let line = index + 2;
+            if cells.iter().all(|c| c.trim().is_empty()) {
+                continue;
+            }
+            let row = Row {
+                values: fields
+                    .iter()
+                    .zip(cells)
+                    .filter_map(|(field, value)| field.map(|f| (f, value)))
+                    .collect(),
+            };
+            planner
+                .plan_row(&row)
+                .map_err(|e| KanbanError::validation(format!("Row {}: {}", line, e)))?;
+        }
+        Ok(planner.plan)
+    }
+}
+
+struct Row {
+    values: HashMap<CsvField, String>,
+}
+
+impl Row {
+    fn get(&self, field: CsvField) -> Option<&str> {
+        self.values.get(&field).map(|v| v.trim())
+    }
+
+    fn non_empty(&self, field: CsvField) -> Option<&str> {
+        self.get(field).filter(|v| !v.is_empty())
+    }
+}
+
+struct Planner<'a> {
+    snapshot: &'a Snapshot,
+    boards: Vec<&'a Board>,
+    prefixes: DefaultPrefixes<'a>,
+    plan: CsvImportPlan,
+    next_position: HashMap<Uuid, i32>,
+    next_number: HashMap<Uuid, u32>,
+}
+
+impl<'a> Planner<'a> {
+    fn plan_row(&mut self, row: &Row) -> Result<(), String> {
+        match row.non_empty(CsvField::Identifier) {
+            Some(identifier) => self.plan_update(row, identifier),
+            None => self.plan_create(row),
+        }
+    }
+
+    fn board_named(&self, name: &str) -> Result<&'a Board, String> {
+        self.boards
+            .iter()
+            .find(|b| b.name.eq_ignore_ascii_case(name))
+            .copied()
+            .ok_or_else(|| format!("unknown board '{}'", name))
+    }
+
+    fn board_of(&self, card: &Card) -> Option<&'a Board> {
+        let column = self
+            .snapshot
+            .columns
+            .iter()
+            .find(|c| c.id == card.column_id)?;
+        self.boards
+            .iter()
+            .find(|b| b.id == column.board_id)
+            .copied()
+    }
+
+    fn board_columns(&self, board: &Board) -> Vec<&'a Column> {
+        crate::card_lifecycle::sorted_board_columns(board.id, &self.snapshot.columns)
+    }
+
+    fn column_named(&self, board: &Board, name: &str) -> Result<&'a Column, String> {
+        self.board_columns(board)
+            .into_iter()
+            .find(|c| c.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| format!("board '{}' has no column '{}'", board.name, name))
+    }
+
+    fn sprint_named(&self, board: &Board, name: &str) -> Result<&'a Sprint, String> {
+        self.snapshot
+            .sprints
+            .iter()
+            .filter(|s| s.board_id == board.id)
+            .find(|s| {
+                s.formatted_name(board, self.prefixes.sprint)
+                    .eq_ignore_ascii_case(name)
+                    || s.get_name(board)
+                        .is_some_and(|n| n.eq_ignore_ascii_case(name))
+            })
+            .ok_or_else(|| format!("board '{}' has no sprint '{}'", board.name, name))
+    }
+
+    /// Append position in `column`, counting cards this plan already
+    /// placed there.
+    fn take_position(&mut self, column: Uuid) -> i32 {
+        let snapshot = self.snapshot;
+        let next = self.next_position.entry(column).or_insert_with(|| {
+            crate::card_lifecycle::next_position_in_column(&snapshot.cards, column)
+        });
+        let position = *next;
+        *next += 1;
+        position
+    }
+
+    fn plan_update(&mut self, row: &Row, identifier: &str) -> Result<(), String> {
+        let scope = row
+            .non_empty(CsvField::Board)
+            .map(|name| self.board_named(name))
+            .transpose()?;
+        let matches: Vec<(&'a Card, &'a Board)> = self
+            .snapshot
+            .cards
+            .iter()
+            .filter_map(|card| self.board_of(card).map(|board| (card, board)))
+            .filter(|(_, board)| scope.is_none_or(|s| s.id == board.id))
+            .filter(|(card, board)| {
+                card.identifier(board, &self.snapshot.sprints, self.prefixes.card)
+                    .eq_ignore_ascii_case(identifier)
+            })
+            .collect();
+        let (card, board) = match matches.as_slice() {
+            [] => return Err(format!("no card '{}'", identifier)),
+            [only] => *only,
+            _ => {
+                return Err(format!(
+                    "identifier '{}' matches cards on several boards; add a board column",
+                    identifier
+                ))
+            }
+        };
+
+        let mut updates = CardUpdate::default();
+        let mut changed = false;
+
+        if let Some(title) = row.get(CsvField::Title) {
+            if title.is_empty() {
+                return Err("title cannot be empty".to_string());
+            }
+            if title != card.title {
+                updates.title = Some(title.to_string());
+            }
+        }
+        if let Some(description) = row.get(CsvField::Description) {
+            let current = card.description.as_deref().unwrap_or("");
+            if description != current.trim() {
+                updates.description = optional(description.to_string());
+            }
+        }
+        if let Some(priority) = row.non_empty(CsvField::Priority) {
+            let priority = parse_priority(priority)?;
+            if priority != card.priority {
+                updates.priority = Some(priority);
+            }
+        }
+        if let Some(points) = row.get(CsvField::Points) {
+            let points = parse_points(points)?;
+            if points != card.points {
+                updates.points = points.map_or(FieldUpdate::Clear, FieldUpdate::Set);
+            }
+        }
+        if let Some(due) = row.get(CsvField::DueDate) {
+            let due = parse_due_date(due)?;
+            if due.map(|d| d.date_naive()) != card.due_date.map(|d| d.date_naive()) {
+                updates.due_date = due.map_or(FieldUpdate::Clear, FieldUpdate::Set);
+            }
+        }
+
+        let status = row
+            .non_empty(CsvField::Status)
+            .map(parse_status)
+            .transpose()?
+            .filter(|s| *s != card.status);
+        let column = row
+            .non_empty(CsvField::Column)
+            .map(|name| self.column_named(board, name))
+            .transpose()?
+            .map(|c| c.id)
+            .filter(|id| *id != card.column_id);
+        let columns = &self.snapshot.columns;
+        let (status, column) = match (status, column) {
+            (Some(status), None) => (
+                Some(status),
+                target_column_for_status(card, status, board, columns),
+            ),
+            (None, Some(column)) => (
+                target_status_for_column_move(card, column, board, columns),
+                Some(column),
+            ),
+            other => other,
+        };
+        updates.status = status;
+
+        let sprint_change = match row.get(CsvField::Sprint) {
+            Some("") if card.sprint_id.is_some() => Some(None),
+            Some("") | None => None,
+            Some(name) => {
+                let sprint = self.sprint_named(board, name)?;
+                (card.sprint_id != Some(sprint.id)).then_some(Some(sprint.id))
+            }
+        };
+
+        if let Some(column) = column {
+            let position = self.take_position(column);
+            self.plan
+                .commands
+                .push(Command::Card(CardCommand::Move(MoveCard {
+                    card_id: card.id,
+                    new_column_id: column,
+                    new_position: position,
+                })));
+            changed = true;
+        }
+        if updates.title.is_some()
+            || updates.priority.is_some()
+            || updates.status.is_some()
+            || !matches!(updates.description, FieldUpdate::NoChange)
+            || !matches!(updates.points, FieldUpdate::NoChange)
+            || !matches!(updates.due_date, FieldUpdate::NoChange)
+        {
+            self.plan
+                .commands
+                .push(Command::Card(CardCommand::Update(UpdateCard {
+                    card_id: card.id,
+                    updates,
+                })));
+            changed = true;
+        }
+        match sprint_change {
+            Some(Some(sprint_id)) => {
+                self.plan
+                    .commands
+                    .push(Command::Card(CardCommand::AssignToSprint(
+                        AssignCardsToSprint {
+                            ids: vec![card.id],
+                            sprint_id,
+                        },
+                    )));
+                changed = true;
+            }
+            Some(None) => {
+                self.plan
+                    .commands
+                    .push(Command::Card(CardCommand::UnassignFromSprint(
+                        UnassignCardFromSprint {
+                            card_id: card.id,
+                            timestamp: Utc::now(),
+                        },
+                    )));
+                changed = true;
+            }
+            None => {}
+        }
+
+        if changed {
+            self.plan.updated += 1;
+        } else {
+            self.plan.unchanged += 1;
+        }
+        Ok(())
+    }
+
+    fn plan_create(&mut self, row: &Row) -> Result<(), String> {
+        let title = row
+            .non_empty(CsvField::Title)
+            .ok_or("a row without an identifier needs a title")?;
+        let board = match row.non_empty(CsvField::Board) {
+            Some(name) => self.board_named(name)?,
+            None => match self.boards.as_slice() {
+                [only] => *only,
+                _ => return Err("a new card needs a board column or a target board".to_string()),
+            },
+        };
+        let column = match row.non_empty(CsvField::Column) {
+            Some(name) => self.column_named(board, name)?,
+            None => *self
+                .board_columns(board)
+                .first()
+                .ok_or_else(|| format!("board '{}' has no columns", board.name))?,
+        };
+        let sprint_id = row
+            .non_empty(CsvField::Sprint)
+            .map(|name| self.sprint_named(board, name).map(|s| s.id))
+            .transpose()?;
+        let options = CreateCardOptions {
+            description: row.non_empty(CsvField::Description).map(String::from),
+            priority: row
+                .non_empty(CsvField::Priority)
+                .map(parse_priority)
+                .transpose()?,
+            points: row
+                .get(CsvField::Points)
+                .map(parse_points)
+                .transpose()?
+                .flatten(),
+            due_date: row
+                .get(CsvField::DueDate)
+                .map(parse_due_date)
+                .transpose()?
+                .flatten(),
+            sprint_id,
+        };
+        let status = row
+            .non_empty(CsvField::Status)
+            .map(parse_status)
+            .transpose()?
+            .filter(|s| *s != CardStatus::Todo);
+
+        let number = self
+            .next_number
+            .entry(board.id)
+            .or_insert(board.card_counter);
+        let card_number = *number;
+        *number += 1;
+        let id = Uuid::new_v4();
+        let position = self.take_position(column.id);
+        self.plan
+            .commands
+            .push(Command::Card(CardCommand::Create(CreateCard {
+                id,
+                card_number,
+                board_id: board.id,
+                column_id: column.id,
+                title: title.to_string(),
+                position,
+                options,
+                timestamp: Utc::now(),
+            })));
+        if let Some(status) = status {
+            self.plan
+                .commands
+                .push(Command::Card(CardCommand::Update(UpdateCard {
+                    card_id: id,
+                    updates: CardUpdate {
+                        status: Some(status),
+                        ..Default::default()
+                    },
+                })));
+        }
+        self.plan.created += 1;
+        Ok(())
+    }
+}
+
+fn optional(value: String) -> FieldUpdate<String> {
+    if value.is_empty() {
+        FieldUpdate::Clear
+    } else {
+        FieldUpdate::Set(value)
+    }
+}
+
+fn parse_priority(s: &str) -> Result<CardPriority, String> {
+    match s.to_lowercase().as_str() {
+        "low" => Ok(CardPriority::Low),
+        "medium" => Ok(CardPriority::Medium),
+        "high" => Ok(CardPriority::High),
+        "critical" => Ok(CardPriority::Critical),
+        _ => Err(format!(
+            "invalid priority '{}': use low, medium, high or critical",
+            s
+        )),
+    }
+}
+
+fn parse_status(s: &str) -> Result<CardStatus, String> {
+    match s.to_lowercase().replace([' ', '-'], "_").as_str() {
+        "todo" => Ok(CardStatus::Todo),
+        "in_progress" | "inprogress" => Ok(CardStatus::InProgress),
+        "blocked" => Ok(CardStatus::Blocked),
+        "done" => Ok(CardStatus::Done),
+        _ => Err(format!(
+            "invalid status '{}': use todo, in_progress, blocked or done",
+            s
+        )),
+    }
+}
+
+fn parse_points(s: &str) -> Result<Option<u8>, String> {
+    if s.is_empty() {
+        return Ok(None);
+    }
+    s.parse::<u8>()
+        .map(Some)
+        .map_err(|_| format!("invalid points '{}'", s))
+}
+
+/// `YYYY-MM-DD` (midnight UTC) or RFC 3339.
+fn parse_due_date(s: &str) -> Result<Option<DateTime<Utc>>, String> {
+    if s.is_empty() {
+        return Ok(None);
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return Ok(date.and_hms_opt(0, 0, 0).map(|dt| dt.and_utc()));
+    }
+    DateTime::parse_from_rfc3339(s)
+        .map(|dt| Some(dt.with_timezone(&Utc)))
+        .map_err(|_| format!("invalid due date '{}': use YYYY-MM-DD", s))
+}
+
+fn write_row(out: &mut String, fields: impl Iterator<Item = String>) {
+    let escaped: Vec<String> = fields.map(|f| escape(&f)).collect();
+    out.push_str(&escaped.join(","));
+    out.push('\n');
+}
+
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Split RFC 4180 CSV into rows of fields. Quoted fields may contain
+/// commas, doubled quotes and line breaks.
+fn parse(data: &str) -> KanbanResult<Vec<Vec<String>>> {
+    let data = data.strip_prefix('\u{feff}').unwrap_or(data);
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = data.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(KanbanError::validation(
+            "CSV has an unterminated quoted field",
+        ));
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DependencyGraph;
+
+    const PREFIXES: DefaultPrefixes = DefaultPrefixes {
+        card: "task",
+        sprint: "sprint",
+    };
+
+    fn snapshot() -> Snapshot {
+        let mut board = Board::new("Ops", Some("OPS"));
+        let todo = Column::new(board.id, "Todo", 0);
+        let doing = Column::new(board.id, "Doing", 1);
+        let done = Column::new(board.id, "Done", 2);
+        board.completion_column_id = Some(done.id);
+        let mut first = Card::new(&mut board, todo.id, "Rotate keys", 0);
+        first.description = Some("Quarterly, \"all\" of them".to_string());
+        let second = Card::new(&mut board, doing.id, "Patch hosts", 0);
+        Snapshot {
+            boards: vec![board],
+            columns: vec![todo, doing, done],
+            cards: vec![first, second],
+            archived_cards: vec![],
+            sprints: vec![],
+            graph: DependencyGraph::new(),
+        }
+    }
+
+    fn export(snapshot: &Snapshot) -> String {
+        CsvExporter::export_cards(
+            &snapshot.boards,
+            &snapshot.columns,
+            &snapshot.cards,
+            &snapshot.sprints,
+            PREFIXES,
+        )
+    }
+
+    fn plan(snapshot: &Snapshot, csv: &str, mapping: &CsvMapping) -> KanbanResult<CsvImportPlan> {
+        CsvImporter::plan(snapshot, csv, mapping, None, PREFIXES)
+    }
+
+    #[test]
+    fn test_export_then_parse_round_trips_fields() {
+        let snapshot = snapshot();
+        let csv = export(&snapshot);
+        let rows = parse(&csv).unwrap();
+        assert_eq!(rows[0], CsvField::ALL.map(|f| f.name().to_string()));
+        assert_eq!(rows[1][1], "OPS-1");
+        assert_eq!(rows[1][9], "Quarterly, \"all\" of them");
+        assert_eq!(rows[2][3], "Doing");
+
+        let unchanged = plan(&snapshot, &csv, &CsvMapping::default()).unwrap();
+        assert!(unchanged.commands.is_empty());
+        assert_eq!(unchanged.unchanged, 2);
+    }
+
+    #[test]
+    fn test_edited_rows_become_updates_and_moves() {
+        let snapshot = snapshot();
+        let csv = "Key,Summary,Column,Priority\n\
+                   ops-1,Rotate all keys,Todo,high\n\
+                   OPS-2,Patch hosts,Done,medium\n";
+        let plan = plan(&snapshot, csv, &CsvMapping::default()).unwrap();
+        assert_eq!((plan.updated, plan.unchanged), (2, 0));
+
+        let [Command::Card(CardCommand::Update(rename)), Command::Card(CardCommand::Move(mv)), Command::Card(CardCommand::Update(status))] =
+            plan.commands.as_slice()
+        else {
+            panic!("unexpected commands: {:?}", plan.commands);
+        };
+        assert_eq!(rename.updates.title.as_deref(), Some("Rotate all keys"));
+        assert_eq!(rename.updates.priority, Some(CardPriority::High));
+        assert_eq!(mv.new_column_id, snapshot.columns[2].id);
+        assert_eq!(status.updates.status, Some(CardStatus::Done));
+    }
+
+    #[test]
+    fn test_rows_without_identifier_create_cards_with_mapping() {
+        let snapshot = snapshot();
+        let mapping = CsvMapping::default()
+            .parse_entry("Task=title")
+            .unwrap()
+            .parse_entry("Owner=-")
+            .unwrap();
+        let csv = "Task,Owner,Estimate\nWrite runbook,sam,5\nReview runbook,kim,\n";
+        let plan = plan(&snapshot, csv, &mapping).unwrap();
+        assert_eq!(plan.created, 2);
+        let numbers: Vec<(u32, i32, Option<u8>)> = plan
+            .commands
+            .iter()
+            .map(|cmd| match cmd {
+                Command::Card(CardCommand::Create(c)) => {
+                    (c.card_number, c.position, c.options.points)
+                }
+                other => panic!("unexpected {:?}", other),
+            })
+            .collect();
+        assert_eq!(numbers, vec![(3, 1, Some(5)), (4, 2, None)]);
+    }
+
+    #[test]
+    fn test_plan_reports_row_errors() {
+        let snapshot = snapshot();
+        let err = plan(&snapshot, "id,title\nOPS-9,Nope\n", &CsvMapping::default())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Row 2") && err.contains("OPS-9"), "{}", err);
+
+        let err = plan(&snapshot, "owner\nsam\n", &CsvMapping::default())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("identifier or a title"), "{}", err);
+
+        assert!(parse("a,\"b\n").is_err());
+        assert!(CsvMapping::default().parse_entry("Owner=assignee").is_err());
+    }
+}
@@ -3,7 +3,8 @@
 //! Converts domain entities into export format for serialization.
 
 use super::models::{AllBoardsExport, BoardExport};
-use crate::{ArchivedCard, Board, Card, Column, Sprint};
+use crate::{ArchivedCard, Board, Card, Column, DependencyGraph, Sprint};
+use std::collections::HashSet;
 use std::io;
 use uuid::Uuid;
 
@@ -12,12 +13,17 @@ pub struct BoardExporter;
 
 impl BoardExporter {
     /// Export a single board with all its associated data.
+    ///
+    /// The board's slice of `graph` covers every edge touching one of its
+    /// cards or archived cards, so edges to cards on other boards survive
+    /// an all-boards export.
     pub fn export_board(
         board: &Board,
         all_columns: &[Column],
         all_cards: &[Card],
         all_archived_cards: &[ArchivedCard],
         all_sprints: &[Sprint],
+        graph: &DependencyGraph,
     ) -> BoardExport {
         let board_columns: Vec<Column> = all_columns
             .iter()
@@ -45,12 +51,22 @@ impl BoardExporter {
             .cloned()
             .collect();
 
+        let card_ids: HashSet<Uuid> = board_cards
+            .iter()
+            .map(|c| c.id)
+            .chain(board_archived_cards.iter().map(|ac| ac.card.id))
+            .collect();
+        let board_graph = graph.filter_edges(|source, target| {
+            card_ids.contains(&source) || card_ids.contains(&target)
+        });
+
         BoardExport {
             board: board.clone(),
             columns: board_columns,
             cards: board_cards,
             archived_cards: board_archived_cards,
             sprints: board_sprints,
+            graph: board_graph,
         }
     }
 
@@ -61,15 +77,14 @@ impl BoardExporter {
         cards: &[Card],
         archived_cards: &[ArchivedCard],
         sprints: &[Sprint],
+        graph: &DependencyGraph,
     ) -> AllBoardsExport {
         let board_exports: Vec<BoardExport> = boards
             .iter()
-            .map(|board| Self::export_board(board, columns, cards, archived_cards, sprints))
+            .map(|board| Self::export_board(board, columns, cards, archived_cards, sprints, graph))
             .collect();
 
-        AllBoardsExport {
-            boards: board_exports,
-        }
+        AllBoardsExport::from_boards(board_exports)
     }
 
     /// Serialize export to JSON string.
@@ -101,8 +116,14 @@ mod tests {
         let archived_cards = vec![];
         let sprints = vec![];
 
-        let export =
-            BoardExporter::export_board(&board, &columns, &cards, &archived_cards, &sprints);
+        let export = BoardExporter::export_board(
+            &board,
+            &columns,
+            &cards,
+            &archived_cards,
+            &sprints,
+            &DependencyGraph::new(),
+        );
 
         assert_eq!(export.board.name, "Test");
         assert_eq!(export.columns.len(), 1);
@@ -124,8 +145,14 @@ mod tests {
         let archived_cards = vec![];
         let sprints = vec![];
 
-        let export =
-            BoardExporter::export_all_boards(&boards, &columns, &cards, &archived_cards, &sprints);
+        let export = BoardExporter::export_all_boards(
+            &boards,
+            &columns,
+            &cards,
+            &archived_cards,
+            &sprints,
+            &DependencyGraph::new(),
+        );
 
         assert_eq!(export.boards.len(), 2);
         assert_eq!(export.boards[0].board.name, "Board 1");
@@ -135,17 +162,41 @@ mod tests {
     #[test]
     fn test_export_to_json() {
         let board = Board::new("Test", None::<String>);
-        let export = AllBoardsExport {
-            boards: vec![BoardExport {
-                board,
-                columns: vec![],
-                cards: vec![],
-                archived_cards: vec![],
-                sprints: vec![],
-            }],
-        };
+        let export = AllBoardsExport::from_boards(vec![BoardExport {
+            board,
+            columns: vec![],
+            cards: vec![],
+            archived_cards: vec![],
+            sprints: vec![],
+            graph: DependencyGraph::new(),
+        }]);
 
         let json = BoardExporter::export_to_json(&export).unwrap();
         assert!(json.contains("Test"));
+        assert!(json.contains("\"version\": 3"));
+    }
+
+    #[test]
+    fn test_export_board_scopes_graph_to_board_cards() {
+        let mut board = Board::new("Test", None::<String>);
+        let column = Column::new(board.id, "Todo", 0);
+        let a = Card::new(&mut board, column.id, "A", 0);
+        let b = Card::new(&mut board, column.id, "B", 1);
+        let archived = ArchivedCard::new(Card::new(&mut board, column.id, "C", 2), column.id, 2);
+        let (elsewhere, unrelated) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let mut graph = DependencyGraph::new();
+        graph.set_block(a.id, b.id).unwrap();
+        graph.set_parent(archived.card.id, a.id).unwrap();
+        graph.archive_node(archived.card.id);
+        graph.relate(b.id, elsewhere).unwrap();
+        graph.relate(elsewhere, unrelated).unwrap();
+
+        let export =
+            BoardExporter::export_board(&board, &[column], &[a, b], &[archived], &[], &graph);
+
+        assert_eq!(export.graph.blocks_edges().len(), 1);
+        assert_eq!(export.graph.spawns_edges(), graph.spawns_edges());
+        assert_eq!(export.graph.relates_edges().len(), 1);
     }
 }
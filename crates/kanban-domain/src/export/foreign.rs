@@ -0,0 +1,448 @@
+//! Importing boards exported from other tools.
+//!
+//! Each importer reads its tool's export into a [`ForeignBoard`], a neutral
+//! description of one board, which [`ForeignBoard::build`] turns into fresh
+//! entities ready to be imported. Labels that name a priority or a status
+//! set those fields; the rest are kept in the card description.
+
+use super::{github, taskwarrior, trello};
+use crate::{
+    Board, Card, CardPriority, CardStatus, Column, DependencyGraph, KanbanError, KanbanResult,
+    Snapshot,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use uuid::Uuid;
+
+/// Tools whose exports can be imported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ForeignSource {
+    /// Board JSON from Trello's "Print and export" menu.
+    Trello,
+    /// `gh issue list --json ...` or `gh project item-list --format json`.
+    Github,
+    /// `task export`.
+    Taskwarrior,
+}
+
+impl fmt::Display for ForeignSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Trello => write!(f, "trello"),
+            Self::Github => write!(f, "github"),
+            Self::Taskwarrior => write!(f, "taskwarrior"),
+        }
+    }
+}
+
+impl std::str::FromStr for ForeignSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "trello" => Ok(Self::Trello),
+            "github" | "gh" => Ok(Self::Github),
+            "taskwarrior" | "task" => Ok(Self::Taskwarrior),
+            _ => Err(format!(
+                "Unknown import source '{}': use trello, github or taskwarrior",
+                s
+            )),
+        }
+    }
+}
+
+/// How [`ForeignImporter::plan`] names and applies the imported board.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForeignImportOptions {
+    /// Board name; defaults to the name found in the export.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Only report what would be created.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// One board read from another tool's export.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ForeignBoard {
+    pub name: String,
+    pub description: Option<String>,
+    /// Column names in board order.
+    pub columns: Vec<String>,
+    /// Column whose cards are complete.
+    pub completion_column: Option<String>,
+    pub cards: Vec<ForeignCard>,
+    /// Items left out of the import, with the reason.
+    pub skipped: Vec<String>,
+}
+
+/// One card read from another tool's export.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ForeignCard {
+    /// The source tool's id, used by `parent` and `blocked_by`.
+    pub key: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub column: String,
+    /// Set from `labels` when `None`.
+    pub priority: Option<CardPriority>,
+    /// Set from `labels` or the column when `None`.
+    pub status: Option<CardStatus>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub labels: Vec<String>,
+    /// Key of the card this one is a child of.
+    pub parent: Option<String>,
+    /// Keys of the cards that block this one.
+    pub blocked_by: Vec<String>,
+}
+
+/// A foreign board built into entities, with the report describing them.
+#[derive(Debug, Clone)]
+pub struct ForeignImport {
+    pub snapshot: Snapshot,
+    pub report: ForeignImportReport,
+}
+
+/// What a foreign import creates, or would create on a dry run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ForeignImportReport {
+    pub source: ForeignSource,
+    pub dry_run: bool,
+    pub board: String,
+    /// Set once the board has been created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub board_id: Option<Uuid>,
+    pub columns: Vec<ForeignColumnReport>,
+    pub cards: usize,
+    pub parent_edges: usize,
+    pub blocking_edges: usize,
+    pub skipped: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ForeignColumnReport {
+    pub name: String,
+    pub cards: usize,
+}
+
+/// Reads other tools' exports into boards.
+pub struct ForeignImporter;
+
+impl ForeignImporter {
+    /// Parse `data` as an export from `source`.
+    pub fn read(source: ForeignSource, data: &str) -> KanbanResult<ForeignBoard> {
+        match source {
+            ForeignSource::Trello => trello::read(data),
+            ForeignSource::Github => github::read(data),
+            ForeignSource::Taskwarrior => taskwarrior::read(data),
+        }
+    }
+
+    /// Parse `data` and build it into a new board at `position`.
+    pub fn plan(
+        source: ForeignSource,
+        data: &str,
+        options: &ForeignImportOptions,
+        position: i32,
+    ) -> KanbanResult<ForeignImport> {
+        let mut board = Self::read(source, data)?;
+        if let Some(ref name) = options.name {
+            board.name = name.clone();
+        }
+        let mut import = board.build(position)?;
+        import.report.source = source;
+        import.report.dry_run = options.dry_run;
+        Ok(import)
+    }
+}
+
+impl ForeignBoard {
+    /// Create the board, its columns and cards, and the edges between
+    /// them. Cards are numbered from 1 in order. References to cards that
+    /// are not in the export are dropped and reported as skipped.
+    pub fn build(self, position: i32) -> KanbanResult<ForeignImport> {
+        if self.columns.is_empty() {
+            return Err(KanbanError::validation("Import has no columns"));
+        }
+        let mut skipped = self.skipped;
+
+        let mut board = Board::new(self.name.clone(), None::<String>);
+        board.description = self.description;
+        board.position = position;
+        let columns: Vec<Column> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, name)| Column::new(board.id, name.clone(), i as i32))
+            .collect();
+        let column_id = |name: &str| columns.iter().find(|c| c.name == name).map(|c| c.id);
+        board.completion_column_id = self.completion_column.as_deref().and_then(column_id);
+
+        let mut positions: HashMap<Uuid, i32> = HashMap::new();
+        let mut cards = Vec::with_capacity(self.cards.len());
+        let mut keys: HashMap<&str, Uuid> = HashMap::new();
+        for foreign in &self.cards {
+            let Some(column) = column_id(&foreign.column) else {
+                return Err(KanbanError::validation(format!(
+                    "Card '{}' uses unknown column '{}'",
+                    foreign.title, foreign.column
+                )));
+            };
+            let position = positions.entry(column).or_default();
+            let mut card = Card::new(&mut board, column, foreign.title.clone(), *position);
+            *position += 1;
+
+            let labels = classify_labels(&foreign.labels);
+            card.description = describe(foreign.description.as_deref(), &labels.rest);
+            card.priority = foreign
+                .priority
+                .or(labels.priority)
+                .unwrap_or(CardPriority::Medium);
+            card.status = if Some(column) == board.completion_column_id {
+                CardStatus::Done
+            } else {
+                foreign.status.or(labels.status).unwrap_or(CardStatus::Todo)
+            };
+            if card.status == CardStatus::Done {
+                card.completed_at = foreign.completed_at.or(Some(card.created_at));
+            }
+            card.due_date = foreign.due_date;
+            keys.insert(foreign.key.as_str(), card.id);
+            cards.push(card);
+        }
+
+        let mut graph = DependencyGraph::new();
+        let mut parent_edges = 0;
+        let mut blocking_edges = 0;
+        for (foreign, card) in self.cards.iter().zip(&cards) {
+            if let Some(ref parent) = foreign.parent {
+                match keys.get(parent.as_str()) {
+                    Some(&parent_id) => match graph.set_parent(card.id, parent_id) {
+                        Ok(()) => parent_edges += 1,
+                        Err(e) => skipped.push(format!("parent of '{}': {}", foreign.title, e)),
+                    },
+                    None => skipped.push(format!(
+                        "parent of '{}': {} is not in the export",
+                        foreign.title, parent
+                    )),
+                }
+            }
+            for blocker in &foreign.blocked_by {
+                match keys.get(blocker.as_str()) {
+                    Some(&blocker_id) => match graph.set_block(blocker_id, card.id) {
+                        Ok(()) => blocking_edges += 1,
+                        Err(e) => skipped.push(format!("blocker of '{}': {}", foreign.title, e)),
+                    },
+                    None => skipped.push(format!(
+                        "blocker of '{}': {} is not in the export",
+                        foreign.title, blocker
+                    )),
+                }
+            }
+        }
+
+        let report = ForeignImportReport {
+            source: ForeignSource::Trello,
+            dry_run: false,
+            board: board.name.clone(),
+            board_id: None,
+            columns: columns
+                .iter()
+                .map(|column| ForeignColumnReport {
+                    name: column.name.clone(),
+                    cards: cards.iter().filter(|c| c.column_id == column.id).count(),
+                })
+                .collect(),
+            cards: cards.len(),
+            parent_edges,
+            blocking_edges,
+            skipped,
+        };
+        Ok(ForeignImport {
+            snapshot: Snapshot {
+                boards: vec![board],
+                columns,
+                cards,
+                archived_cards: Vec::new(),
+                sprints: Vec::new(),
+                graph,
+            },
+            report,
+        })
+    }
+}
+
+struct Labels {
+    priority: Option<CardPriority>,
+    status: Option<CardStatus>,
+    rest: Vec<String>,
+}
+
+/// Split labels into a priority, a status and everything else. The first
+/// label naming a priority or status wins.
+fn classify_labels(labels: &[String]) -> Labels {
+    let mut classified = Labels {
+        priority: None,
+        status: None,
+        rest: Vec::new(),
+    };
+    for label in labels {
+        let normalized = label
+            .to_lowercase()
+            .trim_start_matches("priority")
+            .trim_start_matches([':', '/', '-', ' '])
+            .replace(['-', '_'], " ");
+        match (label_priority(&normalized), label_status(&normalized)) {
+            (Some(priority), _) => {
+                classified.priority.get_or_insert(priority);
+            }
+            (None, Some(status)) => {
+                classified.status.get_or_insert(status);
+            }
+            (None, None) => classified.rest.push(label.clone()),
+        }
+    }
+    classified
+}
+
+/// Priority named by a label such as `high`, `P1` or `urgent`.
+pub(crate) fn label_priority(label: &str) -> Option<CardPriority> {
+    match label.trim().to_lowercase().as_str() {
+        "critical" | "urgent" | "blocker" | "p0" => Some(CardPriority::Critical),
+        "high" | "p1" => Some(CardPriority::High),
+        "medium" | "normal" | "p2" => Some(CardPriority::Medium),
+        "low" | "minor" | "p3" | "p4" => Some(CardPriority::Low),
+        _ => None,
+    }
+}
+
+fn label_status(label: &str) -> Option<CardStatus> {
+    match label.trim() {
+        "blocked" | "on hold" => Some(CardStatus::Blocked),
+        "in progress" | "wip" | "doing" => Some(CardStatus::InProgress),
+        _ => None,
+    }
+}
+
+fn describe(description: Option<&str>, labels: &[String]) -> Option<String> {
+    let description = description.map(str::trim).filter(|d| !d.is_empty());
+    if labels.is_empty() {
+        return description.map(String::from);
+    }
+    let footer = format!("Labels: {}", labels.join(", "));
+    Some(match description {
+        Some(d) => format!("{}\n\n{}", d, footer),
+        None => footer,
+    })
+}
+
+/// RFC 3339, or a bare `YYYY-MM-DD` at midnight UTC.
+pub(crate) fn parse_date(s: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc())
+}
+
+pub(crate) fn parse_json<'a, T: Deserialize<'a>>(
+    source: ForeignSource,
+    data: &'a str,
+) -> KanbanResult<T> {
+    serde_json::from_str(data)
+        .map_err(|e| KanbanError::validation(format!("Invalid {} export: {}", source, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(key: &str, column: &str) -> ForeignCard {
+        ForeignCard {
+            key: key.to_string(),
+            title: format!("Card {}", key),
+            column: column.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn board() -> ForeignBoard {
+        ForeignBoard {
+            name: "Imported".to_string(),
+            columns: vec!["Todo".to_string(), "Done".to_string()],
+            completion_column: Some("Done".to_string()),
+            cards: vec![
+                ForeignCard {
+                    labels: vec!["P1".to_string(), "bug".to_string()],
+                    description: Some("Body".to_string()),
+                    ..card("a", "Todo")
+                },
+                ForeignCard {
+                    parent: Some("a".to_string()),
+                    blocked_by: vec!["c".to_string(), "missing".to_string()],
+                    labels: vec!["blocked".to_string()],
+                    ..card("b", "Todo")
+                },
+                card("c", "Done"),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_build_maps_labels_columns_and_edges() {
+        let import = board().build(2).unwrap();
+        let snapshot = &import.snapshot;
+        let board = &snapshot.boards[0];
+        assert_eq!(board.position, 2);
+        assert_eq!(board.completion_column_id, Some(snapshot.columns[1].id));
+
+        let by_title = |t: &str| snapshot.cards.iter().find(|c| c.title == t).unwrap();
+        let a = by_title("Card a");
+        assert_eq!(a.priority, CardPriority::High);
+        assert_eq!(a.description.as_deref(), Some("Body\n\nLabels: bug"));
+        let b = by_title("Card b");
+        assert_eq!(b.status, CardStatus::Blocked);
+        assert_eq!(b.position, 1);
+        let c = by_title("Card c");
+        assert_eq!(c.status, CardStatus::Done);
+        assert!(c.completed_at.is_some());
+
+        assert_eq!(snapshot.graph.parents(b.id), vec![a.id]);
+        assert_eq!(snapshot.graph.blockers(b.id), vec![c.id]);
+
+        let report = &import.report;
+        assert_eq!(report.cards, 3);
+        assert_eq!(report.parent_edges, 1);
+        assert_eq!(report.blocking_edges, 1);
+        assert_eq!(report.columns[0].cards, 2);
+        assert_eq!(report.skipped.len(), 1);
+        assert!(report.skipped[0].contains("missing"));
+    }
+
+    #[test]
+    fn test_build_rejects_unknown_column() {
+        let mut board = board();
+        board.cards.push(card("d", "Nope"));
+        assert!(board.build(0).is_err());
+    }
+
+    #[test]
+    fn test_plan_renames_and_marks_dry_run() {
+        let data = r#"[{"uuid":"1","description":"Write","status":"pending"}]"#;
+        let options = ForeignImportOptions {
+            name: Some("Mine".to_string()),
+            dry_run: true,
+        };
+        let import = ForeignImporter::plan(ForeignSource::Taskwarrior, data, &options, 0).unwrap();
+        assert_eq!(import.report.board, "Mine");
+        assert_eq!(import.report.source, ForeignSource::Taskwarrior);
+        assert!(import.report.dry_run);
+    }
+}
@@ -0,0 +1,287 @@
+//! GitHub issues and project items, as printed by the `gh` CLI.
+//!
+//! `gh issue list --json number,title,body,state,labels,milestone,url`
+//! gives an array of issues, placed in `Open` and `Closed` columns.
+//! `gh project item-list <n> --format json` gives an object with the
+//! project's items, placed in one column per `Status` value. Milestone due
+//! dates become due dates, and task list entries such as `- [ ] #12` in a
+//! body make issue 12 a child of that issue.
+
+use super::foreign::{
+    label_priority, parse_date, parse_json, ForeignBoard, ForeignCard, ForeignSource,
+};
+use crate::KanbanResult;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+const OPEN_COLUMN: &str = "Open";
+const CLOSED_COLUMN: &str = "Closed";
+const NO_STATUS_COLUMN: &str = "No Status";
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum GithubExport {
+    Issues(Vec<Issue>),
+    Project { items: Vec<ProjectItem> },
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Issue {
+    number: u64,
+    title: String,
+    #[serde(default)]
+    body: String,
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    labels: Vec<Label>,
+    milestone: Option<Milestone>,
+    url: Option<String>,
+    closed_at: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Label {
+    Name(String),
+    Object { name: String },
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Milestone {
+    due_on: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ProjectItem {
+    id: String,
+    #[serde(default)]
+    title: String,
+    status: Option<String>,
+    #[serde(default)]
+    labels: Vec<Label>,
+    content: Option<ProjectContent>,
+    milestone: Option<Milestone>,
+    /// Custom fields, keyed by lowercased field name.
+    #[serde(flatten)]
+    fields: HashMap<String, Value>,
+}
+
+#[derive(Deserialize)]
+struct ProjectContent {
+    number: Option<u64>,
+    #[serde(default)]
+    body: String,
+    #[serde(default)]
+    title: String,
+}
+
+impl Label {
+    fn name(&self) -> &str {
+        match self {
+            Self::Name(name) | Self::Object { name } => name,
+        }
+    }
+}
+
+pub(crate) fn read(data: &str) -> KanbanResult<ForeignBoard> {
+    match parse_json(ForeignSource::Github, data)? {
+        GithubExport::Issues(issues) => Ok(read_issues(issues)),
+        GithubExport::Project { items } => Ok(read_project(items)),
+    }
+}
+
+fn read_issues(issues: Vec<Issue>) -> ForeignBoard {
+    let name = issues
+        .iter()
+        .find_map(|i| i.url.as_deref().and_then(repository))
+        .unwrap_or_else(|| "GitHub issues".to_string());
+    let mut cards: Vec<ForeignCard> = issues
+        .into_iter()
+        .map(|issue| {
+            let closed = issue.state.eq_ignore_ascii_case("closed");
+            ForeignCard {
+                key: issue.number.to_string(),
+                title: issue.title,
+                column: if closed { CLOSED_COLUMN } else { OPEN_COLUMN }.to_string(),
+                due_date: issue
+                    .milestone
+                    .and_then(|m| m.due_on)
+                    .as_deref()
+                    .and_then(parse_date),
+                completed_at: issue.closed_at.as_deref().and_then(parse_date),
+                labels: issue.labels.iter().map(|l| l.name().to_string()).collect(),
+                description: Some(issue.body),
+                ..Default::default()
+            }
+        })
+        .collect();
+    // `gh` lists the newest issue first.
+    cards.sort_by_key(|c| c.key.parse::<u64>().unwrap_or_default());
+    link_task_lists(&mut cards);
+
+    ForeignBoard {
+        name,
+        columns: vec![OPEN_COLUMN.to_string(), CLOSED_COLUMN.to_string()],
+        completion_column: Some(CLOSED_COLUMN.to_string()),
+        cards,
+        ..Default::default()
+    }
+}
+
+fn read_project(items: Vec<ProjectItem>) -> ForeignBoard {
+    let mut columns: Vec<String> = Vec::new();
+    let cards: Vec<ForeignCard> = items
+        .into_iter()
+        .map(|item| {
+            let column = item
+                .status
+                .filter(|s| !s.is_empty())
+                .unwrap_or_else(|| NO_STATUS_COLUMN.to_string());
+            if !columns.contains(&column) {
+                columns.push(column.clone());
+            }
+            let (key, title, body) = match item.content {
+                Some(content) => (
+                    content.number.map_or(item.id, |n| n.to_string()),
+                    if item.title.is_empty() {
+                        content.title
+                    } else {
+                        item.title
+                    },
+                    content.body,
+                ),
+                None => (item.id, item.title, String::new()),
+            };
+            let field = |name: &str| item.fields.get(name).and_then(Value::as_str);
+            ForeignCard {
+                key,
+                title,
+                description: Some(body),
+                column,
+                priority: field("priority").and_then(label_priority),
+                due_date: ["due", "due date", "target date"]
+                    .iter()
+                    .find_map(|name| field(name))
+                    .or(item.milestone.as_ref().and_then(|m| m.due_on.as_deref()))
+                    .and_then(parse_date),
+                labels: item.labels.iter().map(|l| l.name().to_string()).collect(),
+                ..Default::default()
+            }
+        })
+        .collect();
+    let completion_column = columns
+        .iter()
+        .find(|c| c.eq_ignore_ascii_case("done"))
+        .cloned();
+    let mut board = ForeignBoard {
+        name: "GitHub project".to_string(),
+        columns,
+        completion_column,
+        cards,
+        ..Default::default()
+    };
+    link_task_lists(&mut board.cards);
+    board
+}
+
+/// Make every issue listed in another's task list its child. Issues
+/// listed by several parents keep the first.
+fn link_task_lists(cards: &mut [ForeignCard]) {
+    let mut parents: HashMap<String, String> = HashMap::new();
+    for card in cards.iter() {
+        for child in task_list_refs(card.description.as_deref().unwrap_or_default()) {
+            if child != card.key {
+                parents.entry(child).or_insert_with(|| card.key.clone());
+            }
+        }
+    }
+    for card in cards.iter_mut() {
+        card.parent = parents.remove(&card.key);
+    }
+}
+
+/// Issue numbers in `- [ ] #12` or `- [x] https://github.com/o/r/issues/12`
+/// task list entries.
+fn task_list_refs(body: &str) -> Vec<String> {
+    body.lines()
+        .filter_map(|line| {
+            let line = line.trim_start();
+            let rest = line.strip_prefix("- [").or(line.strip_prefix("* ["))?;
+            let rest = rest.get(2..).filter(|_| rest.get(1..2) == Some("]"))?;
+            let target = rest.split_whitespace().next()?;
+            let number = target
+                .strip_prefix('#')
+                .or_else(|| target.rsplit_once("/issues/").map(|(_, n)| n))?;
+            number.parse::<u64>().ok().map(|_| number.to_string())
+        })
+        .collect()
+}
+
+/// `owner/repo` from an issue URL.
+fn repository(url: &str) -> Option<String> {
+    let path = url.strip_prefix("https://github.com/")?;
+    let mut parts = path.split('/');
+    Some(format!("{}/{}", parts.next()?, parts.next()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CardPriority;
+
+    #[test]
+    fn test_read_issues_links_task_lists() {
+        let data = r#"[
+            {"number": 3, "title": "Child", "state": "CLOSED",
+             "closedAt": "2024-04-02T10:00:00Z",
+             "labels": [{"name": "bug"}],
+             "url": "https://github.com/acme/app/issues/3"},
+            {"number": 1, "title": "Epic", "state": "OPEN",
+             "body": "Plan\n- [x] #3\n- [ ] https://github.com/acme/app/issues/9",
+             "labels": [{"name": "priority: high"}],
+             "milestone": {"title": "v1", "dueOn": "2024-05-01T00:00:00Z"}}
+        ]"#;
+        let board = read(data).unwrap();
+        assert_eq!(board.name, "acme/app");
+        assert_eq!(board.columns, ["Open", "Closed"]);
+        assert_eq!(board.cards[0].title, "Epic");
+        assert!(board.cards[0].due_date.is_some());
+        assert_eq!(board.cards[1].column, "Closed");
+        assert_eq!(board.cards[1].parent.as_deref(), Some("1"));
+        assert!(board.cards[1].completed_at.is_some());
+
+        let import = board.build(0).unwrap();
+        let epic = &import.snapshot.cards[0];
+        assert_eq!(epic.priority, CardPriority::High);
+        assert_eq!(import.report.parent_edges, 1);
+    }
+
+    #[test]
+    fn test_read_project_items_uses_status_columns() {
+        let data = r#"{"items": [
+            {"id": "PVTI_1", "title": "Design", "status": "In Progress",
+             "priority": "P0", "labels": ["ux"],
+             "content": {"type": "Issue", "number": 7, "body": "", "title": "Design"}},
+            {"id": "PVTI_2", "title": "Draft idea",
+             "content": {"type": "DraftIssue", "body": "", "title": "Draft idea"}},
+            {"id": "PVTI_3", "title": "Setup", "status": "Done",
+             "content": {"type": "Issue", "number": 2, "body": "", "title": "Setup"}}
+        ], "totalCount": 3}"#;
+        let board = read(data).unwrap();
+        assert_eq!(board.columns, ["In Progress", "No Status", "Done"]);
+        assert_eq!(board.completion_column.as_deref(), Some("Done"));
+        assert_eq!(board.cards[0].key, "7");
+        assert_eq!(board.cards[0].priority, Some(CardPriority::Critical));
+        assert_eq!(board.cards[1].key, "PVTI_2");
+    }
+
+    #[test]
+    fn test_task_list_refs_ignores_plain_lines() {
+        let refs = task_list_refs("#4\n- [ ] #5 later\n- [ ] write docs\n * [X] #6");
+        assert_eq!(refs, ["5", "6"]);
+    }
+}
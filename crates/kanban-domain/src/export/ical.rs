@@ -0,0 +1,276 @@
+//! iCalendar export.
+//!
+//! Cards with a due date become `VTODO`s and sprints with a start date
+//! become `VEVENT`s. UIDs are derived from the card and sprint ids, so a
+//! calendar that re-reads the file updates entries instead of duplicating
+//! them.
+
+use super::models::DefaultPrefixes;
+use crate::{Board, Card, CardPriority, CardStatus, Column, Sprint, SprintStatus};
+use chrono::{DateTime, Utc};
+
+/// Renders due dates and sprint windows as an iCalendar (RFC 5545) feed.
+pub struct IcalExporter;
+
+impl IcalExporter {
+    /// Render the dated cards and sprints of `boards` as one calendar.
+    ///
+    /// Cards are listed by board and card number; the summary starts with
+    /// the card identifier and done cards are marked completed. Sprints
+    /// span their start and end dates, or are instant events when they
+    /// have no end date.
+    pub fn export_calendar(
+        boards: &[Board],
+        columns: &[Column],
+        cards: &[Card],
+        sprints: &[Sprint],
+        prefixes: DefaultPrefixes,
+    ) -> String {
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            format!("PRODID:-//kanban//kanban {}//EN", env!("CARGO_PKG_VERSION")),
+            "CALSCALE:GREGORIAN".to_string(),
+        ];
+        let name = match boards {
+            [board] => board.name.as_str(),
+            _ => "Kanban",
+        };
+        lines.push(format!("X-WR-CALNAME:{}", escape(name)));
+
+        for board in boards {
+            let mut board_sprints: Vec<&Sprint> = sprints
+                .iter()
+                .filter(|s| s.board_id == board.id && s.start_date.is_some())
+                .collect();
+            board_sprints.sort_by_key(|s| s.sprint_number);
+            for sprint in board_sprints {
+                push_sprint(&mut lines, board, sprint, prefixes);
+            }
+
+            let mut board_cards: Vec<&Card> = cards
+                .iter()
+                .filter(|c| {
+                    c.due_date.is_some()
+                        && columns
+                            .iter()
+                            .any(|col| col.id == c.column_id && col.board_id == board.id)
+                })
+                .collect();
+            board_cards.sort_by_key(|c| c.card_number);
+            for card in board_cards {
+                push_card(&mut lines, board, card, sprints, prefixes);
+            }
+        }
+        lines.push("END:VCALENDAR".to_string());
+
+        let mut out = String::new();
+        for line in lines {
+            fold(&mut out, &line);
+        }
+        out
+    }
+}
+
+fn push_sprint(lines: &mut Vec<String>, board: &Board, sprint: &Sprint, prefixes: DefaultPrefixes) {
+    let Some(start) = sprint.start_date else {
+        return;
+    };
+    lines.push("BEGIN:VEVENT".to_string());
+    lines.push(format!("UID:sprint-{}@kanban", sprint.id));
+    lines.push(format!("DTSTAMP:{}", timestamp(sprint.updated_at)));
+    lines.push(format!("CREATED:{}", timestamp(sprint.created_at)));
+    lines.push(format!("LAST-MODIFIED:{}", timestamp(sprint.updated_at)));
+    lines.push(format!("DTSTART:{}", timestamp(start)));
+    if let Some(end) = sprint.end_date.filter(|end| *end > start) {
+        lines.push(format!("DTEND:{}", timestamp(end)));
+    }
+    lines.push(format!(
+        "SUMMARY:{}",
+        escape(&sprint.formatted_name(board, prefixes.sprint))
+    ));
+    lines.push(format!(
+        "STATUS:{}",
+        match sprint.status {
+            SprintStatus::Planning => "TENTATIVE",
+            SprintStatus::Active | SprintStatus::Completed => "CONFIRMED",
+            SprintStatus::Cancelled => "CANCELLED",
+        }
+    ));
+    lines.push(format!("CATEGORIES:{}", escape(&board.name)));
+    lines.push("END:VEVENT".to_string());
+}
+
+fn push_card(
+    lines: &mut Vec<String>,
+    board: &Board,
+    card: &Card,
+    sprints: &[Sprint],
+    prefixes: DefaultPrefixes,
+) {
+    let Some(due) = card.due_date else {
+        return;
+    };
+    lines.push("BEGIN:VTODO".to_string());
+    lines.push(format!("UID:card-{}@kanban", card.id));
+    lines.push(format!("DTSTAMP:{}", timestamp(card.updated_at)));
+    lines.push(format!("CREATED:{}", timestamp(card.created_at)));
+    lines.push(format!("LAST-MODIFIED:{}", timestamp(card.updated_at)));
+    lines.push(format!("DUE:{}", timestamp(due)));
+    lines.push(format!(
+        "SUMMARY:{}",
+        escape(&format!(
+            "{} {}",
+            card.identifier(board, sprints, prefixes.card),
+            card.title
+        ))
+    ));
+    if let Some(description) = card.description.as_deref().filter(|d| !d.is_empty()) {
+        lines.push(format!("DESCRIPTION:{}", escape(description)));
+    }
+    // RFC 5545: 1 is the highest priority, 9 the lowest.
+    lines.push(format!(
+        "PRIORITY:{}",
+        match card.priority {
+            CardPriority::Critical => 1,
+            CardPriority::High => 3,
+            CardPriority::Medium => 5,
+            CardPriority::Low => 9,
+        }
+    ));
+    match card.status {
+        CardStatus::Done => {
+            lines.push("STATUS:COMPLETED".to_string());
+            lines.push("PERCENT-COMPLETE:100".to_string());
+            if let Some(completed) = card.completed_at {
+                lines.push(format!("COMPLETED:{}", timestamp(completed)));
+            }
+        }
+        CardStatus::InProgress => lines.push("STATUS:IN-PROCESS".to_string()),
+        CardStatus::Todo | CardStatus::Blocked => lines.push("STATUS:NEEDS-ACTION".to_string()),
+    }
+    lines.push(format!("CATEGORIES:{}", escape(&board.name)));
+    lines.push("END:VTODO".to_string());
+}
+
+fn timestamp(dt: DateTime<Utc>) -> String {
+    dt.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escape a TEXT value: backslashes, commas, semicolons and newlines.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            ',' => out.push_str("\\,"),
+            ';' => out.push_str("\\;"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Write `line` with CRLF endings, folded so no line exceeds 75 octets.
+fn fold(out: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if width + len > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += len;
+    }
+    out.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PREFIXES: DefaultPrefixes<'static> = DefaultPrefixes {
+        card: "task",
+        sprint: "sprint",
+    };
+
+    fn ts(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn fixture() -> (Board, Vec<Column>, Vec<Card>, Vec<Sprint>) {
+        let mut board = Board::new("Acme", Some("KAN"));
+        let column = Column::new(board.id, "Todo", 0);
+        let mut due = Card::new(&mut board, column.id, "Ship, then; celebrate", 0);
+        due.due_date = Some(ts("2024-05-01T12:00:00Z"));
+        due.description = Some("line one\nline two".to_string());
+        due.priority = CardPriority::Critical;
+        let mut done = Card::new(&mut board, column.id, "Done thing", 1);
+        done.due_date = Some(ts("2024-04-01T00:00:00Z"));
+        done.update_status(CardStatus::Done);
+        let undated = Card::new(&mut board, column.id, "Someday", 2);
+
+        let mut sprint = Sprint::new(board.id, 1, None, None::<String>);
+        sprint.start_date = Some(ts("2024-04-15T00:00:00Z"));
+        sprint.end_date = Some(ts("2024-04-29T00:00:00Z"));
+        let unplanned = Sprint::new(board.id, 2, None, None::<String>);
+        (
+            board,
+            vec![column],
+            vec![due, done, undated],
+            vec![sprint, unplanned],
+        )
+    }
+
+    #[test]
+    fn test_export_lists_dated_cards_and_sprints() {
+        let (board, columns, cards, sprints) = fixture();
+        let boards = std::slice::from_ref(&board);
+        let ics = IcalExporter::export_calendar(boards, &columns, &cards, &sprints, PREFIXES);
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VTODO").count(), 2);
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 1);
+        assert!(ics.contains(&format!("UID:card-{}@kanban", cards[0].id)));
+        assert!(ics.contains(&format!("UID:sprint-{}@kanban", sprints[0].id)));
+        assert!(ics.contains("SUMMARY:KAN-1 Ship\\, then\\; celebrate"));
+        assert!(ics.contains("DESCRIPTION:line one\\nline two"));
+        assert!(ics.contains("DUE:20240501T120000Z"));
+        assert!(ics.contains("PRIORITY:1"));
+        assert!(ics.contains("STATUS:COMPLETED"));
+        assert!(ics.contains("DTSTART:20240415T000000Z\r\nDTEND:20240429T000000Z"));
+        assert!(ics.contains("X-WR-CALNAME:Acme"));
+        assert!(!ics.contains("Someday"));
+    }
+
+    #[test]
+    fn test_uids_are_stable_across_exports() {
+        let (board, columns, cards, sprints) = fixture();
+        let boards = std::slice::from_ref(&board);
+        assert_eq!(
+            IcalExporter::export_calendar(boards, &columns, &cards, &sprints, PREFIXES),
+            IcalExporter::export_calendar(boards, &columns, &cards, &sprints, PREFIXES)
+        );
+        assert!(
+            IcalExporter::export_calendar(boards, &[], &cards, &sprints, PREFIXES)
+                .contains("BEGIN:VEVENT")
+        );
+    }
+
+    #[test]
+    fn test_long_lines_are_folded() {
+        let mut out = String::new();
+        fold(&mut out, &format!("SUMMARY:{}", "é".repeat(60)));
+        for line in out.split("\r\n").filter(|l| !l.is_empty()) {
+            assert!(line.len() <= 75);
+        }
+        assert_eq!(
+            out.replace("\r\n ", "").trim_end(),
+            format!("SUMMARY:{}", "é".repeat(60))
+        );
+    }
+}
@@ -2,9 +2,12 @@
 //!
 //! Supports both V1 (AllBoardsExport) and V2 (Snapshot with version envelope) formats.
 
-use super::models::{AllBoardsExport, BoardExport};
-use crate::{ArchivedCard, Board, Card, Column, Snapshot, Sprint};
+use super::exporter::BoardExporter;
+use super::models::{AllBoardsExport, EXPORT_FORMAT_VERSION};
+use crate::{ArchivedCard, Board, Card, Column, DependencyGraph, KanbanResult, Snapshot, Sprint};
+use std::collections::HashSet;
 use std::io;
+use uuid::Uuid;
 
 /// Extracted entities from an import.
 pub struct ImportedEntities {
@@ -13,6 +16,20 @@ pub struct ImportedEntities {
     pub cards: Vec<Card>,
     pub archived_cards: Vec<ArchivedCard>,
     pub sprints: Vec<Sprint>,
+    pub graph: DependencyGraph,
+}
+
+impl From<ImportedEntities> for Snapshot {
+    fn from(entities: ImportedEntities) -> Self {
+        Self {
+            boards: entities.boards,
+            columns: entities.columns,
+            cards: entities.cards,
+            archived_cards: entities.archived_cards,
+            sprints: entities.sprints,
+            graph: entities.graph,
+        }
+    }
 }
 
 /// Imports boards from JSON files.
@@ -42,6 +59,15 @@ impl BoardImporter {
         // Try V2 format first
         if let Ok(envelope) = serde_json::from_str::<serde_json::Value>(json) {
             if let Some(version) = envelope.get("version").and_then(|v| v.as_u64()) {
+                if version > u64::from(EXPORT_FORMAT_VERSION) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "Export format version {} is newer than the supported version {}; upgrade kanban to import it",
+                            version, EXPORT_FORMAT_VERSION
+                        ),
+                    ));
+                }
                 if version == 2 {
                     // V2 format: data is a Snapshot with flat structure
                     if let Some(data) = envelope.get("data") {
@@ -71,56 +97,25 @@ impl BoardImporter {
 
     /// Convert Snapshot format (V2) to AllBoardsExport format (V1-compatible).
     ///
-    /// V2 has flat structure: boards[], columns[], cards[], sprints[]
-    /// V1 has nested structure: boards[{board, columns[], cards[], sprints[]}]
+    /// V2 has flat structure: boards[], columns[], cards[], sprints[], graph
+    /// V1 has nested structure: boards[{board, columns[], cards[], sprints[], graph}]
     pub fn convert_snapshot_to_export(snapshot: Snapshot) -> AllBoardsExport {
-        let mut board_exports = Vec::new();
-
-        for board in snapshot.boards {
-            let board_columns: Vec<_> = snapshot
-                .columns
-                .iter()
-                .filter(|c| c.board_id == board.id)
-                .cloned()
-                .collect();
-
-            let board_cards: Vec<_> = snapshot
-                .cards
-                .iter()
-                .filter(|c| board_columns.iter().any(|col| col.id == c.column_id))
-                .cloned()
-                .collect();
-
-            let board_sprints: Vec<_> = snapshot
-                .sprints
-                .iter()
-                .filter(|s| s.board_id == board.id)
-                .cloned()
-                .collect();
-
-            let board_archived: Vec<_> = snapshot
-                .archived_cards
-                .iter()
-                .filter(|a| {
-                    board_columns
-                        .iter()
-                        .any(|col| col.id == a.original_column_id)
-                })
-                .cloned()
-                .collect();
-
-            board_exports.push(BoardExport {
-                board,
-                columns: board_columns,
-                cards: board_cards,
-                sprints: board_sprints,
-                archived_cards: board_archived,
-            });
-        }
+        let board_exports = snapshot
+            .boards
+            .iter()
+            .map(|board| {
+                BoardExporter::export_board(
+                    board,
+                    &snapshot.columns,
+                    &snapshot.cards,
+                    &snapshot.archived_cards,
+                    &snapshot.sprints,
+                    &snapshot.graph,
+                )
+            })
+            .collect();
 
-        AllBoardsExport {
-            boards: board_exports,
-        }
+        AllBoardsExport::from_boards(board_exports)
     }
 
     /// Import from a file path.
@@ -130,12 +125,17 @@ impl BoardImporter {
     }
 
     /// Extract flat entity lists from an AllBoardsExport.
-    pub fn extract_entities(import: AllBoardsExport) -> ImportedEntities {
+    ///
+    /// The per-board graphs are merged, dropping edges whose other end is
+    /// a card that is not part of the import. Fails if the merged edges
+    /// break a graph invariant, e.g. a hand-edited file that forms a cycle.
+    pub fn extract_entities(import: AllBoardsExport) -> KanbanResult<ImportedEntities> {
         let mut boards = Vec::new();
         let mut columns = Vec::new();
         let mut cards = Vec::new();
         let mut archived_cards = Vec::new();
         let mut sprints = Vec::new();
+        let mut graph = DependencyGraph::new();
 
         for board_data in import.boards {
             boards.push(board_data.board);
@@ -143,21 +143,34 @@ impl BoardImporter {
             cards.extend(board_data.cards);
             archived_cards.extend(board_data.archived_cards);
             sprints.extend(board_data.sprints);
+            graph.merge(&board_data.graph)?;
         }
 
-        ImportedEntities {
+        let card_ids: HashSet<Uuid> = cards
+            .iter()
+            .map(|c| c.id)
+            .chain(archived_cards.iter().map(|ac| ac.card.id))
+            .collect();
+        let graph = graph.filter_edges(|source, target| {
+            card_ids.contains(&source) && card_ids.contains(&target)
+        });
+
+        Ok(ImportedEntities {
             boards,
             columns,
             cards,
             archived_cards,
             sprints,
-        }
+            graph,
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::export::BoardExport;
+    use crate::{RelatesKind, Severity};
 
     #[test]
     fn test_import_from_json_v1_valid() {
@@ -195,8 +208,10 @@ mod tests {
         assert!(result.is_ok());
 
         let import = result.unwrap();
+        assert_eq!(import.version, 1);
         assert_eq!(import.boards.len(), 1);
         assert_eq!(import.boards[0].board.name, "Test Board");
+        assert!(import.boards[0].graph.is_empty());
     }
 
     #[test]
@@ -214,17 +229,16 @@ mod tests {
         let mut board_mut = board.clone();
         let card = Card::new(&mut board_mut, column.id, "Task", 0);
 
-        let export = AllBoardsExport {
-            boards: vec![BoardExport {
-                board: board.clone(),
-                columns: vec![column.clone()],
-                cards: vec![card.clone()],
-                archived_cards: vec![],
-                sprints: vec![],
-            }],
-        };
+        let export = AllBoardsExport::from_boards(vec![BoardExport {
+            board: board.clone(),
+            columns: vec![column.clone()],
+            cards: vec![card.clone()],
+            archived_cards: vec![],
+            sprints: vec![],
+            graph: DependencyGraph::new(),
+        }]);
 
-        let entities = BoardImporter::extract_entities(export);
+        let entities = BoardImporter::extract_entities(export).unwrap();
 
         assert_eq!(entities.boards.len(), 1);
         assert_eq!(entities.columns.len(), 1);
@@ -258,4 +272,120 @@ mod tests {
         assert_eq!(export.boards[0].board.name, "Test");
         assert_eq!(export.boards[0].columns.len(), 1);
     }
+
+    /// Two boards with edges inside each board, across the boards, to an
+    /// archived card, and to a card outside the store.
+    fn snapshot_with_edges() -> Snapshot {
+        let mut alpha = Board::new("Alpha", None::<String>);
+        let mut beta = Board::new("Beta", None::<String>);
+        let alpha_col = Column::new(alpha.id, "Todo", 0);
+        let beta_col = Column::new(beta.id, "Todo", 0);
+        let a1 = Card::new(&mut alpha, alpha_col.id, "A1", 0);
+        let a2 = Card::new(&mut alpha, alpha_col.id, "A2", 1);
+        let b1 = Card::new(&mut beta, beta_col.id, "B1", 0);
+        let archived = ArchivedCard::new(
+            Card::new(&mut alpha, alpha_col.id, "Old", 2),
+            alpha_col.id,
+            2,
+        );
+
+        let mut graph = DependencyGraph::new();
+        graph.set_parent(a2.id, a1.id).unwrap();
+        graph
+            .set_block_with_severity(a1.id, b1.id, Severity::Critical)
+            .unwrap();
+        graph
+            .relate_with_kind(a2.id, b1.id, RelatesKind::Duplicates)
+            .unwrap();
+        graph.relate(a1.id, archived.card.id).unwrap();
+        graph.archive_node(archived.card.id);
+
+        Snapshot {
+            boards: vec![alpha, beta],
+            columns: vec![alpha_col, beta_col],
+            cards: vec![a1, a2, b1],
+            archived_cards: vec![archived],
+            sprints: vec![],
+            graph,
+        }
+    }
+
+    #[test]
+    fn test_export_import_round_trip_preserves_graph() {
+        let snapshot = snapshot_with_edges();
+        let export = BoardExporter::export_all_boards(
+            &snapshot.boards,
+            &snapshot.columns,
+            &snapshot.cards,
+            &snapshot.archived_cards,
+            &snapshot.sprints,
+            &snapshot.graph,
+        );
+        let json = BoardExporter::export_to_json(&export).unwrap();
+
+        let import = BoardImporter::import_from_json(&json).unwrap();
+        assert_eq!(import.version, crate::export::EXPORT_FORMAT_VERSION);
+        let entities = BoardImporter::extract_entities(import).unwrap();
+
+        assert_eq!(entities.graph, snapshot.graph);
+        assert_eq!(entities.cards.len(), 3);
+        assert_eq!(entities.archived_cards.len(), 1);
+    }
+
+    #[test]
+    fn test_import_rejects_newer_format_version() {
+        let newer = EXPORT_FORMAT_VERSION + 1;
+        let json = format!(r#"{{"version": {}, "boards": []}}"#, newer);
+
+        let err = BoardImporter::import_from_json(&json).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let message = err.to_string();
+        assert!(message.contains(&newer.to_string()), "{message}");
+        assert!(
+            message.contains(&EXPORT_FORMAT_VERSION.to_string()),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn test_single_board_import_drops_edges_to_missing_cards() {
+        let snapshot = snapshot_with_edges();
+        let export = AllBoardsExport::from_boards(vec![BoardExporter::export_board(
+            &snapshot.boards[0],
+            &snapshot.columns,
+            &snapshot.cards,
+            &snapshot.archived_cards,
+            &snapshot.sprints,
+            &snapshot.graph,
+        )]);
+        assert_eq!(export.boards[0].graph.len(), 4);
+
+        let entities = BoardImporter::extract_entities(export).unwrap();
+        assert_eq!(entities.graph.spawns_edges(), snapshot.graph.spawns_edges());
+        assert!(entities.graph.blocks_edges().is_empty());
+        assert_eq!(entities.graph.relates_edges().len(), 1);
+        assert_eq!(entities.graph.active_len(), 1);
+    }
+
+    #[test]
+    fn test_convert_snapshot_to_export_keeps_graph() {
+        let snapshot = snapshot_with_edges();
+        let graph = snapshot.graph.clone();
+
+        let export = BoardImporter::convert_snapshot_to_export(snapshot);
+        let entities = BoardImporter::extract_entities(export).unwrap();
+        assert_eq!(entities.graph, graph);
+    }
+
+    #[test]
+    fn test_extract_entities_rejects_cycle_across_boards() {
+        let mut snapshot = snapshot_with_edges();
+        let (a1, b1) = (snapshot.cards[0].id, snapshot.cards[2].id);
+        let mut export = BoardImporter::convert_snapshot_to_export(snapshot.clone());
+        snapshot.graph = DependencyGraph::new();
+        snapshot.graph.set_block(b1, a1).unwrap();
+        export.boards[1].graph = snapshot.graph;
+
+        assert!(BoardImporter::extract_entities(export).is_err());
+    }
 }
@@ -0,0 +1,164 @@
+//! Markdown export.
+//!
+//! Renders boards as checklists that read well when pasted into pull
+//! requests and wikis. The output is for people; it cannot be imported.
+
+use super::models::DefaultPrefixes;
+use crate::{Board, Card, CardStatus, Column, DependencyGraph, Sprint};
+use std::collections::HashMap;
+use std::fmt::Write;
+use uuid::Uuid;
+
+/// Renders boards as Markdown.
+pub struct MarkdownExporter;
+
+impl MarkdownExporter {
+    /// Render `boards` with one `##` heading per column, in column order.
+    ///
+    /// Each card is a checkbox item, ticked when done, followed by its
+    /// priority, points and sprint. Child cards are listed as nested items
+    /// under their parent as well as in their own column.
+    pub fn export_boards(
+        boards: &[Board],
+        columns: &[Column],
+        cards: &[Card],
+        sprints: &[Sprint],
+        graph: &DependencyGraph,
+        prefixes: DefaultPrefixes,
+    ) -> String {
+        let cards_by_id: HashMap<Uuid, &Card> = cards.iter().map(|c| (c.id, c)).collect();
+        let mut out = String::new();
+
+        for board in boards {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            let identifier = |card: &Card| card.identifier(board, sprints, prefixes.card);
+
+            let _ = writeln!(out, "# {}", board.name);
+            if let Some(description) = board.description.as_deref().filter(|d| !d.is_empty()) {
+                let _ = writeln!(out, "\n{}", description.trim_end());
+            }
+
+            let mut board_columns: Vec<&Column> =
+                columns.iter().filter(|c| c.board_id == board.id).collect();
+            board_columns.sort_by_key(|c| c.position);
+
+            for column in board_columns {
+                let _ = writeln!(out, "\n## {}\n", column.name);
+
+                let mut column_cards: Vec<&Card> =
+                    cards.iter().filter(|c| c.column_id == column.id).collect();
+                column_cards.sort_by_key(|c| c.position);
+                if column_cards.is_empty() {
+                    let _ = writeln!(out, "_No cards_");
+                    continue;
+                }
+
+                for card in column_cards {
+                    let mut details = vec![card.priority.to_string()];
+                    if let Some(points) = card.points {
+                        details.push(format!("{} pts", points));
+                    }
+                    if let Some(sprint) = card
+                        .sprint_id
+                        .and_then(|id| sprints.iter().find(|s| s.id == id))
+                    {
+                        details.push(sprint.formatted_name(board, prefixes.sprint));
+                    }
+                    let _ = writeln!(
+                        out,
+                        "- {} **{}** {} · {}",
+                        checkbox(card),
+                        identifier(card),
+                        single_line(&card.title),
+                        details.join(" · ")
+                    );
+
+                    let mut children: Vec<&Card> = graph
+                        .children(card.id)
+                        .into_iter()
+                        .filter_map(|id| cards_by_id.get(&id).copied())
+                        .collect();
+                    children.sort_by_key(|c| c.card_number);
+                    for child in children {
+                        let _ = writeln!(
+                            out,
+                            "  - {} {} {}",
+                            checkbox(child),
+                            identifier(child),
+                            single_line(&child.title)
+                        );
+                    }
+                }
+            }
+        }
+
+        out
+    }
+}
+
+fn checkbox(card: &Card) -> &'static str {
+    if card.status == CardStatus::Done {
+        "[x]"
+    } else {
+        "[ ]"
+    }
+}
+
+/// Titles can't break out of their list item.
+fn single_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CardPriority;
+
+    const PREFIXES: DefaultPrefixes = DefaultPrefixes {
+        card: "task",
+        sprint: "sprint",
+    };
+
+    #[test]
+    fn test_markdown_lists_cards_under_column_headings() {
+        let mut board = Board::new("Release", Some("REL"));
+        board.description = Some("Ship 1.0".to_string());
+        let todo = Column::new(board.id, "Todo", 0);
+        let done = Column::new(board.id, "Done", 1);
+        let empty = Column::new(board.id, "Review", 2);
+
+        let sprint = Sprint::new(board.id, 1, None, None::<String>);
+        let mut parent = Card::new(&mut board, todo.id, "Write notes", 0);
+        parent.priority = CardPriority::High;
+        parent.points = Some(3);
+        parent.sprint_id = Some(sprint.id);
+        let mut child = Card::new(&mut board, done.id, "Changelog", 0);
+        child.status = CardStatus::Done;
+
+        let mut graph = DependencyGraph::new();
+        graph.set_parent(child.id, parent.id).unwrap();
+
+        let md = MarkdownExporter::export_boards(
+            std::slice::from_ref(&board),
+            &[done.clone(), todo.clone(), empty],
+            &[parent, child],
+            &[sprint],
+            &graph,
+            PREFIXES,
+        );
+
+        assert_eq!(
+            md,
+            "# Release\n\nShip 1.0\n\n\
+             ## Todo\n\n\
+             - [ ] **REL-1** Write notes · high · 3 pts · sprint-1\n  \
+             - [x] REL-2 Changelog\n\n\
+             ## Done\n\n\
+             - [x] **REL-2** Changelog · medium\n\n\
+             ## Review\n\n\
+             _No cards_\n"
+        );
+    }
+}
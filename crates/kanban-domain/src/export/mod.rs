@@ -1,12 +1,32 @@
 //! Board import/export functionality.
 //!
 //! Provides serialization and deserialization of board data for backup,
-//! migration, and sharing purposes.
+//! migration, and sharing purposes, plus Markdown and CSV renderings for
+//! people, spreadsheets and calendars, and importers for other tools'
+//! exports.
 
+pub mod copy;
+pub mod csv;
 pub mod exporter;
+pub mod foreign;
+pub mod github;
+pub mod ical;
 pub mod importer;
+pub mod markdown;
 pub mod models;
+pub mod taskwarrior;
+pub mod trello;
 
+pub use copy::{BoardCopier, CloneOptions};
+pub use csv::{CsvExporter, CsvField, CsvImportPlan, CsvImportSummary, CsvImporter, CsvMapping};
 pub use exporter::BoardExporter;
+pub use foreign::{
+    ForeignBoard, ForeignCard, ForeignColumnReport, ForeignImport, ForeignImportOptions,
+    ForeignImportReport, ForeignImporter, ForeignSource,
+};
+pub use ical::IcalExporter;
 pub use importer::{BoardImporter, ImportedEntities};
-pub use models::{AllBoardsExport, BoardExport};
+pub use markdown::MarkdownExporter;
+pub use models::{
+    AllBoardsExport, BoardExport, BoardExportFormat, DefaultPrefixes, EXPORT_FORMAT_VERSION,
+};
@@ -2,9 +2,21 @@
 //!
 //! These DTOs represent the structure for import/export operations.
 
-use crate::{ArchivedCard, Board, Card, Column, Sprint};
+use crate::{ArchivedCard, Board, Card, Column, DependencyGraph, Sprint};
 use serde::{Deserialize, Serialize};
 
+/// Version written to the `version` field of [`AllBoardsExport`].
+///
+/// - 1: boards with columns, cards, sprints and archived cards (no
+///   `version` field on disk)
+/// - 2: reserved for the `{"version": 2, "data": Snapshot}` envelope
+/// - 3: adds each board's dependency graph
+pub const EXPORT_FORMAT_VERSION: u32 = 3;
+
+fn legacy_format_version() -> u32 {
+    1
+}
+
 /// Export format for a single board with all its data.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BoardExport {
@@ -14,22 +26,94 @@ pub struct BoardExport {
     pub sprints: Vec<Sprint>,
     #[serde(default)]
     pub archived_cards: Vec<ArchivedCard>,
+    /// Spawns/blocks/relates edges, active and archived, with at least
+    /// one endpoint among this board's cards or archived cards.
+    #[serde(default)]
+    pub graph: DependencyGraph,
 }
 
 /// Export format for all boards.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AllBoardsExport {
+    #[serde(default = "legacy_format_version")]
+    pub version: u32,
     pub boards: Vec<BoardExport>,
 }
 
 impl AllBoardsExport {
     /// Create an empty export.
     pub fn empty() -> Self {
-        Self { boards: Vec::new() }
+        Self::from_boards(Vec::new())
     }
 
     /// Create from a list of board exports.
     pub fn from_boards(boards: Vec<BoardExport>) -> Self {
-        Self { boards }
+        Self {
+            version: EXPORT_FORMAT_VERSION,
+            boards,
+        }
+    }
+}
+
+/// Output formats for board exports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BoardExportFormat {
+    /// Versioned JSON that can be imported back losslessly.
+    #[default]
+    Json,
+    /// Human-readable checklist, one section per column.
+    Markdown,
+    /// One row per card, for spreadsheets.
+    Csv,
+    /// iCalendar feed of card due dates and sprint windows.
+    Ics,
+    /// Versioned JSON plus the content of every attachment, so a board
+    /// moves to another data file with its files.
+    Bundle,
+}
+
+impl BoardExportFormat {
+    /// Conventional file extension, without the dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Markdown => "md",
+            Self::Csv => "csv",
+            Self::Ics => "ics",
+            Self::Bundle => "bundle",
+        }
     }
+
+    /// Guess the format from a file name's extension.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let ext = std::path::Path::new(path).extension()?.to_str()?;
+        ext.parse().ok()
+    }
+}
+
+impl std::str::FromStr for BoardExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "md" | "markdown" => Ok(Self::Markdown),
+            "csv" => Ok(Self::Csv),
+            "ics" | "ical" | "icalendar" => Ok(Self::Ics),
+            "bundle" => Ok(Self::Bundle),
+            _ => Err(format!(
+                "Unknown export format '{}': use json, md, csv, ics or bundle",
+                s
+            )),
+        }
+    }
+}
+
+/// Fallback prefixes used to render card identifiers and sprint names for
+/// boards and sprints that don't set their own.
+#[derive(Debug, Clone, Copy)]
+pub struct DefaultPrefixes<'a> {
+    pub card: &'a str,
+    pub sprint: &'a str,
 }
@@ -0,0 +1,200 @@
+//! Taskwarrior `task export` JSON.
+//!
+//! Pending and waiting tasks go to `Pending`, started ones to `Active` and
+//! completed ones to `Completed`. Deleted tasks and recurrence templates
+//! are skipped. `H`/`M`/`L` priorities map to high, medium and low, tags
+//! and the project are kept as labels, and `depends` becomes blocking
+//! edges.
+
+use super::foreign::{parse_date, parse_json, ForeignBoard, ForeignCard, ForeignSource};
+use crate::{CardPriority, CardStatus, KanbanResult};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::Deserialize;
+
+const PENDING_COLUMN: &str = "Pending";
+const ACTIVE_COLUMN: &str = "Active";
+const COMPLETED_COLUMN: &str = "Completed";
+
+#[derive(Deserialize)]
+struct Task {
+    uuid: String,
+    description: String,
+    status: String,
+    priority: Option<String>,
+    due: Option<String>,
+    start: Option<String>,
+    end: Option<String>,
+    project: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    depends: Option<Depends>,
+    #[serde(default)]
+    annotations: Vec<Annotation>,
+}
+
+/// An array of uuids, or a comma separated string before Taskwarrior 2.6.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Depends {
+    List(Vec<String>),
+    Joined(String),
+}
+
+#[derive(Deserialize)]
+struct Annotation {
+    description: String,
+}
+
+pub(crate) fn read(data: &str) -> KanbanResult<ForeignBoard> {
+    let tasks: Vec<Task> = parse_json(ForeignSource::Taskwarrior, data)?;
+    let mut skipped = Vec::new();
+    let mut cards = Vec::new();
+    for task in tasks {
+        let column = match task.status.as_str() {
+            "completed" => COMPLETED_COLUMN,
+            "pending" | "waiting" if task.start.is_some() => ACTIVE_COLUMN,
+            "pending" | "waiting" => PENDING_COLUMN,
+            "deleted" => {
+                skipped.push(format!("task '{}': deleted", task.description));
+                continue;
+            }
+            "recurring" => {
+                skipped.push(format!("task '{}': recurrence template", task.description));
+                continue;
+            }
+            other => {
+                skipped.push(format!(
+                    "task '{}': unknown status '{}'",
+                    task.description, other
+                ));
+                continue;
+            }
+        };
+        let notes: Vec<&str> = task
+            .annotations
+            .iter()
+            .map(|a| a.description.as_str())
+            .collect();
+        let labels = task
+            .project
+            .iter()
+            .map(|p| format!("project:{}", p))
+            .chain(task.tags)
+            .collect();
+        cards.push(ForeignCard {
+            key: task.uuid,
+            title: task.description,
+            description: Some(notes.join("\n")),
+            column: column.to_string(),
+            priority: task.priority.as_deref().and_then(|p| match p {
+                "H" => Some(CardPriority::High),
+                "M" => Some(CardPriority::Medium),
+                "L" => Some(CardPriority::Low),
+                _ => None,
+            }),
+            status: (column == ACTIVE_COLUMN).then_some(CardStatus::InProgress),
+            due_date: task.due.as_deref().and_then(parse_timestamp),
+            completed_at: task.end.as_deref().and_then(parse_timestamp),
+            labels,
+            blocked_by: match task.depends {
+                Some(Depends::List(uuids)) => uuids,
+                Some(Depends::Joined(uuids)) => uuids
+                    .split(',')
+                    .filter(|u| !u.is_empty())
+                    .map(String::from)
+                    .collect(),
+                None => Vec::new(),
+            },
+            ..Default::default()
+        });
+    }
+
+    // A board per project reads better than one named after the tool.
+    let mut projects = cards
+        .iter()
+        .map(|c| c.labels.first().filter(|l| l.starts_with("project:")));
+    let name = match projects.next().flatten() {
+        Some(project) if projects.all(|p| p == Some(project)) => {
+            project["project:".len()..].to_string()
+        }
+        _ => "Taskwarrior".to_string(),
+    };
+
+    Ok(ForeignBoard {
+        name,
+        columns: vec![
+            PENDING_COLUMN.to_string(),
+            ACTIVE_COLUMN.to_string(),
+            COMPLETED_COLUMN.to_string(),
+        ],
+        completion_column: Some(COMPLETED_COLUMN.to_string()),
+        cards,
+        skipped,
+        ..Default::default()
+    })
+}
+
+/// Taskwarrior's `20241020T120000Z`, or ISO 8601 as some versions write.
+fn parse_timestamp(s: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(s, "%Y%m%dT%H%M%SZ")
+        .map(|dt| dt.and_utc())
+        .ok()
+        .or_else(|| parse_date(s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Datelike;
+
+    const EXPORT: &str = r#"[
+        {"uuid": "a", "description": "Write docs", "status": "pending",
+         "priority": "H", "due": "20241020T120000Z", "project": "site",
+         "tags": ["blocked"], "depends": ["b"],
+         "annotations": [{"entry": "20241001T000000Z", "description": "see wiki"}]},
+        {"uuid": "b", "description": "Build", "status": "pending",
+         "start": "20241002T000000Z", "project": "site", "depends": "a,"},
+        {"uuid": "c", "description": "Deploy", "status": "completed",
+         "end": "20241003T000000Z", "project": "site"},
+        {"uuid": "d", "description": "Old", "status": "deleted"}
+    ]"#;
+
+    #[test]
+    fn test_read_maps_status_priority_and_depends() {
+        let board = read(EXPORT).unwrap();
+        assert_eq!(board.name, "site");
+        assert_eq!(board.skipped.len(), 1);
+        let [docs, build, deploy] = &board.cards[..] else {
+            panic!("expected three cards");
+        };
+        assert_eq!(docs.column, "Pending");
+        assert_eq!(docs.priority, Some(CardPriority::High));
+        assert_eq!(docs.due_date.unwrap().day(), 20);
+        assert_eq!(docs.description.as_deref(), Some("see wiki"));
+        assert_eq!(docs.labels, ["project:site", "blocked"]);
+        assert_eq!(docs.blocked_by, ["b"]);
+        assert_eq!(build.column, "Active");
+        assert_eq!(build.status, Some(CardStatus::InProgress));
+        assert_eq!(build.blocked_by, ["a"]);
+        assert_eq!(deploy.column, "Completed");
+        assert!(deploy.completed_at.is_some());
+    }
+
+    #[test]
+    fn test_build_reports_dependency_cycles_as_skipped() {
+        let import = read(EXPORT).unwrap().build(0).unwrap();
+        assert_eq!(import.report.blocking_edges, 1);
+        assert_eq!(import.report.skipped.len(), 2);
+        let docs = &import.snapshot.cards[0];
+        assert_eq!(docs.status, CardStatus::Blocked);
+    }
+
+    #[test]
+    fn test_read_names_mixed_projects_after_tool() {
+        let data = r#"[
+            {"uuid": "a", "description": "One", "status": "pending", "project": "x"},
+            {"uuid": "b", "description": "Two", "status": "pending"}
+        ]"#;
+        assert_eq!(read(data).unwrap().name, "Taskwarrior");
+    }
+}
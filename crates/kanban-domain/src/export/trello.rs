@@ -0,0 +1,244 @@
+//! Trello board JSON.
+//!
+//! Open lists become columns in board order and open cards become cards.
+//! A list named like `Done` is the completion column, and cards whose due
+//! date is marked complete are done. Checklist items become child cards of
+//! their card.
+
+use super::foreign::{parse_date, parse_json, ForeignBoard, ForeignCard, ForeignSource};
+use crate::{CardStatus, KanbanResult};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+const COMPLETION_LISTS: &[&str] = &["done", "complete", "completed", "closed", "finished"];
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrelloBoard {
+    name: String,
+    #[serde(default)]
+    desc: String,
+    #[serde(default)]
+    lists: Vec<TrelloList>,
+    #[serde(default)]
+    cards: Vec<TrelloCard>,
+    #[serde(default)]
+    checklists: Vec<TrelloChecklist>,
+}
+
+#[derive(Deserialize)]
+struct TrelloList {
+    id: String,
+    name: String,
+    #[serde(default)]
+    closed: bool,
+    #[serde(default)]
+    pos: f64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrelloCard {
+    id: String,
+    name: String,
+    #[serde(default)]
+    desc: String,
+    id_list: String,
+    #[serde(default)]
+    closed: bool,
+    #[serde(default)]
+    pos: f64,
+    due: Option<String>,
+    #[serde(default)]
+    due_complete: bool,
+    #[serde(default)]
+    labels: Vec<TrelloLabel>,
+}
+
+#[derive(Deserialize)]
+struct TrelloLabel {
+    #[serde(default)]
+    name: String,
+    color: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrelloChecklist {
+    id_card: String,
+    #[serde(default)]
+    pos: f64,
+    #[serde(default)]
+    check_items: Vec<TrelloCheckItem>,
+}
+
+#[derive(Deserialize)]
+struct TrelloCheckItem {
+    id: String,
+    name: String,
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    pos: f64,
+    due: Option<String>,
+}
+
+pub(crate) fn read(data: &str) -> KanbanResult<ForeignBoard> {
+    let mut trello: TrelloBoard = parse_json(ForeignSource::Trello, data)?;
+    let mut skipped = Vec::new();
+
+    trello.lists.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+    let open_lists: HashMap<&str, &str> = trello
+        .lists
+        .iter()
+        .filter(|l| !l.closed)
+        .map(|l| (l.id.as_str(), l.name.as_str()))
+        .collect();
+    let columns: Vec<String> = trello
+        .lists
+        .iter()
+        .filter(|l| !l.closed)
+        .map(|l| l.name.clone())
+        .collect();
+    let completion_column = columns
+        .iter()
+        .find(|name| COMPLETION_LISTS.contains(&name.to_lowercase().as_str()))
+        .cloned();
+
+    let mut checklists: HashMap<&str, Vec<&TrelloChecklist>> = HashMap::new();
+    for checklist in &trello.checklists {
+        checklists
+            .entry(checklist.id_card.as_str())
+            .or_default()
+            .push(checklist);
+    }
+
+    trello.cards.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+    let mut cards = Vec::new();
+    for card in &trello.cards {
+        let Some(&column) = open_lists.get(card.id_list.as_str()) else {
+            skipped.push(format!("card '{}': its list is archived", card.name));
+            continue;
+        };
+        if card.closed {
+            skipped.push(format!("card '{}': archived", card.name));
+            continue;
+        }
+        cards.push(ForeignCard {
+            key: card.id.clone(),
+            title: card.name.clone(),
+            description: Some(card.desc.clone()),
+            column: column.to_string(),
+            status: card.due_complete.then_some(CardStatus::Done),
+            due_date: card.due.as_deref().and_then(parse_date),
+            labels: card
+                .labels
+                .iter()
+                .filter_map(|l| {
+                    if l.name.is_empty() {
+                        l.color.clone()
+                    } else {
+                        Some(l.name.clone())
+                    }
+                })
+                .collect(),
+            ..Default::default()
+        });
+
+        let mut items: Vec<(&TrelloChecklist, &TrelloCheckItem)> = checklists
+            .get(card.id.as_str())
+            .into_iter()
+            .flatten()
+            .flat_map(|checklist| checklist.check_items.iter().map(move |i| (*checklist, i)))
+            .collect();
+        items.sort_by(|(la, a), (lb, b)| la.pos.total_cmp(&lb.pos).then(a.pos.total_cmp(&b.pos)));
+        for (_, item) in items {
+            let complete = item.state == "complete";
+            cards.push(ForeignCard {
+                key: item.id.clone(),
+                title: item.name.clone(),
+                column: match (&completion_column, complete) {
+                    (Some(done), true) => done.clone(),
+                    _ => column.to_string(),
+                },
+                status: complete.then_some(CardStatus::Done),
+                due_date: item.due.as_deref().and_then(parse_date),
+                parent: Some(card.id.clone()),
+                ..Default::default()
+            });
+        }
+    }
+
+    Ok(ForeignBoard {
+        name: trello.name,
+        description: Some(trello.desc).filter(|d| !d.is_empty()),
+        columns,
+        completion_column,
+        cards,
+        skipped,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CardPriority;
+
+    const BOARD: &str = r#"{
+        "name": "Roadmap",
+        "desc": "Q3",
+        "lists": [
+            {"id": "l2", "name": "Done", "pos": 2},
+            {"id": "l1", "name": "To Do", "pos": 1},
+            {"id": "l3", "name": "Old", "closed": true, "pos": 3}
+        ],
+        "cards": [
+            {"id": "c1", "name": "Launch", "desc": "Ship it", "idList": "l1", "pos": 1,
+             "due": "2024-05-01T12:00:00.000Z",
+             "labels": [{"name": "High", "color": "red"}, {"name": "", "color": "green"}]},
+            {"id": "c2", "name": "Shipped", "idList": "l2", "pos": 1, "dueComplete": true},
+            {"id": "c3", "name": "Gone", "idList": "l1", "closed": true, "pos": 2},
+            {"id": "c4", "name": "Stale", "idList": "l3", "pos": 1}
+        ],
+        "checklists": [
+            {"id": "k1", "idCard": "c1", "pos": 1, "checkItems": [
+                {"id": "i2", "name": "Announce", "state": "incomplete", "pos": 2},
+                {"id": "i1", "name": "Build", "state": "complete", "pos": 1}
+            ]}
+        ]
+    }"#;
+
+    #[test]
+    fn test_read_maps_lists_cards_and_checklists() {
+        let board = read(BOARD).unwrap();
+        assert_eq!(board.name, "Roadmap");
+        assert_eq!(board.description.as_deref(), Some("Q3"));
+        assert_eq!(board.columns, ["To Do", "Done"]);
+        assert_eq!(board.completion_column.as_deref(), Some("Done"));
+        assert_eq!(board.skipped.len(), 2);
+
+        let titles: Vec<_> = board.cards.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(titles, ["Launch", "Build", "Announce", "Shipped"]);
+        assert_eq!(board.cards[0].labels, ["High", "green"]);
+        assert!(board.cards[0].due_date.is_some());
+        assert_eq!(board.cards[1].column, "Done");
+        assert_eq!(board.cards[1].parent.as_deref(), Some("c1"));
+        assert_eq!(board.cards[2].column, "To Do");
+        assert_eq!(board.cards[3].status, Some(CardStatus::Done));
+    }
+
+    #[test]
+    fn test_build_turns_checklist_items_into_children() {
+        let import = read(BOARD).unwrap().build(0).unwrap();
+        let snapshot = &import.snapshot;
+        let launch = snapshot.cards.iter().find(|c| c.title == "Launch").unwrap();
+        assert_eq!(launch.priority, CardPriority::High);
+        assert_eq!(snapshot.graph.children(launch.id).len(), 2);
+        assert_eq!(import.report.parent_edges, 2);
+    }
+
+    #[test]
+    fn test_read_rejects_invalid_json() {
+        assert!(read("[]").is_err());
+    }
+}
@@ -302,7 +302,7 @@ impl DataStore for InMemoryStore {
     fn list_archived_cards(&self) -> KanbanResult<Vec<ArchivedCard>> {
         let state = self.read_state()?;
         let mut acs: Vec<ArchivedCard> = state.archived_cards.values().cloned().collect();
-        acs.sort_by(|a, b| a.archived_at.cmp(&b.archived_at));
+        acs.sort_by_key(|a| a.archived_at);
         Ok(acs)
     }
 
@@ -323,7 +323,7 @@ impl DataStore for InMemoryStore {
             .filter(|ac| column_ids.contains(&ac.original_column_id))
             .cloned()
             .collect();
-        acs.sort_by(|a, b| a.archived_at.cmp(&b.archived_at));
+        acs.sort_by_key(|a| a.archived_at);
         Ok(acs)
     }
 
@@ -428,7 +428,7 @@ impl DataStore for InMemoryStore {
         cards.sort_by_key(|c| c.position);
 
         let mut archived_cards: Vec<_> = state.archived_cards.values().cloned().collect();
-        archived_cards.sort_by(|a, b| a.archived_at.cmp(&b.archived_at));
+        archived_cards.sort_by_key(|a| a.archived_at);
 
         let mut sprints: Vec<_> = state.sprints.values().cloned().collect();
         sprints.sort_by_key(|s| s.sprint_number);
@@ -1,9 +1,12 @@
 pub mod error;
 
+pub mod activity;
 pub mod archived_card;
+pub mod attachment;
 pub mod board;
 pub mod card;
 pub mod card_lifecycle;
+pub mod checklist;
 pub mod column;
 pub mod command_store;
 pub mod commands;
@@ -19,27 +22,38 @@ pub mod operations;
 pub mod query;
 pub mod search;
 pub mod snapshot;
+pub mod snapshot_diff;
 pub mod sort;
 pub mod sprint;
 pub mod sprint_log;
 pub mod tag;
 pub mod task_list_view;
+pub mod template;
 
+pub use activity::{ActivityEntry, GIT_ACTIVITY_SOURCE};
 pub use archived_card::{ArchivedCard, ArchivedCardSummary};
+pub use attachment::{Attachment, AttachmentGcReport};
 pub use board::{
     get_active_sprint_card_prefix_override, get_active_sprint_prefix_override, Board, BoardId,
     BoardUpdate, SortField, SortOrder,
 };
 pub use card::{
-    AnimationType, Card, CardId, CardPriority, CardStatus, CardSummary, CardUpdate,
-    CreateCardOptions,
+    card_identifier, AnimationType, Card, CardId, CardPriority, CardStatus, CardSummary,
+    CardUpdate, CreateCardOptions,
 };
+pub use checklist::{ChecklistItem, ChecklistProgress};
 pub use column::{Column, ColumnId, ColumnUpdate};
 pub use dependencies::{
     BlocksEdge, CardEdgeType, DependencyGraph, RelatesEdge, RelatesKind, Severity, SpawnsEdge,
 };
 pub use editable::{BoardSettingsDto, CardMetadataDto};
-pub use export::{AllBoardsExport, BoardExport, BoardExporter, BoardImporter, ImportedEntities};
+pub use export::{
+    AllBoardsExport, BoardCopier, BoardExport, BoardExportFormat, BoardExporter, BoardImporter,
+    CloneOptions, CsvExporter, CsvField, CsvImportPlan, CsvImportSummary, CsvImporter, CsvMapping,
+    DefaultPrefixes, ForeignBoard, ForeignCard, ForeignColumnReport, ForeignImport,
+    ForeignImportOptions, ForeignImportReport, ForeignImporter, ForeignSource, IcalExporter,
+    ImportedEntities, MarkdownExporter,
+};
 pub use field_update::FieldUpdate;
 pub use filter::CardFilters;
 pub use graph_operations::GraphOperations;
@@ -53,16 +67,20 @@ pub use query::{
     ArchivedCardListFilter, CardListFilter, CardQueryBuilder,
 };
 pub use search::{
-    find_boards_by_name, find_cards_by_identifier, find_columns_by_name,
-    find_sprints_by_query_global, find_sprints_by_query_on_board, format_ambiguous_matches,
-    BranchNameSearcher, CardSearcher, CompositeSearcher, SearchBy, TitleSearcher,
+    find_boards_by_name, find_card_backlinks, find_card_references, find_cards_by_identifier,
+    find_columns_by_name, find_identifier_mentions, find_sprints_by_query_global,
+    find_sprints_by_query_on_board, format_ambiguous_matches, index_cards_by_identifier,
+    BranchNameSearcher, CardIdentifierIndex, CardSearcher, CompositeSearcher, SearchBy,
+    TitleSearcher,
 };
 pub use snapshot::Snapshot;
+pub use snapshot_diff::{diff_snapshots, ChangeKind, ChangedEntity, SnapshotChange};
 pub use sort::{get_sorter_for_field, resolve_sort, sort_cards_in_place, OrderedSorter, SortBy};
 pub use sprint::{Sprint, SprintId, SprintStatus, SprintUpdate};
 pub use sprint_log::SprintLog;
 pub use tag::{Tag, TagId};
 pub use task_list_view::TaskListView;
+pub use template::{BoardTemplate, CardTemplate, ColumnTemplate, BUILTIN_BOARD_TEMPLATES};
 
 pub use command_store::CommandStore;
 pub use data_store::{DataStore, GraphMutFn};
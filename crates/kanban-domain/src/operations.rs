@@ -1,9 +1,11 @@
 use crate::query::filter_sort::{filter_and_sort_cards, ArchivedCardListFilter, CardListFilter};
 use crate::KanbanResult;
 use crate::{
-    AmbiguousMatch, ArchivedCard, BatchResolutionCause, BatchResolutionFailure, Board, BoardUpdate,
-    Card, CardSummary, CardUpdate, Column, ColumnUpdate, CreateCardOptions, KanbanError, Sprint,
-    SprintUpdate,
+    AmbiguousMatch, ArchivedCard, Attachment, AttachmentGcReport, BatchResolutionCause,
+    BatchResolutionFailure, Board, BoardExportFormat, BoardTemplate, BoardUpdate, Card,
+    CardSummary, CardUpdate, ChecklistItem, CloneOptions, Column, ColumnUpdate, CreateCardOptions,
+    CsvImportSummary, CsvMapping, ForeignImportOptions, ForeignImportReport, ForeignSource,
+    KanbanError, Sprint, SprintUpdate,
 };
 use uuid::Uuid;
 
@@ -16,6 +18,20 @@ pub trait KanbanOperations {
     fn get_board(&self, id: Uuid) -> KanbanResult<Option<Board>>;
     fn update_board(&mut self, id: Uuid, updates: BoardUpdate) -> KanbanResult<Board>;
     fn delete_board(&mut self, id: Uuid) -> KanbanResult<()>;
+    /// Create a board named `name` with the template's columns, settings
+    /// and seed cards.
+    fn create_board_from_template(
+        &mut self,
+        name: String,
+        template: &BoardTemplate,
+    ) -> KanbanResult<Board>;
+    /// Capture a board's layout, and optionally its cards, as a template.
+    fn board_template(
+        &self,
+        board_id: Uuid,
+        template_name: String,
+        include_cards: bool,
+    ) -> KanbanResult<BoardTemplate>;
 
     // Column operations
     fn create_column(
@@ -94,6 +110,83 @@ pub trait KanbanOperations {
     fn get_card_branch_name(&self, id: Uuid) -> KanbanResult<String>;
     fn get_card_git_checkout(&self, id: Uuid) -> KanbanResult<String>;
 
+    // Card checklist operations
+    /// Add an item at `position` (0-based), or at the end.
+    fn add_checklist_item(
+        &mut self,
+        card_id: Uuid,
+        text: String,
+        position: Option<usize>,
+    ) -> KanbanResult<ChecklistItem>;
+    fn update_checklist_item(
+        &mut self,
+        card_id: Uuid,
+        item_id: Uuid,
+        text: Option<String>,
+        done: Option<bool>,
+    ) -> KanbanResult<ChecklistItem>;
+    fn toggle_checklist_item(
+        &mut self,
+        card_id: Uuid,
+        item_id: Uuid,
+    ) -> KanbanResult<ChecklistItem> {
+        let card = self
+            .get_card(card_id)?
+            .ok_or_else(|| KanbanError::not_found("Card", card_id))?;
+        let done = card
+            .checklist
+            .iter()
+            .find(|item| item.id == item_id)
+            .map(|item| item.done)
+            .ok_or_else(|| KanbanError::not_found("Checklist item", item_id))?;
+        self.update_checklist_item(card_id, item_id, None, Some(!done))
+    }
+    fn move_checklist_item(
+        &mut self,
+        card_id: Uuid,
+        item_id: Uuid,
+        position: usize,
+    ) -> KanbanResult<Card>;
+    fn remove_checklist_item(&mut self, card_id: Uuid, item_id: Uuid) -> KanbanResult<Card>;
+    /// Replace an item with a child card titled after it, in the parent's
+    /// column, as one undo unit. Returns the new card.
+    fn convert_checklist_item_to_card(
+        &mut self,
+        card_id: Uuid,
+        item_id: Uuid,
+    ) -> KanbanResult<Card>;
+    /// Move the Markdown task lines (`- [ ] …`) of the card's description
+    /// into its checklist as one undo unit. Returns the items added; tasks
+    /// already on the checklist are dropped from the description only.
+    fn import_checklist_from_description(
+        &mut self,
+        card_id: Uuid,
+    ) -> KanbanResult<Vec<ChecklistItem>>;
+
+    // Card attachment operations
+    /// Store `content` in the backend's blob store and attach it to the
+    /// card as `name`.
+    fn add_attachment(
+        &mut self,
+        card_id: Uuid,
+        name: String,
+        content: &[u8],
+    ) -> KanbanResult<Attachment>;
+    /// An attachment of a live card together with its content.
+    fn read_attachment(
+        &self,
+        card_id: Uuid,
+        attachment_id: Uuid,
+    ) -> KanbanResult<(Attachment, Vec<u8>)>;
+    /// Detach an attachment. Its content stays stored, so the removal can
+    /// be undone, until [`collect_attachment_garbage`](Self::collect_attachment_garbage).
+    fn remove_attachment(&mut self, card_id: Uuid, attachment_id: Uuid) -> KanbanResult<Card>;
+    /// Delete stored content that no live or archived card, and no undo
+    /// or redo step, refers to; steps that re-insert deleted cards count.
+    /// Undo history held by other processes is not visible here. `dry_run`
+    /// only reports what would go.
+    fn collect_attachment_garbage(&mut self, dry_run: bool) -> KanbanResult<AttachmentGcReport>;
+
     // Multi-card operations
     fn archive_cards(&mut self, ids: Vec<Uuid>) -> KanbanResult<usize>;
     fn move_cards(&mut self, ids: Vec<Uuid>, column_id: Uuid) -> KanbanResult<usize>;
@@ -128,7 +221,41 @@ pub trait KanbanOperations {
 
     // Import/Export
     fn export_board(&self, board_id: Option<Uuid>) -> KanbanResult<String>;
+    /// Export one board, or all boards, in `format`.
+    fn export_board_as(
+        &self,
+        board_id: Option<Uuid>,
+        format: BoardExportFormat,
+    ) -> KanbanResult<String>;
     fn import_board(&mut self, data: &str) -> KanbanResult<Board>;
+    /// Import like [`import_board`](Self::import_board), but under fresh ids
+    /// with cards renumbered, so the data can sit next to the original.
+    fn import_board_as_copy(&mut self, data: &str) -> KanbanResult<Board>;
+    /// Copy a board's columns, and optionally its cards and sprints, into a
+    /// new board named `name`.
+    fn clone_board(
+        &mut self,
+        board_id: Uuid,
+        name: String,
+        options: CloneOptions,
+    ) -> KanbanResult<Board>;
+    /// Apply a CSV of cards as one undoable batch: rows with an identifier
+    /// update that card, rows without one create a card. `board_id`
+    /// restricts matching and creation to one board.
+    fn import_cards_csv(
+        &mut self,
+        board_id: Option<Uuid>,
+        data: &str,
+        mapping: &CsvMapping,
+    ) -> KanbanResult<CsvImportSummary>;
+    /// Create a board from another tool's export as one undoable batch,
+    /// or with `options.dry_run` only report what would be created.
+    fn import_foreign(
+        &mut self,
+        source: ForeignSource,
+        data: &str,
+        options: ForeignImportOptions,
+    ) -> KanbanResult<ForeignImportReport>;
 
     // ---------- Name/UUID resolvers (shared by CLI, MCP, anything else) ----------
     //
@@ -4,6 +4,9 @@
 //! Used by both TUI and API for consistent search behavior.
 
 use crate::{Board, Card, Column, Sprint};
+use std::collections::HashMap;
+use std::ops::Range;
+use uuid::Uuid;
 
 /// Trait for searching cards by various criteria.
 pub trait CardSearcher {
@@ -265,6 +268,131 @@ pub fn find_cards_by_identifier<'a>(
         .collect()
 }
 
+/// Byte ranges of the words in `text` shaped like card identifiers
+/// (`KAN-17`, `task-3`), to resolve with [`find_cards_by_identifier`].
+///
+/// A mention is a `PREFIX-N` word whose prefix starts with a letter. Words
+/// inside paths, URLs and e-mail addresses (`docs/KAN-1`, `a@b-2`) are
+/// skipped.
+pub fn find_identifier_mentions(text: &str) -> Vec<Range<usize>> {
+    let is_word = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-';
+    let mut mentions = Vec::new();
+    let mut rest = 0;
+    while let Some(offset) = text[rest..].find(is_word) {
+        let start = rest + offset;
+        let len = text[start..]
+            .find(|c| !is_word(c))
+            .unwrap_or(text.len() - start);
+        let word = text[start..start + len].trim_end_matches('-');
+        let end = start + word.len();
+        rest = start + len;
+
+        let before = text[..start].chars().next_back();
+        let after = text[end..].chars().next();
+        let in_path = |c: Option<char>| matches!(c, Some('/' | '@' | '\\'));
+        let continues = text[end..].starts_with('.') && text[end + 1..].starts_with(is_word);
+        if is_identifier_word(word) && !in_path(before) && !in_path(after) && !continues {
+            mentions.push(start..end);
+        }
+    }
+    mentions
+}
+
+/// Cards keyed by lowercase identifier prefix and number, for resolving
+/// many mentions without scanning every card for each one.
+pub type CardIdentifierIndex<'a> = HashMap<(String, u32), Vec<&'a Card>>;
+
+/// Index `cards` by identifier, resolving prefixes the way
+/// [`find_cards_by_identifier`] does.
+pub fn index_cards_by_identifier<'a>(
+    cards: &'a [Card],
+    columns: &[Column],
+    boards: &[Board],
+    sprints: &[Sprint],
+) -> CardIdentifierIndex<'a> {
+    let boards: HashMap<Uuid, &Board> = boards.iter().map(|b| (b.id, b)).collect();
+    let column_boards: HashMap<Uuid, &Board> = columns
+        .iter()
+        .filter_map(|col| Some((col.id, *boards.get(&col.board_id)?)))
+        .collect();
+    let mut index = CardIdentifierIndex::new();
+    for card in cards {
+        let Some(board) = column_boards.get(&card.column_id) else {
+            continue;
+        };
+        let prefix = card
+            .sprint_id
+            .and_then(|sid| sprints.iter().find(|s| s.id == sid))
+            .and_then(|s| s.card_prefix.as_deref())
+            .or(board.card_prefix.as_deref())
+            .unwrap_or("task");
+        index
+            .entry((prefix.to_lowercase(), card.card_number))
+            .or_default()
+            .push(card);
+    }
+    index
+}
+
+/// Cards that `card` mentions by identifier in its description or
+/// checklist, in order of first mention.
+///
+/// Identifiers that match no card or several cards are skipped, as are
+/// mentions of `card` itself.
+pub fn find_card_references<'a>(card: &Card, index: &CardIdentifierIndex<'a>) -> Vec<&'a Card> {
+    let mut references: Vec<&'a Card> = Vec::new();
+    for text in card_texts(card) {
+        for mention in find_identifier_mentions(text) {
+            let Some(ParsedIdentifier::PrefixAndNumber { prefix, number }) =
+                parse_identifier(&text[mention])
+            else {
+                continue;
+            };
+            if let Some([target]) = index.get(&(prefix, number)).map(Vec::as_slice) {
+                if target.id != card.id && !references.iter().any(|c| c.id == target.id) {
+                    references.push(target);
+                }
+            }
+        }
+    }
+    references
+}
+
+/// Cards whose description or checklist mentions the card `card_id`; see
+/// [`find_card_references`].
+pub fn find_card_backlinks<'a>(
+    card_id: Uuid,
+    cards: &'a [Card],
+    index: &CardIdentifierIndex<'a>,
+) -> Vec<&'a Card> {
+    cards
+        .iter()
+        .filter(|card| {
+            find_card_references(card, index)
+                .iter()
+                .any(|target| target.id == card_id)
+        })
+        .collect()
+}
+
+/// The free text of a card that may mention other cards.
+fn card_texts(card: &Card) -> impl Iterator<Item = &str> {
+    card.description
+        .as_deref()
+        .into_iter()
+        .chain(card.checklist.iter().map(|item| item.text.as_str()))
+}
+
+/// `PREFIX-N`, with a prefix that starts with a letter.
+fn is_identifier_word(word: &str) -> bool {
+    let Some((prefix, number)) = word.rsplit_once('-') else {
+        return false;
+    };
+    prefix.starts_with(|c: char| c.is_ascii_alphabetic())
+        && !number.is_empty()
+        && number.chars().all(|c| c.is_ascii_digit())
+}
+
 /// Format an error message listing ambiguous card matches.
 ///
 /// Used by both CLI and MCP when an identifier resolves to multiple cards.
@@ -994,4 +1122,52 @@ mod tests {
         let result = find_sprints_by_query_on_board("13", &sprints, &board_a);
         assert!(result.is_empty());
     }
+
+    #[test]
+    fn test_find_identifier_mentions() {
+        let text = "See KAN-17, task-3 and (my-board-42). Not 12-3 or KAN- or KAN-x.";
+        let found: Vec<&str> = find_identifier_mentions(text)
+            .into_iter()
+            .map(|r| &text[r])
+            .collect();
+        assert_eq!(found, ["KAN-17", "task-3", "my-board-42"]);
+    }
+
+    #[test]
+    fn test_find_card_references_and_backlinks() {
+        let mut board = Board::new("Project", None::<String>);
+        board.card_prefix = Some("KAN".to_string());
+        let column = crate::Column::new(board.id, "Todo", 0);
+        let mut first = Card::new(&mut board, column.id, "First", 0);
+        let second = Card::new(&mut board, column.id, "Second", 1);
+        let mut third = Card::new(&mut board, column.id, "Third", 2);
+        first.description = Some("Blocked on KAN-2, see also KAN-1 and KAN-9. KAN-2 again".into());
+        third.checklist.push(crate::ChecklistItem::new(
+            "Review KAN-2",
+            chrono::Utc::now(),
+        ));
+        let boards = vec![board];
+        let columns = vec![column];
+        let cards = vec![first.clone(), second.clone(), third.clone()];
+
+        let index = index_cards_by_identifier(&cards, &columns, &boards, &[]);
+        let references = find_card_references(&first, &index);
+        let ids: Vec<Uuid> = references.iter().map(|c| c.id).collect();
+        assert_eq!(ids, [second.id]);
+
+        let backlinks = find_card_backlinks(second.id, &cards, &index);
+        let ids: Vec<Uuid> = backlinks.iter().map(|c| c.id).collect();
+        assert_eq!(ids, [first.id, third.id]);
+        assert!(find_card_backlinks(first.id, &cards, &index).is_empty());
+    }
+
+    #[test]
+    fn test_find_identifier_mentions_skips_paths_and_urls() {
+        let text = "docs/KAN-1 KAN-2/x me@KAN-3 KAN-5.txt KAN-6.";
+        let found: Vec<&str> = find_identifier_mentions(text)
+            .into_iter()
+            .map(|r| &text[r])
+            .collect();
+        assert_eq!(found, ["KAN-6"]);
+    }
 }
@@ -0,0 +1,316 @@
+//! Entity-level differences between two [`Snapshot`]s.
+//!
+//! Used by streaming surfaces (`kanban watch`) to describe what an external
+//! write changed without access to the commands that produced it.
+
+use crate::{ArchivedCard, Card, Snapshot};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+
+/// Fields that change on every mutation and carry no information of their own.
+const IGNORED_FIELDS: &[&str] = &["updated_at"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangedEntity {
+    Board,
+    Column,
+    Card,
+    Sprint,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Created,
+    Updated,
+    /// A card changed column.
+    Moved,
+    Archived,
+    Restored,
+    Deleted,
+}
+
+/// One entity that differs between two snapshots.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotChange {
+    pub entity: ChangedEntity,
+    pub change: ChangeKind,
+    pub id: Uuid,
+    /// Board the entity belongs to, when it can be resolved from either side.
+    pub board_id: Option<Uuid>,
+    /// Top-level fields whose values differ. Empty for creations/deletions.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+}
+
+/// Compute the entity changes that turn `before` into `after`.
+///
+/// Changes are ordered boards, columns, sprints, then cards, so consumers
+/// see a container appear before anything placed in it.
+pub fn diff_snapshots(before: &Snapshot, after: &Snapshot) -> Vec<SnapshotChange> {
+    let column_boards: HashMap<Uuid, Uuid> = before
+        .columns
+        .iter()
+        .chain(after.columns.iter())
+        .map(|c| (c.id, c.board_id))
+        .collect();
+
+    let mut changes = Vec::new();
+    diff_entities(
+        &mut changes,
+        ChangedEntity::Board,
+        index(&before.boards, |b| b.id),
+        index(&after.boards, |b| b.id),
+        |b| Some(b.id),
+    );
+    diff_entities(
+        &mut changes,
+        ChangedEntity::Column,
+        index(&before.columns, |c| c.id),
+        index(&after.columns, |c| c.id),
+        |c| Some(c.board_id),
+    );
+    diff_entities(
+        &mut changes,
+        ChangedEntity::Sprint,
+        index(&before.sprints, |s| s.id),
+        index(&after.sprints, |s| s.id),
+        |s| Some(s.board_id),
+    );
+    diff_cards(&mut changes, before, after, &column_boards);
+    changes
+}
+
+fn index<T, F: Fn(&T) -> Uuid>(items: &[T], id: F) -> BTreeMap<Uuid, &T> {
+    items.iter().map(|item| (id(item), item)).collect()
+}
+
+fn changed_fields<T: Serialize>(before: &T, after: &T) -> Vec<String> {
+    let (Ok(Value::Object(old)), Ok(Value::Object(new))) =
+        (serde_json::to_value(before), serde_json::to_value(after))
+    else {
+        return Vec::new();
+    };
+    new.iter()
+        .filter(|(key, value)| {
+            !IGNORED_FIELDS.contains(&key.as_str()) && old.get(key.as_str()) != Some(value)
+        })
+        .map(|(key, _)| key.clone())
+        .chain(
+            old.keys()
+                .filter(|key| !new.contains_key(key.as_str()))
+                .cloned(),
+        )
+        .collect()
+}
+
+fn diff_entities<T, F>(
+    changes: &mut Vec<SnapshotChange>,
+    entity: ChangedEntity,
+    before: BTreeMap<Uuid, &T>,
+    after: BTreeMap<Uuid, &T>,
+    board_of: F,
+) where
+    T: Serialize,
+    F: Fn(&T) -> Option<Uuid>,
+{
+    for (id, new) in &after {
+        let (change, fields) = match before.get(id) {
+            None => (ChangeKind::Created, Vec::new()),
+            Some(old) => {
+                let fields = changed_fields(*old, *new);
+                if fields.is_empty() {
+                    continue;
+                }
+                (ChangeKind::Updated, fields)
+            }
+        };
+        changes.push(SnapshotChange {
+            entity,
+            change,
+            id: *id,
+            board_id: board_of(new),
+            fields,
+        });
+    }
+    for (id, old) in &before {
+        if !after.contains_key(id) {
+            changes.push(SnapshotChange {
+                entity,
+                change: ChangeKind::Deleted,
+                id: *id,
+                board_id: board_of(old),
+                fields: Vec::new(),
+            });
+        }
+    }
+}
+
+fn diff_cards(
+    changes: &mut Vec<SnapshotChange>,
+    before: &Snapshot,
+    after: &Snapshot,
+    column_boards: &HashMap<Uuid, Uuid>,
+) {
+    let old_cards = index(&before.cards, |c| c.id);
+    let new_cards = index(&after.cards, |c| c.id);
+    let old_archived = index(&before.archived_cards, |a| a.card.id);
+    let new_archived = index(&after.archived_cards, |a| a.card.id);
+    let board_of = |column_id: Uuid| column_boards.get(&column_id).copied();
+    let card_change = |change, card: &Card, fields| SnapshotChange {
+        entity: ChangedEntity::Card,
+        change,
+        id: card.id,
+        board_id: board_of(card.column_id),
+        fields,
+    };
+    let archived_change = |change, archived: &ArchivedCard| SnapshotChange {
+        entity: ChangedEntity::Card,
+        change,
+        id: archived.card.id,
+        board_id: board_of(archived.original_column_id),
+        fields: Vec::new(),
+    };
+
+    for (id, card) in &new_cards {
+        match old_cards.get(id) {
+            Some(old) => {
+                let fields = changed_fields(*old, *card);
+                if fields.is_empty() {
+                    continue;
+                }
+                let change = if old.column_id != card.column_id {
+                    ChangeKind::Moved
+                } else {
+                    ChangeKind::Updated
+                };
+                changes.push(card_change(change, card, fields));
+            }
+            None if old_archived.contains_key(id) => {
+                changes.push(card_change(ChangeKind::Restored, card, Vec::new()));
+            }
+            None => changes.push(card_change(ChangeKind::Created, card, Vec::new())),
+        }
+    }
+    for (id, card) in &old_cards {
+        if new_cards.contains_key(id) {
+            continue;
+        }
+        match new_archived.get(id) {
+            Some(archived) => changes.push(archived_change(ChangeKind::Archived, archived)),
+            None => changes.push(card_change(ChangeKind::Deleted, card, Vec::new())),
+        }
+    }
+    for (id, archived) in &old_archived {
+        if !new_archived.contains_key(id) && !new_cards.contains_key(id) {
+            changes.push(archived_change(ChangeKind::Deleted, archived));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Board, Column};
+
+    fn base() -> (Snapshot, Board, Column) {
+        let board = Board::new("Board", None::<String>);
+        let column = Column::new(board.id, "Todo", 0);
+        let snapshot = Snapshot {
+            boards: vec![board.clone()],
+            columns: vec![column.clone()],
+            ..Default::default()
+        };
+        (snapshot, board, column)
+    }
+
+    #[test]
+    fn test_identical_snapshots_have_no_changes() {
+        let (snapshot, _, _) = base();
+        assert!(diff_snapshots(&snapshot, &snapshot).is_empty());
+    }
+
+    #[test]
+    fn test_created_card_reports_board() {
+        let (before, mut board, column) = base();
+        let card = Card::new(&mut board, column.id, "Task", 0);
+        let mut after = before.clone();
+        after.cards.push(card.clone());
+
+        let changes = diff_snapshots(&before, &after);
+        assert_eq!(
+            changes,
+            vec![SnapshotChange {
+                entity: ChangedEntity::Card,
+                change: ChangeKind::Created,
+                id: card.id,
+                board_id: Some(board.id),
+                fields: vec![],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_column_change_is_a_move_with_fields() {
+        let (mut before, mut board, column) = base();
+        let done = Column::new(board.id, "Done", 1);
+        before.columns.push(done.clone());
+        let card = Card::new(&mut board, column.id, "Task", 0);
+        before.cards.push(card.clone());
+        let mut after = before.clone();
+        after.cards[0].move_to_column(done.id, 0);
+
+        let changes = diff_snapshots(&before, &after);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].change, ChangeKind::Moved);
+        assert_eq!(changes[0].fields, vec!["column_id".to_string()]);
+    }
+
+    #[test]
+    fn test_updated_at_only_is_not_a_change() {
+        let (before, _, _) = base();
+        let mut after = before.clone();
+        after.boards[0].updated_at += chrono::Duration::seconds(5);
+        assert!(diff_snapshots(&before, &after).is_empty());
+    }
+
+    #[test]
+    fn test_archive_restore_and_delete_are_distinguished() {
+        let (mut before, mut board, column) = base();
+        let card = Card::new(&mut board, column.id, "Task", 0);
+        before.cards.push(card.clone());
+
+        let mut archived = before.clone();
+        archived.cards.clear();
+        archived
+            .archived_cards
+            .push(ArchivedCard::new(card.clone(), column.id, 0));
+        assert_eq!(
+            diff_snapshots(&before, &archived)[0].change,
+            ChangeKind::Archived
+        );
+        assert_eq!(
+            diff_snapshots(&archived, &before)[0].change,
+            ChangeKind::Restored
+        );
+
+        let mut deleted = archived.clone();
+        deleted.archived_cards.clear();
+        let changes = diff_snapshots(&archived, &deleted);
+        assert_eq!(changes[0].change, ChangeKind::Deleted);
+        assert_eq!(changes[0].board_id, Some(board.id));
+    }
+
+    #[test]
+    fn test_deleted_board_is_reported() {
+        let (before, board, _) = base();
+        let after = Snapshot::default();
+        let changes = diff_snapshots(&before, &after);
+        assert!(changes.iter().any(|c| c.entity == ChangedEntity::Board
+            && c.change == ChangeKind::Deleted
+            && c.id == board.id));
+    }
+}
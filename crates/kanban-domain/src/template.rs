@@ -0,0 +1,528 @@
+//! Board templates: reusable blueprints for new boards.
+//!
+//! A template names a board's columns, WIP limits, completion column,
+//! prefixes and sprint names, plus optional seed cards. Seed cards refer to
+//! each other by title for parent, blocking and related links:
+//!
+//! ```toml
+//! name = "client"
+//! card_prefix = "cli"
+//! completion_column = "Done"
+//!
+//! [[columns]]
+//! name = "Todo"
+//!
+//! [[columns]]
+//! name = "Done"
+//!
+//! [[cards]]
+//! title = "Kick-off call"
+//!
+//! [[cards]]
+//! title = "Send contract"
+//! blocked_by = ["Kick-off call"]
+//! ```
+
+use crate::{
+    Board, Card, CardPriority, Column, DependencyGraph, KanbanError, KanbanResult, Snapshot,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// Names of the templates that ship with the application.
+pub const BUILTIN_BOARD_TEMPLATES: &[&str] = &["basic", "kanban", "scrum"];
+
+/// Blueprint for creating a board.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoardTemplate {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub card_prefix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sprint_prefix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sprint_duration_days: Option<u32>,
+    /// Names handed out to new sprints in order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sprint_names: Vec<String>,
+    /// Name of the column that marks cards complete.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completion_column: Option<String>,
+    pub columns: Vec<ColumnTemplate>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cards: Vec<CardTemplate>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnTemplate {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wip_limit: Option<i32>,
+}
+
+/// Seed card created with every board made from the template.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CardTemplate {
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Column name; defaults to the first column.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<CardPriority>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub points: Option<u8>,
+    /// Title of the seed card this one is a child of.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    /// Titles of the seed cards that block this one.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocked_by: Vec<String>,
+    /// Titles of the seed cards this one relates to.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_to: Vec<String>,
+}
+
+impl ColumnTemplate {
+    fn new(name: &str, wip_limit: Option<i32>) -> Self {
+        Self {
+            name: name.to_string(),
+            wip_limit,
+        }
+    }
+}
+
+impl BoardTemplate {
+    /// Look up a template that ships with the application.
+    pub fn builtin(name: &str) -> Option<Self> {
+        let columns = |names: &[(&str, Option<i32>)]| {
+            names
+                .iter()
+                .map(|(n, wip)| ColumnTemplate::new(n, *wip))
+                .collect()
+        };
+        let template = |name: &str, cols: Vec<ColumnTemplate>, completion: Option<&str>| Self {
+            name: name.to_string(),
+            description: None,
+            card_prefix: None,
+            sprint_prefix: None,
+            sprint_duration_days: None,
+            sprint_names: Vec::new(),
+            completion_column: completion.map(String::from),
+            columns: cols,
+            cards: Vec::new(),
+        };
+        match name {
+            // The columns every board got before templates existed.
+            "basic" => Some(template(
+                "basic",
+                columns(&[("TODO", None), ("Doing", None), ("Complete", None)]),
+                None,
+            )),
+            "kanban" => Some(template(
+                "kanban",
+                columns(&[
+                    ("Backlog", None),
+                    ("Ready", None),
+                    ("In Progress", Some(3)),
+                    ("Review", Some(2)),
+                    ("Done", None),
+                ]),
+                Some("Done"),
+            )),
+            "scrum" => Some(Self {
+                sprint_prefix: Some("sprint".to_string()),
+                sprint_duration_days: Some(14),
+                ..template(
+                    "scrum",
+                    columns(&[
+                        ("Backlog", None),
+                        ("To Do", None),
+                        ("In Progress", None),
+                        ("Review", None),
+                        ("Done", None),
+                    ]),
+                    Some("Done"),
+                )
+            }),
+            _ => None,
+        }
+    }
+
+    /// Check that the template has columns and that every column and card
+    /// it refers to exists. Relations need unambiguous card titles.
+    pub fn validate(&self) -> KanbanResult<()> {
+        let invalid = |msg: String| {
+            Err(KanbanError::validation(format!(
+                "Template '{}': {}",
+                self.name, msg
+            )))
+        };
+        if self.columns.is_empty() {
+            return invalid("needs at least one column".to_string());
+        }
+        let mut column_names = HashSet::new();
+        for column in &self.columns {
+            if !column_names.insert(column.name.as_str()) {
+                return invalid(format!("duplicate column '{}'", column.name));
+            }
+        }
+        if let Some(ref completion) = self.completion_column {
+            if !column_names.contains(completion.as_str()) {
+                return invalid(format!("unknown completion column '{}'", completion));
+            }
+        }
+
+        let mut title_counts: HashMap<&str, usize> = HashMap::new();
+        for card in &self.cards {
+            *title_counts.entry(card.title.as_str()).or_default() += 1;
+        }
+        for card in &self.cards {
+            if let Some(ref column) = card.column {
+                if !column_names.contains(column.as_str()) {
+                    return invalid(format!(
+                        "card '{}' uses unknown column '{}'",
+                        card.title, column
+                    ));
+                }
+            }
+            for target in card
+                .parent
+                .iter()
+                .chain(&card.blocked_by)
+                .chain(&card.related_to)
+            {
+                match title_counts.get(target.as_str()) {
+                    Some(1) => {}
+                    Some(_) => {
+                        return invalid(format!(
+                            "card '{}' refers to '{}', which is not a unique title",
+                            card.title, target
+                        ))
+                    }
+                    None => {
+                        return invalid(format!(
+                            "card '{}' refers to unknown card '{}'",
+                            card.title, target
+                        ))
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Build a new board named `board_name` from the template, ready to be
+    /// imported. Seed cards are numbered from 1 in template order.
+    pub fn instantiate(&self, board_name: &str, position: i32) -> KanbanResult<Snapshot> {
+        self.validate()?;
+
+        let mut board = Board::new(board_name, self.card_prefix.clone());
+        board.description = self.description.clone();
+        board.sprint_prefix = self.sprint_prefix.clone();
+        board.sprint_duration_days = self.sprint_duration_days;
+        board.sprint_names = self.sprint_names.clone();
+        board.position = position;
+
+        let columns: Vec<Column> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, template)| {
+                let mut column = Column::new(board.id, template.name.clone(), i as i32);
+                column.wip_limit = template.wip_limit;
+                column
+            })
+            .collect();
+        let column_id = |name: &str| {
+            columns
+                .iter()
+                .find(|c| c.name == name)
+                .map(|c| c.id)
+                .expect("validated column name")
+        };
+        board.completion_column_id = self.completion_column.as_deref().map(column_id);
+
+        let mut positions: HashMap<Uuid, i32> = HashMap::new();
+        let mut cards = Vec::with_capacity(self.cards.len());
+        for template in &self.cards {
+            let column = template
+                .column
+                .as_deref()
+                .map(column_id)
+                .unwrap_or(columns[0].id);
+            let position = positions.entry(column).or_default();
+            let mut card = Card::new(&mut board, column, template.title.clone(), *position);
+            *position += 1;
+            card.description = template.description.clone();
+            card.priority = template.priority.unwrap_or(CardPriority::Medium);
+            card.points = template.points;
+            cards.push(card);
+        }
+
+        let card_id = |title: &str| {
+            cards
+                .iter()
+                .find(|c| c.title == title)
+                .map(|c| c.id)
+                .expect("validated card title")
+        };
+        let mut graph = DependencyGraph::new();
+        for (template, card) in self.cards.iter().zip(&cards) {
+            if let Some(ref parent) = template.parent {
+                graph.set_parent(card.id, card_id(parent))?;
+            }
+            for blocker in &template.blocked_by {
+                graph.set_block(card_id(blocker), card.id)?;
+            }
+            for related in &template.related_to {
+                let other = card_id(related);
+                if !graph.related(card.id).contains(&other) {
+                    graph.relate(card.id, other)?;
+                }
+            }
+        }
+
+        Ok(Snapshot {
+            boards: vec![board],
+            columns,
+            cards,
+            archived_cards: Vec::new(),
+            sprints: Vec::new(),
+            graph,
+        })
+    }
+
+    /// Capture a board's layout as a template named `name`. With
+    /// `include_cards` its active cards become seed cards; links between
+    /// cards are kept where both titles are unique on the board.
+    pub fn from_board(
+        snapshot: &Snapshot,
+        board_id: Uuid,
+        name: &str,
+        include_cards: bool,
+    ) -> KanbanResult<Self> {
+        let board = snapshot
+            .boards
+            .iter()
+            .find(|b| b.id == board_id)
+            .ok_or_else(|| KanbanError::not_found("Board", board_id))?;
+
+        let mut board_columns: Vec<&Column> = snapshot
+            .columns
+            .iter()
+            .filter(|c| c.board_id == board_id)
+            .collect();
+        board_columns.sort_by_key(|c| c.position);
+        let column_names: HashMap<Uuid, &str> = board_columns
+            .iter()
+            .map(|c| (c.id, c.name.as_str()))
+            .collect();
+
+        let mut board_cards: Vec<&Card> = if include_cards {
+            snapshot
+                .cards
+                .iter()
+                .filter(|c| column_names.contains_key(&c.column_id))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        // Card number order, so the seed cards are numbered as they were.
+        board_cards.sort_by_key(|c| c.card_number);
+
+        let mut title_counts: HashMap<&str, usize> = HashMap::new();
+        for card in &board_cards {
+            *title_counts.entry(card.title.as_str()).or_default() += 1;
+        }
+        let linkable: HashMap<Uuid, &str> = board_cards
+            .iter()
+            .filter(|c| title_counts[c.title.as_str()] == 1)
+            .map(|c| (c.id, c.title.as_str()))
+            .collect();
+        let title_of = |id: Uuid| linkable.get(&id).map(|t| t.to_string());
+        let order: HashMap<Uuid, usize> = board_cards
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.id, i))
+            .collect();
+
+        let graph = &snapshot.graph;
+        let cards = board_cards
+            .iter()
+            .map(|card| {
+                let linked = linkable.contains_key(&card.id);
+                let parent = if linked {
+                    graph.parents(card.id).into_iter().find_map(title_of)
+                } else {
+                    None
+                };
+                let blocked_by = if linked {
+                    graph
+                        .blockers(card.id)
+                        .into_iter()
+                        .filter_map(title_of)
+                        .collect()
+                } else {
+                    Vec::new()
+                };
+                // Each relation is recorded once, on the later card.
+                let related_to = if linked {
+                    graph
+                        .related(card.id)
+                        .into_iter()
+                        .filter(|other| order.get(other).is_some_and(|i| *i < order[&card.id]))
+                        .filter_map(title_of)
+                        .collect()
+                } else {
+                    Vec::new()
+                };
+                CardTemplate {
+                    title: card.title.clone(),
+                    description: card.description.clone(),
+                    column: Some(column_names[&card.column_id].to_string()),
+                    priority: Some(card.priority),
+                    points: card.points,
+                    parent,
+                    blocked_by,
+                    related_to,
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            name: name.to_string(),
+            description: board.description.clone(),
+            card_prefix: board.card_prefix.clone(),
+            sprint_prefix: board.sprint_prefix.clone(),
+            sprint_duration_days: board.sprint_duration_days,
+            sprint_names: board.sprint_names.clone(),
+            completion_column: board
+                .completion_column_id
+                .and_then(|id| column_names.get(&id))
+                .map(|name| name.to_string()),
+            columns: board_columns
+                .iter()
+                .map(|c| ColumnTemplate::new(&c.name, c.wip_limit))
+                .collect(),
+            cards,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(title: &str) -> CardTemplate {
+        CardTemplate {
+            title: title.to_string(),
+            description: None,
+            column: None,
+            priority: None,
+            points: None,
+            parent: None,
+            blocked_by: Vec::new(),
+            related_to: Vec::new(),
+        }
+    }
+
+    fn seeded() -> BoardTemplate {
+        let mut template = BoardTemplate::builtin("basic").unwrap();
+        template.name = "client".to_string();
+        template.completion_column = Some("Complete".to_string());
+        template.card_prefix = Some("cli".to_string());
+        template.sprint_names = vec!["Alpha".to_string()];
+        template.cards = vec![
+            card("Kick-off"),
+            CardTemplate {
+                column: Some("Doing".to_string()),
+                parent: Some("Kick-off".to_string()),
+                ..card("Agenda")
+            },
+            CardTemplate {
+                blocked_by: vec!["Kick-off".to_string()],
+                related_to: vec!["Agenda".to_string()],
+                ..card("Contract")
+            },
+        ];
+        template
+    }
+
+    #[test]
+    fn test_builtin_templates_are_valid() {
+        for name in BUILTIN_BOARD_TEMPLATES {
+            let template = BoardTemplate::builtin(name).unwrap();
+            assert_eq!(template.name, *name);
+            template.validate().unwrap();
+        }
+        assert!(BoardTemplate::builtin("nope").is_none());
+    }
+
+    #[test]
+    fn test_instantiate_builds_board_columns_cards_and_edges() {
+        let snapshot = seeded().instantiate("Acme", 4).unwrap();
+        let board = &snapshot.boards[0];
+        assert_eq!(board.name, "Acme");
+        assert_eq!(board.position, 4);
+        assert_eq!(board.card_prefix.as_deref(), Some("cli"));
+        assert_eq!(board.sprint_names, vec!["Alpha".to_string()]);
+        assert_eq!(board.card_counter, 4);
+
+        let names: Vec<_> = snapshot.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["TODO", "Doing", "Complete"]);
+        assert_eq!(board.completion_column_id, Some(snapshot.columns[2].id));
+
+        let id = |t: &str| snapshot.cards.iter().find(|c| c.title == t).unwrap();
+        assert_eq!(id("Agenda").column_id, snapshot.columns[1].id);
+        assert_eq!(id("Contract").card_number, 3);
+        assert_eq!(id("Contract").position, 1);
+        let graph = &snapshot.graph;
+        assert_eq!(graph.parents(id("Agenda").id), vec![id("Kick-off").id]);
+        assert_eq!(graph.blockers(id("Contract").id), vec![id("Kick-off").id]);
+        assert_eq!(graph.related(id("Contract").id), vec![id("Agenda").id]);
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_references() {
+        let mut template = seeded();
+        template.cards[0].column = Some("Nope".to_string());
+        assert!(template.validate().is_err());
+
+        let mut template = seeded();
+        template.cards[2].blocked_by = vec!["Missing".to_string()];
+        assert!(template.validate().is_err());
+
+        let mut template = seeded();
+        template.cards.push(card("Kick-off"));
+        assert!(template.validate().is_err());
+
+        let mut template = seeded();
+        template.completion_column = Some("Nope".to_string());
+        assert!(template.instantiate("X", 0).is_err());
+    }
+
+    #[test]
+    fn test_from_board_round_trips_instantiated_template() {
+        let template = seeded();
+        let snapshot = template.instantiate("Acme", 0).unwrap();
+        let board_id = snapshot.boards[0].id;
+
+        let mut saved = BoardTemplate::from_board(&snapshot, board_id, "client", true).unwrap();
+        for card in &mut saved.cards {
+            card.priority = None;
+            if card.column.as_deref() == Some("TODO") {
+                card.column = None;
+            }
+        }
+        assert_eq!(saved, template);
+
+        let layout = BoardTemplate::from_board(&snapshot, board_id, "layout", false).unwrap();
+        assert!(layout.cards.is_empty());
+        assert_eq!(layout.columns, template.columns);
+    }
+}
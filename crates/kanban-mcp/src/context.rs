@@ -1,14 +1,38 @@
 use kanban_core::{AppConfig, PaginatedList};
-use kanban_domain::KanbanResult;
+use kanban_domain::snapshot_diff::diff_snapshots;
 use kanban_domain::{
-    ArchivedCard, Board, BoardUpdate, Card, CardListFilter, CardSummary, CardUpdate, Column,
-    ColumnUpdate, CreateCardOptions, GraphOperations, KanbanOperations, Sprint, SprintUpdate,
+    ArchivedCard, Attachment, AttachmentGcReport, Board, BoardExportFormat, BoardTemplate,
+    BoardUpdate, Card, CardListFilter, CardSummary, CardUpdate, ChecklistItem, CloneOptions,
+    Column, ColumnUpdate, CreateCardOptions, CsvImportSummary, CsvMapping, DependencyGraph,
+    ForeignImportOptions, ForeignImportReport, ForeignSource, GraphOperations, KanbanOperations,
+    Snapshot, Sprint, SprintUpdate,
 };
-use kanban_service::{KanbanContext, StoreManager};
+use kanban_domain::{KanbanError, KanbanResult};
+use kanban_persistence::{ChangeDetector, FileWatcher};
+use kanban_service::{KanbanContext, NewCardBatch, StoreManager};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::sync::RwLock;
 use uuid::Uuid;
 
 pub struct McpContext {
     inner: KanbanContext,
+    /// Shared by every session over the same backend: tool calls that
+    /// write hold it exclusively, reads hold it shared.
+    gate: Arc<RwLock<()>>,
+    /// Fires after every save from any session and after external writes
+    /// to the data file, so sessions can notify resource subscribers.
+    changes: broadcast::Sender<()>,
+    watcher: Option<FileWatcher>,
+    /// The store as this session left it after its last save. Whatever
+    /// differs from it now was changed by another session or process.
+    written: Option<Snapshot>,
+    /// Attachment content each session's undo history can put back, by
+    /// session, so garbage collection in one session spares the others'.
+    history_blobs: Arc<Mutex<HashMap<Uuid, HashSet<String>>>>,
+    session_id: Uuid,
 }
 
 impl McpContext {
@@ -26,25 +50,142 @@ impl McpContext {
         let backend = store_manager.make_backend(data_file, &config).await?;
         Ok(Self {
             inner: KanbanContext::open(backend, config).await?,
+            gate: Arc::new(RwLock::new(())),
+            changes: broadcast::channel(16).0,
+            watcher: None,
+            written: None,
+            history_blobs: Arc::default(),
+            session_id: Uuid::new_v4(),
         })
     }
 
+    /// Watch `path` for writes by other processes. On each one the shared
+    /// backend drops its cache, so reads see the new state, and
+    /// subscribers are told the store changed. Call before creating
+    /// sessions so they share the watcher.
+    pub async fn watch_data_file(&mut self, path: PathBuf) {
+        let watcher = FileWatcher::new();
+        if let Err(e) = watcher.start_watching(path.clone()).await {
+            tracing::warn!("Failed to watch {}: {}", path.display(), e);
+            return;
+        }
+        let mut events = watcher.subscribe();
+        let backend = self.inner.backend();
+        let gate = self.gate();
+        let changes = self.changes.clone();
+        tokio::spawn(async move {
+            while let Ok(_) | Err(RecvError::Lagged(_)) = events.recv().await {
+                let _write = gate.write().await;
+                if let Err(e) = backend.reload().await {
+                    tracing::warn!("Failed to reload after external change: {}", e);
+                }
+                let _ = changes.send(());
+            }
+        });
+        self.watcher = Some(watcher);
+    }
+
+    /// A context for another client of the same server: same backend and
+    /// gate, separate undo history.
+    pub fn new_session(&self) -> Self {
+        Self {
+            inner: self.inner.new_session(),
+            gate: Arc::clone(&self.gate),
+            changes: self.changes.clone(),
+            watcher: self.watcher.clone(),
+            written: None,
+            history_blobs: Arc::clone(&self.history_blobs),
+            session_id: Uuid::new_v4(),
+        }
+    }
+
+    pub fn app_config(&self) -> &AppConfig {
+        self.inner.app_config()
+    }
+
+    /// A scratch copy of this session's state for dry runs: writes to it
+    /// never reach the backend, and it has its own gate and no watcher.
+    pub fn preview(&self) -> KanbanResult<Self> {
+        Ok(Self {
+            inner: self.inner.preview()?,
+            gate: Arc::new(RwLock::new(())),
+            changes: broadcast::channel(1).0,
+            watcher: None,
+            written: None,
+            history_blobs: Arc::default(),
+            session_id: Uuid::new_v4(),
+        })
+    }
+
+    pub fn snapshot(&self) -> KanbanResult<Snapshot> {
+        self.inner.snapshot()
+    }
+
+    /// Yields once per change to the store, from this or any other session.
+    pub fn subscribe_changes(&self) -> broadcast::Receiver<()> {
+        self.changes.subscribe()
+    }
+
+    pub fn gate(&self) -> Arc<RwLock<()>> {
+        Arc::clone(&self.gate)
+    }
+
+    /// Reload from the backend, keeping this session's undo history.
+    /// [`undo`][Self::undo] and [`redo`][Self::redo] check it still
+    /// applies before running it.
     pub async fn reload(&mut self) -> KanbanResult<()> {
-        self.inner.reload().await
+        self.inner.reload_keeping_history().await
     }
 
     pub fn clear_history(&mut self) -> KanbanResult<()> {
         self.inner.clear_history()
     }
 
+    /// Undo this session's last change. Fails, leaving the history as it
+    /// is, if the undo would touch anything another session or process
+    /// changed since this session last saved.
     pub fn undo(&mut self) -> KanbanResult<bool> {
+        self.check_unchanged_by_others("undo", KanbanContext::undo)?;
         self.inner.undo()
     }
 
+    /// Redo this session's last undone change, with the same check as
+    /// [`undo`][Self::undo].
     pub fn redo(&mut self) -> KanbanResult<bool> {
+        self.check_unchanged_by_others("redo", KanbanContext::redo)?;
         self.inner.redo()
     }
 
+    /// Run `step` on a scratch copy and fail if any entity or relation it
+    /// changes was also changed by someone else since our last save.
+    fn check_unchanged_by_others(
+        &self,
+        action: &str,
+        step: fn(&mut KanbanContext) -> KanbanResult<bool>,
+    ) -> KanbanResult<()> {
+        let Some(written) = &self.written else {
+            return Ok(());
+        };
+        let current = self.inner.snapshot()?;
+        let others = touched(written, &current);
+        if others.is_empty() {
+            return Ok(());
+        }
+        let mut scratch = self.inner.preview()?;
+        if !step(&mut scratch)? {
+            return Ok(());
+        }
+        let ours = touched(&current, &scratch.snapshot()?);
+        if ours.is_disjoint(&others) {
+            return Ok(());
+        }
+        Err(KanbanError::validation(format!(
+            "Cannot {}: another client has changed the same data since this session's \
+             last change",
+            action
+        )))
+    }
+
     pub fn can_undo(&self) -> bool {
         self.inner.can_undo()
     }
@@ -53,8 +194,22 @@ impl McpContext {
         self.inner.can_redo()
     }
 
-    pub async fn save(&self) -> KanbanResult<()> {
-        self.inner.save().await
+    pub async fn save(&mut self) -> KanbanResult<()> {
+        if let Some(watcher) = &self.watcher {
+            watcher.suppress_next_event();
+        }
+        self.inner.save().await?;
+        self.written = Some(self.inner.snapshot()?);
+        self.history_blobs()
+            .insert(self.session_id, self.inner.history_attachment_hashes());
+        let _ = self.changes.send(());
+        Ok(())
+    }
+
+    fn history_blobs(&self) -> MutexGuard<'_, HashMap<Uuid, HashSet<String>>> {
+        self.history_blobs
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
     }
 
     /// MCP-specific method that exposes pagination.
@@ -69,6 +224,39 @@ impl McpContext {
         let cards = self.inner.list_cards(filter)?;
         Ok(PaginatedList::paginate(cards, page, page_size)?)
     }
+
+    pub fn create_cards(&mut self, board_id: Uuid, batch: NewCardBatch) -> KanbanResult<Vec<Card>> {
+        self.inner.create_cards(board_id, batch)
+    }
+
+    pub fn graph(&self) -> KanbanResult<DependencyGraph> {
+        self.inner.graph()
+    }
+
+    pub fn recently_changed_cards(&self) -> KanbanResult<Vec<(Uuid, String)>> {
+        self.inner.recently_changed_cards()
+    }
+}
+
+/// Ids of the entities that differ between `before` and `after`, plus the
+/// cards at either end of any relation added or removed.
+fn touched(before: &Snapshot, after: &Snapshot) -> HashSet<Uuid> {
+    let (before_edges, after_edges) = (crate::preview::edges(before), crate::preview::edges(after));
+    diff_snapshots(before, after)
+        .into_iter()
+        .map(|change| change.id)
+        .chain(
+            before_edges
+                .symmetric_difference(&after_edges)
+                .flat_map(|&(_, from, to)| [from, to]),
+        )
+        .collect()
+}
+
+impl Drop for McpContext {
+    fn drop(&mut self) {
+        self.history_blobs().remove(&self.session_id);
+    }
 }
 
 impl KanbanOperations for McpContext {
@@ -96,6 +284,24 @@ impl KanbanOperations for McpContext {
         self.inner.delete_board(id)
     }
 
+    fn create_board_from_template(
+        &mut self,
+        name: String,
+        template: &BoardTemplate,
+    ) -> KanbanResult<Board> {
+        self.inner.create_board_from_template(name, template)
+    }
+
+    fn board_template(
+        &self,
+        board_id: Uuid,
+        template_name: String,
+        include_cards: bool,
+    ) -> KanbanResult<BoardTemplate> {
+        self.inner
+            .board_template(board_id, template_name, include_cards)
+    }
+
     // ========================================================================
     // Column Operations
     // ========================================================================
@@ -224,6 +430,86 @@ impl KanbanOperations for McpContext {
     // Multi-card operations
     // ========================================================================
 
+    fn add_checklist_item(
+        &mut self,
+        card_id: Uuid,
+        text: String,
+        position: Option<usize>,
+    ) -> KanbanResult<ChecklistItem> {
+        self.inner.add_checklist_item(card_id, text, position)
+    }
+
+    fn update_checklist_item(
+        &mut self,
+        card_id: Uuid,
+        item_id: Uuid,
+        text: Option<String>,
+        done: Option<bool>,
+    ) -> KanbanResult<ChecklistItem> {
+        self.inner
+            .update_checklist_item(card_id, item_id, text, done)
+    }
+
+    fn move_checklist_item(
+        &mut self,
+        card_id: Uuid,
+        item_id: Uuid,
+        position: usize,
+    ) -> KanbanResult<Card> {
+        self.inner.move_checklist_item(card_id, item_id, position)
+    }
+
+    fn remove_checklist_item(&mut self, card_id: Uuid, item_id: Uuid) -> KanbanResult<Card> {
+        self.inner.remove_checklist_item(card_id, item_id)
+    }
+
+    fn convert_checklist_item_to_card(
+        &mut self,
+        card_id: Uuid,
+        item_id: Uuid,
+    ) -> KanbanResult<Card> {
+        self.inner.convert_checklist_item_to_card(card_id, item_id)
+    }
+
+    fn import_checklist_from_description(
+        &mut self,
+        card_id: Uuid,
+    ) -> KanbanResult<Vec<ChecklistItem>> {
+        self.inner.import_checklist_from_description(card_id)
+    }
+
+    fn add_attachment(
+        &mut self,
+        card_id: Uuid,
+        name: String,
+        content: &[u8],
+    ) -> KanbanResult<Attachment> {
+        self.inner.add_attachment(card_id, name, content)
+    }
+
+    fn read_attachment(
+        &self,
+        card_id: Uuid,
+        attachment_id: Uuid,
+    ) -> KanbanResult<(Attachment, Vec<u8>)> {
+        self.inner.read_attachment(card_id, attachment_id)
+    }
+
+    fn remove_attachment(&mut self, card_id: Uuid, attachment_id: Uuid) -> KanbanResult<Card> {
+        self.inner.remove_attachment(card_id, attachment_id)
+    }
+
+    fn collect_attachment_garbage(&mut self, dry_run: bool) -> KanbanResult<AttachmentGcReport> {
+        let others: HashSet<String> = self
+            .history_blobs()
+            .iter()
+            .filter(|(session, _)| **session != self.session_id)
+            .flat_map(|(_, hashes)| hashes.iter().cloned())
+            .collect();
+        self.inner
+            .collect_attachment_garbage_keeping(dry_run, &others)
+    }
+
     fn archive_cards(&mut self, ids: Vec<Uuid>) -> KanbanResult<usize> {
         self.inner.archive_cards(ids)
     }
@@ -301,9 +587,48 @@ impl KanbanOperations for McpContext {
         self.inner.export_board(board_id)
     }
 
+    fn export_board_as(
+        &self,
+        board_id: Option<Uuid>,
+        format: BoardExportFormat,
+    ) -> KanbanResult<String> {
+        self.inner.export_board_as(board_id, format)
+    }
+
     fn import_board(&mut self, data: &str) -> KanbanResult<Board> {
         self.inner.import_board(data)
     }
+
+    fn import_board_as_copy(&mut self, data: &str) -> KanbanResult<Board> {
+        self.inner.import_board_as_copy(data)
+    }
+
+    fn clone_board(
+        &mut self,
+        board_id: Uuid,
+        name: String,
+        options: CloneOptions,
+    ) -> KanbanResult<Board> {
+        self.inner.clone_board(board_id, name, options)
+    }
+
+    fn import_cards_csv(
+        &mut self,
+        board_id: Option<Uuid>,
+        data: &str,
+        mapping: &CsvMapping,
+    ) -> KanbanResult<CsvImportSummary> {
+        self.inner.import_cards_csv(board_id, data, mapping)
+    }
+
+    fn import_foreign(
+        &mut self,
+        source: ForeignSource,
+        data: &str,
+        options: ForeignImportOptions,
+    ) -> KanbanResult<ForeignImportReport> {
+        self.inner.import_foreign(source, data, options)
+    }
 }
 
 impl GraphOperations for McpContext {
@@ -0,0 +1,481 @@
+//! `get_board_digest`: one bounded summary of a board, so an agent can
+//! orient itself without walking boards, columns, cards and relations one
+//! call at a time.
+//!
+//! Every list is capped at `max_items`; what falls past the cap is only
+//! counted. The hierarchy stops expanding at `max_depth` and titles are
+//! shortened to `max_title_chars`.
+
+use crate::context::McpContext;
+use crate::kanban_err_to_mcp;
+use crate::prompts::{points, priority_rank, truncate};
+use crate::resources::{single_line, BoardSnapshot};
+use chrono::{DateTime, Utc};
+use kanban_domain::{
+    Card, CardPriority, CardStatus, CardSummary, ChecklistProgress, DependencyGraph, Sprint,
+};
+use rmcp::model::ErrorData as McpError;
+use serde::Serialize;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+pub const DEFAULT_MAX_ITEMS: usize = 10;
+pub const DEFAULT_MAX_DEPTH: usize = 3;
+pub const DEFAULT_MAX_TITLE_CHARS: usize = 80;
+
+#[derive(Debug, Clone, Copy)]
+pub struct DigestLimits {
+    pub max_items: usize,
+    pub max_depth: usize,
+    pub max_title_chars: usize,
+}
+
+impl Default for DigestLimits {
+    fn default() -> Self {
+        Self {
+            max_items: DEFAULT_MAX_ITEMS,
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_title_chars: DEFAULT_MAX_TITLE_CHARS,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BoardDigest {
+    pub board: String,
+    pub totals: Totals,
+    pub columns: Vec<ColumnDigest>,
+    pub active_sprint: Option<SprintDigest>,
+    pub blocked: Section<BlockedCard>,
+    pub overdue: Section<OverdueCard>,
+    pub recent: Vec<RecentCard>,
+    pub hierarchy: Section<HierarchyNode>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Totals {
+    pub cards: usize,
+    pub done: usize,
+    pub points: u32,
+    pub points_done: u32,
+}
+
+/// The first `max_items` entries of a list plus a count of the rest.
+#[derive(Debug, Serialize)]
+pub struct Section<T> {
+    pub items: Vec<T>,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub omitted: usize,
+}
+
+impl<T> Section<T> {
+    fn take(items: impl IntoIterator<Item = T>, limit: usize) -> Self {
+        let mut items: Vec<T> = items.into_iter().collect();
+        let omitted = items.len().saturating_sub(limit);
+        items.truncate(limit);
+        Self { items, omitted }
+    }
+}
+
+fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WipStatus {
+    Ok,
+    AtLimit,
+    OverLimit,
+}
+
+impl WipStatus {
+    fn of(cards: usize, limit: i32) -> Self {
+        match cards.cmp(&usize::try_from(limit).unwrap_or(0)) {
+            std::cmp::Ordering::Less => WipStatus::Ok,
+            std::cmp::Ordering::Equal => WipStatus::AtLimit,
+            std::cmp::Ordering::Greater => WipStatus::OverLimit,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ColumnDigest {
+    pub name: String,
+    pub cards: usize,
+    pub points: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wip_limit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wip: Option<WipStatus>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SprintDigest {
+    pub name: String,
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_left: Option<i64>,
+    pub cards: usize,
+    pub done: usize,
+    pub points: u32,
+    pub points_done: u32,
+}
+
+/// The fields of a [`CardSummary`] an agent needs to pick a card out,
+/// keyed by identifier instead of UUIDs.
+#[derive(Debug, Serialize)]
+pub struct CardRef {
+    pub identifier: String,
+    pub title: String,
+    pub column: String,
+    pub status: CardStatus,
+    pub priority: CardPriority,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub points: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checklist: Option<ChecklistProgress>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BlockedCard {
+    #[serde(flatten)]
+    pub card: CardRef,
+    pub blockers: Vec<Blocker>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Blocker {
+    pub identifier: String,
+    pub title: String,
+    pub status: CardStatus,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OverdueCard {
+    #[serde(flatten)]
+    pub card: CardRef,
+    pub days_overdue: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecentCard {
+    #[serde(flatten)]
+    pub card: CardRef,
+    pub updated_at: DateTime<Utc>,
+    /// What the last command touching the card did, when the command log
+    /// still holds it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HierarchyNode {
+    pub identifier: String,
+    pub title: String,
+    pub status: CardStatus,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<HierarchyNode>,
+    /// Children past `max_items` or below `max_depth`.
+    #[serde(skip_serializing_if = "is_zero")]
+    pub omitted_children: usize,
+}
+
+pub fn build(
+    ctx: &McpContext,
+    board_id: Uuid,
+    limits: DigestLimits,
+) -> Result<BoardDigest, McpError> {
+    let digest = Digest {
+        snapshot: BoardSnapshot::load(ctx, board_id)?,
+        graph: ctx.graph().map_err(kanban_err_to_mcp)?,
+        limits,
+        now: Utc::now(),
+    };
+    let recent_log = ctx.recently_changed_cards().map_err(kanban_err_to_mcp)?;
+    Ok(BoardDigest {
+        board: digest.snapshot.board.name.clone(),
+        totals: digest.totals(),
+        columns: digest.columns(),
+        active_sprint: digest.active_sprint(),
+        blocked: digest.blocked(),
+        overdue: digest.overdue(),
+        recent: digest.recent(recent_log),
+        hierarchy: digest.hierarchy(),
+    })
+}
+
+struct Digest {
+    snapshot: BoardSnapshot,
+    graph: DependencyGraph,
+    limits: DigestLimits,
+    now: DateTime<Utc>,
+}
+
+impl Digest {
+    fn title(&self, card: &Card) -> String {
+        truncate(&single_line(&card.title), self.limits.max_title_chars)
+    }
+
+    fn card_ref(&self, card: &Card) -> CardRef {
+        let summary = CardSummary::from(card);
+        CardRef {
+            identifier: self.snapshot.identifier(card),
+            title: self.title(card),
+            column: self.snapshot.column_name(card).to_string(),
+            status: summary.status,
+            priority: summary.priority,
+            points: summary.points,
+            due_date: summary.due_date,
+            checklist: summary.checklist,
+        }
+    }
+
+    /// `ids` narrowed to cards on this board, in graph order.
+    fn on_board(&self, ids: Vec<Uuid>) -> Vec<&Card> {
+        ids.into_iter()
+            .filter_map(|id| self.snapshot.card(id))
+            .collect()
+    }
+
+    fn totals(&self) -> Totals {
+        let cards: Vec<&Card> = self.snapshot.cards.iter().collect();
+        let done: Vec<&Card> = cards
+            .iter()
+            .copied()
+            .filter(|c| c.status == CardStatus::Done)
+            .collect();
+        Totals {
+            cards: cards.len(),
+            done: done.len(),
+            points: points(&cards),
+            points_done: points(&done),
+        }
+    }
+
+    fn columns(&self) -> Vec<ColumnDigest> {
+        self.snapshot
+            .columns
+            .iter()
+            .map(|column| {
+                let cards: Vec<&Card> = self
+                    .snapshot
+                    .cards
+                    .iter()
+                    .filter(|c| c.column_id == column.id)
+                    .collect();
+                ColumnDigest {
+                    name: column.name.clone(),
+                    cards: cards.len(),
+                    points: points(&cards),
+                    wip_limit: column.wip_limit,
+                    wip: column
+                        .wip_limit
+                        .map(|limit| WipStatus::of(cards.len(), limit)),
+                }
+            })
+            .collect()
+    }
+
+    fn active_sprint(&self) -> Option<SprintDigest> {
+        let sprint: &Sprint = self.snapshot.active_sprint()?;
+        let cards: Vec<&Card> = self
+            .snapshot
+            .cards
+            .iter()
+            .filter(|c| c.sprint_id == Some(sprint.id))
+            .collect();
+        let done: Vec<&Card> = cards
+            .iter()
+            .copied()
+            .filter(|c| c.status == CardStatus::Done)
+            .collect();
+        Some(SprintDigest {
+            name: self.snapshot.sprint_name(sprint),
+            start_date: sprint.start_date,
+            end_date: sprint.end_date,
+            days_left: sprint.end_date.map(|end| (end - self.now).num_days()),
+            cards: cards.len(),
+            done: done.len(),
+            points: points(&cards),
+            points_done: points(&done),
+        })
+    }
+
+    /// Unfinished cards that are marked blocked or wait on an unfinished
+    /// blocker, most urgent first.
+    fn blocked(&self) -> Section<BlockedCard> {
+        let mut blocked: Vec<(&Card, Vec<&Card>)> = self
+            .snapshot
+            .cards
+            .iter()
+            .filter(|card| card.status != CardStatus::Done)
+            .filter_map(|card| {
+                let blockers: Vec<&Card> = self
+                    .on_board(self.graph.blockers(card.id))
+                    .into_iter()
+                    .filter(|b| b.status != CardStatus::Done)
+                    .collect();
+                (card.status == CardStatus::Blocked || !blockers.is_empty())
+                    .then_some((card, blockers))
+            })
+            .collect();
+        blocked.sort_by_key(|(card, _)| priority_rank(card.priority));
+        Section::take(
+            blocked.into_iter().map(|(card, blockers)| BlockedCard {
+                card: self.card_ref(card),
+                blockers: blockers
+                    .into_iter()
+                    .map(|b| Blocker {
+                        identifier: self.snapshot.identifier(b),
+                        title: self.title(b),
+                        status: b.status,
+                    })
+                    .collect(),
+            }),
+            self.limits.max_items,
+        )
+    }
+
+    /// Unfinished cards past their due date, longest overdue first.
+    fn overdue(&self) -> Section<OverdueCard> {
+        let mut overdue: Vec<(&Card, DateTime<Utc>)> = self
+            .snapshot
+            .cards
+            .iter()
+            .filter(|card| card.status != CardStatus::Done)
+            .filter_map(|card| card.due_date.map(|due| (card, due)))
+            .filter(|(_, due)| *due < self.now)
+            .collect();
+        overdue.sort_by_key(|(_, due)| *due);
+        Section::take(
+            overdue.into_iter().map(|(card, due)| OverdueCard {
+                card: self.card_ref(card),
+                days_overdue: (self.now - due).num_days(),
+            }),
+            self.limits.max_items,
+        )
+    }
+
+    /// Cards from the command log first, newest first, topped up by
+    /// `updated_at` since the log only covers the current session.
+    fn recent(&self, log: Vec<(Uuid, String)>) -> Vec<RecentCard> {
+        let mut seen = HashSet::new();
+        let mut recent = Vec::new();
+        for (id, description) in log {
+            if let Some(card) = self.snapshot.card(id) {
+                if seen.insert(id) {
+                    recent.push((card, Some(self.readable(description))));
+                }
+            }
+        }
+        let mut rest: Vec<&Card> = self
+            .snapshot
+            .cards
+            .iter()
+            .filter(|c| !seen.contains(&c.id))
+            .collect();
+        rest.sort_by_key(|c| std::cmp::Reverse(c.updated_at));
+        recent.extend(rest.into_iter().map(|card| (card, None)));
+        recent.truncate(self.limits.max_items);
+        recent
+            .into_iter()
+            .map(|(card, change)| RecentCard {
+                card: self.card_ref(card),
+                updated_at: card.updated_at,
+                change,
+            })
+            .collect()
+    }
+
+    /// Command descriptions name cards, columns and sprints by UUID; swap
+    /// in the identifiers and names the rest of the digest uses.
+    fn readable(&self, mut description: String) -> String {
+        let s = &self.snapshot;
+        let names = s
+            .cards
+            .iter()
+            .map(|c| (c.id, s.identifier(c)))
+            .chain(s.columns.iter().map(|c| (c.id, c.name.clone())))
+            .chain(s.sprints.iter().map(|sp| (sp.id, s.sprint_name(sp))));
+        for (id, name) in names {
+            let id = id.to_string();
+            if description.contains(&id) {
+                description = description.replace(&id, &name);
+            }
+        }
+        description
+    }
+
+    /// Trees rooted at cards that have children but no parent on this
+    /// board.
+    fn hierarchy(&self) -> Section<HierarchyNode> {
+        let roots: Vec<&Card> = self
+            .snapshot
+            .cards
+            .iter()
+            .filter(|c| {
+                !self.on_board(self.graph.children(c.id)).is_empty()
+                    && self.on_board(self.graph.parents(c.id)).is_empty()
+            })
+            .collect();
+        let limit = if self.limits.max_depth == 0 {
+            0
+        } else {
+            self.limits.max_items
+        };
+        let mut path = HashSet::new();
+        Section::take(
+            roots
+                .into_iter()
+                .map(|root| self.node(root, self.limits.max_depth, &mut path)),
+            limit,
+        )
+    }
+
+    fn node(&self, card: &Card, depth: usize, path: &mut HashSet<Uuid>) -> HierarchyNode {
+        let children = self.on_board(self.graph.children(card.id));
+        let mut node = HierarchyNode {
+            identifier: self.snapshot.identifier(card),
+            title: self.title(card),
+            status: card.status,
+            children: Vec::new(),
+            omitted_children: children.len(),
+        };
+        if depth <= 1 || !path.insert(card.id) {
+            return node;
+        }
+        let shown = children.len().min(self.limits.max_items);
+        node.children = children[..shown]
+            .iter()
+            .map(|child| self.node(child, depth - 1, path))
+            .collect();
+        node.omitted_children = children.len() - shown;
+        path.remove(&card.id);
+        node
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_section_counts_what_it_drops() {
+        let section = Section::take(1..=5, 3);
+        assert_eq!(section.items, vec![1, 2, 3]);
+        assert_eq!(section.omitted, 2);
+        let json = serde_json::to_value(Section::take(1..=2, 3)).unwrap();
+        assert!(json.get("omitted").is_none(), "{json}");
+    }
+
+    #[test]
+    fn test_wip_status_against_limit() {
+        assert_eq!(WipStatus::of(2, 3), WipStatus::Ok);
+        assert_eq!(WipStatus::of(3, 3), WipStatus::AtLimit);
+        assert_eq!(WipStatus::of(4, 3), WipStatus::OverLimit);
+    }
+}
@@ -1,29 +1,42 @@
 pub mod context;
+pub mod digest;
 pub mod error;
+pub mod preview;
+pub mod prompts;
+pub mod resources;
 pub mod server;
 
 pub use error::{KanbanMcpError, KanbanMcpResult};
 pub use server::McpServer;
 
+use base64::{engine::general_purpose::STANDARD, Engine};
 use context::McpContext;
 use kanban_core::{parse_datetime_input, resolve_page_params, PaginatedList};
 use kanban_domain::{
     ArchivedCardListFilter, ArchivedCardSummary, BoardUpdate, CardListFilter, CardPriority,
-    CardStatus, CardSummary, CardUpdate, ColumnUpdate, CreateCardOptions, FieldUpdate,
-    GraphOperations, KanbanOperations, SortField, SortOrder, SprintUpdate,
+    CardStatus, CardSummary, CardUpdate, CloneOptions, ColumnUpdate, CreateCardOptions,
+    FieldUpdate, GraphOperations, KanbanOperations, SortField, SortOrder, SprintUpdate,
 };
 use kanban_domain::{KanbanError, KanbanResult};
-use kanban_service::StoreManager;
+use kanban_service::attachment_files::extract_attachment;
+use kanban_service::{NewCard, NewCardBatch, NewCardRef, StoreManager};
 use rmcp::{
     handler::server::{router::tool::ToolRouter, wrapper::Parameters},
     model::{
-        CallToolResult, Content, ErrorData as McpError, Implementation, ProtocolVersion,
-        ServerCapabilities, ServerInfo,
+        CallToolResult, Content, ErrorData as McpError, GetPromptRequestParam, GetPromptResult,
+        Implementation, ListPromptsResult, ListResourceTemplatesResult, ListResourcesResult,
+        PaginatedRequestParam, ProtocolVersion, ReadResourceRequestParam, ReadResourceResult,
+        ResourceUpdatedNotificationParam, ServerCapabilities, ServerInfo, SubscribeRequestParam,
+        UnsubscribeRequestParam,
     },
-    schemars, tool, tool_handler, tool_router, ServerHandler,
+    schemars,
+    service::{NotificationContext, RequestContext},
+    tool, tool_handler, tool_router, RoleServer, ServerHandler,
 };
 use serde::Deserialize;
+use std::collections::{BTreeSet, HashMap};
 use std::sync::Arc;
+use tokio::sync::broadcast::error::RecvError;
 use tokio::sync::Mutex;
 use uuid::Uuid;
 
@@ -43,6 +56,111 @@ fn to_call_tool_result_json(value: serde_json::Value) -> Result<CallToolResult,
     Ok(CallToolResult::success(vec![Content::text(json)]))
 }
 
+/// Split an `update_card` request into the card reference and the
+/// `CardUpdate` to apply to it.
+fn parse_card_update(req: UpdateCardRequest) -> Result<(String, CardUpdate), McpError> {
+    let priority = req.priority.as_deref().map(parse_priority).transpose()?;
+    let status = req.status.as_deref().map(parse_status).transpose()?;
+    let due_date = if req.clear_due_date == Some(true) {
+        FieldUpdate::Clear
+    } else {
+        match req.due_date {
+            Some(ref d) => FieldUpdate::Set(parse_datetime(d)?),
+            None => FieldUpdate::NoChange,
+        }
+    };
+    let updates = CardUpdate {
+        title: req.title,
+        description: req
+            .description
+            .map(FieldUpdate::Set)
+            .unwrap_or(FieldUpdate::NoChange),
+        priority,
+        status,
+        position: None,
+        column_id: None,
+        points: req
+            .points
+            .map(FieldUpdate::Set)
+            .unwrap_or(FieldUpdate::NoChange),
+        due_date,
+        sprint_id: FieldUpdate::NoChange,
+    };
+    Ok((req.card, updates))
+}
+
+/// Resolve a `create_cards` request into a service batch plus each card's
+/// temporary key. A relation naming one of the request's keys points at
+/// that new card; anything else is looked up as an existing card.
+fn new_card_batch(
+    ctx: &McpContext,
+    board_id: Uuid,
+    req: &CreateCardsRequest,
+) -> Result<(NewCardBatch, Vec<Option<String>>), McpError> {
+    let mut keys: HashMap<&str, usize> = HashMap::new();
+    for (index, card) in req.cards.iter().enumerate() {
+        if let Some(key) = card.key.as_deref() {
+            if keys.insert(key, index).is_some() {
+                return Err(McpError::invalid_params(
+                    format!("Duplicate card key '{}'", key),
+                    None,
+                ));
+            }
+        }
+    }
+    let card_ref = |raw: &str| -> Result<NewCardRef, McpError> {
+        match keys.get(raw) {
+            Some(&index) => Ok(NewCardRef::New(index)),
+            None => Ok(NewCardRef::Existing(ctx.mcp_resolve_card(raw)?)),
+        }
+    };
+
+    let mut batch = NewCardBatch::default();
+    for (index, card) in req.cards.iter().enumerate() {
+        let column = card
+            .column
+            .as_deref()
+            .or(req.column.as_deref())
+            .ok_or_else(|| {
+                McpError::invalid_params(
+                    format!(
+                        "Card '{}' has no column and the request sets no default column",
+                        card.title
+                    ),
+                    None,
+                )
+            })?;
+        let sprint_id = card
+            .sprint_id
+            .as_deref()
+            .map(|raw| ctx.mcp_resolve_sprint_in_board(raw, board_id))
+            .transpose()?;
+        batch.cards.push(NewCard {
+            column_id: ctx.mcp_resolve_column_in_board(column, board_id)?,
+            title: card.title.clone(),
+            options: CreateCardOptions {
+                description: card.description.clone(),
+                priority: card.priority.as_deref().map(parse_priority).transpose()?,
+                points: card.points,
+                due_date: card.due_date.as_deref().map(parse_datetime).transpose()?,
+                sprint_id,
+            },
+        });
+        if let Some(parent) = card.parent.as_deref() {
+            batch
+                .parents
+                .push((card_ref(parent)?, NewCardRef::New(index)));
+        }
+        for blocker in card.blocked_by.iter().flatten() {
+            batch
+                .blocks
+                .push((card_ref(blocker)?, NewCardRef::New(index)));
+        }
+    }
+    let keys = req.cards.iter().map(|card| card.key.clone()).collect();
+    Ok((batch, keys))
+}
+
 fn resolve_summaries(ctx: &McpContext, ids: Vec<Uuid>) -> Vec<CardSummary> {
     ids.into_iter()
         .filter_map(|id| match ctx.get_card(id) {
@@ -211,13 +329,20 @@ fn parse_sort_order(s: &str) -> Result<SortOrder, McpError> {
 //   read-only semantics. Use for tool handlers that resolve names + read
 //   state without mutating.
 //
-// - `locked_write(ctx, |ctx| ...)` — lock, reload from disk, run closure,
-//   save, drop. The closure takes `&mut McpContext`. Reload+save bracket
-//   the closure so mutations see the latest disk state and are persisted.
+// - `locked_write(ctx, tool, dry_run, |ctx| ...)` — lock, reload from disk,
+//   run closure, save, drop. The closure takes `&mut McpContext`.
+//   Reload+save bracket the closure so mutations see the latest disk state
+//   and are persisted. A dry run previews the closure on a scratch copy
+//   instead; `locked_destructive_write` adds the confirmation-token check.
 //
 // For trivial reads with no resolution (`tool_list_boards`, etc.) the older
 // `read_op!` macro is still appropriate — it's a one-liner that elides the
 // closure ceremony.
+//
+// Over HTTP each client session has its own context, so the session lock
+// alone does not order calls from different clients. Every flavour also
+// takes the contexts' shared gate after the session lock: shared for reads,
+// exclusive for anything that reloads, mutates or saves.
 
 /// Acquire the context lock and run the closure with read-only access.
 ///
@@ -233,6 +358,7 @@ where
     E: Into<McpError>,
 {
     let guard = ctx.lock().await;
+    let _gate = guard.gate().read_owned().await;
     f(&guard).map_err(Into::into)
 }
 
@@ -241,28 +367,164 @@ where
 /// mutation always operates on the latest disk state and is persisted before
 /// the lock releases.
 ///
-/// # Reload semantics and undo limitations
+/// With `dry_run` set the closure runs against a scratch copy of the
+/// reloaded state instead (see [`locked_preview`]), and the tool answers
+/// with the preview rather than its own result.
 ///
-/// `guard.reload()` fully discards the in-memory cache and resets undo
-/// history to the current on-disk state. As a consequence, within-session
-/// undo history from earlier tool calls is always wiped before each
-/// mutation: `tool_undo` can only undo the operation recorded during the
-/// **current** tool call, not operations from prior calls.
+/// # Reload semantics and undo
 ///
-/// **Future work**: a `reload_if_changed()` method that compares file
-/// metadata (mtime / instance_id) and skips the full reload when no
-/// external write has occurred would let undo history persist across calls
-/// in the same session. Track as `KanbanBackend::reload_if_changed()`.
-async fn locked_write<T, E, F>(ctx: &Arc<Mutex<McpContext>>, f: F) -> Result<T, McpError>
+/// `guard.reload()` discards the in-memory cache but keeps the session's
+/// undo history, so `tool_undo` can step back through every change this
+/// session made, not just the last call. Other sessions and processes may
+/// have written in between; `tool_undo` and `tool_redo` reload too and
+/// refuse to run when the step would touch anything changed since this
+/// session's last save (see [`McpContext::undo`]).
+async fn locked_write<T, E, F>(
+    ctx: &Arc<Mutex<McpContext>>,
+    tool: &str,
+    dry_run: Option<bool>,
+    f: F,
+) -> Result<Outcome<T>, McpError>
 where
     F: FnOnce(&mut McpContext) -> Result<T, E>,
     E: Into<McpError>,
 {
+    if dry_run == Some(true) {
+        return locked_preview(ctx, tool, f).await.map(Outcome::Previewed);
+    }
     let mut guard = ctx.lock().await;
+    let _gate = guard.gate().write_owned().await;
     guard.reload().await.map_err(kanban_err_to_mcp)?;
     let result = f(&mut guard).map_err(Into::into)?;
     guard.save().await.map_err(kanban_err_to_mcp)?;
-    Ok(result)
+    Ok(Outcome::Applied(result))
+}
+
+/// Dry-run half of [`locked_write`]: reload like a write would, then run
+/// the closure against a scratch copy (see [`preview`]) under the shared
+/// gate, so the preview and its token reflect what is on disk now rather
+/// than this session's cache.
+async fn locked_preview<T, E, F>(
+    ctx: &Arc<Mutex<McpContext>>,
+    tool: &str,
+    f: F,
+) -> Result<preview::Preview, McpError>
+where
+    F: FnOnce(&mut McpContext) -> Result<T, E>,
+    E: Into<McpError>,
+{
+    let mut guard = ctx.lock().await;
+    let _gate = guard.gate().read_owned().await;
+    guard.reload().await.map_err(kanban_err_to_mcp)?;
+    preview::run(&guard, tool, f)
+}
+
+/// [`locked_write`] for the tools in [`preview::DESTRUCTIVE_TOOLS`]. When
+/// the config sets `mcp_confirm_destructive`, the closure first runs
+/// against a scratch copy of the freshly reloaded state, and the write
+/// only goes ahead if `confirm` matches that preview's token. The closure
+/// runs twice in that case, hence `Fn`.
+async fn locked_destructive_write<T, E, F>(
+    ctx: &Arc<Mutex<McpContext>>,
+    tool: &str,
+    dry_run: Option<bool>,
+    confirm: Option<&str>,
+    f: F,
+) -> Result<Outcome<T>, McpError>
+where
+    F: Fn(&mut McpContext) -> Result<T, E>,
+    E: Into<McpError>,
+{
+    if dry_run == Some(true) {
+        return locked_preview(ctx, tool, &f).await.map(Outcome::Previewed);
+    }
+    let mut guard = ctx.lock().await;
+    let _gate = guard.gate().write_owned().await;
+    guard.reload().await.map_err(kanban_err_to_mcp)?;
+    if guard.app_config().effective_mcp_confirm_destructive() {
+        let expected = preview::run(&guard, tool, &f)?.confirmation_token;
+        match confirm {
+            None => {
+                return Err(McpError::invalid_params(
+                    format!(
+                        "{} needs confirmation: call it with dry_run: true, check the changes, \
+                         then pass the returned confirmation_token as confirm",
+                        tool
+                    ),
+                    None,
+                ))
+            }
+            Some(token) if Some(token) != expected.as_deref() => {
+                return Err(McpError::invalid_params(
+                    format!(
+                        "Confirmation token does not match what {} would change now; \
+                         run it with dry_run: true again",
+                        tool
+                    ),
+                    None,
+                ))
+            }
+            Some(_) => {}
+        }
+    }
+    let result = f(&mut guard).map_err(Into::into)?;
+    guard.save().await.map_err(kanban_err_to_mcp)?;
+    Ok(Outcome::Applied(result))
+}
+
+/// What a mutating tool call did: the write's own result, or the preview
+/// of a dry run.
+enum Outcome<T> {
+    Applied(T),
+    Previewed(preview::Preview),
+}
+
+impl<T> Outcome<T> {
+    /// Build the tool result, answering a dry run with its preview.
+    fn respond(
+        self,
+        f: impl FnOnce(T) -> Result<CallToolResult, McpError>,
+    ) -> Result<CallToolResult, McpError> {
+        match self {
+            Outcome::Applied(result) => f(result),
+            Outcome::Previewed(preview) => to_call_tool_result(&preview),
+        }
+    }
+}
+
+/// Resolve a card and one of its checklist items (1-based position or
+/// UUID prefix).
+fn resolve_checklist_item(
+    ctx: &McpContext,
+    card: &str,
+    item: &str,
+) -> Result<(Uuid, Uuid), McpError> {
+    let card_id = ctx.mcp_resolve_card(card)?;
+    let card = ctx
+        .get_card(card_id)
+        .map_err(kanban_err_to_mcp)?
+        .ok_or_else(|| kanban_err_to_mcp(KanbanError::not_found("Card", card_id)))?;
+    let item_id = kanban_domain::checklist::resolve_checklist_item(&card.checklist, item)
+        .map_err(kanban_err_to_mcp)?;
+    Ok((card_id, item_id))
+}
+
+/// Resolve a card and one of its attachments (1-based position, file
+/// name or UUID prefix).
+fn resolve_attachment(
+    ctx: &McpContext,
+    card: &str,
+    attachment: &str,
+) -> Result<(Uuid, Uuid), McpError> {
+    let card_id = ctx.mcp_resolve_card(card)?;
+    let card = ctx
+        .get_card(card_id)
+        .map_err(kanban_err_to_mcp)?
+        .ok_or_else(|| kanban_err_to_mcp(KanbanError::not_found("Card", card_id)))?;
+    let attachment_id =
+        kanban_domain::attachment::resolve_attachment(&card.attachments, attachment)
+            .map_err(kanban_err_to_mcp)?;
+    Ok((card_id, attachment_id))
 }
 
 /// Helper trait: gives `&McpContext` access to MCP-flavoured error mapping for
@@ -328,40 +590,18 @@ fn card_board(ctx: &McpContext, card_id: Uuid) -> Result<Uuid, McpError> {
     Ok(column.board_id)
 }
 
-/// Lock the context, reload from disk, execute a mutating operation, then save.
-///
-/// # Reload semantics and undo limitations
-///
-/// Every invocation begins with `guard.reload()`, which fully discards the
-/// in-memory cache and resets undo history to the current on-disk state.
-/// Consequently, within-session undo history from earlier API calls is always
-/// wiped before each mutation: `tool_undo` can only undo the operation
-/// recorded during the **current** tool call, not operations from prior calls.
-///
-/// **Future work**: a `reload_if_changed()` method that compares file metadata
-/// (mtime / instance_id) and skips the full reload when no external write has
-/// occurred would allow undo history to persist across calls in the same
-/// session. Track as `KanbanBackend::reload_if_changed()`.
-macro_rules! mutating_op {
+/// Lock, read (no save).
+macro_rules! read_op {
     ($ctx:expr, $method:ident $(, $arg:expr)*) => {{
         async {
-            let mut guard = $ctx.lock().await;
-            guard.reload().await.map_err(kanban_err_to_mcp)?;
-            let result = guard.$method($($arg),*).map_err(kanban_err_to_mcp)?;
-            guard.save().await.map_err(kanban_err_to_mcp)?;
-            Ok::<_, McpError>(result)
+            let guard = $ctx.lock().await;
+            let _gate = guard.gate().read_owned().await;
+            guard.$method($($arg),*).map_err(kanban_err_to_mcp)
         }
         .await
     }};
 }
 
-/// Lock, read (no save).
-macro_rules! read_op {
-    ($ctx:expr, $method:ident $(, $arg:expr)*) => {{
-        $ctx.lock().await.$method($($arg),*).map_err(kanban_err_to_mcp)
-    }};
-}
-
 // ============================================================================
 // Request Types (MCP tool schemas)
 // ============================================================================
@@ -374,6 +614,8 @@ pub struct CreateBoardRequest {
     pub name: String,
     #[schemars(description = "Optional card prefix (e.g., 'KAN' for KAN-1, KAN-2, etc.)")]
     pub card_prefix: Option<String>,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -382,6 +624,20 @@ pub struct GetBoardRequest {
     pub board: String,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetBoardDigestRequest {
+    #[schemars(description = "UUID or name of the board to summarize")]
+    pub board: String,
+    #[schemars(
+        description = "Maximum entries per list before the rest are only counted (default: 10)"
+    )]
+    pub max_items: Option<usize>,
+    #[schemars(description = "Levels of the parent/child hierarchy to expand (default: 3)")]
+    pub max_depth: Option<usize>,
+    #[schemars(description = "Card titles longer than this are shortened (default: 80)")]
+    pub max_title_chars: Option<usize>,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct UpdateBoardRequest {
     #[schemars(description = "UUID or name of the board to update")]
@@ -400,12 +656,34 @@ pub struct UpdateBoardRequest {
     pub task_sort_field: Option<String>,
     #[schemars(description = "Default sort direction. Valid: asc, desc")]
     pub task_sort_order: Option<String>,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct DeleteBoardRequest {
     #[schemars(description = "UUID or name of the board to delete")]
     pub board: String,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
+    #[schemars(
+        description = "confirmation_token from a dry run of this call; required when the server sets mcp_confirm_destructive"
+    )]
+    pub confirm: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct CloneBoardRequest {
+    #[schemars(description = "UUID or name of the board to copy")]
+    pub board: String,
+    #[schemars(description = "Name of the new board")]
+    pub name: String,
+    #[schemars(description = "Also copy the board's cards and the relations between them")]
+    pub include_cards: Option<bool>,
+    #[schemars(description = "Also copy the board's sprints")]
+    pub include_sprints: Option<bool>,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 // Column
@@ -418,6 +696,8 @@ pub struct CreateColumnRequest {
     pub name: String,
     #[schemars(description = "Position of the column (optional, appends to end if not specified)")]
     pub position: Option<i32>,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -444,12 +724,20 @@ pub struct UpdateColumnRequest {
     pub wip_limit: Option<u32>,
     #[schemars(description = "Clear the WIP limit")]
     pub clear_wip_limit: Option<bool>,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct DeleteColumnRequest {
     #[schemars(description = "UUID or name of the column to delete")]
     pub column: String,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
+    #[schemars(
+        description = "confirmation_token from a dry run of this call; required when the server sets mcp_confirm_destructive"
+    )]
+    pub confirm: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -458,6 +746,8 @@ pub struct ReorderColumnRequest {
     pub column: String,
     #[schemars(description = "New position")]
     pub position: i32,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 // Card
@@ -486,6 +776,8 @@ pub struct CreateCardRequest {
             sprint's id here so the card lands in the active sprint in a single call."
     )]
     pub sprint_id: Option<String>,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -562,6 +854,10 @@ pub struct UpdateCardRequest {
     pub clear_due_date: Option<bool>,
     #[schemars(description = "Story points (optional, 0-255)")]
     pub points: Option<u8>,
+    #[schemars(
+        description = "Return the changes this call would make without making them (ignored on update_cards entries; set it on the request instead)"
+    )]
+    pub dry_run: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -574,12 +870,20 @@ pub struct MoveCardRequest {
     pub column: String,
     #[schemars(description = "Position in the new column (optional)")]
     pub position: Option<i32>,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct ArchiveCardRequest {
     #[schemars(description = "UUID or identifier of the card to archive (e.g. 'KAN-5' or '5')")]
     pub card: String,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
+    #[schemars(
+        description = "confirmation_token from a dry run of this call; required when the server sets mcp_confirm_destructive"
+    )]
+    pub confirm: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -592,12 +896,20 @@ pub struct RestoreCardRequest {
         description = "UUID or name of the column to restore the card to (optional; resolved within the card's board)"
     )]
     pub column: Option<String>,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct DeleteCardRequest {
     #[schemars(description = "UUID or identifier of the card to delete (e.g. 'KAN-5' or '5')")]
     pub card: String,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
+    #[schemars(
+        description = "confirmation_token from a dry run of this call; required when the server sets mcp_confirm_destructive"
+    )]
+    pub confirm: Option<String>,
 }
 
 // Card Sprint
@@ -610,6 +922,8 @@ pub struct AssignCardToSprintRequest {
         description = "UUID, name, or number of the sprint to assign to (resolved within the card's board)"
     )]
     pub sprint: String,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -618,6 +932,8 @@ pub struct UnassignCardFromSprintRequest {
         description = "UUID or identifier of the card to unassign from its sprint (e.g. 'KAN-5' or '5')"
     )]
     pub card: String,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 // Card Utilities
@@ -634,6 +950,136 @@ pub struct GetCardGitCheckoutRequest {
     pub card: String,
 }
 
+// Card checklists
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct AddChecklistItemRequest {
+    #[schemars(description = "UUID or identifier of the card (e.g. 'KAN-5' or '5')")]
+    pub card: String,
+    #[schemars(description = "Item text")]
+    pub text: String,
+    #[schemars(description = "1-based position to insert at (defaults to the end)")]
+    pub position: Option<usize>,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct UpdateChecklistItemRequest {
+    #[schemars(description = "UUID or identifier of the card (e.g. 'KAN-5' or '5')")]
+    pub card: String,
+    #[schemars(description = "Item position (1-based) or UUID prefix")]
+    pub item: String,
+    #[schemars(description = "New item text")]
+    pub text: Option<String>,
+    #[schemars(description = "Mark the item done (true) or not done (false)")]
+    pub done: Option<bool>,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct MoveChecklistItemRequest {
+    #[schemars(description = "UUID or identifier of the card (e.g. 'KAN-5' or '5')")]
+    pub card: String,
+    #[schemars(description = "Item position (1-based) or UUID prefix")]
+    pub item: String,
+    #[schemars(description = "1-based target position")]
+    pub position: usize,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ChecklistItemRequest {
+    #[schemars(description = "UUID or identifier of the card (e.g. 'KAN-5' or '5')")]
+    pub card: String,
+    #[schemars(description = "Item position (1-based) or UUID prefix")]
+    pub item: String,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct RemoveChecklistItemRequest {
+    #[schemars(description = "UUID or identifier of the card (e.g. 'KAN-5' or '5')")]
+    pub card: String,
+    #[schemars(description = "Item position (1-based) or UUID prefix")]
+    pub item: String,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
+    #[schemars(
+        description = "confirmation_token from a dry run of this call; required when the server sets mcp_confirm_destructive"
+    )]
+    pub confirm: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ImportChecklistRequest {
+    #[schemars(description = "UUID or identifier of the card (e.g. 'KAN-5' or '5')")]
+    pub card: String,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
+}
+
+// Card attachments
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct AddAttachmentRequest {
+    #[schemars(description = "UUID or identifier of the card (e.g. 'KAN-5' or '5')")]
+    pub card: String,
+    #[schemars(
+        description = "Local file to attach (give this or content_base64); not available over HTTP"
+    )]
+    pub path: Option<String>,
+    #[schemars(description = "Base64 file content (give this or path)")]
+    pub content_base64: Option<String>,
+    #[schemars(
+        description = "Attachment file name; defaults to the name of path and is required with content_base64"
+    )]
+    pub name: Option<String>,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListAttachmentsRequest {
+    #[schemars(description = "UUID or identifier of the card (e.g. 'KAN-5' or '5')")]
+    pub card: String,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetAttachmentRequest {
+    #[schemars(description = "UUID or identifier of the card (e.g. 'KAN-5' or '5')")]
+    pub card: String,
+    #[schemars(description = "Attachment position (1-based), file name or UUID prefix")]
+    pub attachment: String,
+    #[schemars(
+        description = "File or directory to write the content to instead of returning it inline; not available over HTTP"
+    )]
+    pub output_path: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct AttachmentRequest {
+    #[schemars(description = "UUID or identifier of the card (e.g. 'KAN-5' or '5')")]
+    pub card: String,
+    #[schemars(description = "Attachment position (1-based), file name or UUID prefix")]
+    pub attachment: String,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
+    #[schemars(
+        description = "confirmation_token from a dry run of this call; required when the server sets mcp_confirm_destructive"
+    )]
+    pub confirm: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct CollectAttachmentGarbageRequest {
+    #[schemars(description = "Report what would be deleted without deleting it")]
+    pub dry_run: Option<bool>,
+}
+
 // Card relations (parent/child)
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -642,6 +1088,8 @@ pub struct SetCardParentRequest {
     pub child: String,
     #[schemars(description = "UUID or identifier of the parent card (e.g. 'KAN-2')")]
     pub parent: String,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -650,6 +1098,8 @@ pub struct RemoveCardParentRequest {
     pub child: String,
     #[schemars(description = "UUID or identifier of the parent card")]
     pub parent: String,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -670,6 +1120,68 @@ pub struct ListCardChildrenRequest {
 pub struct ArchiveCardsRequest {
     #[schemars(description = "Card UUIDs or identifiers (e.g. ['KAN-1', 'KAN-2', '42'])")]
     pub cards: Vec<String>,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
+    #[schemars(
+        description = "confirmation_token from a dry run of this call; required when the server sets mcp_confirm_destructive"
+    )]
+    pub confirm: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct UpdateCardsRequest {
+    #[schemars(
+        description = "Per-card updates; each entry takes the same fields as tool_update_card and changes only the fields it sets"
+    )]
+    pub cards: Vec<UpdateCardRequest>,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct CreateCardsRequest {
+    #[schemars(description = "UUID or name of the board")]
+    pub board: String,
+    #[schemars(
+        description = "UUID or name of the column for cards that do not name their own (optional)"
+    )]
+    pub column: Option<String>,
+    #[schemars(description = "Cards to create, in order")]
+    pub cards: Vec<NewCardRequest>,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct NewCardRequest {
+    #[schemars(
+        description = "Temporary key other entries in this call use to refer to this card (optional, e.g. 'api')"
+    )]
+    pub key: Option<String>,
+    #[schemars(description = "Title of the card")]
+    pub title: String,
+    #[schemars(description = "UUID or name of the column (defaults to the request's column)")]
+    pub column: Option<String>,
+    #[schemars(description = "Description of the card (optional)")]
+    pub description: Option<String>,
+    #[schemars(description = "Priority: 'low', 'medium', 'high', or 'critical' (optional)")]
+    pub priority: Option<String>,
+    #[schemars(description = "Story points (optional, 0-255)")]
+    pub points: Option<u8>,
+    #[schemars(
+        description = "Due date in YYYY-MM-DD or RFC 3339 format (e.g. 2024-06-15 or 2024-06-15T10:30:00Z)"
+    )]
+    pub due_date: Option<String>,
+    #[schemars(description = "UUID, name, or number of the sprint to assign to (optional)")]
+    pub sprint_id: Option<String>,
+    #[schemars(
+        description = "Parent card: a key from this call, or the UUID or identifier of an existing card (optional)"
+    )]
+    pub parent: Option<String>,
+    #[schemars(
+        description = "Cards that block this one: keys from this call or existing card UUIDs/identifiers (optional)"
+    )]
+    pub blocked_by: Option<Vec<String>>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -682,6 +1194,8 @@ pub struct MoveCardsRequest {
         description = "UUID or name of the destination column (resolved within the cards' shared board)"
     )]
     pub column: String,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -694,6 +1208,8 @@ pub struct AssignCardsToSprintRequest {
         description = "UUID, name, or number of the sprint to assign to (resolved within the cards' shared board)"
     )]
     pub sprint: String,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 // Sprint
@@ -706,6 +1222,8 @@ pub struct CreateSprintRequest {
     pub prefix: Option<String>,
     #[schemars(description = "Sprint name (optional)")]
     pub name: Option<String>,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -738,6 +1256,8 @@ pub struct UpdateSprintRequest {
     pub clear_start_date: Option<bool>,
     #[schemars(description = "Clear the end date")]
     pub clear_end_date: Option<bool>,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -746,24 +1266,36 @@ pub struct ActivateSprintRequest {
     pub sprint: String,
     #[schemars(description = "Duration in days (optional)")]
     pub duration_days: Option<i32>,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct CompleteSprintRequest {
     #[schemars(description = "UUID, name, or number of the sprint to complete")]
     pub sprint: String,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct CancelSprintRequest {
     #[schemars(description = "UUID, name, or number of the sprint to cancel")]
     pub sprint: String,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct DeleteSprintRequest {
     #[schemars(description = "UUID, name, or number of the sprint to delete")]
     pub sprint: String,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
+    #[schemars(
+        description = "confirmation_token from a dry run of this call; required when the server sets mcp_confirm_destructive"
+    )]
+    pub confirm: Option<String>,
 }
 
 // Carry-over
@@ -778,6 +1310,8 @@ pub struct CarryOverSprintCardsRequest {
         description = "UUID, name, or number of the planning sprint to carry cards to (must be on the same board as source)"
     )]
     pub to_sprint: String,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 // Export/Import
@@ -788,12 +1322,22 @@ pub struct ExportBoardRequest {
         description = "UUID or name of the board to export (optional, exports all if omitted)"
     )]
     pub board: Option<String>,
+    #[schemars(
+        description = "Export a bundle that also carries attachment content as base64 (default false)"
+    )]
+    pub include_attachments: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct ImportBoardRequest {
-    #[schemars(description = "JSON data to import (full board export format)")]
+    #[schemars(description = "JSON data to import (full board export format or a bundle)")]
     pub data: String,
+    #[schemars(
+        description = "Import under fresh ids with cards renumbered, so an export can be imported next to its original"
+    )]
+    pub as_copy: Option<bool>,
+    #[schemars(description = "Return the changes this call would make without making them")]
+    pub dry_run: Option<bool>,
 }
 
 // ============================================================================
@@ -803,6 +1347,11 @@ pub struct ImportBoardRequest {
 #[derive(Clone)]
 pub struct KanbanMcpServer {
     ctx: Arc<Mutex<McpContext>>,
+    /// Resource URIs this session's client subscribed to.
+    subscriptions: Arc<std::sync::Mutex<BTreeSet<String>>>,
+    /// Whether tools may read and write server-side files by path. Off
+    /// over HTTP, where the caller is a network client.
+    local_files: bool,
     tool_router: ToolRouter<Self>,
 }
 
@@ -812,12 +1361,38 @@ impl KanbanMcpServer {
         data_file: &str,
         config: kanban_core::AppConfig,
     ) -> KanbanResult<Self> {
-        Ok(Self {
-            ctx: Arc::new(Mutex::new(
-                McpContext::new(store_manager, data_file, config).await?,
-            )),
+        Ok(Self::from_context(
+            McpContext::new(store_manager, data_file, config).await?,
+        ))
+    }
+
+    pub fn from_context(ctx: McpContext) -> Self {
+        Self {
+            ctx: Arc::new(Mutex::new(ctx)),
+            subscriptions: Arc::default(),
+            local_files: true,
             tool_router: Self::tool_router(),
-        })
+        }
+    }
+
+    /// Refuse `path` and `output_path` arguments; attachment content then
+    /// only travels inline as base64.
+    pub fn without_local_files(mut self) -> Self {
+        self.local_files = false;
+        self
+    }
+
+    fn require_local_files(&self, argument: &str) -> Result<(), McpError> {
+        if self.local_files {
+            return Ok(());
+        }
+        Err(McpError::invalid_params(
+            format!(
+                "{} is not available over HTTP; send and receive content as base64",
+                argument
+            ),
+            None,
+        ))
     }
 }
 
@@ -834,8 +1409,12 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<CreateBoardRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let board = mutating_op!(self.ctx, create_board, req.name, req.card_prefix)?;
-        to_call_tool_result(&board)
+        locked_write(&self.ctx, "create_board", req.dry_run, |ctx| {
+            ctx.create_board(req.name, req.card_prefix)
+                .map_err(kanban_err_to_mcp)
+        })
+        .await?
+        .respond(|board| to_call_tool_result(&board))
     }
 
     #[tool(description = "List all kanban boards")]
@@ -857,6 +1436,27 @@ impl KanbanMcpServer {
         to_call_tool_result(&board)
     }
 
+    #[tool(
+        description = "Summarize a board in one call: columns with counts and WIP status, the active sprint with point totals, blocked and overdue cards, recently changed cards and the parent/child hierarchy. Lists are capped by max_items with the remainder counted."
+    )]
+    pub async fn tool_get_board_digest(
+        &self,
+        Parameters(req): Parameters<GetBoardDigestRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let defaults = digest::DigestLimits::default();
+        let limits = digest::DigestLimits {
+            max_items: req.max_items.unwrap_or(defaults.max_items),
+            max_depth: req.max_depth.unwrap_or(defaults.max_depth),
+            max_title_chars: req.max_title_chars.unwrap_or(defaults.max_title_chars),
+        };
+        let digest = locked_read(&self.ctx, |ctx| {
+            let id = ctx.mcp_resolve_board(&req.board)?;
+            digest::build(ctx, id, limits)
+        })
+        .await?;
+        to_call_tool_result(&digest)
+    }
+
     #[tool(
         description = "Update a board's properties (name, description, sprint_prefix, card_prefix, task_sort_field, task_sort_order)"
     )]
@@ -892,12 +1492,12 @@ impl KanbanMcpServer {
             task_sort_order,
             ..Default::default()
         };
-        let board = locked_write(&self.ctx, |ctx| {
+        locked_write(&self.ctx, "update_board", req.dry_run, |ctx| {
             let id = ctx.mcp_resolve_board(&req.board)?;
             ctx.update_board(id, updates).map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result(&board)
+        .await?
+        .respond(|board| to_call_tool_result(&board))
     }
 
     #[tool(description = "Delete a board and all its columns, cards, and sprints")]
@@ -905,13 +1505,39 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<DeleteBoardRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let id = locked_write(&self.ctx, |ctx| -> Result<_, McpError> {
+        locked_destructive_write(
+            &self.ctx,
+            "delete_board",
+            req.dry_run,
+            req.confirm.as_deref(),
+            |ctx| -> Result<_, McpError> {
+                let id = ctx.mcp_resolve_board(&req.board)?;
+                ctx.delete_board(id).map_err(kanban_err_to_mcp)?;
+                Ok(id)
+            },
+        )
+        .await?
+        .respond(|id| to_call_tool_result_json(serde_json::json!({"deleted": id.to_string()})))
+    }
+
+    #[tool(
+        description = "Copy a board's columns, and optionally its cards and sprints, into a new board"
+    )]
+    pub async fn tool_clone_board(
+        &self,
+        Parameters(req): Parameters<CloneBoardRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let options = CloneOptions {
+            include_cards: req.include_cards.unwrap_or(false),
+            include_sprints: req.include_sprints.unwrap_or(false),
+        };
+        locked_write(&self.ctx, "clone_board", req.dry_run, |ctx| {
             let id = ctx.mcp_resolve_board(&req.board)?;
-            ctx.delete_board(id).map_err(kanban_err_to_mcp)?;
-            Ok(id)
+            ctx.clone_board(id, req.name, options)
+                .map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result_json(serde_json::json!({"deleted": id.to_string()}))
+        .await?
+        .respond(|board| to_call_tool_result(&board))
     }
 
     // Column Operations
@@ -921,13 +1547,13 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<CreateColumnRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let column = locked_write(&self.ctx, |ctx| {
+        locked_write(&self.ctx, "create_column", req.dry_run, |ctx| {
             let board_id = ctx.mcp_resolve_board(&req.board)?;
             ctx.create_column(board_id, req.name, req.position)
                 .map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result(&column)
+        .await?
+        .respond(|column| to_call_tool_result(&column))
     }
 
     #[tool(description = "List all columns in a board")]
@@ -972,12 +1598,12 @@ impl KanbanMcpServer {
                     .unwrap_or(FieldUpdate::NoChange)
             },
         };
-        let column = locked_write(&self.ctx, |ctx| {
+        locked_write(&self.ctx, "update_column", req.dry_run, |ctx| {
             let id = ctx.mcp_resolve_column_global(&req.column)?;
             ctx.update_column(id, updates).map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result(&column)
+        .await?
+        .respond(|column| to_call_tool_result(&column))
     }
 
     #[tool(description = "Delete a column and all its cards")]
@@ -985,13 +1611,19 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<DeleteColumnRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let id = locked_write(&self.ctx, |ctx| -> Result<_, McpError> {
-            let id = ctx.mcp_resolve_column_global(&req.column)?;
-            ctx.delete_column(id).map_err(kanban_err_to_mcp)?;
-            Ok(id)
-        })
-        .await?;
-        to_call_tool_result_json(serde_json::json!({"deleted": id.to_string()}))
+        locked_destructive_write(
+            &self.ctx,
+            "delete_column",
+            req.dry_run,
+            req.confirm.as_deref(),
+            |ctx| -> Result<_, McpError> {
+                let id = ctx.mcp_resolve_column_global(&req.column)?;
+                ctx.delete_column(id).map_err(kanban_err_to_mcp)?;
+                Ok(id)
+            },
+        )
+        .await?
+        .respond(|id| to_call_tool_result_json(serde_json::json!({"deleted": id.to_string()})))
     }
 
     #[tool(description = "Reorder a column to a new position")]
@@ -999,13 +1631,13 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<ReorderColumnRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let column = locked_write(&self.ctx, |ctx| {
+        locked_write(&self.ctx, "reorder_column", req.dry_run, |ctx| {
             let id = ctx.mcp_resolve_column_global(&req.column)?;
             ctx.reorder_column(id, req.position)
                 .map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result(&column)
+        .await?
+        .respond(|column| to_call_tool_result(&column))
     }
 
     // Card Operations
@@ -1017,7 +1649,7 @@ impl KanbanMcpServer {
     ) -> Result<CallToolResult, McpError> {
         let priority = req.priority.as_deref().map(parse_priority).transpose()?;
         let due_date = req.due_date.as_deref().map(parse_datetime).transpose()?;
-        let card = locked_write(&self.ctx, |ctx| {
+        locked_write(&self.ctx, "create_card", req.dry_run, |ctx| {
             let board_id = ctx.mcp_resolve_board(&req.board)?;
             let column_id = ctx.mcp_resolve_column_in_board(&req.column, board_id)?;
             let sprint_id = req
@@ -1035,8 +1667,8 @@ impl KanbanMcpServer {
             ctx.create_card(board_id, column_id, req.title, options)
                 .map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result(&card)
+        .await?
+        .respond(|card| to_call_tool_result(&card))
     }
 
     #[tool(
@@ -1099,6 +1731,7 @@ impl KanbanMcpServer {
         }
         let cards = {
             let guard = self.ctx.lock().await;
+            let _gate = guard.gate().read_owned().await;
             guard
                 .find_cards_by_identifier(&req.card)
                 .map_err(kanban_err_to_mcp)?
@@ -1120,39 +1753,14 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<UpdateCardRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let priority = req.priority.as_deref().map(parse_priority).transpose()?;
-        let status = req.status.as_deref().map(parse_status).transpose()?;
-        let due_date = if req.clear_due_date == Some(true) {
-            FieldUpdate::Clear
-        } else {
-            match req.due_date {
-                Some(ref d) => FieldUpdate::Set(parse_datetime(d)?),
-                None => FieldUpdate::NoChange,
-            }
-        };
-        let updates = CardUpdate {
-            title: req.title,
-            description: req
-                .description
-                .map(FieldUpdate::Set)
-                .unwrap_or(FieldUpdate::NoChange),
-            priority,
-            status,
-            position: None,
-            column_id: None,
-            points: req
-                .points
-                .map(FieldUpdate::Set)
-                .unwrap_or(FieldUpdate::NoChange),
-            due_date,
-            sprint_id: FieldUpdate::NoChange,
-        };
-        let card = locked_write(&self.ctx, |ctx| {
-            let id = ctx.mcp_resolve_card(&req.card)?;
+        let dry_run = req.dry_run;
+        let (card, updates) = parse_card_update(req)?;
+        locked_write(&self.ctx, "update_card", dry_run, |ctx| {
+            let id = ctx.mcp_resolve_card(&card)?;
             ctx.update_card(id, updates).map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result(&card)
+        .await?
+        .respond(|card| to_call_tool_result(&card))
     }
 
     #[tool(description = "Move a card to a different column on the same board")]
@@ -1160,15 +1768,15 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<MoveCardRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let card = locked_write(&self.ctx, |ctx| {
+        locked_write(&self.ctx, "move_card", req.dry_run, |ctx| {
             let id = ctx.mcp_resolve_card(&req.card)?;
             let board_id = card_board(ctx, id)?;
             let column_id = ctx.mcp_resolve_column_in_board(&req.column, board_id)?;
             ctx.move_card(id, column_id, req.position)
                 .map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result(&card)
+        .await?
+        .respond(|card| to_call_tool_result(&card))
     }
 
     #[tool(description = "Archive a card (move to archive, can be restored later)")]
@@ -1176,13 +1784,19 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<ArchiveCardRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let id = locked_write(&self.ctx, |ctx| -> Result<_, McpError> {
-            let id = ctx.mcp_resolve_card(&req.card)?;
-            ctx.archive_card(id).map_err(kanban_err_to_mcp)?;
-            Ok(id)
-        })
-        .await?;
-        to_call_tool_result_json(serde_json::json!({"archived": id.to_string()}))
+        locked_destructive_write(
+            &self.ctx,
+            "archive_card",
+            req.dry_run,
+            req.confirm.as_deref(),
+            |ctx| -> Result<_, McpError> {
+                let id = ctx.mcp_resolve_card(&req.card)?;
+                ctx.archive_card(id).map_err(kanban_err_to_mcp)?;
+                Ok(id)
+            },
+        )
+        .await?
+        .respond(|id| to_call_tool_result_json(serde_json::json!({"archived": id.to_string()})))
     }
 
     #[tool(description = "Restore an archived card")]
@@ -1190,7 +1804,7 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<RestoreCardRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let card = locked_write(&self.ctx, |ctx| {
+        locked_write(&self.ctx, "restore_card", req.dry_run, |ctx| {
             let id = ctx.mcp_resolve_card(&req.card)?;
             let column_id = match req.column.as_deref() {
                 Some(raw) => {
@@ -1201,8 +1815,8 @@ impl KanbanMcpServer {
             };
             ctx.restore_card(id, column_id).map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result(&card)
+        .await?
+        .respond(|card| to_call_tool_result(&card))
     }
 
     #[tool(description = "Delete a card permanently")]
@@ -1210,13 +1824,19 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<DeleteCardRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let id = locked_write(&self.ctx, |ctx| -> Result<_, McpError> {
-            let id = ctx.mcp_resolve_card(&req.card)?;
-            ctx.delete_card(id).map_err(kanban_err_to_mcp)?;
-            Ok(id)
-        })
-        .await?;
-        to_call_tool_result_json(serde_json::json!({"deleted": id.to_string()}))
+        locked_destructive_write(
+            &self.ctx,
+            "delete_card",
+            req.dry_run,
+            req.confirm.as_deref(),
+            |ctx| -> Result<_, McpError> {
+                let id = ctx.mcp_resolve_card(&req.card)?;
+                ctx.delete_card(id).map_err(kanban_err_to_mcp)?;
+                Ok(id)
+            },
+        )
+        .await?
+        .respond(|id| to_call_tool_result_json(serde_json::json!({"deleted": id.to_string()})))
     }
 
     #[tool(
@@ -1257,15 +1877,15 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<AssignCardToSprintRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let card = locked_write(&self.ctx, |ctx| {
+        locked_write(&self.ctx, "assign_card_to_sprint", req.dry_run, |ctx| {
             let card_id = ctx.mcp_resolve_card(&req.card)?;
             let board_id = card_board(ctx, card_id)?;
             let sprint_id = ctx.mcp_resolve_sprint_in_board(&req.sprint, board_id)?;
             ctx.assign_card_to_sprint(card_id, sprint_id)
                 .map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result(&card)
+        .await?
+        .respond(|card| to_call_tool_result(&card))
     }
 
     #[tool(description = "Unassign a card from its sprint")]
@@ -1273,13 +1893,13 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<UnassignCardFromSprintRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let card = locked_write(&self.ctx, |ctx| {
+        locked_write(&self.ctx, "unassign_card_from_sprint", req.dry_run, |ctx| {
             let card_id = ctx.mcp_resolve_card(&req.card)?;
             ctx.unassign_card_from_sprint(card_id)
                 .map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result(&card)
+        .await?
+        .respond(|card| to_call_tool_result(&card))
     }
 
     // Card Utilities
@@ -1310,6 +1930,251 @@ impl KanbanMcpServer {
         to_call_tool_result_json(serde_json::json!({"command": command}))
     }
 
+    // Card checklists
+
+    #[tool(description = "Add an item to a card's checklist")]
+    pub async fn tool_add_checklist_item(
+        &self,
+        Parameters(req): Parameters<AddChecklistItemRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        locked_write(&self.ctx, "add_checklist_item", req.dry_run, |ctx| {
+            let card_id = ctx.mcp_resolve_card(&req.card)?;
+            let position = req.position.map(|p| p.saturating_sub(1));
+            ctx.add_checklist_item(card_id, req.text, position)
+                .map_err(kanban_err_to_mcp)
+        })
+        .await?
+        .respond(|item| to_call_tool_result(&item))
+    }
+
+    #[tool(
+        description = "Change a checklist item's text and/or mark it done or not done. Items are addressed by 1-based position or UUID prefix."
+    )]
+    pub async fn tool_update_checklist_item(
+        &self,
+        Parameters(req): Parameters<UpdateChecklistItemRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        locked_write(&self.ctx, "update_checklist_item", req.dry_run, |ctx| {
+            let (card_id, item_id) = resolve_checklist_item(ctx, &req.card, &req.item)?;
+            ctx.update_checklist_item(card_id, item_id, req.text, req.done)
+                .map_err(kanban_err_to_mcp)
+        })
+        .await?
+        .respond(|item| to_call_tool_result(&item))
+    }
+
+    #[tool(description = "Move a checklist item to another position. Returns the card.")]
+    pub async fn tool_move_checklist_item(
+        &self,
+        Parameters(req): Parameters<MoveChecklistItemRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        locked_write(&self.ctx, "move_checklist_item", req.dry_run, |ctx| {
+            let (card_id, item_id) = resolve_checklist_item(ctx, &req.card, &req.item)?;
+            ctx.move_checklist_item(card_id, item_id, req.position.saturating_sub(1))
+                .map_err(kanban_err_to_mcp)
+        })
+        .await?
+        .respond(|card| to_call_tool_result(&card))
+    }
+
+    #[tool(description = "Remove a checklist item. Returns the card.")]
+    pub async fn tool_remove_checklist_item(
+        &self,
+        Parameters(req): Parameters<RemoveChecklistItemRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        locked_destructive_write(
+            &self.ctx,
+            "remove_checklist_item",
+            req.dry_run,
+            req.confirm.as_deref(),
+            |ctx| {
+                let (card_id, item_id) = resolve_checklist_item(ctx, &req.card, &req.item)?;
+                ctx.remove_checklist_item(card_id, item_id)
+                    .map_err(kanban_err_to_mcp)
+            },
+        )
+        .await?
+        .respond(|card| to_call_tool_result(&card))
+    }
+
+    #[tool(
+        description = "Replace a checklist item with a child card in the parent's column. Returns the new card."
+    )]
+    pub async fn tool_convert_checklist_item_to_card(
+        &self,
+        Parameters(req): Parameters<ChecklistItemRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        locked_write(
+            &self.ctx,
+            "convert_checklist_item_to_card",
+            req.dry_run,
+            |ctx| {
+                let (card_id, item_id) = resolve_checklist_item(ctx, &req.card, &req.item)?;
+                ctx.convert_checklist_item_to_card(card_id, item_id)
+                    .map_err(kanban_err_to_mcp)
+            },
+        )
+        .await?
+        .respond(|card| to_call_tool_result(&card))
+    }
+
+    #[tool(
+        description = "Move Markdown task lines ('- [ ] ...', '- [x] ...') from a card's description into its checklist. Returns the items added."
+    )]
+    pub async fn tool_import_checklist_from_description(
+        &self,
+        Parameters(req): Parameters<ImportChecklistRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        locked_write(
+            &self.ctx,
+            "import_checklist_from_description",
+            req.dry_run,
+            |ctx| {
+                let card_id = ctx.mcp_resolve_card(&req.card)?;
+                ctx.import_checklist_from_description(card_id)
+                    .map_err(kanban_err_to_mcp)
+            },
+        )
+        .await?
+        .respond(|items| to_call_tool_result(&items))
+    }
+
+    // Card attachments
+
+    #[tool(
+        description = "Attach a file to a card, from a local path or base64 content. The content is stored beside the board data, so later changes to the original file are not picked up."
+    )]
+    pub async fn tool_add_card_attachment(
+        &self,
+        Parameters(req): Parameters<AddAttachmentRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let (name, content) = match (req.path, req.content_base64) {
+            (Some(path), None) => {
+                self.require_local_files("path")?;
+                let content = std::fs::read(&path).map_err(|e| {
+                    McpError::invalid_params(format!("Failed to read {}: {}", path, e), None)
+                })?;
+                let name = req.name.unwrap_or_else(|| {
+                    std::path::Path::new(&path)
+                        .file_name()
+                        .map(|n| n.to_string_lossy().into_owned())
+                        .unwrap_or(path.clone())
+                });
+                (name, content)
+            }
+            (None, Some(encoded)) => {
+                let name = req.name.ok_or_else(|| {
+                    McpError::invalid_params("name is required with content_base64", None)
+                })?;
+                let content = STANDARD.decode(encoded.trim()).map_err(|e| {
+                    McpError::invalid_params(format!("content_base64 is not valid: {}", e), None)
+                })?;
+                (name, content)
+            }
+            _ => {
+                return Err(McpError::invalid_params(
+                    "Give exactly one of path or content_base64",
+                    None,
+                ))
+            }
+        };
+        locked_write(&self.ctx, "add_card_attachment", req.dry_run, |ctx| {
+            let card_id = ctx.mcp_resolve_card(&req.card)?;
+            ctx.add_attachment(card_id, name, &content)
+                .map_err(kanban_err_to_mcp)
+        })
+        .await?
+        .respond(|attachment| to_call_tool_result(&attachment))
+    }
+
+    #[tool(description = "List a card's attachments: name, size, MIME type and hash")]
+    pub async fn tool_list_card_attachments(
+        &self,
+        Parameters(req): Parameters<ListAttachmentsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let attachments = locked_read(&self.ctx, |ctx| {
+            let card_id = ctx.mcp_resolve_card(&req.card)?;
+            ctx.get_card(card_id)
+                .map_err(kanban_err_to_mcp)?
+                .map(|card| card.attachments)
+                .ok_or_else(|| kanban_err_to_mcp(KanbanError::not_found("Card", card_id)))
+        })
+        .await?;
+        to_call_tool_result(&attachments)
+    }
+
+    #[tool(
+        description = "Read an attachment. Returns its content as base64 (plus 'text' when it is UTF-8), or writes it to output_path and returns the path."
+    )]
+    pub async fn tool_get_card_attachment(
+        &self,
+        Parameters(req): Parameters<GetAttachmentRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        if req.output_path.is_some() {
+            self.require_local_files("output_path")?;
+        }
+        let (attachment, content) = locked_read(&self.ctx, |ctx| {
+            let (card_id, attachment_id) = resolve_attachment(ctx, &req.card, &req.attachment)?;
+            ctx.read_attachment(card_id, attachment_id)
+                .map_err(kanban_err_to_mcp)
+        })
+        .await?;
+        if let Some(output_path) = req.output_path {
+            let path = extract_attachment(
+                &attachment,
+                &content,
+                Some(std::path::Path::new(&output_path)),
+            )
+            .map_err(kanban_err_to_mcp)?;
+            return to_call_tool_result_json(serde_json::json!({
+                "attachment": attachment,
+                "path": path,
+            }));
+        }
+        to_call_tool_result_json(serde_json::json!({
+            "attachment": attachment,
+            "content_base64": STANDARD.encode(&content),
+            "text": std::str::from_utf8(&content).ok(),
+        }))
+    }
+
+    #[tool(
+        description = "Detach a file from a card. The content stays stored until collect_attachment_garbage runs. Returns the card."
+    )]
+    pub async fn tool_remove_card_attachment(
+        &self,
+        Parameters(req): Parameters<AttachmentRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        locked_destructive_write(
+            &self.ctx,
+            "remove_card_attachment",
+            req.dry_run,
+            req.confirm.as_deref(),
+            |ctx| {
+                let (card_id, attachment_id) = resolve_attachment(ctx, &req.card, &req.attachment)?;
+                ctx.remove_attachment(card_id, attachment_id)
+                    .map_err(kanban_err_to_mcp)
+            },
+        )
+        .await?
+        .respond(|card| to_call_tool_result(&card))
+    }
+
+    #[tool(
+        description = "Delete stored attachment content that no card refers to any more. Use dry_run to see what would be freed."
+    )]
+    pub async fn tool_collect_attachment_garbage(
+        &self,
+        Parameters(req): Parameters<CollectAttachmentGarbageRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        locked_write(&self.ctx, "collect_attachment_garbage", None, |ctx| {
+            ctx.collect_attachment_garbage(req.dry_run.unwrap_or(false))
+                .map_err(kanban_err_to_mcp)
+        })
+        .await?
+        .respond(|report| to_call_tool_result(&report))
+    }
+
     // Card relations (parent/child)
 
     #[tool(
@@ -1321,18 +2186,25 @@ impl KanbanMcpServer {
     ) -> Result<CallToolResult, McpError> {
         let parent_raw = req.parent.clone();
         let child_raw = req.child.clone();
-        let (child_id, parent_id) = locked_write(&self.ctx, |ctx| -> KanbanMcpResult<_> {
-            let child_id = ctx.resolve_card_id(&req.child)?;
-            let parent_id = ctx.resolve_card_id(&req.parent)?;
-            ctx.attach_child(parent_id, child_id)
-                .map_err(|e| mcp_enrich_add_error(e, &parent_raw, &child_raw))?;
-            Ok((child_id, parent_id))
+        locked_write(
+            &self.ctx,
+            "set_card_parent",
+            req.dry_run,
+            |ctx| -> KanbanMcpResult<_> {
+                let child_id = ctx.resolve_card_id(&req.child)?;
+                let parent_id = ctx.resolve_card_id(&req.parent)?;
+                ctx.attach_child(parent_id, child_id)
+                    .map_err(|e| mcp_enrich_add_error(e, &parent_raw, &child_raw))?;
+                Ok((child_id, parent_id))
+            },
+        )
+        .await?
+        .respond(|(child_id, parent_id)| {
+            to_call_tool_result_json(serde_json::json!({
+                "parent": parent_id.to_string(),
+                "child":  child_id.to_string(),
+            }))
         })
-        .await?;
-        to_call_tool_result_json(serde_json::json!({
-            "parent": parent_id.to_string(),
-            "child":  child_id.to_string(),
-        }))
     }
 
     #[tool(description = "Remove a parent -> child edge between two cards.")]
@@ -1342,18 +2214,25 @@ impl KanbanMcpServer {
     ) -> Result<CallToolResult, McpError> {
         let parent_raw = req.parent.clone();
         let child_raw = req.child.clone();
-        let (child_id, parent_id) = locked_write(&self.ctx, |ctx| -> KanbanMcpResult<_> {
-            let child_id = ctx.resolve_card_id(&req.child)?;
-            let parent_id = ctx.resolve_card_id(&req.parent)?;
-            ctx.detach_child(parent_id, child_id)
-                .map_err(|e| mcp_enrich_remove_error(e, &parent_raw, &child_raw))?;
-            Ok((child_id, parent_id))
+        locked_write(
+            &self.ctx,
+            "remove_card_parent",
+            req.dry_run,
+            |ctx| -> KanbanMcpResult<_> {
+                let child_id = ctx.resolve_card_id(&req.child)?;
+                let parent_id = ctx.resolve_card_id(&req.parent)?;
+                ctx.detach_child(parent_id, child_id)
+                    .map_err(|e| mcp_enrich_remove_error(e, &parent_raw, &child_raw))?;
+                Ok((child_id, parent_id))
+            },
+        )
+        .await?
+        .respond(|(child_id, parent_id)| {
+            to_call_tool_result_json(serde_json::json!({
+                "parent": parent_id.to_string(),
+                "child":  child_id.to_string(),
+            }))
         })
-        .await?;
-        to_call_tool_result_json(serde_json::json!({
-            "parent": parent_id.to_string(),
-            "child":  child_id.to_string(),
-        }))
     }
 
     #[tool(description = "List direct parents of a card.")]
@@ -1393,12 +2272,84 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<ArchiveCardsRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let count = locked_write(&self.ctx, |ctx| {
-            let ids = ctx.mcp_resolve_cards(&req.cards)?;
-            ctx.archive_cards(ids).map_err(kanban_err_to_mcp)
+        locked_destructive_write(
+            &self.ctx,
+            "archive_cards",
+            req.dry_run,
+            req.confirm.as_deref(),
+            |ctx| {
+                let ids = ctx.mcp_resolve_cards(&req.cards)?;
+                ctx.archive_cards(ids).map_err(kanban_err_to_mcp)
+            },
+        )
+        .await?
+        .respond(|count| to_call_tool_result_json(serde_json::json!({"archived_count": count})))
+    }
+
+    #[tool(
+        description = "Update several cards at once, each with its own fields. Applied as one batch and one undo step; if any card fails, none change."
+    )]
+    pub async fn tool_update_cards(
+        &self,
+        Parameters(req): Parameters<UpdateCardsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let updates = req
+            .cards
+            .into_iter()
+            .map(parse_card_update)
+            .collect::<Result<Vec<_>, _>>()?;
+        locked_write(&self.ctx, "update_cards", req.dry_run, |ctx| {
+            let updates = updates
+                .into_iter()
+                .map(|(card, update)| Ok((ctx.mcp_resolve_card(&card)?, update)))
+                .collect::<Result<Vec<_>, McpError>>()?;
+            ctx.update_cards(updates).map_err(kanban_err_to_mcp)
+        })
+        .await?
+        .respond(|count| to_call_tool_result_json(serde_json::json!({"updated_count": count})))
+    }
+
+    #[tool(
+        description = "Create several cards on one board, with parent/child and blocks relations between them given by temporary keys (or to existing cards). Applied as one batch and one undo step; if any card or relation is rejected, nothing is created."
+    )]
+    pub async fn tool_create_cards(
+        &self,
+        Parameters(req): Parameters<CreateCardsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        locked_write(&self.ctx, "create_cards", req.dry_run, |ctx| {
+            let board_id = ctx.mcp_resolve_board(&req.board)?;
+            let (batch, keys) = new_card_batch(ctx, board_id, &req)?;
+            let cards = ctx
+                .create_cards(board_id, batch)
+                .map_err(kanban_err_to_mcp)?;
+            let board = ctx
+                .get_board(board_id)
+                .map_err(kanban_err_to_mcp)?
+                .ok_or_else(|| McpError::internal_error("Board vanished", None))?;
+            let sprints = ctx.list_sprints(board_id).map_err(kanban_err_to_mcp)?;
+            let prefix = ctx.app_config().effective_default_card_prefix();
+            Ok::<_, McpError>(
+                cards
+                    .iter()
+                    .zip(keys)
+                    .map(|(card, key)| {
+                        serde_json::json!({
+                            "key": key,
+                            "identifier": card.identifier(&board, &sprints, prefix),
+                            "id": card.id,
+                            "title": card.title,
+                        })
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .await?
+        .respond(|created| {
+            to_call_tool_result_json(serde_json::json!({
+                "created_count": created.len(),
+                "cards": created,
+            }))
         })
-        .await?;
-        to_call_tool_result_json(serde_json::json!({"archived_count": count}))
     }
 
     #[tool(
@@ -1408,14 +2359,14 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<MoveCardsRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let count = locked_write(&self.ctx, |ctx| {
+        locked_write(&self.ctx, "move_cards", req.dry_run, |ctx| {
             let ids = ctx.mcp_resolve_cards(&req.cards)?;
             let board_id = ctx.mcp_require_same_board(&ids)?;
             let column_id = ctx.mcp_resolve_column_in_board(&req.column, board_id)?;
             ctx.move_cards(ids, column_id).map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result_json(serde_json::json!({"moved_count": count}))
+        .await?
+        .respond(|count| to_call_tool_result_json(serde_json::json!({"moved_count": count})))
     }
 
     #[tool(
@@ -1425,15 +2376,15 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<AssignCardsToSprintRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let count = locked_write(&self.ctx, |ctx| {
+        locked_write(&self.ctx, "assign_cards_to_sprint", req.dry_run, |ctx| {
             let ids = ctx.mcp_resolve_cards(&req.cards)?;
             let board_id = ctx.mcp_require_same_board(&ids)?;
             let sprint_id = ctx.mcp_resolve_sprint_in_board(&req.sprint, board_id)?;
             ctx.assign_cards_to_sprint(ids, sprint_id)
                 .map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result_json(serde_json::json!({"assigned_count": count}))
+        .await?
+        .respond(|count| to_call_tool_result_json(serde_json::json!({"assigned_count": count})))
     }
 
     // Sprint Operations
@@ -1443,13 +2394,13 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<CreateSprintRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let sprint = locked_write(&self.ctx, |ctx| {
+        locked_write(&self.ctx, "create_sprint", req.dry_run, |ctx| {
             let board_id = ctx.mcp_resolve_board(&req.board)?;
             ctx.create_sprint(board_id, req.prefix, req.name)
                 .map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result(&sprint)
+        .await?
+        .respond(|sprint| to_call_tool_result(&sprint))
     }
 
     #[tool(description = "List sprints for a board")]
@@ -1516,12 +2467,12 @@ impl KanbanMcpServer {
             start_date,
             end_date,
         };
-        let sprint = locked_write(&self.ctx, |ctx| {
+        locked_write(&self.ctx, "update_sprint", req.dry_run, |ctx| {
             let id = ctx.mcp_resolve_sprint_global(&req.sprint)?;
             ctx.update_sprint(id, updates).map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result(&sprint)
+        .await?
+        .respond(|sprint| to_call_tool_result(&sprint))
     }
 
     #[tool(description = "Activate a sprint")]
@@ -1529,13 +2480,13 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<ActivateSprintRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let sprint = locked_write(&self.ctx, |ctx| {
+        locked_write(&self.ctx, "activate_sprint", req.dry_run, |ctx| {
             let id = ctx.mcp_resolve_sprint_global(&req.sprint)?;
             ctx.activate_sprint(id, req.duration_days)
                 .map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result(&sprint)
+        .await?
+        .respond(|sprint| to_call_tool_result(&sprint))
     }
 
     #[tool(description = "Complete a sprint")]
@@ -1543,12 +2494,12 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<CompleteSprintRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let sprint = locked_write(&self.ctx, |ctx| {
+        locked_write(&self.ctx, "complete_sprint", req.dry_run, |ctx| {
             let id = ctx.mcp_resolve_sprint_global(&req.sprint)?;
             ctx.complete_sprint(id).map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result(&sprint)
+        .await?
+        .respond(|sprint| to_call_tool_result(&sprint))
     }
 
     #[tool(description = "Cancel a sprint")]
@@ -1556,12 +2507,12 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<CancelSprintRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let sprint = locked_write(&self.ctx, |ctx| {
+        locked_write(&self.ctx, "cancel_sprint", req.dry_run, |ctx| {
             let id = ctx.mcp_resolve_sprint_global(&req.sprint)?;
             ctx.cancel_sprint(id).map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result(&sprint)
+        .await?
+        .respond(|sprint| to_call_tool_result(&sprint))
     }
 
     #[tool(description = "Delete a sprint")]
@@ -1569,13 +2520,19 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<DeleteSprintRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let id = locked_write(&self.ctx, |ctx| -> Result<_, McpError> {
-            let id = ctx.mcp_resolve_sprint_global(&req.sprint)?;
-            ctx.delete_sprint(id).map_err(kanban_err_to_mcp)?;
-            Ok(id)
-        })
-        .await?;
-        to_call_tool_result_json(serde_json::json!({"deleted": id.to_string()}))
+        locked_destructive_write(
+            &self.ctx,
+            "delete_sprint",
+            req.dry_run,
+            req.confirm.as_deref(),
+            |ctx| -> Result<_, McpError> {
+                let id = ctx.mcp_resolve_sprint_global(&req.sprint)?;
+                ctx.delete_sprint(id).map_err(kanban_err_to_mcp)?;
+                Ok(id)
+            },
+        )
+        .await?
+        .respond(|id| to_call_tool_result_json(serde_json::json!({"deleted": id.to_string()})))
     }
 
     #[tool(
@@ -1585,7 +2542,7 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<CarryOverSprintCardsRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let count = locked_write(&self.ctx, |ctx| {
+        locked_write(&self.ctx, "carry_over_sprint_cards", req.dry_run, |ctx| {
             let from_id = ctx.mcp_resolve_sprint_global(&req.from_sprint)?;
             let from_sprint = ctx
                 .get_sprint(from_id)
@@ -1597,8 +2554,10 @@ impl KanbanMcpServer {
             ctx.carry_over_sprint_cards(from_id, to_id)
                 .map_err(kanban_err_to_mcp)
         })
-        .await?;
-        to_call_tool_result_json(serde_json::json!({ "carried_over_count": count }))
+        .await?
+        .respond(|count| {
+            to_call_tool_result_json(serde_json::json!({ "carried_over_count": count }))
+        })
     }
 
     // Export/Import
@@ -1613,7 +2572,12 @@ impl KanbanMcpServer {
                 Some(raw) => Some(ctx.mcp_resolve_board(raw)?),
                 None => None,
             };
-            ctx.export_board(board_id).map_err(kanban_err_to_mcp)
+            if req.include_attachments.unwrap_or(false) {
+                ctx.export_board_as(board_id, kanban_domain::BoardExportFormat::Bundle)
+            } else {
+                ctx.export_board(board_id)
+            }
+            .map_err(kanban_err_to_mcp)
         })
         .await?;
         Ok(CallToolResult::success(vec![Content::text(json)]))
@@ -1624,14 +2588,26 @@ impl KanbanMcpServer {
         &self,
         Parameters(req): Parameters<ImportBoardRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let data = req.data;
-        let board = mutating_op!(self.ctx, import_board, &data)?;
-        to_call_tool_result(&board)
+        let as_copy = req.as_copy.unwrap_or(false);
+        locked_write(&self.ctx, "import_board", req.dry_run, |ctx| {
+            if as_copy {
+                ctx.import_board_as_copy(&req.data)
+            } else {
+                ctx.import_board(&req.data)
+            }
+            .map_err(kanban_err_to_mcp)
+        })
+        .await?
+        .respond(|board| to_call_tool_result(&board))
     }
 
-    #[tool(description = "Undo the last operation")]
+    #[tool(
+        description = "Undo this session's last operation. Fails if another client has since changed the same data"
+    )]
     pub async fn tool_undo(&self) -> Result<CallToolResult, McpError> {
         let mut guard = self.ctx.lock().await;
+        let _gate = guard.gate().write_owned().await;
+        guard.reload().await.map_err(kanban_err_to_mcp)?;
         if guard.undo().map_err(kanban_err_to_mcp)? {
             guard.save().await.map_err(kanban_err_to_mcp)?;
             Ok(CallToolResult::success(vec![Content::text(
@@ -1644,9 +2620,13 @@ impl KanbanMcpServer {
         }
     }
 
-    #[tool(description = "Redo the last undone operation")]
+    #[tool(
+        description = "Redo this session's last undone operation. Fails if another client has since changed the same data"
+    )]
     pub async fn tool_redo(&self) -> Result<CallToolResult, McpError> {
         let mut guard = self.ctx.lock().await;
+        let _gate = guard.gate().write_owned().await;
+        guard.reload().await.map_err(kanban_err_to_mcp)?;
         if guard.redo().map_err(kanban_err_to_mcp)? {
             guard.save().await.map_err(kanban_err_to_mcp)?;
             Ok(CallToolResult::success(vec![Content::text(
@@ -1669,15 +2649,142 @@ impl ServerHandler for KanbanMcpServer {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
             protocol_version: ProtocolVersion::V_2024_11_05,
-            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_resources()
+                .enable_resources_subscribe()
+                .enable_resources_list_changed()
+                .enable_prompts()
+                .build(),
             server_info: Implementation::from_build_env(),
             instructions: Some(
                 "Kanban MCP Server - Manage your kanban boards, columns, and cards through MCP. \
-                 This server delegates to the kanban CLI for all operations."
+                 This server delegates to the kanban CLI for all operations. Boards, cards and \
+                 the current sprint are also readable as kanban:// resources, and the \
+                 plan_next_sprint, triage_backlog and write_standup_summary prompts arrive \
+                 with the board context they need."
                     .to_string(),
             ),
         }
     }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, McpError> {
+        let resources = locked_read(&self.ctx, resources::list).await?;
+        Ok(ListResourcesResult::with_all_items(resources))
+    }
+
+    async fn list_resource_templates(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourceTemplatesResult, McpError> {
+        Ok(ListResourceTemplatesResult::with_all_items(
+            resources::templates(),
+        ))
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, McpError> {
+        locked_read(&self.ctx, |ctx| resources::read(ctx, &request.uri)).await
+    }
+
+    async fn subscribe(
+        &self,
+        request: SubscribeRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        resources::KanbanResource::parse(&request.uri)?;
+        self.subscriptions
+            .lock()
+            .map_err(|_| McpError::internal_error("Subscriptions poisoned", None))?
+            .insert(request.uri);
+        Ok(())
+    }
+
+    async fn unsubscribe(
+        &self,
+        request: UnsubscribeRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        self.subscriptions
+            .lock()
+            .map_err(|_| McpError::internal_error("Subscriptions poisoned", None))?
+            .remove(&request.uri);
+        Ok(())
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListPromptsResult, McpError> {
+        Ok(ListPromptsResult::with_all_items(prompts::list()))
+    }
+
+    async fn get_prompt(
+        &self,
+        request: GetPromptRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<GetPromptResult, McpError> {
+        locked_read(&self.ctx, |ctx| {
+            prompts::get(ctx, &request.name, request.arguments)
+        })
+        .await
+    }
+
+    /// Relay store changes to the client for as long as it is connected:
+    /// `resources/updated` for every subscribed URI, and
+    /// `resources/list_changed` when boards come or go.
+    async fn on_initialized(&self, context: NotificationContext<RoleServer>) {
+        tracing::info!("client initialized");
+        let mut changes = self.ctx.lock().await.subscribe_changes();
+        let ctx = Arc::clone(&self.ctx);
+        let subscriptions = Arc::clone(&self.subscriptions);
+        let peer = context.peer;
+        let board_ids = |ctx: &McpContext| -> Vec<Uuid> {
+            ctx.list_boards()
+                .map(|boards| boards.into_iter().map(|b| b.id).collect())
+                .unwrap_or_default()
+        };
+        let mut boards = locked_read(&ctx, |c| Ok::<_, McpError>(board_ids(c)))
+            .await
+            .unwrap_or_default();
+        tokio::spawn(async move {
+            loop {
+                if let Err(RecvError::Closed) = changes.recv().await {
+                    break;
+                }
+                let current = locked_read(&ctx, |c| Ok::<_, McpError>(board_ids(c)))
+                    .await
+                    .unwrap_or_default();
+                if current != boards {
+                    boards = current;
+                    if peer.notify_resource_list_changed().await.is_err() {
+                        break;
+                    }
+                }
+                let uris: Vec<String> = match subscriptions.lock() {
+                    Ok(subscribed) => subscribed.iter().cloned().collect(),
+                    Err(_) => break,
+                };
+                for uri in uris {
+                    let notified = peer
+                        .notify_resource_updated(ResourceUpdatedNotificationParam { uri })
+                        .await;
+                    if notified.is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+    }
 }
 
 #[cfg(test)]
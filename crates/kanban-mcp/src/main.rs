@@ -5,6 +5,7 @@ use anyhow::Result;
 use clap::Parser;
 use kanban_core::CLI_VERSION_DISPLAY;
 use kanban_mcp::McpServer;
+use std::net::SocketAddr;
 
 #[derive(Parser)]
 #[command(
@@ -15,6 +16,24 @@ use kanban_mcp::McpServer;
 struct Args {
     /// Path to the kanban data file (JSON or SQLite)
     data_file: Option<String>,
+
+    /// Serve streamable HTTP on this address (e.g. 127.0.0.1:8765) instead
+    /// of stdio, so several MCP clients can share one board
+    #[arg(long, value_name = "ADDR")]
+    listen: Option<SocketAddr>,
+
+    /// Require this bearer token from HTTP clients
+    #[arg(
+        long,
+        value_name = "TOKEN",
+        env = "KANBAN_MCP_TOKEN",
+        requires = "listen"
+    )]
+    token: Option<String>,
+
+    /// Allow --listen on a non-loopback address; needs --token
+    #[arg(long, requires = "token")]
+    allow_remote: bool,
 }
 
 #[tokio::main]
@@ -24,5 +43,14 @@ async fn main() -> Result<()> {
     if let Some(path) = args.data_file {
         server = server.with_data_file(path);
     }
+    if let Some(addr) = args.listen {
+        server = server.with_listen(addr);
+    }
+    if let Some(token) = args.token {
+        server = server.with_bearer_token(token);
+    }
+    if args.allow_remote {
+        server = server.allow_remote();
+    }
     server.run().await
 }
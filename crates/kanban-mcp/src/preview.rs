@@ -0,0 +1,241 @@
+//! Dry runs of mutating tools.
+//!
+//! With `dry_run: true` a tool runs against a scratch copy of the data
+//! ([`McpContext::preview`]) and answers with what it would have created,
+//! changed or deleted instead of its usual result. Destructive tools also
+//! return a confirmation token: a hash of that exact set of changes. With
+//! `mcp_confirm_destructive = true` in the config they refuse to run
+//! without it, so an agent has to look before it deletes, and a token
+//! from before someone else changed the same cards no longer matches.
+
+use crate::context::McpContext;
+use crate::kanban_err_to_mcp;
+use kanban_core::AppConfig;
+use kanban_domain::snapshot_diff::{diff_snapshots, ChangedEntity, SnapshotChange};
+use kanban_domain::{Card, Snapshot};
+use kanban_persistence::blob_hash;
+use rmcp::model::ErrorData as McpError;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use uuid::Uuid;
+
+/// Tools that delete or archive data. Their dry runs carry a confirmation
+/// token, and `mcp_confirm_destructive` makes them require it.
+pub const DESTRUCTIVE_TOOLS: &[&str] = &[
+    "delete_board",
+    "delete_column",
+    "delete_card",
+    "delete_sprint",
+    "archive_card",
+    "archive_cards",
+    "remove_card_attachment",
+    "remove_checklist_item",
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Preview {
+    pub dry_run: bool,
+    pub tool: String,
+    pub changes: Vec<PreviewChange>,
+    /// Parent/child, blocks and relates edges added or removed.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub relations: Vec<RelationChange>,
+    /// Pass as `confirm` to run the same call for real. Only set for
+    /// destructive tools.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirmation_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PreviewChange {
+    #[serde(flatten)]
+    pub change: SnapshotChange,
+    /// Card identifier and title, or the board, column or sprint name.
+    pub label: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct RelationChange {
+    pub change: EdgeChange,
+    pub kind: EdgeKind,
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EdgeChange {
+    Added,
+    Removed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EdgeKind {
+    /// `from` is the parent of `to`.
+    Parent,
+    /// `from` blocks `to`.
+    Blocks,
+    Relates,
+}
+
+pub fn is_destructive(tool: &str) -> bool {
+    DESTRUCTIVE_TOOLS.contains(&tool)
+}
+
+/// Run `write` against a scratch copy of `ctx` and describe what it did.
+pub fn run<T, E, F>(ctx: &McpContext, tool: &str, write: F) -> Result<Preview, McpError>
+where
+    F: FnOnce(&mut McpContext) -> Result<T, E>,
+    E: Into<McpError>,
+{
+    let mut scratch = ctx.preview().map_err(kanban_err_to_mcp)?;
+    let before = scratch.snapshot().map_err(kanban_err_to_mcp)?;
+    write(&mut scratch).map_err(Into::into)?;
+    let after = scratch.snapshot().map_err(kanban_err_to_mcp)?;
+    Ok(Preview::new(tool, &before, &after, ctx.app_config()))
+}
+
+impl Preview {
+    pub fn new(tool: &str, before: &Snapshot, after: &Snapshot, config: &AppConfig) -> Self {
+        let labels = Labels {
+            snapshots: [before, after],
+            config,
+        };
+        let changes: Vec<PreviewChange> = diff_snapshots(before, after)
+            .into_iter()
+            .map(|change| PreviewChange {
+                label: labels.entity(&change),
+                change,
+            })
+            .collect();
+        let added = &edges(after) - &edges(before);
+        let removed = &edges(before) - &edges(after);
+        let mut relations: Vec<RelationChange> = added
+            .into_iter()
+            .map(|edge| (EdgeChange::Added, edge))
+            .chain(removed.into_iter().map(|edge| (EdgeChange::Removed, edge)))
+            .map(|(change, (kind, from, to))| RelationChange {
+                change,
+                kind,
+                from: labels.card_identifier(from),
+                to: labels.card_identifier(to),
+            })
+            .collect();
+        relations.sort();
+
+        let confirmation_token = is_destructive(tool).then(|| token(tool, &changes, &relations));
+        Self {
+            dry_run: true,
+            tool: tool.to_string(),
+            changes,
+            relations,
+            confirmation_token,
+        }
+    }
+}
+
+/// Short hash of what a destructive call would change. Deterministic for
+/// deletions and archives, which only name entities that already exist.
+fn token(tool: &str, changes: &[PreviewChange], relations: &[RelationChange]) -> String {
+    let body = serde_json::to_vec(&(tool, changes, relations)).unwrap_or_default();
+    blob_hash(&body)[..16].to_string()
+}
+
+/// Active edges as `(kind, from, to)`. Archived edges count as absent, so
+/// archiving a card shows its relations as removed.
+pub(crate) fn edges(snapshot: &Snapshot) -> BTreeSet<(EdgeKind, Uuid, Uuid)> {
+    let graph = &snapshot.graph;
+    let spawns = graph
+        .spawns_edges()
+        .iter()
+        .filter(|e| e.base.archived_at.is_none())
+        .map(|e| (EdgeKind::Parent, e.base.source, e.base.target));
+    let blocks = graph
+        .blocks_edges()
+        .iter()
+        .filter(|e| e.base.archived_at.is_none())
+        .map(|e| (EdgeKind::Blocks, e.base.source, e.base.target));
+    let relates = graph
+        .relates_edges()
+        .iter()
+        .filter(|e| e.base.archived_at.is_none())
+        .map(|e| (EdgeKind::Relates, e.base.source, e.base.target));
+    spawns.chain(blocks).chain(relates).collect()
+}
+
+/// Names for entities that may exist on only one side of the diff.
+struct Labels<'a> {
+    snapshots: [&'a Snapshot; 2],
+    config: &'a AppConfig,
+}
+
+impl Labels<'_> {
+    fn entity(&self, change: &SnapshotChange) -> String {
+        let id = change.id;
+        let found = match change.entity {
+            ChangedEntity::Board => {
+                self.find(|s| s.boards.iter().find(|b| b.id == id).map(|b| b.name.clone()))
+            }
+            ChangedEntity::Column => self.find(|s| {
+                s.columns
+                    .iter()
+                    .find(|c| c.id == id)
+                    .map(|c| c.name.clone())
+            }),
+            ChangedEntity::Sprint => self.find(|s| {
+                let sprint = s.sprints.iter().find(|sp| sp.id == id)?;
+                let board = s.boards.iter().find(|b| b.id == sprint.board_id)?;
+                Some(sprint.formatted_name(board, self.config.effective_default_sprint_prefix()))
+            }),
+            ChangedEntity::Card => self
+                .card(id)
+                .map(|card| format!("{} {}", self.identifier(card), card.title)),
+        };
+        found.unwrap_or_else(|| id.to_string())
+    }
+
+    fn card_identifier(&self, id: Uuid) -> String {
+        self.card(id)
+            .map(|card| self.identifier(card))
+            .unwrap_or_else(|| id.to_string())
+    }
+
+    /// Prefer the `after` side so renames show the new name; deleted
+    /// entities fall back to `before`.
+    fn find<T>(&self, f: impl Fn(&Snapshot) -> Option<T>) -> Option<T> {
+        self.snapshots.iter().rev().find_map(|s| f(s))
+    }
+
+    fn card(&self, id: Uuid) -> Option<&Card> {
+        self.snapshots.iter().rev().find_map(|s| {
+            s.cards.iter().find(|c| c.id == id).or_else(|| {
+                s.archived_cards
+                    .iter()
+                    .map(|a| &a.card)
+                    .find(|c| c.id == id)
+            })
+        })
+    }
+
+    fn identifier(&self, card: &Card) -> String {
+        let column_boards: HashMap<Uuid, Uuid> = self
+            .snapshots
+            .iter()
+            .flat_map(|s| s.columns.iter().map(|c| (c.id, c.board_id)))
+            .collect();
+        self.snapshots
+            .iter()
+            .rev()
+            .find_map(|s| {
+                let board_id = column_boards.get(&card.column_id)?;
+                let board = s.boards.iter().find(|b| b.id == *board_id)?;
+                Some(card.identifier(
+                    board,
+                    &s.sprints,
+                    self.config.effective_default_card_prefix(),
+                ))
+            })
+            .unwrap_or_else(|| card.id.to_string())
+    }
+}
@@ -0,0 +1,391 @@
+//! MCP prompts: canned requests that arrive with the board context they
+//! need already assembled, so the model doesn't page through cards first.
+
+use crate::context::McpContext;
+use crate::resources::{single_line, BoardSnapshot};
+use crate::{kanban_err_to_mcp, McpResolve};
+use chrono::{Duration, Utc};
+use kanban_core::parse_datetime_input;
+use kanban_domain::{Card, CardPriority, CardStatus, GraphOperations, SprintStatus};
+use rmcp::model::{
+    ErrorData as McpError, GetPromptResult, JsonObject, Prompt, PromptArgument, PromptMessage,
+    PromptMessageRole,
+};
+use std::fmt::Write;
+
+pub const PLAN_NEXT_SPRINT: &str = "plan_next_sprint";
+pub const TRIAGE_BACKLOG: &str = "triage_backlog";
+pub const WRITE_STANDUP_SUMMARY: &str = "write_standup_summary";
+
+/// Days without an update after which triage calls a card stale.
+const STALE_AFTER_DAYS: i64 = 30;
+
+fn argument(name: &str, description: &str, required: bool) -> PromptArgument {
+    PromptArgument {
+        name: name.into(),
+        title: None,
+        description: Some(description.into()),
+        required: Some(required),
+    }
+}
+
+pub fn list() -> Vec<Prompt> {
+    let board = || argument("board", "UUID or name of the board", true);
+    vec![
+        Prompt::new(
+            PLAN_NEXT_SPRINT,
+            Some("Propose the next sprint from the backlog, recent velocity and unfinished work"),
+            Some(vec![
+                board(),
+                argument(
+                    "capacity",
+                    "Story points the team can take on (defaults to recent velocity)",
+                    false,
+                ),
+            ]),
+        ),
+        Prompt::new(
+            TRIAGE_BACKLOG,
+            Some("Review cards outside any sprint and suggest priorities, estimates and cleanup"),
+            Some(vec![board()]),
+        ),
+        Prompt::new(
+            WRITE_STANDUP_SUMMARY,
+            Some("Summarise what was done, what is in progress and what is blocked"),
+            Some(vec![
+                board(),
+                argument(
+                    "since",
+                    "Start of the reporting window, YYYY-MM-DD or RFC 3339 (defaults to 24 hours ago)",
+                    false,
+                ),
+            ]),
+        ),
+    ]
+}
+
+pub fn get(
+    ctx: &McpContext,
+    name: &str,
+    arguments: Option<JsonObject>,
+) -> Result<GetPromptResult, McpError> {
+    let arguments = arguments.unwrap_or_default();
+    let text_arg = |key: &str| -> Option<String> {
+        arguments.get(key).and_then(|v| match v {
+            serde_json::Value::String(s) => Some(s.clone()),
+            serde_json::Value::Null => None,
+            other => Some(other.to_string()),
+        })
+    };
+    let board = text_arg("board")
+        .ok_or_else(|| McpError::invalid_params("Missing required argument 'board'", None))?;
+    let snapshot = BoardSnapshot::load(ctx, ctx.mcp_resolve_board(&board)?)?;
+
+    let (description, text) = match name {
+        PLAN_NEXT_SPRINT => {
+            let capacity = text_arg("capacity")
+                .map(|raw| {
+                    raw.trim().parse::<u32>().map_err(|_| {
+                        McpError::invalid_params(format!("Invalid capacity: '{}'", raw), None)
+                    })
+                })
+                .transpose()?;
+            (
+                "Plan the next sprint",
+                plan_next_sprint(ctx, &snapshot, capacity)?,
+            )
+        }
+        TRIAGE_BACKLOG => ("Triage the backlog", triage_backlog(ctx, &snapshot)?),
+        WRITE_STANDUP_SUMMARY => {
+            let since = match text_arg("since") {
+                Some(raw) => parse_datetime_input(&raw)
+                    .map_err(|e| McpError::invalid_params(format!("Invalid since: {}", e), None))?,
+                None => Utc::now() - Duration::hours(24),
+            };
+            (
+                "Write a standup summary",
+                write_standup_summary(ctx, &snapshot, since)?,
+            )
+        }
+        _ => {
+            return Err(McpError::invalid_params(
+                format!("Unknown prompt: '{}'", name),
+                None,
+            ))
+        }
+    };
+    Ok(GetPromptResult {
+        description: Some(format!("{} for {}", description, snapshot.board.name)),
+        messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+    })
+}
+
+pub(crate) fn priority_rank(priority: CardPriority) -> u8 {
+    match priority {
+        CardPriority::Critical => 0,
+        CardPriority::High => 1,
+        CardPriority::Medium => 2,
+        CardPriority::Low => 3,
+    }
+}
+
+/// Identifiers of the unfinished cards blocking `card`.
+fn open_blockers(
+    ctx: &McpContext,
+    snapshot: &BoardSnapshot,
+    card: &Card,
+) -> Result<Vec<String>, McpError> {
+    let blockers = ctx.list_blockers_of(card.id).map_err(kanban_err_to_mcp)?;
+    Ok(blockers
+        .iter()
+        .filter_map(|id| snapshot.card(*id))
+        .filter(|blocker| blocker.status != CardStatus::Done)
+        .map(|blocker| snapshot.identifier(blocker))
+        .collect())
+}
+
+pub(crate) fn points(cards: &[&Card]) -> u32 {
+    cards.iter().filter_map(|c| c.points).map(u32::from).sum()
+}
+
+fn plan_next_sprint(
+    ctx: &McpContext,
+    snapshot: &BoardSnapshot,
+    capacity: Option<u32>,
+) -> Result<String, McpError> {
+    let active = snapshot.active_sprint();
+    let mut completed: Vec<_> = snapshot
+        .sprints
+        .iter()
+        .filter(|s| s.status == SprintStatus::Completed)
+        .collect();
+    completed.sort_by_key(|s| std::cmp::Reverse(s.end_date));
+    let velocities: Vec<(String, u32)> = completed
+        .iter()
+        .take(3)
+        .map(|sprint| {
+            let done: Vec<&Card> = snapshot
+                .cards
+                .iter()
+                .filter(|c| c.sprint_id == Some(sprint.id) && c.status == CardStatus::Done)
+                .collect();
+            (snapshot.sprint_name(sprint), points(&done))
+        })
+        .collect();
+
+    let mut out = format!(
+        "Plan the next sprint for the board \"{}\". Pick cards from the backlog below, \
+         respecting blockers and priorities, and explain what you left out and why. \
+         Suggest splitting cards that are too large or unestimated.\n",
+        snapshot.board.name
+    );
+    match capacity {
+        Some(capacity) => {
+            let _ = writeln!(out, "\nCapacity: {} points.", capacity);
+        }
+        None if !velocities.is_empty() => {
+            let average = velocities.iter().map(|(_, p)| p).sum::<u32>() / velocities.len() as u32;
+            let _ = writeln!(
+                out,
+                "\nNo capacity was given; recent velocity averages {} points.",
+                average
+            );
+        }
+        None => {
+            let _ = writeln!(
+                out,
+                "\nNo capacity was given and no sprint has been completed yet."
+            );
+        }
+    }
+    if !velocities.is_empty() {
+        let _ = writeln!(out, "\n## Recent sprints\n");
+        for (name, done) in &velocities {
+            let _ = writeln!(out, "- {}: {} points done", name, done);
+        }
+    }
+
+    if let Some(sprint) = active {
+        let unfinished: Vec<&Card> = snapshot
+            .cards
+            .iter()
+            .filter(|c| c.sprint_id == Some(sprint.id) && c.status != CardStatus::Done)
+            .collect();
+        let _ = writeln!(
+            out,
+            "\n## Unfinished in {} ({} points)\n",
+            snapshot.sprint_name(sprint),
+            points(&unfinished)
+        );
+        if unfinished.is_empty() {
+            let _ = writeln!(out, "_Nothing left_");
+        }
+        for card in unfinished {
+            let _ = writeln!(out, "{}", snapshot.card_line(card));
+        }
+    }
+
+    let mut backlog: Vec<&Card> = snapshot
+        .cards
+        .iter()
+        .filter(|c| c.status != CardStatus::Done)
+        .filter(|c| active.is_none() || c.sprint_id != active.map(|s| s.id))
+        .collect();
+    backlog.sort_by_key(|c| priority_rank(c.priority));
+    let _ = writeln!(out, "\n## Backlog ({} points)\n", points(&backlog));
+    if backlog.is_empty() {
+        let _ = writeln!(out, "_Empty_");
+    }
+    for card in backlog {
+        let mut line = snapshot.card_line(card);
+        if card.points.is_none() {
+            line.push_str(" · unestimated");
+        }
+        let blockers = open_blockers(ctx, snapshot, card)?;
+        if !blockers.is_empty() {
+            let _ = write!(line, " · blocked by {}", blockers.join(", "));
+        }
+        let _ = writeln!(out, "{}", line);
+    }
+    Ok(out)
+}
+
+fn triage_backlog(ctx: &McpContext, snapshot: &BoardSnapshot) -> Result<String, McpError> {
+    let now = Utc::now();
+    let mut backlog: Vec<&Card> = snapshot
+        .cards
+        .iter()
+        .filter(|c| c.status != CardStatus::Done && c.sprint_id.is_none())
+        .collect();
+    backlog.sort_by_key(|c| (priority_rank(c.priority), c.created_at));
+
+    let mut out = format!(
+        "Triage the backlog of the board \"{}\": the cards below are unfinished and in no \
+         sprint. For each, suggest a priority and estimate where they look wrong or \
+         missing, flag duplicates and cards to split, and list cards that look stale \
+         enough to archive. Group your answer by recommended action.\n",
+        snapshot.board.name
+    );
+    let _ = writeln!(out, "\n## Backlog ({} cards)\n", backlog.len());
+    if backlog.is_empty() {
+        let _ = writeln!(out, "_Empty_");
+    }
+    for card in backlog {
+        let mut flags = Vec::new();
+        if card.points.is_none() {
+            flags.push("unestimated".to_string());
+        }
+        if card.description.as_deref().unwrap_or("").trim().is_empty() {
+            flags.push("no description".to_string());
+        }
+        if card.due_date.is_some_and(|due| due < now) {
+            flags.push("overdue".to_string());
+        }
+        if now - card.updated_at > Duration::days(STALE_AFTER_DAYS) {
+            flags.push(format!(
+                "untouched for {} days",
+                (now - card.updated_at).num_days()
+            ));
+        }
+        let blockers = open_blockers(ctx, snapshot, card)?;
+        if !blockers.is_empty() {
+            flags.push(format!("blocked by {}", blockers.join(", ")));
+        }
+        let mut line = format!(
+            "{} · {}",
+            snapshot.card_line(card),
+            snapshot.column_name(card)
+        );
+        if !flags.is_empty() {
+            let _ = write!(line, " · {}", flags.join(", "));
+        }
+        let _ = writeln!(out, "{}", line);
+        if let Some(description) = card.description.as_deref().filter(|d| !d.trim().is_empty()) {
+            let _ = writeln!(out, "  {}", truncate(&single_line(description), 200));
+        }
+    }
+    Ok(out)
+}
+
+fn write_standup_summary(
+    ctx: &McpContext,
+    snapshot: &BoardSnapshot,
+    since: chrono::DateTime<Utc>,
+) -> Result<String, McpError> {
+    let mut done = Vec::new();
+    let mut in_progress = Vec::new();
+    let mut blocked = Vec::new();
+    let mut other_changes = Vec::new();
+    for card in &snapshot.cards {
+        let blockers = open_blockers(ctx, snapshot, card)?;
+        if card.status == CardStatus::Done {
+            if card.completed_at.is_some_and(|at| at >= since) {
+                done.push(snapshot.card_line(card));
+            }
+        } else if card.status == CardStatus::Blocked || !blockers.is_empty() {
+            let mut line = snapshot.card_line(card);
+            if !blockers.is_empty() {
+                let _ = write!(line, " · blocked by {}", blockers.join(", "));
+            }
+            blocked.push(line);
+        } else if card.status == CardStatus::InProgress {
+            in_progress.push(snapshot.card_line(card));
+        } else if card.updated_at >= since {
+            other_changes.push(format!(
+                "{} · {}",
+                snapshot.card_line(card),
+                snapshot.column_name(card)
+            ));
+        }
+    }
+
+    let mut out = format!(
+        "Write a short standup summary for the board \"{}\" covering {} until now: what was \
+         finished, what is in progress, and what is blocked and on whom. Keep it to a few \
+         bullet points per section and call out risks.\n",
+        snapshot.board.name,
+        since.format("%Y-%m-%d %H:%M UTC")
+    );
+    for (heading, lines) in [
+        ("Done", done),
+        ("In progress", in_progress),
+        ("Blocked", blocked),
+        ("Other changes", other_changes),
+    ] {
+        let _ = writeln!(out, "\n## {}\n", heading);
+        if lines.is_empty() {
+            let _ = writeln!(out, "_None_");
+        }
+        for line in lines {
+            let _ = writeln!(out, "{}", line);
+        }
+    }
+    Ok(out)
+}
+
+pub(crate) fn truncate(text: &str, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((end, _)) => format!("{}…", &text[..end]),
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_counts_characters() {
+        assert_eq!(truncate("héllo", 10), "héllo");
+        assert_eq!(truncate("héllo", 2), "hé…");
+    }
+
+    #[test]
+    fn test_prompts_require_a_board() {
+        assert!(list()
+            .iter()
+            .all(|p| p.arguments.as_ref().is_some_and(|args| args
+                .iter()
+                .any(|a| a.name == "board" && a.required == Some(true)))));
+    }
+}
@@ -0,0 +1,564 @@
+//! MCP resources: read-only renderings of boards, cards and the current
+//! sprint that clients can attach as context.
+//!
+//! URIs take a board or card reference the same way the tools do (UUID,
+//! name, or identifier like `KAN-42`, percent-encoded where needed) and
+//! render Markdown unless `?format=json` is appended.
+
+use crate::context::McpContext;
+use crate::{kanban_err_to_mcp, McpResolve};
+use kanban_domain::{
+    Board, BoardExportFormat, Card, CardStatus, CardSummary, Column, GraphOperations,
+    KanbanOperations, Sprint, SprintStatus,
+};
+use rmcp::model::{
+    AnnotateAble, ErrorData as McpError, RawResource, RawResourceTemplate, ReadResourceResult,
+    Resource, ResourceContents, ResourceTemplate,
+};
+use serde_json::json;
+use std::fmt::Write;
+use uuid::Uuid;
+
+pub const SCHEME: &str = "kanban://";
+pub const CURRENT_SPRINT_URI: &str = "kanban://sprint/current";
+
+const MARKDOWN: &str = "text/markdown";
+const JSON: &str = "application/json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceFormat {
+    Markdown,
+    Json,
+}
+
+impl ResourceFormat {
+    fn mime_type(self) -> &'static str {
+        match self {
+            Self::Markdown => MARKDOWN,
+            Self::Json => JSON,
+        }
+    }
+}
+
+/// A parsed `kanban://` URI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KanbanResource {
+    Board(String),
+    Card(String),
+    /// The active sprint of one board, or of every board when `None`.
+    CurrentSprint(Option<String>),
+}
+
+impl KanbanResource {
+    pub fn parse(uri: &str) -> Result<(Self, ResourceFormat), McpError> {
+        let unknown = || McpError::resource_not_found(format!("Unknown resource: {}", uri), None);
+        let rest = uri.strip_prefix(SCHEME).ok_or_else(unknown)?;
+        let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let mut format = ResourceFormat::Markdown;
+        for pair in query.split('&').filter(|p| !p.is_empty()) {
+            match pair.split_once('=') {
+                Some(("format", "json")) => format = ResourceFormat::Json,
+                Some(("format", "markdown" | "md")) => format = ResourceFormat::Markdown,
+                _ => {
+                    return Err(McpError::invalid_params(
+                        format!("Unsupported query '{}' in {}", pair, uri),
+                        None,
+                    ))
+                }
+            }
+        }
+        let segments: Vec<String> = path.split('/').map(percent_decode).collect();
+        let resource = match segments.as_slice() {
+            [kind, board] if kind == "board" && !board.is_empty() => Self::Board(board.clone()),
+            [kind, card] if kind == "card" && !card.is_empty() => Self::Card(card.clone()),
+            [kind, current] if kind == "sprint" && current == "current" => {
+                Self::CurrentSprint(None)
+            }
+            [kind, board, sprint, current]
+                if kind == "board" && sprint == "sprint" && current == "current" =>
+            {
+                Self::CurrentSprint(Some(board.clone()))
+            }
+            _ => return Err(unknown()),
+        };
+        Ok((resource, format))
+    }
+}
+
+/// Decode `%XX` escapes; anything malformed is kept as written.
+fn percent_decode(raw: &str) -> String {
+    let bytes = raw.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| raw.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                out.push(byte);
+                i += 3;
+            }
+            None => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// One resource per board plus the cross-board current sprint.
+pub fn list(ctx: &McpContext) -> Result<Vec<Resource>, McpError> {
+    let mut resources: Vec<Resource> = ctx
+        .list_boards()
+        .map_err(kanban_err_to_mcp)?
+        .into_iter()
+        .map(|board| {
+            let mut resource =
+                RawResource::new(format!("{}board/{}", SCHEME, board.id), &board.name);
+            resource.description = Some(format!("Columns and cards of {}", board.name));
+            resource.mime_type = Some(MARKDOWN.into());
+            resource.no_annotation()
+        })
+        .collect();
+    let mut sprint = RawResource::new(CURRENT_SPRINT_URI, "Current sprint");
+    sprint.description = Some("Active sprint of every board with its cards and points".into());
+    sprint.mime_type = Some(MARKDOWN.into());
+    resources.push(sprint.no_annotation());
+    Ok(resources)
+}
+
+pub fn templates() -> Vec<ResourceTemplate> {
+    let template = |uri: &str, name: &str, description: &str| {
+        RawResourceTemplate {
+            uri_template: uri.into(),
+            name: name.into(),
+            title: None,
+            description: Some(format!("{} Append ?format=json for JSON.", description)),
+            mime_type: Some(MARKDOWN.into()),
+        }
+        .no_annotation()
+    };
+    vec![
+        template(
+            "kanban://board/{board}",
+            "Board",
+            "Columns and cards of a board, by UUID or name.",
+        ),
+        template(
+            "kanban://card/{card}",
+            "Card",
+            "A card with its description, checklist, relations and attachments, by UUID or identifier such as KAN-42.",
+        ),
+        template(
+            "kanban://board/{board}/sprint/current",
+            "Current sprint of a board",
+            "The active sprint of a board with its cards and points.",
+        ),
+    ]
+}
+
+pub fn read(ctx: &McpContext, uri: &str) -> Result<ReadResourceResult, McpError> {
+    let (resource, format) = KanbanResource::parse(uri)?;
+    let text = match resource {
+        KanbanResource::Board(raw) => {
+            let board_id = ctx.mcp_resolve_board(&raw)?;
+            let export_format = match format {
+                ResourceFormat::Markdown => BoardExportFormat::Markdown,
+                ResourceFormat::Json => BoardExportFormat::Json,
+            };
+            ctx.export_board_as(Some(board_id), export_format)
+                .map_err(kanban_err_to_mcp)?
+        }
+        KanbanResource::Card(raw) => {
+            let card_id = ctx.mcp_resolve_card(&raw)?;
+            render_card(ctx, card_id, format)?
+        }
+        KanbanResource::CurrentSprint(board) => {
+            let board_id = board.map(|raw| ctx.mcp_resolve_board(&raw)).transpose()?;
+            render_current_sprints(ctx, board_id, format)?
+        }
+    };
+    Ok(ReadResourceResult {
+        contents: vec![ResourceContents::TextResourceContents {
+            uri: uri.into(),
+            mime_type: Some(format.mime_type().into()),
+            text,
+            meta: None,
+        }],
+    })
+}
+
+/// Everything needed to name and group the cards of one board.
+pub(crate) struct BoardSnapshot {
+    pub board: Board,
+    pub columns: Vec<Column>,
+    pub cards: Vec<Card>,
+    pub sprints: Vec<Sprint>,
+    card_prefix: String,
+    sprint_prefix: String,
+}
+
+impl BoardSnapshot {
+    pub fn load(ctx: &McpContext, board_id: Uuid) -> Result<Self, McpError> {
+        let board = ctx
+            .get_board(board_id)
+            .map_err(kanban_err_to_mcp)?
+            .ok_or_else(|| {
+                McpError::invalid_params(format!("Board not found: {}", board_id), None)
+            })?;
+        let mut columns = ctx.list_columns(board_id).map_err(kanban_err_to_mcp)?;
+        columns.sort_by_key(|c| c.position);
+        let mut cards: Vec<Card> = ctx
+            .list_all_cards()
+            .map_err(kanban_err_to_mcp)?
+            .into_iter()
+            .filter(|card| columns.iter().any(|c| c.id == card.column_id))
+            .collect();
+        cards.sort_by_key(|c| (column_position(&columns, c), c.position));
+        let sprints = ctx.list_sprints(board_id).map_err(kanban_err_to_mcp)?;
+        let config = ctx.app_config();
+        Ok(Self {
+            board,
+            columns,
+            cards,
+            sprints,
+            card_prefix: config.effective_default_card_prefix().to_string(),
+            sprint_prefix: config.effective_default_sprint_prefix().to_string(),
+        })
+    }
+
+    pub fn identifier(&self, card: &Card) -> String {
+        card.identifier(&self.board, &self.sprints, &self.card_prefix)
+    }
+
+    pub fn sprint_name(&self, sprint: &Sprint) -> String {
+        sprint.formatted_name(&self.board, &self.sprint_prefix)
+    }
+
+    pub fn column_name(&self, card: &Card) -> &str {
+        self.columns
+            .iter()
+            .find(|c| c.id == card.column_id)
+            .map_or("", |c| c.name.as_str())
+    }
+
+    pub fn card(&self, id: Uuid) -> Option<&Card> {
+        self.cards.iter().find(|c| c.id == id)
+    }
+
+    /// The board's chosen active sprint, else its first sprint with
+    /// active status.
+    pub fn active_sprint(&self) -> Option<&Sprint> {
+        let active = |s: &&Sprint| s.status == SprintStatus::Active;
+        self.board
+            .active_sprint_id
+            .and_then(|id| self.sprints.iter().filter(active).find(|s| s.id == id))
+            .or_else(|| self.sprints.iter().find(active))
+    }
+
+    /// Identifiers of `ids` that are cards on this board.
+    pub fn identifiers(&self, ids: &[Uuid]) -> Vec<String> {
+        ids.iter()
+            .filter_map(|id| self.card(*id))
+            .map(|card| self.identifier(card))
+            .collect()
+    }
+
+    /// One Markdown list item: checkbox, identifier, title, then status,
+    /// priority and points.
+    pub fn card_line(&self, card: &Card) -> String {
+        let mut details = vec![card.status.to_string(), card.priority.to_string()];
+        if let Some(points) = card.points {
+            details.push(format!("{} pts", points));
+        }
+        format!(
+            "- [{}] **{}** {} · {}",
+            if card.status == CardStatus::Done {
+                "x"
+            } else {
+                " "
+            },
+            self.identifier(card),
+            single_line(&card.title),
+            details.join(" · ")
+        )
+    }
+}
+
+fn column_position(columns: &[Column], card: &Card) -> i32 {
+    columns
+        .iter()
+        .find(|c| c.id == card.column_id)
+        .map_or(i32::MAX, |c| c.position)
+}
+
+pub(crate) fn single_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn board_of_card(ctx: &McpContext, card: &Card) -> Result<Uuid, McpError> {
+    ctx.get_column(card.column_id)
+        .map_err(kanban_err_to_mcp)?
+        .map(|c| c.board_id)
+        .ok_or_else(|| {
+            McpError::invalid_params(format!("Column not found: {}", card.column_id), None)
+        })
+}
+
+struct Relations {
+    parents: Vec<String>,
+    children: Vec<String>,
+    blocked_by: Vec<String>,
+    blocks: Vec<String>,
+    related: Vec<String>,
+}
+
+impl Relations {
+    fn of(ctx: &McpContext, snapshot: &BoardSnapshot, card_id: Uuid) -> Result<Self, McpError> {
+        let ids = |ids: Vec<Uuid>| snapshot.identifiers(&ids);
+        Ok(Self {
+            parents: ids(ctx.list_parents_of(card_id).map_err(kanban_err_to_mcp)?),
+            children: ids(ctx.list_children_of(card_id).map_err(kanban_err_to_mcp)?),
+            blocked_by: ids(ctx.list_blockers_of(card_id).map_err(kanban_err_to_mcp)?),
+            blocks: ids(ctx.list_blocked_by(card_id).map_err(kanban_err_to_mcp)?),
+            related: ids(ctx.list_related_to(card_id).map_err(kanban_err_to_mcp)?),
+        })
+    }
+
+    fn labelled(&self) -> [(&'static str, &[String]); 5] {
+        [
+            ("Parents", &self.parents),
+            ("Children", &self.children),
+            ("Blocked by", &self.blocked_by),
+            ("Blocks", &self.blocks),
+            ("Related", &self.related),
+        ]
+    }
+}
+
+fn render_card(
+    ctx: &McpContext,
+    card_id: Uuid,
+    format: ResourceFormat,
+) -> Result<String, McpError> {
+    let card = ctx
+        .get_card(card_id)
+        .map_err(kanban_err_to_mcp)?
+        .ok_or_else(|| McpError::invalid_params(format!("Card not found: {}", card_id), None))?;
+    let snapshot = BoardSnapshot::load(ctx, board_of_card(ctx, &card)?)?;
+    let relations = Relations::of(ctx, &snapshot, card.id)?;
+    let sprint = card
+        .sprint_id
+        .and_then(|id| snapshot.sprints.iter().find(|s| s.id == id))
+        .map(|s| snapshot.sprint_name(s));
+
+    if format == ResourceFormat::Json {
+        let value = json!({
+            "identifier": snapshot.identifier(&card),
+            "board": snapshot.board.name,
+            "column": snapshot.column_name(&card),
+            "sprint": sprint,
+            "relations": {
+                "parents": relations.parents,
+                "children": relations.children,
+                "blocked_by": relations.blocked_by,
+                "blocks": relations.blocks,
+                "related": relations.related,
+            },
+            "card": card,
+        });
+        return serde_json::to_string_pretty(&value)
+            .map_err(|e| McpError::internal_error(format!("Serialization failed: {}", e), None));
+    }
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "# {} {}\n",
+        snapshot.identifier(&card),
+        single_line(&card.title)
+    );
+    let _ = writeln!(out, "- Board: {}", snapshot.board.name);
+    let _ = writeln!(out, "- Column: {}", snapshot.column_name(&card));
+    let _ = writeln!(out, "- Status: {}", card.status);
+    let _ = writeln!(out, "- Priority: {}", card.priority);
+    if let Some(points) = card.points {
+        let _ = writeln!(out, "- Points: {}", points);
+    }
+    if let Some(due) = card.due_date {
+        let _ = writeln!(out, "- Due: {}", due.format("%Y-%m-%d"));
+    }
+    if let Some(sprint) = sprint {
+        let _ = writeln!(out, "- Sprint: {}", sprint);
+    }
+    for (label, identifiers) in relations.labelled() {
+        if !identifiers.is_empty() {
+            let _ = writeln!(out, "- {}: {}", label, identifiers.join(", "));
+        }
+    }
+    if let Some(description) = card.description.as_deref().filter(|d| !d.trim().is_empty()) {
+        let _ = writeln!(out, "\n## Description\n\n{}", description.trim_end());
+    }
+    if !card.checklist.is_empty() {
+        let _ = writeln!(out, "\n## Checklist\n");
+        for item in &card.checklist {
+            let _ = writeln!(
+                out,
+                "- [{}] {}",
+                if item.done { "x" } else { " " },
+                single_line(&item.text)
+            );
+        }
+    }
+    if !card.attachments.is_empty() {
+        let _ = writeln!(out, "\n## Attachments\n");
+        for attachment in &card.attachments {
+            let _ = writeln!(
+                out,
+                "- {} ({}, {})",
+                attachment.name,
+                attachment.mime_type,
+                attachment.display_size()
+            );
+        }
+    }
+    Ok(out)
+}
+
+fn render_current_sprints(
+    ctx: &McpContext,
+    board_id: Option<Uuid>,
+    format: ResourceFormat,
+) -> Result<String, McpError> {
+    let board_ids = match board_id {
+        Some(id) => vec![id],
+        None => ctx
+            .list_boards()
+            .map_err(kanban_err_to_mcp)?
+            .into_iter()
+            .map(|b| b.id)
+            .collect(),
+    };
+    let mut markdown = String::new();
+    let mut sprints = Vec::new();
+    for board_id in board_ids {
+        let snapshot = BoardSnapshot::load(ctx, board_id)?;
+        let Some(sprint) = snapshot.active_sprint() else {
+            continue;
+        };
+        let cards: Vec<&Card> = snapshot
+            .cards
+            .iter()
+            .filter(|c| c.sprint_id == Some(sprint.id))
+            .collect();
+        let total: u32 = cards.iter().filter_map(|c| c.points).map(u32::from).sum();
+        let done: u32 = cards
+            .iter()
+            .filter(|c| c.status == CardStatus::Done)
+            .filter_map(|c| c.points)
+            .map(u32::from)
+            .sum();
+
+        if format == ResourceFormat::Json {
+            sprints.push(json!({
+                "board": snapshot.board.name,
+                "sprint": snapshot.sprint_name(sprint),
+                "start_date": sprint.start_date,
+                "end_date": sprint.end_date,
+                "points_total": total,
+                "points_done": done,
+                "cards": cards.iter().map(|c| {
+                    let mut summary = serde_json::to_value(CardSummary::from(*c)).unwrap_or_default();
+                    summary["identifier"] = json!(snapshot.identifier(c));
+                    summary
+                }).collect::<Vec<_>>(),
+            }));
+            continue;
+        }
+
+        if !markdown.is_empty() {
+            markdown.push('\n');
+        }
+        let _ = writeln!(
+            markdown,
+            "# {} — {}\n",
+            snapshot.board.name,
+            snapshot.sprint_name(sprint)
+        );
+        let mut progress = format!("{} of {} points done", done, total);
+        if let (Some(start), Some(end)) = (sprint.start_date, sprint.end_date) {
+            progress = format!(
+                "{} to {} · {}",
+                start.format("%Y-%m-%d"),
+                end.format("%Y-%m-%d"),
+                progress
+            );
+        }
+        let _ = writeln!(markdown, "{}\n", progress);
+        if cards.is_empty() {
+            let _ = writeln!(markdown, "_No cards_");
+        }
+        for card in cards {
+            let _ = writeln!(
+                markdown,
+                "{} · {}",
+                snapshot.card_line(card),
+                snapshot.column_name(card)
+            );
+        }
+    }
+
+    if format == ResourceFormat::Json {
+        return serde_json::to_string_pretty(&sprints)
+            .map_err(|e| McpError::internal_error(format!("Serialization failed: {}", e), None));
+    }
+    if markdown.is_empty() {
+        markdown.push_str("_No active sprint_\n");
+    }
+    Ok(markdown)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_resource_uris() {
+        let parse = |uri| KanbanResource::parse(uri).unwrap();
+        assert_eq!(
+            parse("kanban://card/KAN-42"),
+            (
+                KanbanResource::Card("KAN-42".into()),
+                ResourceFormat::Markdown
+            )
+        );
+        assert_eq!(
+            parse("kanban://board/My%20Board?format=json"),
+            (
+                KanbanResource::Board("My Board".into()),
+                ResourceFormat::Json
+            )
+        );
+        assert_eq!(
+            parse("kanban://sprint/current").0,
+            KanbanResource::CurrentSprint(None)
+        );
+        assert_eq!(
+            parse("kanban://board/Ops/sprint/current").0,
+            KanbanResource::CurrentSprint(Some("Ops".into()))
+        );
+        assert!(KanbanResource::parse("kanban://sprint/next").is_err());
+        assert!(KanbanResource::parse("file:///etc/passwd").is_err());
+        assert!(KanbanResource::parse("kanban://card/KAN-1?page=2").is_err());
+    }
+
+    #[test]
+    fn test_percent_decode_keeps_malformed_escapes() {
+        assert_eq!(percent_decode("a%2Fb"), "a/b");
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz"), "%zz");
+    }
+}
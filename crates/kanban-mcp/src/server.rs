@@ -3,21 +3,39 @@
 //! Mirrors `kanban_cli::CliApp` in spirit: third-party backend crates
 //! construct an `McpServer`, register their own `StoreFactory`, and call
 //! `run` from their own `main`.
+//!
+//! `run` serves a single client over stdio unless [`McpServer::with_listen`]
+//! selects streamable HTTP, where every client session shares one backend.
+//! HTTP listens on loopback only unless remote access is enabled together
+//! with a bearer token.
 
+use crate::context::McpContext;
 use crate::KanbanMcpServer;
 use anyhow::{Context, Result};
+use axum::extract::{Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
 use kanban_core::AppConfig;
 use kanban_persistence::{StoreFactory, StoreRegistry};
 use kanban_service::{validate_path, StoreManager};
 use rmcp::transport::stdio;
+use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
+use rmcp::transport::streamable_http_server::{StreamableHttpServerConfig, StreamableHttpService};
 use rmcp::ServiceExt;
+use std::net::SocketAddr;
 use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::net::TcpListener;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
 pub struct McpServer {
     registry: StoreRegistry,
     config: Option<AppConfig>,
     data_file: Option<String>,
+    listen: Option<SocketAddr>,
+    token: Option<String>,
+    allow_remote: bool,
 }
 
 impl Default for McpServer {
@@ -28,6 +46,9 @@ impl Default for McpServer {
             registry: StoreRegistry::new(),
             config: None,
             data_file: None,
+            listen: None,
+            token: None,
+            allow_remote: false,
         }
     }
 }
@@ -42,6 +63,9 @@ impl McpServer {
             registry: kanban_service::default_registry(),
             config: None,
             data_file: None,
+            listen: None,
+            token: None,
+            allow_remote: false,
         }
     }
 
@@ -96,6 +120,28 @@ impl McpServer {
         self
     }
 
+    /// Serve streamable HTTP on `addr` instead of stdio. The endpoint is
+    /// `/mcp`. Only loopback addresses are accepted unless
+    /// [`allow_remote`][Self::allow_remote] is set.
+    pub fn with_listen(mut self, addr: SocketAddr) -> Self {
+        self.listen = Some(addr);
+        self
+    }
+
+    /// Require `Authorization: Bearer <token>` on every HTTP request.
+    pub fn with_bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Accept a non-loopback listen address. Serving then also needs a
+    /// bearer token, so the board is never open to the network
+    /// unauthenticated.
+    pub fn allow_remote(mut self) -> Self {
+        self.allow_remote = true;
+        self
+    }
+
     /// Exposes the underlying registry for inspection and tests.
     pub fn registry(&self) -> &StoreRegistry {
         &self.registry
@@ -103,6 +149,10 @@ impl McpServer {
 
     /// Consumes this builder and returns a ready-to-serve `KanbanMcpServer`.
     pub async fn build(self) -> Result<KanbanMcpServer> {
+        Ok(KanbanMcpServer::from_context(self.build_context().await?))
+    }
+
+    async fn build_context(self) -> Result<McpContext> {
         let config = self.config.unwrap_or_else(kanban_service::config::load);
         let store_manager = StoreManager::new(self.registry);
         if !store_manager.has_backends() {
@@ -117,13 +167,68 @@ impl McpServer {
         };
         let validated = validate_path(&data_file_path)?;
         let data_file = validated.to_string_lossy().to_string();
-        KanbanMcpServer::new(&store_manager, &data_file, config)
+        let mut ctx = McpContext::new(&store_manager, &data_file, config)
             .await
-            .context("Failed to initialize KanbanMcpServer")
+            .context("Failed to initialize KanbanMcpServer")?;
+        if validated.exists() {
+            ctx.watch_data_file(validated).await;
+        }
+        Ok(ctx)
+    }
+
+    /// Serves streamable HTTP at `/mcp` on an already bound listener until
+    /// the process is interrupted. Each client session gets its own undo
+    /// history over the shared backend; writes from all sessions are
+    /// serialised.
+    ///
+    /// Clients are remote callers, so tools cannot read or write files on
+    /// the server by path; attachment content travels inline as base64.
+    pub async fn serve_http(self, listener: TcpListener) -> Result<()> {
+        let addr = listener.local_addr()?;
+        if !addr.ip().is_loopback() {
+            if !self.allow_remote {
+                anyhow::bail!(
+                    "Refusing to serve on non-loopback address {}; pass --allow-remote \
+                     together with --token to expose the board to the network",
+                    addr
+                );
+            }
+            if self.token.is_none() {
+                anyhow::bail!(
+                    "Serving on non-loopback address {} needs a bearer token (--token)",
+                    addr
+                );
+            }
+        }
+        let token = self.token.clone();
+        let seed = std::sync::Mutex::new(self.build_context().await?);
+        let service = StreamableHttpService::new(
+            move || {
+                let seed = seed
+                    .lock()
+                    .map_err(|_| std::io::Error::other("MCP session seed poisoned"))?;
+                Ok(KanbanMcpServer::from_context(seed.new_session()).without_local_files())
+            },
+            LocalSessionManager::default().into(),
+            StreamableHttpServerConfig::default(),
+        );
+        let mut router = axum::Router::new().nest_service("/mcp", service);
+        if let Some(token) = token {
+            router = router.layer(axum::middleware::from_fn_with_state(
+                Arc::<str>::from(token),
+                require_bearer_token,
+            ));
+        }
+        axum::serve(listener, router)
+            .with_graceful_shutdown(async {
+                tokio::signal::ctrl_c().await.ok();
+            })
+            .await?;
+        Ok(())
     }
 
     /// Initializes tracing, constructs the server, and serves it over stdio
-    /// until the transport closes.
+    /// until the transport closes, or over HTTP when a listen address is set.
     pub async fn run(self) -> Result<()> {
         tracing_subscriber::registry()
             // "info" default: MCP server runs headlessly; startup/lifecycle events aid operators.
@@ -132,6 +237,14 @@ impl McpServer {
             .try_init()
             .ok();
 
+        if let Some(addr) = self.listen {
+            let listener = TcpListener::bind(addr)
+                .await
+                .with_context(|| format!("Failed to listen on {}", addr))?;
+            tracing::info!("Serving Kanban MCP over HTTP at http://{}/mcp", addr);
+            return self.serve_http(listener).await;
+        }
+
         let server = self.build().await?;
         tracing::info!("Starting Kanban MCP server");
         let service = server.serve(stdio()).await?;
@@ -140,3 +253,28 @@ impl McpServer {
         Ok(())
     }
 }
+
+/// Reject requests whose `Authorization` header does not carry `token`.
+async fn require_bearer_token(
+    State(token): State<Arc<str>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let given = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    match given {
+        Some(given) if constant_time_eq(given.as_bytes(), token.as_bytes()) => {
+            next.run(request).await
+        }
+        _ => StatusCode::UNAUTHORIZED.into_response(),
+    }
+}
+
+/// Compare without returning early, so response timing does not reveal how
+/// much of a guessed token was right.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
@@ -0,0 +1,365 @@
+//! Streamable HTTP transport: several MCP clients against one in-process
+//! server on localhost.
+
+use kanban_core::AppConfig;
+use kanban_mcp::McpServer;
+use rmcp::model::{CallToolRequestParam, CallToolResult};
+use rmcp::service::{RoleClient, RunningService};
+use rmcp::transport::streamable_http_client::StreamableHttpClientTransportConfig;
+use rmcp::transport::StreamableHttpClientTransport;
+use rmcp::ServiceExt;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tokio::net::TcpListener;
+
+type Client = RunningService<RoleClient, ()>;
+
+fn server_in(tmp: &TempDir) -> McpServer {
+    let path = tmp.path().join("boards.json").to_string_lossy().to_string();
+    McpServer::with_defaults()
+        .with_config(AppConfig::default())
+        .with_data_file(path)
+}
+
+async fn start_server() -> (String, TempDir) {
+    let tmp = tempfile::tempdir().unwrap();
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/mcp", listener.local_addr().unwrap());
+    tokio::spawn(server_in(&tmp).serve_http(listener));
+    (url, tmp)
+}
+
+async fn connect(url: &str) -> Client {
+    ().serve(StreamableHttpClientTransport::from_uri(url.to_string()))
+        .await
+        .expect("client connects")
+}
+
+async fn try_call(
+    client: &Client,
+    tool: &'static str,
+    args: Value,
+) -> Result<CallToolResult, String> {
+    let arguments = match args {
+        Value::Object(map) => Some(map),
+        _ => None,
+    };
+    client
+        .call_tool(CallToolRequestParam {
+            name: tool.into(),
+            arguments,
+        })
+        .await
+        .map_err(|e| e.to_string())
+}
+
+async fn call(client: &Client, tool: &'static str, args: Value) -> CallToolResult {
+    try_call(client, tool, args)
+        .await
+        .unwrap_or_else(|e| panic!("{tool} failed: {e}"))
+}
+
+fn text(result: &CallToolResult) -> String {
+    result.content[0]
+        .as_text()
+        .expect("expected text content")
+        .text
+        .clone()
+}
+
+async fn card_titles(client: &Client) -> Vec<String> {
+    let listing: Value =
+        serde_json::from_str(&text(&call(client, "tool_list_cards", json!({})).await)).unwrap();
+    let mut titles: Vec<String> = listing["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["title"].as_str().unwrap().to_string())
+        .collect();
+    titles.sort();
+    titles
+}
+
+async fn board_with_column(client: &Client) {
+    call(client, "tool_create_board", json!({"name": "Ops"})).await;
+    call(
+        client,
+        "tool_create_column",
+        json!({"board": "Ops", "name": "Todo"}),
+    )
+    .await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn clients_share_state_but_undo_only_their_own_changes() {
+    let (url, _tmp) = start_server().await;
+    let alice = connect(&url).await;
+    let bob = connect(&url).await;
+    board_with_column(&alice).await;
+
+    let card = |title: &str| json!({"board": "Ops", "column": "Todo", "title": title});
+    call(&alice, "tool_create_card", card("From Alice")).await;
+    call(&bob, "tool_create_card", card("From Bob")).await;
+    assert_eq!(card_titles(&bob).await, ["From Alice", "From Bob"]);
+
+    let undo = call(&alice, "tool_undo", json!({})).await;
+    assert_eq!(text(&undo), "Undo successful");
+    assert_eq!(card_titles(&alice).await, ["From Bob"]);
+    assert_eq!(card_titles(&bob).await, ["From Bob"]);
+
+    let undo = try_call(&alice, "tool_undo", json!({})).await;
+    assert!(undo.is_err(), "Alice's column still holds Bob's card");
+    assert_eq!(card_titles(&bob).await, ["From Bob"]);
+
+    alice.cancel().await.unwrap();
+    bob.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn undo_history_survives_other_clients_writes() {
+    let (url, _tmp) = start_server().await;
+    let alice = connect(&url).await;
+    let bob = connect(&url).await;
+    board_with_column(&alice).await;
+
+    let card = |title: &str| json!({"board": "Ops", "column": "Todo", "title": title});
+    call(&alice, "tool_create_card", card("A1")).await;
+    call(&bob, "tool_create_card", card("B1")).await;
+    call(&alice, "tool_create_card", card("A2")).await;
+    call(&bob, "tool_create_card", card("B2")).await;
+
+    for (client, expected) in [
+        (&alice, vec!["A1", "B1", "B2"]),
+        (&alice, vec!["B1", "B2"]),
+        (&bob, vec!["B1"]),
+        (&bob, vec![]),
+    ] {
+        let undo = call(client, "tool_undo", json!({})).await;
+        assert_eq!(text(&undo), "Undo successful");
+        assert_eq!(card_titles(&alice).await, expected);
+    }
+
+    let redo = call(&bob, "tool_redo", json!({})).await;
+    assert_eq!(text(&redo), "Redo successful");
+    assert_eq!(card_titles(&alice).await, ["B1"]);
+
+    alice.cancel().await.unwrap();
+    bob.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn undo_refuses_to_overwrite_another_clients_change() {
+    let (url, _tmp) = start_server().await;
+    let alice = connect(&url).await;
+    let bob = connect(&url).await;
+    board_with_column(&alice).await;
+
+    let created = call(
+        &alice,
+        "tool_create_card",
+        json!({"board": "Ops", "column": "Todo", "title": "Draft"}),
+    )
+    .await;
+    let id: Value = serde_json::from_str(&text(&created)).unwrap();
+    let id = id["id"].as_str().unwrap();
+    call(
+        &alice,
+        "tool_update_card",
+        json!({"card": id, "title": "Alice's title"}),
+    )
+    .await;
+    call(
+        &bob,
+        "tool_update_card",
+        json!({"card": id, "title": "Bob's title"}),
+    )
+    .await;
+
+    let undo = try_call(&alice, "tool_undo", json!({})).await;
+    let error = undo.expect_err("Bob's title would be overwritten");
+    assert!(error.contains("another client"), "{error}");
+    assert_eq!(card_titles(&alice).await, ["Bob's title"]);
+
+    let undo = call(&bob, "tool_undo", json!({})).await;
+    assert_eq!(text(&undo), "Undo successful");
+    let undo = call(&alice, "tool_undo", json!({})).await;
+    assert_eq!(text(&undo), "Undo successful");
+    assert_eq!(card_titles(&alice).await, ["Draft"]);
+
+    alice.cancel().await.unwrap();
+    bob.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn concurrent_writes_from_several_clients_are_all_kept() {
+    let (url, _tmp) = start_server().await;
+    let first = connect(&url).await;
+    board_with_column(&first).await;
+    let mut clients = vec![first];
+    for _ in 0..3 {
+        clients.push(connect(&url).await);
+    }
+
+    let mut writes = tokio::task::JoinSet::new();
+    for (c, client) in clients.iter().enumerate() {
+        for n in 0..5 {
+            let peer = client.peer().clone();
+            writes.spawn(async move {
+                peer.call_tool(CallToolRequestParam {
+                    name: "tool_create_card".into(),
+                    arguments:
+                        json!({"board": "Ops", "column": "Todo", "title": format!("c{c}-{n}")})
+                            .as_object()
+                            .cloned(),
+                })
+                .await
+            });
+        }
+    }
+    while let Some(result) = writes.join_next().await {
+        assert!(result.unwrap().is_ok());
+    }
+
+    assert_eq!(card_titles(&clients[0]).await.len(), 20);
+    for client in clients {
+        client.cancel().await.unwrap();
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn garbage_collection_spares_content_another_client_can_restore() {
+    let (url, _tmp) = start_server().await;
+    let alice = connect(&url).await;
+    let bob = connect(&url).await;
+    board_with_column(&alice).await;
+
+    let created = call(
+        &alice,
+        "tool_create_card",
+        json!({"board": "Ops", "column": "Todo", "title": "Crash"}),
+    )
+    .await;
+    let card: Value = serde_json::from_str(&text(&created)).unwrap();
+    let card = card["id"].as_str().unwrap();
+    call(
+        &alice,
+        "tool_add_card_attachment",
+        json!({"card": card, "name": "crash.log", "content_base64": "cGFuaWM="}),
+    )
+    .await;
+    call(&alice, "tool_delete_card", json!({"card": card})).await;
+
+    let report = call(&bob, "tool_collect_attachment_garbage", json!({})).await;
+    let report: Value = serde_json::from_str(&text(&report)).unwrap();
+    assert_eq!(
+        report["removed"],
+        json!([]),
+        "Alice can still undo the delete"
+    );
+
+    call(&alice, "tool_undo", json!({})).await;
+    let read = call(
+        &alice,
+        "tool_get_card_attachment",
+        json!({"card": card, "attachment": "crash.log"}),
+    )
+    .await;
+    let read: Value = serde_json::from_str(&text(&read)).unwrap();
+    assert_eq!(read["text"], "panic");
+
+    alice.cancel().await.unwrap();
+    bob.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn non_loopback_addresses_need_opt_in_and_a_token() {
+    let tmp = tempfile::tempdir().unwrap();
+    let listener = TcpListener::bind("0.0.0.0:0").await.unwrap();
+    let error = server_in(&tmp).serve_http(listener).await.unwrap_err();
+    assert!(error.to_string().contains("--allow-remote"), "{error}");
+
+    let listener = TcpListener::bind("0.0.0.0:0").await.unwrap();
+    let error = server_in(&tmp)
+        .allow_remote()
+        .serve_http(listener)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("--token"), "{error}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bearer_token_is_required_when_set() {
+    let tmp = tempfile::tempdir().unwrap();
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/mcp", listener.local_addr().unwrap());
+    tokio::spawn(
+        server_in(&tmp)
+            .with_bearer_token("s3cret")
+            .serve_http(listener),
+    );
+
+    let anonymous = ().serve(StreamableHttpClientTransport::from_uri(url.clone())).await;
+    assert!(
+        anonymous.is_err(),
+        "a client without the token is turned away"
+    );
+    let wrong = ()
+        .serve(StreamableHttpClientTransport::from_config(
+            StreamableHttpClientTransportConfig::with_uri(url.clone()).auth_header("guess"),
+        ))
+        .await;
+    assert!(wrong.is_err(), "a wrong token is turned away");
+
+    let client = ()
+        .serve(StreamableHttpClientTransport::from_config(
+            StreamableHttpClientTransportConfig::with_uri(url).auth_header("s3cret"),
+        ))
+        .await
+        .expect("client with the token connects");
+    assert!(card_titles(&client).await.is_empty());
+    client.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn attachment_tools_do_not_touch_server_files_over_http() {
+    let (url, tmp) = start_server().await;
+    let client = connect(&url).await;
+    board_with_column(&client).await;
+    let secret = tmp.path().join("secret.txt");
+    std::fs::write(&secret, "top secret").unwrap();
+    let created = call(
+        &client,
+        "tool_create_card",
+        json!({"board": "Ops", "column": "Todo", "title": "Crash"}),
+    )
+    .await;
+    let card: Value = serde_json::from_str(&text(&created)).unwrap();
+    let card = card["id"].as_str().unwrap();
+
+    let error = try_call(
+        &client,
+        "tool_add_card_attachment",
+        json!({"card": card, "path": secret.to_string_lossy()}),
+    )
+    .await
+    .unwrap_err();
+    assert!(error.contains("not available over HTTP"), "{error}");
+
+    call(
+        &client,
+        "tool_add_card_attachment",
+        json!({"card": card, "name": "crash.log", "content_base64": "cGFuaWM="}),
+    )
+    .await;
+    let error = try_call(
+        &client,
+        "tool_get_card_attachment",
+        json!({"card": card, "attachment": "1", "output_path": secret.to_string_lossy()}),
+    )
+    .await
+    .unwrap_err();
+    assert!(error.contains("not available over HTTP"), "{error}");
+    assert_eq!(std::fs::read_to_string(&secret).unwrap(), "top secret");
+
+    client.cancel().await.unwrap();
+}
@@ -619,18 +619,20 @@ async fn test_mcp_undo_on_empty_returns_false() {
 }
 
 #[tokio::test]
-async fn test_mcp_reload_resets_undo_history() {
-    // reload() semantics: "pick up external changes". The previous undo history
-    // was computed against a different file state and is no longer valid.
+async fn test_mcp_reload_keeps_undo_history() {
+    // reload() picks up external changes but keeps the session's history;
+    // undo checks the step still applies before running it.
     let (mut ctx, _tmp) = setup().await;
     ctx.create_board("Board".into(), None).unwrap();
     assert!(ctx.can_undo(), "should have undo entry after create");
     ctx.save().await.unwrap();
     ctx.reload().await.unwrap();
     assert!(
-        !ctx.can_undo(),
-        "reload must reset undo history — cursor is invalid after external change"
+        ctx.can_undo(),
+        "reload must keep the session's undo history"
     );
+    assert!(ctx.undo().unwrap());
+    assert!(ctx.list_boards().unwrap().is_empty());
 }
 
 // ============================================================================
@@ -739,16 +741,16 @@ use rmcp::handler::server::wrapper::Parameters;
 use serde_json::Value;
 
 async fn setup_server() -> (KanbanMcpServer, TempDir) {
+    setup_server_with_config(AppConfig::default()).await
+}
+
+async fn setup_server_with_config(config: AppConfig) -> (KanbanMcpServer, TempDir) {
     let dir = TempDir::new().unwrap();
     let path = dir.path().join("test.json");
     let store_manager = default_store_manager();
-    let server = KanbanMcpServer::new(
-        &store_manager,
-        &path.to_string_lossy(),
-        AppConfig::default(),
-    )
-    .await
-    .unwrap();
+    let server = KanbanMcpServer::new(&store_manager, &path.to_string_lossy(), config)
+        .await
+        .unwrap();
     (server, dir)
 }
 
@@ -768,6 +770,7 @@ async fn tool_move_card_resolves_names_through_locked_session() {
         .tool_create_board(Parameters(CreateBoardRequest {
             name: "B".into(),
             card_prefix: Some("KAN".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -776,6 +779,7 @@ async fn tool_move_card_resolves_names_through_locked_session() {
             board: "B".into(),
             name: "TODO".into(),
             position: None,
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -784,6 +788,7 @@ async fn tool_move_card_resolves_names_through_locked_session() {
             board: "B".into(),
             name: "Doing".into(),
             position: None,
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -797,6 +802,7 @@ async fn tool_move_card_resolves_names_through_locked_session() {
             points: None,
             due_date: None,
             sprint_id: None,
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -806,6 +812,7 @@ async fn tool_move_card_resolves_names_through_locked_session() {
             card: "KAN-1".into(),
             column: "Doing".into(),
             position: None,
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -821,6 +828,7 @@ async fn tool_move_cards_rejects_cross_board_batch() {
         .tool_create_board(Parameters(CreateBoardRequest {
             name: "Alpha".into(),
             card_prefix: Some("A".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -828,6 +836,7 @@ async fn tool_move_cards_rejects_cross_board_batch() {
         .tool_create_board(Parameters(CreateBoardRequest {
             name: "Beta".into(),
             card_prefix: Some("B".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -837,6 +846,7 @@ async fn tool_move_cards_rejects_cross_board_batch() {
                 board: board.into(),
                 name: "TODO".into(),
                 position: None,
+                dry_run: None,
             }))
             .await
             .unwrap();
@@ -850,6 +860,7 @@ async fn tool_move_cards_rejects_cross_board_batch() {
                 points: None,
                 due_date: None,
                 sprint_id: None,
+                dry_run: None,
             }))
             .await
             .unwrap();
@@ -858,6 +869,7 @@ async fn tool_move_cards_rejects_cross_board_batch() {
         .tool_move_cards(Parameters(MoveCardsRequest {
             cards: vec!["A-1".into(), "B-1".into()],
             column: "TODO".into(),
+            dry_run: None,
         }))
         .await
         .unwrap_err();
@@ -876,6 +888,7 @@ async fn tool_carry_over_sprint_cards_scopes_to_named_from_board() {
         .tool_create_board(Parameters(CreateBoardRequest {
             name: "Alpha".into(),
             card_prefix: Some("A".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -883,6 +896,7 @@ async fn tool_carry_over_sprint_cards_scopes_to_named_from_board() {
         .tool_create_board(Parameters(CreateBoardRequest {
             name: "Beta".into(),
             card_prefix: Some("B".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -892,6 +906,7 @@ async fn tool_carry_over_sprint_cards_scopes_to_named_from_board() {
             board: "Alpha".into(),
             prefix: None,
             name: Some("completed".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -900,6 +915,7 @@ async fn tool_carry_over_sprint_cards_scopes_to_named_from_board() {
             board: "Alpha".into(),
             prefix: None,
             name: Some("next".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -908,6 +924,7 @@ async fn tool_carry_over_sprint_cards_scopes_to_named_from_board() {
             board: "Beta".into(),
             prefix: None,
             name: Some("next".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -916,12 +933,14 @@ async fn tool_carry_over_sprint_cards_scopes_to_named_from_board() {
         .tool_activate_sprint(Parameters(kanban_mcp::ActivateSprintRequest {
             sprint: "completed".into(),
             duration_days: Some(1),
+            dry_run: None,
         }))
         .await
         .unwrap();
     server
         .tool_complete_sprint(Parameters(kanban_mcp::CompleteSprintRequest {
             sprint: "completed".into(),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -932,6 +951,7 @@ async fn tool_carry_over_sprint_cards_scopes_to_named_from_board() {
         .tool_carry_over_sprint_cards(Parameters(CarryOverSprintCardsRequest {
             from_sprint: "completed".into(),
             to_sprint: "next".into(),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -946,6 +966,7 @@ async fn tool_assign_card_to_sprint_resolves_by_name_then_mutates() {
         .tool_create_board(Parameters(CreateBoardRequest {
             name: "B".into(),
             card_prefix: Some("KAN".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -954,6 +975,7 @@ async fn tool_assign_card_to_sprint_resolves_by_name_then_mutates() {
             board: "B".into(),
             name: "TODO".into(),
             position: None,
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -962,6 +984,7 @@ async fn tool_assign_card_to_sprint_resolves_by_name_then_mutates() {
             board: "B".into(),
             prefix: None,
             name: Some("alpha".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -975,6 +998,7 @@ async fn tool_assign_card_to_sprint_resolves_by_name_then_mutates() {
             points: None,
             due_date: None,
             sprint_id: None,
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -983,6 +1007,7 @@ async fn tool_assign_card_to_sprint_resolves_by_name_then_mutates() {
         .tool_assign_card_to_sprint(Parameters(AssignCardToSprintRequest {
             card: "KAN-1".into(),
             sprint: "alpha".into(),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -992,6 +1017,7 @@ async fn tool_assign_card_to_sprint_resolves_by_name_then_mutates() {
         .tool_assign_card_to_sprint(Parameters(AssignCardToSprintRequest {
             card: "KAN-1".into(),
             sprint: "1".into(), // sprint number
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1008,11 +1034,17 @@ use kanban_mcp::{
 };
 
 async fn setup_server_with_two_cards() -> (KanbanMcpServer, TempDir, String, String) {
-    let (server, dir) = setup_server().await;
+    seed_two_cards(setup_server().await).await
+}
+
+async fn seed_two_cards(
+    (server, dir): (KanbanMcpServer, TempDir),
+) -> (KanbanMcpServer, TempDir, String, String) {
     server
         .tool_create_board(Parameters(CreateBoardRequest {
             name: "B".into(),
             card_prefix: Some("KAN".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1021,6 +1053,7 @@ async fn setup_server_with_two_cards() -> (KanbanMcpServer, TempDir, String, Str
             board: "B".into(),
             name: "TODO".into(),
             position: None,
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1034,6 +1067,7 @@ async fn setup_server_with_two_cards() -> (KanbanMcpServer, TempDir, String, Str
             points: None,
             due_date: None,
             sprint_id: None,
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1047,6 +1081,7 @@ async fn setup_server_with_two_cards() -> (KanbanMcpServer, TempDir, String, Str
             points: None,
             due_date: None,
             sprint_id: None,
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1061,6 +1096,7 @@ async fn tool_set_card_parent_resolves_identifiers_and_persists() {
         .tool_set_card_parent(Parameters(SetCardParentRequest {
             child: child.clone(),
             parent: parent.clone(),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1090,6 +1126,7 @@ async fn tool_set_card_parent_cycle_returns_mcp_error() {
         .tool_set_card_parent(Parameters(SetCardParentRequest {
             child: b.clone(),
             parent: a.clone(),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1099,6 +1136,7 @@ async fn tool_set_card_parent_cycle_returns_mcp_error() {
         .tool_set_card_parent(Parameters(SetCardParentRequest {
             child: a.clone(),
             parent: b.clone(),
+            dry_run: None,
         }))
         .await
         .unwrap_err();
@@ -1141,6 +1179,7 @@ async fn tool_set_card_parent_self_reference_returns_invalid_params_with_card_id
         .tool_set_card_parent(Parameters(SetCardParentRequest {
             child: a.clone(),
             parent: a.clone(),
+            dry_run: None,
         }))
         .await
         .unwrap_err();
@@ -1167,6 +1206,7 @@ async fn tool_list_card_parents_returns_summaries() {
         .tool_set_card_parent(Parameters(SetCardParentRequest {
             child: child.clone(),
             parent: parent.clone(),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1205,6 +1245,7 @@ async fn tool_remove_card_parent_returns_error_when_edge_missing() {
         .tool_remove_card_parent(Parameters(RemoveCardParentRequest {
             child: child.clone(),
             parent: parent.clone(),
+            dry_run: None,
         }))
         .await
         .unwrap_err();
@@ -1224,6 +1265,7 @@ async fn tool_create_card_with_sprint_id_assigns_to_sprint() {
         .tool_create_board(Parameters(CreateBoardRequest {
             name: "B".into(),
             card_prefix: Some("KAN".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1232,6 +1274,7 @@ async fn tool_create_card_with_sprint_id_assigns_to_sprint() {
             board: "B".into(),
             name: "TODO".into(),
             position: None,
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1240,6 +1283,7 @@ async fn tool_create_card_with_sprint_id_assigns_to_sprint() {
             board: "B".into(),
             prefix: None,
             name: Some("alpha".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1256,6 +1300,7 @@ async fn tool_create_card_with_sprint_id_assigns_to_sprint() {
             points: None,
             due_date: None,
             sprint_id: Some(sprint_id.clone()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1270,6 +1315,7 @@ async fn tool_create_card_with_sprint_name_resolves_and_assigns() {
         .tool_create_board(Parameters(CreateBoardRequest {
             name: "B".into(),
             card_prefix: Some("KAN".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1278,6 +1324,7 @@ async fn tool_create_card_with_sprint_name_resolves_and_assigns() {
             board: "B".into(),
             name: "TODO".into(),
             position: None,
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1286,6 +1333,7 @@ async fn tool_create_card_with_sprint_name_resolves_and_assigns() {
             board: "B".into(),
             prefix: None,
             name: Some("alpha".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1299,6 +1347,7 @@ async fn tool_create_card_with_sprint_name_resolves_and_assigns() {
             points: None,
             due_date: None,
             sprint_id: Some("alpha".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1313,6 +1362,7 @@ async fn tool_create_card_without_sprint_id_leaves_card_unassigned() {
         .tool_create_board(Parameters(CreateBoardRequest {
             name: "B".into(),
             card_prefix: Some("KAN".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1321,6 +1371,7 @@ async fn tool_create_card_without_sprint_id_leaves_card_unassigned() {
             board: "B".into(),
             name: "TODO".into(),
             position: None,
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1334,6 +1385,7 @@ async fn tool_create_card_without_sprint_id_leaves_card_unassigned() {
             points: None,
             due_date: None,
             sprint_id: None,
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1352,6 +1404,7 @@ async fn tool_create_card_with_unknown_sprint_name_returns_useful_error() {
         .tool_create_board(Parameters(CreateBoardRequest {
             name: "B".into(),
             card_prefix: Some("KAN".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1360,6 +1413,7 @@ async fn tool_create_card_with_unknown_sprint_name_returns_useful_error() {
             board: "B".into(),
             name: "TODO".into(),
             position: None,
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1374,6 +1428,7 @@ async fn tool_create_card_with_unknown_sprint_name_returns_useful_error() {
             points: None,
             due_date: None,
             sprint_id: Some("nonexistent".into()),
+            dry_run: None,
         }))
         .await
         .unwrap_err();
@@ -1393,6 +1448,7 @@ async fn tool_create_card_with_cross_board_sprint_returns_useful_error() {
         .tool_create_board(Parameters(CreateBoardRequest {
             name: "A".into(),
             card_prefix: Some("A".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1400,6 +1456,7 @@ async fn tool_create_card_with_cross_board_sprint_returns_useful_error() {
         .tool_create_board(Parameters(CreateBoardRequest {
             name: "B".into(),
             card_prefix: Some("B".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1408,6 +1465,7 @@ async fn tool_create_card_with_cross_board_sprint_returns_useful_error() {
             board: "A".into(),
             name: "TODO".into(),
             position: None,
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1416,6 +1474,7 @@ async fn tool_create_card_with_cross_board_sprint_returns_useful_error() {
             board: "B".into(),
             prefix: None,
             name: Some("beta".into()),
+            dry_run: None,
         }))
         .await
         .unwrap();
@@ -1438,9 +1497,573 @@ async fn tool_create_card_with_cross_board_sprint_returns_useful_error() {
             points: None,
             due_date: None,
             sprint_id: Some(sprint_b_id.clone()),
+            dry_run: None,
         }))
         .await
         .unwrap_err();
     let msg = format!("{:?}", err);
     assert!(msg.contains("belongs to board"), "err: {msg}");
 }
+
+#[tokio::test]
+async fn tool_import_board_as_copy_and_clone_board() {
+    use kanban_mcp::{CloneBoardRequest, ExportBoardRequest, ImportBoardRequest};
+
+    let (server, _tmp, _, _) = setup_server_with_two_cards().await;
+    let export = server
+        .tool_export_board(Parameters(ExportBoardRequest {
+            board: Some("B".into()),
+            include_attachments: None,
+        }))
+        .await
+        .unwrap();
+    let data = export.content[0].as_text().unwrap().text.clone();
+
+    let clone = server
+        .tool_clone_board(Parameters(CloneBoardRequest {
+            board: "B".into(),
+            name: "Fork".into(),
+            include_cards: Some(true),
+            include_sprints: None,
+            dry_run: None,
+        }))
+        .await
+        .unwrap();
+    assert_eq!(text_payload(&clone)["name"], "Fork");
+
+    assert!(server
+        .tool_import_board(Parameters(ImportBoardRequest {
+            data: data.clone(),
+            as_copy: None,
+            dry_run: None,
+        }))
+        .await
+        .is_err());
+    let copy = server
+        .tool_import_board(Parameters(ImportBoardRequest {
+            data,
+            as_copy: Some(true),
+            dry_run: None,
+        }))
+        .await
+        .unwrap();
+    assert_eq!(text_payload(&copy)["name"], "B");
+
+    let boards = text_payload(&server.tool_list_boards().await.unwrap());
+    assert_eq!(boards.as_array().unwrap().len(), 3);
+    let fork_cards = server
+        .tool_list_cards(Parameters(kanban_mcp::ListCardsRequest {
+            board: Some("Fork".into()),
+            column: None,
+            sprint: None,
+            status: None,
+            sort: None,
+            order: None,
+            page: None,
+            page_size: None,
+        }))
+        .await
+        .unwrap();
+    assert_eq!(
+        text_payload(&fork_cards)["items"].as_array().unwrap().len(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn tool_card_attachments_roundtrip() {
+    use kanban_mcp::{
+        AddAttachmentRequest, AttachmentRequest, CollectAttachmentGarbageRequest,
+        GetAttachmentRequest, ListAttachmentsRequest,
+    };
+
+    let (server, tmp, card, _) = setup_server_with_two_cards().await;
+    let added = server
+        .tool_add_card_attachment(Parameters(AddAttachmentRequest {
+            card: card.clone(),
+            path: None,
+            content_base64: Some("cGFuaWMgYXQgbGluZSAz".into()),
+            name: Some("crash.log".into()),
+            dry_run: None,
+        }))
+        .await
+        .unwrap();
+    assert_eq!(text_payload(&added)["size"], 15);
+
+    let listed = server
+        .tool_list_card_attachments(Parameters(ListAttachmentsRequest { card: card.clone() }))
+        .await
+        .unwrap();
+    assert_eq!(text_payload(&listed)[0]["mime_type"], "text/plain");
+
+    let read = server
+        .tool_get_card_attachment(Parameters(GetAttachmentRequest {
+            card: card.clone(),
+            attachment: "crash.log".into(),
+            output_path: None,
+        }))
+        .await
+        .unwrap();
+    assert_eq!(text_payload(&read)["text"], "panic at line 3");
+
+    let written = server
+        .tool_get_card_attachment(Parameters(GetAttachmentRequest {
+            card: card.clone(),
+            attachment: "1".into(),
+            output_path: Some(tmp.path().to_string_lossy().into_owned()),
+        }))
+        .await
+        .unwrap();
+    assert!(text_payload(&written)["path"]
+        .as_str()
+        .unwrap()
+        .ends_with("crash.log"));
+
+    server
+        .tool_remove_card_attachment(Parameters(AttachmentRequest {
+            card,
+            attachment: "1".into(),
+            confirm: None,
+            dry_run: None,
+        }))
+        .await
+        .unwrap();
+    let report = server
+        .tool_collect_attachment_garbage(Parameters(CollectAttachmentGarbageRequest {
+            dry_run: Some(true),
+        }))
+        .await
+        .unwrap();
+    let report = text_payload(&report);
+    assert_eq!(report["dry_run"], true);
+    // The removal can still be undone, so its content stays referenced.
+    assert!(report["removed"].as_array().unwrap().is_empty());
+    assert_eq!(report["kept"], 1);
+}
+
+#[tokio::test]
+async fn tool_get_board_digest_summarizes_board() {
+    use kanban_mcp::{GetBoardDigestRequest, UpdateCardRequest, UpdateColumnRequest};
+
+    let (server, _tmp, parent, child) = setup_server_with_two_cards().await;
+    server
+        .tool_set_card_parent(Parameters(SetCardParentRequest {
+            child: child.clone(),
+            parent: parent.clone(),
+            dry_run: None,
+        }))
+        .await
+        .unwrap();
+    server
+        .tool_update_column(Parameters(UpdateColumnRequest {
+            column: "TODO".into(),
+            name: None,
+            position: None,
+            wip_limit: Some(2),
+            clear_wip_limit: None,
+            dry_run: None,
+        }))
+        .await
+        .unwrap();
+    server
+        .tool_update_card(Parameters(UpdateCardRequest {
+            card: child.clone(),
+            title: None,
+            description: None,
+            priority: None,
+            status: Some("blocked".into()),
+            due_date: Some("2020-01-01".into()),
+            clear_due_date: None,
+            points: Some(3),
+            dry_run: None,
+        }))
+        .await
+        .unwrap();
+
+    let digest = |max_items: Option<usize>, max_depth: Option<usize>| {
+        server.tool_get_board_digest(Parameters(GetBoardDigestRequest {
+            board: "B".into(),
+            max_items,
+            max_depth,
+            max_title_chars: Some(3),
+        }))
+    };
+    let body = text_payload(&digest(None, None).await.unwrap());
+    assert_eq!(body["totals"]["cards"], 2);
+    assert_eq!(body["totals"]["points"], 3);
+    assert_eq!(body["columns"][0]["cards"], 2);
+    assert_eq!(body["columns"][0]["wip"], "at_limit");
+    assert!(body["active_sprint"].is_null());
+    assert_eq!(body["blocked"]["items"][0]["identifier"], "KAN-2");
+    assert_eq!(body["overdue"]["items"][0]["identifier"], "KAN-2");
+    assert!(
+        body["overdue"]["items"][0]["days_overdue"]
+            .as_i64()
+            .unwrap()
+            > 0
+    );
+    assert_eq!(body["recent"][0]["identifier"], "KAN-2");
+    assert_eq!(body["recent"][0]["change"], "Update card");
+    assert!(body["recent"][1]["change"].is_null());
+    let root = &body["hierarchy"]["items"][0];
+    assert_eq!(root["identifier"], "KAN-1");
+    assert_eq!(root["title"], "Par…");
+    assert_eq!(root["children"][0]["identifier"], "KAN-2");
+
+    let body = text_payload(&digest(Some(1), Some(1)).await.unwrap());
+    assert_eq!(body["recent"].as_array().unwrap().len(), 1);
+    let root = &body["hierarchy"]["items"][0];
+    assert!(root.get("children").is_none(), "{root}");
+    assert_eq!(root["omitted_children"], 1);
+}
+
+#[tokio::test]
+async fn tool_update_cards_applies_per_card_fields_as_one_undo_step() {
+    use kanban_mcp::{UpdateCardRequest, UpdateCardsRequest};
+
+    let (server, _tmp, first, second) = setup_server_with_two_cards().await;
+    let update = |card: &str, title: Option<&str>, points: Option<u8>| UpdateCardRequest {
+        card: card.into(),
+        title: title.map(Into::into),
+        description: None,
+        priority: None,
+        status: None,
+        due_date: None,
+        clear_due_date: None,
+        points,
+        dry_run: None,
+    };
+    let result = server
+        .tool_update_cards(Parameters(UpdateCardsRequest {
+            cards: vec![
+                update(&first, Some("Epic"), None),
+                update(&second, None, Some(5)),
+            ],
+            dry_run: None,
+        }))
+        .await
+        .unwrap();
+    assert_eq!(text_payload(&result)["updated_count"], 2);
+
+    let get = |card: String| server.tool_get_card(Parameters(kanban_mcp::GetCardRequest { card }));
+    assert_eq!(
+        text_payload(&get(first.clone()).await.unwrap())["title"],
+        "Epic"
+    );
+    assert_eq!(
+        text_payload(&get(second.clone()).await.unwrap())["points"],
+        5
+    );
+
+    server.tool_undo().await.unwrap();
+    assert_eq!(
+        text_payload(&get(first.clone()).await.unwrap())["title"],
+        "Parent"
+    );
+    assert!(text_payload(&get(second).await.unwrap())["points"].is_null());
+
+    let err = server
+        .tool_update_cards(Parameters(UpdateCardsRequest {
+            cards: vec![
+                update(&first, Some("Renamed"), None),
+                update("KAN-99", Some("Missing"), None),
+            ],
+            dry_run: None,
+        }))
+        .await;
+    assert!(err.is_err());
+    assert_eq!(text_payload(&get(first).await.unwrap())["title"], "Parent");
+}
+
+#[tokio::test]
+async fn tool_create_cards_builds_tree_with_temp_keys() {
+    use kanban_mcp::{CreateCardsRequest, ListCardChildrenRequest, NewCardRequest};
+
+    let (server, _tmp, epic, _) = setup_server_with_two_cards().await;
+    let card = |key: &str, parent: &str, blocked_by: Option<Vec<String>>| NewCardRequest {
+        key: Some(key.into()),
+        title: key.to_uppercase(),
+        column: None,
+        description: None,
+        priority: None,
+        points: Some(2),
+        due_date: None,
+        sprint_id: None,
+        parent: Some(parent.into()),
+        blocked_by,
+    };
+    let created = server
+        .tool_create_cards(Parameters(CreateCardsRequest {
+            board: "B".into(),
+            column: Some("TODO".into()),
+            cards: vec![
+                card("api", &epic, None),
+                card("ui", &epic, Some(vec!["api".into()])),
+                card("docs", "ui", None),
+            ],
+            dry_run: None,
+        }))
+        .await
+        .unwrap();
+    let body = text_payload(&created);
+    assert_eq!(body["created_count"], 3);
+    assert_eq!(body["cards"][0]["key"], "api");
+    assert_eq!(body["cards"][0]["identifier"], "KAN-3");
+    assert_eq!(body["cards"][2]["identifier"], "KAN-5");
+
+    let children = |card: &str| {
+        server.tool_list_card_children(Parameters(ListCardChildrenRequest { card: card.into() }))
+    };
+    let epic_children = text_payload(&children(&epic).await.unwrap());
+    assert_eq!(epic_children.as_array().unwrap().len(), 2);
+    let ui_children = text_payload(&children("KAN-4").await.unwrap());
+    assert_eq!(ui_children[0]["title"], "DOCS");
+
+    server.tool_undo().await.unwrap();
+    let listing = server
+        .tool_list_cards(Parameters(kanban_mcp::ListCardsRequest {
+            board: None,
+            column: None,
+            sprint: None,
+            status: None,
+            sort: None,
+            order: None,
+            page: None,
+            page_size: None,
+        }))
+        .await
+        .unwrap();
+    assert_eq!(text_payload(&listing)["items"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn tool_create_cards_rejects_bad_batches_without_writing() {
+    use kanban_mcp::{CreateCardsRequest, NewCardRequest};
+
+    let (server, _tmp, _, _) = setup_server_with_two_cards().await;
+    let card = |key: &str, parent: Option<&str>| NewCardRequest {
+        key: Some(key.into()),
+        title: key.into(),
+        column: Some("TODO".into()),
+        description: None,
+        priority: None,
+        points: None,
+        due_date: None,
+        sprint_id: None,
+        parent: parent.map(Into::into),
+        blocked_by: None,
+    };
+    let create = |cards: Vec<NewCardRequest>| {
+        server.tool_create_cards(Parameters(CreateCardsRequest {
+            board: "B".into(),
+            column: None,
+            cards,
+            dry_run: None,
+        }))
+    };
+    assert!(create(vec![card("a", None), card("a", None)])
+        .await
+        .is_err());
+    assert!(create(vec![card("a", Some("nope"))]).await.is_err());
+    assert!(create(vec![card("a", Some("b")), card("b", Some("a"))])
+        .await
+        .is_err());
+
+    let listing = server
+        .tool_list_cards(Parameters(kanban_mcp::ListCardsRequest {
+            board: None,
+            column: None,
+            sprint: None,
+            status: None,
+            sort: None,
+            order: None,
+            page: None,
+            page_size: None,
+        }))
+        .await
+        .unwrap();
+    assert_eq!(text_payload(&listing)["items"].as_array().unwrap().len(), 2);
+}
+
+fn list_all_cards_request() -> kanban_mcp::ListCardsRequest {
+    kanban_mcp::ListCardsRequest {
+        board: None,
+        column: None,
+        sprint: None,
+        status: None,
+        sort: None,
+        order: None,
+        page: None,
+        page_size: None,
+    }
+}
+
+#[tokio::test]
+async fn tool_dry_run_delete_board_lists_what_would_go() {
+    use kanban_mcp::DeleteBoardRequest;
+
+    let (server, _tmp, parent, child) = setup_server_with_two_cards().await;
+    server
+        .tool_set_card_parent(Parameters(SetCardParentRequest {
+            child: child.clone(),
+            parent: parent.clone(),
+            dry_run: None,
+        }))
+        .await
+        .unwrap();
+
+    let preview = server
+        .tool_delete_board(Parameters(DeleteBoardRequest {
+            board: "B".into(),
+            dry_run: Some(true),
+            confirm: None,
+        }))
+        .await
+        .unwrap();
+    let body = text_payload(&preview);
+    assert_eq!(body["dry_run"], true);
+    assert_eq!(body["tool"], "delete_board");
+    let mut labels: Vec<&str> = body["changes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .inspect(|change| assert_eq!(change["change"], "deleted"))
+        .map(|change| change["label"].as_str().unwrap())
+        .collect();
+    labels.sort();
+    assert_eq!(labels, ["B", "KAN-1 Parent", "KAN-2 Child", "TODO"]);
+    assert_eq!(body["relations"][0]["change"], "removed");
+    assert_eq!(body["relations"][0]["kind"], "parent");
+    assert_eq!(body["relations"][0]["from"], "KAN-1");
+    assert!(body["confirmation_token"].is_string());
+
+    let listing = server
+        .tool_list_cards(Parameters(list_all_cards_request()))
+        .await
+        .unwrap();
+    assert_eq!(text_payload(&listing)["items"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn tool_dry_run_create_card_has_no_token_and_writes_nothing() {
+    let (server, _tmp, _, _) = setup_server_with_two_cards().await;
+    let preview = server
+        .tool_create_card(Parameters(CreateCardRequest {
+            board: "B".into(),
+            column: "TODO".into(),
+            title: "Draft".into(),
+            description: None,
+            priority: None,
+            points: None,
+            due_date: None,
+            sprint_id: None,
+            dry_run: Some(true),
+        }))
+        .await
+        .unwrap();
+    let body = text_payload(&preview);
+    let card = body["changes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|change| change["entity"] == "card")
+        .unwrap();
+    assert_eq!(card["change"], "created");
+    assert_eq!(card["label"], "KAN-3 Draft");
+    assert!(body.get("confirmation_token").is_none());
+
+    let listing = server
+        .tool_list_cards(Parameters(list_all_cards_request()))
+        .await
+        .unwrap();
+    assert_eq!(text_payload(&listing)["items"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn tool_confirm_destructive_requires_the_dry_run_token() {
+    use kanban_mcp::DeleteCardRequest;
+
+    let config = AppConfig {
+        mcp_confirm_destructive: Some(true),
+        ..Default::default()
+    };
+    let (server, _tmp, parent, child) =
+        seed_two_cards(setup_server_with_config(config).await).await;
+    let delete = |confirm: Option<String>, dry_run: Option<bool>| {
+        server.tool_delete_card(Parameters(DeleteCardRequest {
+            card: child.clone(),
+            dry_run,
+            confirm,
+        }))
+    };
+
+    let err = delete(None, None).await.unwrap_err();
+    assert!(err.message.contains("dry_run"), "msg: {}", err.message);
+    assert!(delete(Some("0000".into()), None).await.is_err());
+
+    let preview = text_payload(&delete(None, Some(true)).await.unwrap());
+    let token = preview["confirmation_token"].as_str().unwrap().to_string();
+
+    // Linking the card after the dry run changes what deleting it does,
+    // so the earlier token no longer matches.
+    server
+        .tool_set_card_parent(Parameters(SetCardParentRequest {
+            child: child.clone(),
+            parent,
+            dry_run: None,
+        }))
+        .await
+        .unwrap();
+    let err = delete(Some(token), None).await.unwrap_err();
+    assert!(
+        err.message.contains("does not match"),
+        "msg: {}",
+        err.message
+    );
+
+    let preview = text_payload(&delete(None, Some(true)).await.unwrap());
+    let token = preview["confirmation_token"].as_str().unwrap().to_string();
+    assert_eq!(preview["relations"][0]["to"], "KAN-2");
+    delete(Some(token), None).await.unwrap();
+    let listing = server
+        .tool_list_cards(Parameters(list_all_cards_request()))
+        .await
+        .unwrap();
+    assert_eq!(text_payload(&listing)["items"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn tool_dry_run_sees_writes_from_another_process() {
+    use kanban_mcp::DeleteCardRequest;
+
+    let config = AppConfig {
+        mcp_confirm_destructive: Some(true),
+        ..Default::default()
+    };
+    let (server, tmp, parent, child) =
+        seed_two_cards(setup_server_with_config(config.clone()).await).await;
+    let path = tmp.path().join("test.json");
+    let other = KanbanMcpServer::new(&default_store_manager(), &path.to_string_lossy(), config)
+        .await
+        .unwrap();
+    other
+        .tool_set_card_parent(Parameters(SetCardParentRequest {
+            child: child.clone(),
+            parent,
+            dry_run: None,
+        }))
+        .await
+        .unwrap();
+
+    let delete = |confirm: Option<String>, dry_run: Option<bool>| {
+        server.tool_delete_card(Parameters(DeleteCardRequest {
+            card: child.clone(),
+            dry_run,
+            confirm,
+        }))
+    };
+    let preview = text_payload(&delete(None, Some(true)).await.unwrap());
+    assert_eq!(preview["relations"][0]["to"], "KAN-2");
+    let token = preview["confirmation_token"].as_str().unwrap().to_string();
+    delete(Some(token), None).await.unwrap();
+}
@@ -0,0 +1,241 @@
+//! MCP resources, prompts and resource-change notifications, driven by a
+//! real client over an in-memory duplex transport.
+
+use kanban_core::AppConfig;
+use kanban_mcp::McpServer;
+use rmcp::model::{
+    CallToolRequestParam, GetPromptRequestParam, PromptMessageContent, ReadResourceRequestParam,
+    ResourceContents, ResourceUpdatedNotificationParam, SubscribeRequestParam,
+};
+use rmcp::service::{NotificationContext, RoleClient, RunningService};
+use rmcp::{ClientHandler, ServiceExt};
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tokio::sync::mpsc;
+
+/// Forwards `resources/updated` notifications to the test.
+#[derive(Clone)]
+struct Updates(mpsc::UnboundedSender<String>);
+
+impl ClientHandler for Updates {
+    async fn on_resource_updated(
+        &self,
+        params: ResourceUpdatedNotificationParam,
+        _context: NotificationContext<RoleClient>,
+    ) {
+        let _ = self.0.send(params.uri);
+    }
+}
+
+async fn connect<C: ClientHandler>(handler: C) -> (RunningService<RoleClient, C>, TempDir) {
+    let tmp = tempfile::tempdir().unwrap();
+    let path = tmp.path().join("boards.json").to_string_lossy().to_string();
+    let server = McpServer::with_defaults()
+        .with_config(AppConfig::default())
+        .with_data_file(path)
+        .build()
+        .await
+        .unwrap();
+    let (server_io, client_io) = tokio::io::duplex(64 * 1024);
+    tokio::spawn(async move {
+        if let Ok(running) = server.serve(server_io).await {
+            let _ = running.waiting().await;
+        }
+    });
+    let client = handler.serve(client_io).await.expect("client connects");
+    (client, tmp)
+}
+
+async fn call<C: ClientHandler>(client: &RunningService<RoleClient, C>, tool: &str, args: Value) {
+    client
+        .call_tool(CallToolRequestParam {
+            name: tool.to_string().into(),
+            arguments: args.as_object().cloned(),
+        })
+        .await
+        .unwrap_or_else(|e| panic!("{tool} failed: {e}"));
+}
+
+/// Board "Ops" (prefix OPS) with an active sprint holding OPS-1 (parent of
+/// OPS-2, 3 points, done) and an unsprinted OPS-2.
+async fn seed<C: ClientHandler>(client: &RunningService<RoleClient, C>) {
+    call(
+        client,
+        "tool_create_board",
+        json!({"name": "Ops", "card_prefix": "OPS"}),
+    )
+    .await;
+    call(
+        client,
+        "tool_create_column",
+        json!({"board": "Ops", "name": "Todo"}),
+    )
+    .await;
+    for (title, points) in [("Rotate keys", 3), ("Audit access", 5)] {
+        call(
+            client,
+            "tool_create_card",
+            json!({"board": "Ops", "column": "Todo", "title": title, "points": points}),
+        )
+        .await;
+    }
+    call(
+        client,
+        "tool_set_card_parent",
+        json!({"child": "OPS-2", "parent": "OPS-1"}),
+    )
+    .await;
+    call(client, "tool_create_sprint", json!({"board": "Ops"})).await;
+    call(
+        client,
+        "tool_assign_card_to_sprint",
+        json!({"card": "OPS-1", "sprint": "1"}),
+    )
+    .await;
+    call(
+        client,
+        "tool_activate_sprint",
+        json!({"sprint": "1", "duration_days": 14}),
+    )
+    .await;
+    call(
+        client,
+        "tool_update_card",
+        json!({"card": "OPS-1", "status": "done"}),
+    )
+    .await;
+}
+
+async fn read<C: ClientHandler>(client: &RunningService<RoleClient, C>, uri: &str) -> String {
+    let result = client
+        .read_resource(ReadResourceRequestParam { uri: uri.into() })
+        .await
+        .unwrap_or_else(|e| panic!("reading {uri} failed: {e}"));
+    match &result.contents[0] {
+        ResourceContents::TextResourceContents { text, .. } => text.clone(),
+        other => panic!("expected text, got {other:?}"),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn resources_render_boards_cards_and_current_sprint() {
+    let (client, _tmp) = connect(()).await;
+    seed(&client).await;
+
+    let resources = client.list_all_resources().await.unwrap();
+    let uris: Vec<&str> = resources.iter().map(|r| r.uri.as_str()).collect();
+    assert_eq!(uris.len(), 2, "{uris:?}");
+    assert!(uris[0].starts_with("kanban://board/"));
+    assert_eq!(uris[1], "kanban://sprint/current");
+
+    let board = read(&client, "kanban://board/Ops").await;
+    assert!(board.contains("# Ops"), "{board}");
+    assert!(board.contains("## Todo"), "{board}");
+
+    let card = read(&client, "kanban://card/OPS-2").await;
+    assert!(card.starts_with("# OPS-2 Audit access"), "{card}");
+    assert!(card.contains("- Parents: OPS-1"), "{card}");
+
+    let card: Value =
+        serde_json::from_str(&read(&client, "kanban://card/OPS-1?format=json").await).unwrap();
+    assert_eq!(card["identifier"], "OPS-1");
+    assert_eq!(card["relations"]["children"], json!(["OPS-2"]));
+    assert_eq!(card["card"]["points"], 3);
+
+    let sprint = read(&client, "kanban://sprint/current").await;
+    assert!(sprint.contains("3 of 3 points done"), "{sprint}");
+    assert!(sprint.contains("OPS-1"), "{sprint}");
+    assert!(!sprint.contains("OPS-2"), "{sprint}");
+
+    let err = client
+        .read_resource(ReadResourceRequestParam {
+            uri: "kanban://card/OPS-99".into(),
+        })
+        .await;
+    assert!(err.is_err());
+    client.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn prompts_assemble_board_context() {
+    let (client, _tmp) = connect(()).await;
+    seed(&client).await;
+
+    let prompts = client.list_prompts(None).await.unwrap().prompts;
+    let names: Vec<&str> = prompts.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "plan_next_sprint",
+            "triage_backlog",
+            "write_standup_summary"
+        ]
+    );
+
+    let prompt_text = |name: &'static str, args: Value| {
+        let client = &client;
+        async move {
+            let result = client
+                .get_prompt(GetPromptRequestParam {
+                    name: name.into(),
+                    arguments: args.as_object().cloned(),
+                })
+                .await
+                .unwrap_or_else(|e| panic!("{name} failed: {e}"));
+            match &result.messages[0].content {
+                PromptMessageContent::Text { text } => text.clone(),
+                other => panic!("expected text, got {other:?}"),
+            }
+        }
+    };
+
+    let plan = prompt_text("plan_next_sprint", json!({"board": "Ops", "capacity": "8"})).await;
+    assert!(plan.contains("Capacity: 8 points."), "{plan}");
+    assert!(plan.contains("## Backlog (5 points)"), "{plan}");
+    assert!(plan.contains("OPS-2"), "{plan}");
+
+    let triage = prompt_text("triage_backlog", json!({"board": "Ops"})).await;
+    assert!(triage.contains("## Backlog (1 cards)"), "{triage}");
+    assert!(triage.contains("no description"), "{triage}");
+
+    let standup = prompt_text("write_standup_summary", json!({"board": "Ops"})).await;
+    let done = standup.split("## In progress").next().unwrap();
+    assert!(done.contains("OPS-1"), "{standup}");
+
+    let missing_board = client
+        .get_prompt(GetPromptRequestParam {
+            name: "triage_backlog".into(),
+            arguments: None,
+        })
+        .await;
+    assert!(missing_board.is_err());
+    client.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn subscribed_resources_are_notified_on_change() {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let (client, _tmp) = connect(Updates(tx)).await;
+    seed(&client).await;
+    client
+        .subscribe(SubscribeRequestParam {
+            uri: "kanban://card/OPS-2".into(),
+        })
+        .await
+        .unwrap();
+    while rx.try_recv().is_ok() {}
+
+    call(
+        &client,
+        "tool_update_card",
+        json!({"card": "OPS-2", "title": "Audit all access"}),
+    )
+    .await;
+    let uri = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+        .await
+        .expect("notified within 5s")
+        .unwrap();
+    assert_eq!(uri, "kanban://card/OPS-2");
+    assert!(read(&client, &uri).await.contains("Audit all access"));
+    client.cancel().await.unwrap();
+}
@@ -118,6 +118,57 @@ CREATE TABLE IF NOT EXISTS sprint_logs (
 
 CREATE INDEX IF NOT EXISTS idx_sprint_logs_card_id ON sprint_logs(card_id);
 
+-- Checklist items, ordered by position within their card. Item ids are
+-- only unique per card: copied boards keep the ids of copied items.
+CREATE TABLE IF NOT EXISTS checklist_items (
+    card_id TEXT NOT NULL,
+    id TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    text TEXT NOT NULL,
+    done INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL,
+    completed_at TEXT,
+    PRIMARY KEY (card_id, id),
+    FOREIGN KEY (card_id) REFERENCES cards(id) ON DELETE CASCADE
+);
+
+-- Attachment metadata, ordered by position within their card. The
+-- content lives in `blobs` under `hash` and may be shared between cards.
+CREATE TABLE IF NOT EXISTS attachments (
+    card_id TEXT NOT NULL,
+    id TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    size INTEGER NOT NULL,
+    mime_type TEXT NOT NULL,
+    hash TEXT NOT NULL,
+    added_at TEXT NOT NULL,
+    PRIMARY KEY (card_id, id),
+    FOREIGN KEY (card_id) REFERENCES cards(id) ON DELETE CASCADE
+);
+
+-- Activity log entries, ordered by position within their card.
+CREATE TABLE IF NOT EXISTS card_activity (
+    card_id TEXT NOT NULL,
+    id TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    source TEXT NOT NULL,
+    message TEXT NOT NULL,
+    reference TEXT,
+    at TEXT NOT NULL,
+    PRIMARY KEY (card_id, id),
+    FOREIGN KEY (card_id) REFERENCES cards(id) ON DELETE CASCADE
+);
+
+-- Content-addressed attachment content. No FK from `attachments`: a
+-- blob outlives its last reference (so removing an attachment stays
+-- undoable) until garbage collection deletes it.
+CREATE TABLE IF NOT EXISTS blobs (
+    hash TEXT PRIMARY KEY,
+    size INTEGER NOT NULL,
+    data BLOB NOT NULL
+);
+
 -- Archived cards metadata (card data lives in cards table)
 CREATE TABLE IF NOT EXISTS archived_cards (
     card_id TEXT PRIMARY KEY,
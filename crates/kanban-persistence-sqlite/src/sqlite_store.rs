@@ -4,11 +4,12 @@ use std::path::{Path, PathBuf};
 use chrono::{DateTime, Utc};
 use kanban_domain::data_store::DataStore;
 use kanban_domain::{
-    ArchivedCard, Board, Card, Column, DependencyGraph, KanbanError, KanbanResult, Snapshot,
-    Sprint, SprintLog,
+    ActivityEntry, ArchivedCard, Attachment, Board, Card, ChecklistItem, Column, DependencyGraph,
+    KanbanError, KanbanResult, Snapshot, Sprint, SprintLog,
 };
 use kanban_persistence::{
-    PersistenceError, PersistenceMetadata, PersistenceResult, PersistenceStore, StoreSnapshot,
+    blob_hash, validate_blob_hash, BlobInfo, BlobStore, PersistenceError, PersistenceMetadata,
+    PersistenceResult, PersistenceStore, StoreSnapshot,
 };
 use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions, SqliteRow};
 use sqlx::{Pool, Row, Sqlite};
@@ -163,7 +164,13 @@ fn row_to_column(row: &SqliteRow) -> KanbanResult<Column> {
     })
 }
 
-fn row_to_card(row: &SqliteRow, sprint_logs: Vec<SprintLog>) -> KanbanResult<Card> {
+fn row_to_card(
+    row: &SqliteRow,
+    sprint_logs: Vec<SprintLog>,
+    checklist: Vec<ChecklistItem>,
+    attachments: Vec<Attachment>,
+    activity: Vec<ActivityEntry>,
+) -> KanbanResult<Card> {
     let id_str: String = row.try_get("id").map_err(db_err)?;
     let column_id_str: String = row.try_get("column_id").map_err(db_err)?;
     let sprint_id_str: Option<String> = row.try_get("sprint_id").map_err(db_err)?;
@@ -193,6 +200,9 @@ fn row_to_card(row: &SqliteRow, sprint_logs: Vec<SprintLog>) -> KanbanResult<Car
         updated_at: p_dt(&updated_at_str)?,
         completed_at: completed_at_str.as_deref().map(p_dt).transpose()?,
         sprint_logs,
+        checklist,
+        attachments,
+        activity,
     })
 }
 
@@ -251,6 +261,48 @@ fn row_to_sprint_log(row: &SqliteRow) -> KanbanResult<SprintLog> {
     })
 }
 
+fn row_to_checklist_item(row: &SqliteRow) -> KanbanResult<ChecklistItem> {
+    let id_str: String = row.try_get("id").map_err(db_err)?;
+    let created_at_str: String = row.try_get("created_at").map_err(db_err)?;
+    let completed_at_str: Option<String> = row.try_get("completed_at").map_err(db_err)?;
+
+    Ok(ChecklistItem {
+        id: p_uuid(&id_str)?,
+        text: row.try_get("text").map_err(db_err)?,
+        done: row.try_get("done").map_err(db_err)?,
+        created_at: p_dt(&created_at_str)?,
+        completed_at: completed_at_str.as_deref().map(p_dt).transpose()?,
+    })
+}
+
+fn row_to_attachment(row: &SqliteRow) -> KanbanResult<Attachment> {
+    let id_str: String = row.try_get("id").map_err(db_err)?;
+    let added_at_str: String = row.try_get("added_at").map_err(db_err)?;
+    let size: i64 = row.try_get("size").map_err(db_err)?;
+
+    Ok(Attachment {
+        id: p_uuid(&id_str)?,
+        name: row.try_get("name").map_err(db_err)?,
+        size: u64::try_from(size).map_err(|_| ser_err(format!("size {size} out of range")))?,
+        mime_type: row.try_get("mime_type").map_err(db_err)?,
+        hash: row.try_get("hash").map_err(db_err)?,
+        added_at: p_dt(&added_at_str)?,
+    })
+}
+
+fn row_to_activity_entry(row: &SqliteRow) -> KanbanResult<ActivityEntry> {
+    let id_str: String = row.try_get("id").map_err(db_err)?;
+    let at_str: String = row.try_get("at").map_err(db_err)?;
+
+    Ok(ActivityEntry {
+        id: p_uuid(&id_str)?,
+        source: row.try_get("source").map_err(db_err)?,
+        message: row.try_get("message").map_err(db_err)?,
+        reference: row.try_get("reference").map_err(db_err)?,
+        at: p_dt(&at_str)?,
+    })
+}
+
 // --- SqliteStore ---
 
 impl SqliteStore {
@@ -765,6 +817,42 @@ impl SqliteStore {
         rows.iter().map(row_to_sprint_log).collect()
     }
 
+    async fn fetch_checklist_for_card(&self, card_id: &str) -> KanbanResult<Vec<ChecklistItem>> {
+        let rows = sqlx::query(
+            "SELECT id, text, done, created_at, completed_at
+             FROM checklist_items WHERE card_id = ? ORDER BY position",
+        )
+        .bind(card_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_err)?;
+        rows.iter().map(row_to_checklist_item).collect()
+    }
+
+    async fn fetch_attachments_for_card(&self, card_id: &str) -> KanbanResult<Vec<Attachment>> {
+        let rows = sqlx::query(
+            "SELECT id, name, size, mime_type, hash, added_at
+             FROM attachments WHERE card_id = ? ORDER BY position",
+        )
+        .bind(card_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_err)?;
+        rows.iter().map(row_to_attachment).collect()
+    }
+
+    async fn fetch_activity_for_card(&self, card_id: &str) -> KanbanResult<Vec<ActivityEntry>> {
+        let rows = sqlx::query(
+            "SELECT id, source, message, reference, at
+             FROM card_activity WHERE card_id = ? ORDER BY position",
+        )
+        .bind(card_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_err)?;
+        rows.iter().map(row_to_activity_entry).collect()
+    }
+
     async fn write_card_with_conn(
         conn: &mut sqlx::SqliteConnection,
         card: &Card,
@@ -824,6 +912,76 @@ impl SqliteStore {
             .map_err(db_err)?;
         }
 
+        sqlx::query("DELETE FROM checklist_items WHERE card_id = ?")
+            .bind(&id)
+            .execute(&mut *conn)
+            .await
+            .map_err(db_err)?;
+        for (position, item) in card.checklist.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO checklist_items (card_id, id, position, text, done,
+                    created_at, completed_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&id)
+            .bind(item.id.to_string())
+            .bind(position as i32)
+            .bind(required_str(&item.text, "checklist_item.text")?)
+            .bind(item.done)
+            .bind(fmt_dt(&item.created_at))
+            .bind(opt_dt(&item.completed_at))
+            .execute(&mut *conn)
+            .await
+            .map_err(db_err)?;
+        }
+
+        sqlx::query("DELETE FROM attachments WHERE card_id = ?")
+            .bind(&id)
+            .execute(&mut *conn)
+            .await
+            .map_err(db_err)?;
+        for (position, attachment) in card.attachments.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO attachments (card_id, id, position, name, size, mime_type,
+                    hash, added_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&id)
+            .bind(attachment.id.to_string())
+            .bind(position as i32)
+            .bind(required_str(&attachment.name, "attachment.name")?)
+            .bind(attachment.size as i64)
+            .bind(&attachment.mime_type)
+            .bind(&attachment.hash)
+            .bind(fmt_dt(&attachment.added_at))
+            .execute(&mut *conn)
+            .await
+            .map_err(db_err)?;
+        }
+
+        sqlx::query("DELETE FROM card_activity WHERE card_id = ?")
+            .bind(&id)
+            .execute(&mut *conn)
+            .await
+            .map_err(db_err)?;
+        for (position, entry) in card.activity.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO card_activity (card_id, id, position, source, message,
+                    reference, at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&id)
+            .bind(entry.id.to_string())
+            .bind(position as i32)
+            .bind(required_str(&entry.source, "activity.source")?)
+            .bind(&entry.message)
+            .bind(&entry.reference)
+            .bind(fmt_dt(&entry.at))
+            .execute(&mut *conn)
+            .await
+            .map_err(db_err)?;
+        }
+
         Ok(())
     }
 
@@ -860,6 +1018,84 @@ impl SqliteStore {
         Ok(map)
     }
 
+    async fn fetch_checklists_batch(
+        &self,
+        card_ids: &[String],
+    ) -> KanbanResult<HashMap<String, Vec<ChecklistItem>>> {
+        if card_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let placeholders = card_ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+        let sql = format!(
+            "SELECT card_id, id, text, done, created_at, completed_at
+             FROM checklist_items WHERE card_id IN ({placeholders}) ORDER BY position"
+        );
+        let mut query = sqlx::query(&sql);
+        for id in card_ids {
+            query = query.bind(id);
+        }
+        let rows = query.fetch_all(&self.pool).await.map_err(db_err)?;
+        let mut map: HashMap<String, Vec<ChecklistItem>> = HashMap::new();
+        for row in &rows {
+            let card_id: String = row.try_get("card_id").map_err(db_err)?;
+            let item = row_to_checklist_item(row)?;
+            map.entry(card_id).or_default().push(item);
+        }
+        Ok(map)
+    }
+
+    async fn fetch_attachments_batch(
+        &self,
+        card_ids: &[String],
+    ) -> KanbanResult<HashMap<String, Vec<Attachment>>> {
+        if card_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let placeholders = card_ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+        let sql = format!(
+            "SELECT card_id, id, name, size, mime_type, hash, added_at
+             FROM attachments WHERE card_id IN ({placeholders}) ORDER BY position"
+        );
+        let mut query = sqlx::query(&sql);
+        for id in card_ids {
+            query = query.bind(id);
+        }
+        let rows = query.fetch_all(&self.pool).await.map_err(db_err)?;
+        let mut map: HashMap<String, Vec<Attachment>> = HashMap::new();
+        for row in &rows {
+            let card_id: String = row.try_get("card_id").map_err(db_err)?;
+            let attachment = row_to_attachment(row)?;
+            map.entry(card_id).or_default().push(attachment);
+        }
+        Ok(map)
+    }
+
+    async fn fetch_activity_batch(
+        &self,
+        card_ids: &[String],
+    ) -> KanbanResult<HashMap<String, Vec<ActivityEntry>>> {
+        if card_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let placeholders = card_ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+        let sql = format!(
+            "SELECT card_id, id, source, message, reference, at
+             FROM card_activity WHERE card_id IN ({placeholders}) ORDER BY position"
+        );
+        let mut query = sqlx::query(&sql);
+        for id in card_ids {
+            query = query.bind(id);
+        }
+        let rows = query.fetch_all(&self.pool).await.map_err(db_err)?;
+        let mut map: HashMap<String, Vec<ActivityEntry>> = HashMap::new();
+        for row in &rows {
+            let card_id: String = row.try_get("card_id").map_err(db_err)?;
+            let entry = row_to_activity_entry(row)?;
+            map.entry(card_id).or_default().push(entry);
+        }
+        Ok(map)
+    }
+
     async fn fetch_cards_with_filter(
         &self,
         where_clause: &str,
@@ -883,12 +1119,18 @@ impl SqliteStore {
             .map(|r| r.try_get("id").map_err(db_err))
             .collect::<KanbanResult<_>>()?;
         let mut logs_map = self.fetch_sprint_logs_batch(&card_ids).await?;
+        let mut checklists_map = self.fetch_checklists_batch(&card_ids).await?;
+        let mut attachments_map = self.fetch_attachments_batch(&card_ids).await?;
+        let mut activity_map = self.fetch_activity_batch(&card_ids).await?;
 
         let mut cards = Vec::with_capacity(rows.len());
         for row in &rows {
             let id_str: String = row.try_get("id").map_err(db_err)?;
             let logs = logs_map.remove(&id_str).unwrap_or_default();
-            cards.push(row_to_card(row, logs)?);
+            let checklist = checklists_map.remove(&id_str).unwrap_or_default();
+            let attachments = attachments_map.remove(&id_str).unwrap_or_default();
+            let activity = activity_map.remove(&id_str).unwrap_or_default();
+            cards.push(row_to_card(row, logs, checklist, attachments, activity)?);
         }
         Ok(cards)
     }
@@ -1073,6 +1315,18 @@ impl SqliteStore {
             .execute(&mut *tx)
             .await
             .map_err(db_err)?;
+        sqlx::query("DELETE FROM checklist_items")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_err)?;
+        sqlx::query("DELETE FROM attachments")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_err)?;
+        sqlx::query("DELETE FROM card_activity")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_err)?;
         sqlx::query("DELETE FROM cards")
             .execute(&mut *tx)
             .await
@@ -1224,12 +1478,18 @@ impl SqliteStore {
             .map(|r| r.try_get("id").map_err(db_err))
             .collect::<KanbanResult<_>>()?;
         let mut logs_map = self.fetch_sprint_logs_batch(&card_ids).await?;
+        let mut checklists_map = self.fetch_checklists_batch(&card_ids).await?;
+        let mut attachments_map = self.fetch_attachments_batch(&card_ids).await?;
+        let mut activity_map = self.fetch_activity_batch(&card_ids).await?;
 
         let mut result = Vec::with_capacity(rows.len());
         for row in &rows {
             let id_str: String = row.try_get("id").map_err(db_err)?;
             let logs = logs_map.remove(&id_str).unwrap_or_default();
-            let card = row_to_card(row, logs)?;
+            let checklist = checklists_map.remove(&id_str).unwrap_or_default();
+            let attachments = attachments_map.remove(&id_str).unwrap_or_default();
+            let activity = activity_map.remove(&id_str).unwrap_or_default();
+            let card = row_to_card(row, logs, checklist, attachments, activity)?;
             let archived_at_str: String = row.try_get("archived_at").map_err(db_err)?;
             let orig_col_str: String = row.try_get("original_column_id").map_err(db_err)?;
             result.push(ArchivedCard {
@@ -1478,7 +1738,16 @@ impl DataStore for SqliteStore {
             match row {
                 Some(row) => {
                     let logs = self.fetch_sprint_logs_for_card(&id_str).await?;
-                    Ok(Some(row_to_card(&row, logs)?))
+                    let checklist = self.fetch_checklist_for_card(&id_str).await?;
+                    let attachments = self.fetch_attachments_for_card(&id_str).await?;
+                    let activity = self.fetch_activity_for_card(&id_str).await?;
+                    Ok(Some(row_to_card(
+                        &row,
+                        logs,
+                        checklist,
+                        attachments,
+                        activity,
+                    )?))
                 }
                 None => Ok(None),
             }
@@ -1627,7 +1896,10 @@ impl DataStore for SqliteStore {
             match row {
                 Some(row) => {
                     let logs = self.fetch_sprint_logs_for_card(&id_str).await?;
-                    let card = row_to_card(&row, logs)?;
+                    let checklist = self.fetch_checklist_for_card(&id_str).await?;
+                    let attachments = self.fetch_attachments_for_card(&id_str).await?;
+                    let activity = self.fetch_activity_for_card(&id_str).await?;
+                    let card = row_to_card(&row, logs, checklist, attachments, activity)?;
                     let archived_at_str: String = row.try_get("archived_at").map_err(db_err)?;
                     let orig_col_str: String = row.try_get("original_column_id").map_err(db_err)?;
                     Ok(Some(ArchivedCard {
@@ -1698,12 +1970,18 @@ impl DataStore for SqliteStore {
                 .map(|r| r.try_get("id").map_err(db_err))
                 .collect::<KanbanResult<_>>()?;
             let mut logs_map = self.fetch_sprint_logs_batch(&card_ids).await?;
+            let mut checklists_map = self.fetch_checklists_batch(&card_ids).await?;
+            let mut attachments_map = self.fetch_attachments_batch(&card_ids).await?;
+            let mut activity_map = self.fetch_activity_batch(&card_ids).await?;
 
             let mut result = Vec::with_capacity(rows.len());
             for row in &rows {
                 let id_str: String = row.try_get("id").map_err(db_err)?;
                 let logs = logs_map.remove(&id_str).unwrap_or_default();
-                let card = row_to_card(row, logs)?;
+                let checklist = checklists_map.remove(&id_str).unwrap_or_default();
+                let attachments = attachments_map.remove(&id_str).unwrap_or_default();
+                let activity = activity_map.remove(&id_str).unwrap_or_default();
+                let card = row_to_card(row, logs, checklist, attachments, activity)?;
                 let archived_at_str: String = row.try_get("archived_at").map_err(db_err)?;
                 let orig_col_str: String = row.try_get("original_column_id").map_err(db_err)?;
                 result.push(ArchivedCard {
@@ -1825,6 +2103,76 @@ impl DataStore for SqliteStore {
     }
 }
 
+// --- BlobStore ---
+
+impl BlobStore for SqliteStore {
+    fn put(&self, bytes: &[u8]) -> KanbanResult<String> {
+        let hash = blob_hash(bytes);
+        run(async {
+            sqlx::query("INSERT OR IGNORE INTO blobs (hash, size, data) VALUES (?, ?, ?)")
+                .bind(&hash)
+                .bind(bytes.len() as i64)
+                .bind(bytes)
+                .execute(&self.pool)
+                .await
+                .map_err(db_err)
+        })?;
+        Ok(hash)
+    }
+
+    fn get(&self, hash: &str) -> KanbanResult<Option<Vec<u8>>> {
+        validate_blob_hash(hash)?;
+        run(async {
+            sqlx::query_scalar("SELECT data FROM blobs WHERE hash = ?")
+                .bind(hash)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(db_err)
+        })
+    }
+
+    fn contains(&self, hash: &str) -> KanbanResult<bool> {
+        validate_blob_hash(hash)?;
+        run(async {
+            let found: Option<i64> = sqlx::query_scalar("SELECT 1 FROM blobs WHERE hash = ?")
+                .bind(hash)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(db_err)?;
+            Ok(found.is_some())
+        })
+    }
+
+    fn list(&self) -> KanbanResult<Vec<BlobInfo>> {
+        run(async {
+            let rows: Vec<(String, i64)> =
+                sqlx::query_as("SELECT hash, size FROM blobs ORDER BY hash")
+                    .fetch_all(&self.pool)
+                    .await
+                    .map_err(db_err)?;
+            Ok(rows
+                .into_iter()
+                .map(|(hash, size)| BlobInfo {
+                    hash,
+                    size: size.max(0) as u64,
+                })
+                .collect())
+        })
+    }
+
+    fn delete(&self, hash: &str) -> KanbanResult<bool> {
+        validate_blob_hash(hash)?;
+        run(async {
+            let result = sqlx::query("DELETE FROM blobs WHERE hash = ?")
+                .bind(hash)
+                .execute(&self.pool)
+                .await
+                .map_err(db_err)?;
+            Ok(result.rows_affected() > 0)
+        })
+    }
+}
+
 #[async_trait::async_trait]
 impl PersistenceStore for SqliteStore {
     async fn save(&self, snapshot: StoreSnapshot) -> PersistenceResult<PersistenceMetadata> {
@@ -1986,6 +2334,29 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_blob_store_round_trip_survives_snapshot_replace() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("test.db");
+        let rt = make_rt();
+        rt.block_on(async {
+            let store = SqliteStore::open(&path).await.unwrap();
+            let hash = store.put(b"hello world").unwrap();
+            assert_eq!(hash, blob_hash(b"hello world"));
+            assert_eq!(store.put(b"hello world").unwrap(), hash);
+            assert_eq!(store.get(&hash).unwrap().unwrap(), b"hello world");
+
+            // Blobs are not part of the snapshot, so replacing it keeps them.
+            store.apply_snapshot(Snapshot::default()).unwrap();
+            assert!(store.contains(&hash).unwrap());
+            assert_eq!(store.list().unwrap().len(), 1);
+
+            assert!(store.delete(&hash).unwrap());
+            assert!(store.get(&hash).unwrap().is_none());
+            assert!(store.get("../x").is_err());
+        });
+    }
+
     #[test]
     fn test_checkpoint_executes_without_error() {
         let dir = TempDir::new().unwrap();
@@ -0,0 +1,234 @@
+//! Content-addressed storage for card attachments.
+//!
+//! Blobs are keyed by the lowercase hex SHA-256 of their content, so the
+//! same file attached twice is stored once and a key always identifies
+//! exactly one byte string. Cards hold the key (see
+//! [`kanban_domain::Attachment`]); nothing here knows about cards, which is
+//! why unreferenced blobs have to be garbage-collected by the caller.
+
+use kanban_domain::{KanbanError, KanbanResult};
+use sha2::{Digest, Sha256};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// A stored blob as reported by [`BlobStore::list`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlobInfo {
+    pub hash: String,
+    pub size: u64,
+}
+
+/// Synchronous blob storage, mirroring the synchronous `DataStore`.
+pub trait BlobStore: Send + Sync {
+    /// Store `bytes` and return their hash. Storing content that is
+    /// already present is a no-op.
+    fn put(&self, bytes: &[u8]) -> KanbanResult<String>;
+
+    /// Content stored under `hash`, or `None` if there is none.
+    fn get(&self, hash: &str) -> KanbanResult<Option<Vec<u8>>>;
+
+    fn contains(&self, hash: &str) -> KanbanResult<bool>;
+
+    fn list(&self) -> KanbanResult<Vec<BlobInfo>>;
+
+    /// Delete the blob under `hash`. Returns `false` if there was none.
+    fn delete(&self, hash: &str) -> KanbanResult<bool>;
+}
+
+/// Lowercase hex SHA-256 of `bytes`: the key a blob is stored under.
+pub fn blob_hash(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// Reject anything that is not a SHA-256 hex digest before it is used as a
+/// key, so a hash taken from an imported file can never name a path
+/// outside the blob directory.
+pub fn validate_blob_hash(hash: &str) -> KanbanResult<()> {
+    if hash.len() == 64 && hash.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')) {
+        Ok(())
+    } else {
+        Err(KanbanError::validation(format!(
+            "Invalid attachment hash '{}'",
+            hash
+        )))
+    }
+}
+
+/// Copy every blob in `from` that `to` lacks. Returns how many were copied.
+pub fn copy_blobs(from: &dyn BlobStore, to: &dyn BlobStore) -> KanbanResult<usize> {
+    let mut copied = 0;
+    for blob in from.list()? {
+        if to.contains(&blob.hash)? {
+            continue;
+        }
+        if let Some(bytes) = from.get(&blob.hash)? {
+            to.put(&bytes)?;
+            copied += 1;
+        }
+    }
+    Ok(copied)
+}
+
+/// Blobs kept as one file per hash in a directory. Used by file-based
+/// backends; the directory is created on the first `put`.
+#[derive(Debug, Clone)]
+pub struct DirBlobStore {
+    dir: PathBuf,
+}
+
+impl DirBlobStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The store kept next to `data_file`: `board.json` keeps its
+    /// attachments in `board.json.attachments/`.
+    pub fn beside(data_file: &Path) -> Self {
+        let mut dir = data_file.as_os_str().to_owned();
+        dir.push(".attachments");
+        Self::new(dir)
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn blob_path(&self, hash: &str) -> KanbanResult<PathBuf> {
+        validate_blob_hash(hash)?;
+        Ok(self.dir.join(hash))
+    }
+}
+
+impl BlobStore for DirBlobStore {
+    fn put(&self, bytes: &[u8]) -> KanbanResult<String> {
+        let hash = blob_hash(bytes);
+        let path = self.blob_path(&hash)?;
+        if path.exists() {
+            return Ok(hash);
+        }
+        std::fs::create_dir_all(&self.dir)?;
+        // Write beside the target and rename so a crash never leaves a
+        // truncated blob under a valid hash.
+        let tmp = self.dir.join(format!(".tmp-{}", uuid::Uuid::new_v4()));
+        let written = std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&tmp)
+            .and_then(|mut file| {
+                file.write_all(bytes)?;
+                file.sync_all()
+            })
+            .and_then(|()| std::fs::rename(&tmp, &path));
+        if let Err(e) = written {
+            let _ = std::fs::remove_file(&tmp);
+            return Err(e.into());
+        }
+        Ok(hash)
+    }
+
+    fn get(&self, hash: &str) -> KanbanResult<Option<Vec<u8>>> {
+        match std::fs::read(self.blob_path(hash)?) {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn contains(&self, hash: &str) -> KanbanResult<bool> {
+        Ok(self.blob_path(hash)?.is_file())
+    }
+
+    fn list(&self) -> KanbanResult<Vec<BlobInfo>> {
+        let entries = match std::fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut blobs = Vec::new();
+        for entry in entries {
+            let entry = entry?;
+            let Some(hash) = entry.file_name().to_str().map(str::to_string) else {
+                continue;
+            };
+            if validate_blob_hash(&hash).is_err() || !entry.file_type()?.is_file() {
+                continue;
+            }
+            blobs.push(BlobInfo {
+                hash,
+                size: entry.metadata()?.len(),
+            });
+        }
+        blobs.sort_by(|a, b| a.hash.cmp(&b.hash));
+        Ok(blobs)
+    }
+
+    fn delete(&self, hash: &str) -> KanbanResult<bool> {
+        match std::fs::remove_file(self.blob_path(hash)?) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blob_hash_is_sha256_hex() {
+        assert_eq!(
+            blob_hash(b"hello world"),
+            "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+        );
+    }
+
+    #[test]
+    fn test_beside_appends_attachments_suffix() {
+        let store = DirBlobStore::beside(Path::new("/tmp/board.json"));
+        assert_eq!(store.dir(), Path::new("/tmp/board.json.attachments"));
+    }
+
+    #[test]
+    fn test_dir_store_round_trip_and_delete() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = DirBlobStore::new(dir.path().join("blobs"));
+        assert!(store.list().unwrap().is_empty());
+
+        let hash = store.put(b"screenshot").unwrap();
+        assert_eq!(store.put(b"screenshot").unwrap(), hash);
+        assert!(store.contains(&hash).unwrap());
+        assert_eq!(store.get(&hash).unwrap().unwrap(), b"screenshot");
+        assert_eq!(
+            store.list().unwrap(),
+            vec![BlobInfo {
+                hash: hash.clone(),
+                size: 10
+            }]
+        );
+
+        assert!(store.delete(&hash).unwrap());
+        assert!(!store.delete(&hash).unwrap());
+        assert!(store.get(&hash).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_copy_blobs_skips_existing() {
+        let dir = tempfile::tempdir().unwrap();
+        let from = DirBlobStore::new(dir.path().join("from"));
+        let to = DirBlobStore::new(dir.path().join("to"));
+        from.put(b"a").unwrap();
+        from.put(b"b").unwrap();
+        to.put(b"a").unwrap();
+        assert_eq!(copy_blobs(&from, &to).unwrap(), 1);
+        assert_eq!(to.list().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_invalid_hash_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = DirBlobStore::new(dir.path());
+        assert!(store.get("../board.json").is_err());
+        assert!(store.delete(&"A".repeat(64)).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+pub mod blob_store;
 pub mod conflict;
 pub mod error;
 pub mod null_store;
@@ -9,6 +10,9 @@ pub mod test_helpers;
 pub mod traits;
 pub mod watch;
 
+pub use blob_store::{
+    blob_hash, copy_blobs, validate_blob_hash, BlobInfo, BlobStore, DirBlobStore,
+};
 pub use conflict::*;
 pub use error::{PersistenceError, PersistenceResult};
 pub use null_store::NullStore;
@@ -2,7 +2,10 @@ use chrono::Utc;
 use kanban_domain::card::{Card, CardPriority, CardStatus};
 use kanban_domain::sprint::{Sprint, SprintStatus};
 use kanban_domain::Snapshot;
-use kanban_domain::{ArchivedCard, Board, Column, DependencyGraph, SprintLog};
+use kanban_domain::{
+    ActivityEntry, ArchivedCard, Attachment, Board, ChecklistItem, Column, DependencyGraph,
+    SprintLog,
+};
 use uuid::Uuid;
 
 pub fn fully_populated_snapshot() -> Snapshot {
@@ -86,6 +89,25 @@ pub fn fully_populated_snapshot() -> Snapshot {
             ended_at: None,
             status: "Active".into(),
         }],
+        checklist: vec![
+            ChecklistItem {
+                id: Uuid::new_v4(),
+                text: "Update changelog".into(),
+                done: true,
+                created_at: now,
+                completed_at: Some(now),
+            },
+            ChecklistItem::new("Tag release", now),
+        ],
+        attachments: vec![Attachment::new(
+            "crash.log",
+            11,
+            "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9",
+            now,
+        )],
+        activity: vec![
+            ActivityEntry::new("git", "Closed by abc1234", now).with_reference("abc1234")
+        ],
     };
 
     let archived_card = ArchivedCard {
@@ -105,6 +127,9 @@ pub fn fully_populated_snapshot() -> Snapshot {
             updated_at: now,
             completed_at: Some(now),
             sprint_logs: vec![],
+            checklist: vec![ChecklistItem::new("Archived step", now)],
+            attachments: vec![],
+            activity: vec![],
         },
         archived_at: now,
         original_column_id: col_id,
@@ -0,0 +1,87 @@
+//! Writing attachment content out of the blob store for the user.
+//!
+//! Extraction always writes under [`Attachment::file_name`], never the raw
+//! stored name, so an attachment from an imported file cannot write
+//! outside the chosen directory.
+
+use kanban_domain::{Attachment, KanbanError, KanbanResult};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Write `content` to `dest`. A missing `dest` means the current directory
+/// and an existing directory receives the file under the attachment's
+/// name; any other path is written as given. Returns the path written.
+pub fn extract_attachment(
+    attachment: &Attachment,
+    content: &[u8],
+    dest: Option<&Path>,
+) -> KanbanResult<PathBuf> {
+    let path = match dest {
+        None => PathBuf::from(attachment.file_name()),
+        Some(dir) if dir.is_dir() => dir.join(attachment.file_name()),
+        Some(file) => file.to_path_buf(),
+    };
+    std::fs::write(&path, content)?;
+    Ok(path)
+}
+
+/// Extract into a per-attachment temporary directory and hand the file to
+/// the system viewer. Returns the path opened; the viewer is not waited on.
+pub fn open_attachment(attachment: &Attachment, content: &[u8]) -> KanbanResult<PathBuf> {
+    let dir = std::env::temp_dir()
+        .join("kanban-attachments")
+        .join(attachment.id.to_string());
+    std::fs::create_dir_all(&dir)?;
+    let path = extract_attachment(attachment, content, Some(&dir))?;
+    open_in_viewer(&path)?;
+    Ok(path)
+}
+
+fn open_in_viewer(path: &Path) -> KanbanResult<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(windows) {
+        let mut c = Command::new("cmd");
+        c.args(["/C", "start", ""]);
+        c
+    } else {
+        Command::new("xdg-open")
+    };
+    command
+        .arg(path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(drop)
+        .map_err(|e| {
+            KanbanError::validation(format!(
+                "Could not open {}: {}; extract it instead",
+                path.display(),
+                e
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_into_directory_uses_sanitized_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let attachment = Attachment::new("../escape.log", 2, "00", chrono::Utc::now());
+        let path = extract_attachment(&attachment, b"ok", Some(dir.path())).unwrap();
+        assert_eq!(path, dir.path().join("escape.log"));
+        assert_eq!(std::fs::read(path).unwrap(), b"ok");
+    }
+
+    #[test]
+    fn test_extract_to_explicit_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let attachment = Attachment::new("a.log", 2, "00", chrono::Utc::now());
+        let target = dir.path().join("renamed.txt");
+        let path = extract_attachment(&attachment, b"ok", Some(&target)).unwrap();
+        assert_eq!(path, target);
+    }
+}
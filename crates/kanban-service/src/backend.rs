@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use kanban_domain::command_store::CommandStore;
 use kanban_domain::data_store::DataStore;
 use kanban_domain::{InMemoryStore, KanbanError, KanbanResult};
-use kanban_persistence::PersistenceMetadata;
+use kanban_persistence::{BlobStore, PersistenceMetadata};
 use uuid::Uuid;
 
 /// Combines the entity-level CRUD interface (`DataStore`) with the command
@@ -67,6 +67,13 @@ pub trait KanbanBackend: DataStore + CommandStore + Send + Sync {
         None
     }
 
+    /// Content-addressed store holding attachment content: a directory
+    /// beside a JSON file, a table inside a SQLite database. `None` for
+    /// backends with nowhere to keep files, which rejects attachments.
+    fn blob_store(&self) -> Option<&dyn BlobStore> {
+        None
+    }
+
     /// Run `f` as an atomic batch: every mutation commits or rolls
     /// back together. The default impl snapshots state before `f`
     /// runs and restores it on failure — cheap for in-memory backends,
@@ -147,6 +154,13 @@ mod tests {
         assert!(backend.persistence_metadata().is_none());
     }
 
+    #[test]
+    fn test_in_memory_backend_has_no_blob_store() {
+        let store = InMemoryStore::new();
+        let backend: &dyn KanbanBackend = &store;
+        assert!(backend.blob_store().is_none());
+    }
+
     #[test]
     fn test_in_memory_backend_health_checker_returns_none() {
         let store = InMemoryStore::new();
@@ -0,0 +1,171 @@
+//! Export bundles: a snapshot plus the content of its attachments.
+//!
+//! A plain JSON export only carries attachment metadata, which is useless
+//! once it leaves the data file whose blob store holds the content. A
+//! bundle inlines that content as base64 keyed by hash, so importing it
+//! into any backend restores the files too.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use kanban_domain::{Attachment, KanbanError, KanbanResult, Snapshot};
+use kanban_persistence::{blob_hash, validate_blob_hash, BlobStore, PersistenceError};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The bundle layout this binary writes and the newest it reads.
+pub const BUNDLE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachmentBundle {
+    pub bundle_version: u32,
+    pub snapshot: Snapshot,
+    /// Base64 content keyed by SHA-256 hash.
+    pub blobs: BTreeMap<String, String>,
+}
+
+impl AttachmentBundle {
+    /// Bundle `snapshot` with the content of every attachment in it. An
+    /// attachment whose content is missing from `store` is exported
+    /// without it rather than failing the whole export.
+    pub fn build(snapshot: Snapshot, store: Option<&dyn BlobStore>) -> KanbanResult<Self> {
+        let mut blobs = BTreeMap::new();
+        for attachment in attachments(&snapshot) {
+            if blobs.contains_key(&attachment.hash) {
+                continue;
+            }
+            match store
+                .map(|s| s.get(&attachment.hash))
+                .transpose()?
+                .flatten()
+            {
+                Some(bytes) => {
+                    blobs.insert(attachment.hash.clone(), STANDARD.encode(bytes));
+                }
+                None => tracing::warn!(
+                    "Attachment '{}' ({}) has no stored content; exporting without it",
+                    attachment.name,
+                    attachment.hash
+                ),
+            }
+        }
+        Ok(Self {
+            bundle_version: BUNDLE_VERSION,
+            snapshot,
+            blobs,
+        })
+    }
+
+    pub fn to_json(&self) -> KanbanResult<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| PersistenceError::Serialization(e.to_string()).into())
+    }
+
+    /// The bundle in `data`, or `None` when `data` is some other JSON
+    /// export.
+    pub fn parse(data: &str) -> KanbanResult<Option<Self>> {
+        let value: serde_json::Value = serde_json::from_str(data)
+            .map_err(|e| PersistenceError::Serialization(e.to_string()))?;
+        let Some(version) = value.get("bundle_version") else {
+            return Ok(None);
+        };
+        if !matches!(version.as_u64(), Some(v) if v <= BUNDLE_VERSION as u64) {
+            return Err(KanbanError::validation(format!(
+                "Unsupported bundle version {}; this kanban reads up to {}",
+                version, BUNDLE_VERSION
+            )));
+        }
+        serde_json::from_value(value)
+            .map(Some)
+            .map_err(|e| PersistenceError::Serialization(e.to_string()).into())
+    }
+
+    /// Store every blob in `store`, checking each against its hash, and
+    /// return the snapshot to import.
+    pub fn unpack(self, store: Option<&dyn BlobStore>) -> KanbanResult<Snapshot> {
+        if self.blobs.is_empty() {
+            return Ok(self.snapshot);
+        }
+        let store = store.ok_or_else(|| {
+            KanbanError::validation("This storage backend cannot store attachments")
+        })?;
+        for (hash, encoded) in &self.blobs {
+            validate_blob_hash(hash)?;
+            let bytes = STANDARD.decode(encoded).map_err(|e| {
+                KanbanError::validation(format!("Attachment {} is not valid base64: {}", hash, e))
+            })?;
+            if blob_hash(&bytes) != *hash {
+                return Err(KanbanError::validation(format!(
+                    "Attachment content does not match its hash {}",
+                    hash
+                )));
+            }
+            store.put(&bytes)?;
+        }
+        Ok(self.snapshot)
+    }
+}
+
+/// Every attachment on the live and archived cards of `snapshot`.
+pub(crate) fn attachments(snapshot: &Snapshot) -> impl Iterator<Item = &Attachment> {
+    snapshot
+        .cards
+        .iter()
+        .chain(snapshot.archived_cards.iter().map(|ac| &ac.card))
+        .flat_map(|card| &card.attachments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kanban_persistence::DirBlobStore;
+
+    fn snapshot_with(content: &[u8]) -> Snapshot {
+        let mut board = kanban_domain::Board::new("B", None::<String>);
+        let mut card = kanban_domain::Card::new(&mut board, uuid::Uuid::new_v4(), "C", 0);
+        card.attachments.push(Attachment::new(
+            "crash.log",
+            content.len() as u64,
+            blob_hash(content),
+            chrono::Utc::now(),
+        ));
+        Snapshot {
+            boards: vec![board],
+            cards: vec![card],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_bundle_round_trip_restores_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = DirBlobStore::new(dir.path().join("a"));
+        let target = DirBlobStore::new(dir.path().join("b"));
+        let hash = source.put(b"panic at line 3").unwrap();
+
+        let json = AttachmentBundle::build(snapshot_with(b"panic at line 3"), Some(&source))
+            .unwrap()
+            .to_json()
+            .unwrap();
+        let bundle = AttachmentBundle::parse(&json).unwrap().unwrap();
+        let snapshot = bundle.unpack(Some(&target)).unwrap();
+
+        assert_eq!(snapshot.cards[0].attachments[0].hash, hash);
+        assert_eq!(target.get(&hash).unwrap().unwrap(), b"panic at line 3");
+    }
+
+    #[test]
+    fn test_plain_export_is_not_a_bundle() {
+        let json = serde_json::to_string(&Snapshot::default()).unwrap();
+        assert!(AttachmentBundle::parse(&json).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_tampered_content_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut bundle = AttachmentBundle::build(snapshot_with(b"x"), None).unwrap();
+        bundle
+            .blobs
+            .insert(blob_hash(b"x"), STANDARD.encode(b"not x"));
+        let target = DirBlobStore::new(dir.path());
+        assert!(bundle.unpack(Some(&target)).is_err());
+    }
+}
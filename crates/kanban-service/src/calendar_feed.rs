@@ -0,0 +1,52 @@
+//! The iCalendar feed file rewritten after every save.
+//!
+//! With `calendar_feed = "~/calendars/kanban.ics"` in the config, each
+//! successful save rewrites that file with the due dates and sprint windows
+//! of every board, so calendar apps subscribed to it stay current.
+
+use crate::KanbanBackend;
+use kanban_core::AppConfig;
+use kanban_domain::{DefaultPrefixes, IcalExporter, KanbanResult, Snapshot};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Path of the configured feed, with a leading `~` expanded.
+pub fn feed_path(config: &AppConfig) -> Option<PathBuf> {
+    let raw = config.calendar_feed.as_deref()?;
+    match raw.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir().map(|home| home.join(rest)),
+        None => Some(PathBuf::from(raw)),
+    }
+}
+
+/// Rewrite the configured feed from `backend`; a no-op when none is set.
+pub fn refresh(backend: &dyn KanbanBackend, config: &AppConfig) -> KanbanResult<()> {
+    let Some(path) = feed_path(config) else {
+        return Ok(());
+    };
+    write(&path, &backend.snapshot()?, config)
+}
+
+/// Write the calendar for `snapshot` to `path`, replacing the file in one
+/// rename so subscribers never read a partial feed.
+pub fn write(path: &Path, snapshot: &Snapshot, config: &AppConfig) -> KanbanResult<()> {
+    let calendar = IcalExporter::export_calendar(
+        &snapshot.boards,
+        &snapshot.columns,
+        &snapshot.cards,
+        &snapshot.sprints,
+        DefaultPrefixes {
+            card: config.effective_default_card_prefix(),
+            sprint: config.effective_default_sprint_prefix(),
+        },
+    );
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    std::fs::create_dir_all(dir)?;
+    let mut tmp = tempfile::NamedTempFile::new_in(dir)?;
+    tmp.write_all(calendar.as_bytes())?;
+    tmp.persist(path).map_err(|e| e.error)?;
+    Ok(())
+}
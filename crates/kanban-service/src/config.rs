@@ -197,7 +197,15 @@ fn is_all_defaults(config: &AppConfig) -> bool {
         && config.editing_format.is_none()
         && config.configuration_format.is_none()
         && config.configuration_location.is_none()
-        && config.storage_location.is_none();
+        && config.storage_location.is_none()
+        && config.theme.is_none()
+        && config.mouse.is_none()
+        && config.text_editor.is_none()
+        && config.preview.is_none()
+        && config.preview_size.is_none()
+        && config.calendar_feed.is_none()
+        && config.mcp_confirm_destructive.is_none()
+        && config.keymap.is_empty();
 
     if all_none {
         return true;
@@ -207,6 +215,17 @@ fn is_all_defaults(config: &AppConfig) -> bool {
         return false;
     }
 
+    if !config.keymap.is_empty()
+        || config.mouse.is_some()
+        || config.text_editor.is_some()
+        || config.preview.is_some()
+        || config.preview_size.is_some()
+        || config.calendar_feed.is_some()
+        || config.mcp_confirm_destructive.is_some()
+    {
+        return false;
+    }
+
     config
         .default_card_prefix
         .as_deref()
@@ -220,6 +239,7 @@ fn is_all_defaults(config: &AppConfig) -> bool {
             .as_deref()
             .is_none_or(|v| v == DEFAULT_STORAGE_BACKEND)
         && config.editing_format.as_deref().is_none_or(|v| v == "json")
+        && config.theme.as_deref().is_none_or(|v| v == "dark")
         && config
             .configuration_format
             .as_deref()
@@ -262,6 +282,9 @@ pub fn strip_defaults(config: &mut AppConfig) {
     if config.configuration_format.as_deref() == Some("toml") {
         config.configuration_format = None;
     }
+    if config.theme.as_deref() == Some("dark") {
+        config.theme = None;
+    }
     if let Some(ref loc) = config.configuration_location {
         if config_path().map(|p| p.display().to_string()).as_deref() == Some(loc.as_str()) {
             config.configuration_location = None;
@@ -291,6 +314,8 @@ pub struct AppConfigDto {
     pub storage_backend: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub storage_location: Option<String>,
+    #[serde(default)]
+    pub theme: Option<String>,
 }
 
 impl AppConfigDto {
@@ -311,6 +336,7 @@ impl AppConfigDto {
             configuration_format: Some(entity.effective_configuration_format().to_string()),
             configuration_location: Some(effective_configuration_location(entity)),
             storage_location,
+            theme: Some(entity.effective_theme().to_string()),
         }
     }
 
@@ -342,6 +368,7 @@ impl Editable<AppConfig> for AppConfigDto {
         entity.editing_format = self.editing_format;
         entity.configuration_format = self.configuration_format;
         entity.configuration_location = self.configuration_location;
+        entity.theme = self.theme;
         if let Some(location) = self.storage_location {
             entity.storage_location = Some(location);
         }
@@ -582,6 +609,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_keymap_round_trip_toml() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        let mut f = std::fs::File::create(&path).unwrap();
+        writeln!(f, "default_card_prefix = \"feat\"").unwrap();
+        writeln!(f, "[keymap.card_list]").unwrap();
+        writeln!(f, "jump_to_top = \"Ctrl+g\"").unwrap();
+        writeln!(f, "navigate_down = [\"j\", \"Ctrl+n\"]").unwrap();
+
+        let config = load_from(&path);
+        let card_list = &config.keymap["card_list"];
+        assert_eq!(
+            card_list["jump_to_top"],
+            kanban_core::KeySequences::One("Ctrl+g".into())
+        );
+        assert_eq!(card_list["navigate_down"].to_vec(), vec!["j", "Ctrl+n"]);
+        assert!(has_non_default_values(&AppConfig {
+            keymap: config.keymap.clone(),
+            ..Default::default()
+        }));
+
+        save_to(&config, &path).unwrap();
+        assert_eq!(load_from(&path).keymap, config.keymap);
+    }
+
     #[test]
     fn test_save_creates_parent_dirs() {
         let dir = TempDir::new().unwrap();
@@ -631,6 +684,7 @@ mod tests {
             configuration_format: Some("toml".into()),
             configuration_location: Some("/tmp/test.toml".into()),
             storage_location: None,
+            theme: None,
         };
         let serialized = toml::to_string(&dto).unwrap();
         assert!(serialized.contains("default_card_prefix"));
@@ -657,6 +711,7 @@ mod tests {
             configuration_format: Some("json".into()),
             configuration_location: Some("/home/user/.config/kanban/config.toml".into()),
             storage_location: None,
+            theme: None,
         };
         dto.apply_to(&mut config);
 
@@ -768,6 +823,7 @@ mod tests {
             configuration_format: Some("toml".into()),
             configuration_location: Some("/home/user/.config/kanban/config.toml".into()),
             storage_location: None,
+            theme: None,
         };
         dto.apply_to(&mut config);
 
@@ -831,6 +887,23 @@ mod tests {
             configuration_format: Some("toml".into()),
             configuration_location: config_path().map(|p| p.display().to_string()),
             storage_location: Some("boards.json".into()),
+            theme: Some("dark".into()),
+            mouse: None,
+            text_editor: None,
+            preview: None,
+            preview_size: None,
+            calendar_feed: None,
+            mcp_confirm_destructive: None,
+            keymap: Default::default(),
+        };
+        assert!(has_non_default_values(&config));
+    }
+
+    #[test]
+    fn test_has_non_default_values_calendar_feed_returns_true() {
+        let config = AppConfig {
+            calendar_feed: Some("kanban.ics".into()),
+            ..Default::default()
         };
         assert!(has_non_default_values(&config));
     }
@@ -877,6 +950,7 @@ mod tests {
             configuration_format: Some("toml".into()),
             configuration_location: config_path().map(|p| p.display().to_string()),
             storage_location: None,
+            theme: None,
         };
         let mut config = AppConfig::default();
         dto.apply_to(&mut config);
@@ -917,6 +991,7 @@ mod tests {
             configuration_format: Some("toml".into()),
             configuration_location: Some("/tmp/test.toml".into()),
             storage_location: None,
+            theme: None,
         };
         let mut config = AppConfig::default();
         let err = dto.validate_and_apply(&mut config).unwrap_err();
@@ -933,6 +1008,7 @@ mod tests {
             editing_format: Some("json".into()),
             storage_backend: Some("json".into()),
             storage_location: Some("boards.json".into()),
+            theme: None,
         };
         let serialized = serde_json::to_string_pretty(&dto).unwrap();
         let keys: Vec<&str> = serialized
@@ -1,17 +1,26 @@
 use crate::backend::KanbanBackend;
+use crate::bundle::AttachmentBundle;
+use crate::preview::OverlayBlobStore;
 use kanban_core::AppConfig;
 use kanban_domain::commands::{
-    AddBlocks, AddRelates, AddSpawns, BoardCommand, CardCommand, ColumnCommand, Command,
-    CommandContext, DependencyCommand, RemoveBlocks, RemoveRelates, RemoveSpawns, SprintCommand,
+    AddAttachment, AddBlocks, AddChecklistItem, AddRelates, AddSpawns, AttachmentCommand,
+    BoardCommand, CardCommand, ChecklistCommand, ColumnCommand, Command, CommandContext,
+    DependencyCommand, MoveChecklistItem, RemoveAttachment, RemoveBlocks, RemoveChecklistItem,
+    RemoveRelates, RemoveSpawns, SprintCommand, UpdateChecklistItem,
 };
 use kanban_domain::{
-    ArchivedCard, Board, BoardUpdate, Card, CardListFilter, CardStatus, CardSummary, CardUpdate,
-    Column, ColumnUpdate, DataStore, DependencyGraph, FieldUpdate, GraphOperations,
-    KanbanOperations, RelatesKind, Severity, Snapshot, Sprint, SprintUpdate,
+    ArchivedCard, Attachment, AttachmentGcReport, Board, BoardCopier, BoardExportFormat,
+    BoardTemplate, BoardUpdate, Card, CardListFilter, CardStatus, CardSummary, CardUpdate,
+    ChecklistItem, CloneOptions, Column, ColumnUpdate, CreateCardOptions, CsvExporter,
+    CsvImportSummary, CsvImporter, CsvMapping, DataStore, DefaultPrefixes, DependencyGraph,
+    FieldUpdate, ForeignImport, ForeignImportOptions, ForeignImportReport, ForeignImporter,
+    ForeignSource, GraphOperations, IcalExporter, InMemoryStore, KanbanOperations,
+    MarkdownExporter, RelatesKind, Severity, Snapshot, Sprint, SprintUpdate,
 };
 use kanban_domain::{KanbanError, KanbanResult};
-use kanban_persistence::PersistenceError;
+use kanban_persistence::{BlobStore, PersistenceError};
 use serde::Serialize;
+use std::collections::HashSet;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -27,6 +36,31 @@ pub struct BatchOperationFailure {
     pub error: String,
 }
 
+/// Cards and relations for [`KanbanContext::create_cards`].
+#[derive(Debug, Clone, Default)]
+pub struct NewCardBatch {
+    pub cards: Vec<NewCard>,
+    /// `(parent, child)` pairs.
+    pub parents: Vec<(NewCardRef, NewCardRef)>,
+    /// `(blocker, blocked)` pairs.
+    pub blocks: Vec<(NewCardRef, NewCardRef)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewCard {
+    pub column_id: Uuid,
+    pub title: String,
+    pub options: CreateCardOptions,
+}
+
+/// One end of a relation in a [`NewCardBatch`]: a card of the batch by
+/// index, or a card that already exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NewCardRef {
+    New(usize),
+    Existing(Uuid),
+}
+
 /// Service layer: wraps a pluggable [`KanbanBackend`] with undo/redo history
 /// and a unified async `save()` / `reload()` interface.
 ///
@@ -52,6 +86,9 @@ pub struct KanbanContext {
     undo_stack: crate::undo_stack::UndoStack,
     dirty: bool,
     conflict_pending: bool,
+    /// Used in place of the backend's blob store when set; previews keep
+    /// new attachment content here instead of writing it.
+    blob_overlay: Option<Arc<dyn BlobStore>>,
 }
 
 impl KanbanContext {
@@ -65,6 +102,7 @@ impl KanbanContext {
             undo_stack: crate::undo_stack::UndoStack::new(),
             dirty: false,
             conflict_pending: false,
+            blob_overlay: None,
         }
     }
 
@@ -77,6 +115,31 @@ impl KanbanContext {
         Ok(ctx)
     }
 
+    /// A second context over the same backend with its own, empty undo
+    /// history. Lets one process serve several clients without one
+    /// client's undo reverting another's change. Callers must serialise
+    /// writes across the sessions themselves.
+    pub fn new_session(&self) -> Self {
+        Self::open_deferred(self.backend(), self.app_config.clone())
+    }
+
+    /// A throwaway copy of the current state: an in-memory backend seeded
+    /// with this context's snapshot, with attachment content written to
+    /// an overlay over the real blob store. Run a write against it and
+    /// diff snapshots to see what the write would change; nothing reaches
+    /// the real backend. The copy carries this context's undo history, so
+    /// an undo or redo can be previewed too.
+    pub fn preview(&self) -> KanbanResult<Self> {
+        let store = InMemoryStore::new();
+        store.apply_snapshot(self.backend.snapshot()?)?;
+        let mut preview = Self::open_deferred(Arc::new(store), self.app_config.clone());
+        preview.blob_overlay = self
+            .blob_store_opt()
+            .map(|_| Arc::new(OverlayBlobStore::new(self.backend())) as Arc<dyn BlobStore>);
+        preview.undo_stack = self.undo_stack.clone();
+        Ok(preview)
+    }
+
     // ── Accessors ─────────────────────────────────────────────────────────────
 
     pub fn app_config(&self) -> &AppConfig {
@@ -276,6 +339,76 @@ impl KanbanContext {
         self.undo_stack.redo_depth()
     }
 
+    /// Hashes of the attachment content an undo or redo step would put
+    /// back. Sessions sharing a blob store pool these so one session's
+    /// garbage collection keeps what another session can still restore.
+    pub fn history_attachment_hashes(&self) -> HashSet<String> {
+        self.undo_stack
+            .commands()
+            .flat_map(Command::attachments)
+            .map(|a| a.hash.clone())
+            .collect()
+    }
+
+    /// [`collect_attachment_garbage`][KanbanOperations::collect_attachment_garbage]
+    /// that also keeps the blobs in `keep`, such as those other sessions'
+    /// undo history refers to.
+    pub fn collect_attachment_garbage_keeping(
+        &mut self,
+        dry_run: bool,
+        keep: &HashSet<String>,
+    ) -> KanbanResult<AttachmentGcReport> {
+        let snapshot = self.backend.snapshot()?;
+        let mut referenced: HashSet<&str> = crate::bundle::attachments(&snapshot)
+            .map(|a| a.hash.as_str())
+            .chain(keep.iter().map(String::as_str))
+            .collect();
+        // Undoing a removal or a delete puts attachments back, so their
+        // content must survive for as long as the step is on the stack.
+        referenced.extend(
+            self.undo_stack
+                .commands()
+                .flat_map(Command::attachments)
+                .map(|a| a.hash.as_str()),
+        );
+
+        let store = self.blob_store()?;
+        let mut report = AttachmentGcReport {
+            dry_run,
+            ..Default::default()
+        };
+        for blob in store.list()? {
+            if referenced.contains(blob.hash.as_str()) {
+                report.kept += 1;
+                continue;
+            }
+            if !dry_run {
+                store.delete(&blob.hash)?;
+            }
+            report.freed_bytes += blob.size;
+            report.removed.push(blob.hash);
+        }
+        Ok(report)
+    }
+
+    /// Cards named by the command log, most recently changed first, each
+    /// paired with the description of the first command naming it in the
+    /// batch that last touched it.
+    /// The log only covers changes since the backend was last loaded.
+    pub fn recently_changed_cards(&self) -> KanbanResult<Vec<(Uuid, String)>> {
+        let (batches, _) = self.backend.load_all_commands()?;
+        let mut seen = std::collections::HashSet::new();
+        let mut recent = Vec::new();
+        for cmd in batches.iter().rev().flatten() {
+            for id in cmd.card_ids() {
+                if seen.insert(id) {
+                    recent.push((id, cmd.description()));
+                }
+            }
+        }
+        Ok(recent)
+    }
+
     pub fn is_dirty(&self) -> bool {
         self.dirty
     }
@@ -318,10 +451,54 @@ impl KanbanContext {
         Ok(())
     }
 
+    /// [`reload`][Self::reload] that keeps the undo history. Only for
+    /// callers that check an undo or redo still applies to the reloaded
+    /// state before running it, as MCP sessions sharing a store do.
+    pub async fn reload_keeping_history(&mut self) -> KanbanResult<()> {
+        self.backend.reload().await?;
+        self.dirty = false;
+        Ok(())
+    }
+
     /// Persist any dirty state to durable storage.
     /// For SQLite this is a WAL checkpoint; for JSON this flushes the cache.
     pub async fn save(&self) -> KanbanResult<()> {
-        self.backend.flush().await
+        self.backend.flush().await?;
+        self.refresh_calendar_feed();
+        Ok(())
+    }
+
+    /// Rewrite the configured calendar feed. A feed that cannot be written
+    /// is logged rather than failing the save that triggered it.
+    pub fn refresh_calendar_feed(&self) {
+        if let Err(e) = crate::calendar_feed::refresh(self.backend.as_ref(), &self.app_config) {
+            tracing::warn!("Failed to refresh calendar feed: {}", e);
+        }
+    }
+
+    /// Branch, ahead/behind and mentioning commits of card `id` in `repo`.
+    pub fn card_git_status(
+        &self,
+        id: Uuid,
+        repo: &crate::git::GitRepository,
+    ) -> KanbanResult<crate::git::CardGitStatus> {
+        let card = self
+            .get_card(id)?
+            .ok_or_else(|| KanbanError::not_found("Card", id))?;
+        let column = self
+            .backend
+            .get_column(card.column_id)?
+            .ok_or_else(|| KanbanError::not_found("Column", card.column_id))?;
+        let board = self
+            .backend
+            .get_board(column.board_id)?
+            .ok_or_else(|| KanbanError::not_found("Board", column.board_id))?;
+        let sprints = self.backend.list_all_sprints()?;
+        let prefix = self.app_config.effective_default_card_prefix();
+        repo.card_status(
+            &card.identifier(&board, &sprints, prefix),
+            &card.branch_name(&board, &sprints, prefix),
+        )
     }
 
     // ── Batch ops ─────────────────────────────────────────────────────────────
@@ -552,6 +729,96 @@ impl KanbanContext {
         }
     }
 
+    /// Create `batch.cards` on `board_id` together with the parent/child
+    /// and blocks relations between them (or to cards that already exist)
+    /// as one command batch: one undo step, and nothing is left behind if
+    /// any card or relation is rejected. Returns the new cards in input
+    /// order.
+    pub fn create_cards(&mut self, board_id: Uuid, batch: NewCardBatch) -> KanbanResult<Vec<Card>> {
+        use kanban_domain::commands::CreateCard;
+        use std::collections::hash_map::{Entry, HashMap};
+
+        if batch.cards.is_empty() {
+            return Err(KanbanError::validation("No cards to create"));
+        }
+        let board = self
+            .backend
+            .get_board(board_id)?
+            .ok_or_else(|| KanbanError::not_found("Board", board_id))?;
+        let ids: Vec<Uuid> = batch.cards.iter().map(|_| Uuid::new_v4()).collect();
+        let resolve = |card: NewCardRef| -> KanbanResult<Uuid> {
+            match card {
+                NewCardRef::New(index) => ids.get(index).copied().ok_or_else(|| {
+                    KanbanError::validation(format!(
+                        "Card index {} is out of range for a batch of {}",
+                        index,
+                        ids.len()
+                    ))
+                }),
+                NewCardRef::Existing(id) => {
+                    self.require_card_exists(id)?;
+                    Ok(id)
+                }
+            }
+        };
+
+        let mut commands = Vec::with_capacity(ids.len() + batch.parents.len() + batch.blocks.len());
+        let mut positions: HashMap<Uuid, i32> = HashMap::new();
+        let timestamp = chrono::Utc::now();
+        for (n, (card, id)) in batch.cards.into_iter().zip(&ids).enumerate() {
+            let column = self
+                .backend
+                .get_column(card.column_id)?
+                .filter(|c| c.board_id == board_id)
+                .ok_or_else(|| KanbanError::not_found("Column", card.column_id))?;
+            let position = match positions.entry(column.id) {
+                Entry::Occupied(next) => next.into_mut(),
+                Entry::Vacant(slot) => {
+                    slot.insert(self.backend.list_cards_by_column(column.id)?.len() as i32)
+                }
+            };
+            commands.push(Command::Card(CardCommand::Create(CreateCard {
+                id: *id,
+                card_number: board.card_counter + n as u32,
+                board_id,
+                column_id: column.id,
+                title: card.title,
+                position: *position,
+                options: card.options,
+                timestamp,
+            })));
+            *position += 1;
+        }
+        for (parent, child) in batch.parents {
+            commands.push(Command::Dependency(DependencyCommand::AddSpawns(
+                AddSpawns {
+                    source: resolve(parent)?,
+                    target: resolve(child)?,
+                    as_archived: false,
+                },
+            )));
+        }
+        for (blocker, blocked) in batch.blocks {
+            commands.push(Command::Dependency(DependencyCommand::AddBlocks(
+                AddBlocks {
+                    source: resolve(blocker)?,
+                    target: resolve(blocked)?,
+                    severity: Severity::default(),
+                    as_archived: false,
+                },
+            )));
+        }
+
+        self.execute(commands)?;
+        ids.into_iter()
+            .map(|id| {
+                self.get_card(id)?.ok_or_else(|| {
+                    KanbanError::Internal("Card creation succeeded but card not found".into())
+                })
+            })
+            .collect()
+    }
+
     /// KAN-394: given a status that's about to be applied to a card, compute the
     /// target column the card should live in (and the position to use in that
     /// column) to maintain the status ↔ completion column invariant. Returns
@@ -676,6 +943,38 @@ impl KanbanContext {
         Ok(batch)
     }
 
+    /// The batch [`move_card`][KanbanOperations::move_card] executes: the
+    /// move, then the status change the target column implies. `None`
+    /// appends the card to the column.
+    pub(crate) fn move_card_commands(
+        &self,
+        id: Uuid,
+        column_id: Uuid,
+        position: Option<i32>,
+    ) -> KanbanResult<Vec<Command>> {
+        use kanban_domain::commands::{MoveCard, UpdateCard};
+        let position = match position {
+            Some(p) => p,
+            None => self.backend.list_cards_by_column(column_id)?.len() as i32,
+        };
+        let mut batch = vec![Command::Card(CardCommand::Move(MoveCard {
+            card_id: id,
+            new_column_id: column_id,
+            new_position: position,
+        }))];
+
+        if let Some(new_status) = self.compute_target_status_for_move(id, column_id)? {
+            batch.push(Command::Card(CardCommand::Update(UpdateCard {
+                card_id: id,
+                updates: CardUpdate {
+                    status: Some(new_status),
+                    ..Default::default()
+                },
+            })));
+        }
+        Ok(batch)
+    }
+
     /// KAN-394: given a column the card is about to move to, compute the status
     /// the card should have to maintain the status ↔ completion column invariant.
     /// Returns None when no chained status update is needed.
@@ -773,6 +1072,42 @@ impl KanbanOperations for KanbanContext {
         self.execute(commands)
     }
 
+    fn create_board_from_template(
+        &mut self,
+        name: String,
+        template: &BoardTemplate,
+    ) -> KanbanResult<Board> {
+        use kanban_domain::commands::ImportEntities;
+        let position = self.backend.list_boards()?.len() as i32;
+        let created = template.instantiate(&name, position)?;
+        let id = created.boards[0].id;
+        let cmd = Command::Board(BoardCommand::Import(ImportEntities {
+            boards: created.boards,
+            columns: created.columns,
+            cards: created.cards,
+            archived_cards: Vec::new(),
+            sprints: Vec::new(),
+            graph: Some(created.graph),
+        }));
+        self.execute(vec![cmd])?;
+        self.get_board(id)?
+            .ok_or_else(|| KanbanError::not_found("Board", id))
+    }
+
+    fn board_template(
+        &self,
+        board_id: Uuid,
+        template_name: String,
+        include_cards: bool,
+    ) -> KanbanResult<BoardTemplate> {
+        BoardTemplate::from_board(
+            &self.backend.snapshot()?,
+            board_id,
+            &template_name,
+            include_cards,
+        )
+    }
+
     fn create_column(
         &mut self,
         board_id: Uuid,
@@ -907,27 +1242,7 @@ impl KanbanOperations for KanbanContext {
         column_id: Uuid,
         position: Option<i32>,
     ) -> KanbanResult<Card> {
-        use kanban_domain::commands::{MoveCard, UpdateCard};
-        let position = match position {
-            Some(p) => p,
-            None => self.backend.list_cards_by_column(column_id)?.len() as i32,
-        };
-        let mut batch = vec![Command::Card(CardCommand::Move(MoveCard {
-            card_id: id,
-            new_column_id: column_id,
-            new_position: position,
-        }))];
-
-        if let Some(new_status) = self.compute_target_status_for_move(id, column_id)? {
-            batch.push(Command::Card(CardCommand::Update(UpdateCard {
-                card_id: id,
-                updates: CardUpdate {
-                    status: Some(new_status),
-                    ..Default::default()
-                },
-            })));
-        }
-
+        let batch = self.move_card_commands(id, column_id, position)?;
         self.execute(batch)?;
         self.get_card(id)?
             .ok_or_else(|| KanbanError::not_found("Card", id))
@@ -1044,6 +1359,217 @@ impl KanbanOperations for KanbanContext {
         ))
     }
 
+    fn add_checklist_item(
+        &mut self,
+        card_id: Uuid,
+        text: String,
+        position: Option<usize>,
+    ) -> KanbanResult<ChecklistItem> {
+        let item = ChecklistItem::new(text.trim(), chrono::Utc::now());
+        let item_id = item.id;
+        self.execute(vec![Command::Checklist(ChecklistCommand::AddItem(
+            AddChecklistItem {
+                card_id,
+                item,
+                position,
+            },
+        ))])?;
+        self.checklist_item(card_id, item_id)
+    }
+
+    fn update_checklist_item(
+        &mut self,
+        card_id: Uuid,
+        item_id: Uuid,
+        text: Option<String>,
+        done: Option<bool>,
+    ) -> KanbanResult<ChecklistItem> {
+        self.execute(vec![Command::Checklist(ChecklistCommand::UpdateItem(
+            UpdateChecklistItem {
+                card_id,
+                item_id,
+                text: text.map(|t| t.trim().to_string()),
+                done,
+                timestamp: chrono::Utc::now(),
+            },
+        ))])?;
+        self.checklist_item(card_id, item_id)
+    }
+
+    fn move_checklist_item(
+        &mut self,
+        card_id: Uuid,
+        item_id: Uuid,
+        position: usize,
+    ) -> KanbanResult<Card> {
+        self.execute(vec![Command::Checklist(ChecklistCommand::MoveItem(
+            MoveChecklistItem {
+                card_id,
+                item_id,
+                position,
+            },
+        ))])?;
+        self.get_card(card_id)?
+            .ok_or_else(|| KanbanError::not_found("Card", card_id))
+    }
+
+    fn remove_checklist_item(&mut self, card_id: Uuid, item_id: Uuid) -> KanbanResult<Card> {
+        self.execute(vec![Command::Checklist(ChecklistCommand::RemoveItem(
+            RemoveChecklistItem { card_id, item_id },
+        ))])?;
+        self.get_card(card_id)?
+            .ok_or_else(|| KanbanError::not_found("Card", card_id))
+    }
+
+    fn convert_checklist_item_to_card(
+        &mut self,
+        card_id: Uuid,
+        item_id: Uuid,
+    ) -> KanbanResult<Card> {
+        use kanban_domain::commands::CreateSubcardCommand;
+        let item = self.checklist_item(card_id, item_id)?;
+        let card = self
+            .get_card(card_id)?
+            .ok_or_else(|| KanbanError::not_found("Card", card_id))?;
+        let column = self
+            .backend
+            .get_column(card.column_id)?
+            .ok_or_else(|| KanbanError::not_found("Column", card.column_id))?;
+        let position = self.backend.list_cards_by_column(column.id)?.len() as i32;
+        let id = Uuid::new_v4();
+        self.execute(vec![
+            Command::Dependency(DependencyCommand::CreateSubcard(CreateSubcardCommand {
+                id,
+                parent_id: card_id,
+                board_id: column.board_id,
+                column_id: column.id,
+                title: item.text,
+                description: None,
+                position,
+            })),
+            Command::Checklist(ChecklistCommand::RemoveItem(RemoveChecklistItem {
+                card_id,
+                item_id,
+            })),
+        ])?;
+        self.get_card(id)?
+            .ok_or_else(|| KanbanError::not_found("Card", id))
+    }
+
+    fn import_checklist_from_description(
+        &mut self,
+        card_id: Uuid,
+    ) -> KanbanResult<Vec<ChecklistItem>> {
+        use kanban_domain::checklist::extract_markdown_tasks;
+        use kanban_domain::commands::UpdateCard;
+        let card = self
+            .get_card(card_id)?
+            .ok_or_else(|| KanbanError::not_found("Card", card_id))?;
+        let Some(description) = card.description.as_deref() else {
+            return Ok(Vec::new());
+        };
+        let (tasks, rest) = extract_markdown_tasks(description);
+        if tasks.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let now = chrono::Utc::now();
+        let mut added: Vec<ChecklistItem> = Vec::new();
+        for task in tasks {
+            let known = card
+                .checklist
+                .iter()
+                .chain(&added)
+                .any(|item| item.text == task.text);
+            if !known {
+                let mut item = ChecklistItem::new(task.text, now);
+                item.set_done(task.done, now);
+                added.push(item);
+            }
+        }
+
+        let mut batch = vec![Command::Card(CardCommand::Update(UpdateCard {
+            card_id,
+            updates: CardUpdate {
+                description: if rest.is_empty() {
+                    FieldUpdate::Clear
+                } else {
+                    FieldUpdate::Set(rest)
+                },
+                ..Default::default()
+            },
+        }))];
+        batch.extend(added.iter().map(|item| {
+            Command::Checklist(ChecklistCommand::AddItem(AddChecklistItem {
+                card_id,
+                item: item.clone(),
+                position: None,
+            }))
+        }));
+        self.execute(batch)?;
+        Ok(added)
+    }
+
+    fn add_attachment(
+        &mut self,
+        card_id: Uuid,
+        name: String,
+        content: &[u8],
+    ) -> KanbanResult<Attachment> {
+        self.require_card_exists(card_id)?;
+        if name.trim().is_empty() {
+            return Err(KanbanError::validation("Attachment name cannot be empty"));
+        }
+        let hash = self.blob_store()?.put(content)?;
+        let mut attachment =
+            Attachment::new(name.trim(), content.len() as u64, hash, chrono::Utc::now());
+        attachment.name = attachment.file_name();
+        self.execute(vec![Command::Attachment(AttachmentCommand::Add(
+            AddAttachment {
+                card_id,
+                attachment: attachment.clone(),
+                position: None,
+            },
+        ))])?;
+        Ok(attachment)
+    }
+
+    fn read_attachment(
+        &self,
+        card_id: Uuid,
+        attachment_id: Uuid,
+    ) -> KanbanResult<(Attachment, Vec<u8>)> {
+        let attachment = self
+            .get_card(card_id)?
+            .ok_or_else(|| KanbanError::not_found("Card", card_id))?
+            .attachments
+            .into_iter()
+            .find(|a| a.id == attachment_id)
+            .ok_or_else(|| KanbanError::not_found("Attachment", attachment_id))?;
+        let content = self.blob_store()?.get(&attachment.hash)?.ok_or_else(|| {
+            KanbanError::validation(format!(
+                "The content of '{}' is missing from the attachment store",
+                attachment.name
+            ))
+        })?;
+        Ok((attachment, content))
+    }
+
+    fn remove_attachment(&mut self, card_id: Uuid, attachment_id: Uuid) -> KanbanResult<Card> {
+        self.execute(vec![Command::Attachment(AttachmentCommand::Remove(
+            RemoveAttachment {
+                card_id,
+                attachment_id,
+            },
+        ))])?;
+        self.get_card(card_id)?
+            .ok_or_else(|| KanbanError::not_found("Card", card_id))
+    }
+
+    fn collect_attachment_garbage(&mut self, dry_run: bool) -> KanbanResult<AttachmentGcReport> {
+        self.collect_attachment_garbage_keeping(dry_run, &HashSet::new())
+    }
+
     fn archive_cards(&mut self, ids: Vec<Uuid>) -> KanbanResult<usize> {
         use kanban_domain::commands::ArchiveCards;
         let before = self.backend.list_archived_cards()?.len();
@@ -1258,45 +1784,170 @@ impl KanbanOperations for KanbanContext {
     }
 
     fn export_board(&self, board_id: Option<Uuid>) -> KanbanResult<String> {
-        let snapshot = if let Some(id) = board_id {
-            let boards: Vec<_> = self
-                .backend
-                .list_boards()?
-                .into_iter()
-                .filter(|b| b.id == id)
-                .collect();
-            let columns = self.backend.list_columns_by_board(id)?;
-            let column_ids: Vec<_> = columns.iter().map(|c| c.id).collect();
-            let cards: Vec<_> = self
-                .backend
-                .list_all_cards()?
-                .into_iter()
-                .filter(|c| column_ids.contains(&c.column_id))
-                .collect();
-            let sprints = self.backend.list_sprints_by_board(id)?;
-            let graph = self.backend.get_graph()?;
-            Snapshot {
-                boards,
-                columns,
-                cards,
-                archived_cards: vec![],
-                sprints,
-                graph,
-            }
-        } else {
-            self.backend.snapshot()?
-        };
+        self.export_board_as(board_id, BoardExportFormat::Json)
+    }
 
-        serde_json::to_string_pretty(&snapshot)
-            .map_err(|e| PersistenceError::Serialization(e.to_string()).into())
+    fn export_board_as(
+        &self,
+        board_id: Option<Uuid>,
+        format: BoardExportFormat,
+    ) -> KanbanResult<String> {
+        let snapshot = self.export_snapshot(board_id)?;
+        let prefixes = self.default_prefixes();
+        Ok(match format {
+            BoardExportFormat::Json => serde_json::to_string_pretty(&snapshot)
+                .map_err(|e| PersistenceError::Serialization(e.to_string()))?,
+            BoardExportFormat::Markdown => MarkdownExporter::export_boards(
+                &snapshot.boards,
+                &snapshot.columns,
+                &snapshot.cards,
+                &snapshot.sprints,
+                &snapshot.graph,
+                prefixes,
+            ),
+            BoardExportFormat::Csv => CsvExporter::export_cards(
+                &snapshot.boards,
+                &snapshot.columns,
+                &snapshot.cards,
+                &snapshot.sprints,
+                prefixes,
+            ),
+            BoardExportFormat::Ics => IcalExporter::export_calendar(
+                &snapshot.boards,
+                &snapshot.columns,
+                &snapshot.cards,
+                &snapshot.sprints,
+                prefixes,
+            ),
+            BoardExportFormat::Bundle => {
+                AttachmentBundle::build(snapshot, self.blob_store_opt())?.to_json()?
+            }
+        })
     }
 
     fn import_board(&mut self, data: &str) -> KanbanResult<Board> {
+        let imported = self.parse_import(data)?;
+        self.import_snapshot(imported)
+    }
+
+    fn import_board_as_copy(&mut self, data: &str) -> KanbanResult<Board> {
+        let imported = self.parse_import(data)?;
+        self.import_snapshot(BoardCopier::remap_ids(imported))
+    }
+
+    fn clone_board(
+        &mut self,
+        board_id: Uuid,
+        name: String,
+        options: CloneOptions,
+    ) -> KanbanResult<Board> {
+        let copy = BoardCopier::clone_board(&self.backend.snapshot()?, board_id, &name, options)?;
+        self.import_snapshot(copy)
+    }
+
+    fn import_cards_csv(
+        &mut self,
+        board_id: Option<Uuid>,
+        data: &str,
+        mapping: &CsvMapping,
+    ) -> KanbanResult<CsvImportSummary> {
+        let snapshot = self.backend.snapshot()?;
+        let plan = CsvImporter::plan(&snapshot, data, mapping, board_id, self.default_prefixes())?;
+        let summary = plan.summary();
+        if !plan.commands.is_empty() {
+            self.execute(plan.commands)?;
+        }
+        Ok(summary)
+    }
+
+    fn import_foreign(
+        &mut self,
+        source: ForeignSource,
+        data: &str,
+        options: ForeignImportOptions,
+    ) -> KanbanResult<ForeignImportReport> {
         use kanban_domain::commands::ImportEntities;
-        use std::collections::HashSet;
+        let position = self.backend.list_boards()?.len() as i32;
+        let ForeignImport {
+            snapshot,
+            mut report,
+        } = ForeignImporter::plan(source, data, &options, position)?;
+        if options.dry_run {
+            return Ok(report);
+        }
+        let id = snapshot.boards[0].id;
+        let cmd = Command::Board(BoardCommand::Import(ImportEntities {
+            boards: snapshot.boards,
+            columns: snapshot.columns,
+            cards: snapshot.cards,
+            archived_cards: Vec::new(),
+            sprints: Vec::new(),
+            graph: Some(snapshot.graph),
+        }));
+        self.execute(vec![cmd])?;
+        report.board_id = Some(id);
+        Ok(report)
+    }
+}
 
-        let imported: Snapshot = serde_json::from_str(data)
-            .map_err(|e| PersistenceError::Serialization(e.to_string()))?;
+impl KanbanContext {
+    fn default_prefixes(&self) -> DefaultPrefixes<'_> {
+        DefaultPrefixes {
+            card: self.app_config.effective_default_card_prefix(),
+            sprint: self.app_config.effective_default_sprint_prefix(),
+        }
+    }
+
+    /// One board and the cards, sprints and edges on it, or the whole
+    /// store when `board_id` is `None`.
+    fn export_snapshot(&self, board_id: Option<Uuid>) -> KanbanResult<Snapshot> {
+        let Some(id) = board_id else {
+            return self.backend.snapshot();
+        };
+        let boards: Vec<_> = self
+            .backend
+            .list_boards()?
+            .into_iter()
+            .filter(|b| b.id == id)
+            .collect();
+        let columns = self.backend.list_columns_by_board(id)?;
+        let column_ids: Vec<_> = columns.iter().map(|c| c.id).collect();
+        let cards: Vec<_> = self
+            .backend
+            .list_all_cards()?
+            .into_iter()
+            .filter(|c| column_ids.contains(&c.column_id))
+            .collect();
+        let archived_cards: Vec<_> = self
+            .backend
+            .list_archived_cards()?
+            .into_iter()
+            .filter(|ac| column_ids.contains(&ac.original_column_id))
+            .collect();
+        let sprints = self.backend.list_sprints_by_board(id)?;
+        let card_ids: std::collections::HashSet<Uuid> = cards
+            .iter()
+            .map(|c| c.id)
+            .chain(archived_cards.iter().map(|ac| ac.card.id))
+            .collect();
+        let graph = self.backend.get_graph()?.filter_edges(|source, target| {
+            card_ids.contains(&source) && card_ids.contains(&target)
+        });
+        Ok(Snapshot {
+            boards,
+            columns,
+            cards,
+            archived_cards,
+            sprints,
+            graph,
+        })
+    }
+
+    /// Append `imported` to the store and return its first board. Like
+    /// loading a file, this bypasses the undo stack and clears it.
+    fn import_snapshot(&mut self, imported: Snapshot) -> KanbanResult<Board> {
+        use kanban_domain::commands::ImportEntities;
+        use std::collections::HashSet;
 
         let board = imported
             .boards
@@ -1344,9 +1995,38 @@ impl KanbanOperations for KanbanContext {
 
         Ok(board)
     }
-}
 
-impl KanbanContext {
+    /// A plain JSON export, or a bundle whose attachment content is
+    /// stored first so the imported cards never point at missing blobs.
+    fn parse_import(&self, data: &str) -> KanbanResult<Snapshot> {
+        if let Some(bundle) = AttachmentBundle::parse(data)? {
+            return bundle.unpack(self.blob_store_opt());
+        }
+        serde_json::from_str(data)
+            .map_err(|e| PersistenceError::Serialization(e.to_string()).into())
+    }
+
+    fn blob_store(&self) -> KanbanResult<&dyn BlobStore> {
+        self.blob_store_opt()
+            .ok_or_else(|| KanbanError::validation("This storage backend cannot store attachments"))
+    }
+
+    fn blob_store_opt(&self) -> Option<&dyn BlobStore> {
+        match &self.blob_overlay {
+            Some(overlay) => Some(overlay.as_ref()),
+            None => self.backend.blob_store(),
+        }
+    }
+
+    fn checklist_item(&self, card_id: Uuid, item_id: Uuid) -> KanbanResult<ChecklistItem> {
+        self.get_card(card_id)?
+            .ok_or_else(|| KanbanError::not_found("Card", card_id))?
+            .checklist
+            .into_iter()
+            .find(|item| item.id == item_id)
+            .ok_or_else(|| KanbanError::not_found("Checklist item", item_id))
+    }
+
     /// Reject edge mutations against unknown card ids before the
     /// command reaches the graph. Without this guard a stale or
     /// fabricated UUID would silently land in the graph as a dangling
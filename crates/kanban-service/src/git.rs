@@ -0,0 +1,290 @@
+//! Read-only view of a local git repository, linked to cards.
+//!
+//! Cards name their branches `<identifier>/<kebab-title>` (see
+//! [`kanban_domain::Card::branch_name`]). [`GitRepository::card_status`]
+//! reports whether such a branch exists, how far it is ahead of or behind
+//! its base, and which commits mention the card identifier. The `git`
+//! binary does the reading, so any repository layout git understands works.
+
+use chrono::{DateTime, Utc};
+use kanban_domain::{KanbanError, KanbanResult};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Most commits listed per card.
+const MAX_COMMITS: usize = 50;
+
+/// A repository found by [`GitRepository::discover`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitRepository {
+    root: PathBuf,
+}
+
+/// Git state of one card.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CardGitStatus {
+    pub identifier: String,
+    /// The card's branch when it exists, otherwise the name it would have.
+    pub branch: String,
+    pub branch_exists: bool,
+    /// Upstream, `origin/HEAD` or local `main`/`master` the branch is
+    /// compared against.
+    pub base: Option<String>,
+    pub ahead: Option<usize>,
+    pub behind: Option<usize>,
+    /// Commits on any ref whose message mentions the identifier, newest first.
+    pub commits: Vec<GitCommit>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GitCommit {
+    pub hash: String,
+    pub short_hash: String,
+    pub summary: String,
+    pub author: String,
+    pub date: DateTime<Utc>,
+    /// Full commit message; the summary is its first line.
+    #[serde(skip)]
+    pub message: String,
+}
+
+impl GitRepository {
+    /// The repository containing `path`.
+    pub fn discover(path: &Path) -> KanbanResult<Self> {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(path)
+            .args(["rev-parse", "--show-toplevel"])
+            .output()
+            .map_err(|e| KanbanError::validation(format!("Could not run git: {}", e)))?;
+        if !output.status.success() {
+            return Err(KanbanError::validation(format!(
+                "{} is not inside a git repository",
+                path.display()
+            )));
+        }
+        let root = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Ok(Self {
+            root: PathBuf::from(root),
+        })
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Status of the card `identifier`, whose branch is named `branch_name`.
+    ///
+    /// A local branch named exactly `branch_name` wins; otherwise any local
+    /// branch named `identifier` or starting with `identifier/` matches, so
+    /// renaming the card does not lose its branch.
+    pub fn card_status(&self, identifier: &str, branch_name: &str) -> KanbanResult<CardGitStatus> {
+        let branch = self.find_branch(identifier, branch_name)?;
+        let (base, ahead, behind) = match &branch {
+            Some(branch) => match self.base_for(branch)? {
+                Some(base) => {
+                    let (behind, ahead) = self.ahead_behind(&base, branch)?;
+                    (Some(base), Some(ahead), Some(behind))
+                }
+                None => (None, None, None),
+            },
+            None => (None, None, None),
+        };
+        Ok(CardGitStatus {
+            identifier: identifier.to_string(),
+            branch_exists: branch.is_some(),
+            branch: branch.unwrap_or_else(|| branch_name.to_string()),
+            base,
+            ahead,
+            behind,
+            commits: self.commits_mentioning(identifier)?,
+        })
+    }
+
+    fn find_branch(&self, identifier: &str, branch_name: &str) -> KanbanResult<Option<String>> {
+        let listing = self.run(&["for-each-ref", "--format=%(refname:short)", "refs/heads/"])?;
+        let branches: Vec<&str> = listing.lines().collect();
+        if branches.contains(&branch_name) {
+            return Ok(Some(branch_name.to_string()));
+        }
+        let id = identifier.to_lowercase();
+        let prefix = format!("{}/", id);
+        Ok(branches
+            .into_iter()
+            .find(|b| {
+                let b = b.to_lowercase();
+                b == id || b.starts_with(&prefix)
+            })
+            .map(String::from))
+    }
+
+    fn base_for(&self, branch: &str) -> KanbanResult<Option<String>> {
+        let upstream = format!("{}@{{upstream}}", branch);
+        if let Some(base) = self.try_run(&["rev-parse", "--abbrev-ref", &upstream])? {
+            return Ok(Some(base));
+        }
+        if let Some(base) =
+            self.try_run(&["symbolic-ref", "--short", "refs/remotes/origin/HEAD"])?
+        {
+            return Ok(Some(base));
+        }
+        for candidate in ["main", "master"] {
+            let full = format!("refs/heads/{}", candidate);
+            if candidate != branch
+                && self
+                    .try_run(&["rev-parse", "--verify", "--quiet", &full])?
+                    .is_some()
+            {
+                return Ok(Some(candidate.to_string()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Commits only on `base` and only on `branch`.
+    fn ahead_behind(&self, base: &str, branch: &str) -> KanbanResult<(usize, usize)> {
+        let range = format!("{}...{}", base, branch);
+        let counts = self.run(&["rev-list", "--left-right", "--count", &range])?;
+        let mut parts = counts.split_whitespace().map(|n| n.parse().unwrap_or(0));
+        Ok((parts.next().unwrap_or(0), parts.next().unwrap_or(0)))
+    }
+
+    fn commits_mentioning(&self, identifier: &str) -> KanbanResult<Vec<GitCommit>> {
+        // A repository without commits has no refs for `git log --all`.
+        if self.run(&["for-each-ref", "--count=1"])?.is_empty() {
+            return Ok(Vec::new());
+        }
+        let grep = format!("--grep={}", identifier);
+        Ok(self
+            .log(&["--all", "--regexp-ignore-case", "--fixed-strings", &grep])?
+            .into_iter()
+            .filter(|commit| mentions(&commit.message, identifier))
+            .take(MAX_COMMITS)
+            .collect())
+    }
+
+    /// The commit `rev` names.
+    pub fn commit(&self, rev: &str) -> KanbanResult<GitCommit> {
+        self.log(&["-1", rev])?
+            .pop()
+            .ok_or_else(|| KanbanError::validation(format!("No commit '{}'", rev)))
+    }
+
+    /// Commits in `range` (e.g. `ORIG_HEAD..HEAD`), oldest first.
+    pub fn commits(&self, range: &str) -> KanbanResult<Vec<GitCommit>> {
+        let mut commits = self.log(&[range])?;
+        commits.reverse();
+        Ok(commits)
+    }
+
+    /// Local branches reachable from `HEAD` that were not reachable from
+    /// `since`, other than the current branch: the branches a merge or
+    /// fast-forward just brought in.
+    pub fn merged_branches(&self, since: &str) -> KanbanResult<Vec<String>> {
+        let current = self.try_run(&["symbolic-ref", "--short", "HEAD"])?;
+        let no_merged = format!("--no-merged={}", since);
+        let listing = self.run(&[
+            "for-each-ref",
+            "--format=%(refname:short)",
+            "--merged=HEAD",
+            &no_merged,
+            "refs/heads/",
+        ])?;
+        Ok(listing
+            .lines()
+            .filter(|b| Some(*b) != current.as_deref())
+            .map(String::from)
+            .collect())
+    }
+
+    /// Directory git runs hooks from, honouring `core.hooksPath`.
+    pub fn hooks_dir(&self) -> KanbanResult<PathBuf> {
+        let path = PathBuf::from(self.run(&["rev-parse", "--git-path", "hooks"])?);
+        Ok(if path.is_absolute() {
+            path
+        } else {
+            self.root.join(path)
+        })
+    }
+
+    fn log(&self, args: &[&str]) -> KanbanResult<Vec<GitCommit>> {
+        let mut command = vec!["log", "--format=%H%x1f%h%x1f%an%x1f%aI%x1f%B%x1e"];
+        command.extend_from_slice(args);
+        let log = self.run(&command)?;
+        Ok(log
+            .split('\u{1e}')
+            .filter_map(|record| {
+                let mut fields = record.trim_start_matches('\n').splitn(5, '\u{1f}');
+                let hash = fields.next()?.to_string();
+                let short_hash = fields.next()?.to_string();
+                let author = fields.next()?.to_string();
+                let date = DateTime::parse_from_rfc3339(fields.next()?).ok()?;
+                let message = fields.next()?.trim_end().to_string();
+                Some(GitCommit {
+                    hash,
+                    short_hash,
+                    summary: message.lines().next().unwrap_or_default().to_string(),
+                    author,
+                    date: date.with_timezone(&Utc),
+                    message,
+                })
+            })
+            .collect())
+    }
+
+    /// Stdout of a git command that must succeed.
+    fn run(&self, args: &[&str]) -> KanbanResult<String> {
+        let output = self.command(args)?;
+        if !output.status.success() {
+            return Err(KanbanError::validation(format!(
+                "git {} failed: {}",
+                args.first().copied().unwrap_or_default(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    /// Stdout of a git command, or `None` when it fails or prints nothing.
+    fn try_run(&self, args: &[&str]) -> KanbanResult<Option<String>> {
+        let output = self.command(args)?;
+        let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Ok((output.status.success() && !stdout.is_empty()).then_some(stdout))
+    }
+
+    fn command(&self, args: &[&str]) -> KanbanResult<std::process::Output> {
+        Command::new("git")
+            .arg("-C")
+            .arg(&self.root)
+            .args(args)
+            .output()
+            .map_err(|e| KanbanError::validation(format!("Could not run git: {}", e)))
+    }
+}
+
+/// Whether `message` mentions `identifier` as a whole word, so `KAN-4`
+/// does not match `KAN-42`.
+fn mentions(message: &str, identifier: &str) -> bool {
+    let message = message.to_lowercase();
+    let identifier = identifier.to_lowercase();
+    message.match_indices(&identifier).any(|(start, _)| {
+        let before = message[..start].chars().next_back();
+        let after = message[start + identifier.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mentions_requires_whole_identifier() {
+        assert!(mentions("KAN-42: fix login", "KAN-42"));
+        assert!(mentions("fix login (kan-42)", "KAN-42"));
+        assert!(!mentions("KAN-421 follow-up", "KAN-42"));
+        assert!(!mentions("XKAN-42", "KAN-42"));
+        assert!(!mentions("KAN-4 only", "KAN-42"));
+    }
+}
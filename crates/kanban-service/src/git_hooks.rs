@@ -0,0 +1,434 @@
+//! Git hooks that keep cards in step with commits and merges.
+//!
+//! `kanban git install-hooks` writes `commit-msg`, `post-commit` and
+//! `post-merge` hooks that call back into the CLI. Commit messages are
+//! scanned for keywords followed by card identifiers:
+//!
+//! - `Closes KAN-42`, `Fixes`, `Resolves`: move the card to its board's
+//!   completion column.
+//! - `WIP KAN-9`, `Starts`: move the card to an in-progress column and mark
+//!   it in progress.
+//! - `Refs KAN-7`, `See`, `Relates to`: note the commit in the card's
+//!   activity log.
+//!
+//! Merging a card's branch completes the card, as `Closes` would. Every
+//! change also records an activity entry from `git` naming the commit or
+//! branch, in the same undoable batch as the change itself.
+
+use crate::git::{GitCommit, GitRepository};
+use crate::KanbanContext;
+use kanban_domain::commands::{ActivityCommand, CardCommand, Command, RecordActivity, UpdateCard};
+use kanban_domain::{
+    ActivityEntry, Card, CardStatus, CardUpdate, KanbanError, KanbanOperations, KanbanResult,
+    GIT_ACTIVITY_SOURCE,
+};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// Hooks written by [`install`].
+pub const HOOKS: &[&str] = &["commit-msg", "post-commit", "post-merge"];
+
+/// First line after the shebang of every hook [`install`] writes, so a
+/// reinstall can tell its own hooks from ones it must not overwrite.
+const HOOK_MARKER: &str = "# Installed by `kanban git install-hooks`.";
+
+/// Column names, compared without case or punctuation, that count as the
+/// in-progress column for `WIP`/`Starts`.
+const IN_PROGRESS_COLUMNS: &[&str] = &["inprogress", "doing", "wip", "active", "started"];
+
+/// What a keyword in a commit message does to the cards after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommitKeyword {
+    Reference,
+    Start,
+    Close,
+}
+
+impl CommitKeyword {
+    fn parse(word: &str) -> Option<Self> {
+        match word.to_lowercase().as_str() {
+            "close" | "closes" | "closed" | "fix" | "fixes" | "fixed" | "resolve" | "resolves"
+            | "resolved" => Some(Self::Close),
+            "wip" | "start" | "starts" | "started" => Some(Self::Start),
+            "ref" | "refs" | "references" | "see" | "relates" | "related" => Some(Self::Reference),
+            _ => None,
+        }
+    }
+}
+
+/// A card identifier named after a keyword in a commit message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CardReference {
+    pub keyword: CommitKeyword,
+    pub identifier: String,
+}
+
+/// Card references in `message`, one per identifier. An identifier named
+/// after several keywords keeps the strongest: close, then start, then
+/// reference.
+///
+/// A keyword applies to the identifiers right after it, so
+/// `Fixes KAN-1, KAN-2 and KAN-3` names three cards. Words between the
+/// keyword and the first identifier other than `to` end the list.
+pub fn parse_references(message: &str) -> Vec<CardReference> {
+    let mut references: Vec<CardReference> = Vec::new();
+    let mut keyword = None;
+    for token in message.split_whitespace() {
+        let word = token.trim_matches(|c: char| !c.is_alphanumeric() && c != '-');
+        if let Some(k) = CommitKeyword::parse(word) {
+            keyword = Some(k);
+            continue;
+        }
+        let Some(k) = keyword else {
+            continue;
+        };
+        if is_identifier(word) {
+            match references
+                .iter_mut()
+                .find(|r| r.identifier.eq_ignore_ascii_case(word))
+            {
+                Some(existing) => existing.keyword = existing.keyword.max(k),
+                None => references.push(CardReference {
+                    keyword: k,
+                    identifier: word.to_string(),
+                }),
+            }
+        } else if !matches!(word.to_lowercase().as_str(), "and" | "to" | "") {
+            keyword = None;
+        }
+    }
+    references
+}
+
+/// `PREFIX-N`, with a prefix that starts with a letter.
+fn is_identifier(word: &str) -> bool {
+    let Some((prefix, number)) = word.rsplit_once('-') else {
+        return false;
+    };
+    prefix.starts_with(|c: char| c.is_ascii_alphabetic())
+        && prefix
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        && !number.is_empty()
+        && number.chars().all(|c| c.is_ascii_digit())
+}
+
+/// The card identifier a branch is named after: `KAN-42` for
+/// `KAN-42/login-form` or `KAN-42`.
+pub fn branch_identifier(branch: &str) -> Option<&str> {
+    let head = branch.split('/').next()?;
+    is_identifier(head).then_some(head)
+}
+
+/// Body of a commit message file, without the `#` comment lines git adds.
+pub fn strip_comments(message: &str) -> String {
+    message
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Whether `name`, a column name, reads as an in-progress column.
+pub fn is_in_progress_column(name: &str) -> bool {
+    let normalized: String = name
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<String>()
+        .to_lowercase();
+    IN_PROGRESS_COLUMNS.contains(&normalized.as_str())
+}
+
+/// A card a hook changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GitCardAction {
+    pub identifier: String,
+    pub card_id: Uuid,
+    pub action: CommitKeyword,
+    /// Short hash of the commit naming the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    /// Merged branch the card was completed for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+}
+
+/// What a hook did to the board.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct GitSyncReport {
+    pub actions: Vec<GitCardAction>,
+    /// Identifiers that matched no card, or more than one.
+    pub unknown: Vec<String>,
+}
+
+/// Apply the keywords in `commits`, oldest first, then complete the cards
+/// whose branches are in `merged_branches`.
+///
+/// Each card change is one ordinary batch, undoable like any other, that
+/// also records a `git` activity entry on the card. Changes already made
+/// are skipped, so replaying a commit (say from both `post-commit` and a
+/// later `post-merge`) does nothing twice.
+pub fn apply(
+    ctx: &mut KanbanContext,
+    commits: &[GitCommit],
+    merged_branches: &[String],
+) -> KanbanResult<GitSyncReport> {
+    let mut report = GitSyncReport::default();
+    for commit in commits {
+        for reference in parse_references(&commit.message) {
+            apply_one(ctx, &mut report, &reference, Some(commit), None)?;
+        }
+    }
+    for branch in merged_branches {
+        if let Some(identifier) = branch_identifier(branch) {
+            let reference = CardReference {
+                keyword: CommitKeyword::Close,
+                identifier: identifier.to_string(),
+            };
+            apply_one(ctx, &mut report, &reference, None, Some(branch))?;
+        }
+    }
+    Ok(report)
+}
+
+fn apply_one(
+    ctx: &mut KanbanContext,
+    report: &mut GitSyncReport,
+    reference: &CardReference,
+    commit: Option<&GitCommit>,
+    branch: Option<&str>,
+) -> KanbanResult<()> {
+    let matches = ctx.find_cards_by_identifier(&reference.identifier)?;
+    let [card] = matches.as_slice() else {
+        if !report.unknown.contains(&reference.identifier) {
+            report.unknown.push(reference.identifier.clone());
+        }
+        return Ok(());
+    };
+    let board_id = ctx
+        .get_column(card.column_id)?
+        .ok_or_else(|| KanbanError::not_found("Column", card.column_id))?
+        .board_id;
+    let board = ctx
+        .get_board(board_id)?
+        .ok_or_else(|| KanbanError::not_found("Board", board_id))?;
+    let columns = ctx.list_columns(board_id)?;
+
+    let mut batch = match reference.keyword {
+        CommitKeyword::Close => match board.resolve_completion_column(&columns) {
+            Some(done) if done != card.column_id => ctx.move_card_commands(card.id, done, None)?,
+            _ => return Ok(()),
+        },
+        CommitKeyword::Start => {
+            if matches!(card.status, CardStatus::InProgress | CardStatus::Done) {
+                return Ok(());
+            }
+            let target = columns
+                .iter()
+                .filter(|c| is_in_progress_column(&c.name))
+                .min_by_key(|c| c.position);
+            let mut batch = match target.filter(|c| c.id != card.column_id) {
+                Some(column) => ctx.move_card_commands(card.id, column.id, None)?,
+                None => Vec::new(),
+            };
+            let sets_status = batch.iter().any(|cmd| {
+                matches!(cmd, Command::Card(CardCommand::Update(u)) if u.updates.status.is_some())
+            });
+            if !sets_status {
+                batch.push(Command::Card(CardCommand::Update(UpdateCard {
+                    card_id: card.id,
+                    updates: CardUpdate {
+                        status: Some(CardStatus::InProgress),
+                        ..Default::default()
+                    },
+                })));
+            }
+            batch
+        }
+        CommitKeyword::Reference => match commit {
+            Some(commit) if !has_activity_for(card, &commit.hash) => Vec::new(),
+            _ => return Ok(()),
+        },
+    };
+    batch.push(Command::Activity(ActivityCommand::Record(RecordActivity {
+        card_id: card.id,
+        entry: activity_entry(reference.keyword, commit, branch),
+        position: None,
+    })));
+    ctx.execute(batch)?;
+
+    report.actions.push(GitCardAction {
+        identifier: reference.identifier.clone(),
+        card_id: card.id,
+        action: reference.keyword,
+        commit: commit.map(|c| c.short_hash.clone()),
+        branch: branch.map(String::from),
+    });
+    Ok(())
+}
+
+fn has_activity_for(card: &Card, reference: &str) -> bool {
+    card.activity.iter().any(|entry| {
+        entry.source == GIT_ACTIVITY_SOURCE && entry.reference.as_deref() == Some(reference)
+    })
+}
+
+/// Activity entry recording what `keyword` did for `commit`, or for the
+/// merge of `branch`. The reference is the full commit hash or the branch.
+fn activity_entry(
+    keyword: CommitKeyword,
+    commit: Option<&GitCommit>,
+    branch: Option<&str>,
+) -> ActivityEntry {
+    let verb = match keyword {
+        CommitKeyword::Close => "Completed",
+        CommitKeyword::Start => "Started",
+        CommitKeyword::Reference => "Referenced",
+    };
+    let (message, reference) = match commit {
+        Some(commit) => (
+            format!(
+                "{} by commit {}: {}",
+                verb, commit.short_hash, commit.summary
+            ),
+            commit.hash.clone(),
+        ),
+        None => {
+            let branch = branch.unwrap_or_default();
+            (
+                format!("{} by merging branch {}", verb, branch),
+                branch.to_string(),
+            )
+        }
+    };
+    ActivityEntry::new(GIT_ACTIVITY_SOURCE, message, chrono::Utc::now()).with_reference(reference)
+}
+
+/// Write the hooks into `repo`, calling `kanban` on `data_file`.
+///
+/// Existing hooks not written by this command are left alone unless
+/// `force` is set. Returns the paths written.
+pub fn install(
+    repo: &GitRepository,
+    kanban: &Path,
+    data_file: &Path,
+    force: bool,
+) -> KanbanResult<Vec<PathBuf>> {
+    let dir = repo.hooks_dir()?;
+    std::fs::create_dir_all(&dir)?;
+    let paths: Vec<PathBuf> = HOOKS.iter().map(|hook| dir.join(hook)).collect();
+    if !force {
+        let foreign: Vec<String> = paths
+            .iter()
+            .filter(|path| {
+                std::fs::read_to_string(path).is_ok_and(|body| !body.contains(HOOK_MARKER))
+            })
+            .map(|path| path.display().to_string())
+            .collect();
+        if !foreign.is_empty() {
+            return Err(KanbanError::validation(format!(
+                "Hooks already exist: {}. Pass --force to replace them",
+                foreign.join(", ")
+            )));
+        }
+    }
+    for (hook, path) in HOOKS.iter().zip(&paths) {
+        std::fs::write(path, hook_script(hook, kanban, data_file))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))?;
+        }
+    }
+    Ok(paths)
+}
+
+/// Shell script for `hook`. Board updates never fail a commit or merge,
+/// but a failed update is reported on stderr.
+fn hook_script(hook: &str, kanban: &Path, data_file: &Path) -> String {
+    let args = if hook == "commit-msg" { " \"$1\"" } else { "" };
+    format!(
+        concat!(
+            "#!/bin/sh\n{}\n",
+            "{} {} git {}{} >/dev/null ||\n",
+            "    echo \"kanban: {} hook failed; the board was not updated\" >&2\n",
+            "exit 0\n",
+        ),
+        HOOK_MARKER,
+        shell_quote(&kanban.to_string_lossy()),
+        shell_quote(&data_file.to_string_lossy()),
+        hook,
+        args,
+        hook
+    )
+}
+
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_references_reads_keyword_lists() {
+        let parsed = parse_references("Fixes KAN-1, KAN-2 and kan-3.\n\nRefs: OPS-10");
+        let found: Vec<_> = parsed
+            .iter()
+            .map(|r| (r.keyword, r.identifier.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (CommitKeyword::Close, "KAN-1"),
+                (CommitKeyword::Close, "KAN-2"),
+                (CommitKeyword::Close, "kan-3"),
+                (CommitKeyword::Reference, "OPS-10"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_references_keeps_strongest_keyword() {
+        let parsed = parse_references("WIP KAN-9 (see KAN-9), closes KAN-9");
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].keyword, CommitKeyword::Close);
+        assert_eq!(
+            parse_references("Relates to KAN-4")[0].keyword,
+            CommitKeyword::Reference
+        );
+    }
+
+    #[test]
+    fn test_parse_references_ignores_unkeyworded_identifiers() {
+        assert!(parse_references("Bump KAN-1 deps; fixed the build for KAN-2").is_empty());
+        assert!(parse_references("Closes #12").is_empty());
+    }
+
+    #[test]
+    fn test_branch_identifier_and_columns() {
+        assert_eq!(branch_identifier("KAN-42/login-form"), Some("KAN-42"));
+        assert_eq!(branch_identifier("KAN-42"), Some("KAN-42"));
+        assert_eq!(branch_identifier("feature/KAN-42"), None);
+        assert!(is_in_progress_column("In Progress"));
+        assert!(is_in_progress_column("in-progress"));
+        assert!(!is_in_progress_column("Done"));
+    }
+
+    #[test]
+    fn test_hook_script_quotes_paths() {
+        let script = hook_script(
+            "commit-msg",
+            Path::new("/usr/bin/kanban"),
+            Path::new("/tmp/it's.json"),
+        );
+        assert!(script.starts_with("#!/bin/sh\n"));
+        assert!(script.contains(HOOK_MARKER));
+        assert!(script.contains("'/tmp/it'\\''s.json' git commit-msg \"$1\""));
+        assert!(script.contains("commit-msg hook failed; the board was not updated\" >&2"));
+        assert!(!script.contains("|| true"));
+    }
+}
@@ -7,8 +7,8 @@ use kanban_domain::{
     KanbanError, KanbanResult, Snapshot, Sprint,
 };
 use kanban_persistence::{
-    snapshot_from_json_bytes, snapshot_to_json_bytes, PersistenceMetadata, PersistenceStore,
-    StoreSnapshot,
+    snapshot_from_json_bytes, snapshot_to_json_bytes, BlobStore, DirBlobStore, PersistenceMetadata,
+    PersistenceStore, StoreSnapshot,
 };
 use std::sync::{
     atomic::{AtomicBool, Ordering},
@@ -31,11 +31,16 @@ pub struct JsonDataStore {
     /// diagnostics panel.
     last_metadata: RwLock<Option<PersistenceMetadata>>,
     dirty: AtomicBool,
+    /// Attachment content in `<file>.attachments/`. Written through
+    /// immediately rather than on flush: blobs are immutable and
+    /// unreferenced ones are harmless until garbage-collected.
+    blobs: DirBlobStore,
 }
 
 impl JsonDataStore {
     pub fn new(file_store: Arc<dyn PersistenceStore + Send + Sync>) -> Self {
         Self {
+            blobs: DirBlobStore::beside(file_store.path()),
             file_store,
             inner: RwLock::new(None),
             last_metadata: RwLock::new(None),
@@ -364,6 +369,10 @@ impl KanbanBackend for JsonDataStore {
         // cache as a side effect of any DataStore call, which is enough.
         self.last_metadata.read().ok().and_then(|g| g.clone())
     }
+
+    fn blob_store(&self) -> Option<&dyn BlobStore> {
+        Some(&self.blobs)
+    }
 }
 
 #[cfg(test)]
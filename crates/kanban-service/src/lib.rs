@@ -10,20 +10,29 @@
 //! [`backend::KanbanBackend::append_commands`]) is a separate
 //! append-only record of executed batches.
 
+pub mod attachment_files;
 pub mod backend;
+pub mod bundle;
+pub mod calendar_feed;
 mod cascade;
 pub mod config;
 mod context;
+pub mod git;
+pub mod git_hooks;
 #[cfg(feature = "json")]
 pub mod json_backend;
 mod path;
+mod preview;
 #[cfg(feature = "sqlite")]
 pub mod sqlite_backend;
 mod store_manager;
+pub mod templates;
 pub mod undo_stack;
 pub use backend::KanbanBackend;
 pub use config::AppConfigDto;
-pub use context::{BatchOperationFailure, BatchOperationResult, KanbanContext};
+pub use context::{
+    BatchOperationFailure, BatchOperationResult, KanbanContext, NewCard, NewCardBatch, NewCardRef,
+};
 pub use path::validate_path;
 pub use store_manager::StoreManager;
 
@@ -0,0 +1,103 @@
+//! Blob storage for [`KanbanContext::preview`](crate::KanbanContext::preview).
+//!
+//! A preview runs writes against a scratch copy of the data, but attaching
+//! or collecting a file also touches the blob store. The overlay reads
+//! through to the real store and keeps every write in memory, so a preview
+//! can show what a write would do without storing or deleting content.
+
+use crate::backend::KanbanBackend;
+use kanban_domain::KanbanResult;
+use kanban_persistence::{blob_hash, BlobInfo, BlobStore};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+
+pub(crate) struct OverlayBlobStore {
+    base: Arc<dyn KanbanBackend>,
+    added: Mutex<BTreeMap<String, Vec<u8>>>,
+    deleted: Mutex<BTreeSet<String>>,
+}
+
+impl OverlayBlobStore {
+    pub(crate) fn new(base: Arc<dyn KanbanBackend>) -> Self {
+        Self {
+            base,
+            added: Mutex::new(BTreeMap::new()),
+            deleted: Mutex::new(BTreeSet::new()),
+        }
+    }
+
+    fn base(&self) -> Option<&dyn BlobStore> {
+        self.base.blob_store()
+    }
+
+    fn is_deleted(&self, hash: &str) -> bool {
+        self.deleted.lock().unwrap().contains(hash)
+    }
+}
+
+impl BlobStore for OverlayBlobStore {
+    fn put(&self, bytes: &[u8]) -> KanbanResult<String> {
+        let hash = blob_hash(bytes);
+        self.deleted.lock().unwrap().remove(&hash);
+        self.added
+            .lock()
+            .unwrap()
+            .entry(hash.clone())
+            .or_insert_with(|| bytes.to_vec());
+        Ok(hash)
+    }
+
+    fn get(&self, hash: &str) -> KanbanResult<Option<Vec<u8>>> {
+        if let Some(bytes) = self.added.lock().unwrap().get(hash) {
+            return Ok(Some(bytes.clone()));
+        }
+        if self.is_deleted(hash) {
+            return Ok(None);
+        }
+        match self.base() {
+            Some(base) => base.get(hash),
+            None => Ok(None),
+        }
+    }
+
+    fn contains(&self, hash: &str) -> KanbanResult<bool> {
+        if self.added.lock().unwrap().contains_key(hash) {
+            return Ok(true);
+        }
+        if self.is_deleted(hash) {
+            return Ok(false);
+        }
+        match self.base() {
+            Some(base) => base.contains(hash),
+            None => Ok(false),
+        }
+    }
+
+    fn list(&self) -> KanbanResult<Vec<BlobInfo>> {
+        let added = self.added.lock().unwrap();
+        let deleted = self.deleted.lock().unwrap();
+        let mut blobs: Vec<BlobInfo> = match self.base() {
+            Some(base) => base
+                .list()?
+                .into_iter()
+                .filter(|b| !deleted.contains(&b.hash) && !added.contains_key(&b.hash))
+                .collect(),
+            None => Vec::new(),
+        };
+        blobs.extend(added.iter().map(|(hash, bytes)| BlobInfo {
+            hash: hash.clone(),
+            size: bytes.len() as u64,
+        }));
+        blobs.sort_by(|a, b| a.hash.cmp(&b.hash));
+        Ok(blobs)
+    }
+
+    fn delete(&self, hash: &str) -> KanbanResult<bool> {
+        let existed = self.contains(hash)?;
+        self.added.lock().unwrap().remove(hash);
+        if existed {
+            self.deleted.lock().unwrap().insert(hash.to_string());
+        }
+        Ok(existed)
+    }
+}
@@ -7,7 +7,7 @@ use kanban_domain::{
     ArchivedCard, Board, Card, Column, DependencyGraph, GraphMutFn, InMemoryStore, KanbanError,
     KanbanResult, Snapshot, Sprint,
 };
-use kanban_persistence::{PersistenceMetadata, PersistenceStore};
+use kanban_persistence::{BlobStore, PersistenceMetadata, PersistenceStore};
 use kanban_persistence_sqlite::SqliteStore;
 use uuid::Uuid;
 
@@ -226,4 +226,8 @@ impl crate::backend::KanbanBackend for SqliteBackend {
     fn persistence_metadata(&self) -> Option<PersistenceMetadata> {
         self.last_metadata.read().ok().and_then(|g| g.clone())
     }
+
+    fn blob_store(&self) -> Option<&dyn BlobStore> {
+        Some(&self.db)
+    }
 }
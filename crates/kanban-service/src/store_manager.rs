@@ -2,7 +2,8 @@ use crate::config;
 use crate::AppConfig;
 use kanban_domain::{DataStore, KanbanError};
 use kanban_persistence::{
-    snapshot_from_json_bytes, PersistenceStore, StoreRegistry, StoreSnapshot,
+    copy_blobs, snapshot_from_json_bytes, BlobStore, DirBlobStore, PersistenceStore, StoreRegistry,
+    StoreSnapshot,
 };
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -197,9 +198,6 @@ impl StoreManager {
     }
 
     /// Exports a board selection to a new SQLite file via `SqliteStore`.
-    ///
-    /// **Note:** The dependency graph is not part of the `AllBoardsExport` format
-    /// and will not be present in the exported file.
     pub async fn export_to_sqlite(
         &self,
         export: kanban_domain::export::AllBoardsExport,
@@ -208,16 +206,16 @@ impl StoreManager {
         #[cfg(feature = "sqlite")]
         {
             use kanban_domain::export::BoardImporter;
-            use kanban_domain::{DependencyGraph, Snapshot};
+            use kanban_domain::Snapshot;
 
-            let entities = BoardImporter::extract_entities(export);
+            let entities = BoardImporter::extract_entities(export)?;
             let snapshot = Snapshot {
                 boards: entities.boards,
                 columns: entities.columns,
                 cards: entities.cards,
                 archived_cards: entities.archived_cards,
                 sprints: entities.sprints,
-                graph: DependencyGraph::default(),
+                graph: entities.graph,
             };
             let store = kanban_persistence_sqlite::SqliteStore::open(filename).await?;
             store.apply_snapshot(snapshot)?;
@@ -319,10 +317,58 @@ impl StoreManager {
                 }
             }
         }
+
+        if let Err(e) = copy_attachment_blobs(from_backend, from_path, to_backend, to_path).await {
+            cleanup_destination_files(to_path).await;
+            let _ = std::fs::remove_dir_all(DirBlobStore::beside(to).dir());
+            return Err(e);
+        }
         Ok(())
     }
 }
 
+/// Blob store of a built-in backend's data file, or `None` for backends
+/// that keep no attachment content.
+async fn open_blob_store(
+    backend: &str,
+    path: &str,
+) -> Result<Option<Box<dyn BlobStore>>, KanbanError> {
+    match backend {
+        #[cfg(feature = "sqlite")]
+        "sqlite" | "sqlite3" | "db" => Ok(Some(Box::new(
+            kanban_persistence_sqlite::SqliteStore::open(path).await?,
+        ))),
+        "json" => Ok(Some(Box::new(DirBlobStore::beside(std::path::Path::new(
+            path,
+        ))))),
+        _ => Ok(None),
+    }
+}
+
+/// Carry attachment content over to a migrated data file.
+async fn copy_attachment_blobs(
+    from_backend: &str,
+    from_path: &str,
+    to_backend: &str,
+    to_path: &str,
+) -> Result<(), KanbanError> {
+    let Some(from) = open_blob_store(from_backend, from_path).await? else {
+        return Ok(());
+    };
+    if from.list()?.is_empty() {
+        return Ok(());
+    }
+    let Some(to) = open_blob_store(to_backend, to_path).await? else {
+        return Err(KanbanError::validation(format!(
+            "The {} backend cannot store attachments",
+            to_backend
+        )));
+    };
+    let copied = copy_blobs(from.as_ref(), to.as_ref())?;
+    tracing::info!(copied, "copied attachment content to migrated file");
+    Ok(())
+}
+
 impl Clone for StoreManager {
     fn clone(&self) -> Self {
         Self {
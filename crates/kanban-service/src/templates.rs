@@ -0,0 +1,171 @@
+//! Finding, loading and saving board templates.
+//!
+//! A template name is a built-in name, the name of a file in the
+//! `templates/` directory next to the config file, or a path to a TOML or
+//! JSON template file. Files in the directory shadow built-ins of the same
+//! name.
+
+use kanban_domain::{BoardTemplate, KanbanError, KanbanResult, BUILTIN_BOARD_TEMPLATES};
+use std::path::{Path, PathBuf};
+
+pub fn templates_dir() -> Option<PathBuf> {
+    crate::config::config_path().and_then(|p| p.parent().map(|dir| dir.join("templates")))
+}
+
+/// Load the named template.
+pub fn load(name: &str) -> KanbanResult<BoardTemplate> {
+    load_from(name, templates_dir().as_deref())
+}
+
+/// Names of the built-in templates followed by those in the templates
+/// directory, without duplicates.
+pub fn list() -> Vec<String> {
+    list_from(templates_dir().as_deref())
+}
+
+/// Write `template` to the templates directory as `<name>.toml` and return
+/// the path written.
+pub fn save(template: &BoardTemplate) -> KanbanResult<PathBuf> {
+    let dir = templates_dir()
+        .ok_or_else(|| KanbanError::validation("No configuration directory available"))?;
+    save_to(template, &dir)
+}
+
+fn template_file_path(name: &str, dir: Option<&Path>) -> Option<PathBuf> {
+    let as_path = Path::new(name);
+    let has_ext = as_path
+        .extension()
+        .is_some_and(|e| e == "toml" || e == "json");
+    if has_ext || as_path.components().count() > 1 {
+        return Some(as_path.to_path_buf());
+    }
+    dir.map(|d| d.join(format!("{}.toml", name)))
+}
+
+pub fn load_from(name: &str, dir: Option<&Path>) -> KanbanResult<BoardTemplate> {
+    let path = template_file_path(name, dir);
+    let template = match path {
+        Some(ref path) if path.exists() => {
+            let content = std::fs::read_to_string(path)?;
+            let parsed = if path.extension().is_some_and(|e| e == "json") {
+                serde_json::from_str(&content).map_err(|e| e.to_string())
+            } else {
+                toml::from_str(&content).map_err(|e| e.to_string())
+            };
+            parsed.map_err(|e| {
+                KanbanError::validation(format!(
+                    "Failed to parse template {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?
+        }
+        _ => BoardTemplate::builtin(name).ok_or_else(|| {
+            KanbanError::validation(format!(
+                "Unknown template '{}'; available: {}",
+                name,
+                list_from(dir).join(", ")
+            ))
+        })?,
+    };
+    template.validate()?;
+    Ok(template)
+}
+
+pub fn list_from(dir: Option<&Path>) -> Vec<String> {
+    let mut names: Vec<String> = BUILTIN_BOARD_TEMPLATES
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let mut found: Vec<String> = dir
+        .and_then(|d| std::fs::read_dir(d).ok())
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension().is_some_and(|e| e == "toml") {
+                path.file_stem().and_then(|s| s.to_str()).map(String::from)
+            } else {
+                None
+            }
+        })
+        .filter(|name| !names.contains(name))
+        .collect();
+    found.sort();
+    names.extend(found);
+    names
+}
+
+pub fn save_to(template: &BoardTemplate, dir: &Path) -> KanbanResult<PathBuf> {
+    template.validate()?;
+    let name = &template.name;
+    if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+        return Err(KanbanError::validation(format!(
+            "Invalid template name '{}': must be a plain file name",
+            name
+        )));
+    }
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(format!("{}.toml", template.name));
+    let content = toml::to_string_pretty(template)
+        .map_err(|e| KanbanError::Serialization(format!("Failed to serialize template: {}", e)))?;
+    std::fs::write(&path, content)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kanban_domain::CardPriority;
+
+    #[test]
+    fn test_load_builtin_and_unknown() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(load_from("scrum", Some(dir.path())).unwrap().name, "scrum");
+        let err = load_from("nope", Some(dir.path())).unwrap_err().to_string();
+        assert!(err.contains("basic, kanban, scrum"), "{}", err);
+    }
+
+    #[test]
+    fn test_save_then_load_and_list() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut template = BoardTemplate::builtin("kanban").unwrap();
+        template.name = "client".to_string();
+        template.cards = vec![toml::from_str(
+            r#"
+            title = "Kick-off"
+            priority = "High"
+            column = "Ready"
+            "#,
+        )
+        .unwrap()];
+
+        let path = save_to(&template, dir.path()).unwrap();
+        assert_eq!(path, dir.path().join("client.toml"));
+        let loaded = load_from("client", Some(dir.path())).unwrap();
+        assert_eq!(loaded, template);
+        assert_eq!(loaded.cards[0].priority, Some(CardPriority::High));
+        assert_eq!(load_from(path.to_str().unwrap(), None).unwrap(), template);
+
+        assert_eq!(
+            list_from(Some(dir.path())),
+            vec!["basic", "kanban", "scrum", "client"]
+        );
+    }
+
+    #[test]
+    fn test_load_rejects_invalid_template_file() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("broken.toml"),
+            "name = \"broken\"\ncolumns = []\n",
+        )
+        .unwrap();
+        assert!(load_from("broken", Some(dir.path())).is_err());
+
+        let mut template = BoardTemplate::builtin("basic").unwrap();
+        template.name = "../escape".to_string();
+        assert!(save_to(&template, dir.path()).is_err());
+    }
+}
@@ -77,6 +77,13 @@ impl UndoStack {
         self.cursor = 0;
     }
 
+    /// Every forward and inverse command still reachable by undo or redo.
+    pub fn commands(&self) -> impl Iterator<Item = &Command> {
+        self.entries
+            .iter()
+            .flat_map(|entry| entry.forward.iter().chain(&entry.inverse))
+    }
+
     pub fn undo_depth(&self) -> usize {
         self.cursor
     }
@@ -0,0 +1,100 @@
+//! Attachment content lifecycle: undoable removal, garbage collection, and
+//! backends without a blob store.
+
+use kanban_core::AppConfig;
+use kanban_domain::{InMemoryStore, KanbanOperations, KanbanResult};
+use kanban_service::KanbanContext;
+use std::sync::Arc;
+use uuid::Uuid;
+
+async fn card_in(ctx: &mut KanbanContext) -> KanbanResult<Uuid> {
+    let board = ctx.create_board("Bugs".into(), None)?;
+    let column = ctx.create_column(board.id, "Todo".into(), None)?;
+    let card = ctx.create_card(board.id, column.id, "Crash".into(), Default::default())?;
+    Ok(card.id)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_gc_keeps_content_while_removal_can_be_undone() -> KanbanResult<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("board.json");
+    let mut ctx =
+        kanban_service::open_context(file.to_str().unwrap(), AppConfig::default()).await?;
+    let card_id = card_in(&mut ctx).await?;
+
+    let attachment = ctx.add_attachment(card_id, "crash.log".into(), b"panic")?;
+    assert!(dir
+        .path()
+        .join("board.json.attachments")
+        .join(&attachment.hash)
+        .is_file());
+    ctx.remove_attachment(card_id, attachment.id)?;
+
+    let report = ctx.collect_attachment_garbage(false)?;
+    assert!(report.removed.is_empty(), "undo stack still refers to it");
+    assert_eq!(report.kept, 1);
+
+    assert!(ctx.undo()?);
+    let (restored, content) = ctx.read_attachment(card_id, attachment.id)?;
+    assert_eq!(restored, attachment);
+    assert_eq!(content, b"panic");
+    ctx.save().await?;
+
+    // Once the attachment is gone with no way back, its content is freed.
+    let mut fresh =
+        kanban_service::open_context(file.to_str().unwrap(), AppConfig::default()).await?;
+    fresh.remove_attachment(card_id, attachment.id)?;
+    fresh.save().await?;
+    let mut reopened =
+        kanban_service::open_context(file.to_str().unwrap(), AppConfig::default()).await?;
+    let dry_run = reopened.collect_attachment_garbage(true)?;
+    assert_eq!(dry_run.removed, vec![attachment.hash.clone()]);
+    assert_eq!(dry_run.freed_bytes, 5);
+    reopened.collect_attachment_garbage(false)?;
+    assert!(!dir
+        .path()
+        .join("board.json.attachments")
+        .join(&attachment.hash)
+        .exists());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_gc_keeps_content_of_deleted_cards_while_delete_can_be_undone() -> KanbanResult<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("board.json");
+    let mut ctx =
+        kanban_service::open_context(file.to_str().unwrap(), AppConfig::default()).await?;
+    let card_id = card_in(&mut ctx).await?;
+    let attachment = ctx.add_attachment(card_id, "crash.log".into(), b"panic")?;
+    let board_id = ctx.boards()?[0].id;
+
+    ctx.delete_card(card_id)?;
+    let report = ctx.collect_attachment_garbage(false)?;
+    assert!(report.removed.is_empty(), "undoing the delete restores it");
+    assert!(ctx.undo()?);
+    let (_, content) = ctx.read_attachment(card_id, attachment.id)?;
+    assert_eq!(content, b"panic");
+
+    ctx.delete_board(board_id)?;
+    assert!(ctx.collect_attachment_garbage(false)?.removed.is_empty());
+    assert!(ctx.undo()?);
+    let (_, content) = ctx.read_attachment(card_id, attachment.id)?;
+    assert_eq!(content, b"panic");
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_backend_without_blob_store_rejects_attachments() -> KanbanResult<()> {
+    let mut ctx = KanbanContext::open(Arc::new(InMemoryStore::new()), AppConfig::default()).await?;
+    let card_id = card_in(&mut ctx).await?;
+    let err = ctx
+        .add_attachment(card_id, "a.txt".into(), b"a")
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("cannot store attachments"),
+        "{err}"
+    );
+    assert!(ctx.get_card(card_id)?.unwrap().attachments.is_empty());
+    Ok(())
+}
@@ -4,7 +4,7 @@
 
 use kanban_core::AppConfig;
 use kanban_domain::commands::{BoardCommand, Command, CreateBoard};
-use kanban_domain::{InMemoryStore, KanbanResult};
+use kanban_domain::{InMemoryStore, KanbanOperations, KanbanResult};
 use kanban_service::KanbanContext;
 use std::sync::Arc;
 use uuid::Uuid;
@@ -140,3 +140,21 @@ async fn test_audit_log_preserves_abandoned_redo_tail_on_branching_execute() ->
     );
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_recently_changed_cards_reads_log_newest_first() -> KanbanResult<()> {
+    let mut ctx = make_ctx().await;
+    let board = ctx.create_board("B".into(), None)?;
+    let todo = ctx.create_column(board.id, "Todo".into(), None)?;
+    let done = ctx.create_column(board.id, "Done".into(), None)?;
+    let first = ctx.create_card(board.id, todo.id, "First".into(), Default::default())?;
+    let second = ctx.create_card(board.id, todo.id, "Second".into(), Default::default())?;
+    ctx.move_card(first.id, done.id, None)?;
+
+    let recent = ctx.recently_changed_cards()?;
+    let ids: Vec<Uuid> = recent.iter().map(|(id, _)| *id).collect();
+    assert_eq!(ids, vec![first.id, second.id]);
+    assert!(recent[0].1.starts_with("Move card"), "{:?}", recent[0]);
+    assert_eq!(recent[1].1, "Create card: 'Second'");
+    Ok(())
+}
@@ -0,0 +1,114 @@
+//! `KanbanContext::create_cards`: a batch of new cards plus the relations
+//! between them lands as one undo step, or not at all.
+
+use kanban_core::AppConfig;
+use kanban_domain::{GraphOperations, InMemoryStore, KanbanOperations, KanbanResult};
+use kanban_service::{KanbanContext, NewCard, NewCardBatch, NewCardRef};
+use std::sync::Arc;
+use uuid::Uuid;
+
+async fn make_ctx() -> (KanbanContext, Uuid, Uuid) {
+    let mut ctx = KanbanContext::open(Arc::new(InMemoryStore::new()), AppConfig::default())
+        .await
+        .unwrap();
+    let board = ctx.create_board("B".into(), None).unwrap();
+    let column = ctx.create_column(board.id, "Todo".into(), None).unwrap();
+    (ctx, board.id, column.id)
+}
+
+fn new_card(column_id: Uuid, title: &str) -> NewCard {
+    NewCard {
+        column_id,
+        title: title.into(),
+        options: Default::default(),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_cards_with_relations_is_one_undo_step() -> KanbanResult<()> {
+    let (mut ctx, board_id, column_id) = make_ctx().await;
+    let epic = ctx.create_card(board_id, column_id, "Epic".into(), Default::default())?;
+
+    let cards = ctx.create_cards(
+        board_id,
+        NewCardBatch {
+            cards: vec![new_card(column_id, "Design"), new_card(column_id, "Build")],
+            parents: vec![
+                (NewCardRef::Existing(epic.id), NewCardRef::New(0)),
+                (NewCardRef::Existing(epic.id), NewCardRef::New(1)),
+            ],
+            blocks: vec![(NewCardRef::New(0), NewCardRef::New(1))],
+        },
+    )?;
+
+    let titles: Vec<&str> = cards.iter().map(|c| c.title.as_str()).collect();
+    assert_eq!(titles, ["Design", "Build"]);
+    assert_eq!(
+        cards.iter().map(|c| c.card_number).collect::<Vec<_>>(),
+        [epic.card_number + 1, epic.card_number + 2]
+    );
+    assert_eq!(cards[1].position, cards[0].position + 1);
+    assert_eq!(
+        ctx.list_children_of(epic.id)?,
+        vec![cards[0].id, cards[1].id]
+    );
+    assert_eq!(ctx.list_blockers_of(cards[1].id)?, vec![cards[0].id]);
+
+    assert!(ctx.undo()?);
+    assert_eq!(ctx.list_all_cards()?.len(), 1);
+    assert!(ctx.list_children_of(epic.id)?.is_empty());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_cards_rejected_relation_creates_nothing() -> KanbanResult<()> {
+    let (mut ctx, board_id, column_id) = make_ctx().await;
+    let depth = ctx.undo_depth();
+
+    let result = ctx.create_cards(
+        board_id,
+        NewCardBatch {
+            cards: vec![new_card(column_id, "A"), new_card(column_id, "B")],
+            parents: vec![
+                (NewCardRef::New(0), NewCardRef::New(1)),
+                (NewCardRef::New(1), NewCardRef::New(0)),
+            ],
+            blocks: vec![],
+        },
+    );
+
+    assert!(result.is_err());
+    assert!(ctx.list_all_cards()?.is_empty());
+    assert_eq!(ctx.undo_depth(), depth);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_cards_rejects_unknown_references() -> KanbanResult<()> {
+    let (mut ctx, board_id, column_id) = make_ctx().await;
+
+    let out_of_range = ctx.create_cards(
+        board_id,
+        NewCardBatch {
+            cards: vec![new_card(column_id, "A")],
+            parents: vec![(NewCardRef::New(0), NewCardRef::New(3))],
+            blocks: vec![],
+        },
+    );
+    assert!(out_of_range.is_err());
+
+    let missing = ctx.create_cards(
+        board_id,
+        NewCardBatch {
+            cards: vec![new_card(column_id, "A")],
+            parents: vec![],
+            blocks: vec![(NewCardRef::Existing(Uuid::new_v4()), NewCardRef::New(0))],
+        },
+    );
+    assert!(missing.unwrap_err().is_not_found());
+
+    let empty = ctx.create_cards(board_id, NewCardBatch::default());
+    assert!(empty.is_err());
+    assert!(ctx.list_all_cards()?.is_empty());
+    Ok(())
+}
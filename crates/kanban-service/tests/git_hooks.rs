@@ -0,0 +1,114 @@
+//! `git_hooks::apply` against a real context: each card change is one
+//! undo step that carries a `git` activity entry, and the entry persists.
+
+use chrono::Utc;
+use kanban_domain::{CardStatus, CreateCardOptions};
+use kanban_persistence_json::JsonFileStore;
+use kanban_service::git::GitCommit;
+use kanban_service::{
+    git_hooks, json_backend::JsonDataStore, sqlite_backend::SqliteBackend, AppConfig,
+    KanbanBackend, KanbanContext, KanbanOperations,
+};
+use std::sync::Arc;
+use tempfile::tempdir;
+use uuid::Uuid;
+
+fn commit(hash: &str, message: &str) -> GitCommit {
+    GitCommit {
+        hash: hash.to_string(),
+        short_hash: hash[..7].to_string(),
+        summary: message.lines().next().unwrap_or_default().to_string(),
+        author: "Dev".to_string(),
+        date: Utc::now(),
+        message: message.to_string(),
+    }
+}
+
+/// A `KAN` board with Todo / In Progress / Done and one card in Todo.
+fn setup(ctx: &mut KanbanContext) -> (Uuid, Uuid) {
+    let board = ctx
+        .create_board("App".to_string(), Some("KAN".to_string()))
+        .unwrap();
+    let mut todo = None;
+    for name in ["Todo", "In Progress", "Done"] {
+        let column = ctx.create_column(board.id, name.to_string(), None).unwrap();
+        todo.get_or_insert(column.id);
+    }
+    let todo = todo.unwrap();
+    let card = ctx
+        .create_card(
+            board.id,
+            todo,
+            "Login".to_string(),
+            CreateCardOptions::default(),
+        )
+        .unwrap();
+    (todo, card.id)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_git_change_is_one_undo_step_with_activity() {
+    let dir = tempdir().unwrap();
+    let backend: Arc<dyn KanbanBackend> = Arc::new(JsonDataStore::new(Arc::new(
+        JsonFileStore::new(dir.path().join("board.json")),
+    )));
+    let mut ctx = KanbanContext::open(backend, AppConfig::default())
+        .await
+        .unwrap();
+    let (todo, card_id) = setup(&mut ctx);
+    let depth = ctx.undo_depth();
+
+    let wip = commit("aaaaaaa111", "WIP KAN-1");
+    let report = git_hooks::apply(&mut ctx, &[wip], &[]).unwrap();
+    assert_eq!(report.actions.len(), 1);
+    assert_eq!(ctx.undo_depth(), depth + 1);
+
+    let card = ctx.get_card(card_id).unwrap().unwrap();
+    assert_eq!(card.status, CardStatus::InProgress);
+    assert_eq!(card.description, None);
+    assert_eq!(card.activity.len(), 1);
+    assert_eq!(card.activity[0].source, "git");
+    assert_eq!(card.activity[0].reference.as_deref(), Some("aaaaaaa111"));
+
+    let refs = commit("bbbbbbb222", "Tidy up, refs KAN-1");
+    git_hooks::apply(&mut ctx, std::slice::from_ref(&refs), &[]).unwrap();
+    let replay = git_hooks::apply(&mut ctx, &[refs], &[]).unwrap();
+    assert!(replay.actions.is_empty());
+    assert_eq!(ctx.get_card(card_id).unwrap().unwrap().activity.len(), 2);
+
+    ctx.undo().unwrap();
+    ctx.undo().unwrap();
+    let card = ctx.get_card(card_id).unwrap().unwrap();
+    assert_eq!(card.column_id, todo);
+    assert_eq!(card.status, CardStatus::Todo);
+    assert!(card.activity.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_git_activity_persists_in_sqlite() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("board.sqlite");
+    let backend: Arc<dyn KanbanBackend> =
+        Arc::new(SqliteBackend::open(path.to_str().unwrap()).await.unwrap());
+    let mut ctx = KanbanContext::open(backend, AppConfig::default())
+        .await
+        .unwrap();
+    let (_, card_id) = setup(&mut ctx);
+
+    git_hooks::apply(&mut ctx, &[commit("ccccccc333", "Closes KAN-1")], &[]).unwrap();
+    ctx.save().await.unwrap();
+    drop(ctx);
+
+    let backend: Arc<dyn KanbanBackend> =
+        Arc::new(SqliteBackend::open(path.to_str().unwrap()).await.unwrap());
+    let ctx = KanbanContext::open(backend, AppConfig::default())
+        .await
+        .unwrap();
+    let card = ctx.get_card(card_id).unwrap().unwrap();
+    assert_eq!(card.status, CardStatus::Done);
+    assert_eq!(card.activity.len(), 1);
+    assert_eq!(
+        card.activity[0].message,
+        "Completed by commit ccccccc: Closes KAN-1"
+    );
+}
@@ -1533,3 +1533,40 @@ async fn test_inverse_cascade_preserves_archived_incident_edge_state() -> Kanban
     assert!(ac.is_active(), "A->C active state preserved");
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_inverse_checklist_convert_and_import() -> KanbanResult<()> {
+    let mut ctx = make_ctx().await;
+    let board = ctx.create_board("Checklists".into(), None)?;
+    let column = ctx.create_column(board.id, "Todo".into(), None)?;
+    let card = ctx.create_card(
+        board.id,
+        column.id,
+        "Release".into(),
+        kanban_domain::CreateCardOptions {
+            description: Some("Notes\n- [ ] changelog\n- [x] version".into()),
+            ..Default::default()
+        },
+    )?;
+
+    let imported = ctx.import_checklist_from_description(card.id)?;
+    assert_eq!(imported.len(), 2);
+    let after_import = ctx.get_card(card.id)?.unwrap();
+    assert_eq!(after_import.description.as_deref(), Some("Notes"));
+    assert!(after_import.checklist[1].done);
+
+    let child = ctx.convert_checklist_item_to_card(card.id, imported[0].id)?;
+    assert_eq!(child.title, "changelog");
+    assert_eq!(ctx.get_card(card.id)?.unwrap().checklist.len(), 1);
+    assert_eq!(ctx.graph()?.children(card.id), vec![child.id]);
+
+    assert!(ctx.undo()?, "undo convert");
+    assert!(ctx.get_card(child.id)?.is_none());
+    assert_eq!(ctx.get_card(card.id)?.unwrap().checklist, imported);
+
+    assert!(ctx.undo()?, "undo import");
+    let restored = ctx.get_card(card.id)?.unwrap();
+    assert!(restored.checklist.is_empty());
+    assert_eq!(restored.description, card.description);
+    Ok(())
+}
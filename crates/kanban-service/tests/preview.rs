@@ -0,0 +1,40 @@
+//! `KanbanContext::preview`: writes against the scratch copy, attachment
+//! content included, never reach the real backend.
+
+use kanban_core::AppConfig;
+use kanban_domain::{KanbanOperations, KanbanResult};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_preview_writes_leave_backend_and_blobs_untouched() -> KanbanResult<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("board.json");
+    let mut ctx =
+        kanban_service::open_context(file.to_str().unwrap(), AppConfig::default()).await?;
+    let board = ctx.create_board("Bugs".into(), None)?;
+    let column = ctx.create_column(board.id, "Todo".into(), None)?;
+    let card = ctx.create_card(board.id, column.id, "Crash".into(), Default::default())?;
+    let kept = ctx.add_attachment(card.id, "crash.log".into(), b"panic")?;
+    ctx.save().await?;
+    let depth = ctx.undo_depth();
+
+    let mut preview = ctx.preview()?;
+    assert_eq!(preview.snapshot()?.cards, ctx.snapshot()?.cards);
+    let added = preview.add_attachment(card.id, "trace.txt".into(), b"stack")?;
+    let (_, content) = preview.read_attachment(card.id, added.id)?;
+    assert_eq!(content, b"stack");
+    preview.remove_attachment(card.id, kept.id)?;
+    preview.delete_card(card.id)?;
+
+    let blobs = dir.path().join("board.json.attachments");
+    assert!(!blobs.join(&added.hash).exists());
+    assert!(blobs.join(&kept.hash).is_file());
+    assert_eq!(ctx.list_all_cards()?.len(), 1);
+    assert_eq!(ctx.undo_depth(), depth);
+
+    let reopened =
+        kanban_service::open_context(file.to_str().unwrap(), AppConfig::default()).await?;
+    assert_eq!(reopened.list_all_cards()?.len(), 1);
+    let (_, content) = reopened.read_attachment(card.id, kept.id)?;
+    assert_eq!(content, b"panic");
+    Ok(())
+}
@@ -263,6 +263,107 @@ async fn test_import_board_includes_archived_cards() -> KanbanResult<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_import_board_restores_edges_scoped_to_board() -> KanbanResult<()> {
+    use kanban_domain::{GraphOperations, Severity};
+
+    let mut ctx = make_ctx().await;
+    let board = ctx.create_board("Export".into(), None)?;
+    let col = ctx.create_column(board.id, "C".into(), None)?;
+    let a = ctx.create_card(board.id, col.id, "A".into(), Default::default())?;
+    let b = ctx.create_card(board.id, col.id, "B".into(), Default::default())?;
+    let other = ctx.create_board("Other".into(), None)?;
+    let other_col = ctx.create_column(other.id, "C".into(), None)?;
+    let c = ctx.create_card(other.id, other_col.id, "C".into(), Default::default())?;
+    ctx.block(a.id, b.id, Severity::Low)?;
+    ctx.attach_child(a.id, c.id)?;
+    ctx.archive_card(b.id)?;
+
+    let json = ctx.export_board(Some(board.id))?;
+
+    let mut ctx2 = make_ctx().await;
+    ctx2.import_board(&json)?;
+    let graph = ctx2.graph()?;
+    assert_eq!(graph.blocks_edges(), ctx.graph()?.blocks_edges());
+    assert!(
+        graph.spawns_edges().is_empty(),
+        "edge to another board is dropped"
+    );
+    assert_eq!(ctx2.archived_cards()?.len(), 1);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_board_from_template_is_one_undo_step() -> KanbanResult<()> {
+    use kanban_domain::{BoardTemplate, CardTemplate};
+
+    let mut ctx = make_ctx().await;
+    let mut template = BoardTemplate::builtin("kanban").unwrap();
+    let seed = |title: &str, blocked_by: Vec<String>| CardTemplate {
+        title: title.to_string(),
+        description: None,
+        column: None,
+        priority: None,
+        points: None,
+        parent: None,
+        blocked_by,
+        related_to: Vec::new(),
+    };
+    template.cards = vec![seed("Setup", vec![]), seed("Launch", vec!["Setup".into()])];
+
+    let board = ctx.create_board_from_template("Client".into(), &template)?;
+    assert_eq!(ctx.columns()?.len(), 5);
+    assert_eq!(ctx.cards()?.len(), 2);
+    assert_eq!(ctx.graph()?.blocks_edges().len(), 1);
+    let done = ctx
+        .columns()?
+        .into_iter()
+        .find(|c| c.name == "Done")
+        .unwrap();
+    assert_eq!(board.completion_column_id, Some(done.id));
+
+    let saved = ctx.board_template(board.id, "again".into(), true)?;
+    assert_eq!(saved.cards[1].blocked_by, vec!["Setup".to_string()]);
+
+    ctx.undo()?;
+    assert!(ctx.boards()?.is_empty());
+    assert!(ctx.columns()?.is_empty());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_csv_round_trip_is_one_undo_step() -> KanbanResult<()> {
+    use kanban_domain::{BoardExportFormat, CsvMapping};
+
+    let mut ctx = make_ctx().await;
+    let board = ctx.create_board("Ops".into(), Some("OPS".into()))?;
+    let todo = ctx.create_column(board.id, "Todo".into(), None)?;
+    ctx.create_column(board.id, "Done".into(), None)?;
+    ctx.create_card(board.id, todo.id, "Rotate keys".into(), Default::default())?;
+
+    let csv = ctx.export_board_as(Some(board.id), BoardExportFormat::Csv)?;
+    let edited = csv.replace("Rotate keys,Todo,todo", "Rotate all keys,Done,done")
+        + "Ops,,Write runbook,,,,,,,\n";
+    let summary = ctx.import_cards_csv(Some(board.id), &edited, &CsvMapping::default())?;
+    assert_eq!(
+        (summary.updated, summary.created, summary.unchanged),
+        (1, 1, 0)
+    );
+
+    let cards = ctx.cards()?;
+    let rotated = cards.iter().find(|c| c.card_number == 1).unwrap();
+    assert_eq!(rotated.title, "Rotate all keys");
+    assert!(rotated.is_completed());
+    assert_eq!(cards.len(), 2);
+
+    ctx.undo()?;
+    let cards = ctx.cards()?;
+    assert_eq!(cards.len(), 1);
+    assert_eq!(cards[0].title, "Rotate keys");
+    assert_eq!(cards[0].column_id, todo.id);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_conflict_flag_lifecycle() {
     let mut ctx = make_ctx().await;
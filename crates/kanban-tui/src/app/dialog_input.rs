@@ -1,5 +1,6 @@
 use crate::components::sprint_picker::{SprintFilter, SprintPicker};
 use kanban_core::SelectionState;
+use kanban_domain::CloneOptions;
 use std::cell::Cell;
 use uuid::Uuid;
 
@@ -27,6 +28,11 @@ pub struct DialogInputState {
     /// from completed/ended sprints as well, which the create-card
     /// picker intentionally hides.
     pub assign_sprint_picker: SprintPicker,
+    /// What the clone-project dialog copies besides the columns.
+    pub clone_options: CloneOptions,
+    /// Templates offered by the create-project dialog, and the chosen one.
+    pub board_templates: Vec<String>,
+    pub board_template_selection: usize,
 }
 
 impl Default for DialogInputState {
@@ -44,6 +50,9 @@ impl Default for DialogInputState {
             create_card_sprint_picker: SprintPicker::with_filter(SprintFilter::ActiveOnly),
             create_card_focus: CreateCardFocus::default(),
             assign_sprint_picker: SprintPicker::with_filter(SprintFilter::All),
+            clone_options: CloneOptions::default(),
+            board_templates: Vec::new(),
+            board_template_selection: 0,
         }
     }
 }
@@ -63,4 +72,22 @@ impl DialogInputState {
     pub fn reset_create_card_focus(&mut self) {
         self.create_card_focus = CreateCardFocus::Title;
     }
+
+    pub fn selected_board_template(&self) -> Option<&str> {
+        self.board_templates
+            .get(self.board_template_selection)
+            .map(String::as_str)
+    }
+
+    pub fn cycle_board_template(&mut self, forward: bool) {
+        let len = self.board_templates.len();
+        if len == 0 {
+            return;
+        }
+        self.board_template_selection = if forward {
+            (self.board_template_selection + 1) % len
+        } else {
+            (self.board_template_selection + len - 1) % len
+        };
+    }
 }
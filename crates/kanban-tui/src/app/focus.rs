@@ -39,6 +39,7 @@ pub enum SettingsFocus {
     Configuration,
     ConfigFile,
     Storage,
+    Appearance,
 }
 
 #[cfg(test)]
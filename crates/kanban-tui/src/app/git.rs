@@ -0,0 +1,24 @@
+use kanban_service::git::{CardGitStatus, GitRepository};
+use std::path::Path;
+use uuid::Uuid;
+
+/// Git section of the card detail view, read from the repository around
+/// the working directory.
+#[derive(Default)]
+pub struct GitState {
+    repository: Option<GitRepository>,
+    discovered: bool,
+    /// Status of the card it was read for.
+    pub status: Option<(Uuid, CardGitStatus)>,
+}
+
+impl GitState {
+    /// The repository around the working directory, looked up on first use.
+    pub fn repository(&mut self) -> Option<&GitRepository> {
+        if !self.discovered {
+            self.discovered = true;
+            self.repository = GitRepository::discover(Path::new(".")).ok();
+        }
+        self.repository.as_ref()
+    }
+}
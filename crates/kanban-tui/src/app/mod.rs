@@ -25,10 +25,16 @@ pub use dialog_input::DialogInputState;
 pub mod relationship;
 pub use relationship::RelationshipState;
 
+pub mod git;
+pub use git::GitState;
+
 pub mod model;
 
 pub mod view;
-pub use view::ViewState;
+pub use view::{HitAreas, PreviewLayout, PreviewState, ViewState};
+
+pub mod mouse;
+pub use mouse::MouseState;
 
 pub mod persistence;
 pub use persistence::PersistenceState;
@@ -41,6 +47,7 @@ use crate::{
     components::Banner,
     editor::edit_in_external_editor,
     events::{Event, EventHandler},
+    text_editor::TextTarget,
     tui_context::TuiContext,
     ui,
     view_strategy::{UnifiedViewStrategy, ViewRefreshContext, ViewStrategy},
@@ -53,8 +60,11 @@ use kanban_core::{AppConfig, Editable, InputState};
 use kanban_domain::AnimationType;
 use kanban_domain::KanbanResult;
 use kanban_domain::{
-    export::{AllBoardsExport, BoardExporter, BoardImporter},
-    partition_sprint_cards, sort_card_ids, Board, Card, SortField, SortOrder, Sprint,
+    export::{
+        AllBoardsExport, BoardCopier, BoardExportFormat, BoardExporter, BoardImporter, CsvExporter,
+        CsvMapping, DefaultPrefixes, IcalExporter, MarkdownExporter,
+    },
+    partition_sprint_cards, sort_card_ids, Board, Card, Snapshot, SortField, SortOrder, Sprint,
 };
 use kanban_service::StoreManager;
 
@@ -90,10 +100,13 @@ pub struct App {
     pub ui_state: UiState,
     pub sprint_view: SprintViewState,
     pub view: ViewState,
+    pub mouse: MouseState,
     pub model: model::Model,
     pub relationship: RelationshipState,
+    pub git: GitState,
     pub save_error: Option<String>,
     pub pending_key: Option<char>,
+    pub keymap: crate::keybindings::Keymap,
     pub has_data_file: bool,
     pub cli_file_provided: bool,
     pub cli_file_override: bool,
@@ -157,6 +170,56 @@ pub enum ExportFormat {
     #[default]
     Json,
     Sqlite,
+    Markdown,
+    Csv,
+    Ics,
+    Bundle,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 6] = [
+        Self::Json,
+        Self::Sqlite,
+        Self::Markdown,
+        Self::Csv,
+        Self::Ics,
+        Self::Bundle,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Json => "JSON",
+            Self::Sqlite => "SQLite",
+            Self::Markdown => "Markdown",
+            Self::Csv => "CSV",
+            Self::Ics => "iCalendar",
+            Self::Bundle => "Bundle",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Sqlite => "sqlite",
+            Self::Markdown => "md",
+            Self::Csv => "csv",
+            Self::Ics => "ics",
+            Self::Bundle => "bundle",
+        }
+    }
+
+    /// The format after this one in [`ExportFormat::ALL`], or before it
+    /// when `forward` is false, wrapping around.
+    pub fn cycle(self, forward: bool) -> Self {
+        let len = Self::ALL.len();
+        let idx = Self::ALL.iter().position(|f| *f == self).unwrap_or(0);
+        let next = if forward {
+            (idx + 1) % len
+        } else {
+            (idx + len - 1) % len
+        };
+        Self::ALL[next]
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -200,17 +263,19 @@ impl ExportDialogState {
 pub enum MigrationState {
     Idle,
     Migrating {
-        old_config: AppConfig,
+        old_config: Box<AppConfig>,
         old_storage_location: String,
         result_rx: tokio::sync::oneshot::Receiver<Result<(kanban_domain::Snapshot, bool), String>>,
     },
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CardField {
     Title,
     Description,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BoardField {
     Name,
     Description,
@@ -231,6 +296,9 @@ impl App {
         save_file: Option<String>,
     ) -> kanban_domain::KanbanResult<(Self, Option<tokio::sync::mpsc::Receiver<()>>)> {
         let mut app_config = kanban_service::config::load();
+        let keymap = crate::keybindings::Keymap::from_config(&app_config.keymap)?;
+        crate::theme::activate_theme(app_config.effective_theme())
+            .map_err(kanban_domain::KanbanError::validation)?;
         let config_resolved = kanban_service::config::resolve_storage_location(&app_config);
         let config_storage_backend = app_config.effective_storage_backend().to_string();
         let config_storage_location = config_resolved.clone();
@@ -293,6 +361,10 @@ impl App {
             kanban_service::KanbanContext::open(kanban_backend, app_config.clone()).await?;
         let (ctx, save_rx, save_completion_rx) = TuiContext::new(inner_ctx)?;
         let store_manager = Arc::new(store_manager);
+        let view = ViewState {
+            preview: PreviewState::from_config(&app_config),
+            ..ViewState::default()
+        };
         let app = Self {
             store_manager,
             should_quit: false,
@@ -312,11 +384,14 @@ impl App {
             multi_select: MultiSelectState::default(),
             ui_state: UiState::default(),
             sprint_view: SprintViewState::default(),
-            view: ViewState::default(),
+            view,
+            mouse: MouseState::default(),
             model: model::Model::default(),
             relationship: RelationshipState::default(),
+            git: GitState::default(),
             save_error: None,
             pending_key: None,
+            keymap,
             has_data_file: has_explicit_file,
             cli_file_provided: save_file.is_some(),
             cli_file_override,
@@ -382,6 +457,7 @@ impl App {
         }
 
         let backend = self.ctx.backend();
+        let app_config = self.app_config.clone();
         let file_watcher = self.persistence.file_watcher.clone();
         let save_completion_tx = self.ctx.save_coordinator.save_completion_tx().cloned();
         let (save_error_tx, save_error_rx) = tokio::sync::mpsc::unbounded_channel::<String>();
@@ -404,6 +480,11 @@ impl App {
                 let save_succeeded = match backend.flush().await {
                     Ok(()) => {
                         tracing::debug!("Save worker completed flush");
+                        if let Err(e) =
+                            kanban_service::calendar_feed::refresh(backend.as_ref(), &app_config)
+                        {
+                            tracing::warn!("Failed to refresh calendar feed: {}", e);
+                        }
                         if !watching_started {
                             if let (Some(ref watcher), Some(ref p)) =
                                 (&file_watcher, &deferred_watch_path)
@@ -748,6 +829,7 @@ impl App {
             KeybindingAction::CreateSprint => self.handle_create_sprint_key(),
             KeybindingAction::CreateColumn => self.handle_create_column_key(),
             KeybindingAction::RenameBoard => self.handle_rename_board_key(),
+            KeybindingAction::CloneBoard => self.handle_clone_board_key(),
             KeybindingAction::RenameColumn => self.handle_rename_column_key(),
             KeybindingAction::EditCard => {}
             KeybindingAction::EditBoard => self.handle_edit_board_key(),
@@ -802,6 +884,19 @@ impl App {
             }
             KeybindingAction::OpenSettings => self.handle_open_settings(),
             KeybindingAction::ExportBoards => {}
+            KeybindingAction::CommandPalette => self.open_command_palette(),
+            KeybindingAction::TogglePreview => self.handle_toggle_preview(),
+            KeybindingAction::FocusPreview => self.handle_focus_preview(),
+            KeybindingAction::ShrinkPreview => self.handle_shrink_preview(),
+            KeybindingAction::GrowPreview => self.handle_grow_preview(),
+        }
+    }
+
+    pub(crate) fn is_text_input_mode(&self) -> bool {
+        match &self.mode {
+            AppMode::Search => true,
+            AppMode::Dialog(dialog) => crate::keybindings::keymap::is_text_input_dialog(dialog),
+            _ => false,
         }
     }
 
@@ -811,32 +906,51 @@ impl App {
         terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
         event_handler: &EventHandler,
     ) -> bool {
-        use crossterm::event::KeyCode;
-        let mut should_restart_events = false;
-
         // Clear banner on any key press
         if self.ui_state.banner.is_some() {
             self.clear_banner();
             return false;
         }
 
-        let is_input_mode = matches!(
-            self.mode,
-            AppMode::Search
-                | AppMode::Dialog(DialogMode::CreateBoard)
-                | AppMode::Dialog(DialogMode::CreateCard)
-                | AppMode::Dialog(DialogMode::CreateSprint)
-                | AppMode::Dialog(DialogMode::RenameBoard)
-                | AppMode::Dialog(DialogMode::ExportBoard)
-                | AppMode::Dialog(DialogMode::ExportAll)
-                | AppMode::Dialog(DialogMode::SetCardPoints)
-                | AppMode::Dialog(DialogMode::SetBranchPrefix)
-                | AppMode::Dialog(DialogMode::CreateColumn)
-                | AppMode::Dialog(DialogMode::RenameColumn)
-                | AppMode::Dialog(DialogMode::SetSprintPrefix)
-                | AppMode::Dialog(DialogMode::SetSprintCardPrefix)
-                | AppMode::Dialog(DialogMode::ChooseStorageFile)
-        );
+        // The help popup matches keys against the relabelled bindings itself.
+        let context = match self.mode {
+            AppMode::Help(_) => None,
+            _ => crate::keybindings::KeymapContext::for_mode(&self.mode, &self.focus.active),
+        };
+        let keys = self
+            .keymap
+            .translate(context, key, self.is_text_input_mode());
+        let mut should_restart_events = false;
+        for key in keys {
+            should_restart_events |= self.dispatch_key_event(key, terminal, event_handler);
+        }
+        should_restart_events
+    }
+
+    /// Runs a mouse event and dispatches the keys it stands for.
+    fn handle_mouse_input(
+        &mut self,
+        mouse: crossterm::event::MouseEvent,
+        terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+        event_handler: &EventHandler,
+    ) -> bool {
+        let mut should_restart_events = false;
+        for key in self.handle_mouse_event(mouse) {
+            should_restart_events |= self.dispatch_key_event(key, terminal, event_handler);
+        }
+        should_restart_events
+    }
+
+    fn dispatch_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+        terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+        event_handler: &EventHandler,
+    ) -> bool {
+        use crossterm::event::KeyCode;
+        let mut should_restart_events = false;
+
+        let is_input_mode = self.is_text_input_mode();
 
         if matches!(key.code, KeyCode::Char('q') | KeyCode::Char('Q'))
             && !is_input_mode
@@ -866,6 +980,18 @@ impl App {
             return false;
         }
 
+        let opens_palette = match key.code {
+            KeyCode::Char(':') => true,
+            KeyCode::Char('p') => key
+                .modifiers
+                .contains(crossterm::event::KeyModifiers::CONTROL),
+            _ => false,
+        };
+        if opens_palette && !is_input_mode && self.can_open_command_palette() {
+            self.open_command_palette();
+            return false;
+        }
+
         // Handle Ctrl+a for select all cards
         if matches!(self.mode, AppMode::Normal)
             && key
@@ -954,7 +1080,10 @@ impl App {
                 }
                 KeyCode::Char('c') => {
                     self.pending_key = None;
-                    self.handle_toggle_card_completion();
+                    match self.focus.active {
+                        Focus::Boards => self.handle_clone_board_key(),
+                        Focus::Cards => self.handle_toggle_card_completion(),
+                    }
                 }
                 KeyCode::Char('s') => {
                     self.pending_key = None;
@@ -1074,6 +1203,22 @@ impl App {
                     self.pending_key = None;
                     self.handle_open_settings();
                 }
+                KeyCode::Char('w') => {
+                    self.pending_key = None;
+                    self.handle_toggle_preview();
+                }
+                KeyCode::Tab => {
+                    self.pending_key = None;
+                    self.handle_focus_preview();
+                }
+                KeyCode::Char('<') => {
+                    self.pending_key = None;
+                    self.handle_shrink_preview();
+                }
+                KeyCode::Char('>') => {
+                    self.pending_key = None;
+                    self.handle_grow_preview();
+                }
                 _ => {
                     self.pending_key = None;
                 }
@@ -1099,12 +1244,14 @@ impl App {
                 DialogMode::CreateCard => self.handle_create_card_dialog(key.code),
                 DialogMode::CreateSprint => self.handle_create_sprint_dialog(key.code),
                 DialogMode::RenameBoard => self.handle_rename_board_dialog(key.code),
+                DialogMode::CloneBoard => self.handle_clone_board_dialog(key.code),
                 DialogMode::ExportBoard => self.handle_export_board_dialog(key.code),
                 DialogMode::ExportAll => self.handle_export_all_dialog(key.code),
                 DialogMode::ImportBoard => self.handle_import_board_popup(key.code),
                 DialogMode::SetCardPoints => {
                     should_restart_events = self.handle_set_card_points_dialog(key.code);
                 }
+                DialogMode::AddAttachment => self.handle_add_attachment_dialog(key.code),
                 DialogMode::SetCardPriority => self.handle_set_card_priority_popup(key.code),
                 DialogMode::SetMultipleCardsPriority => {
                     self.handle_set_multiple_cards_priority_popup(key.code)
@@ -1140,11 +1287,48 @@ impl App {
                 DialogMode::CarryOverSprint => self.handle_carry_over_sprint_popup(key.code),
                 DialogMode::ExportBoards => self.handle_export_boards_dialog(key.code),
                 DialogMode::ChooseStorageFile => self.handle_choose_storage_file_dialog(key.code),
+                DialogMode::CommandPalette => {
+                    if let Some(command) = self.handle_command_palette_key(key.code) {
+                        should_restart_events =
+                            self.run_palette_command(command, terminal, event_handler);
+                    }
+                }
+                DialogMode::EditText => {
+                    if self.handle_text_editor_key(key) {
+                        self.continue_in_external_editor(terminal, event_handler);
+                        should_restart_events = true;
+                    }
+                }
             },
         }
         should_restart_events
     }
 
+    fn run_palette_command(
+        &mut self,
+        command: crate::command_palette::PaletteCommand,
+        terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+        event_handler: &EventHandler,
+    ) -> bool {
+        use crate::command_palette::PaletteCommand;
+
+        match command {
+            PaletteCommand::Keys(keys) => {
+                let mut should_restart_events = false;
+                for key in keys {
+                    should_restart_events |= self.dispatch_key_event(key, terminal, event_handler);
+                }
+                return should_restart_events;
+            }
+            PaletteCommand::JumpToBoard(board_id) => {
+                self.jump_to_board(board_id);
+            }
+            PaletteCommand::JumpToCard(card_id) => self.jump_to_card(card_id),
+            PaletteCommand::JumpToSprint(sprint_id) => self.jump_to_sprint(sprint_id),
+        }
+        false
+    }
+
     fn handle_search_mode(&mut self, key_code: crossterm::event::KeyCode) {
         use crossterm::event::KeyCode;
         match key_code {
@@ -1659,35 +1843,98 @@ impl App {
 
     pub fn export_board_with_filename(&self) -> io::Result<()> {
         if let Some(board_idx) = self.selection.board.get() {
-            let boards = self.model.boards();
-            if let Some(board) = boards.get(board_idx) {
-                let columns = self.model.columns();
-                let cards = self.model.cards();
-                let archived_cards = self.model.archived_cards();
-                let sprints = self.model.sprints();
-                let board_export =
-                    BoardExporter::export_board(board, columns, cards, archived_cards, sprints);
-
-                let export = AllBoardsExport {
-                    boards: vec![board_export],
-                };
-
-                BoardExporter::export_to_file(&export, self.input.as_str())?;
+            if let Some(board) = self.model.boards().get(board_idx) {
+                self.export_boards_to_file(
+                    std::slice::from_ref(board),
+                    Self::export_format_for(self.input.as_str()),
+                    self.input.as_str(),
+                )?;
             }
         }
         Ok(())
     }
 
     pub fn export_all_boards_with_filename(&self) -> io::Result<()> {
-        let boards = self.model.boards();
+        self.export_boards_to_file(
+            self.model.boards(),
+            Self::export_format_for(self.input.as_str()),
+            self.input.as_str(),
+        )
+    }
+
+    /// Markdown for `.md` files, CSV for `.csv`, iCalendar for `.ics` and
+    /// JSON otherwise.
+    fn export_format_for(filename: &str) -> BoardExportFormat {
+        BoardExportFormat::from_path(filename).unwrap_or_default()
+    }
+
+    /// Write `boards` and their columns, cards and sprints to `filename`.
+    pub(crate) fn export_boards_to_file(
+        &self,
+        boards: &[Board],
+        format: BoardExportFormat,
+        filename: &str,
+    ) -> io::Result<()> {
         let columns = self.model.columns();
         let cards = self.model.cards();
-        let archived_cards = self.model.archived_cards();
         let sprints = self.model.sprints();
-        let export =
-            BoardExporter::export_all_boards(boards, columns, cards, archived_cards, sprints);
-        BoardExporter::export_to_file(&export, self.input.as_str())?;
-        Ok(())
+        let prefixes = DefaultPrefixes {
+            card: self.app_config.effective_default_card_prefix(),
+            sprint: self.app_config.effective_default_sprint_prefix(),
+        };
+        match format {
+            BoardExportFormat::Json => {
+                let board_exports = boards
+                    .iter()
+                    .map(|board| {
+                        BoardExporter::export_board(
+                            board,
+                            columns,
+                            cards,
+                            self.model.archived_cards(),
+                            sprints,
+                            self.model.graph(),
+                        )
+                    })
+                    .collect();
+                BoardExporter::export_to_file(
+                    &AllBoardsExport::from_boards(board_exports),
+                    filename,
+                )
+            }
+            BoardExportFormat::Markdown => std::fs::write(
+                filename,
+                MarkdownExporter::export_boards(
+                    boards,
+                    columns,
+                    cards,
+                    sprints,
+                    self.model.graph(),
+                    prefixes,
+                ),
+            ),
+            BoardExportFormat::Csv => std::fs::write(
+                filename,
+                CsvExporter::export_cards(boards, columns, cards, sprints, prefixes),
+            ),
+            BoardExportFormat::Ics => std::fs::write(
+                filename,
+                IcalExporter::export_calendar(boards, columns, cards, sprints, prefixes),
+            ),
+            BoardExportFormat::Bundle => {
+                use kanban_domain::KanbanOperations;
+                let board_id = match boards {
+                    [board] => Some(board.id),
+                    _ if boards.len() == self.model.boards().len() => None,
+                    _ => return Err(io::Error::other("A bundle holds one board or all of them")),
+                };
+                let bundle = self
+                    .ctx
+                    .export_board_as(board_id, format)
+                    .map_err(|e| io::Error::other(e.to_string()))?;
+                std::fs::write(filename, bundle)
+            }
+        }
     }
 
     pub fn auto_save(&self) -> io::Result<()> {
@@ -1697,8 +1944,14 @@ impl App {
             let cards = self.model.cards();
             let archived_cards = self.model.archived_cards();
             let sprints = self.model.sprints();
-            let export =
-                BoardExporter::export_all_boards(boards, columns, cards, archived_cards, sprints);
+            let export = BoardExporter::export_all_boards(
+                boards,
+                columns,
+                cards,
+                archived_cards,
+                sprints,
+                self.model.graph(),
+            );
             BoardExporter::export_to_file(&export, filename)?;
         }
         Ok(())
@@ -1732,133 +1985,68 @@ impl App {
         }
     }
 
+    /// Edits a board field in the inline editor, or in $EDITOR when the
+    /// config asks for it.
     pub fn edit_board_field(
         &mut self,
         terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
         event_handler: &EventHandler,
         field: BoardField,
     ) -> io::Result<()> {
-        if let Some(board_idx) = self.selection.board.get() {
-            let boards = self.model.boards();
-            if let Some(board) = boards.get(board_idx) {
-                let temp_dir = std::env::temp_dir();
-                let (temp_file, current_content) = match field {
-                    BoardField::Name => {
-                        let temp_file = temp_dir.join(format!("kanban-board-{}-name.md", board.id));
-                        (temp_file, board.name.clone())
-                    }
-                    BoardField::Description => {
-                        let temp_file =
-                            temp_dir.join(format!("kanban-board-{}-description.md", board.id));
-                        let content = board.description.as_deref().unwrap_or("").to_string();
-                        (temp_file, content)
-                    }
-                };
-
-                let board_id = board.id;
-                if let Some(new_content) =
-                    edit_in_external_editor(terminal, event_handler, temp_file, &current_content)?
-                {
-                    let updates = match field {
-                        BoardField::Name => {
-                            if new_content.trim().is_empty() {
-                                None
-                            } else {
-                                Some(kanban_domain::BoardUpdate {
-                                    name: Some(new_content.trim().to_string()),
-                                    ..Default::default()
-                                })
-                            }
-                        }
-                        BoardField::Description => {
-                            let desc = if new_content.trim().is_empty() {
-                                kanban_domain::FieldUpdate::Clear
-                            } else {
-                                kanban_domain::FieldUpdate::Set(new_content)
-                            };
-                            Some(kanban_domain::BoardUpdate {
-                                description: desc,
-                                ..Default::default()
-                            })
-                        }
-                    };
-                    if let Some(updates) = updates {
-                        let cmd = kanban_domain::commands::Command::Board(
-                            kanban_domain::commands::BoardCommand::Update(
-                                kanban_domain::commands::UpdateBoard { board_id, updates },
-                            ),
-                        );
-                        if let Err(e) = self.execute_command(cmd) {
-                            tracing::error!("Failed to update board: {}", e);
-                        }
-                    }
-                }
-            }
-        }
-        Ok(())
+        let Some(board) = self
+            .selection
+            .board
+            .get()
+            .and_then(|idx| self.model.boards().get(idx))
+        else {
+            return Ok(());
+        };
+        let target = TextTarget::Board(board.id, field);
+        let current_content = match field {
+            BoardField::Name => board.name.clone(),
+            BoardField::Description => board.description.clone().unwrap_or_default(),
+        };
+        self.edit_text(terminal, event_handler, target, &current_content)
     }
 
+    /// Edits a field of the active card in the inline editor, or in $EDITOR
+    /// when the config asks for it.
     pub fn edit_card_field(
         &mut self,
         terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
         event_handler: &EventHandler,
         field: CardField,
     ) -> io::Result<()> {
-        if let Some(active_id) = self.selection.active_card_id {
-            if let Some(card) = self.model.card(active_id) {
-                let temp_dir = std::env::temp_dir();
-                let (temp_file, current_content) = match field {
-                    CardField::Title => {
-                        let temp_file = temp_dir.join(format!("kanban-card-{}-title.md", card.id));
-                        (temp_file, card.title.clone())
-                    }
-                    CardField::Description => {
-                        let temp_file =
-                            temp_dir.join(format!("kanban-card-{}-description.md", card.id));
-                        let content = card.description.as_deref().unwrap_or("").to_string();
-                        (temp_file, content)
-                    }
-                };
+        let Some(card) = self
+            .selection
+            .active_card_id
+            .and_then(|id| self.model.card(id))
+        else {
+            return Ok(());
+        };
+        let target = TextTarget::Card(card.id, field);
+        let current_content = match field {
+            CardField::Title => card.title.clone(),
+            CardField::Description => card.description.clone().unwrap_or_default(),
+        };
+        self.edit_text(terminal, event_handler, target, &current_content)
+    }
 
-                let card_id = card.id;
-                if let Some(new_content) =
-                    edit_in_external_editor(terminal, event_handler, temp_file, &current_content)?
-                {
-                    let updates = match field {
-                        CardField::Title => {
-                            if new_content.trim().is_empty() {
-                                None
-                            } else {
-                                Some(kanban_domain::CardUpdate {
-                                    title: Some(new_content.trim().to_string()),
-                                    ..Default::default()
-                                })
-                            }
-                        }
-                        CardField::Description => {
-                            let desc = if new_content.trim().is_empty() {
-                                kanban_domain::FieldUpdate::Clear
-                            } else {
-                                kanban_domain::FieldUpdate::Set(new_content)
-                            };
-                            Some(kanban_domain::CardUpdate {
-                                description: desc,
-                                ..Default::default()
-                            })
-                        }
-                    };
-                    if let Some(updates) = updates {
-                        let cmd = kanban_domain::commands::Command::Card(
-                            kanban_domain::commands::CardCommand::Update(
-                                kanban_domain::commands::UpdateCard { card_id, updates },
-                            ),
-                        );
-                        if let Err(e) = self.execute_command(cmd) {
-                            tracing::error!("Failed to update card: {}", e);
-                        }
-                    }
-                }
-            }
+    fn edit_text(
+        &mut self,
+        terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+        event_handler: &EventHandler,
+        target: TextTarget,
+        current_content: &str,
+    ) -> io::Result<()> {
+        if !self.uses_external_editor() {
+            self.open_text_editor(target, current_content);
+            return Ok(());
+        }
+        if let Some(new_content) =
+            edit_in_external_editor(terminal, event_handler, target.temp_file(), current_content)?
+        {
+            self.apply_text_edit(target, new_content);
         }
         Ok(())
     }
@@ -1932,7 +2120,7 @@ impl App {
     ) -> KanbanResult<()> {
         self.load_initial_state().await;
 
-        let mut terminal = setup_terminal()?;
+        let mut terminal = setup_terminal(self.app_config.effective_mouse())?;
 
         // Initialize file watching if a save file is configured
         if let Some(ref save_file) = self.persistence.save_file {
@@ -2012,6 +2200,13 @@ impl App {
                                                 break;
                                             }
                                         }
+                                        Event::Mouse(m) => {
+                                            let should_restart = self.handle_mouse_input(m, &mut terminal, &events);
+                                            if should_restart {
+                                                break;
+                                            }
+                                        }
+                                        Event::Resize(..) => {}
                                         Event::Tick => {
                                             saw_tick = true;
                                         }
@@ -2027,6 +2222,16 @@ impl App {
                                     }
                                 }
                             }
+                            Event::Mouse(mouse) => {
+                                self.needs_redraw = true;
+                                let should_restart = self.handle_mouse_input(mouse, &mut terminal, &events);
+                                if should_restart {
+                                    break;
+                                }
+                            }
+                            Event::Resize(..) => {
+                                self.needs_redraw = true;
+                            }
                             Event::Tick => {
                                 if !self.animation.animating.is_empty() {
                                     self.needs_redraw = true;
@@ -2125,7 +2330,7 @@ impl App {
                             MigrationState::Idle => unreachable!(),
                         };
                         if let Some(result) = result {
-                            self.handle_migration_complete(old_config, result).await;
+                            self.handle_migration_complete(*old_config, result).await;
                         }
                     }
                     export_result = async {
@@ -2241,53 +2446,82 @@ impl App {
     }
 
     pub fn import_board_from_file(&mut self, filename: &str) -> io::Result<()> {
+        self.import_file(filename, false)
+    }
+
+    /// Imports the file with freshly generated ids so it can sit alongside
+    /// the boards it was exported from.
+    pub fn import_board_copy_from_file(&mut self, filename: &str) -> io::Result<()> {
+        self.import_file(filename, true)
+    }
+
+    fn import_file(&mut self, filename: &str, as_copy: bool) -> io::Result<()> {
         let content = std::fs::read_to_string(filename)?;
 
+        if BoardExportFormat::from_path(filename) == Some(BoardExportFormat::Csv) {
+            if as_copy {
+                self.set_error("CSV imports update cards in place; only JSON can be copied");
+            } else {
+                self.import_cards_csv(&content);
+            }
+            return Ok(());
+        }
+
         let first_new_index = self.model.boards().len();
 
-        // Try V2 format first (preserves graph)
-        if let Some(snapshot) = BoardImporter::try_load_snapshot(&content) {
-            let cmd = kanban_domain::commands::Command::Board(
-                kanban_domain::commands::BoardCommand::Import(
-                    kanban_domain::commands::ImportEntities {
-                        boards: snapshot.boards,
-                        columns: snapshot.columns,
-                        cards: snapshot.cards,
-                        archived_cards: snapshot.archived_cards,
-                        sprints: snapshot.sprints,
-                        graph: Some(snapshot.graph),
-                    },
-                ),
-            );
-            if let Err(e) = self.ctx.execute_command(cmd) {
+        if BoardExportFormat::from_path(filename) == Some(BoardExportFormat::Bundle) {
+            use kanban_domain::KanbanOperations;
+            let imported = if as_copy {
+                self.ctx.import_board_as_copy(&content)
+            } else {
+                self.ctx.import_board(&content)
+            };
+            if let Err(e) = imported {
                 self.set_error(e.to_string());
-                tracing::error!("Failed to import V2 board: {}", e);
+                tracing::error!("Failed to import bundle: {}", e);
                 return Ok(());
             }
-
             self.selection.board.set(Some(first_new_index));
             self.switch_view_strategy(kanban_domain::TaskListView::GroupedByColumn);
             return Ok(());
         }
 
-        // Fall back to V1 format (no graph)
-        let import = BoardImporter::import_from_json(&content)?;
-        let entities = BoardImporter::extract_entities(import);
+        // Try V2 format first (preserves graph), then fall back to the nested
+        // boards format; versions before 3 carry no graph
+        let snapshot = match BoardImporter::try_load_snapshot(&content) {
+            Some(snapshot) => snapshot,
+            None => {
+                let import = BoardImporter::import_from_json(&content)?;
+                match BoardImporter::extract_entities(import) {
+                    Ok(entities) => Snapshot::from(entities),
+                    Err(e) => {
+                        self.set_error(e.to_string());
+                        tracing::error!("Failed to import board graph: {}", e);
+                        return Ok(());
+                    }
+                }
+            }
+        };
+        let snapshot = if as_copy {
+            BoardCopier::remap_ids(snapshot)
+        } else {
+            snapshot
+        };
 
         let cmd =
             kanban_domain::commands::Command::Board(kanban_domain::commands::BoardCommand::Import(
                 kanban_domain::commands::ImportEntities {
-                    boards: entities.boards,
-                    columns: entities.columns,
-                    cards: entities.cards,
-                    archived_cards: entities.archived_cards,
-                    sprints: entities.sprints,
-                    graph: None,
+                    boards: snapshot.boards,
+                    columns: snapshot.columns,
+                    cards: snapshot.cards,
+                    archived_cards: snapshot.archived_cards,
+                    sprints: snapshot.sprints,
+                    graph: Some(snapshot.graph),
                 },
             ));
         if let Err(e) = self.ctx.execute_command(cmd) {
             self.set_error(e.to_string());
-            tracing::error!("Failed to import V1 board: {}", e);
+            tracing::error!("Failed to import board: {}", e);
             return Ok(());
         }
 
@@ -2297,6 +2531,32 @@ impl App {
         Ok(())
     }
 
+    /// Apply a CSV of cards to the selected project, or match identifiers
+    /// across all projects when none is selected.
+    fn import_cards_csv(&mut self, content: &str) {
+        use kanban_domain::KanbanOperations;
+
+        let board_id = self
+            .selection
+            .board
+            .get()
+            .and_then(|idx| self.model.boards().get(idx))
+            .map(|b| b.id);
+        match self
+            .ctx
+            .import_cards_csv(board_id, content, &CsvMapping::default())
+        {
+            Ok(summary) => self.set_success(format!(
+                "Imported CSV: {} updated, {} created, {} unchanged",
+                summary.updated, summary.created, summary.unchanged
+            )),
+            Err(e) => {
+                tracing::error!("Failed to import CSV: {}", e);
+                self.set_error(e.to_string());
+            }
+        }
+    }
+
     async fn auto_reload_from_external_change(&mut self) {
         match self.ctx.reload().await {
             Ok(()) => {
@@ -2346,6 +2606,31 @@ impl App {
         }
     }
 
+    /// Re-read the git state of the active card for the detail view.
+    pub fn refresh_card_git(&mut self) {
+        self.git.status = None;
+        let Some(card) = self.get_card_for_detail_view() else {
+            return;
+        };
+        let Some(board) = self
+            .selection
+            .active_board_index
+            .and_then(|idx| self.model.boards().get(idx))
+        else {
+            return;
+        };
+        let prefix = self.app_config.effective_default_card_prefix();
+        let identifier = card.identifier(board, self.model.sprints(), prefix);
+        let branch = card.branch_name(board, self.model.sprints(), prefix);
+        let Some(repository) = self.git.repository() else {
+            return;
+        };
+        match repository.card_status(&identifier, &branch) {
+            Ok(status) => self.git.status = Some((card.id, status)),
+            Err(e) => tracing::warn!("Failed to read git status for {}: {}", identifier, e),
+        }
+    }
+
     pub fn copy_branch_name(&mut self) {
         self.copy_card_output("branch name", |card, board, sprints, prefix| {
             card.branch_name(board, sprints, prefix)
@@ -2405,10 +2690,13 @@ impl App {
     }
 }
 
-fn setup_terminal() -> Result<Terminal<CrosstermBackend<io::Stdout>>, io::Error> {
+fn setup_terminal(mouse: bool) -> Result<Terminal<CrosstermBackend<io::Stdout>>, io::Error> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
+    if mouse {
+        crate::events::set_mouse_capture(true)?;
+    }
     let backend = CrosstermBackend::new(stdout);
     Terminal::new(backend)
 }
@@ -2416,6 +2704,9 @@ fn setup_terminal() -> Result<Terminal<CrosstermBackend<io::Stdout>>, io::Error>
 fn restore_terminal(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
 ) -> Result<(), io::Error> {
+    if crate::events::mouse_capture_enabled() {
+        crate::events::set_mouse_capture(false)?;
+    }
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
     terminal.show_cursor()?;
@@ -2458,10 +2749,13 @@ impl App {
             ui_state: UiState::default(),
             sprint_view: SprintViewState::default(),
             view: ViewState::default(),
+            mouse: MouseState::default(),
             model: model::Model::default(),
             relationship: RelationshipState::default(),
+            git: GitState::default(),
             save_error: None,
             pending_key: None,
+            keymap: crate::keybindings::Keymap::default(),
             has_data_file: true,
             cli_file_provided: false,
             cli_file_override: false,
@@ -2561,10 +2855,13 @@ mod tests {
             ui_state: UiState::default(),
             sprint_view: SprintViewState::default(),
             view: ViewState::default(),
+            mouse: MouseState::default(),
             model: model::Model::default(),
             relationship: RelationshipState::default(),
+            git: GitState::default(),
             save_error: None,
             pending_key: None,
+            keymap: crate::keybindings::Keymap::default(),
             has_data_file: true,
             cli_file_provided: false,
             cli_file_override: false,
@@ -3,10 +3,12 @@ pub enum DialogMode {
     CreateBoard,
     CreateCard,
     RenameBoard,
+    CloneBoard,
     ExportBoard,
     ExportAll,
     ImportBoard,
     SetCardPoints,
+    AddAttachment,
     SetCardPriority,
     SetMultipleCardsPriority,
     SetBranchPrefix,
@@ -29,6 +31,8 @@ pub enum DialogMode {
     CarryOverSprint,
     ExportBoards,
     ChooseStorageFile,
+    CommandPalette,
+    EditText,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -0,0 +1,15 @@
+use crate::hit_test::HitTarget;
+use std::time::Instant;
+use uuid::Uuid;
+
+/// Two left clicks on the same target within this many milliseconds open it.
+pub(crate) const DOUBLE_CLICK_MS: u128 = 400;
+
+#[derive(Default)]
+pub struct MouseState {
+    /// Time and target of the last left click, to spot double clicks.
+    pub last_click: Option<(Instant, HitTarget)>,
+    /// Card picked up by a left click in the kanban view; released over
+    /// another column, it moves there.
+    pub dragging: Option<Uuid>,
+}
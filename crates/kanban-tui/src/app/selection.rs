@@ -10,6 +10,10 @@ pub struct SelectionHub {
     pub sprint_scroll: Cell<usize>,
     pub active_sprint_index: Option<usize>,
     pub card_navigation_history: Vec<uuid::Uuid>,
+    /// Selected attachment of the card in the detail view.
+    pub attachment: usize,
+    /// Selected card reference of the card in the detail view.
+    pub reference: usize,
     pub settings_config: SelectionState,
     pub settings_config_file: SelectionState,
     pub settings_storage: SelectionState,
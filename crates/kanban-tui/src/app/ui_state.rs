@@ -1,5 +1,7 @@
+use crate::command_palette::CommandPaletteState;
 use crate::components::{generic_list::ListComponent, Banner};
 use crate::keybindings::KeybindingAction;
+use crate::text_editor::TextEditorState;
 use std::time::Instant;
 
 pub struct UiState {
@@ -7,6 +9,8 @@ pub struct UiState {
     pub help_list: ListComponent,
     pub help_pending_action: Option<(Instant, KeybindingAction)>,
     pub error_log_list: ListComponent,
+    pub command_palette: CommandPaletteState,
+    pub text_editor: TextEditorState,
 }
 
 impl Default for UiState {
@@ -16,6 +20,8 @@ impl Default for UiState {
             help_list: ListComponent::new(false),
             help_pending_action: None,
             error_log_list: ListComponent::new(false),
+            command_palette: CommandPaletteState::default(),
+            text_editor: TextEditorState::default(),
         }
     }
 }
@@ -1,13 +1,29 @@
 use crate::card_list::CardListId;
 use crate::card_list_component::{CardListComponent, CardListComponentConfig};
 use crate::view_strategy::{UnifiedViewStrategy, ViewStrategy};
-use ratatui::layout::Rect;
+use kanban_core::{AppConfig, PREVIEW_SIZE_RANGE};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
 
 pub struct ViewState {
     pub strategy: Box<dyn ViewStrategy>,
     pub card_list_component: CardListComponent,
     pub viewport_height: usize,
     pub last_frame_area: Rect,
+    pub hit_areas: HitAreas,
+    pub preview: PreviewState,
+}
+
+/// Where the last frame drew the panels the mouse can point at. Cleared at
+/// the start of every frame, so a panel that is not on screen is `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HitAreas {
+    pub projects: Option<Rect>,
+    pub tasks: Option<Rect>,
+    /// The text of the card or board description in a detail view.
+    pub description: Option<Rect>,
+    /// The pane previewing the selected card beside the card list.
+    pub preview: Option<Rect>,
+    pub footer: Option<Rect>,
 }
 
 impl Default for ViewState {
@@ -20,6 +36,87 @@ impl Default for ViewState {
             ),
             viewport_height: 20,
             last_frame_area: Rect::default(),
+            hit_areas: HitAreas::default(),
+            preview: PreviewState::default(),
+        }
+    }
+}
+
+/// Where the selected card's detail is shown next to the card list.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PreviewLayout {
+    #[default]
+    Off,
+    Right,
+    Bottom,
+}
+
+impl PreviewLayout {
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "right" => Self::Right,
+            "bottom" => Self::Bottom,
+            _ => Self::Off,
+        }
+    }
+
+    /// Off, then right, then bottom.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Right,
+            Self::Right => Self::Bottom,
+            Self::Bottom => Self::Off,
+        }
+    }
+}
+
+/// The split-pane preview: its layout and the percentage of the main area
+/// the preview pane takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PreviewState {
+    pub layout: PreviewLayout,
+    pub size: u16,
+}
+
+impl Default for PreviewState {
+    fn default() -> Self {
+        Self::from_config(&AppConfig::default())
+    }
+}
+
+impl PreviewState {
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self {
+            layout: PreviewLayout::from_name(config.effective_preview()),
+            size: config.effective_preview_size(),
         }
     }
+
+    pub fn is_enabled(&self) -> bool {
+        self.layout != PreviewLayout::Off
+    }
+
+    /// Grows the preview pane by `step` percent, or shrinks it when negative.
+    pub fn resize(&mut self, step: i16) {
+        let size = self.size.saturating_add_signed(step);
+        self.size = size.clamp(*PREVIEW_SIZE_RANGE.start(), *PREVIEW_SIZE_RANGE.end());
+    }
+
+    /// Splits `area` into the list area and the preview area, or `None`
+    /// when the preview is off.
+    pub fn split(&self, area: Rect) -> Option<(Rect, Rect)> {
+        let direction = match self.layout {
+            PreviewLayout::Off => return None,
+            PreviewLayout::Right => Direction::Horizontal,
+            PreviewLayout::Bottom => Direction::Vertical,
+        };
+        let chunks = Layout::default()
+            .direction(direction)
+            .constraints([
+                Constraint::Percentage(100 - self.size),
+                Constraint::Percentage(self.size),
+            ])
+            .split(area);
+        Some((chunks[0], chunks[1]))
+    }
 }
@@ -0,0 +1,216 @@
+use crossterm::event::KeyEvent;
+use kanban_core::InputState;
+use uuid::Uuid;
+
+/// What running a palette entry does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaletteCommand {
+    /// Replay the action's default keys through the key handlers of the
+    /// mode the palette was opened from.
+    Keys(Vec<KeyEvent>),
+    JumpToBoard(Uuid),
+    JumpToCard(Uuid),
+    JumpToSprint(Uuid),
+}
+
+#[derive(Debug, Clone)]
+pub struct PaletteEntry {
+    pub label: String,
+    /// Key shown next to the entry; empty for jump entries.
+    pub key: String,
+    pub command: PaletteCommand,
+}
+
+impl PaletteEntry {
+    pub fn new(label: impl Into<String>, key: impl Into<String>, command: PaletteCommand) -> Self {
+        Self {
+            label: label.into(),
+            key: key.into(),
+            command,
+        }
+    }
+}
+
+/// UI state for the command palette.
+///
+/// `matches` holds indices into `entries` for the current query, best match
+/// first; `selected` indexes into `matches`.
+pub struct CommandPaletteState {
+    pub input: InputState,
+    pub entries: Vec<PaletteEntry>,
+    pub matches: Vec<usize>,
+    pub selected: usize,
+}
+
+impl CommandPaletteState {
+    pub fn new() -> Self {
+        Self {
+            input: InputState::new(),
+            entries: Vec::new(),
+            matches: Vec::new(),
+            selected: 0,
+        }
+    }
+
+    pub fn open(&mut self, entries: Vec<PaletteEntry>) {
+        self.input.clear();
+        self.entries = entries;
+        self.update_matches();
+    }
+
+    pub fn close(&mut self) {
+        self.input.clear();
+        self.entries.clear();
+        self.matches.clear();
+        self.selected = 0;
+    }
+
+    pub fn query(&self) -> &str {
+        self.input.as_str()
+    }
+
+    /// Re-rank the entries against the current query and reset the selection
+    /// to the best match.
+    pub fn update_matches(&mut self) {
+        let query = self.input.as_str();
+        let mut scored: Vec<(i32, usize)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, e)| fuzzy_score(query, &e.label).map(|s| (s, i)))
+            .collect();
+        // Stable sort keeps context actions ahead of jump entries on ties.
+        scored.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+        self.matches = scored.into_iter().map(|(_, i)| i).collect();
+        self.selected = 0;
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.matches.is_empty() {
+            self.selected = (self.selected + 1) % self.matches.len();
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        if !self.matches.is_empty() {
+            self.selected = (self.selected + self.matches.len() - 1) % self.matches.len();
+        }
+    }
+
+    pub fn selected_entry(&self) -> Option<&PaletteEntry> {
+        self.matches
+            .get(self.selected)
+            .and_then(|&i| self.entries.get(i))
+    }
+}
+
+impl Default for CommandPaletteState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Score `text` against `query` as a case-insensitive subsequence match, or
+/// `None` if some query character cannot be matched in order.
+///
+/// Consecutive matches and matches at the start of a word score higher, and
+/// the best-scoring alignment wins, so `"mcr"` ranks "Move card right" above
+/// "Manage children".
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+    let text: Vec<char> = text.chars().collect();
+    let lower: Vec<char> = text
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    let base = |j: usize| {
+        if j == 0 || !text[j - 1].is_alphanumeric() {
+            4
+        } else {
+            1
+        }
+    };
+
+    // best[j]: best score with the current query char matched at text[j].
+    let mut best: Vec<Option<i32>> = lower
+        .iter()
+        .enumerate()
+        .map(|(j, &c)| (c == query[0]).then(|| base(j)))
+        .collect();
+    for &qc in &query[1..] {
+        let mut next = vec![None; text.len()];
+        let mut earlier_best: Option<i32> = None;
+        for j in 1..text.len() {
+            if j >= 2 {
+                earlier_best = earlier_best.max(best[j - 2]);
+            }
+            if lower[j] != qc {
+                continue;
+            }
+            let consecutive = best[j - 1].map(|s| s + 5);
+            next[j] = consecutive.max(earlier_best).map(|s| s + base(j));
+        }
+        best = next;
+    }
+    best.into_iter().flatten().max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(label: &str) -> PaletteEntry {
+        PaletteEntry::new(label, "", PaletteCommand::Keys(vec![]))
+    }
+
+    #[test]
+    fn test_fuzzy_score_requires_ordered_subsequence() {
+        assert!(fuzzy_score("mcr", "Move card right").is_some());
+        assert!(fuzzy_score("rcm", "Move card right").is_none());
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+        assert!(fuzzy_score("MOVE", "move card").is_some());
+    }
+
+    #[test]
+    fn test_fuzzy_score_prefers_word_starts_and_runs() {
+        let word_starts = fuzzy_score("mcr", "Move card right").unwrap();
+        let scattered = fuzzy_score("mcr", "Manage children").unwrap();
+        assert!(word_starts > scattered);
+        assert!(fuzzy_score("arch", "Archive card") > fuzzy_score("arch", "Search cards"));
+    }
+
+    #[test]
+    fn test_update_matches_ranks_and_filters() {
+        let mut state = CommandPaletteState::new();
+        state.open(vec![
+            entry("Manage children"),
+            entry("Move card right"),
+            entry("Undo"),
+        ]);
+        assert_eq!(state.matches.len(), 3);
+
+        for c in "mcr".chars() {
+            state.input.insert_char(c);
+        }
+        state.update_matches();
+        assert_eq!(state.selected_entry().unwrap().label, "Move card right");
+        assert_eq!(state.matches.len(), 2);
+    }
+
+    #[test]
+    fn test_selection_wraps() {
+        let mut state = CommandPaletteState::new();
+        state.open(vec![entry("a"), entry("b")]);
+        state.select_prev();
+        assert_eq!(state.selected_entry().unwrap().label, "b");
+        state.select_next();
+        assert_eq!(state.selected_entry().unwrap().label, "a");
+    }
+}
@@ -1,3 +1,4 @@
+use crate::theme::current;
 use ratatui::{
     layout::{Alignment, Rect},
     style::{Color, Modifier, Style},
@@ -15,8 +16,8 @@ pub enum BannerVariant {
 impl BannerVariant {
     fn color(self) -> Color {
         match self {
-            BannerVariant::Error => Color::Red,
-            BannerVariant::Success => Color::Green,
+            BannerVariant::Error => current().error,
+            BannerVariant::Success => current().active_item,
         }
     }
 }
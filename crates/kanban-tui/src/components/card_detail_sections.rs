@@ -1,9 +1,11 @@
 use crate::components::metadata_line_multi;
+use crate::markdown_renderer::{render_markdown_with, MarkdownOptions, RenderedMarkdown};
 use crate::theme::*;
 use kanban_core::AppConfig;
 use kanban_domain::{Board, Card, Sprint};
+use kanban_service::git::CardGitStatus;
 use ratatui::{
-    style::{Color, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
 };
 
@@ -32,7 +34,7 @@ pub fn build_metadata_lines(
         if let Some(due_date) = card.due_date {
             Line::from(Span::styled(
                 format!("Due: {}", due_date.format("%Y-%m-%d %H:%M")),
-                Style::default().fg(Color::Red),
+                Style::default().fg(current().error),
             ))
         } else {
             Line::from(Span::styled("No due date", label_text()))
@@ -48,14 +50,16 @@ pub fn build_metadata_lines(
 }
 
 pub fn build_description_lines(card: &Card) -> Vec<Line<'static>> {
-    if let Some(desc_text) = &card.description {
-        if desc_text.trim().is_empty() {
-            vec![Line::from(Span::styled("No description", label_text()))]
-        } else {
-            crate::markdown_renderer::render_markdown(desc_text)
-        }
-    } else {
-        vec![Line::from(Span::styled("No description", label_text()))]
+    build_description(card.description.as_deref(), &MarkdownOptions::default()).lines
+}
+
+pub fn build_description(description: Option<&str>, options: &MarkdownOptions) -> RenderedMarkdown {
+    match description {
+        Some(text) if !text.trim().is_empty() => render_markdown_with(text, options),
+        _ => RenderedMarkdown::plain(vec![Line::from(Span::styled(
+            "No description",
+            label_text(),
+        ))]),
     }
 }
 
@@ -85,7 +89,7 @@ pub fn build_sprint_logs_lines(card: &Card) -> Vec<Line<'static>> {
             Span::styled(format!("{}. ", absolute_idx + 1), label_text()),
             Span::styled(
                 format!("{} ", sprint_name_str),
-                Style::default().fg(Color::Cyan),
+                Style::default().fg(current().accent),
             ),
             Span::styled(status_str, label_text()),
         ]));
@@ -103,3 +107,128 @@ pub fn build_sprint_logs_lines(card: &Card) -> Vec<Line<'static>> {
 
     sprint_log_lines
 }
+
+/// One line per checklist item, with an overflow line once more than
+/// `max_items` exist. Empty when the card has no checklist.
+pub fn build_checklist_lines(card: &Card, max_items: usize) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line<'static>> = card
+        .checklist
+        .iter()
+        .take(max_items)
+        .enumerate()
+        .map(|(i, item)| {
+            let (checkbox, style) = if item.done {
+                (
+                    "[x]",
+                    Style::default()
+                        .fg(current().done_text)
+                        .add_modifier(Modifier::CROSSED_OUT),
+                )
+            } else {
+                ("[ ]", normal_text())
+            };
+            Line::from(vec![
+                Span::styled(format!("{}. {} ", i + 1, checkbox), label_text()),
+                Span::styled(item.text.clone(), style),
+            ])
+        })
+        .collect();
+    let hidden = card.checklist.len().saturating_sub(max_items);
+    if hidden > 0 {
+        lines.push(Line::from(Span::styled(
+            format!("... ({} more)", hidden),
+            label_text(),
+        )));
+    }
+    lines
+}
+
+/// One line per attachment, scrolled so the `selected` one stays in view
+/// when more than `max_items` exist. Empty when the card has none.
+pub fn build_attachment_lines(
+    card: &Card,
+    selected: usize,
+    max_items: usize,
+) -> Vec<Line<'static>> {
+    let count = card.attachments.len();
+    if count == 0 {
+        return Vec::new();
+    }
+    let selected = selected.min(count - 1);
+    let start = (selected + 1).saturating_sub(max_items);
+    let mut lines: Vec<Line<'static>> = card
+        .attachments
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(max_items)
+        .map(|(i, attachment)| {
+            let (marker, name_style) = if i == selected {
+                ("> ", bold_highlight())
+            } else {
+                ("  ", normal_text())
+            };
+            Line::from(vec![
+                Span::styled(format!("{}{}. ", marker, i + 1), label_text()),
+                Span::styled(attachment.name.clone(), name_style),
+                Span::styled(
+                    format!("  {}  {}", attachment.display_size(), attachment.mime_type),
+                    label_text(),
+                ),
+            ])
+        })
+        .collect();
+    let hidden = count - start - lines.len();
+    if start > 0 || hidden > 0 {
+        lines.push(Line::from(Span::styled(
+            format!("... ({} of {}, [ ] to scroll)", selected + 1, count),
+            label_text(),
+        )));
+    }
+    lines
+}
+
+pub fn build_git_lines(status: &CardGitStatus, max_commits: usize) -> Vec<Line<'static>> {
+    let mut branch_line = vec![
+        Span::styled("Branch: ", label_text()),
+        Span::styled(status.branch.clone(), active_item()),
+    ];
+    if !status.branch_exists {
+        branch_line.push(Span::styled(" (not created)", label_text()));
+    } else if let (Some(base), Some(ahead), Some(behind)) =
+        (&status.base, status.ahead, status.behind)
+    {
+        branch_line.push(Span::styled(
+            format!("  ↑{} ↓{} vs {}", ahead, behind, base),
+            normal_text(),
+        ));
+    }
+    let mut lines = vec![Line::from(branch_line)];
+
+    if status.commits.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("No commits mention {}", status.identifier),
+            label_text(),
+        )));
+    }
+    for commit in status.commits.iter().take(max_commits) {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{} ", commit.short_hash),
+                Style::default().fg(current().accent),
+            ),
+            Span::styled(commit.summary.clone(), normal_text()),
+            Span::styled(
+                format!("  {}", commit.date.format("%Y-%m-%d")),
+                label_text(),
+            ),
+        ]));
+    }
+    if status.commits.len() > max_commits {
+        lines.push(Line::from(Span::styled(
+            format!("... ({} more commits)", status.commits.len() - max_commits),
+            label_text(),
+        )));
+    }
+    lines
+}
@@ -22,9 +22,9 @@ pub fn render_card_list_item(config: CardListItemConfig) -> Line<'static> {
     let is_done = config.card.status == CardStatus::Done;
 
     let (checkbox, text_color) = if is_done {
-        ("[x]", DONE_TEXT)
+        ("[x]", current().done_text)
     } else {
-        ("[ ]", NORMAL_TEXT)
+        ("[ ]", current().normal_text)
     };
 
     let mut base_style = Style::default().fg(text_color);
@@ -37,14 +37,14 @@ pub fn render_card_list_item(config: CardListItemConfig) -> Line<'static> {
     // Apply animation flash effect if card is animating
     if let Some(animation_type) = config.animation_type {
         let flash_bg = match animation_type {
-            AnimationType::Archiving | AnimationType::Deleting => FLASH_DELETE,
-            AnimationType::Restoring => FLASH_RESTORE,
+            AnimationType::Archiving | AnimationType::Deleting => current().flash_delete,
+            AnimationType::Restoring => current().flash_restore,
         };
         base_style = base_style.bg(flash_bg);
         title_style = title_style.bg(flash_bg);
     } else if config.is_selected && config.is_focused {
-        base_style = base_style.bg(SELECTED_BG);
-        title_style = title_style.bg(SELECTED_BG);
+        base_style = base_style.bg(current().selected_bg);
+        title_style = title_style.bg(current().selected_bg);
     }
 
     let suffix_text = if config.show_sprint_name {
@@ -85,12 +85,12 @@ pub fn render_card_list_item(config: CardListItemConfig) -> Line<'static> {
     };
 
     if config.is_selected && config.is_focused {
-        points_style = points_style.bg(SELECTED_BG);
+        points_style = points_style.bg(current().selected_bg);
     }
 
     let mut priority_style_val = priority_style(config.card.priority);
     if config.is_selected && config.is_focused {
-        priority_style_val = priority_style_val.bg(SELECTED_BG);
+        priority_style_val = priority_style_val.bg(current().selected_bg);
     }
 
     let points_text = config
@@ -112,11 +112,23 @@ pub fn render_card_list_item(config: CardListItemConfig) -> Line<'static> {
     if !suffix_text.is_empty() {
         let mut suffix_style = label_text();
         if config.is_selected && config.is_focused {
-            suffix_style = suffix_style.bg(SELECTED_BG);
+            suffix_style = suffix_style.bg(current().selected_bg);
         }
         spans.push(Span::styled(suffix_text, suffix_style));
     }
 
+    if let Some(progress) = config.card.checklist_progress() {
+        let mut progress_style = if progress.is_complete() {
+            Style::default().fg(current().done_text)
+        } else {
+            label_text()
+        };
+        if config.is_selected && config.is_focused {
+            progress_style = progress_style.bg(current().selected_bg);
+        }
+        spans.push(Span::styled(format!(" [{}]", progress), progress_style));
+    }
+
     Line::from(spans)
 }
 
@@ -127,7 +139,9 @@ fn build_title_spans(title: &str, base_style: Style, query: Option<&str>) -> Vec
 
     let title_lower = title.to_lowercase();
     let query_lower = q.to_lowercase();
-    let highlight_style = base_style.fg(HIGHLIGHT_TEXT).add_modifier(Modifier::BOLD);
+    let highlight_style = base_style
+        .fg(current().highlight_text)
+        .add_modifier(Modifier::BOLD);
 
     // Map byte offset in title_lower → byte offset in title.
     // to_lowercase() can expand chars (e.g. İ → "i\u{307}"), so offsets
@@ -181,11 +195,12 @@ fn build_title_spans(title: &str, base_style: Style, query: Option<&str>) -> Vec
 #[cfg(test)]
 mod tests {
     use super::build_title_spans;
-    use crate::theme::HIGHLIGHT_TEXT;
+    use crate::theme::current;
     use ratatui::style::{Modifier, Style};
 
     fn highlight_style(base: Style) -> Style {
-        base.fg(HIGHLIGHT_TEXT).add_modifier(Modifier::BOLD)
+        base.fg(current().highlight_text)
+            .add_modifier(Modifier::BOLD)
     }
 
     #[test]
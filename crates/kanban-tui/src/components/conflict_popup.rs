@@ -3,7 +3,7 @@ use crate::components::centered_rect;
 use crate::theme::*;
 use ratatui::{
     layout::{Constraint, Direction, Layout},
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
@@ -16,7 +16,7 @@ pub fn render_conflict_resolution_popup(_app: &App, frame: &mut Frame) {
     let block = Block::default()
         .title("File Conflict Detected")
         .borders(Borders::ALL)
-        .style(Style::default().bg(Color::Black));
+        .style(Style::default().bg(current().popup_bg));
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -34,13 +34,13 @@ pub fn render_conflict_resolution_popup(_app: &App, frame: &mut Frame) {
     let message = Paragraph::new(
         "The file was modified by another instance.\nChoose how to resolve this conflict:",
     )
-    .style(Style::default().fg(Color::Yellow));
+    .style(Style::default().fg(current().highlight_text));
     frame.render_widget(message, chunks[0]);
 
     let options = vec![
         Line::from(Span::styled(
             "(O)verwrite",
-            Style::default().fg(Color::Cyan),
+            Style::default().fg(current().accent),
         )),
         Line::from(Span::styled(
             "  Keep your changes and overwrite the file",
@@ -49,7 +49,7 @@ pub fn render_conflict_resolution_popup(_app: &App, frame: &mut Frame) {
         Line::from(""),
         Line::from(Span::styled(
             "(T)ake theirs",
-            Style::default().fg(Color::Cyan),
+            Style::default().fg(current().accent),
         )),
         Line::from(Span::styled(
             "  Discard your changes and reload the file",
@@ -71,7 +71,7 @@ pub fn render_external_change_detected_popup(_app: &App, frame: &mut Frame) {
     let block = Block::default()
         .title("External File Change Detected")
         .borders(Borders::ALL)
-        .style(Style::default().bg(Color::Black));
+        .style(Style::default().bg(current().popup_bg));
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -89,17 +89,23 @@ pub fn render_external_change_detected_popup(_app: &App, frame: &mut Frame) {
     let message = Paragraph::new(
         "The file was modified by another instance.\nYou have unsaved changes. Choose an action:",
     )
-    .style(Style::default().fg(Color::Yellow));
+    .style(Style::default().fg(current().highlight_text));
     frame.render_widget(message, chunks[0]);
 
     let options = vec![
-        Line::from(Span::styled("(R)eload", Style::default().fg(Color::Cyan))),
+        Line::from(Span::styled(
+            "(R)eload",
+            Style::default().fg(current().accent),
+        )),
         Line::from(Span::styled(
             "  Discard your changes and reload the file",
             label_text(),
         )),
         Line::from(""),
-        Line::from(Span::styled("(K)eep", Style::default().fg(Color::Cyan))),
+        Line::from(Span::styled(
+            "(K)eep",
+            Style::default().fg(current().accent),
+        )),
         Line::from(Span::styled(
             "  Continue with your changes (save will overwrite)",
             label_text(),
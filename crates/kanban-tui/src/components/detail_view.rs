@@ -1,6 +1,5 @@
-use crate::theme::{
-    colors::SELECTED_BG, focused_border, label_text, normal_text, unfocused_border,
-};
+use crate::theme::current;
+use crate::theme::{focused_border, label_text, normal_text, unfocused_border};
 use ratatui::{
     style::Style,
     text::{Line, Span},
@@ -81,8 +80,11 @@ pub fn metadata_line_selectable<'a>(
 ) -> Line<'a> {
     if selected {
         Line::from(vec![
-            Span::styled(format!("{}: ", label), label_text().bg(SELECTED_BG)),
-            Span::styled(value.into(), normal_text().bg(SELECTED_BG)),
+            Span::styled(
+                format!("{}: ", label),
+                label_text().bg(current().selected_bg),
+            ),
+            Span::styled(value.into(), normal_text().bg(current().selected_bg)),
         ])
     } else {
         metadata_line(label, value)
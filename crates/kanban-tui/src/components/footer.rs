@@ -1,8 +1,11 @@
 use crate::app::{App, AppMode};
+use crate::keybindings::keymap::key_events_for_label;
+use crate::keybindings::KeybindingRegistry;
 use crate::theme::*;
+use crossterm::event::KeyEvent;
 use ratatui::{
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
@@ -26,7 +29,7 @@ pub fn render_footer(app: &App, frame: &mut Frame, area: Rect) {
         };
 
         let footer_line = Line::from(vec![
-            Span::styled(search_text, Style::default().fg(Color::Yellow)),
+            Span::styled(search_text, Style::default().fg(current().highlight_text)),
             Span::styled(
                 format!("{:width$}", "", width = padding as usize),
                 label_text(),
@@ -56,7 +59,7 @@ pub fn render_footer(app: &App, frame: &mut Frame, area: Rect) {
         };
 
         let footer_line = Line::from(vec![
-            Span::styled(search_text, Style::default().fg(Color::White)),
+            Span::styled(search_text, Style::default().fg(current().normal_text)),
             Span::styled(
                 format!("{:width$}", "", width = padding as usize),
                 label_text(),
@@ -69,18 +72,7 @@ pub fn render_footer(app: &App, frame: &mut Frame, area: Rect) {
         return;
     }
 
-    use crate::keybindings::KeybindingRegistry;
-
-    let selection_prefix = if app.multi_select.selection_mode_active {
-        format!(
-            "-- SELECT ({}) -- | ",
-            app.multi_select.selected_cards.len()
-        )
-    } else if !app.multi_select.selected_cards.is_empty() {
-        format!("({} selected) | ", app.multi_select.selected_cards.len())
-    } else {
-        String::new()
-    };
+    let selection_prefix = selection_prefix(app);
 
     let error_badge: String = {
         let (unread_count,) = app.with_error_log(|log| (log.unread_count,));
@@ -125,3 +117,39 @@ pub fn render_footer(app: &App, frame: &mut Frame, area: Rect) {
         .block(Block::default().borders(Borders::ALL));
     frame.render_widget(help, area);
 }
+
+fn selection_prefix(app: &App) -> String {
+    if app.multi_select.selection_mode_active {
+        format!(
+            "-- SELECT ({}) -- | ",
+            app.multi_select.selected_cards.len()
+        )
+    } else if !app.multi_select.selected_cards.is_empty() {
+        format!("({} selected) | ", app.multi_select.selected_cards.len())
+    } else {
+        String::new()
+    }
+}
+
+/// The keys of the footer hint drawn at column `x` of a footer rendered in
+/// `area`, or `None` between hints. Hints are matched by their default keys,
+/// the same way the command palette runs them, so remapped keys still work.
+pub fn footer_hint_keys(app: &App, area: Rect, x: u16) -> Option<Vec<KeyEvent>> {
+    if app.filter.search.is_active || app.mode == AppMode::Search {
+        return None;
+    }
+
+    let shown = KeybindingRegistry::get_provider(app).get_context();
+    let defaults = KeybindingRegistry::get_default_provider(app, &app.mode).get_context();
+
+    // Inside the border, after the selection prefix.
+    let mut start = area.x as usize + 1 + selection_prefix(app).chars().count();
+    for (binding, default) in shown.bindings.iter().zip(defaults.bindings.iter()) {
+        let width = binding.key.chars().count() + 2 + binding.short_description.chars().count();
+        if (start..start + width).contains(&(x as usize)) {
+            return key_events_for_label(&default.key);
+        }
+        start += width + " | ".len();
+    }
+    None
+}
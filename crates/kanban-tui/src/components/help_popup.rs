@@ -4,7 +4,7 @@ use crate::components::ListItemConfig;
 use crate::theme::*;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
@@ -58,7 +58,7 @@ pub fn render_help_popup(app: &App, frame: &mut Frame) {
             Line::from(Span::styled(
                 context.name.clone(),
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(current().accent)
                     .add_modifier(Modifier::BOLD),
             )),
             Line::from(""),
@@ -95,7 +95,10 @@ pub fn render_help_popup(app: &App, frame: &mut Frame) {
             let style = config.item_style();
             Some(Line::from(vec![
                 Span::styled(prefix.to_string(), style),
-                Span::styled(binding.key.to_string(), Style::default().fg(Color::Yellow)),
+                Span::styled(
+                    binding.key.to_string(),
+                    Style::default().fg(current().highlight_text),
+                ),
                 Span::raw(" "),
                 Span::styled(binding.description.clone(), style),
             ]))
@@ -115,7 +118,7 @@ pub fn render_help_popup(app: &App, frame: &mut Frame) {
         Line::from(Span::styled(
             "j/k or ↑↓: navigate | Enter: activate | ESC or ?: close",
             Style::default()
-                .fg(Color::DarkGray)
+                .fg(current().label_text)
                 .add_modifier(Modifier::ITALIC),
         )),
     ]);
@@ -1,6 +1,7 @@
 use crate::theme::{focused_border, popup_bg};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
+    text::Line,
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
@@ -58,6 +59,18 @@ pub fn render_input_popup(
     label: &str,
     input_text: &str,
     cursor_pos: usize,
+) {
+    render_input_popup_with_footer(frame, title, label, input_text, cursor_pos, None);
+}
+
+/// [`render_input_popup`] with an extra line below the input box.
+pub fn render_input_popup_with_footer(
+    frame: &mut Frame,
+    title: &str,
+    label: &str,
+    input_text: &str,
+    cursor_pos: usize,
+    footer: Option<Line>,
 ) {
     let area = centered_rect(60, 30, frame.area());
 
@@ -89,6 +102,10 @@ pub fn render_input_popup(
         .block(Block::default().borders(Borders::ALL));
     frame.render_widget(input, chunks[1]);
 
+    if let Some(footer) = footer {
+        frame.render_widget(Paragraph::new(footer), chunks[2]);
+    }
+
     let cursor_x = chunks[1].x + cursor_pos as u16 + 1;
     let cursor_y = chunks[1].y + 1;
     frame.set_cursor_position((cursor_x, cursor_y));
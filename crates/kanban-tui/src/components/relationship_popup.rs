@@ -1,8 +1,9 @@
 use crate::app::App;
 use crate::components::centered_rect;
+use crate::theme::current;
 use ratatui::{
     layout::{Constraint, Direction, Layout},
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
@@ -23,7 +24,7 @@ fn render_relationship_popup(app: &App, frame: &mut Frame, title: &str) {
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
-        .style(Style::default().bg(Color::Black));
+        .style(Style::default().bg(current().popup_bg));
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -45,9 +46,9 @@ fn render_relationship_popup(app: &App, frame: &mut Frame, title: &str) {
 
 fn render_relationship_search_box(app: &App, frame: &mut Frame, area: ratatui::layout::Rect) {
     let search_border_style = if app.relationship.search_active {
-        Style::default().fg(Color::Yellow)
+        Style::default().fg(current().highlight_text)
     } else {
-        Style::default().fg(Color::Gray)
+        Style::default().fg(current().label_text)
     };
     let search_block = Block::default()
         .title("Search")
@@ -56,18 +57,21 @@ fn render_relationship_search_box(app: &App, frame: &mut Frame, area: ratatui::l
 
     let search_text: Line = if app.relationship.search_active {
         Line::from(vec![
-            Span::styled(&app.relationship.search, Style::default().fg(Color::White)),
-            Span::styled("_", Style::default().fg(Color::Yellow)),
+            Span::styled(
+                &app.relationship.search,
+                Style::default().fg(current().normal_text),
+            ),
+            Span::styled("_", Style::default().fg(current().highlight_text)),
         ])
     } else if app.relationship.search.is_empty() {
         Line::from(Span::styled(
             "/ to search",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(current().label_text),
         ))
     } else {
         Line::from(Span::styled(
             &app.relationship.search,
-            Style::default().fg(Color::White),
+            Style::default().fg(current().normal_text),
         ))
     };
 
@@ -101,11 +105,13 @@ fn render_relationship_card_list(app: &App, frame: &mut Frame, area: ratatui::la
             let checkbox = if is_checked { "[✓]" } else { "[ ]" };
 
             let style = if is_selected {
-                Style::default().fg(Color::White).bg(Color::Blue)
+                Style::default()
+                    .fg(current().normal_text)
+                    .bg(current().selected_bg)
             } else if is_checked {
-                Style::default().fg(Color::Green)
+                Style::default().fg(current().active_item)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(current().normal_text)
             };
 
             lines.push(Line::from(Span::styled(
@@ -118,7 +124,7 @@ fn render_relationship_card_list(app: &App, frame: &mut Frame, area: ratatui::la
     if lines.is_empty() {
         lines.push(Line::from(Span::styled(
             "No eligible cards found",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(current().label_text),
         )));
     }
 
@@ -133,6 +139,6 @@ fn render_relationship_instructions(app: &App, frame: &mut Frame, area: ratatui:
         "j/k: navigate | Space: toggle | /: search | Esc: close"
     };
     let instructions =
-        Paragraph::new(instructions_text).style(Style::default().fg(Color::DarkGray));
+        Paragraph::new(instructions_text).style(Style::default().fg(current().label_text));
     frame.render_widget(instructions, area);
 }
@@ -1,5 +1,6 @@
 use crate::app::App;
 use crate::components::sprint_assign_list::build_entries;
+use crate::theme::current;
 use kanban_domain::{SortField, SprintStatus};
 use ratatui::Frame;
 
@@ -213,7 +214,7 @@ impl SelectionDialog for CarryOverSprintDialog {
         use crate::components::centered_rect;
         use ratatui::{
             layout::{Constraint, Direction, Layout},
-            style::{Color, Style},
+            style::Style,
             text::{Line, Span},
             widgets::{Block, Borders, Clear, Paragraph},
         };
@@ -225,7 +226,7 @@ impl SelectionDialog for CarryOverSprintDialog {
         let block = Block::default()
             .title(title.as_str())
             .borders(Borders::ALL)
-            .style(Style::default().bg(Color::Black));
+            .style(Style::default().bg(current().popup_bg));
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
@@ -236,8 +237,8 @@ impl SelectionDialog for CarryOverSprintDialog {
             .constraints([Constraint::Length(1), Constraint::Min(0)])
             .split(inner);
 
-        let label =
-            Paragraph::new("Select target sprint:").style(Style::default().fg(Color::Yellow));
+        let label = Paragraph::new("Select target sprint:")
+            .style(Style::default().fg(current().highlight_text));
         frame.render_widget(label, chunks[0]);
 
         let mut lines = vec![];
@@ -256,9 +257,11 @@ impl SelectionDialog for CarryOverSprintDialog {
                         app.dialog_input.carry_over_sprint_selection.get() == Some(idx);
 
                     let style = if is_selected {
-                        Style::default().fg(Color::White).bg(Color::Blue)
+                        Style::default()
+                            .fg(current().normal_text)
+                            .bg(current().selected_bg)
                     } else {
-                        Style::default().fg(Color::White)
+                        Style::default().fg(current().normal_text)
                     };
 
                     let prefix = if is_selected { "> " } else { "  " };
@@ -303,7 +306,7 @@ impl SelectionDialog for SprintAssignDialog {
         use crate::components::centered_rect;
         use ratatui::{
             layout::{Constraint, Direction, Layout},
-            style::{Color, Style},
+            style::Style,
             widgets::{Block, Borders, Clear, Paragraph},
         };
 
@@ -313,7 +316,7 @@ impl SelectionDialog for SprintAssignDialog {
         let block = Block::default()
             .title("Assign to Sprint")
             .borders(Borders::ALL)
-            .style(Style::default().bg(Color::Black));
+            .style(Style::default().bg(current().popup_bg));
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
@@ -325,7 +328,7 @@ impl SelectionDialog for SprintAssignDialog {
             .split(inner);
 
         frame.render_widget(
-            Paragraph::new("Select sprint:").style(Style::default().fg(Color::Yellow)),
+            Paragraph::new("Select sprint:").style(Style::default().fg(current().highlight_text)),
             chunks[0],
         );
 
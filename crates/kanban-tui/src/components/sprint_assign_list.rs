@@ -1,7 +1,8 @@
+use crate::theme::current;
 use chrono::{DateTime, Utc};
 use kanban_domain::{Board, Sprint, SprintStatus};
 use ratatui::{
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
 };
 use uuid::Uuid;
@@ -147,7 +148,7 @@ pub fn render_entry_line(
         SprintAssignEntry::Header(label) => Line::from(Span::styled(
             (*label).to_string(),
             Style::default()
-                .fg(Color::Yellow)
+                .fg(current().highlight_text)
                 .add_modifier(Modifier::BOLD),
         )),
         SprintAssignEntry::None => {
@@ -155,13 +156,15 @@ pub fn render_entry_line(
             let prefix = if is_checked { "[x] " } else { "[ ] " };
             let suffix = if is_current { " (current)" } else { "" };
             let style = if is_focused {
-                Style::default().fg(Color::White).bg(Color::Blue)
+                Style::default()
+                    .fg(current().normal_text)
+                    .bg(current().selected_bg)
             } else if is_current {
                 Style::default()
-                    .fg(Color::Green)
+                    .fg(current().active_item)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(current().normal_text)
             };
             Line::from(Span::styled(format!("{}(None){}", prefix, suffix), style))
         }
@@ -170,13 +173,15 @@ pub fn render_entry_line(
             let prefix = if is_checked { "[x] " } else { "[ ] " };
             let suffix = if is_current { " (current)" } else { "" };
             let style = if is_focused {
-                Style::default().fg(Color::White).bg(Color::Blue)
+                Style::default()
+                    .fg(current().normal_text)
+                    .bg(current().selected_bg)
             } else if is_current {
                 Style::default()
-                    .fg(Color::Green)
+                    .fg(current().active_item)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(current().normal_text)
             };
             Line::from(Span::styled(
                 format!("{}{}{}", prefix, s.formatted_name(board, "sprint"), suffix),
@@ -188,12 +193,14 @@ pub fn render_entry_line(
             let prefix = if is_checked { "[x] " } else { "[ ] " };
             let suffix = if is_current { " (current)" } else { "" };
             let status_color = if matches!(entry, SprintAssignEntry::Completed(_)) {
-                Color::Green
+                current().active_item
             } else {
-                Color::Red
+                current().error
             };
             let style = if is_focused {
-                Style::default().fg(Color::White).bg(Color::Blue)
+                Style::default()
+                    .fg(current().normal_text)
+                    .bg(current().selected_bg)
             } else {
                 Style::default().fg(status_color)
             };
@@ -355,6 +355,7 @@ mod tests {
             configuration_format: Some("toml".into()),
             configuration_location: Some("/tmp/test.toml".into()),
             storage_location: None,
+            theme: Some("dark".into()),
         };
         let serialized = EditFormat::Json.serialize(&dto).unwrap();
         let deserialized: AppConfigDto = EditFormat::Json.deserialize(&serialized).unwrap();
@@ -373,6 +374,7 @@ mod tests {
             configuration_format: Some("toml".into()),
             configuration_location: Some("/tmp/test.toml".into()),
             storage_location: None,
+            theme: Some("dark".into()),
         };
         let serialized = EditFormat::Toml.serialize(&dto).unwrap();
         let deserialized: AppConfigDto = EditFormat::Toml.deserialize(&serialized).unwrap();
@@ -1,5 +1,6 @@
 use crate::events::EventHandler;
 use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -62,6 +63,10 @@ pub fn edit_in_external_editor(
 
     event_handler.stop();
 
+    let mouse_capture = crate::events::mouse_capture_enabled();
+    if mouse_capture {
+        execute!(io::stdout(), DisableMouseCapture)?;
+    }
     disable_raw_mode()?;
     execute!(io::stdout(), LeaveAlternateScreen)?;
     io::stdout().flush()?;
@@ -72,6 +77,9 @@ pub fn edit_in_external_editor(
         tracing::error!("Failed to launch editor '{}': {}", program.display(), e);
         execute!(io::stdout(), EnterAlternateScreen)?;
         enable_raw_mode()?;
+        if mouse_capture {
+            execute!(io::stdout(), EnableMouseCapture)?;
+        }
         terminal.clear()?;
         return Err(io::Error::new(
             io::ErrorKind::NotFound,
@@ -91,6 +99,9 @@ pub fn edit_in_external_editor(
 
     execute!(io::stdout(), EnterAlternateScreen)?;
     enable_raw_mode()?;
+    if mouse_capture {
+        execute!(io::stdout(), EnableMouseCapture)?;
+    }
 
     terminal.clear()?;
 
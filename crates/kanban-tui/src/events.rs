@@ -1,13 +1,40 @@
-use crossterm::event::{self, Event as CrosstermEvent, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::event::{
+    self, DisableMouseCapture, EnableMouseCapture, Event as CrosstermEvent, KeyCode, KeyEvent,
+    KeyEventKind, MouseEvent, MouseEventKind,
+};
+use crossterm::execute;
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 use tokio::sync::mpsc;
 
 #[derive(Debug, Clone)]
 pub enum Event {
     Key(KeyEvent),
+    Mouse(MouseEvent),
+    Resize(u16, u16),
     Tick,
 }
 
+static MOUSE_CAPTURE: AtomicBool = AtomicBool::new(false);
+
+/// Turns terminal mouse reporting on or off. Off unless the config sets
+/// `mouse = true`: while it is on, selecting text with the mouse needs the
+/// terminal's override modifier (usually Shift).
+pub fn set_mouse_capture(enabled: bool) -> io::Result<()> {
+    if enabled {
+        execute!(io::stdout(), EnableMouseCapture)?;
+    } else {
+        execute!(io::stdout(), DisableMouseCapture)?;
+    }
+    MOUSE_CAPTURE.store(enabled, Ordering::Relaxed);
+    Ok(())
+}
+
+pub fn mouse_capture_enabled() -> bool {
+    MOUSE_CAPTURE.load(Ordering::Relaxed)
+}
+
 pub struct EventHandler {
     rx: mpsc::UnboundedReceiver<Event>,
     shutdown_tx: mpsc::UnboundedSender<()>,
@@ -31,21 +58,36 @@ impl EventHandler {
                         break;
                     }
                     _ = tokio::time::sleep(Duration::from_millis(16)) => {
-                        let mut had_key = false;
+                        let mut had_input = false;
                         while event::poll(Duration::from_millis(0)).unwrap_or(false) {
-                            if let Ok(CrosstermEvent::Key(key)) = event::read() {
-                                tracing::trace!(code = ?key.code, kind = ?key.kind, modifiers = ?key.modifiers, "raw key event");
-                                if key.kind != KeyEventKind::Press {
-                                    continue;
+                            let forwarded = match event::read() {
+                                Ok(CrosstermEvent::Key(key)) => {
+                                    tracing::trace!(code = ?key.code, kind = ?key.kind, modifiers = ?key.modifiers, "raw key event");
+                                    if key.kind != KeyEventKind::Press {
+                                        continue;
+                                    }
+                                    Event::Key(key)
                                 }
-
-                                had_key = true;
-                                if tx.send(Event::Key(key)).is_err() {
-                                    break;
+                                // Plain motion arrives on every cell the pointer crosses
+                                // and nothing reacts to it.
+                                Ok(CrosstermEvent::Mouse(mouse)) => {
+                                    if mouse.kind == MouseEventKind::Moved {
+                                        continue;
+                                    }
+                                    Event::Mouse(mouse)
                                 }
+                                Ok(CrosstermEvent::Resize(width, height)) => {
+                                    Event::Resize(width, height)
+                                }
+                                _ => continue,
+                            };
+
+                            had_input = true;
+                            if tx.send(forwarded).is_err() {
+                                break;
                             }
                         }
-                        if !had_key && tx.send(Event::Tick).is_err() {
+                        if !had_input && tx.send(Event::Tick).is_err() {
                             break;
                         }
                     }
@@ -0,0 +1,132 @@
+use crate::app::App;
+use crate::dialog::{handle_dialog_input, DialogAction};
+use crossterm::event::KeyCode;
+use kanban_domain::{Attachment, KanbanOperations};
+use kanban_service::attachment_files::{extract_attachment, open_attachment};
+use std::path::Path;
+
+impl App {
+    pub fn handle_add_attachment_dialog(&mut self, key_code: KeyCode) {
+        match handle_dialog_input(&mut self.input, key_code, false) {
+            DialogAction::Confirm => {
+                // Terminals quote paths dropped onto them.
+                let path = self
+                    .input
+                    .as_str()
+                    .trim()
+                    .trim_matches(|c| c == '\'' || c == '"')
+                    .to_string();
+                self.pop_mode();
+                self.input.clear();
+                if !path.is_empty() {
+                    self.attach_file(&path);
+                }
+            }
+            DialogAction::Cancel => {
+                self.pop_mode();
+                self.input.clear();
+            }
+            DialogAction::None => {}
+        }
+    }
+
+    fn attach_file(&mut self, path: &str) {
+        let Some(card_id) = self.selection.active_card_id else {
+            return;
+        };
+        let content = match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                self.set_error(format!("Failed to read {}: {}", path, e));
+                return;
+            }
+        };
+        let name = Path::new(path)
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.to_string());
+        match self.ctx.add_attachment(card_id, name, &content) {
+            Ok(attachment) => {
+                // The model reloads on the next frame, so the old count is
+                // the index of the new attachment.
+                if let Some(card) = self.get_card_for_detail_view() {
+                    self.selection.attachment = card.attachments.len();
+                }
+                self.set_success(format!("Attached {}", attachment.name));
+            }
+            Err(e) => {
+                tracing::error!("Failed to attach {}: {}", path, e);
+                self.set_error(format!("Failed to attach {}: {}", path, e));
+            }
+        }
+    }
+
+    /// Move the attachment selection by `step`, wrapping at either end.
+    pub fn select_attachment(&mut self, step: isize) {
+        let count = self
+            .get_card_for_detail_view()
+            .map_or(0, |card| card.attachments.len());
+        if count == 0 {
+            return;
+        }
+        let current = self.selection.attachment.min(count - 1) as isize;
+        self.selection.attachment = (current + step).rem_euclid(count as isize) as usize;
+    }
+
+    /// The selected attachment of the card in the detail view with its
+    /// content, reporting failures in the banner.
+    fn read_selected_attachment(&mut self) -> Option<(Attachment, Vec<u8>)> {
+        let card = self.get_card_for_detail_view()?;
+        let index = self
+            .selection
+            .attachment
+            .min(card.attachments.len().checked_sub(1)?);
+        match self
+            .ctx
+            .read_attachment(card.id, card.attachments[index].id)
+        {
+            Ok(read) => Some(read),
+            Err(e) => {
+                self.set_error(format!("Failed to read attachment: {}", e));
+                None
+            }
+        }
+    }
+
+    pub fn open_selected_attachment(&mut self) {
+        let Some((attachment, content)) = self.read_selected_attachment() else {
+            return;
+        };
+        match open_attachment(&attachment, &content) {
+            Ok(_) => self.set_success(format!("Opened {}", attachment.name)),
+            Err(e) => self.set_error(e.to_string()),
+        }
+    }
+
+    pub fn extract_selected_attachment(&mut self) {
+        let Some((attachment, content)) = self.read_selected_attachment() else {
+            return;
+        };
+        match extract_attachment(&attachment, &content, None) {
+            Ok(path) => self.set_success(format!("Extracted to {}", path.display())),
+            Err(e) => self.set_error(format!("Failed to extract {}: {}", attachment.name, e)),
+        }
+    }
+
+    pub fn remove_selected_attachment(&mut self) {
+        let Some(card) = self.get_card_for_detail_view() else {
+            return;
+        };
+        let Some(last) = card.attachments.len().checked_sub(1) else {
+            return;
+        };
+        let attachment = &card.attachments[self.selection.attachment.min(last)];
+        match self.ctx.remove_attachment(card.id, attachment.id) {
+            Ok(_) => {
+                self.selection.attachment = self.selection.attachment.min(last.saturating_sub(1));
+                self.set_success(format!("Removed {} (u to undo)", attachment.name));
+            }
+            Err(e) => self.set_error(format!("Failed to remove {}: {}", attachment.name, e)),
+        }
+    }
+}
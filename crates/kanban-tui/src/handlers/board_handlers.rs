@@ -2,11 +2,13 @@ use crate::app::{App, AppMode, BoardFocus, DialogMode, Focus};
 use kanban_domain::commands::{
     BoardCommand, ColumnCommand, Command, CreateBoard, CreateColumn, UpdateBoard,
 };
-use kanban_domain::{BoardUpdate, TaskListView};
+use kanban_domain::{BoardUpdate, CloneOptions, KanbanOperations, TaskListView};
 
 impl App {
     pub fn handle_create_board_key(&mut self) {
         if self.focus.active == Focus::Boards {
+            self.dialog_input.board_templates = kanban_service::templates::list();
+            self.dialog_input.board_template_selection = 0;
             self.open_dialog(DialogMode::CreateBoard);
             self.input.clear();
         }
@@ -23,6 +25,18 @@ impl App {
         }
     }
 
+    pub fn handle_clone_board_key(&mut self) {
+        if self.focus.active == Focus::Boards {
+            if let Some(board_idx) = self.selection.board.get() {
+                if let Some(board) = self.model.boards().get(board_idx) {
+                    self.input.set(format!("{} (copy)", board.name));
+                    self.dialog_input.clone_options = CloneOptions::default();
+                    self.open_dialog(DialogMode::CloneBoard);
+                }
+            }
+        }
+    }
+
     pub fn handle_edit_board_key(&mut self) {
         if self.focus.active == Focus::Boards && self.selection.board.get().is_some() {
             self.push_mode(AppMode::BoardDetail);
@@ -68,6 +82,40 @@ impl App {
     }
 
     pub fn create_board(&mut self) {
+        match self.dialog_input.selected_board_template() {
+            Some(name) if name != "basic" => {
+                let name = name.to_string();
+                self.create_board_from_template(&name);
+            }
+            _ => self.create_default_board(),
+        }
+    }
+
+    fn create_board_from_template(&mut self, template_name: &str) {
+        let board_name = self.input.as_str().to_string();
+        let new_index = self.model.boards().len();
+
+        let result = kanban_service::templates::load(template_name)
+            .and_then(|template| self.ctx.create_board_from_template(board_name, &template));
+        match result {
+            Ok(board) => {
+                tracing::info!(
+                    "Created board: {} (id: {}) from template {}",
+                    board.name,
+                    board.id,
+                    template_name
+                );
+                self.selection.board.set(Some(new_index));
+                self.switch_view_strategy(TaskListView::default());
+            }
+            Err(e) => {
+                tracing::error!("Failed to create board: {}", e);
+                self.set_error(format!("Failed to create board: {}", e));
+            }
+        }
+    }
+
+    fn create_default_board(&mut self) {
         let board_name = self.input.as_str().to_string();
 
         let board_id = uuid::Uuid::new_v4();
@@ -130,6 +178,38 @@ impl App {
         }
     }
 
+    pub fn clone_board(&mut self) {
+        let Some(board_id) = self
+            .selection
+            .board
+            .get()
+            .and_then(|idx| self.model.boards().get(idx))
+            .map(|board| board.id)
+        else {
+            return;
+        };
+        let name = self.input.as_str().trim().to_string();
+        if name.is_empty() {
+            return;
+        }
+
+        let new_index = self.model.boards().len();
+        match self
+            .ctx
+            .clone_board(board_id, name, self.dialog_input.clone_options)
+        {
+            Ok(board) => {
+                self.selection.board.set(Some(new_index));
+                self.switch_view_strategy(TaskListView::GroupedByColumn);
+                tracing::info!("Cloned board as: {}", board.name);
+            }
+            Err(e) => {
+                tracing::error!("Failed to clone board: {}", e);
+                self.set_error(format!("Failed to clone board: {}", e));
+            }
+        }
+    }
+
     fn scan_import_files(&mut self) {
         self.dialog_input.import_files.clear();
         if let Ok(entries) = std::fs::read_dir(".") {
@@ -137,7 +217,7 @@ impl App {
                 if let Ok(metadata) = entry.metadata() {
                     if metadata.is_file() {
                         if let Some(filename) = entry.file_name().to_str() {
-                            if filename.ends_with(".json") {
+                            if filename.ends_with(".json") || filename.ends_with(".csv") {
                                 self.dialog_input.import_files.push(filename.to_string());
                             }
                         }
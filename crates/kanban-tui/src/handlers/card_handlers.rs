@@ -248,7 +248,7 @@ impl App {
                     tracing::error!("Failed to edit card description: {}", e);
                     self.set_error(format!("Failed to edit card description: {}", e));
                 }
-                should_restart = true;
+                should_restart = self.uses_external_editor();
             }
         }
         should_restart
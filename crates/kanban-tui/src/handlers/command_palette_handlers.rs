@@ -0,0 +1,184 @@
+use crate::app::{App, AppMode, DialogMode, Focus};
+use crate::command_palette::{PaletteCommand, PaletteEntry};
+use crate::keybindings::keymap::key_events_for_label;
+use crate::keybindings::{KeybindingAction, KeybindingRegistry};
+use crossterm::event::KeyCode;
+use uuid::Uuid;
+
+impl App {
+    /// Modes the palette can be opened from; dialogs, search and popups
+    /// capture their own keys.
+    pub fn can_open_command_palette(&self) -> bool {
+        matches!(
+            self.mode,
+            AppMode::Normal
+                | AppMode::CardDetail
+                | AppMode::BoardDetail
+                | AppMode::SprintDetail
+                | AppMode::ArchivedCardsView
+                | AppMode::Settings
+        )
+    }
+
+    pub fn open_command_palette(&mut self) {
+        if !self.can_open_command_palette() {
+            return;
+        }
+        self.pending_key = None;
+        let entries = self.command_palette_entries();
+        self.ui_state.command_palette.open(entries);
+        self.push_mode(AppMode::Dialog(DialogMode::CommandPalette));
+    }
+
+    /// The actions of the current context, labelled with the user's keys,
+    /// followed by jump entries for every board, sprint and card.
+    fn command_palette_entries(&self) -> Vec<PaletteEntry> {
+        let shown = KeybindingRegistry::get_provider(self).get_context();
+        let defaults = KeybindingRegistry::get_default_provider(self, &self.mode).get_context();
+
+        let mut entries: Vec<PaletteEntry> = shown
+            .bindings
+            .iter()
+            .zip(defaults.bindings.iter())
+            .filter(|(b, _)| {
+                !matches!(
+                    b.action,
+                    KeybindingAction::ShowHelp | KeybindingAction::CommandPalette
+                )
+            })
+            .filter_map(|(shown, default)| {
+                let keys = key_events_for_label(&default.key)?;
+                Some(PaletteEntry::new(
+                    shown.description.clone(),
+                    shown.key.clone(),
+                    PaletteCommand::Keys(keys),
+                ))
+            })
+            .collect();
+
+        let default_card_prefix = self.app_config.effective_default_card_prefix();
+        let default_sprint_prefix = self.app_config.effective_default_sprint_prefix();
+        let boards = self.model.boards();
+        let sprints = self.model.sprints();
+
+        for board in boards {
+            entries.push(PaletteEntry::new(
+                format!("Go to board: {}", board.name),
+                "",
+                PaletteCommand::JumpToBoard(board.id),
+            ));
+        }
+        for sprint in sprints {
+            if let Some(board) = boards.iter().find(|b| b.id == sprint.board_id) {
+                entries.push(PaletteEntry::new(
+                    format!(
+                        "Go to sprint: {} ({})",
+                        sprint.formatted_name(board, default_sprint_prefix),
+                        board.name
+                    ),
+                    "",
+                    PaletteCommand::JumpToSprint(sprint.id),
+                ));
+            }
+        }
+        for card in self.model.cards() {
+            let Some(board) = self.board_for_card(card.column_id) else {
+                continue;
+            };
+            entries.push(PaletteEntry::new(
+                format!(
+                    "Go to card: {} {}",
+                    card.identifier(board, sprints, default_card_prefix),
+                    card.title
+                ),
+                "",
+                PaletteCommand::JumpToCard(card.id),
+            ));
+        }
+        entries
+    }
+
+    fn board_for_card(&self, column_id: Uuid) -> Option<&kanban_domain::Board> {
+        let column = self.model.columns().iter().find(|c| c.id == column_id)?;
+        self.model.boards().iter().find(|b| b.id == column.board_id)
+    }
+
+    /// Handles a key in the palette. Returns the command to run once the
+    /// palette has closed, if the key selected one.
+    pub fn handle_command_palette_key(&mut self, key_code: KeyCode) -> Option<PaletteCommand> {
+        let palette = &mut self.ui_state.command_palette;
+        match key_code {
+            KeyCode::Esc => {
+                palette.close();
+                self.pop_mode();
+            }
+            KeyCode::Enter => {
+                let command = palette.selected_entry().map(|e| e.command.clone());
+                palette.close();
+                self.pop_mode();
+                return command;
+            }
+            KeyCode::Down | KeyCode::Tab => palette.select_next(),
+            KeyCode::Up | KeyCode::BackTab => palette.select_prev(),
+            KeyCode::Char(c) => {
+                palette.input.insert_char(c);
+                palette.update_matches();
+            }
+            KeyCode::Backspace => {
+                palette.input.backspace();
+                palette.update_matches();
+            }
+            KeyCode::Left => palette.input.move_left(),
+            KeyCode::Right => palette.input.move_right(),
+            _ => {}
+        }
+        None
+    }
+
+    /// Leaves any detail views and dialogs and opens `board_id` in the
+    /// main view.
+    pub fn jump_to_board(&mut self, board_id: Uuid) -> bool {
+        let Some(board_idx) = self.model.boards().iter().position(|b| b.id == board_id) else {
+            return false;
+        };
+        self.mode_stack.clear();
+        self.mode = AppMode::Normal;
+        self.focus.active = Focus::Boards;
+        self.selection.board.set(Some(board_idx));
+        self.handle_selection_activate();
+        true
+    }
+
+    pub fn jump_to_card(&mut self, card_id: Uuid) {
+        let Some(board_id) = self
+            .model
+            .card(card_id)
+            .and_then(|card| self.board_for_card(card.column_id))
+            .map(|b| b.id)
+        else {
+            return;
+        };
+        if self.jump_to_board(board_id) {
+            self.select_card_by_id(card_id);
+            self.open_card_detail(card_id);
+        }
+    }
+
+    pub fn jump_to_sprint(&mut self, sprint_id: Uuid) {
+        let Some((sprint_idx, board_id)) = self
+            .model
+            .sprints()
+            .iter()
+            .enumerate()
+            .find(|(_, s)| s.id == sprint_id)
+            .map(|(i, s)| (i, s.board_id))
+        else {
+            return;
+        };
+        if self.jump_to_board(board_id) {
+            self.selection.active_sprint_index = Some(sprint_idx);
+            self.populate_sprint_task_lists(sprint_id);
+            self.push_mode(AppMode::SprintDetail);
+        }
+    }
+}
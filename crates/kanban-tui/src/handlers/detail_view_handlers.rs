@@ -30,6 +30,8 @@ impl App {
             KeyCode::Esc => {
                 self.pop_mode();
                 self.selection.active_card_id = None;
+                self.selection.attachment = 0;
+                self.selection.reference = 0;
                 self.focus.card_focus = CardFocus::Title;
                 self.relationship.parents_list.selection.clear();
                 self.relationship.children_list.selection.clear();
@@ -214,6 +216,18 @@ impl App {
             KeyCode::Char('Y') => {
                 self.copy_git_checkout_command();
             }
+            KeyCode::Char('f') => {
+                self.open_dialog(DialogMode::AddAttachment);
+            }
+            KeyCode::Char(']') => self.select_attachment(1),
+            KeyCode::Char('[') => self.select_attachment(-1),
+            KeyCode::Char('o') => self.open_selected_attachment(),
+            KeyCode::Char('x') => self.extract_selected_attachment(),
+            KeyCode::Char('X') => self.remove_selected_attachment(),
+            KeyCode::Tab if self.focus.card_focus != CardFocus::Description => {
+                self.focus.card_focus = CardFocus::Description;
+            }
+            KeyCode::Tab => self.select_reference(1),
             KeyCode::Char('e') => match self.focus.card_focus {
                 CardFocus::Title => {
                     if let Err(e) = self.edit_card_field(terminal, event_handler, CardField::Title)
@@ -221,7 +235,7 @@ impl App {
                         tracing::error!("Failed to edit title: {}", e);
                         self.set_error(format!("Failed to edit title: {}", e));
                     }
-                    should_restart = true;
+                    should_restart = self.uses_external_editor();
                 }
                 CardFocus::Description => {
                     if let Err(e) =
@@ -230,7 +244,7 @@ impl App {
                         tracing::error!("Failed to edit description: {}", e);
                         self.set_error(format!("Failed to edit description: {}", e));
                     }
-                    should_restart = true;
+                    should_restart = self.uses_external_editor();
                 }
                 CardFocus::Metadata => {
                     if let Some(card) = self.get_card_for_detail_view() {
@@ -336,8 +350,12 @@ impl App {
             KeyCode::Enter => match self.focus.card_focus {
                 CardFocus::Parents => self.navigate_to_selected_parent(),
                 CardFocus::Children => self.navigate_to_selected_child(),
+                CardFocus::Description => self.follow_selected_reference(),
                 _ => {}
             },
+            KeyCode::Backspace if self.focus.card_focus == CardFocus::Description => {
+                self.return_to_previous_card_from_detail_history();
+            }
             KeyCode::Backspace | KeyCode::Char('h')
                 if self.focus.card_focus != CardFocus::Title
                     && self.focus.card_focus != CardFocus::Metadata
@@ -384,7 +402,7 @@ impl App {
                         tracing::error!("Failed to edit board name: {}", e);
                         self.set_error(format!("Failed to edit board name: {}", e));
                     }
-                    should_restart = true;
+                    should_restart = self.uses_external_editor();
                 }
                 BoardFocus::Description => {
                     if let Err(e) =
@@ -393,7 +411,7 @@ impl App {
                         tracing::error!("Failed to edit board description: {}", e);
                         self.set_error(format!("Failed to edit board description: {}", e));
                     }
-                    should_restart = true;
+                    should_restart = self.uses_external_editor();
                 }
                 BoardFocus::Settings => {
                     if let Some(board_idx) = self.selection.board.get() {
@@ -458,25 +476,17 @@ impl App {
                     self.handle_create_column_key();
                 }
             }
-            KeyCode::Char('r') => {
-                if self.focus.board_focus == BoardFocus::Columns {
-                    self.handle_rename_column_key();
-                }
+            KeyCode::Char('r') if self.focus.board_focus == BoardFocus::Columns => {
+                self.handle_rename_column_key();
             }
-            KeyCode::Char('d') => {
-                if self.focus.board_focus == BoardFocus::Columns {
-                    self.handle_delete_column_key();
-                }
+            KeyCode::Char('d') if self.focus.board_focus == BoardFocus::Columns => {
+                self.handle_delete_column_key();
             }
-            KeyCode::Char('J') => {
-                if self.focus.board_focus == BoardFocus::Columns {
-                    self.handle_move_column_down();
-                }
+            KeyCode::Char('J') if self.focus.board_focus == BoardFocus::Columns => {
+                self.handle_move_column_down();
             }
-            KeyCode::Char('K') => {
-                if self.focus.board_focus == BoardFocus::Columns {
-                    self.handle_move_column_up();
-                }
+            KeyCode::Char('K') if self.focus.board_focus == BoardFocus::Columns => {
+                self.handle_move_column_up();
             }
             KeyCode::Char('j') | KeyCode::Down => match self.focus.board_focus {
                 BoardFocus::Sprints => {
@@ -582,40 +592,38 @@ impl App {
                     }
                 }
             },
-            KeyCode::Enter | KeyCode::Char(' ') => {
-                if self.focus.board_focus == BoardFocus::Sprints {
-                    if let Some(sprint_idx) = self.selection.sprint.get() {
-                        if let Some(board_idx) = self.selection.board.get() {
-                            let boards = self.model.boards();
-                            if let Some(board) = boards.get(board_idx) {
-                                let sprints = self.model.sprints();
-                                let board_sprints: Vec<_> = sprints
-                                    .iter()
-                                    .enumerate()
-                                    .filter(|(_, s)| s.board_id == board.id)
-                                    .collect();
-                                if let Some((actual_idx, _)) = board_sprints.get(sprint_idx) {
-                                    self.selection.active_sprint_index = Some(*actual_idx);
-                                    self.selection.active_board_index = Some(board_idx);
-                                    if let Some(sprint) = sprints.get(*actual_idx) {
-                                        self.populate_sprint_task_lists(sprint.id);
-                                    }
-                                    self.push_mode(AppMode::SprintDetail);
+            KeyCode::Enter | KeyCode::Char(' ')
+                if self.focus.board_focus == BoardFocus::Sprints =>
+            {
+                if let Some(sprint_idx) = self.selection.sprint.get() {
+                    if let Some(board_idx) = self.selection.board.get() {
+                        let boards = self.model.boards();
+                        if let Some(board) = boards.get(board_idx) {
+                            let sprints = self.model.sprints();
+                            let board_sprints: Vec<_> = sprints
+                                .iter()
+                                .enumerate()
+                                .filter(|(_, s)| s.board_id == board.id)
+                                .collect();
+                            if let Some((actual_idx, _)) = board_sprints.get(sprint_idx) {
+                                self.selection.active_sprint_index = Some(*actual_idx);
+                                self.selection.active_board_index = Some(board_idx);
+                                if let Some(sprint) = sprints.get(*actual_idx) {
+                                    self.populate_sprint_task_lists(sprint.id);
                                 }
+                                self.push_mode(AppMode::SprintDetail);
                             }
                         }
                     }
                 }
             }
-            KeyCode::Char('p') => {
-                if self.focus.board_focus == BoardFocus::Settings {
-                    if let Some(board_idx) = self.selection.board.get() {
-                        if let Some(board) = self.model.boards().get(board_idx) {
-                            let current_prefix =
-                                board.sprint_prefix.clone().unwrap_or_else(String::new);
-                            self.input.set(current_prefix);
-                            self.open_dialog(DialogMode::SetBranchPrefix);
-                        }
+            KeyCode::Char('p') if self.focus.board_focus == BoardFocus::Settings => {
+                if let Some(board_idx) = self.selection.board.get() {
+                    if let Some(board) = self.model.boards().get(board_idx) {
+                        let current_prefix =
+                            board.sprint_prefix.clone().unwrap_or_else(String::new);
+                        self.input.set(current_prefix);
+                        self.open_dialog(DialogMode::SetBranchPrefix);
                     }
                 }
             }
@@ -750,6 +758,7 @@ impl App {
                                 self.relationship
                                     .children_list
                                     .update_item_count(children.len());
+                                self.refresh_card_git();
                                 self.push_mode(AppMode::CardDetail);
                                 self.focus.card_focus = CardFocus::Title;
                             }
@@ -765,6 +774,7 @@ impl App {
                                 self.relationship
                                     .children_list
                                     .update_item_count(children.len());
+                                self.refresh_card_git();
                                 self.push_mode(AppMode::CardDetail);
                                 self.focus.card_focus = CardFocus::Title;
                             }
@@ -1068,7 +1078,7 @@ impl App {
         Vec::new()
     }
 
-    fn refresh_relationship_counts(&mut self) {
+    pub(crate) fn refresh_relationship_counts(&mut self) {
         let parents = self.get_current_card_parents();
         let children = self.get_current_card_children();
         self.relationship
@@ -1100,6 +1110,7 @@ impl App {
             // the user on a stale card. Pinned by
             // test_backspace_return_with_unknown_previous_id_clears_active_card_entirely.
             self.set_active_card_or_clear(previous_id);
+            self.selection.reference = 0;
             self.focus.card_focus = CardFocus::Title;
             self.refresh_relationship_counts();
         }
@@ -1328,6 +1339,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_follow_reference_opens_mentioned_card_and_backspace_returns() {
+        let mut app = App::test_default();
+        let board = app
+            .ctx
+            .create_board("Board".into(), Some("KAN".into()))
+            .unwrap();
+        let column = app
+            .ctx
+            .create_column(board.id, "TODO".into(), None)
+            .unwrap();
+        let mut ids = Vec::new();
+        for description in ["Needs KAN-3 and KAN-2 first", "", ""] {
+            let options = CreateCardOptions {
+                description: Some(description.into()),
+                ..Default::default()
+            };
+            let card = app
+                .ctx
+                .create_card(board.id, column.id, "Card".into(), options)
+                .unwrap();
+            ids.push(card.id);
+        }
+        reload_snapshot(&mut app);
+        app.selection.active_board_index = Some(0);
+        app.open_card_detail(ids[0]);
+        app.focus.card_focus = CardFocus::Description;
+
+        assert_eq!(app.current_card_references(), [ids[2], ids[1]]);
+        app.select_reference(1);
+        assert_eq!(app.selected_card_reference(), Some(ids[1]));
+
+        app.follow_selected_reference();
+        assert_eq!(app.selection.active_card_id, Some(ids[1]));
+        assert_eq!(app.selection.card_navigation_history, [ids[0]]);
+
+        app.return_to_previous_card_from_detail_history();
+        assert_eq!(app.selection.active_card_id, Some(ids[0]));
+        assert_eq!(app.selected_card_reference(), Some(ids[2]));
+    }
+
     #[test]
     fn test_sprint_detail_enter_on_card_sets_active_card_id_so_detail_view_resolves() {
         let mut app = App::test_default();
@@ -15,6 +15,11 @@ enum PrefixDialogContext {
 
 impl App {
     pub fn handle_create_board_dialog(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Tab => return self.dialog_input.cycle_board_template(true),
+            KeyCode::BackTab => return self.dialog_input.cycle_board_template(false),
+            _ => {}
+        }
         match handle_dialog_input(&mut self.input, key_code, false) {
             DialogAction::Confirm => {
                 self.create_board();
@@ -118,6 +123,34 @@ impl App {
         }
     }
 
+    pub fn handle_clone_board_dialog(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Tab => {
+                let options = &mut self.dialog_input.clone_options;
+                options.include_cards = !options.include_cards;
+                return;
+            }
+            KeyCode::BackTab => {
+                let options = &mut self.dialog_input.clone_options;
+                options.include_sprints = !options.include_sprints;
+                return;
+            }
+            _ => {}
+        }
+        match handle_dialog_input(&mut self.input, key_code, false) {
+            DialogAction::Confirm => {
+                self.clone_board();
+                self.pop_mode();
+                self.input.clear();
+            }
+            DialogAction::Cancel => {
+                self.pop_mode();
+                self.input.clear();
+            }
+            DialogAction::None => {}
+        }
+    }
+
     pub fn handle_export_board_dialog(&mut self, key_code: KeyCode) {
         match handle_dialog_input(&mut self.input, key_code, false) {
             DialogAction::Confirm => {
@@ -1,10 +1,16 @@
+pub mod attachment_handlers;
 pub mod board_handlers;
 pub mod card_handlers;
 pub mod column_handlers;
+pub mod command_palette_handlers;
 pub mod detail_view_handlers;
 pub mod dialog_handlers;
 pub mod filter_handlers;
+pub mod mouse_handlers;
 pub mod navigation_handlers;
 pub mod popup_handlers;
+pub mod preview_handlers;
+pub mod reference_handlers;
 pub mod settings_handlers;
 pub mod sprint_handlers;
+pub mod text_editor_handlers;
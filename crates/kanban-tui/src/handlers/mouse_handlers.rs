@@ -0,0 +1,178 @@
+use crate::app::mouse::DOUBLE_CLICK_MS;
+use crate::app::{App, AppMode, Focus};
+use crate::card_list::CardListId;
+use crate::hit_test::{hit_test, HitTarget};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use kanban_domain::{KanbanOperations, TaskListView};
+use std::time::Instant;
+
+impl App {
+    /// Handles a mouse event. Returns the keys it stands for, which the
+    /// caller dispatches like typed ones: a clicked footer hint, or the
+    /// wheel outside the main view.
+    pub fn handle_mouse_event(&mut self, mouse: MouseEvent) -> Vec<KeyEvent> {
+        let target = hit_test(self, mouse.column, mouse.row);
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.mouse.dragging = None;
+                if target == Some(HitTarget::Footer) {
+                    return self
+                        .view
+                        .hit_areas
+                        .footer
+                        .and_then(|area| {
+                            crate::components::footer_hint_keys(self, area, mouse.column)
+                        })
+                        .unwrap_or_default();
+                }
+                if let Some(HitTarget::CardReference(card_id)) = target {
+                    if matches!(self.mode, AppMode::CardDetail | AppMode::BoardDetail) {
+                        self.follow_card_reference(card_id);
+                    }
+                } else if self.mode == AppMode::Normal {
+                    if let Some(target) = target {
+                        self.handle_click(target);
+                    }
+                }
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                if let Some(card_id) = self.mouse.dragging.take() {
+                    self.handle_card_drop(card_id, target);
+                }
+            }
+            MouseEventKind::ScrollDown => return self.handle_scroll(target, true),
+            MouseEventKind::ScrollUp => return self.handle_scroll(target, false),
+            _ => {}
+        }
+        Vec::new()
+    }
+
+    fn handle_click(&mut self, target: HitTarget) {
+        let now = Instant::now();
+        let double_click = self.mouse.last_click.is_some_and(|(at, last)| {
+            last == target && now.duration_since(at).as_millis() <= DOUBLE_CLICK_MS
+        });
+        // A double click is complete; a third click starts over.
+        self.mouse.last_click = (!double_click).then_some((now, target));
+        self.pending_key = None;
+
+        match target {
+            HitTarget::Board(index) => {
+                self.focus.active = Focus::Boards;
+                if self.selection.board.get() != Some(index) {
+                    self.selection.board.set(Some(index));
+                    self.switch_view_strategy(TaskListView::GroupedByColumn);
+                }
+                if double_click {
+                    self.handle_selection_activate();
+                }
+            }
+            HitTarget::Projects => self.handle_focus_switch(Focus::Boards),
+            HitTarget::Tasks { column } => {
+                self.focus_task_column(column);
+            }
+            HitTarget::Card { column, index } => {
+                if !self.focus_task_column(column) {
+                    return;
+                }
+                let viewport = self.get_adjusted_viewport_height();
+                let Some(list) = self.view.strategy.get_active_task_list_mut() else {
+                    return;
+                };
+                list.set_selected_index(Some(index));
+                list.ensure_selected_visible(viewport);
+                let Some(card_id) = list.get_selected_card_id() else {
+                    return;
+                };
+                if double_click {
+                    self.open_card_detail(card_id);
+                } else if self.is_kanban_view() {
+                    self.mouse.dragging = Some(card_id);
+                }
+            }
+            HitTarget::Preview => self.handle_focus_preview(),
+            HitTarget::Footer | HitTarget::CardReference(_) => {}
+        }
+    }
+
+    /// Focuses the card list, and in the kanban view the given column.
+    /// Returns false when no board is open, so there is no list to focus.
+    fn focus_task_column(&mut self, column: usize) -> bool {
+        self.handle_focus_switch(Focus::Cards);
+        if self.focus.active != Focus::Cards {
+            return false;
+        }
+        if self.is_kanban_view() {
+            self.view.strategy.try_navigate_to_column(column);
+        }
+        true
+    }
+
+    /// Drops a card dragged in the kanban view onto the column under the
+    /// pointer, if that is a different column.
+    fn handle_card_drop(&mut self, card_id: uuid::Uuid, target: Option<HitTarget>) {
+        if self.mode != AppMode::Normal || !self.is_kanban_view() {
+            return;
+        }
+        let column = match target {
+            Some(HitTarget::Tasks { column } | HitTarget::Card { column, .. }) => column,
+            _ => return,
+        };
+        let Some(CardListId::Column(column_id)) = self
+            .view
+            .strategy
+            .get_all_task_lists()
+            .get(column)
+            .map(|list| list.id.clone())
+        else {
+            return;
+        };
+        if self
+            .model
+            .card(card_id)
+            .is_none_or(|c| c.column_id == column_id)
+        {
+            return;
+        }
+
+        if let Err(e) = self.ctx.move_card(card_id, column_id, None) {
+            tracing::error!("Failed to move card: {}", e);
+            self.set_error(format!("Failed to move card: {}", e));
+            return;
+        }
+        self.prepare_frame();
+        self.select_card_by_id(card_id);
+    }
+
+    /// The wheel moves the selection of the list under the pointer, like
+    /// j/k. Outside the main view it stands for the arrow keys.
+    fn handle_scroll(&mut self, target: Option<HitTarget>, down: bool) -> Vec<KeyEvent> {
+        if self.is_text_input_mode() {
+            return Vec::new();
+        }
+        if self.mode != AppMode::Normal {
+            let code = if down { KeyCode::Down } else { KeyCode::Up };
+            return vec![KeyEvent::new(code, KeyModifiers::NONE)];
+        }
+
+        match target {
+            Some(HitTarget::Board(_) | HitTarget::Projects) => {
+                self.handle_focus_switch(Focus::Boards)
+            }
+            Some(HitTarget::Tasks { column } | HitTarget::Card { column, .. }) => {
+                if !self.focus_task_column(column) {
+                    return Vec::new();
+                }
+            }
+            Some(HitTarget::Footer | HitTarget::Preview | HitTarget::CardReference(_)) | None => {
+                return Vec::new()
+            }
+        }
+        if down {
+            self.handle_navigation_down();
+        } else {
+            self.handle_navigation_up();
+        }
+        Vec::new()
+    }
+}
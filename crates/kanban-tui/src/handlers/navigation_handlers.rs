@@ -230,7 +230,7 @@ fn calculate_jump_target_up(
 impl App {
     /// Calculate actual usable viewport height accounting for indicators and headers
     /// Must match the rendering logic to ensure page boundaries align with visible cards
-    fn get_adjusted_viewport_height(&self) -> usize {
+    pub(crate) fn get_adjusted_viewport_height(&self) -> usize {
         let raw_viewport = self.view.viewport_height;
 
         if let Some(list) = self.view.strategy.get_active_task_list() {
@@ -487,23 +487,27 @@ impl App {
             }
             Focus::Cards => {
                 if let Some(selected_card) = self.get_selected_card_in_context() {
-                    let card_id = selected_card.id;
-                    self.set_active_card_or_clear(card_id);
-                    // Initialize list components with item counts
-                    let parents = self.get_current_card_parents();
-                    let children = self.get_current_card_children();
-                    self.relationship
-                        .parents_list
-                        .update_item_count(parents.len());
-                    self.relationship
-                        .children_list
-                        .update_item_count(children.len());
-                    self.push_mode(AppMode::CardDetail);
+                    self.open_card_detail(selected_card.id);
                 }
             }
         }
     }
 
+    pub fn open_card_detail(&mut self, card_id: uuid::Uuid) {
+        self.set_active_card_or_clear(card_id);
+        // Initialize list components with item counts
+        let parents = self.get_current_card_parents();
+        let children = self.get_current_card_children();
+        self.relationship
+            .parents_list
+            .update_item_count(parents.len());
+        self.relationship
+            .children_list
+            .update_item_count(children.len());
+        self.refresh_card_git();
+        self.push_mode(AppMode::CardDetail);
+    }
+
     pub fn handle_escape_key(&mut self) {
         if self.filter.search.is_active {
             self.filter.search.deactivate();
@@ -31,6 +31,18 @@ impl App {
                 self.pop_mode();
                 self.dialog_input.import_selection.clear();
             }
+            KeyCode::Char('c') => {
+                if let Some(idx) = self.dialog_input.import_selection.get() {
+                    if let Some(filename) = self.dialog_input.import_files.get(idx).cloned() {
+                        if let Err(e) = self.import_board_copy_from_file(&filename) {
+                            tracing::error!("Failed to import board copy: {}", e);
+                            self.set_error(format!("Failed to import board copy: {}", e));
+                        }
+                    }
+                }
+                self.pop_mode();
+                self.dialog_input.import_selection.clear();
+            }
             _ => {}
         }
     }
@@ -0,0 +1,52 @@
+use crate::app::{App, AppMode, Focus};
+
+/// Percentage points `<` and `>` move the split between list and preview.
+const PREVIEW_RESIZE_STEP: i16 = 5;
+
+impl App {
+    /// Cycles the card preview from off to the right of the list, below it,
+    /// and off again.
+    pub fn handle_toggle_preview(&mut self) {
+        let preview = &mut self.view.preview;
+        preview.layout = preview.layout.next();
+    }
+
+    /// Moves focus from the card list into the preview, opening the selected
+    /// card in the detail view without leaving the split layout.
+    pub fn handle_focus_preview(&mut self) {
+        if !self.view.preview.is_enabled() || self.focus.active != Focus::Cards {
+            return;
+        }
+        if let Some(card_id) = self.get_selected_card_id() {
+            self.open_card_detail(card_id);
+        }
+    }
+
+    pub fn handle_grow_preview(&mut self) {
+        self.view.preview.resize(PREVIEW_RESIZE_STEP);
+    }
+
+    pub fn handle_shrink_preview(&mut self) {
+        self.view.preview.resize(-PREVIEW_RESIZE_STEP);
+    }
+
+    /// Whether the main view splits off a pane previewing the selected card.
+    pub fn shows_card_preview(&self) -> bool {
+        self.view.preview.is_enabled()
+            && matches!(self.get_base_mode(), AppMode::Normal | AppMode::Search)
+    }
+
+    /// Whether the card detail view was opened from the card list while the
+    /// preview is on, so it takes the preview pane rather than the screen.
+    pub fn card_detail_in_preview(&self) -> bool {
+        if !self.view.preview.is_enabled() || *self.get_base_mode() != AppMode::CardDetail {
+            return false;
+        }
+        let depth = if self.is_dialog_mode() { 2 } else { 1 };
+        self.mode_stack
+            .len()
+            .checked_sub(depth)
+            .and_then(|i| self.mode_stack.get(i))
+            == Some(&AppMode::Normal)
+    }
+}
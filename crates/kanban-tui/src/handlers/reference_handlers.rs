@@ -0,0 +1,129 @@
+use crate::app::{App, AppMode, CardFocus};
+use crate::components::build_description;
+use crate::markdown_renderer::{MarkdownOptions, RenderedMarkdown};
+use uuid::Uuid;
+
+impl App {
+    /// The card an identifier such as `KAN-17` names, if exactly one card
+    /// matches it.
+    pub fn resolve_card_reference(&self, identifier: &str) -> Option<Uuid> {
+        let matches = kanban_domain::search::find_cards_by_identifier(
+            identifier,
+            self.model.cards(),
+            self.model.columns(),
+            self.model.boards(),
+            self.model.sprints(),
+        );
+        match matches.as_slice() {
+            [card] => Some(card.id),
+            _ => None,
+        }
+    }
+
+    /// Renders a description as the detail views draw it: wrapped to
+    /// `width` cells, with references to other cards resolved and the
+    /// selected one marked while the card description has focus.
+    pub fn render_description(&self, description: Option<&str>, width: u16) -> RenderedMarkdown {
+        let resolve = |identifier: &str| self.resolve_card_reference(identifier);
+        let selected_card = (*self.get_base_mode() == AppMode::CardDetail
+            && self.focus.card_focus == CardFocus::Description)
+            .then(|| self.selected_card_reference())
+            .flatten();
+        let options = MarkdownOptions {
+            width: Some(width as usize),
+            resolve_card: Some(&resolve),
+            selected_card,
+        };
+        build_description(description, &options)
+    }
+
+    /// Cards the detail view card mentions in its description and
+    /// checklist, in order of first mention.
+    pub fn current_card_references(&self) -> Vec<Uuid> {
+        let Some(card) = self.get_card_for_detail_view() else {
+            return Vec::new();
+        };
+        let index = kanban_domain::index_cards_by_identifier(
+            self.model.cards(),
+            self.model.columns(),
+            self.model.boards(),
+            self.model.sprints(),
+        );
+        kanban_domain::find_card_references(&card, &index)
+            .into_iter()
+            .map(|c| c.id)
+            .collect()
+    }
+
+    pub fn selected_card_reference(&self) -> Option<Uuid> {
+        let references = self.current_card_references();
+        let index = self
+            .selection
+            .reference
+            .min(references.len().checked_sub(1)?);
+        references.get(index).copied()
+    }
+
+    /// Moves the reference selection by `step`, wrapping around.
+    pub fn select_reference(&mut self, step: isize) {
+        let count = self.current_card_references().len();
+        if count == 0 {
+            return;
+        }
+        let current = self.selection.reference.min(count - 1) as isize;
+        self.selection.reference = (current + step).rem_euclid(count as isize) as usize;
+    }
+
+    pub(crate) fn follow_selected_reference(&mut self) {
+        if let Some(card_id) = self.selected_card_reference() {
+            self.follow_card_reference(card_id);
+        }
+    }
+
+    /// Opens a referenced card. From a card on the same board the card it
+    /// was opened from goes on the history that Backspace walks back; a
+    /// card on another board is jumped to like the command palette does.
+    pub(crate) fn follow_card_reference(&mut self, card_id: Uuid) {
+        let current = self.selection.active_card_id;
+        let same_board = *self.get_base_mode() == AppMode::CardDetail
+            && self.board_of_card(card_id).is_some()
+            && current.and_then(|id| self.board_of_card(id)) == self.board_of_card(card_id);
+        if !same_board {
+            self.jump_to_card(card_id);
+            return;
+        }
+        if let Some(current) = current.filter(|id| *id != card_id) {
+            if self.activate_card(card_id) {
+                self.selection.card_navigation_history.push(current);
+                self.selection.reference = 0;
+                self.selection.attachment = 0;
+                self.focus.card_focus = CardFocus::Title;
+                self.refresh_relationship_counts();
+                self.refresh_card_git();
+            }
+        }
+    }
+
+    pub(crate) fn board_of_card(&self, card_id: Uuid) -> Option<Uuid> {
+        let card = self.model.card(card_id)?;
+        self.model
+            .columns()
+            .iter()
+            .find(|c| c.id == card.column_id)
+            .map(|c| c.board_id)
+    }
+
+    /// The description shown by the card or board detail view.
+    pub(crate) fn detail_description(&self) -> Option<String> {
+        match *self.get_base_mode() {
+            AppMode::CardDetail => self.get_card_for_detail_view()?.description,
+            AppMode::BoardDetail => self
+                .model
+                .boards()
+                .get(self.selection.board.get()?)?
+                .description
+                .clone(),
+            _ => None,
+        }
+    }
+}
@@ -3,7 +3,7 @@ use crate::edit_format::EditFormat;
 use crate::editor::edit_in_external_editor;
 use crate::events::EventHandler;
 use crossterm::event::KeyCode;
-use kanban_domain::export::{AllBoardsExport, BoardExporter};
+use kanban_domain::export::{AllBoardsExport, BoardExportFormat, BoardExporter};
 use kanban_service::AppConfigDto;
 use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
@@ -27,6 +27,7 @@ impl App {
             }
             SettingsFocus::ConfigFile => 3,
             SettingsFocus::Storage => 4,
+            SettingsFocus::Appearance => 1,
         }
     }
 
@@ -106,6 +107,8 @@ impl App {
         updated_dto
             .validate_and_apply(&mut config)
             .map_err(|e| format!("Invalid config: {}", e))?;
+        let theme = crate::theme::load_theme(config.effective_theme())
+            .map_err(|e| format!("Invalid config: {}", e))?;
 
         if kanban_service::config::has_non_default_values(&config) {
             kanban_service::config::save(&config)
@@ -134,6 +137,7 @@ impl App {
         }
 
         self.app_config = config;
+        crate::theme::set_theme(theme.for_depth(crate::theme::ColorDepth::detect()));
 
         if self.cli_file_override {
             if user_unlocked_storage {
@@ -216,7 +220,7 @@ impl App {
         });
 
         self.migration_state = MigrationState::Migrating {
-            old_config,
+            old_config: Box::new(old_config),
             old_storage_location: old_storage_location.to_string(),
             result_rx: rx,
         };
@@ -305,7 +309,7 @@ impl App {
                 MigrationState::Idle => return,
             };
         if let Ok(result) = rx.await {
-            self.handle_migration_complete(old_config, result).await;
+            self.handle_migration_complete(*old_config, result).await;
         }
     }
 
@@ -365,6 +369,7 @@ impl App {
             KeyCode::Char('1')
             | KeyCode::Char('2')
             | KeyCode::Char('3')
+            | KeyCode::Char('4')
             | KeyCode::Char('j')
             | KeyCode::Down
             | KeyCode::Char('k')
@@ -413,40 +418,87 @@ impl App {
                     .settings_storage
                     .auto_select_first_if_empty(true);
             }
+            KeyCode::Char('4') => {
+                self.focus.settings_focus = SettingsFocus::Appearance;
+            }
             KeyCode::Char('j') | KeyCode::Down => {
                 self.handle_settings_nav_down();
             }
             KeyCode::Char('k') | KeyCode::Up => {
                 self.handle_settings_nav_up();
             }
-            KeyCode::Char('h') | KeyCode::Left => {
-                if self.focus.settings_focus == SettingsFocus::Storage {
-                    self.focus.settings_focus = SettingsFocus::Configuration;
-                    self.selection
-                        .settings_config
-                        .auto_select_first_if_empty(true);
-                }
+            KeyCode::Char('h') | KeyCode::Left
+                if matches!(
+                    self.focus.settings_focus,
+                    SettingsFocus::Storage | SettingsFocus::Appearance
+                ) =>
+            {
+                self.focus.settings_focus = SettingsFocus::Configuration;
+                self.selection
+                    .settings_config
+                    .auto_select_first_if_empty(true);
             }
-            KeyCode::Char('l') | KeyCode::Right => {
-                if self.focus.settings_focus != SettingsFocus::Storage {
-                    self.focus.settings_focus = SettingsFocus::Storage;
-                    self.selection
-                        .settings_storage
-                        .auto_select_first_if_empty(true);
-                }
+            KeyCode::Char('l') | KeyCode::Right
+                if matches!(
+                    self.focus.settings_focus,
+                    SettingsFocus::Configuration | SettingsFocus::ConfigFile
+                ) =>
+            {
+                self.focus.settings_focus = SettingsFocus::Storage;
+                self.selection
+                    .settings_storage
+                    .auto_select_first_if_empty(true);
             }
-            KeyCode::Enter => {
+            KeyCode::Enter
                 if self.focus.settings_focus == SettingsFocus::Storage
-                    && self.selection.settings_storage.get() == Some(EXPORT_BUTTON_STORAGE_INDEX)
-                {
-                    return self.trigger_export();
-                }
+                    && self.selection.settings_storage.get()
+                        == Some(EXPORT_BUTTON_STORAGE_INDEX) =>
+            {
+                return self.trigger_export();
+            }
+            KeyCode::Enter if self.focus.settings_focus == SettingsFocus::Appearance => {
+                self.cycle_theme();
             }
             _ => {}
         }
         false
     }
 
+    /// Switch to the next available theme, applying it immediately so the
+    /// settings view previews it, and persist the choice to the config file.
+    pub fn cycle_theme(&mut self) {
+        let names = crate::theme::available_themes();
+        let next = next_theme_name(&names, self.app_config.effective_theme());
+        if let Err(e) = crate::theme::activate_theme(&next) {
+            self.set_error(e);
+            return;
+        }
+        self.app_config.theme = Some(next.clone());
+        kanban_service::config::strip_defaults(&mut self.app_config);
+
+        // CLI-supplied storage is session-only and must not be persisted.
+        let mut config = self.app_config.clone();
+        if self.cli_file_override {
+            config.storage_backend = self.original_storage_backend.clone();
+            config.storage_location = self.original_storage_location.clone();
+        }
+        let result = if kanban_service::config::has_non_default_values(&config) {
+            kanban_service::config::save(&config).map_err(|e| e.to_string())
+        } else {
+            let location = kanban_service::config::effective_configuration_location(&config);
+            let path = std::path::Path::new(&location);
+            if path.exists() {
+                std::fs::remove_file(path).map_err(|e| e.to_string())
+            } else {
+                Ok(())
+            }
+        };
+        match result {
+            Ok(()) => self.set_success(format!("Theme set to '{}'", next)),
+            Err(e) => self.set_error(format!("Failed to save config: {}", e)),
+        }
+    }
+
     pub fn handle_settings_nav_down(&mut self) {
         match self.focus.settings_focus {
             SettingsFocus::Configuration => {
@@ -487,6 +539,7 @@ impl App {
                     self.selection.settings_storage.next(count);
                 }
             }
+            SettingsFocus::Appearance => {}
         }
     }
 
@@ -523,6 +576,7 @@ impl App {
                     self.selection.settings_storage.prev();
                 }
             }
+            SettingsFocus::Appearance => {}
         }
     }
 
@@ -562,10 +616,8 @@ impl App {
                         dialog.cursor = (dialog.cursor + len - 1) % len;
                     }
                 }
-                KeyCode::Enter => {
-                    if dialog.any_selected() {
-                        dialog.step = crate::app::ExportStep::ExportOptions;
-                    }
+                KeyCode::Enter if dialog.any_selected() => {
+                    dialog.step = crate::app::ExportStep::ExportOptions;
                 }
                 KeyCode::Esc => {
                     self.export_dialog = None;
@@ -575,20 +627,14 @@ impl App {
             },
             crate::app::ExportStep::ExportOptions => match key_code {
                 KeyCode::Tab | KeyCode::BackTab => {
-                    dialog.format = match dialog.format {
-                        crate::app::ExportFormat::Json => crate::app::ExportFormat::Sqlite,
-                        crate::app::ExportFormat::Sqlite => crate::app::ExportFormat::Json,
-                    };
+                    dialog.format = dialog.format.cycle(key_code == KeyCode::Tab);
                     let stem = dialog
                         .filename
                         .rsplit_once('.')
                         .map(|(s, _)| s)
                         .unwrap_or(&dialog.filename)
                         .to_string();
-                    dialog.filename = match dialog.format {
-                        crate::app::ExportFormat::Json => format!("{}.json", stem),
-                        crate::app::ExportFormat::Sqlite => format!("{}.sqlite", stem),
-                    };
+                    dialog.filename = format!("{}.{}", stem, dialog.format.extension());
                 }
                 KeyCode::Backspace => {
                     dialog.filename.pop();
@@ -632,33 +678,46 @@ impl App {
         }
 
         let boards = self.model.boards();
-        let columns = self.model.columns();
-        let cards = self.model.cards();
-        let archived = self.model.archived_cards();
-        let sprints = self.model.sprints();
-        let board_exports: Vec<_> = selected_indices
+        let selected_boards: Vec<_> = selected_indices
             .iter()
             .filter_map(|&i| boards.get(i))
-            .map(|board| BoardExporter::export_board(board, columns, cards, archived, sprints))
+            .cloned()
             .collect();
 
-        let export = AllBoardsExport::from_boards(board_exports);
+        let text_format = match dialog.format {
+            crate::app::ExportFormat::Json => Some(BoardExportFormat::Json),
+            crate::app::ExportFormat::Markdown => Some(BoardExportFormat::Markdown),
+            crate::app::ExportFormat::Csv => Some(BoardExportFormat::Csv),
+            crate::app::ExportFormat::Ics => Some(BoardExportFormat::Ics),
+            crate::app::ExportFormat::Bundle => Some(BoardExportFormat::Bundle),
+            crate::app::ExportFormat::Sqlite => None,
+        };
+
+        match text_format {
+            Some(format) => match self.export_boards_to_file(&selected_boards, format, &filename) {
+                Ok(()) => self.set_success(format!("Exported to {}", filename)),
+                Err(e) => self.set_error(format!("Export failed: {}", e)),
+            },
+            None => {
+                let columns = self.model.columns();
+                let cards = self.model.cards();
+                let archived = self.model.archived_cards();
+                let sprints = self.model.sprints();
+                let graph = self.model.graph();
+                let board_exports: Vec<_> = selected_boards
+                    .iter()
+                    .map(|board| {
+                        BoardExporter::export_board(board, columns, cards, archived, sprints, graph)
+                    })
+                    .collect();
+                let export = AllBoardsExport::from_boards(board_exports);
 
-        match dialog.format {
-            crate::app::ExportFormat::Json => {
-                match BoardExporter::export_to_file(&export, &filename) {
-                    Ok(()) => self.set_success(format!("Exported to {}", filename)),
-                    Err(e) => self.set_error(format!("Export failed: {}", e)),
-                }
-            }
-            crate::app::ExportFormat::Sqlite => {
                 let filename_clone = filename.clone();
-                let export_clone = export.clone();
                 let store_manager = self.store_manager.clone();
                 let (tx, rx) = tokio::sync::oneshot::channel();
                 tokio::spawn(async move {
                     let result = store_manager
-                        .export_to_sqlite(export_clone, &filename_clone)
+                        .export_to_sqlite(export, &filename_clone)
                         .await
                         .map(|_| filename_clone)
                         .map_err(|e| format!("Export failed: {}", e));
@@ -673,3 +732,30 @@ impl App {
         self.pop_mode();
     }
 }
+
+/// The theme after `current` in `names`, wrapping around; unknown names
+/// (e.g. a path to a theme file) restart at the first theme.
+fn next_theme_name(names: &[String], current: &str) -> String {
+    let next = names
+        .iter()
+        .position(|n| n == current)
+        .map(|i| (i + 1) % names.len())
+        .unwrap_or(0);
+    names[next].clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_theme_name_wraps_and_restarts_on_unknown() {
+        let names: Vec<String> = ["dark", "light", "paper"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(next_theme_name(&names, "dark"), "light");
+        assert_eq!(next_theme_name(&names, "paper"), "dark");
+        assert_eq!(next_theme_name(&names, "/tmp/custom.toml"), "dark");
+    }
+}
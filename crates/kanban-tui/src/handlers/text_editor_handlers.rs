@@ -0,0 +1,125 @@
+use crate::app::{App, AppMode, DialogMode};
+use crate::editor::edit_in_external_editor;
+use crate::events::EventHandler;
+use crate::text_editor::{EditorAction, TextTarget};
+use crossterm::event::KeyEvent;
+use ratatui::{backend::CrosstermBackend, Terminal};
+use std::io;
+
+impl App {
+    /// Whether titles and descriptions are edited in $EDITOR rather than
+    /// the inline editor.
+    pub fn uses_external_editor(&self) -> bool {
+        self.app_config.effective_text_editor() == "external"
+    }
+
+    pub fn open_text_editor(&mut self, target: TextTarget, text: &str) {
+        let vi = self.app_config.effective_text_editor() == "vi";
+        self.ui_state.text_editor.open(target, text, vi);
+        self.push_mode(AppMode::Dialog(DialogMode::EditText));
+    }
+
+    /// Handles a key in the inline editor. Returns true when the key asks
+    /// to continue in $EDITOR, which the caller runs with
+    /// [`App::continue_in_external_editor`].
+    pub fn handle_text_editor_key(&mut self, key: KeyEvent) -> bool {
+        match self.ui_state.text_editor.handle_key(key) {
+            EditorAction::Continue => false,
+            EditorAction::Save => {
+                let editor = &mut self.ui_state.text_editor;
+                let target = editor.target;
+                let text = editor.input.as_str().to_string();
+                editor.close();
+                self.pop_mode();
+                if let Some(target) = target {
+                    self.apply_text_edit(target, text);
+                }
+                false
+            }
+            EditorAction::Cancel => {
+                self.ui_state.text_editor.close();
+                self.pop_mode();
+                false
+            }
+            EditorAction::OpenExternal => true,
+        }
+    }
+
+    /// Hands the text being edited to $EDITOR and loads the result back
+    /// into the inline editor, where it can still be saved or discarded.
+    pub fn continue_in_external_editor(
+        &mut self,
+        terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+        event_handler: &EventHandler,
+    ) {
+        let editor = &self.ui_state.text_editor;
+        let Some(target) = editor.target else {
+            return;
+        };
+        let text = editor.input.as_str().to_string();
+        match edit_in_external_editor(terminal, event_handler, target.temp_file(), &text) {
+            Ok(Some(content)) => {
+                let content = if target.is_single_line() {
+                    content.trim()
+                } else {
+                    content.as_str()
+                };
+                self.ui_state.text_editor.replace_text(content);
+            }
+            Ok(None) => {}
+            Err(e) => {
+                tracing::error!("Failed to open external editor: {}", e);
+                self.set_error(format!("Failed to open external editor: {}", e));
+            }
+        }
+    }
+
+    /// Writes edited text back to its field. Empty titles and names are
+    /// ignored; an empty description clears it.
+    pub fn apply_text_edit(&mut self, target: TextTarget, content: String) {
+        use crate::app::{BoardField, CardField};
+        use kanban_domain::commands::{
+            BoardCommand, CardCommand, Command, UpdateBoard, UpdateCard,
+        };
+        use kanban_domain::FieldUpdate;
+
+        let description = if content.trim().is_empty() {
+            FieldUpdate::Clear
+        } else {
+            FieldUpdate::Set(content.clone())
+        };
+        let cmd = match target {
+            TextTarget::Card(card_id, field) => {
+                let updates = match field {
+                    CardField::Title if content.trim().is_empty() => return,
+                    CardField::Title => kanban_domain::CardUpdate {
+                        title: Some(content.trim().to_string()),
+                        ..Default::default()
+                    },
+                    CardField::Description => kanban_domain::CardUpdate {
+                        description,
+                        ..Default::default()
+                    },
+                };
+                Command::Card(CardCommand::Update(UpdateCard { card_id, updates }))
+            }
+            TextTarget::Board(board_id, field) => {
+                let updates = match field {
+                    BoardField::Name if content.trim().is_empty() => return,
+                    BoardField::Name => kanban_domain::BoardUpdate {
+                        name: Some(content.trim().to_string()),
+                        ..Default::default()
+                    },
+                    BoardField::Description => kanban_domain::BoardUpdate {
+                        description,
+                        ..Default::default()
+                    },
+                };
+                Command::Board(BoardCommand::Update(UpdateBoard { board_id, updates }))
+            }
+        };
+        if let Err(e) = self.execute_command(cmd) {
+            tracing::error!("Failed to update {}: {}", target.noun(), e);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Maps a screen position back to what the last frame drew there.
+//!
+//! Panel areas come from [`HitAreas`](crate::app::HitAreas); rows inside a
+//! card list are laid out again with the same [`ListRow`] helpers the
+//! renderers use, so the layout strategy and its column boundaries decide
+//! both what is drawn and what a click hits. Descriptions are rendered
+//! again the same way to find the card reference under the pointer.
+
+use crate::app::App;
+use crate::layout_strategy::{ColumnListsLayout, VirtualUnifiedLayout};
+use crate::markdown_renderer::LinkTarget;
+use crate::render_strategy::{column_panels, flat_rows, grouped_rows, ListRow};
+use crate::view_strategy::UnifiedViewStrategy;
+use ratatui::layout::{Position, Rect};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HitTarget {
+    /// A board row in the projects panel.
+    Board(usize),
+    /// The projects panel below its last board.
+    Projects,
+    /// A card list outside any card: empty space, a column header or a
+    /// scroll indicator. `column` is the kanban column, 0 in other views.
+    Tasks {
+        column: usize,
+    },
+    /// The card at `index` in the list of `column`.
+    Card {
+        column: usize,
+        index: usize,
+    },
+    Footer,
+    /// The pane previewing the selected card.
+    Preview,
+    /// A reference to another card in a detail view's description.
+    CardReference(uuid::Uuid),
+}
+
+pub fn hit_test(app: &App, x: u16, y: u16) -> Option<HitTarget> {
+    let position = Position::new(x, y);
+    let areas = app.view.hit_areas;
+
+    if areas.footer.is_some_and(|area| area.contains(position)) {
+        return Some(HitTarget::Footer);
+    }
+
+    if let Some(area) = areas.description.filter(|area| area.contains(position)) {
+        let description = app.render_description(app.detail_description().as_deref(), area.width);
+        return match description.link_at((y - area.y) as usize, (x - area.x) as usize) {
+            Some(LinkTarget::Card(card_id)) => Some(HitTarget::CardReference(*card_id)),
+            _ => None,
+        };
+    }
+
+    if let Some(area) = areas.projects.filter(|area| area.contains(position)) {
+        let board_count = app.model.boards().len();
+        return Some(match inner_row(area, y) {
+            Some(row) if row < board_count => HitTarget::Board(row),
+            _ => HitTarget::Projects,
+        });
+    }
+
+    if areas.preview.is_some_and(|area| area.contains(position)) {
+        return Some(HitTarget::Preview);
+    }
+
+    let area = areas.tasks.filter(|area| area.contains(position))?;
+    let layout = app
+        .view
+        .strategy
+        .as_any()
+        .downcast_ref::<UnifiedViewStrategy>()?
+        .get_layout_strategy()
+        .as_any();
+
+    if layout.is::<ColumnListsLayout>() {
+        let lists = app.view.strategy.get_all_task_lists();
+        if lists.is_empty() {
+            return Some(HitTarget::Tasks { column: 0 });
+        }
+        let panels = column_panels(area, lists.len());
+        let column = panels.iter().position(|panel| panel.contains(position))?;
+        let panel = panels[column];
+        let (_, rows) = flat_rows(lists[column], inner_height(panel));
+        return Some(target_in(&rows, inner_row(panel, y), column));
+    }
+
+    let Some(list) = app.view.strategy.get_active_task_list() else {
+        return Some(HitTarget::Tasks { column: 0 });
+    };
+    let rows = match layout.downcast_ref::<VirtualUnifiedLayout>() {
+        Some(grouped) => grouped_rows(list, grouped.get_column_boundaries(), inner_height(area)).1,
+        None => flat_rows(list, inner_height(area)).1,
+    };
+    Some(target_in(&rows, inner_row(area, y), 0))
+}
+
+fn target_in(rows: &[ListRow], row: Option<usize>, column: usize) -> HitTarget {
+    match row.and_then(|row| rows.get(row)) {
+        Some(ListRow::Card(index)) => HitTarget::Card {
+            column,
+            index: *index,
+        },
+        _ => HitTarget::Tasks { column },
+    }
+}
+
+/// Lines inside a bordered panel.
+fn inner_height(area: Rect) -> usize {
+    area.height.saturating_sub(2) as usize
+}
+
+/// Line `y` falls on inside a bordered panel, or `None` on the border.
+fn inner_row(area: Rect, y: u16) -> Option<usize> {
+    (y > area.y && y + 1 < area.bottom()).then(|| (y - area.y - 1) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inner_row_skips_borders() {
+        let area = Rect::new(0, 2, 10, 5);
+        assert_eq!(inner_row(area, 2), None);
+        assert_eq!(inner_row(area, 3), Some(0));
+        assert_eq!(inner_row(area, 5), Some(2));
+        assert_eq!(inner_row(area, 6), None);
+    }
+
+    #[test]
+    fn test_target_in_maps_headers_and_indicators_to_the_list() {
+        let rows = [ListRow::Above, ListRow::Header(0), ListRow::Card(4)];
+        assert_eq!(
+            target_in(&rows, Some(2), 1),
+            HitTarget::Card {
+                column: 1,
+                index: 4
+            }
+        );
+        assert_eq!(target_in(&rows, Some(1), 1), HitTarget::Tasks { column: 1 });
+        assert_eq!(target_in(&rows, Some(7), 1), HitTarget::Tasks { column: 1 });
+        assert_eq!(target_in(&rows, None, 1), HitTarget::Tasks { column: 1 });
+    }
+}
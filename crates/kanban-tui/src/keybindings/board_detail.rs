@@ -23,6 +23,12 @@ impl KeybindingProvider for BoardDetailProvider {
 
         let mut bindings = vec![
             Keybinding::new("?", "help", "Show help", KeybindingAction::ShowHelp),
+            Keybinding::new(
+                ":",
+                "commands",
+                "Open command palette",
+                KeybindingAction::CommandPalette,
+            ),
             Keybinding::new(
                 "q",
                 "quit",
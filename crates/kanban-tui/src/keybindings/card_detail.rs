@@ -23,6 +23,12 @@ impl KeybindingProvider for CardDetailProvider {
 
         let mut bindings = vec![
             Keybinding::new("?", "help", "Show help", KeybindingAction::ShowHelp),
+            Keybinding::new(
+                ":",
+                "commands",
+                "Open command palette",
+                KeybindingAction::CommandPalette,
+            ),
             Keybinding::new(
                 "q",
                 "quit",
@@ -76,6 +82,28 @@ impl KeybindingProvider for CardDetailProvider {
                     "Edit current panel",
                     KeybindingAction::EditCard,
                 ));
+                if self.focus == CardFocus::Description {
+                    bindings.extend([
+                        Keybinding::new(
+                            "Tab",
+                            "next ref",
+                            "Select next card reference",
+                            KeybindingAction::EditCard,
+                        ),
+                        Keybinding::new(
+                            "Enter",
+                            "follow ref",
+                            "Open the selected card reference",
+                            KeybindingAction::EditCard,
+                        ),
+                        Keybinding::new(
+                            "Backspace",
+                            "back",
+                            "Return to the card the reference was followed from",
+                            KeybindingAction::EditCard,
+                        ),
+                    ]);
+                }
             }
             CardFocus::Parents => {
                 bindings.push(Keybinding::new(
@@ -133,6 +161,37 @@ impl KeybindingProvider for CardDetailProvider {
                 "Assign task to sprint",
                 KeybindingAction::AssignToSprint,
             ),
+            Keybinding::new("f", "attach", "Attach a file", KeybindingAction::EditCard),
+            Keybinding::new(
+                "]",
+                "next file",
+                "Select next attachment",
+                KeybindingAction::EditCard,
+            ),
+            Keybinding::new(
+                "[",
+                "prev file",
+                "Select previous attachment",
+                KeybindingAction::EditCard,
+            ),
+            Keybinding::new(
+                "o",
+                "open file",
+                "Open selected attachment",
+                KeybindingAction::EditCard,
+            ),
+            Keybinding::new(
+                "x",
+                "extract",
+                "Extract selected attachment to the working directory",
+                KeybindingAction::EditCard,
+            ),
+            Keybinding::new(
+                "X",
+                "detach",
+                "Remove selected attachment",
+                KeybindingAction::EditCard,
+            ),
             Keybinding::new("u", "undo", "Undo last action", KeybindingAction::Undo),
             Keybinding::new(
                 "U",
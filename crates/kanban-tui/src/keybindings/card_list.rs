@@ -8,6 +8,12 @@ impl KeybindingProvider for CardListProvider {
             "Normal Mode - Cards Panel",
             vec![
                 Keybinding::new("?", "help", "Show help", KeybindingAction::ShowHelp),
+                Keybinding::new(
+                    ":",
+                    "commands",
+                    "Open command palette",
+                    KeybindingAction::CommandPalette,
+                ),
                 Keybinding::new("q", "quit", "Quit application", KeybindingAction::Escape),
                 Keybinding::new(
                     "1",
@@ -169,6 +175,30 @@ impl KeybindingProvider for CardListProvider {
                     "Set task priority",
                     KeybindingAction::EditCard,
                 ),
+                Keybinding::new(
+                    "w",
+                    "preview",
+                    "Toggle card preview (right, bottom, off)",
+                    KeybindingAction::TogglePreview,
+                ),
+                Keybinding::new(
+                    "Tab",
+                    "focus preview",
+                    "Open selected card in the preview pane",
+                    KeybindingAction::FocusPreview,
+                ),
+                Keybinding::new(
+                    "<",
+                    "shrink",
+                    "Shrink preview pane",
+                    KeybindingAction::ShrinkPreview,
+                ),
+                Keybinding::new(
+                    ">",
+                    "grow",
+                    "Grow preview pane",
+                    KeybindingAction::GrowPreview,
+                ),
                 Keybinding::new(
                     "s",
                     "children",
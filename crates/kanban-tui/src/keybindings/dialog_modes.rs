@@ -36,6 +36,99 @@ impl KeybindingProvider for SearchModeProvider {
     }
 }
 
+pub struct CommandPaletteProvider;
+
+impl KeybindingProvider for CommandPaletteProvider {
+    fn get_context(&self) -> KeybindingContext {
+        KeybindingContext::new(
+            "Command Palette",
+            vec![
+                Keybinding::new(
+                    "Type",
+                    "filter",
+                    "Fuzzy-filter commands",
+                    KeybindingAction::Search,
+                ),
+                Keybinding::new(
+                    "↑/↓",
+                    "navigate",
+                    "Select command",
+                    KeybindingAction::NavigateDown,
+                ),
+                Keybinding::new(
+                    "Enter",
+                    "run",
+                    "Run selected command",
+                    KeybindingAction::SelectItem,
+                ),
+                Keybinding::new("ESC", "close", "Close palette", KeybindingAction::Escape),
+            ],
+        )
+    }
+}
+
+pub struct TextEditorProvider;
+
+impl KeybindingProvider for TextEditorProvider {
+    fn get_context(&self) -> KeybindingContext {
+        KeybindingContext::new(
+            "Text Editor",
+            vec![
+                Keybinding::new(
+                    "Ctrl+s",
+                    "save",
+                    "Save and close",
+                    KeybindingAction::SelectItem,
+                ),
+                Keybinding::new(
+                    "ESC",
+                    "cancel",
+                    "Discard changes (leaves insert mode in vi mode)",
+                    KeybindingAction::Escape,
+                ),
+                Keybinding::new(
+                    "Enter",
+                    "newline",
+                    "New line; saves titles and names",
+                    KeybindingAction::EditCard,
+                ),
+                Keybinding::new(
+                    "Ctrl+←/→",
+                    "word",
+                    "Move by word (also Alt+b/f)",
+                    KeybindingAction::NavigateLeft,
+                ),
+                Keybinding::new(
+                    "Shift+move",
+                    "select",
+                    "Extend the selection",
+                    KeybindingAction::NavigateLeft,
+                ),
+                Keybinding::new(
+                    "Ctrl+a",
+                    "all",
+                    "Select all",
+                    KeybindingAction::NavigateLeft,
+                ),
+                Keybinding::new(
+                    "Ctrl+w",
+                    "delete word",
+                    "Delete the previous word",
+                    KeybindingAction::EditCard,
+                ),
+                Keybinding::new("Ctrl+z", "undo", "Undo", KeybindingAction::Undo),
+                Keybinding::new("Ctrl+y", "redo", "Redo", KeybindingAction::Redo),
+                Keybinding::new(
+                    "Ctrl+o",
+                    "$EDITOR",
+                    "Continue in the external editor",
+                    KeybindingAction::EditCard,
+                ),
+            ],
+        )
+    }
+}
+
 pub struct DialogInputProvider {
     dialog_name: String,
 }
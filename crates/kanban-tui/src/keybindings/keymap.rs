@@ -0,0 +1,877 @@
+//! User-configurable key overrides loaded from the `[keymap]` config section.
+//!
+//! The mode handlers match on their built-in keys, so instead of threading the
+//! keymap through every handler, incoming keys are translated: a key sequence
+//! bound by the user to an action is replaced with that action's default keys
+//! before dispatch, and the default keys of a rebound action are swallowed.
+
+use crate::app::{AppMode, DialogMode, Focus};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use kanban_core::KeySequences;
+use kanban_domain::{KanbanError, KanbanResult};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeymapContext {
+    Normal,
+    CardList,
+    CardDetail,
+    BoardDetail,
+    SprintDetail,
+    Settings,
+    Dialogs,
+}
+
+struct ActionSpec {
+    name: &'static str,
+    keys: &'static [&'static str],
+    /// Key label of the matching entry in the help popup.
+    help_key: &'static str,
+}
+
+const fn action(
+    name: &'static str,
+    keys: &'static [&'static str],
+    help_key: &'static str,
+) -> ActionSpec {
+    ActionSpec {
+        name,
+        keys,
+        help_key,
+    }
+}
+
+const NORMAL_ACTIONS: &[ActionSpec] = &[
+    action("quit", &["q"], "q"),
+    action("help", &["?"], "?"),
+    action("focus_projects", &["1"], "1"),
+    action("focus_tasks", &["2"], "2"),
+    action("create_board", &["n"], "n"),
+    action("rename_board", &["r"], "r"),
+    action("clone_board", &["c"], "c"),
+    action("edit_board", &["e"], "e"),
+    action("export_board", &["x"], "x"),
+    action("export_all", &["X"], "X"),
+    action("import_board", &["i"], "i"),
+    action("navigate_down", &["j", "Down"], "j/↓"),
+    action("navigate_up", &["k", "Up"], "k/↑"),
+    action("jump_to_top", &["gg"], "gg"),
+    action("jump_to_bottom", &["G"], "G"),
+    action("half_page_up", &["{"], "{"),
+    action("half_page_down", &["}"], "}"),
+    action("select", &["Enter", "Space"], "Enter/Space"),
+    action("undo", &["u"], "u"),
+    action("redo", &["U"], "U"),
+    action("open_settings", &["S"], "S"),
+    action("command_palette", &[":", "Ctrl+p"], ":"),
+];
+
+const CARD_LIST_ACTIONS: &[ActionSpec] = &[
+    action("quit", &["q"], "q"),
+    action("help", &["?"], "?"),
+    action("focus_projects", &["1"], "1"),
+    action("focus_tasks", &["2"], "2"),
+    action("create_card", &["n"], "n"),
+    action("edit_card", &["e"], "e"),
+    action("toggle_completion", &["c"], "c"),
+    action("archive_card", &["d"], "d"),
+    action("archived_view", &["D"], "D"),
+    action("toggle_selection", &["v"], "v"),
+    action("select_all", &["Ctrl+a"], "Ctrl+a"),
+    action("clear_selection", &["Esc"], "Esc"),
+    action("bulk_priority", &["P"], "P"),
+    action("toggle_view", &["V"], "V"),
+    action("navigate_down", &["j", "Down"], "j/↓"),
+    action("navigate_up", &["k", "Up"], "k/↑"),
+    action("jump_to_top", &["gg"], "gg"),
+    action("jump_to_bottom", &["G"], "G"),
+    action("half_page_up", &["{"], "{"),
+    action("half_page_down", &["}"], "}"),
+    action("previous_column", &["h"], "h"),
+    action("next_column", &["l"], "l"),
+    action("move_card_left", &["H"], "H"),
+    action("move_card_right", &["L"], "L"),
+    action("sort", &["o"], "o"),
+    action("toggle_sort_order", &["O"], "O"),
+    action("assign_sprint", &["a"], "a"),
+    action("toggle_sprint_filter", &["t"], "t"),
+    action("filter_options", &["T"], "T"),
+    action("search", &["/"], "/"),
+    action("open_card", &["Enter", "Space"], "Enter/Space"),
+    action("manage_children", &["s"], "s"),
+    action("export_board", &["x"], "x"),
+    action("export_all", &["X"], "X"),
+    action("import_board", &["i"], "i"),
+    action("undo", &["u"], "u"),
+    action("redo", &["U"], "U"),
+    action("open_settings", &["S"], "S"),
+    action("toggle_preview", &["w"], "w"),
+    action("focus_preview", &["Tab"], "Tab"),
+    action("shrink_preview", &["<"], "<"),
+    action("grow_preview", &[">"], ">"),
+    action("command_palette", &[":", "Ctrl+p"], ":"),
+];
+
+const CARD_DETAIL_ACTIONS: &[ActionSpec] = &[
+    action("quit", &["q"], "q"),
+    action("help", &["?"], "?"),
+    action("back", &["Esc"], "ESC"),
+    action("focus_title", &["1"], "1"),
+    action("focus_metadata", &["2"], "2"),
+    action("focus_description", &["3"], "3"),
+    action("focus_parents", &["4"], "4"),
+    action("focus_children", &["5"], "5"),
+    action("navigate_down", &["j", "Down"], "j/↓"),
+    action("navigate_up", &["k", "Up"], "k/↑"),
+    action("edit", &["e"], "e"),
+    action("archive_card", &["d"], "d"),
+    action("assign_sprint", &["a"], "a"),
+    action("set_points", &["p"], "p"),
+    action("set_priority", &["P"], "P"),
+    action("manage_parents", &["r"], "r"),
+    action("manage_children", &["R"], "R"),
+    action("copy_branch", &["y"], "y"),
+    action("copy_checkout", &["Y"], "Y"),
+    action("attach_file", &["f"], "f"),
+    action("next_attachment", &["]"], "]"),
+    action("previous_attachment", &["["], "["),
+    action("open_attachment", &["o"], "o"),
+    action("extract_attachment", &["x"], "x"),
+    action("remove_attachment", &["X"], "X"),
+    action("open_related", &["Enter"], "Enter"),
+    action("next_reference", &["Tab"], "Tab"),
+    action("command_palette", &[":", "Ctrl+p"], ":"),
+];
+
+const BOARD_DETAIL_ACTIONS: &[ActionSpec] = &[
+    action("quit", &["q"], "q"),
+    action("help", &["?"], "?"),
+    action("back", &["Esc"], "ESC"),
+    action("focus_name", &["1"], "1"),
+    action("focus_description", &["2"], "2"),
+    action("focus_settings", &["3"], "3"),
+    action("focus_sprints", &["4"], "4"),
+    action("focus_columns", &["5"], "5"),
+    action("navigate_down", &["j", "Down"], "j/↓"),
+    action("navigate_up", &["k", "Up"], "k/↑"),
+    action("select", &["Enter", "Space"], "Enter/Space"),
+    action("edit", &["e"], "e"),
+    action("create", &["n"], "n"),
+    action("set_prefix", &["p"], "p"),
+    action("rename_column", &["r"], "r"),
+    action("delete_column", &["d"], "d"),
+    action("move_column_down", &["J"], "J"),
+    action("move_column_up", &["K"], "K"),
+    action("command_palette", &[":", "Ctrl+p"], ":"),
+];
+
+const SPRINT_DETAIL_ACTIONS: &[ActionSpec] = &[
+    action("quit", &["q"], "q"),
+    action("help", &["?"], "?"),
+    action("back", &["Esc"], "ESC"),
+    action("activate_sprint", &["a"], "a"),
+    action("complete_sprint", &["c"], "c"),
+    action("delete_cards", &["d"], "d"),
+    action("set_sprint_prefix", &["p"], "p"),
+    action("set_card_prefix", &["C"], "C"),
+    action("sort", &["o"], "o"),
+    action("toggle_sort_order", &["O"], "O"),
+    action("carry_over", &["M"], "M"),
+    action("uncompleted_panel", &["h", "Left"], "h"),
+    action("completed_panel", &["l", "Right"], "l"),
+    action("navigate_down", &["j", "Down"], "j/↓"),
+    action("navigate_up", &["k", "Up"], "k/↑"),
+    action("toggle_selection", &["v"], "v"),
+    action("create_card", &["n"], "n"),
+    action("edit_card", &["e"], "e"),
+    action("assign_sprint", &["s"], "s"),
+    action("copy_branch", &["y"], "y"),
+    action("copy_checkout", &["Y"], "Y"),
+    action("command_palette", &[":", "Ctrl+p"], ":"),
+];
+
+const SETTINGS_ACTIONS: &[ActionSpec] = &[
+    action("quit", &["q"], "q"),
+    action("help", &["?"], "?"),
+    action("back", &["Esc"], "Esc"),
+    action("focus_configuration", &["1"], "1"),
+    action("focus_config_file", &["2"], "2"),
+    action("focus_storage", &["3"], "3"),
+    action("focus_appearance", &["4"], "4"),
+    action("navigate_down", &["j", "Down"], "j"),
+    action("navigate_up", &["k", "Up"], "k"),
+    action("previous_column", &["h", "Left"], "h"),
+    action("next_column", &["l", "Right"], "l"),
+    action("edit", &["e"], "e"),
+    action("select", &["Enter"], "Enter"),
+    action("export", &["x"], "x"),
+    action("command_palette", &[":", "Ctrl+p"], ":"),
+];
+
+const DIALOG_ACTIONS: &[ActionSpec] = &[
+    action("cancel", &["Esc"], "ESC"),
+    action("confirm", &["Enter"], "Enter"),
+    action("navigate_down", &["j", "Down"], "j/↓"),
+    action("navigate_up", &["k", "Up"], "k/↑"),
+    action("toggle", &["Space"], "Space"),
+];
+
+impl KeymapContext {
+    pub const ALL: [KeymapContext; 7] = [
+        Self::Normal,
+        Self::CardList,
+        Self::CardDetail,
+        Self::BoardDetail,
+        Self::SprintDetail,
+        Self::Settings,
+        Self::Dialogs,
+    ];
+
+    /// Section name under `[keymap]` in the config file.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Normal => "normal",
+            Self::CardList => "card_list",
+            Self::CardDetail => "card_detail",
+            Self::BoardDetail => "board_detail",
+            Self::SprintDetail => "sprint_detail",
+            Self::Settings => "settings",
+            Self::Dialogs => "dialogs",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.name() == name)
+    }
+
+    pub fn for_mode(mode: &AppMode, focus: &Focus) -> Option<Self> {
+        match mode {
+            AppMode::Normal => Some(match focus {
+                Focus::Boards => Self::Normal,
+                Focus::Cards => Self::CardList,
+            }),
+            AppMode::CardDetail => Some(Self::CardDetail),
+            AppMode::BoardDetail => Some(Self::BoardDetail),
+            AppMode::SprintDetail => Some(Self::SprintDetail),
+            AppMode::Settings => Some(Self::Settings),
+            AppMode::Dialog(_) => Some(Self::Dialogs),
+            AppMode::Help(previous) => Self::for_mode(previous, focus),
+            AppMode::Search | AppMode::ArchivedCardsView | AppMode::ErrorLog => None,
+        }
+    }
+
+    /// Names of the actions that can be bound in this context.
+    pub fn action_names(self) -> impl Iterator<Item = &'static str> {
+        self.actions().iter().map(|a| a.name)
+    }
+
+    fn actions(self) -> &'static [ActionSpec] {
+        match self {
+            Self::Normal => NORMAL_ACTIONS,
+            Self::CardList => CARD_LIST_ACTIONS,
+            Self::CardDetail => CARD_DETAIL_ACTIONS,
+            Self::BoardDetail => BOARD_DETAIL_ACTIONS,
+            Self::SprintDetail => SPRINT_DETAIL_ACTIONS,
+            Self::Settings => SETTINGS_ACTIONS,
+            Self::Dialogs => DIALOG_ACTIONS,
+        }
+    }
+}
+
+/// A single key press, normalised so that shifted characters compare by the
+/// character alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyPress {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyPress {
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self {
+            code,
+            modifiers: modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT),
+        }
+    }
+
+    fn is_plain_char(&self) -> bool {
+        matches!(self.code, KeyCode::Char(_)) && self.modifiers.is_empty()
+    }
+
+    fn to_event(self) -> KeyEvent {
+        KeyEvent::new(self.code, self.modifiers)
+    }
+
+    fn label(&self) -> String {
+        let key = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::Enter => "Enter".to_string(),
+            KeyCode::Esc => "Esc".to_string(),
+            KeyCode::Tab => "Tab".to_string(),
+            KeyCode::Backspace => "Backspace".to_string(),
+            KeyCode::Delete => "Delete".to_string(),
+            KeyCode::Home => "Home".to_string(),
+            KeyCode::End => "End".to_string(),
+            KeyCode::PageUp => "PageUp".to_string(),
+            KeyCode::PageDown => "PageDown".to_string(),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::F(n) => format!("F{}", n),
+            other => format!("{:?}", other),
+        };
+        let mut label = String::new();
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            label.push_str("Ctrl+");
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            label.push_str("Alt+");
+        }
+        label.push_str(&key);
+        label
+    }
+}
+
+impl From<KeyEvent> for KeyPress {
+    fn from(event: KeyEvent) -> Self {
+        Self::new(event.code, event.modifiers)
+    }
+}
+
+fn parse_named_key(name: &str) -> Option<KeyCode> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(c));
+    }
+    let code = match name.to_ascii_lowercase().as_str() {
+        "enter" | "return" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "space" => KeyCode::Char(' '),
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        f if f.len() > 1 && f.starts_with('f') => KeyCode::F(f[1..].parse().ok()?),
+        _ => return None,
+    };
+    Some(code)
+}
+
+fn parse_token(token: &str) -> Option<Vec<KeyPress>> {
+    if token.len() > 1 && token.contains('+') {
+        let (prefix, key) = token.rsplit_once('+')?;
+        let key = if key.is_empty() { "+" } else { key };
+        let prefix = prefix.strip_suffix('+').unwrap_or(prefix);
+        let mut modifiers = KeyModifiers::NONE;
+        for part in prefix.split('+') {
+            match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
+                "alt" => modifiers |= KeyModifiers::ALT,
+                "shift" => modifiers |= KeyModifiers::SHIFT,
+                _ => return None,
+            }
+        }
+        let mut code = parse_named_key(key)?;
+        if modifiers.contains(KeyModifiers::SHIFT) {
+            if let KeyCode::Char(c) = code {
+                code = KeyCode::Char(c.to_ascii_uppercase());
+            }
+        }
+        return Some(vec![KeyPress::new(code, modifiers)]);
+    }
+    if let Some(code) = parse_named_key(token) {
+        return Some(vec![KeyPress::new(code, KeyModifiers::NONE)]);
+    }
+    Some(
+        token
+            .chars()
+            .map(|c| KeyPress::new(KeyCode::Char(c), KeyModifiers::NONE))
+            .collect(),
+    )
+}
+
+/// Parse a key sequence such as `gg`, `Ctrl+a`, `Enter` or `Ctrl+x e`.
+///
+/// Whitespace separates keys; a token that is not a key name is read as one
+/// key per character.
+pub fn parse_key_sequence(spec: &str) -> Option<Vec<KeyPress>> {
+    let mut keys = Vec::new();
+    for token in spec.split_whitespace() {
+        keys.extend(parse_token(token)?);
+    }
+    (!keys.is_empty()).then_some(keys)
+}
+
+fn sequence_label(keys: &[KeyPress]) -> String {
+    if keys.iter().all(KeyPress::is_plain_char) && !keys.contains(&space()) {
+        keys.iter().map(KeyPress::label).collect()
+    } else {
+        keys.iter()
+            .map(KeyPress::label)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+fn space() -> KeyPress {
+    KeyPress::new(KeyCode::Char(' '), KeyModifiers::NONE)
+}
+
+fn default_sequences(spec: &ActionSpec) -> Vec<Vec<KeyPress>> {
+    spec.keys
+        .iter()
+        .map(|k| parse_key_sequence(k).expect("built-in key sequences parse"))
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+struct ContextKeymap {
+    /// Effective bindings: (action index, sequences) for every action.
+    bindings: Vec<(usize, Vec<Vec<KeyPress>>)>,
+    overridden: Vec<usize>,
+}
+
+impl ContextKeymap {
+    fn find_exact(&self, pending: &[KeyPress]) -> Option<usize> {
+        self.bindings
+            .iter()
+            .find(|(_, seqs)| seqs.iter().any(|s| s == pending))
+            .map(|(idx, _)| *idx)
+    }
+
+    fn is_prefix(&self, pending: &[KeyPress]) -> bool {
+        self.bindings.iter().any(|(_, seqs)| {
+            seqs.iter()
+                .any(|s| s.len() > pending.len() && s.starts_with(pending))
+        })
+    }
+}
+
+/// Resolved keymap plus the keys typed so far of a multi-key sequence.
+#[derive(Debug, Clone, Default)]
+pub struct Keymap {
+    contexts: HashMap<KeymapContext, ContextKeymap>,
+    pending: Vec<KeyPress>,
+}
+
+impl Keymap {
+    /// Build the keymap from the config section, rejecting unknown contexts,
+    /// actions or keys, and sequences bound to more than one action or that
+    /// shadow a longer sequence.
+    pub fn from_config(
+        config: &BTreeMap<String, BTreeMap<String, KeySequences>>,
+    ) -> KanbanResult<Self> {
+        let mut contexts = HashMap::new();
+        for (context_name, overrides) in config {
+            let context = KeymapContext::from_name(context_name).ok_or_else(|| {
+                KanbanError::validation(format!(
+                    "Unknown keymap context '{}': expected one of {}",
+                    context_name,
+                    KeymapContext::ALL.map(KeymapContext::name).join(", ")
+                ))
+            })?;
+            if overrides.is_empty() {
+                continue;
+            }
+            contexts.insert(context, Self::resolve_context(context, overrides)?);
+        }
+        Ok(Self {
+            contexts,
+            pending: Vec::new(),
+        })
+    }
+
+    fn resolve_context(
+        context: KeymapContext,
+        overrides: &BTreeMap<String, KeySequences>,
+    ) -> KanbanResult<ContextKeymap> {
+        let actions = context.actions();
+        let mut overridden = Vec::new();
+        let mut custom: HashMap<usize, Vec<Vec<KeyPress>>> = HashMap::new();
+        for (action_name, sequences) in overrides {
+            let idx = actions
+                .iter()
+                .position(|a| a.name == action_name)
+                .ok_or_else(|| {
+                    KanbanError::validation(format!(
+                        "Unknown action '{}' in keymap.{}",
+                        action_name,
+                        context.name()
+                    ))
+                })?;
+            let parsed = sequences
+                .to_vec()
+                .into_iter()
+                .map(|spec| {
+                    parse_key_sequence(spec).ok_or_else(|| {
+                        KanbanError::validation(format!(
+                            "Invalid key sequence '{}' for keymap.{}.{}",
+                            spec,
+                            context.name(),
+                            action_name
+                        ))
+                    })
+                })
+                .collect::<KanbanResult<Vec<_>>>()?;
+            overridden.push(idx);
+            custom.insert(idx, parsed);
+        }
+
+        let bindings: Vec<(usize, Vec<Vec<KeyPress>>)> = actions
+            .iter()
+            .enumerate()
+            .map(|(idx, spec)| {
+                let seqs = custom
+                    .remove(&idx)
+                    .unwrap_or_else(|| default_sequences(spec));
+                (idx, seqs)
+            })
+            .collect();
+
+        for (i, (a_idx, a_seqs)) in bindings.iter().enumerate() {
+            for (b_idx, b_seqs) in &bindings[i + 1..] {
+                let (a_name, b_name) = (actions[*a_idx].name, actions[*b_idx].name);
+                for a in a_seqs {
+                    for b in b_seqs {
+                        let ((short, short_name), (long, long_name)) = if a.len() <= b.len() {
+                            ((a, a_name), (b, b_name))
+                        } else {
+                            ((b, b_name), (a, a_name))
+                        };
+                        if !long.starts_with(short) {
+                            continue;
+                        }
+                        let message = if a == b {
+                            format!(
+                                "Keymap conflict in keymap.{}: '{}' is bound to both {} and {}",
+                                context.name(),
+                                sequence_label(a),
+                                a_name,
+                                b_name
+                            )
+                        } else {
+                            format!(
+                                "Keymap conflict in keymap.{}: '{}' ({}) is a prefix of '{}' ({})",
+                                context.name(),
+                                sequence_label(short),
+                                short_name,
+                                sequence_label(long),
+                                long_name
+                            )
+                        };
+                        return Err(KanbanError::validation(message));
+                    }
+                }
+            }
+        }
+
+        Ok(ContextKeymap {
+            bindings,
+            overridden,
+        })
+    }
+
+    pub fn is_customized(&self, context: KeymapContext) -> bool {
+        self.contexts.contains_key(&context)
+    }
+
+    /// Translate a key press into the key events the built-in handlers expect.
+    ///
+    /// Returns an empty list while a multi-key sequence is still incomplete or
+    /// when the key was a default that the user rebound elsewhere. With
+    /// `text_input` set, unmodified characters always pass through so dialogs
+    /// can still be typed into.
+    pub fn translate(
+        &mut self,
+        context: Option<KeymapContext>,
+        event: KeyEvent,
+        text_input: bool,
+    ) -> Vec<KeyEvent> {
+        let Some((actions, keymap)) =
+            context.and_then(|c| self.contexts.get(&c).map(|k| (c.actions(), k)))
+        else {
+            self.pending.clear();
+            return vec![event];
+        };
+        let press = KeyPress::from(event);
+        if text_input && press.is_plain_char() {
+            self.pending.clear();
+            return vec![event];
+        }
+
+        self.pending.push(press);
+        loop {
+            if let Some(idx) = keymap.find_exact(&self.pending) {
+                self.pending.clear();
+                return default_sequences(&actions[idx])[0]
+                    .iter()
+                    .map(|k| k.to_event())
+                    .collect();
+            }
+            if keymap.is_prefix(&self.pending) {
+                return Vec::new();
+            }
+            if self.pending.len() > 1 {
+                self.pending = vec![press];
+                continue;
+            }
+            self.pending.clear();
+            let rebound = keymap.overridden.iter().any(|idx| {
+                default_sequences(&actions[*idx])
+                    .iter()
+                    .any(|seq| seq[0] == press)
+            });
+            return if rebound { Vec::new() } else { vec![event] };
+        }
+    }
+
+    /// Help-popup labels to replace for a context: built-in label -> the
+    /// user's keys for the same action.
+    pub fn help_labels(&self, context: KeymapContext) -> HashMap<&'static str, String> {
+        let Some(keymap) = self.contexts.get(&context) else {
+            return HashMap::new();
+        };
+        let actions = context.actions();
+        keymap
+            .overridden
+            .iter()
+            .map(|idx| {
+                let seqs = &keymap
+                    .bindings
+                    .iter()
+                    .find(|(i, _)| i == idx)
+                    .expect("every action has bindings")
+                    .1;
+                let label = seqs
+                    .iter()
+                    .map(|s| sequence_label(s))
+                    .collect::<Vec<_>>()
+                    .join("/");
+                (actions[*idx].help_key, label)
+            })
+            .collect()
+    }
+}
+
+/// Rewrite a help-popup key label with user bindings. Combined labels such as
+/// `q/Esc` are rewritten part by part.
+pub fn relabel(label: &str, labels: &HashMap<&'static str, String>) -> Option<String> {
+    if let Some(new) = labels.get(label) {
+        return Some(new.clone());
+    }
+    if !label.contains('/') || label == "/" {
+        return None;
+    }
+    let parts: Vec<&str> = label.split('/').collect();
+    if !parts.iter().any(|p| labels.contains_key(p)) {
+        return None;
+    }
+    Some(
+        parts
+            .iter()
+            .map(|p| labels.get(p).cloned().unwrap_or_else(|| p.to_string()))
+            .collect::<Vec<_>>()
+            .join("/"),
+    )
+}
+
+/// Dialogs whose keys are typed into a text field.
+pub fn is_text_input_dialog(dialog: &DialogMode) -> bool {
+    matches!(
+        dialog,
+        DialogMode::CreateBoard
+            | DialogMode::CreateCard
+            | DialogMode::CreateSprint
+            | DialogMode::RenameBoard
+            | DialogMode::CloneBoard
+            | DialogMode::ExportBoard
+            | DialogMode::ExportAll
+            | DialogMode::SetCardPoints
+            | DialogMode::AddAttachment
+            | DialogMode::SetBranchPrefix
+            | DialogMode::CreateColumn
+            | DialogMode::RenameColumn
+            | DialogMode::SetSprintPrefix
+            | DialogMode::SetSprintCardPrefix
+            | DialogMode::ChooseStorageFile
+            | DialogMode::CommandPalette
+            | DialogMode::EditText
+    )
+}
+
+/// Key events for the first key in a help label such as `"j/↓"`, `"gg"` or
+/// `"Ctrl+a"`. Labels that describe rather than name a key (`"Type"`) yield
+/// `None`.
+pub fn key_events_for_label(label: &str) -> Option<Vec<KeyEvent>> {
+    let first = if label == "/" {
+        label
+    } else {
+        label.split('/').next()?.trim()
+    };
+    let spec = match first {
+        "↓" => "Down",
+        "↑" => "Up",
+        "←" => "Left",
+        "→" => "Right",
+        other => other,
+    };
+    let is_key_name =
+        spec.chars().count() <= 2 || spec.contains('+') || parse_named_key(spec).is_some();
+    if !is_key_name {
+        return None;
+    }
+    parse_key_sequence(spec).map(|keys| keys.into_iter().map(KeyPress::to_event).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)
+    }
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    fn keymap(context: &str, bindings: &[(&str, &str)]) -> KanbanResult<Keymap> {
+        let section = bindings
+            .iter()
+            .map(|(action, keys)| (action.to_string(), KeySequences::One(keys.to_string())))
+            .collect();
+        Keymap::from_config(&BTreeMap::from([(context.to_string(), section)]))
+    }
+
+    #[test]
+    fn test_parse_key_sequence() {
+        let g = KeyPress::new(KeyCode::Char('g'), KeyModifiers::NONE);
+        assert_eq!(parse_key_sequence("gg"), Some(vec![g, g]));
+        assert_eq!(
+            parse_key_sequence("Ctrl+a"),
+            Some(vec![KeyPress::new(
+                KeyCode::Char('a'),
+                KeyModifiers::CONTROL
+            )])
+        );
+        assert_eq!(
+            parse_key_sequence("Enter"),
+            Some(vec![KeyPress::new(KeyCode::Enter, KeyModifiers::NONE)])
+        );
+        assert_eq!(parse_key_sequence("Ctrl+x e").map(|k| k.len()), Some(2));
+        assert_eq!(parse_key_sequence("Hyper+a"), None);
+        assert_eq!(parse_key_sequence(""), None);
+    }
+
+    #[test]
+    fn test_builtin_bindings_have_no_conflicts() {
+        for context in KeymapContext::ALL {
+            Keymap::resolve_context(context, &BTreeMap::new()).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_duplicate_binding_is_rejected() {
+        let err = keymap("card_list", &[("archive_card", "x")]).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("archive_card"), "{message}");
+        assert!(message.contains("export_board"), "{message}");
+    }
+
+    #[test]
+    fn test_prefix_binding_is_rejected() {
+        let err = keymap("card_list", &[("open_settings", "aa")]).unwrap_err();
+        assert!(err.to_string().contains("prefix"));
+    }
+
+    #[test]
+    fn test_unknown_context_and_action_are_rejected() {
+        assert!(keymap("nowhere", &[("quit", "Q")])
+            .unwrap_err()
+            .to_string()
+            .contains("nowhere"));
+        assert!(keymap("card_list", &[("fly", "Q")])
+            .unwrap_err()
+            .to_string()
+            .contains("fly"));
+    }
+
+    #[test]
+    fn test_rebound_action_emits_default_keys_and_swallows_old_key() {
+        let mut keymap = keymap("card_list", &[("jump_to_top", "Ctrl+g")]).unwrap();
+        let context = Some(KeymapContext::CardList);
+        assert_eq!(
+            keymap.translate(context, ctrl('g'), false),
+            vec![key('g'), key('g')]
+        );
+        assert!(keymap.translate(context, key('g'), false).is_empty());
+        assert_eq!(keymap.translate(context, key('j'), false), vec![key('j')]);
+    }
+
+    #[test]
+    fn test_multi_key_sequence_waits_then_falls_back() {
+        let mut keymap = keymap("card_list", &[("open_settings", "zz")]).unwrap();
+        let context = Some(KeymapContext::CardList);
+        assert!(keymap.translate(context, key('z'), false).is_empty());
+        assert_eq!(keymap.translate(context, key('z'), false), vec![key('S')]);
+
+        assert!(keymap.translate(context, key('z'), false).is_empty());
+        assert_eq!(keymap.translate(context, key('j'), false), vec![key('j')]);
+    }
+
+    #[test]
+    fn test_text_input_passes_characters_through() {
+        let mut keymap = keymap("dialogs", &[("confirm", "Ctrl+s")]).unwrap();
+        let context = Some(KeymapContext::Dialogs);
+        assert_eq!(keymap.translate(context, key('x'), true), vec![key('x')]);
+        assert_eq!(
+            keymap.translate(context, ctrl('s'), true),
+            vec![KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)]
+        );
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        assert!(keymap.translate(context, enter, true).is_empty());
+    }
+
+    #[test]
+    fn test_uncustomized_context_is_untouched() {
+        let mut keymap = keymap("card_list", &[("quit", "Q")]).unwrap();
+        assert_eq!(
+            keymap.translate(Some(KeymapContext::Normal), key('q'), false),
+            vec![key('q')]
+        );
+    }
+
+    #[test]
+    fn test_help_labels_reflect_overrides() {
+        let keymap = keymap("settings", &[("back", "Backspace")]).unwrap();
+        let labels = keymap.help_labels(KeymapContext::Settings);
+        assert_eq!(relabel("Esc", &labels).as_deref(), Some("Backspace"));
+        assert_eq!(relabel("q/Esc", &labels).as_deref(), Some("q/Backspace"));
+        assert_eq!(relabel("j/k", &labels), None);
+
+        let keymap = keymap_with_list();
+        let labels = keymap.help_labels(KeymapContext::CardList);
+        assert_eq!(relabel("j/↓", &labels).as_deref(), Some("n/Ctrl+n"));
+    }
+
+    fn keymap_with_list() -> Keymap {
+        let section = BTreeMap::from([
+            (
+                "navigate_down".to_string(),
+                KeySequences::Many(vec!["n".into(), "Ctrl+n".into()]),
+            ),
+            ("create_card".to_string(), KeySequences::One("a".into())),
+            ("assign_sprint".to_string(), KeySequences::One("A".into())),
+        ]);
+        Keymap::from_config(&BTreeMap::from([("card_list".to_string(), section)])).unwrap()
+    }
+}
@@ -2,11 +2,13 @@ pub mod board_detail;
 pub mod card_detail;
 pub mod card_list;
 pub mod dialog_modes;
+pub mod keymap;
 pub mod normal_mode;
 pub mod registry;
 pub mod settings;
 pub mod sprint_detail;
 
+pub use keymap::{Keymap, KeymapContext};
 pub use registry::KeybindingRegistry;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -21,6 +23,7 @@ pub enum KeybindingAction {
     CreateSprint,
     CreateColumn,
     RenameBoard,
+    CloneBoard,
     RenameColumn,
     EditCard,
     EditBoard,
@@ -62,6 +65,11 @@ pub enum KeybindingAction {
     Redo,
     OpenSettings,
     ExportBoards,
+    CommandPalette,
+    TogglePreview,
+    FocusPreview,
+    ShrinkPreview,
+    GrowPreview,
 }
 
 #[derive(Debug, Clone)]
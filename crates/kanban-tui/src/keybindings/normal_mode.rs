@@ -8,6 +8,12 @@ impl KeybindingProvider for NormalModeBoardsProvider {
             "Normal Mode - Projects Panel",
             vec![
                 Keybinding::new("?", "help", "Show help", KeybindingAction::ShowHelp),
+                Keybinding::new(
+                    ":",
+                    "commands",
+                    "Open command palette",
+                    KeybindingAction::CommandPalette,
+                ),
                 Keybinding::new("q", "quit", "Quit application", KeybindingAction::Escape),
                 Keybinding::new(
                     "1",
@@ -33,6 +39,12 @@ impl KeybindingProvider for NormalModeBoardsProvider {
                     "Rename selected project",
                     KeybindingAction::RenameBoard,
                 ),
+                Keybinding::new(
+                    "c",
+                    "clone",
+                    "Clone selected project",
+                    KeybindingAction::CloneBoard,
+                ),
                 Keybinding::new(
                     "e",
                     "edit",
@@ -103,6 +115,12 @@ impl KeybindingProvider for ArchivedCardsViewProvider {
             "Archived Cards View",
             vec![
                 Keybinding::new("?", "help", "Show help", KeybindingAction::ShowHelp),
+                Keybinding::new(
+                    ":",
+                    "commands",
+                    "Open command palette",
+                    KeybindingAction::CommandPalette,
+                ),
                 Keybinding::new(
                     "j/↓",
                     "down",
@@ -3,13 +3,15 @@ use super::{
     card_detail::CardDetailProvider,
     card_list::CardListProvider,
     dialog_modes::{
-        DeleteConfirmProvider, DialogInputProvider, DialogSelectionProvider, ErrorLogProvider,
-        FilterOptionsProvider, SearchModeProvider,
+        CommandPaletteProvider, DeleteConfirmProvider, DialogInputProvider,
+        DialogSelectionProvider, ErrorLogProvider, FilterOptionsProvider, SearchModeProvider,
+        TextEditorProvider,
     },
+    keymap::{relabel, KeymapContext},
     normal_mode::{ArchivedCardsViewProvider, NormalModeBoardsProvider},
     settings::SettingsViewProvider,
     sprint_detail::SprintDetailProvider,
-    KeybindingProvider,
+    KeybindingContext, KeybindingProvider,
 };
 use crate::app::{App, AppMode, DialogMode, Focus, SettingsFocus};
 
@@ -17,12 +19,31 @@ pub struct KeybindingRegistry;
 
 impl KeybindingRegistry {
     pub fn get_provider(app: &App) -> Box<dyn KeybindingProvider> {
-        Self::get_provider_for_mode(
+        let provider = Self::get_provider_for_mode(
             &app.mode,
             app.focus.active.clone(),
             app.focus.card_focus,
             app.focus.board_focus,
             app.focus.settings_focus,
+        );
+        match KeymapContext::for_mode(&app.mode, &app.focus.active) {
+            Some(context) if app.keymap.is_customized(context) => Box::new(KeymapProvider {
+                inner: provider,
+                labels: app.keymap.help_labels(context),
+            }),
+            _ => provider,
+        }
+    }
+
+    /// The provider for `mode` with the built-in key labels, ignoring any
+    /// keymap overrides.
+    pub fn get_default_provider(app: &App, mode: &AppMode) -> Box<dyn KeybindingProvider> {
+        Self::get_provider_for_mode(
+            mode,
+            app.focus.active.clone(),
+            app.focus.card_focus,
+            app.focus.board_focus,
+            app.focus.settings_focus,
         )
     }
 
@@ -56,11 +77,13 @@ impl KeybindingRegistry {
                 DialogMode::CreateCard => Box::new(DialogInputProvider::new("Create Task")),
                 DialogMode::CreateSprint => Box::new(DialogInputProvider::new("Create Sprint")),
                 DialogMode::RenameBoard => Box::new(DialogInputProvider::new("Rename Project")),
+                DialogMode::CloneBoard => Box::new(DialogInputProvider::new("Clone Project")),
                 DialogMode::RenameColumn => Box::new(DialogInputProvider::new("Rename Column")),
                 DialogMode::CreateColumn => Box::new(DialogInputProvider::new("Create Column")),
                 DialogMode::ExportBoard => Box::new(DialogInputProvider::new("Export Project")),
                 DialogMode::ExportAll => Box::new(DialogInputProvider::new("Export All Projects")),
                 DialogMode::SetCardPoints => Box::new(DialogInputProvider::new("Set Points")),
+                DialogMode::AddAttachment => Box::new(DialogInputProvider::new("Attach File")),
                 DialogMode::SetBranchPrefix => {
                     Box::new(DialogInputProvider::new("Set Branch Prefix"))
                 }
@@ -109,8 +132,28 @@ impl KeybindingRegistry {
                 DialogMode::ChooseStorageFile => {
                     Box::new(DialogInputProvider::new("Choose Storage File"))
                 }
+                DialogMode::CommandPalette => Box::new(CommandPaletteProvider),
+                DialogMode::EditText => Box::new(TextEditorProvider),
             },
             AppMode::ErrorLog => Box::new(ErrorLogProvider),
         }
     }
 }
+
+/// Shows the user's keymap overrides in place of the built-in key labels.
+struct KeymapProvider {
+    inner: Box<dyn KeybindingProvider>,
+    labels: std::collections::HashMap<&'static str, String>,
+}
+
+impl KeybindingProvider for KeymapProvider {
+    fn get_context(&self) -> KeybindingContext {
+        let mut context = self.inner.get_context();
+        for binding in &mut context.bindings {
+            if let Some(key) = relabel(&binding.key, &self.labels) {
+                binding.key = key;
+            }
+        }
+        context
+    }
+}
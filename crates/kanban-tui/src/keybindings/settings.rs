@@ -32,6 +32,12 @@ impl KeybindingProvider for SettingsViewProvider {
                 "Focus Storage",
                 KeybindingAction::FocusPanel(2),
             ),
+            Keybinding::new(
+                "4",
+                "appearance",
+                "Focus Appearance",
+                KeybindingAction::FocusPanel(3),
+            ),
             Keybinding::new(
                 "j/k",
                 "navigate",
@@ -63,9 +69,23 @@ impl KeybindingProvider for SettingsViewProvider {
                     KeybindingAction::SelectItem,
                 ));
             }
+            SettingsFocus::Appearance => {
+                bindings.push(Keybinding::new(
+                    "Enter",
+                    "theme",
+                    "Switch to the next color theme",
+                    KeybindingAction::SelectItem,
+                ));
+            }
             _ => {}
         }
 
+        bindings.push(Keybinding::new(
+            ":",
+            "commands",
+            "Open command palette",
+            KeybindingAction::CommandPalette,
+        ));
         bindings.push(Keybinding::new(
             "x",
             "export",
@@ -8,6 +8,12 @@ impl KeybindingProvider for SprintDetailProvider {
             "Sprint Detail",
             vec![
                 Keybinding::new("?", "help", "Show help", KeybindingAction::ShowHelp),
+                Keybinding::new(
+                    ":",
+                    "commands",
+                    "Open command palette",
+                    KeybindingAction::CommandPalette,
+                ),
                 Keybinding::new(
                     "q",
                     "quit",
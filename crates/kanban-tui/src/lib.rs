@@ -2,6 +2,7 @@ pub mod app;
 pub mod card_list;
 pub mod card_list_component;
 pub mod clipboard;
+pub mod command_palette;
 pub mod components;
 pub mod dialog;
 pub mod edit_format;
@@ -10,6 +11,7 @@ pub mod error_log;
 pub mod events;
 pub mod filters;
 pub mod handlers;
+pub mod hit_test;
 pub mod keybindings;
 pub mod layout_strategy;
 pub mod markdown_renderer;
@@ -19,6 +21,7 @@ pub mod search;
 pub mod state;
 #[cfg(test)]
 pub(crate) mod test_helpers;
+pub mod text_editor;
 pub mod theme;
 pub mod tui_context;
 pub mod ui;
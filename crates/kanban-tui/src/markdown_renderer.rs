@@ -1,11 +1,85 @@
-use pulldown_cmark::{CowStr, Event, Parser, Tag, TagEnd};
-use ratatui::prelude::Stylize;
-use ratatui::style::Style;
+//! Renders card and board descriptions written in markdown.
+//!
+//! Block structure (headings, lists and task items, quotes, tables and
+//! fenced code) is laid out here rather than by the widget, so wrapped
+//! lines keep their list and quote indentation. Links come back next to
+//! the lines: [`write_hyperlinks`] turns URLs into OSC-8 terminal
+//! hyperlinks once the paragraph is drawn, and card references are left
+//! for the caller to follow.
+
+use crate::theme::*;
+use kanban_domain::find_identifier_mentions;
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
+use std::ops::Range;
+use unicode_width::UnicodeWidthStr;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkTarget {
+    Url(String),
+    /// A card identifier such as `KAN-123` that resolved to this card.
+    Card(Uuid),
+}
+
+/// A link drawn on `line`, covering `width` cells from `column`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownLink {
+    pub line: usize,
+    pub column: usize,
+    pub width: usize,
+    pub target: LinkTarget,
+}
+
+pub struct RenderedMarkdown {
+    pub lines: Vec<Line<'static>>,
+    pub links: Vec<MarkdownLink>,
+}
+
+impl RenderedMarkdown {
+    pub fn plain(lines: Vec<Line<'static>>) -> Self {
+        Self {
+            lines,
+            links: Vec::new(),
+        }
+    }
+
+    /// The link covering the cell at `column` of `line`.
+    pub fn link_at(&self, line: usize, column: usize) -> Option<&LinkTarget> {
+        self.links
+            .iter()
+            .find(|l| l.line == line && (l.column..l.column + l.width).contains(&column))
+            .map(|l| &l.target)
+    }
+}
+
+/// Maps a card identifier such as `KAN-123` to the card it names.
+pub type CardResolver<'a> = dyn Fn(&str) -> Option<Uuid> + 'a;
+
+#[derive(Default)]
+pub struct MarkdownOptions<'a> {
+    /// Wrap lines to this many cells. `None` leaves long lines to the widget.
+    pub width: Option<usize>,
+    /// Resolves a card identifier to the card it names; identifiers that
+    /// resolve are highlighted and returned as links.
+    pub resolve_card: Option<&'a CardResolver<'a>>,
+    /// References to this card are drawn reversed.
+    pub selected_card: Option<Uuid>,
+}
 
 pub fn render_markdown(text: &str) -> Vec<Line<'static>> {
-    let parser = Parser::new(text);
-    let mut renderer = MarkdownRenderer::new();
+    render_markdown_with(text, &MarkdownOptions::default()).lines
+}
+
+pub fn render_markdown_with(text: &str, options: &MarkdownOptions) -> RenderedMarkdown {
+    let parser = Parser::new_ext(
+        text,
+        Options::ENABLE_TABLES | Options::ENABLE_TASKLISTS | Options::ENABLE_STRIKETHROUGH,
+    );
+    let mut renderer = MarkdownRenderer::new(options);
 
     for event in parser {
         renderer.process_event(event);
@@ -14,26 +88,137 @@ pub fn render_markdown(text: &str) -> Vec<Line<'static>> {
     renderer.finish()
 }
 
-struct MarkdownRenderer {
+/// Wraps the cells of each URL link in an OSC-8 hyperlink, so terminals
+/// that support them open the URL on click. `area` is where the rendered
+/// lines were drawn, without scrolling.
+///
+/// `Buffer::diff` takes the width of a cell's symbol, escape sequence
+/// included, as the number of columns it covers, and drops the cell after
+/// a wide one. So the link is written in chunks of at least two columns:
+/// the first cell of a chunk carries the whole chunk and the rest are
+/// marked as skipped.
+pub fn write_hyperlinks(buffer: &mut Buffer, area: Rect, links: &[MarkdownLink]) {
+    for link in links {
+        let LinkTarget::Url(url) = &link.target else {
+            continue;
+        };
+        if link.line >= area.height as usize || link.column >= area.width as usize {
+            continue;
+        }
+        // A URL must not be able to end the sequence early.
+        let url: String = url.chars().filter(|c| !c.is_control()).collect();
+        let y = area.y + link.line as u16;
+        let start = area.x + link.column as u16;
+        let end = area.x + (link.column + link.width).min(area.width as usize) as u16;
+
+        for (x, columns) in hyperlink_chunks(buffer, start, end, y) {
+            let text: String = (x..x + columns)
+                .map(|column| buffer[(column, y)].symbol().to_string())
+                .collect();
+            let mut symbol = format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text);
+            let mut covered = columns;
+            // A lone column would hide the cell after it, so that cell is
+            // drawn by this one, outside the link.
+            if columns == 1 {
+                symbol.push_str(buffer[(x + 1, y)].symbol());
+                covered = 2;
+            }
+            buffer[(x, y)].set_symbol(&symbol);
+            for column in x + 1..x + covered {
+                buffer[(column, y)].set_skip(true);
+            }
+        }
+    }
+}
+
+/// Splits the columns `start..end` of row `y` into `(x, columns)` chunks
+/// of at least two columns each, never starting a chunk on the trailing
+/// half of a wide character. A single column is only returned when the
+/// buffer has another column after it on the row.
+fn hyperlink_chunks(buffer: &Buffer, start: u16, end: u16, y: u16) -> Vec<(u16, u16)> {
+    let mut chunks: Vec<(u16, u16)> = Vec::new();
+    let mut x = start;
+    while x < end {
+        let mut columns = 1;
+        while x + columns < end && (columns < 2 || buffer[(x + columns, y)].symbol().is_empty()) {
+            columns += 1;
+        }
+        chunks.push((x, columns));
+        x += columns;
+    }
+    if let [.., previous, (_, 1)] = chunks.as_mut_slice() {
+        previous.1 += 1;
+        chunks.pop();
+    }
+    if let [(x, 1)] = chunks.as_slice() {
+        if x + 1 >= buffer.area.right() {
+            chunks.clear();
+        }
+    }
+    chunks
+}
+
+struct Segment {
+    text: String,
+    style: Style,
+    link: Option<LinkTarget>,
+}
+
+enum Container {
+    Quote,
+    /// `next` numbers the items of an ordered list.
+    List {
+        next: Option<u64>,
+    },
+    /// A list item. Its marker is drawn on the first line and blanked on
+    /// the lines after it.
+    Item {
+        marker: String,
+        marker_style: Style,
+        started: bool,
+    },
+}
+
+struct Table {
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<String>>,
+    header_rows: usize,
+}
+
+struct MarkdownRenderer<'a> {
+    options: &'a MarkdownOptions<'a>,
     lines: Vec<Line<'static>>,
-    current_line: Vec<Span<'static>>,
-    in_code_block: bool,
-    code_block_lang: String,
-    code_block_content: String,
-    in_emphasis: bool,
-    in_strong: bool,
+    links: Vec<MarkdownLink>,
+    containers: Vec<Container>,
+    /// Inline content of the current block, split at line breaks.
+    block: Vec<Vec<Segment>>,
+    /// A blank line is due before the next block.
+    gap: bool,
+    heading: Option<HeadingLevel>,
+    emphasis: usize,
+    strong: usize,
+    strikethrough: usize,
+    link: Option<String>,
+    code_block: Option<(String, String)>,
+    table: Option<Table>,
 }
 
-impl MarkdownRenderer {
-    fn new() -> Self {
+impl<'a> MarkdownRenderer<'a> {
+    fn new(options: &'a MarkdownOptions<'a>) -> Self {
         Self {
+            options,
             lines: Vec::new(),
-            current_line: Vec::new(),
-            in_code_block: false,
-            code_block_lang: String::new(),
-            code_block_content: String::new(),
-            in_emphasis: false,
-            in_strong: false,
+            links: Vec::new(),
+            containers: Vec::new(),
+            block: Vec::new(),
+            gap: false,
+            heading: None,
+            emphasis: 0,
+            strong: 0,
+            strikethrough: 0,
+            link: None,
+            code_block: None,
+            table: None,
         }
     }
 
@@ -41,122 +226,862 @@ impl MarkdownRenderer {
         match event {
             Event::Start(tag) => self.handle_tag_start(tag),
             Event::End(tag_end) => self.handle_tag_end(tag_end),
-            Event::Text(text) => self.handle_text(text),
-            Event::Code(code) => self.handle_inline_code(code),
+            Event::Text(text) => self.handle_text(&text),
+            Event::Code(code) => self.handle_inline_code(&code),
             Event::SoftBreak | Event::HardBreak => self.handle_break(),
+            Event::TaskListMarker(checked) => self.handle_task_marker(checked),
+            Event::Rule => self.handle_rule(),
             _ => {}
         }
     }
 
     fn handle_tag_start(&mut self, tag: Tag) {
         match tag {
+            Tag::Paragraph => self.begin_block(),
+            Tag::Heading { level, .. } => {
+                self.begin_block();
+                self.heading = Some(level);
+            }
+            Tag::BlockQuote(_) => {
+                self.begin_block();
+                self.containers.push(Container::Quote);
+            }
+            Tag::List(start) => {
+                self.begin_block();
+                self.containers.push(Container::List { next: start });
+            }
+            Tag::Item => {
+                self.flush_block();
+                let marker = match self.containers.last_mut() {
+                    Some(Container::List { next: Some(n) }) => {
+                        *n += 1;
+                        format!("{}. ", *n - 1)
+                    }
+                    _ => "• ".to_string(),
+                };
+                self.containers.push(Container::Item {
+                    marker,
+                    marker_style: highlight_text(),
+                    started: false,
+                });
+            }
             Tag::CodeBlock(kind) => {
-                self.in_code_block = true;
-                self.code_block_lang = match kind {
-                    pulldown_cmark::CodeBlockKind::Fenced(lang) => lang.to_string(),
-                    pulldown_cmark::CodeBlockKind::Indented => String::new(),
+                self.begin_block();
+                let language = match kind {
+                    CodeBlockKind::Fenced(lang) => lang.to_string(),
+                    CodeBlockKind::Indented => String::new(),
                 };
+                self.code_block = Some((language, String::new()));
             }
-            Tag::Emphasis => {
-                self.in_emphasis = true;
+            Tag::Table(alignments) => {
+                self.begin_block();
+                self.table = Some(Table {
+                    alignments,
+                    rows: Vec::new(),
+                    header_rows: 0,
+                });
             }
-            Tag::Strong => {
-                self.in_strong = true;
+            Tag::TableHead | Tag::TableRow => {
+                if let Some(table) = &mut self.table {
+                    table.rows.push(Vec::new());
+                }
             }
-            Tag::Paragraph | Tag::Heading { .. } => {
-                if !self.current_line.is_empty() {
-                    self.flush_line();
+            Tag::TableCell => {
+                if let Some(row) = self.table.as_mut().and_then(|t| t.rows.last_mut()) {
+                    row.push(String::new());
                 }
             }
-            Tag::List(_) => {}
-            Tag::Item => {}
+            Tag::Emphasis => self.emphasis += 1,
+            Tag::Strong => self.strong += 1,
+            Tag::Strikethrough => self.strikethrough += 1,
+            Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. } => {
+                self.link = Some(dest_url.to_string());
+            }
             _ => {}
         }
     }
 
     fn handle_tag_end(&mut self, tag: TagEnd) {
         match tag {
-            TagEnd::CodeBlock => {
-                self.in_code_block = false;
-                self.render_code_block();
+            TagEnd::Paragraph => self.end_block(),
+            TagEnd::Heading(_) => {
+                self.end_block();
+                self.heading = None;
             }
-            TagEnd::Emphasis => {
-                self.in_emphasis = false;
+            TagEnd::BlockQuote(_) | TagEnd::List(_) => {
+                self.end_block();
+                self.containers.pop();
             }
-            TagEnd::Strong => {
-                self.in_strong = false;
+            TagEnd::Item => {
+                self.flush_block();
+                if let Some(Container::Item { started: false, .. }) = self.containers.last() {
+                    self.emit_line(Vec::new());
+                }
+                self.containers.pop();
             }
-            TagEnd::Paragraph | TagEnd::Heading(_) | TagEnd::Item => {
-                if !self.current_line.is_empty() {
-                    self.flush_line();
+            TagEnd::CodeBlock => {
+                if let Some((language, content)) = self.code_block.take() {
+                    self.render_code_block(&language, &content);
                 }
-                self.lines.push(Line::from(""));
+                self.gap = true;
             }
+            TagEnd::TableHead => {
+                if let Some(table) = &mut self.table {
+                    table.header_rows = table.rows.len();
+                }
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    self.render_table(&table);
+                }
+                self.gap = true;
+            }
+            TagEnd::Emphasis => self.emphasis = self.emphasis.saturating_sub(1),
+            TagEnd::Strong => self.strong = self.strong.saturating_sub(1),
+            TagEnd::Strikethrough => self.strikethrough = self.strikethrough.saturating_sub(1),
+            TagEnd::Link | TagEnd::Image => self.link = None,
             _ => {}
         }
     }
 
-    fn handle_text(&mut self, text: CowStr) {
-        if self.in_code_block {
-            self.code_block_content.push_str(&text);
-        } else {
-            let mut style = Style::default();
-            if self.in_strong {
-                style = style.bold();
+    fn handle_text(&mut self, text: &str) {
+        if let Some((_, content)) = &mut self.code_block {
+            content.push_str(text);
+            return;
+        }
+        if let Some(cell) = self
+            .table
+            .as_mut()
+            .and_then(|t| t.rows.last_mut())
+            .and_then(|row| row.last_mut())
+        {
+            cell.push_str(text);
+            return;
+        }
+
+        let style = self.inline_style();
+        if let Some(url) = self.link.clone() {
+            let style = style.patch(link_style());
+            self.push_segment(text, style, Some(LinkTarget::Url(url)));
+            return;
+        }
+
+        let mut rest = 0;
+        for (range, target) in self.find_links(text) {
+            self.push_segment(&text[rest..range.start], style, None);
+            let link_style = match target {
+                LinkTarget::Url(_) => link_style(),
+                LinkTarget::Card(id) if Some(id) == self.options.selected_card => {
+                    card_reference_style().add_modifier(Modifier::REVERSED)
+                }
+                LinkTarget::Card(_) => card_reference_style(),
+            };
+            self.push_segment(&text[range.clone()], style.patch(link_style), Some(target));
+            rest = range.end;
+        }
+        self.push_segment(&text[rest..], style, None);
+    }
+
+    fn handle_inline_code(&mut self, code: &str) {
+        if let Some(cell) = self
+            .table
+            .as_mut()
+            .and_then(|t| t.rows.last_mut())
+            .and_then(|row| row.last_mut())
+        {
+            cell.push_str(code);
+            return;
+        }
+        let style = self.inline_style().add_modifier(Modifier::ITALIC);
+        self.push_segment(&format!("`{}`", code), style, None);
+    }
+
+    fn handle_break(&mut self) {
+        self.block.push(Vec::new());
+    }
+
+    fn handle_task_marker(&mut self, checked: bool) {
+        if let Some(Container::Item {
+            marker,
+            marker_style,
+            ..
+        }) = self
+            .containers
+            .iter_mut()
+            .rev()
+            .find(|c| matches!(c, Container::Item { .. }))
+        {
+            *marker = if checked { "[x] " } else { "[ ] " }.to_string();
+            *marker_style = if checked { active_item() } else { label_text() };
+        }
+    }
+
+    fn handle_rule(&mut self) {
+        self.begin_block();
+        let width = self
+            .options
+            .width
+            .map_or(40, |w| w.saturating_sub(self.prefix_width()));
+        self.emit_line(vec![Span::styled("─".repeat(width), label_text())]);
+        self.gap = true;
+    }
+
+    fn inline_style(&self) -> Style {
+        let mut style = match self.heading {
+            Some(HeadingLevel::H1) => bold_highlight().add_modifier(Modifier::UNDERLINED),
+            Some(HeadingLevel::H2) => bold_highlight(),
+            Some(_) => Style::default().add_modifier(Modifier::BOLD),
+            None => Style::default(),
+        };
+        if self.strong > 0 {
+            style = style.add_modifier(Modifier::BOLD);
+        }
+        if self.emphasis > 0 {
+            style = style.add_modifier(Modifier::ITALIC);
+        }
+        if self.strikethrough > 0 {
+            style = style.add_modifier(Modifier::CROSSED_OUT);
+        }
+        style
+    }
+
+    /// Bare URLs and card identifiers that resolve, in order.
+    fn find_links(&self, text: &str) -> Vec<(Range<usize>, LinkTarget)> {
+        let urls = find_urls(text);
+        let mut links: Vec<(Range<usize>, LinkTarget)> = urls
+            .iter()
+            .map(|r| (r.clone(), LinkTarget::Url(text[r.clone()].to_string())))
+            .collect();
+        if let Some(resolve) = self.options.resolve_card {
+            for mention in find_identifier_mentions(text) {
+                if urls
+                    .iter()
+                    .any(|u| u.start < mention.end && mention.start < u.end)
+                {
+                    continue;
+                }
+                if let Some(card_id) = resolve(&text[mention.clone()]) {
+                    links.push((mention, LinkTarget::Card(card_id)));
+                }
+            }
+        }
+        links.sort_by_key(|(range, _)| range.start);
+        links
+    }
+
+    fn push_segment(&mut self, text: &str, style: Style, link: Option<LinkTarget>) {
+        if text.is_empty() {
+            return;
+        }
+        if self.block.is_empty() {
+            self.block.push(Vec::new());
+        }
+        if let Some(line) = self.block.last_mut() {
+            line.push(Segment {
+                text: text.to_string(),
+                style,
+                link,
+            });
+        }
+    }
+
+    /// Flushes inline content left from a tight list item and puts a blank
+    /// line before the block if one is due.
+    fn begin_block(&mut self) {
+        self.flush_block();
+        if self.gap && !self.lines.is_empty() {
+            let prefix = self.prefix(false);
+            self.lines.push(Line::from(prefix));
+        }
+        self.gap = false;
+    }
+
+    fn end_block(&mut self) {
+        self.flush_block();
+        self.gap = true;
+    }
+
+    /// Lays out the inline content of the current block, wrapping each
+    /// line to the width left after the list and quote prefix.
+    fn flush_block(&mut self) {
+        let block = std::mem::take(&mut self.block);
+        let available = self
+            .options
+            .width
+            .map(|w| w.saturating_sub(self.prefix_width()).max(1));
+        for line in block {
+            for row in wrap_segments(line, available) {
+                self.emit_segments(row);
             }
-            if self.in_emphasis {
-                style = style.italic();
+        }
+    }
+
+    fn emit_segments(&mut self, row: Vec<Segment>) {
+        let line = self.lines.len();
+        let mut column = self.prefix_width();
+        let mut spans = Vec::with_capacity(row.len());
+        for segment in row {
+            let width = segment.text.width();
+            if let Some(target) = segment.link {
+                match self.links.last_mut() {
+                    Some(last)
+                        if last.line == line
+                            && last.column + last.width == column
+                            && last.target == target =>
+                    {
+                        last.width += width;
+                    }
+                    _ => self.links.push(MarkdownLink {
+                        line,
+                        column,
+                        width,
+                        target,
+                    }),
+                }
             }
+            column += width;
+            spans.push(Span::styled(segment.text, segment.style));
+        }
+        self.emit_line(spans);
+    }
+
+    fn emit_line(&mut self, spans: Vec<Span<'static>>) {
+        let mut line = self.prefix(true);
+        line.extend(spans);
+        self.lines.push(Line::from(line));
+    }
 
-            let text_str = text.to_string();
-            self.current_line.push(Span::styled(text_str, style));
+    /// Quote bars and list markers for the next line. `first` draws the
+    /// marker of an item that has not been drawn yet; otherwise markers
+    /// become indentation.
+    fn prefix(&mut self, first: bool) -> Vec<Span<'static>> {
+        let mut spans = Vec::new();
+        for container in &mut self.containers {
+            match container {
+                Container::Quote => spans.push(Span::styled("│ ", label_text())),
+                Container::List { .. } => {}
+                Container::Item {
+                    marker,
+                    marker_style,
+                    started,
+                } => {
+                    if first && !*started {
+                        *started = true;
+                        spans.push(Span::styled(marker.clone(), *marker_style));
+                    } else {
+                        spans.push(Span::raw(" ".repeat(marker.width())));
+                    }
+                }
+            }
         }
+        spans
     }
 
-    fn handle_inline_code(&mut self, code: CowStr) {
-        let style = Style::default().italic();
-        self.current_line
-            .push(Span::styled(format!("`{}`", code), style));
+    fn prefix_width(&self) -> usize {
+        self.containers
+            .iter()
+            .map(|c| match c {
+                Container::Quote => 2,
+                Container::List { .. } => 0,
+                Container::Item { marker, .. } => marker.width(),
+            })
+            .sum()
     }
 
-    fn handle_break(&mut self) {
-        self.flush_line();
+    fn render_code_block(&mut self, language: &str, content: &str) {
+        for line_spans in highlight_code(language, content) {
+            let mut spans = vec![Span::raw("  ")];
+            spans.extend(line_spans);
+            self.emit_line(spans);
+        }
     }
 
-    fn render_code_block(&mut self) {
-        if self.code_block_content.is_empty() {
+    fn render_table(&mut self, table: &Table) {
+        let columns = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+        if columns == 0 {
             return;
         }
+        let mut widths: Vec<usize> = (0..columns)
+            .map(|i| {
+                table
+                    .rows
+                    .iter()
+                    .filter_map(|row| row.get(i))
+                    .map(|cell| cell.width())
+                    .max()
+                    .unwrap_or(0)
+                    .max(1)
+            })
+            .collect();
+        if let Some(width) = self.options.width {
+            let available = width.saturating_sub(self.prefix_width() + 3 * (columns - 1));
+            while widths.iter().sum::<usize>() > available {
+                let Some(widest) = widths.iter_mut().filter(|w| **w > 3).max_by_key(|w| **w) else {
+                    break;
+                };
+                *widest -= 1;
+            }
+        }
+
+        for (i, row) in table.rows.iter().enumerate() {
+            let header = i < table.header_rows;
+            let mut spans = Vec::new();
+            for (column, width) in widths.iter().enumerate() {
+                if column > 0 {
+                    spans.push(Span::styled(" │ ", label_text()));
+                }
+                let cell = row.get(column).map(String::as_str).unwrap_or("");
+                let alignment = table
+                    .alignments
+                    .get(column)
+                    .copied()
+                    .unwrap_or(Alignment::None);
+                let style = if header {
+                    Style::default().add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                spans.push(Span::styled(pad_cell(cell, *width, alignment), style));
+            }
+            self.emit_line(spans);
+            if i + 1 == table.header_rows {
+                let rule = widths
+                    .iter()
+                    .map(|w| "─".repeat(*w))
+                    .collect::<Vec<_>>()
+                    .join("─┼─");
+                self.emit_line(vec![Span::styled(rule, label_text())]);
+            }
+        }
+    }
+
+    fn finish(mut self) -> RenderedMarkdown {
+        self.flush_block();
+        RenderedMarkdown {
+            lines: self.lines,
+            links: self.links,
+        }
+    }
+}
 
-        self.lines.push(Line::from(""));
+fn link_style() -> Style {
+    Style::default()
+        .fg(current().accent)
+        .add_modifier(Modifier::UNDERLINED)
+}
+
+fn card_reference_style() -> Style {
+    Style::default()
+        .fg(current().accent)
+        .add_modifier(Modifier::BOLD)
+}
 
-        let highlighted_lines = highlight_code(&self.code_block_lang, &self.code_block_content);
+/// Byte ranges of `http://` and `https://` URLs in `text`, without
+/// trailing punctuation.
+fn find_urls(text: &str) -> Vec<Range<usize>> {
+    let mut urls = Vec::new();
+    let mut rest = 0;
+    while let Some(offset) = ["https://", "http://"]
+        .iter()
+        .filter_map(|scheme| text[rest..].find(scheme))
+        .min()
+    {
+        let start = rest + offset;
+        let len = text[start..]
+            .find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"'))
+            .unwrap_or(text.len() - start);
+        let url = text[start..start + len].trim_end_matches(['.', ',', ';', ':', '!', '?', ')']);
+        if url.len() > "https://".len() {
+            urls.push(start..start + url.len());
+        }
+        rest = start + len;
+    }
+    urls
+}
 
-        for line_spans in highlighted_lines {
-            let mut indented_spans = vec![Span::raw("  ")];
-            indented_spans.extend(line_spans);
-            self.lines.push(Line::from(indented_spans));
+/// Splits one line of segments into rows of at most `width` cells,
+/// breaking at spaces where possible.
+fn wrap_segments(line: Vec<Segment>, width: Option<usize>) -> Vec<Vec<Segment>> {
+    let Some(width) = width else {
+        return vec![line];
+    };
+    let mut rows: Vec<Vec<Segment>> = vec![Vec::new()];
+    let mut column = 0;
+    for segment in line {
+        for piece in split_words(&segment.text) {
+            let is_space = piece.starts_with(char::is_whitespace);
+            let piece_width = piece.width();
+            if is_space {
+                if column + piece_width > width {
+                    start_row(&mut rows);
+                    column = 0;
+                } else if column > 0 || rows.len() == 1 {
+                    push_piece(&mut rows, piece, &segment);
+                    column += piece_width;
+                }
+                continue;
+            }
+            if column > 0 && column + piece_width > width {
+                start_row(&mut rows);
+                column = 0;
+            }
+            let mut rest = piece;
+            while rest.width() > width - column {
+                let split = split_at_width(rest, width - column);
+                if split == 0 && column == 0 {
+                    // A single char wider than the line.
+                    break;
+                }
+                push_piece(&mut rows, &rest[..split], &segment);
+                start_row(&mut rows);
+                column = 0;
+                rest = &rest[split..];
+            }
+            push_piece(&mut rows, rest, &segment);
+            column += rest.width();
         }
+    }
+    rows
+}
+
+/// Starts a new row, dropping the spaces the last one ended with.
+fn start_row(rows: &mut Vec<Vec<Segment>>) {
+    if let Some(row) = rows.last_mut() {
+        while row
+            .last()
+            .is_some_and(|s| s.text.chars().all(char::is_whitespace))
+        {
+            row.pop();
+        }
+    }
+    rows.push(Vec::new());
+}
+
+fn push_piece(rows: &mut [Vec<Segment>], text: &str, segment: &Segment) {
+    if text.is_empty() {
+        return;
+    }
+    if let Some(row) = rows.last_mut() {
+        row.push(Segment {
+            text: text.to_string(),
+            style: segment.style,
+            link: segment.link.clone(),
+        });
+    }
+}
 
-        self.lines.push(Line::from(""));
-        self.code_block_content.clear();
+/// Alternating runs of whitespace and other chars.
+fn split_words(text: &str) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut in_space = None;
+    for (i, c) in text.char_indices() {
+        let space = c.is_whitespace();
+        if in_space.is_some_and(|s| s != space) {
+            pieces.push(&text[start..i]);
+            start = i;
+        }
+        in_space = Some(space);
+    }
+    if start < text.len() {
+        pieces.push(&text[start..]);
     }
+    pieces
+}
 
-    fn flush_line(&mut self) {
-        if !self.current_line.is_empty() {
-            let line = Line::from(std::mem::take(&mut self.current_line));
-            self.lines.push(line);
+/// Byte offset of the longest prefix of `text` at most `width` cells wide.
+fn split_at_width(text: &str, width: usize) -> usize {
+    let mut used = 0;
+    for (i, c) in text.char_indices() {
+        let w = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+        if used + w > width {
+            return i;
         }
+        used += w;
     }
+    text.len()
+}
 
-    fn finish(mut self) -> Vec<Line<'static>> {
-        self.flush_line();
-        self.lines
+fn pad_cell(cell: &str, width: usize, alignment: Alignment) -> String {
+    let cell = if cell.width() > width {
+        let split = split_at_width(cell, width.saturating_sub(1));
+        format!("{}…", &cell[..split])
+    } else {
+        cell.to_string()
+    };
+    let padding = width.saturating_sub(cell.width());
+    match alignment {
+        Alignment::Right => format!("{}{}", " ".repeat(padding), cell),
+        Alignment::Center => format!(
+            "{}{}{}",
+            " ".repeat(padding / 2),
+            cell,
+            " ".repeat(padding - padding / 2)
+        ),
+        Alignment::None | Alignment::Left => format!("{}{}", cell, " ".repeat(padding)),
     }
 }
 
-fn highlight_code(_language: &str, code: &str) -> Vec<Vec<Span<'static>>> {
+/// Keywords and comment syntax of a fenced code block's language.
+struct Syntax {
+    keywords: &'static [&'static str],
+    line_comment: &'static str,
+    /// Whether `'` quotes strings rather than lifetimes or chars.
+    single_quote_strings: bool,
+}
+
+fn syntax_for(language: &str) -> Option<Syntax> {
+    let language = language.split([' ', ',']).next().unwrap_or("");
+    let syntax = match language.to_lowercase().as_str() {
+        "rust" | "rs" => Syntax {
+            keywords: &[
+                "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else",
+                "enum", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod",
+                "move", "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super",
+                "trait", "true", "type", "unsafe", "use", "where", "while",
+            ],
+            line_comment: "//",
+            single_quote_strings: false,
+        },
+        "python" | "py" => Syntax {
+            keywords: &[
+                "and", "as", "assert", "async", "await", "break", "class", "continue", "def",
+                "del", "elif", "else", "except", "False", "finally", "for", "from", "if", "import",
+                "in", "is", "lambda", "None", "not", "or", "pass", "raise", "return", "True",
+                "try", "while", "with", "yield",
+            ],
+            line_comment: "#",
+            single_quote_strings: true,
+        },
+        "javascript" | "js" | "typescript" | "ts" | "jsx" | "tsx" => Syntax {
+            keywords: &[
+                "async",
+                "await",
+                "break",
+                "case",
+                "catch",
+                "class",
+                "const",
+                "continue",
+                "default",
+                "else",
+                "export",
+                "extends",
+                "false",
+                "finally",
+                "for",
+                "from",
+                "function",
+                "if",
+                "import",
+                "in",
+                "instanceof",
+                "interface",
+                "let",
+                "new",
+                "null",
+                "of",
+                "return",
+                "switch",
+                "this",
+                "throw",
+                "true",
+                "try",
+                "type",
+                "typeof",
+                "undefined",
+                "var",
+                "while",
+                "yield",
+            ],
+            line_comment: "//",
+            single_quote_strings: true,
+        },
+        "go" | "golang" => Syntax {
+            keywords: &[
+                "break",
+                "case",
+                "chan",
+                "const",
+                "continue",
+                "default",
+                "defer",
+                "else",
+                "false",
+                "for",
+                "func",
+                "go",
+                "if",
+                "import",
+                "interface",
+                "map",
+                "nil",
+                "package",
+                "range",
+                "return",
+                "select",
+                "struct",
+                "switch",
+                "true",
+                "type",
+                "var",
+            ],
+            line_comment: "//",
+            single_quote_strings: false,
+        },
+        "c" | "cpp" | "c++" | "h" | "java" | "kotlin" | "kt" | "cs" | "csharp" | "swift" => {
+            Syntax {
+                keywords: &[
+                    "break",
+                    "case",
+                    "class",
+                    "const",
+                    "continue",
+                    "default",
+                    "do",
+                    "else",
+                    "enum",
+                    "extends",
+                    "false",
+                    "final",
+                    "for",
+                    "fun",
+                    "func",
+                    "if",
+                    "import",
+                    "include",
+                    "let",
+                    "namespace",
+                    "new",
+                    "null",
+                    "private",
+                    "protected",
+                    "public",
+                    "return",
+                    "static",
+                    "struct",
+                    "switch",
+                    "this",
+                    "true",
+                    "try",
+                    "catch",
+                    "val",
+                    "var",
+                    "void",
+                    "while",
+                ],
+                line_comment: "//",
+                single_quote_strings: false,
+            }
+        }
+        "sh" | "bash" | "shell" | "zsh" | "console" => Syntax {
+            keywords: &[
+                "case", "do", "done", "echo", "elif", "else", "esac", "export", "fi", "for",
+                "function", "if", "in", "local", "return", "then", "while",
+            ],
+            line_comment: "#",
+            single_quote_strings: true,
+        },
+        "sql" => Syntax {
+            keywords: &[
+                "and", "as", "by", "create", "delete", "from", "group", "insert", "into", "join",
+                "left", "limit", "not", "null", "on", "or", "order", "select", "set", "table",
+                "update", "values", "where", "AND", "AS", "BY", "CREATE", "DELETE", "FROM",
+                "GROUP", "INSERT", "INTO", "JOIN", "LEFT", "LIMIT", "NOT", "NULL", "ON", "OR",
+                "ORDER", "SELECT", "SET", "TABLE", "UPDATE", "VALUES", "WHERE",
+            ],
+            line_comment: "--",
+            single_quote_strings: true,
+        },
+        "toml" | "yaml" | "yml" | "ini" => Syntax {
+            keywords: &["true", "false", "null"],
+            line_comment: "#",
+            single_quote_strings: true,
+        },
+        "json" => Syntax {
+            keywords: &["true", "false", "null"],
+            line_comment: "",
+            single_quote_strings: false,
+        },
+        _ => return None,
+    };
+    Some(syntax)
+}
+
+fn highlight_code(language: &str, code: &str) -> Vec<Vec<Span<'static>>> {
+    let Some(syntax) = syntax_for(language) else {
+        return code
+            .lines()
+            .map(|line| vec![Span::styled(line.to_string(), normal_text())])
+            .collect();
+    };
     code.lines()
-        .map(|line| vec![Span::raw(line.to_string())])
+        .map(|line| highlight_line(&syntax, line))
         .collect()
 }
+
+fn highlight_line(syntax: &Syntax, line: &str) -> Vec<Span<'static>> {
+    let theme = current();
+    let keyword = Style::default()
+        .fg(theme.accent)
+        .add_modifier(Modifier::BOLD);
+    let string = Style::default().fg(theme.active_item);
+    let number = Style::default().fg(theme.highlight_text);
+
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        let (len, style) =
+            if !syntax.line_comment.is_empty() && rest.starts_with(syntax.line_comment) {
+                (rest.len(), label_text())
+            } else if c == '"' || (c == '\'' && syntax.single_quote_strings) {
+                (string_len(rest, c), string)
+            } else if c.is_ascii_digit() {
+                let len = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '_'))
+                    .unwrap_or(rest.len());
+                (len, number)
+            } else if c.is_alphabetic() || c == '_' {
+                let len = rest
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len());
+                if syntax.keywords.contains(&&rest[..len]) {
+                    (len, keyword)
+                } else {
+                    plain.push_str(&rest[..len]);
+                    rest = &rest[len..];
+                    continue;
+                }
+            } else {
+                plain.push(c);
+                rest = &rest[c.len_utf8()..];
+                continue;
+            };
+        if !plain.is_empty() {
+            spans.push(Span::styled(std::mem::take(&mut plain), normal_text()));
+        }
+        spans.push(Span::styled(rest[..len].to_string(), style));
+        rest = &rest[len..];
+    }
+    if !plain.is_empty() {
+        spans.push(Span::styled(plain, normal_text()));
+    }
+    spans
+}
+
+/// Length of the string literal at the start of `text`, up to and
+/// including the closing `quote`, or the rest of the line.
+fn string_len(text: &str, quote: char) -> usize {
+    let mut escaped = false;
+    for (i, c) in text.char_indices().skip(1) {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == quote {
+            return i + c.len_utf8();
+        }
+    }
+    text.len()
+}
@@ -1,9 +1,11 @@
 use crate::app::App;
+use crate::card_list::{CardList, CardListRenderInfo};
 use crate::components::{
     card_list_item::{render_card_list_item, CardListItemConfig},
     PanelConfig,
 };
 use crate::layout_strategy::ColumnBoundary;
+use crate::theme::current;
 use crate::theme::{deleted_view_focused_border, label_text};
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::text::{Line, Span};
@@ -37,6 +39,130 @@ fn count_headers_in_viewport(
         .count()
 }
 
+/// One line of a card list panel, top to bottom. Rendering and mouse
+/// hit-testing both walk these, so a click lands on the card drawn there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListRow {
+    /// "N Tasks above" indicator.
+    Above,
+    /// Column header; indexes the column boundaries.
+    Header(usize),
+    /// Card at this index in the list.
+    Card(usize),
+    /// "N Tasks below" indicator.
+    Below,
+}
+
+/// Splits the kanban view into one equal-width panel per column.
+pub fn column_panels(area: Rect, column_count: usize) -> std::rc::Rc<[Rect]> {
+    let column_width = 100 / column_count.max(1) as u16;
+    let constraints = vec![Constraint::Percentage(column_width); column_count];
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(constraints)
+        .split(area)
+}
+
+/// Render info for a list without headers, leaving room for the scroll
+/// indicators in `viewport_height` lines.
+fn flat_render_info(task_list: &CardList, viewport_height: usize) -> CardListRenderInfo {
+    // Calculate indicator overhead based on actual position
+    // This matches the logic in get_adjusted_viewport_height
+    let mut indicator_overhead = 0;
+    if task_list.get_scroll_offset() > 0 {
+        indicator_overhead += 1; // Will show "above" indicator
+    }
+    if task_list.get_scroll_offset() + viewport_height < task_list.len() {
+        indicator_overhead += 1; // Will show "below" indicator
+    }
+
+    // Adjust viewport height to account for indicators
+    task_list.get_render_info(viewport_height.saturating_sub(indicator_overhead))
+}
+
+fn with_indicators(info: &CardListRenderInfo, body: Vec<ListRow>) -> Vec<ListRow> {
+    let mut rows = Vec::with_capacity(body.len() + 2);
+    if info.show_above_indicator {
+        rows.push(ListRow::Above);
+    }
+    rows.extend(body);
+    if info.show_below_indicator {
+        rows.push(ListRow::Below);
+    }
+    rows
+}
+
+/// Rows of a list drawn without column headers.
+pub fn flat_rows(
+    task_list: &CardList,
+    viewport_height: usize,
+) -> (CardListRenderInfo, Vec<ListRow>) {
+    let info = flat_render_info(task_list, viewport_height);
+    let body = info
+        .visible_card_indices
+        .iter()
+        .map(|&idx| ListRow::Card(idx))
+        .collect();
+    let rows = with_indicators(&info, body);
+    (info, rows)
+}
+
+/// Rows of a list grouped by column, with a header above the first
+/// visible card of each column.
+pub fn grouped_rows(
+    task_list: &CardList,
+    column_boundaries: &[ColumnBoundary],
+    viewport_height: usize,
+) -> (CardListRenderInfo, Vec<ListRow>) {
+    let scroll_offset = task_list.get_scroll_offset();
+
+    // Calculate "above" indicator overhead (fixed based on scroll position)
+    let above_indicator_height = if scroll_offset > 0 { 1 } else { 0 };
+
+    // Start with space available after above indicator
+    let available_space = viewport_height.saturating_sub(above_indicator_height);
+
+    // Initial estimate: count headers based on available space
+    let initial_header_count =
+        count_headers_in_viewport(column_boundaries, scroll_offset, available_space);
+
+    // Calculate card slots after initial header estimate
+    let initial_card_slots = available_space.saturating_sub(initial_header_count);
+
+    // Refine: count headers for only the cards that will actually be visible
+    let refined_header_count =
+        count_headers_in_viewport(column_boundaries, scroll_offset, initial_card_slots);
+
+    // Calculate card slots with refined header count
+    let card_slots = available_space.saturating_sub(refined_header_count);
+
+    // Check if we need "below" indicator based on actual visible cards
+    let below_indicator_height = if scroll_offset + card_slots < task_list.len() {
+        1
+    } else {
+        0
+    };
+
+    // Final adjusted viewport: cards minus below indicator
+    let info = task_list.get_render_info(card_slots.saturating_sub(below_indicator_height));
+
+    // Insert a header before the first visible card of each column
+    let mut body = Vec::new();
+    let mut columns_shown = std::collections::HashSet::new();
+    for &card_idx in &info.visible_card_indices {
+        let card_column_idx = column_boundaries
+            .iter()
+            .rposition(|b| card_idx >= b.start_index)
+            .unwrap_or(0);
+        if card_column_idx < column_boundaries.len() && columns_shown.insert(card_column_idx) {
+            body.push(ListRow::Header(card_column_idx));
+        }
+        body.push(ListRow::Card(card_idx));
+    }
+    let rows = with_indicators(&info, body);
+    (info, rows)
+}
+
 pub struct SinglePanelRenderer {
     show_column_headers: bool,
 }
@@ -97,88 +223,52 @@ impl RenderStrategy for SinglePanelRenderer {
                             lines.push(Line::from(Span::styled(message, label_text())));
                         } else {
                             let raw_viewport_height = area.height.saturating_sub(2) as usize;
-                            let scroll_offset = task_list.get_scroll_offset();
-
-                            // Calculate "above" indicator overhead (fixed based on scroll position)
-                            let above_indicator_height = if scroll_offset > 0 { 1 } else { 0 };
-
-                            // Start with space available after above indicator
-                            let available_space =
-                                raw_viewport_height.saturating_sub(above_indicator_height);
-
-                            // Initial estimate: count headers based on available space
-                            let initial_header_count = count_headers_in_viewport(
-                                &column_boundaries,
-                                scroll_offset,
-                                available_space,
-                            );
-
-                            // Calculate card slots after initial header estimate
-                            let initial_card_slots =
-                                available_space.saturating_sub(initial_header_count);
-
-                            // Refine: count headers for only the cards that will actually be visible
-                            let refined_header_count = count_headers_in_viewport(
-                                &column_boundaries,
-                                scroll_offset,
-                                initial_card_slots,
-                            );
-
-                            // Calculate card slots with refined header count
-                            let card_slots = available_space.saturating_sub(refined_header_count);
-
-                            // Check if we need "below" indicator based on actual visible cards
-                            let below_indicator_height =
-                                if scroll_offset + card_slots < task_list.len() {
-                                    1
-                                } else {
-                                    0
-                                };
-
-                            // Final adjusted viewport: cards minus below indicator
-                            let adjusted_viewport_height =
-                                card_slots.saturating_sub(below_indicator_height);
-
-                            let render_info = task_list.get_render_info(adjusted_viewport_height);
-
-                            // Render above indicator
-                            lines.extend(crate::scroll_indicators::render_above_indicator(
-                                render_info.show_above_indicator,
-                                render_info.cards_above_count,
-                                "Task",
-                            ));
-
-                            // Render all cards with column headers interspersed
-                            let mut columns_shown = std::collections::HashSet::new();
+                            let (render_info, rows) =
+                                grouped_rows(task_list, &column_boundaries, raw_viewport_height);
                             let sprints = app.model.sprints();
 
-                            for card_idx in &render_info.visible_card_indices {
-                                // Find which column this card belongs to
-                                let card_column_idx = column_boundaries
-                                    .iter()
-                                    .rposition(|b| *card_idx >= b.start_index)
-                                    .unwrap_or(0);
-
-                                // Insert header for this card's column if we haven't already
-                                if !columns_shown.contains(&card_column_idx) {
-                                    if let Some(boundary) = column_boundaries.get(card_column_idx) {
+                            for row in rows {
+                                let card_idx = match row {
+                                    ListRow::Above => {
+                                        lines.extend(
+                                            crate::scroll_indicators::render_above_indicator(
+                                                render_info.show_above_indicator,
+                                                render_info.cards_above_count,
+                                                "Task",
+                                            ),
+                                        );
+                                        continue;
+                                    }
+                                    ListRow::Below => {
+                                        lines.extend(
+                                            crate::scroll_indicators::render_below_indicator(
+                                                render_info.show_below_indicator,
+                                                render_info.cards_below_count,
+                                                "Task",
+                                            ),
+                                        );
+                                        continue;
+                                    }
+                                    ListRow::Header(boundary_idx) => {
+                                        let boundary = &column_boundaries[boundary_idx];
                                         lines.push(Line::from(Span::styled(
                                             format!(
                                                 "── {} ({}) ──",
                                                 boundary.column_name, boundary.card_count
                                             ),
                                             ratatui::style::Style::default()
-                                                .fg(ratatui::style::Color::Cyan)
+                                                .fg(current().accent)
                                                 .add_modifier(ratatui::style::Modifier::BOLD),
                                         )));
-                                        columns_shown.insert(card_column_idx);
+                                        continue;
                                     }
-                                }
+                                    ListRow::Card(card_idx) => card_idx,
+                                };
 
-                                if let Some(card_id) = task_list.cards.get(*card_idx) {
+                                if let Some(card_id) = task_list.cards.get(card_idx) {
                                     if let Some(card) = app.get_card_by_id(*card_id) {
                                         let is_selected =
-                                            task_list.get_selected_index() == Some(*card_idx);
+                                            task_list.get_selected_index() == Some(card_idx);
                                         let animation_type = app
                                             .animation
                                             .animating
@@ -206,13 +296,6 @@ impl RenderStrategy for SinglePanelRenderer {
                                     }
                                 }
                             }
-
-                            // Render below indicator
-                            lines.extend(crate::scroll_indicators::render_below_indicator(
-                                render_info.show_below_indicator,
-                                render_info.cards_below_count,
-                                "Task",
-                            ));
                         }
                     } else if let Some(board) = app.model.boards().get(board_idx.unwrap()) {
                         let mut board_columns: Vec<_> = app
@@ -247,21 +330,7 @@ impl RenderStrategy for SinglePanelRenderer {
                         lines.push(Line::from(Span::styled(message, label_text())));
                     } else {
                         let raw_viewport_height = area.height.saturating_sub(2) as usize;
-
-                        // Calculate indicator overhead based on actual position
-                        let mut indicator_overhead = 0;
-                        if task_list.get_scroll_offset() > 0 {
-                            indicator_overhead += 1; // Will show "above" indicator
-                        }
-                        if task_list.get_scroll_offset() + raw_viewport_height < task_list.len() {
-                            indicator_overhead += 1; // Will show "below" indicator
-                        }
-
-                        // Adjust viewport height to account for indicators
-                        let adjusted_viewport_height =
-                            raw_viewport_height.saturating_sub(indicator_overhead);
-
-                        let render_info = task_list.get_render_info(adjusted_viewport_height);
+                        let render_info = flat_render_info(task_list, raw_viewport_height);
 
                         lines.extend(crate::scroll_indicators::render_above_indicator(
                             render_info.show_above_indicator,
@@ -364,18 +433,7 @@ impl RenderStrategy for MultiPanelRenderer {
 
                 let sprint_filter_suffix = crate::ui::build_filter_title_suffix(app);
 
-                let column_count = task_lists.len();
-                let column_width = 100 / column_count as u16;
-
-                let mut constraints = vec![];
-                for _ in 0..column_count {
-                    constraints.push(Constraint::Percentage(column_width));
-                }
-
-                let chunks = Layout::default()
-                    .direction(Direction::Horizontal)
-                    .constraints(constraints)
-                    .split(area);
+                let chunks = column_panels(area, task_lists.len());
 
                 let active_task_list = app.view.strategy.get_active_task_list();
                 let sprints = app.model.sprints();
@@ -392,22 +450,7 @@ impl RenderStrategy for MultiPanelRenderer {
                         lines.push(Line::from(Span::styled("  (no tasks)", label_text())));
                     } else {
                         let raw_viewport_height = chunks[col_idx].height.saturating_sub(2) as usize;
-
-                        // Calculate indicator overhead based on actual position
-                        // This matches the logic in SinglePanelRenderer and get_adjusted_viewport_height
-                        let mut indicator_overhead = 0;
-                        if task_list.get_scroll_offset() > 0 {
-                            indicator_overhead += 1; // Will show "above" indicator
-                        }
-                        if task_list.get_scroll_offset() + raw_viewport_height < task_list.len() {
-                            indicator_overhead += 1; // Will show "below" indicator
-                        }
-
-                        // Adjust viewport height to account for indicators
-                        let adjusted_viewport_height =
-                            raw_viewport_height.saturating_sub(indicator_overhead);
-
-                        let render_info = task_list.get_render_info(adjusted_viewport_height);
+                        let render_info = flat_render_info(task_list, raw_viewport_height);
 
                         lines.extend(crate::scroll_indicators::render_above_indicator(
                             render_info.show_above_indicator,
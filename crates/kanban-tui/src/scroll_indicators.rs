@@ -4,14 +4,15 @@ pub fn render_above_indicator<'a>(
     count: usize,
     label: &str,
 ) -> Option<ratatui::text::Line<'a>> {
-    use ratatui::style::{Color, Style};
+    use crate::theme::current;
+    use ratatui::style::Style;
     use ratatui::text::{Line, Span};
 
     if show {
         let plural = if count == 1 { "" } else { "s" };
         Some(Line::from(Span::styled(
             format!("  {} {}{} above", count, label, plural),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(current().label_text),
         )))
     } else {
         None
@@ -24,14 +25,15 @@ pub fn render_below_indicator<'a>(
     count: usize,
     label: &str,
 ) -> Option<ratatui::text::Line<'a>> {
-    use ratatui::style::{Color, Style};
+    use crate::theme::current;
+    use ratatui::style::Style;
     use ratatui::text::{Line, Span};
 
     if show {
         let plural = if count == 1 { "" } else { "s" };
         Some(Line::from(Span::styled(
             format!("  {} {}{} below", count, label, plural),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(current().label_text),
         )))
     } else {
         None
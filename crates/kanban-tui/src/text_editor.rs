@@ -0,0 +1,569 @@
+//! The inline text editor used for titles and descriptions.
+//!
+//! Editing itself lives in [`InputState`]; this adds the target being
+//! edited, soft wrapping, the key bindings and an optional vi mode.
+
+use crate::app::{BoardField, CardField};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use kanban_core::InputState;
+use std::cell::Cell;
+use std::ops::Range;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// The field an editor session writes back to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextTarget {
+    Card(Uuid, CardField),
+    Board(Uuid, BoardField),
+}
+
+impl TextTarget {
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::Card(_, CardField::Title) => "Edit Title",
+            Self::Card(_, CardField::Description) => "Edit Description",
+            Self::Board(_, BoardField::Name) => "Edit Project Name",
+            Self::Board(_, BoardField::Description) => "Edit Project Description",
+        }
+    }
+
+    /// "card" or "board", for log messages.
+    pub fn noun(&self) -> &'static str {
+        match self {
+            Self::Card(..) => "card",
+            Self::Board(..) => "board",
+        }
+    }
+
+    /// Scratch file the text goes through when handed to $EDITOR.
+    pub fn temp_file(&self) -> PathBuf {
+        let name = match self {
+            Self::Card(id, CardField::Title) => format!("kanban-card-{}-title.md", id),
+            Self::Card(id, CardField::Description) => format!("kanban-card-{}-description.md", id),
+            Self::Board(id, BoardField::Name) => format!("kanban-board-{}-name.md", id),
+            Self::Board(id, BoardField::Description) => {
+                format!("kanban-board-{}-description.md", id)
+            }
+        };
+        std::env::temp_dir().join(name)
+    }
+
+    /// Titles and names are one line: Enter saves instead of breaking it.
+    pub fn is_single_line(&self) -> bool {
+        matches!(
+            self,
+            Self::Card(_, CardField::Title) | Self::Board(_, BoardField::Name)
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViMode {
+    Normal,
+    Insert,
+    Visual,
+}
+
+/// What a key asks of the caller once the editor has handled it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorAction {
+    Continue,
+    Save,
+    Cancel,
+    /// Hand the text to $EDITOR and load the result back.
+    OpenExternal,
+}
+
+pub struct TextEditorState {
+    pub input: InputState,
+    pub target: Option<TextTarget>,
+    /// `None` for the plain bindings, otherwise the current vi mode.
+    pub vi: Option<ViMode>,
+    /// First key of a two-key vi command (`dd`, `gg`).
+    pending: Option<char>,
+    /// Column kept across up/down motions through shorter lines.
+    goal_column: Option<usize>,
+    /// Wrap width of the last frame, for up/down by visual line.
+    pub wrap_width: Cell<usize>,
+    /// First visual line shown, kept by the renderer.
+    pub scroll: Cell<usize>,
+}
+
+impl TextEditorState {
+    pub fn new() -> Self {
+        Self {
+            input: InputState::new(),
+            target: None,
+            vi: None,
+            pending: None,
+            goal_column: None,
+            wrap_width: Cell::new(usize::MAX),
+            scroll: Cell::new(0),
+        }
+    }
+
+    pub fn open(&mut self, target: TextTarget, text: &str, vi: bool) {
+        self.input.set(text.to_string());
+        self.target = Some(target);
+        self.vi = vi.then_some(ViMode::Normal);
+        self.pending = None;
+        self.goal_column = None;
+        self.scroll.set(0);
+    }
+
+    pub fn close(&mut self) {
+        self.input.clear();
+        self.target = None;
+        self.vi = None;
+        self.pending = None;
+    }
+
+    pub fn is_single_line(&self) -> bool {
+        self.target.is_some_and(|t| t.is_single_line())
+    }
+
+    /// Replaces the whole text as one undo step, e.g. with what $EDITOR
+    /// returned.
+    pub fn replace_text(&mut self, text: &str) {
+        self.input.select_all();
+        self.input.insert_str(text);
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> EditorAction {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Char('s') if ctrl => return EditorAction::Save,
+            KeyCode::Char('o') if ctrl => return EditorAction::OpenExternal,
+            _ => {}
+        }
+        let vertical = matches!(key.code, KeyCode::Up | KeyCode::Down)
+            || (self.vi.is_some_and(|m| m != ViMode::Insert)
+                && matches!(key.code, KeyCode::Char('j' | 'k')));
+        if !vertical {
+            self.goal_column = None;
+        }
+        match self.vi {
+            None => self.handle_plain_key(key),
+            Some(ViMode::Insert) => self.handle_vi_insert_key(key),
+            Some(mode) => self.handle_vi_command_key(key, mode),
+        }
+    }
+
+    fn handle_plain_key(&mut self, key: KeyEvent) -> EditorAction {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        match key.code {
+            KeyCode::Esc => return EditorAction::Cancel,
+            KeyCode::Char('z') if ctrl => {
+                self.input.undo();
+            }
+            KeyCode::Char('y') if ctrl => {
+                self.input.redo();
+            }
+            KeyCode::Char('a') if ctrl => self.input.select_all(),
+            KeyCode::Char('w') if ctrl => self.delete_word_back(),
+            KeyCode::Char('b') if alt => self.motion(false, |e| e.input.move_word_left()),
+            KeyCode::Char('f') if alt => self.motion(false, |e| e.input.move_word_right()),
+            KeyCode::Char(c) if !ctrl && !alt => self.input.insert_char(c),
+            KeyCode::Enter => {
+                if self.is_single_line() {
+                    return EditorAction::Save;
+                }
+                self.input.insert_char('\n');
+            }
+            KeyCode::Backspace => self.input.backspace(),
+            KeyCode::Delete => self.input.delete(),
+            KeyCode::Left if ctrl || alt => self.motion(shift, |e| e.input.move_word_left()),
+            KeyCode::Right if ctrl || alt => self.motion(shift, |e| e.input.move_word_right()),
+            KeyCode::Left => self.motion(shift, |e| e.input.move_left()),
+            KeyCode::Right => self.motion(shift, |e| e.input.move_right()),
+            KeyCode::Up => self.motion(shift, |e| e.move_vertical(false)),
+            KeyCode::Down => self.motion(shift, |e| e.move_vertical(true)),
+            KeyCode::Home if ctrl => self.motion(shift, |e| e.input.move_home()),
+            KeyCode::End if ctrl => self.motion(shift, |e| e.input.move_end()),
+            KeyCode::Home => self.motion(shift, |e| e.input.move_line_start()),
+            KeyCode::End => self.motion(shift, |e| e.input.move_line_end()),
+            _ => {}
+        }
+        EditorAction::Continue
+    }
+
+    fn handle_vi_insert_key(&mut self, key: KeyEvent) -> EditorAction {
+        if key.code == KeyCode::Esc {
+            self.vi = Some(ViMode::Normal);
+            self.input.move_left();
+            return EditorAction::Continue;
+        }
+        self.handle_plain_key(key)
+    }
+
+    /// Normal and visual mode. In visual mode motions extend the selection
+    /// and `d`/`x` delete it.
+    fn handle_vi_command_key(&mut self, key: KeyEvent, mode: ViMode) -> EditorAction {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let visual = mode == ViMode::Visual;
+        let pending = self.pending.take();
+        match key.code {
+            KeyCode::Esc if visual => {
+                self.input.clear_selection();
+                self.vi = Some(ViMode::Normal);
+            }
+            KeyCode::Esc => return EditorAction::Cancel,
+            KeyCode::Enter if self.is_single_line() => return EditorAction::Save,
+            KeyCode::Char('r') if ctrl => {
+                self.input.redo();
+            }
+            KeyCode::Char('h') | KeyCode::Left => self.motion(visual, |e| e.input.move_left()),
+            KeyCode::Char('l') | KeyCode::Right => self.motion(visual, |e| e.input.move_right()),
+            KeyCode::Char('j') | KeyCode::Down => self.motion(visual, |e| e.move_vertical(true)),
+            KeyCode::Char('k') | KeyCode::Up => self.motion(visual, |e| e.move_vertical(false)),
+            KeyCode::Char('w') => self.motion(visual, |e| e.input.move_word_right()),
+            KeyCode::Char('b') => self.motion(visual, |e| e.input.move_word_left()),
+            KeyCode::Char('0') | KeyCode::Home => {
+                self.motion(visual, |e| e.input.move_line_start())
+            }
+            KeyCode::Char('$') | KeyCode::End => self.motion(visual, |e| e.input.move_line_end()),
+            KeyCode::Char('g') if pending == Some('g') => {
+                self.motion(visual, |e| e.input.move_home())
+            }
+            KeyCode::Char('G') => self.motion(visual, |e| e.input.move_end()),
+            KeyCode::Char('d' | 'x') if visual => {
+                self.input.delete_selection();
+                self.vi = Some(ViMode::Normal);
+            }
+            KeyCode::Char('v') if visual => {
+                self.input.clear_selection();
+                self.vi = Some(ViMode::Normal);
+            }
+            KeyCode::Char('g') if !ctrl => self.pending = Some('g'),
+            // Editing keys below are normal mode only.
+            KeyCode::Char(_) if visual || ctrl => {}
+            KeyCode::Char('d') if pending == Some('d') => self.delete_line(),
+            KeyCode::Char('d') => self.pending = Some('d'),
+            KeyCode::Char('x') => self.input.delete(),
+            KeyCode::Char('u') => {
+                self.input.undo();
+            }
+            KeyCode::Char('v') => {
+                self.input.clear_selection();
+                self.input.start_selection();
+                self.vi = Some(ViMode::Visual);
+            }
+            KeyCode::Char('i') => self.enter_insert(|_| {}),
+            KeyCode::Char('a') => self.enter_insert(|input| input.move_right()),
+            KeyCode::Char('I') => self.enter_insert(|input| input.move_line_start()),
+            KeyCode::Char('A') => self.enter_insert(|input| input.move_line_end()),
+            KeyCode::Char('o') if !self.is_single_line() => self.enter_insert(|input| {
+                input.move_line_end();
+                input.insert_char('\n');
+            }),
+            KeyCode::Char('O') if !self.is_single_line() => self.enter_insert(|input| {
+                input.move_line_start();
+                input.insert_char('\n');
+                input.move_left();
+            }),
+            _ => {}
+        }
+        EditorAction::Continue
+    }
+
+    fn enter_insert(&mut self, prepare: impl FnOnce(&mut InputState)) {
+        prepare(&mut self.input);
+        self.vi = Some(ViMode::Insert);
+    }
+
+    /// Runs a cursor motion, extending the selection when `select` is set
+    /// and dropping it otherwise.
+    fn motion(&mut self, select: bool, f: impl FnOnce(&mut Self)) {
+        if select {
+            self.input.start_selection();
+        } else {
+            self.input.clear_selection();
+        }
+        f(self);
+    }
+
+    fn delete_word_back(&mut self) {
+        if self.input.selection().is_none() {
+            self.input.clear_selection();
+            self.input.start_selection();
+            self.input.move_word_left();
+        }
+        self.input.delete_selection();
+    }
+
+    /// `dd`: deletes the line under the cursor with its newline.
+    fn delete_line(&mut self) {
+        let text = self.input.as_str();
+        let start = self.input.line_start(self.input.cursor_byte_offset());
+        let end = text[start..]
+            .find('\n')
+            .map_or(text.len(), |i| start + i + 1);
+        // The last line takes the newline before it instead.
+        let start = if end == text.len() && start > 0 && !text.ends_with('\n') {
+            start - 1
+        } else {
+            start
+        };
+        self.input.clear_selection();
+        self.input.set_cursor(start);
+        self.input.start_selection();
+        self.input.set_cursor(end);
+        self.input.delete_selection();
+        let line_start = self.input.line_start(self.input.cursor_byte_offset());
+        self.input.set_cursor(line_start);
+    }
+
+    /// Moves the cursor one visual line down or up, keeping its column.
+    fn move_vertical(&mut self, down: bool) {
+        let text = self.input.as_str();
+        let lines = wrap_lines(text, self.wrap_width.get());
+        let (row, column) = cursor_row_column(text, &lines, self.input.cursor_byte_offset());
+        let goal = *self.goal_column.get_or_insert(column);
+        let target = if down {
+            row + 1
+        } else {
+            match row.checked_sub(1) {
+                Some(row) => row,
+                None => return,
+            }
+        };
+        let Some(line) = lines.get(target) else {
+            return;
+        };
+        let mut offset = text[line.clone()]
+            .char_indices()
+            .nth(goal)
+            .map_or(line.end, |(i, _)| line.start + i);
+        // The end of a wrapped segment is the start of the next one.
+        if offset == line.end && lines.get(target + 1).is_some_and(|n| n.start == line.end) {
+            offset = text[line.clone()]
+                .char_indices()
+                .next_back()
+                .map_or(line.start, |(i, _)| line.start + i);
+        }
+        self.input.set_cursor(offset);
+    }
+}
+
+impl Default for TextEditorState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Splits `text` into visual lines of at most `width` chars, breaking
+/// after the last space that fits where possible. Returns the byte range
+/// of each line without its newline.
+pub fn wrap_lines(text: &str, width: usize) -> Vec<Range<usize>> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut line_start = 0;
+    for line in text.split('\n') {
+        let line_end = line_start + line.len();
+        let mut start = line_start;
+        loop {
+            let Some((hard, _)) = text[start..line_end].char_indices().nth(width) else {
+                lines.push(start..line_end);
+                break;
+            };
+            let hard = start + hard;
+            let end = text[start..hard]
+                .rfind(' ')
+                .map(|i| start + i + 1)
+                .filter(|&end| end > start)
+                .unwrap_or(hard);
+            lines.push(start..end);
+            start = end;
+        }
+        line_start = line_end + 1;
+    }
+    lines
+}
+
+/// Visual line and char column of byte `offset`.
+pub fn cursor_row_column(text: &str, lines: &[Range<usize>], offset: usize) -> (usize, usize) {
+    let row = lines
+        .iter()
+        .rposition(|line| line.start <= offset)
+        .unwrap_or(0);
+    let start = lines.get(row).map_or(0, |line| line.start);
+    (row, text[start..offset].chars().count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    fn editor(text: &str, target: TextTarget, vi: bool) -> TextEditorState {
+        let mut editor = TextEditorState::new();
+        editor.open(target, text, vi);
+        editor
+    }
+
+    fn description() -> TextTarget {
+        TextTarget::Card(Uuid::nil(), CardField::Description)
+    }
+
+    fn type_keys(editor: &mut TextEditorState, keys: &str) {
+        for c in keys.chars() {
+            editor.handle_key(key(KeyCode::Char(c)));
+        }
+    }
+
+    #[test]
+    fn test_wrap_lines_breaks_after_spaces() {
+        let text = "one two three\nfour";
+        let lines: Vec<&str> = wrap_lines(text, 8).into_iter().map(|r| &text[r]).collect();
+        assert_eq!(lines, ["one two ", "three", "four"]);
+    }
+
+    #[test]
+    fn test_wrap_lines_hard_breaks_long_words() {
+        let text = "abcdefgh";
+        let lines: Vec<&str> = wrap_lines(text, 3).into_iter().map(|r| &text[r]).collect();
+        assert_eq!(lines, ["abc", "def", "gh"]);
+    }
+
+    #[test]
+    fn test_wrap_lines_keeps_empty_lines() {
+        assert_eq!(wrap_lines("", 5), vec![0..0]);
+        assert_eq!(wrap_lines("a\n\nb\n", 5), [0..1, 2..2, 3..4, 5..5]);
+    }
+
+    #[test]
+    fn test_cursor_row_column_on_wrap_boundary() {
+        let text = "one two three";
+        let lines = wrap_lines(text, 8);
+        assert_eq!(cursor_row_column(text, &lines, 8), (1, 0));
+        assert_eq!(cursor_row_column(text, &lines, 13), (1, 5));
+    }
+
+    #[test]
+    fn test_vertical_motion_keeps_goal_column() {
+        let mut editor = editor("long line\nab\nanother", description(), false);
+        editor.input.set_cursor(6);
+        editor.handle_key(key(KeyCode::Down));
+        assert_eq!(editor.input.cursor_byte_offset(), 12);
+        editor.handle_key(key(KeyCode::Down));
+        assert_eq!(editor.input.cursor_byte_offset(), 19);
+        editor.handle_key(key(KeyCode::Up));
+        editor.handle_key(key(KeyCode::Up));
+        assert_eq!(editor.input.cursor_byte_offset(), 6);
+    }
+
+    #[test]
+    fn test_vertical_motion_follows_soft_wrap() {
+        let mut editor = editor("one two three", description(), false);
+        editor.wrap_width.set(8);
+        editor.input.set_cursor(1);
+        editor.handle_key(key(KeyCode::Down));
+        assert_eq!(editor.input.cursor_byte_offset(), 9);
+        editor.handle_key(key(KeyCode::End));
+        editor.handle_key(key(KeyCode::Left));
+        editor.handle_key(key(KeyCode::Up));
+        assert_eq!(editor.input.cursor_byte_offset(), 4);
+    }
+
+    #[test]
+    fn test_enter_saves_single_line_fields() {
+        let mut title = editor(
+            "Title",
+            TextTarget::Card(Uuid::nil(), CardField::Title),
+            false,
+        );
+        assert_eq!(title.handle_key(key(KeyCode::Enter)), EditorAction::Save);
+
+        let mut body = editor("Body", description(), false);
+        assert_eq!(body.handle_key(key(KeyCode::Enter)), EditorAction::Continue);
+        assert_eq!(body.input.as_str(), "Body\n");
+        assert_eq!(body.handle_key(ctrl('s')), EditorAction::Save);
+    }
+
+    #[test]
+    fn test_shift_motion_selects_and_typing_replaces() {
+        let mut editor = editor("hello world", description(), false);
+        editor.handle_key(KeyEvent::new(
+            KeyCode::Left,
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        ));
+        assert_eq!(editor.input.selected_text(), Some("world"));
+        type_keys(&mut editor, "there");
+        assert_eq!(editor.input.as_str(), "hello there");
+
+        editor.handle_key(ctrl('z'));
+        assert_eq!(editor.input.as_str(), "hello world");
+        editor.handle_key(ctrl('y'));
+        assert_eq!(editor.input.as_str(), "hello there");
+    }
+
+    #[test]
+    fn test_plain_motion_drops_selection() {
+        let mut editor = editor("hello", description(), false);
+        editor.handle_key(KeyEvent::new(KeyCode::Left, KeyModifiers::SHIFT));
+        editor.handle_key(key(KeyCode::Left));
+        assert_eq!(editor.input.selection(), None);
+    }
+
+    #[test]
+    fn test_ctrl_w_deletes_word_back() {
+        let mut editor = editor("hello world", description(), false);
+        editor.handle_key(ctrl('w'));
+        assert_eq!(editor.input.as_str(), "hello ");
+    }
+
+    #[test]
+    fn test_vi_insert_and_normal_modes() {
+        let mut editor = editor("world", description(), true);
+        editor.handle_key(key(KeyCode::Char('0')));
+        type_keys(&mut editor, "ihello ");
+        assert_eq!(editor.vi, Some(ViMode::Insert));
+        assert_eq!(editor.input.as_str(), "hello world");
+
+        editor.handle_key(key(KeyCode::Esc));
+        assert_eq!(editor.vi, Some(ViMode::Normal));
+        type_keys(&mut editor, "A!");
+        assert_eq!(editor.input.as_str(), "hello world!");
+        editor.handle_key(key(KeyCode::Esc));
+        type_keys(&mut editor, "u");
+        assert_eq!(editor.input.as_str(), "hello world");
+        assert_eq!(editor.handle_key(key(KeyCode::Esc)), EditorAction::Cancel);
+    }
+
+    #[test]
+    fn test_vi_dd_deletes_the_line() {
+        let mut editor = editor("one\ntwo\nthree", description(), true);
+        editor.input.set_cursor(5);
+        type_keys(&mut editor, "dd");
+        assert_eq!(editor.input.as_str(), "one\nthree");
+        assert_eq!(editor.input.cursor_byte_offset(), 4);
+
+        type_keys(&mut editor, "dd");
+        assert_eq!(editor.input.as_str(), "one");
+    }
+
+    #[test]
+    fn test_vi_visual_delete() {
+        let mut editor = editor("hello world", description(), true);
+        type_keys(&mut editor, "0vwd");
+        assert_eq!(editor.input.as_str(), "world");
+        assert_eq!(editor.vi, Some(ViMode::Normal));
+    }
+
+    #[test]
+    fn test_vi_gg_and_open_line() {
+        let mut editor = editor("one\ntwo", description(), true);
+        type_keys(&mut editor, "ggOzero");
+        assert_eq!(editor.input.as_str(), "zero\none\ntwo");
+    }
+}
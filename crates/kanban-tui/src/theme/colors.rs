@@ -1,33 +1,442 @@
 use ratatui::style::Color;
+use std::sync::RwLock;
 
-pub const FOCUSED_BORDER: Color = Color::Cyan;
-pub const UNFOCUSED_BORDER: Color = Color::White;
-pub const SELECTED_BG: Color = Color::Blue;
-
-pub const ACTIVE_ITEM: Color = Color::Green;
-pub const DONE_TEXT: Color = Color::DarkGray;
-pub const NORMAL_TEXT: Color = Color::White;
-pub const LABEL_TEXT: Color = Color::DarkGray;
-pub const HIGHLIGHT_TEXT: Color = Color::Yellow;
-
-pub const PRIORITY_CRITICAL: Color = Color::Red;
-pub const PRIORITY_HIGH: Color = Color::LightRed;
-pub const PRIORITY_MEDIUM: Color = Color::Yellow;
-pub const PRIORITY_LOW: Color = Color::White;
-
-pub const POINTS_1: Color = Color::Cyan;
-pub const POINTS_2: Color = Color::Green;
-pub const POINTS_3: Color = Color::Yellow;
-pub const POINTS_4: Color = Color::LightMagenta;
-pub const POINTS_5: Color = Color::Red;
-
-pub const STATUS_ACTIVE: Color = Color::Green;
-pub const STATUS_PLANNING: Color = Color::Yellow;
-pub const STATUS_COMPLETED: Color = Color::Gray;
-pub const STATUS_CANCELLED: Color = Color::Red;
-
-pub const POPUP_BG: Color = Color::Black;
-pub const ERROR_COLOR: Color = Color::Red;
-
-pub const FLASH_DELETE: Color = Color::Red;
-pub const FLASH_RESTORE: Color = Color::Blue;
+/// Semantic color roles used by every view. Built-in themes are provided by
+/// the constructors below; theme files override individual roles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub focused_border: Color,
+    pub unfocused_border: Color,
+    pub selected_bg: Color,
+
+    pub active_item: Color,
+    pub done_text: Color,
+    pub normal_text: Color,
+    pub label_text: Color,
+    pub highlight_text: Color,
+    pub accent: Color,
+
+    pub priority_critical: Color,
+    pub priority_high: Color,
+    pub priority_medium: Color,
+    pub priority_low: Color,
+
+    pub points: [Color; 5],
+
+    pub status_active: Color,
+    pub status_planning: Color,
+    pub status_completed: Color,
+    pub status_cancelled: Color,
+
+    pub popup_bg: Color,
+    pub error: Color,
+
+    pub flash_delete: Color,
+    pub flash_restore: Color,
+}
+
+pub const BUILTIN_THEMES: &[&str] = &["dark", "light", "high-contrast", "colorblind"];
+
+// Okabe-Ito palette, distinguishable under the common forms of color blindness.
+const OKABE_ORANGE: Color = Color::Rgb(230, 159, 0);
+const OKABE_SKY_BLUE: Color = Color::Rgb(86, 180, 233);
+const OKABE_BLUISH_GREEN: Color = Color::Rgb(0, 158, 115);
+const OKABE_YELLOW: Color = Color::Rgb(240, 228, 66);
+const OKABE_BLUE: Color = Color::Rgb(0, 114, 178);
+const OKABE_VERMILLION: Color = Color::Rgb(213, 94, 0);
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self {
+            focused_border: Color::Cyan,
+            unfocused_border: Color::White,
+            selected_bg: Color::Blue,
+            active_item: Color::Green,
+            done_text: Color::DarkGray,
+            normal_text: Color::White,
+            label_text: Color::DarkGray,
+            highlight_text: Color::Yellow,
+            accent: Color::Cyan,
+            priority_critical: Color::Red,
+            priority_high: Color::LightRed,
+            priority_medium: Color::Yellow,
+            priority_low: Color::White,
+            points: [
+                Color::Cyan,
+                Color::Green,
+                Color::Yellow,
+                Color::LightMagenta,
+                Color::Red,
+            ],
+            status_active: Color::Green,
+            status_planning: Color::Yellow,
+            status_completed: Color::Gray,
+            status_cancelled: Color::Red,
+            popup_bg: Color::Black,
+            error: Color::Red,
+            flash_delete: Color::Red,
+            flash_restore: Color::Blue,
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            focused_border: Color::Blue,
+            unfocused_border: Color::DarkGray,
+            selected_bg: Color::Indexed(153),
+            active_item: Color::Indexed(28),
+            done_text: Color::Gray,
+            normal_text: Color::Black,
+            label_text: Color::DarkGray,
+            highlight_text: Color::Indexed(130),
+            accent: Color::Blue,
+            priority_critical: Color::Red,
+            priority_high: Color::Indexed(166),
+            priority_medium: Color::Indexed(136),
+            priority_low: Color::Black,
+            points: [
+                Color::Blue,
+                Color::Indexed(28),
+                Color::Indexed(136),
+                Color::Magenta,
+                Color::Red,
+            ],
+            status_active: Color::Indexed(28),
+            status_planning: Color::Indexed(136),
+            status_completed: Color::DarkGray,
+            status_cancelled: Color::Red,
+            popup_bg: Color::White,
+            error: Color::Red,
+            flash_delete: Color::LightRed,
+            flash_restore: Color::LightBlue,
+        }
+    }
+
+    pub fn high_contrast() -> Self {
+        Self {
+            focused_border: Color::LightYellow,
+            unfocused_border: Color::Gray,
+            selected_bg: Color::Blue,
+            active_item: Color::LightGreen,
+            done_text: Color::Gray,
+            normal_text: Color::White,
+            label_text: Color::Gray,
+            highlight_text: Color::LightYellow,
+            accent: Color::LightCyan,
+            priority_critical: Color::LightRed,
+            priority_high: Color::LightMagenta,
+            priority_medium: Color::LightYellow,
+            priority_low: Color::White,
+            points: [
+                Color::LightCyan,
+                Color::LightGreen,
+                Color::LightYellow,
+                Color::LightMagenta,
+                Color::LightRed,
+            ],
+            status_active: Color::LightGreen,
+            status_planning: Color::LightYellow,
+            status_completed: Color::Gray,
+            status_cancelled: Color::LightRed,
+            popup_bg: Color::Black,
+            error: Color::LightRed,
+            flash_delete: Color::LightRed,
+            flash_restore: Color::LightBlue,
+        }
+    }
+
+    pub fn colorblind() -> Self {
+        Self::dark().with_colorblind_palette()
+    }
+
+    /// Replace the priority, points and status colors with a palette that
+    /// stays distinguishable without relying on red/green contrast.
+    pub fn with_colorblind_palette(self) -> Self {
+        Self {
+            active_item: OKABE_BLUISH_GREEN,
+            priority_critical: OKABE_VERMILLION,
+            priority_high: OKABE_ORANGE,
+            priority_medium: OKABE_YELLOW,
+            points: [
+                OKABE_SKY_BLUE,
+                OKABE_BLUISH_GREEN,
+                OKABE_YELLOW,
+                OKABE_ORANGE,
+                OKABE_VERMILLION,
+            ],
+            status_active: OKABE_BLUISH_GREEN,
+            status_planning: OKABE_YELLOW,
+            status_cancelled: OKABE_VERMILLION,
+            error: OKABE_VERMILLION,
+            flash_delete: OKABE_VERMILLION,
+            flash_restore: OKABE_BLUE,
+            ..self
+        }
+    }
+
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" => Some(Self::high_contrast()),
+            "colorblind" => Some(Self::colorblind()),
+            _ => None,
+        }
+    }
+
+    /// Set a color role by its theme-file key.
+    pub fn set_role(&mut self, role: &str, color: Color) -> bool {
+        let slot = match role {
+            "focused_border" => &mut self.focused_border,
+            "unfocused_border" => &mut self.unfocused_border,
+            "selected_bg" => &mut self.selected_bg,
+            "active_item" => &mut self.active_item,
+            "done_text" => &mut self.done_text,
+            "normal_text" => &mut self.normal_text,
+            "label_text" => &mut self.label_text,
+            "highlight_text" => &mut self.highlight_text,
+            "accent" => &mut self.accent,
+            "priority_critical" => &mut self.priority_critical,
+            "priority_high" => &mut self.priority_high,
+            "priority_medium" => &mut self.priority_medium,
+            "priority_low" => &mut self.priority_low,
+            "points_1" => &mut self.points[0],
+            "points_2" => &mut self.points[1],
+            "points_3" => &mut self.points[2],
+            "points_4" => &mut self.points[3],
+            "points_5" => &mut self.points[4],
+            "status_active" => &mut self.status_active,
+            "status_planning" => &mut self.status_planning,
+            "status_completed" => &mut self.status_completed,
+            "status_cancelled" => &mut self.status_cancelled,
+            "popup_bg" => &mut self.popup_bg,
+            "error" => &mut self.error,
+            "flash_delete" => &mut self.flash_delete,
+            "flash_restore" => &mut self.flash_restore,
+            _ => return false,
+        };
+        *slot = color;
+        true
+    }
+
+    fn map_colors(self, f: impl Fn(Color) -> Color) -> Self {
+        Self {
+            focused_border: f(self.focused_border),
+            unfocused_border: f(self.unfocused_border),
+            selected_bg: f(self.selected_bg),
+            active_item: f(self.active_item),
+            done_text: f(self.done_text),
+            normal_text: f(self.normal_text),
+            label_text: f(self.label_text),
+            highlight_text: f(self.highlight_text),
+            accent: f(self.accent),
+            priority_critical: f(self.priority_critical),
+            priority_high: f(self.priority_high),
+            priority_medium: f(self.priority_medium),
+            priority_low: f(self.priority_low),
+            points: self.points.map(&f),
+            status_active: f(self.status_active),
+            status_planning: f(self.status_planning),
+            status_completed: f(self.status_completed),
+            status_cancelled: f(self.status_cancelled),
+            popup_bg: f(self.popup_bg),
+            error: f(self.error),
+            flash_delete: f(self.flash_delete),
+            flash_restore: f(self.flash_restore),
+        }
+    }
+
+    /// Downsample colors the terminal cannot display.
+    pub fn for_depth(self, depth: ColorDepth) -> Self {
+        match depth {
+            ColorDepth::TrueColor => self,
+            ColorDepth::Ansi256 => self.map_colors(|c| match c {
+                Color::Rgb(r, g, b) => Color::Indexed(nearest_xterm_index(r, g, b)),
+                other => other,
+            }),
+            ColorDepth::Ansi16 => self.map_colors(|c| match c {
+                Color::Rgb(r, g, b) => nearest_ansi16(r, g, b),
+                Color::Indexed(i) => {
+                    let (r, g, b) = xterm_rgb(i);
+                    nearest_ansi16(r, g, b)
+                }
+                other => other,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorDepth {
+    Ansi16,
+    Ansi256,
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Detect from `COLORTERM`/`TERM`, the convention most terminals follow.
+    pub fn detect() -> Self {
+        Self::from_env(
+            std::env::var("COLORTERM").ok().as_deref(),
+            std::env::var("TERM").ok().as_deref(),
+        )
+    }
+
+    pub fn from_env(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        if matches!(colorterm, Some("truecolor" | "24bit")) {
+            Self::TrueColor
+        } else if term.is_some_and(|t| t.contains("256color")) {
+            Self::Ansi256
+        } else {
+            Self::Ansi16
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Ansi16 => "16 colors",
+            Self::Ansi256 => "256 colors",
+            Self::TrueColor => "truecolor",
+        }
+    }
+}
+
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (128, 0, 0)),
+    (Color::Green, (0, 128, 0)),
+    (Color::Yellow, (128, 128, 0)),
+    (Color::Blue, (0, 0, 128)),
+    (Color::Magenta, (128, 0, 128)),
+    (Color::Cyan, (0, 128, 128)),
+    (Color::Gray, (192, 192, 192)),
+    (Color::DarkGray, (128, 128, 128)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (0, 0, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (i32::from(a) - i32::from(b)).pow(2) as u32;
+    d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+fn xterm_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI16[index as usize].1,
+        16..=231 => {
+            let i = index - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[((i / 6) % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+fn nearest_xterm_index(r: u8, g: u8, b: u8) -> u8 {
+    (16..=255u8)
+        .min_by_key(|i| distance(xterm_rgb(*i), (r, g, b)))
+        .unwrap_or(16)
+}
+
+fn nearest_ansi16(r: u8, g: u8, b: u8) -> Color {
+    ANSI16
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb, (r, g, b)))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::White)
+}
+
+static ACTIVE_THEME: RwLock<Option<Theme>> = RwLock::new(None);
+
+/// The theme used for rendering; dark until [`set_theme`] is called.
+pub fn current() -> Theme {
+    ACTIVE_THEME
+        .read()
+        .ok()
+        .and_then(|t| *t)
+        .unwrap_or_else(Theme::dark)
+}
+
+pub fn set_theme(theme: Theme) {
+    if let Ok(mut active) = ACTIVE_THEME.write() {
+        *active = Some(theme);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_depth_from_env() {
+        assert_eq!(
+            ColorDepth::from_env(Some("truecolor"), Some("xterm")),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            ColorDepth::from_env(None, Some("xterm-256color")),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(
+            ColorDepth::from_env(None, Some("linux")),
+            ColorDepth::Ansi16
+        );
+    }
+
+    #[test]
+    fn test_rgb_downsamples_to_256_and_16_colors() {
+        let theme = Theme {
+            accent: Color::Rgb(255, 0, 0),
+            ..Theme::dark()
+        };
+        assert_eq!(
+            theme.for_depth(ColorDepth::Ansi256).accent,
+            Color::Indexed(196)
+        );
+        assert_eq!(theme.for_depth(ColorDepth::Ansi16).accent, Color::LightRed);
+        assert_eq!(theme.for_depth(ColorDepth::TrueColor), theme);
+    }
+
+    #[test]
+    fn test_indexed_colors_fall_back_on_16_color_terminals() {
+        let light = Theme::light().for_depth(ColorDepth::Ansi16);
+        for color in [light.selected_bg, light.highlight_text, light.points[1]] {
+            assert!(!matches!(color, Color::Indexed(_) | Color::Rgb(..)));
+        }
+    }
+
+    #[test]
+    fn test_colorblind_palette_avoids_red_green_pairs() {
+        let theme = Theme::colorblind();
+        assert_ne!(theme.priority_critical, Color::Red);
+        assert_ne!(theme.status_active, Color::Green);
+        assert_eq!(theme.selected_bg, Theme::dark().selected_bg);
+    }
+
+    #[test]
+    fn test_every_builtin_name_resolves() {
+        for name in BUILTIN_THEMES {
+            assert!(Theme::builtin(name).is_some(), "{name}");
+        }
+        assert!(Theme::builtin("solarized").is_none());
+    }
+}
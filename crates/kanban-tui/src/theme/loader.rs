@@ -0,0 +1,165 @@
+//! Resolving the `theme` config value to a [`Theme`].
+//!
+//! The value is a built-in name, the name of a file in the `themes/`
+//! directory next to the config file, or a path to a TOML theme file:
+//!
+//! ```toml
+//! base = "light"                 # optional, defaults to "dark"
+//! priority_palette = "colorblind" # optional
+//!
+//! [colors]
+//! selected_bg = "#d0e4ff"
+//! highlight_text = "208"
+//! ```
+
+use super::colors::{set_theme, ColorDepth, Theme, BUILTIN_THEMES};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_THEME: &str = "dark";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    base: Option<String>,
+    priority_palette: Option<String>,
+    #[serde(default)]
+    colors: BTreeMap<String, String>,
+}
+
+fn themes_dir() -> Option<PathBuf> {
+    kanban_service::config::config_path().and_then(|p| p.parent().map(|dir| dir.join("themes")))
+}
+
+fn theme_file_path(name: &str, dir: Option<&Path>) -> Option<PathBuf> {
+    let as_path = Path::new(name);
+    if as_path.extension().is_some_and(|e| e == "toml") || as_path.components().count() > 1 {
+        return Some(as_path.to_path_buf());
+    }
+    dir.map(|d| d.join(format!("{}.toml", name)))
+}
+
+/// Load the theme named by the config, without adjusting for color depth.
+pub fn load_theme(name: &str) -> Result<Theme, String> {
+    load_theme_from(name, themes_dir().as_deref())
+}
+
+/// Load the named theme, adapt it to the terminal's color depth and make it
+/// the active theme.
+pub fn activate_theme(name: &str) -> Result<(), String> {
+    let theme = load_theme(name)?;
+    set_theme(theme.for_depth(ColorDepth::detect()));
+    Ok(())
+}
+
+fn load_theme_from(name: &str, dir: Option<&Path>) -> Result<Theme, String> {
+    if let Some(theme) = Theme::builtin(name) {
+        return Ok(theme);
+    }
+    let path = theme_file_path(name, dir).ok_or_else(|| format!("Unknown theme '{}'", name))?;
+    let content = std::fs::read_to_string(&path).map_err(|e| {
+        format!(
+            "Unknown theme '{}': expected one of {} or a theme file ({}: {})",
+            name,
+            BUILTIN_THEMES.join(", "),
+            path.display(),
+            e
+        )
+    })?;
+    parse_theme_file(&content).map_err(|e| format!("Invalid theme {}: {}", path.display(), e))
+}
+
+fn parse_theme_file(content: &str) -> Result<Theme, String> {
+    let file: ThemeFile = toml::from_str(content).map_err(|e| e.to_string())?;
+    let base = file.base.as_deref().unwrap_or(DEFAULT_THEME);
+    let mut theme = Theme::builtin(base).ok_or_else(|| format!("unknown base theme '{}'", base))?;
+    match file.priority_palette.as_deref() {
+        None | Some("default") => {}
+        Some("colorblind") => theme = theme.with_colorblind_palette(),
+        Some(other) => return Err(format!("unknown priority_palette '{}'", other)),
+    }
+    for (role, value) in &file.colors {
+        let color = value
+            .parse()
+            .map_err(|_| format!("invalid color '{}' for {}", value, role))?;
+        if !theme.set_role(role, color) {
+            return Err(format!("unknown color role '{}'", role));
+        }
+    }
+    Ok(theme)
+}
+
+/// Built-in theme names followed by any theme files found in the themes
+/// directory, in the order the settings view cycles through them.
+pub fn available_themes() -> Vec<String> {
+    let mut names: Vec<String> = BUILTIN_THEMES.iter().map(|s| s.to_string()).collect();
+    if let Some(entries) = themes_dir().and_then(|d| std::fs::read_dir(d).ok()) {
+        let mut files: Vec<String> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|e| e == "toml"))
+            .filter_map(|p| p.file_stem().map(|s| s.to_string_lossy().into_owned()))
+            .filter(|n| !names.contains(n))
+            .collect();
+        files.sort();
+        names.extend(files);
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Color;
+
+    #[test]
+    fn test_theme_file_overrides_base() {
+        let theme = parse_theme_file(
+            r##"
+base = "light"
+[colors]
+selected_bg = "#102030"
+points_3 = "208"
+"##,
+        )
+        .unwrap();
+        assert_eq!(theme.selected_bg, Color::Rgb(16, 32, 48));
+        assert_eq!(theme.points[2], Color::Indexed(208));
+        assert_eq!(theme.normal_text, Theme::light().normal_text);
+    }
+
+    #[test]
+    fn test_theme_file_can_apply_colorblind_palette() {
+        let theme =
+            parse_theme_file("base = \"light\"\npriority_palette = \"colorblind\"").unwrap();
+        assert_eq!(theme, Theme::light().with_colorblind_palette());
+    }
+
+    #[test]
+    fn test_theme_file_rejects_unknown_roles_and_colors() {
+        assert!(parse_theme_file("[colors]\nborder = \"red\"")
+            .unwrap_err()
+            .contains("border"));
+        assert!(parse_theme_file("[colors]\naccent = \"not-a-color\"")
+            .unwrap_err()
+            .contains("not-a-color"));
+    }
+
+    #[test]
+    fn test_load_theme_from_themes_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("paper.toml"), "base = \"light\"").unwrap();
+        assert_eq!(
+            load_theme_from("paper", Some(dir.path())).unwrap(),
+            Theme::light()
+        );
+        assert_eq!(
+            load_theme_from("high-contrast", None).unwrap(),
+            Theme::high_contrast()
+        );
+        assert!(load_theme_from("missing", Some(dir.path()))
+            .unwrap_err()
+            .contains("missing"));
+    }
+}
@@ -1,5 +1,7 @@
 pub mod colors;
+pub mod loader;
 pub mod styles;
 
-pub use colors::*;
+pub use colors::{current, set_theme, ColorDepth, Theme, BUILTIN_THEMES};
+pub use loader::{activate_theme, available_themes, load_theme, DEFAULT_THEME};
 pub use styles::*;
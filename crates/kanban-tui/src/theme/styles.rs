@@ -1,22 +1,22 @@
-use super::colors::*;
+use super::colors::current;
 use kanban_domain::{CardPriority, SprintStatus};
 use ratatui::style::{Modifier, Style};
 
 pub fn focused_border() -> Style {
-    Style::default().fg(FOCUSED_BORDER)
+    Style::default().fg(current().focused_border)
 }
 
 pub fn unfocused_border() -> Style {
-    Style::default().fg(UNFOCUSED_BORDER)
+    Style::default().fg(current().unfocused_border)
 }
 
 pub fn deleted_view_focused_border() -> Style {
-    Style::default().fg(ratatui::style::Color::Yellow)
+    Style::default().fg(current().highlight_text)
 }
 
 pub fn selected_item(focused: bool) -> Style {
     if focused {
-        Style::default().bg(SELECTED_BG)
+        Style::default().bg(current().selected_bg)
     } else {
         Style::default()
     }
@@ -24,66 +24,65 @@ pub fn selected_item(focused: bool) -> Style {
 
 pub fn active_item() -> Style {
     Style::default()
-        .fg(ACTIVE_ITEM)
+        .fg(current().active_item)
         .add_modifier(Modifier::BOLD)
 }
 
 pub fn done_text() -> Style {
     Style::default()
-        .fg(DONE_TEXT)
+        .fg(current().done_text)
         .add_modifier(Modifier::CROSSED_OUT)
 }
 
 pub fn normal_text() -> Style {
-    Style::default().fg(NORMAL_TEXT)
+    Style::default().fg(current().normal_text)
 }
 
 pub fn label_text() -> Style {
-    Style::default().fg(LABEL_TEXT)
+    Style::default().fg(current().label_text)
 }
 
 pub fn highlight_text() -> Style {
-    Style::default().fg(HIGHLIGHT_TEXT)
+    Style::default().fg(current().highlight_text)
 }
 
 pub fn bold_highlight() -> Style {
     Style::default()
-        .fg(HIGHLIGHT_TEXT)
+        .fg(current().highlight_text)
         .add_modifier(Modifier::BOLD)
 }
 
 pub fn priority_style(priority: CardPriority) -> Style {
+    let theme = current();
     let color = match priority {
-        CardPriority::Critical => PRIORITY_CRITICAL,
-        CardPriority::High => PRIORITY_HIGH,
-        CardPriority::Medium => PRIORITY_MEDIUM,
-        CardPriority::Low => PRIORITY_LOW,
+        CardPriority::Critical => theme.priority_critical,
+        CardPriority::High => theme.priority_high,
+        CardPriority::Medium => theme.priority_medium,
+        CardPriority::Low => theme.priority_low,
     };
     Style::default().fg(color)
 }
 
 pub fn points_style(points: u8) -> Style {
+    let theme = current();
     let color = match points {
-        1 => POINTS_1,
-        2 => POINTS_2,
-        3 => POINTS_3,
-        4 => POINTS_4,
-        5 => POINTS_5,
-        _ => NORMAL_TEXT,
+        1..=5 => theme.points[points as usize - 1],
+        _ => theme.normal_text,
     };
     Style::default().fg(color).add_modifier(Modifier::BOLD)
 }
 
 pub fn sprint_status_style(status: SprintStatus) -> Style {
+    let theme = current();
     let color = match status {
-        SprintStatus::Active => STATUS_ACTIVE,
-        SprintStatus::Planning => STATUS_PLANNING,
-        SprintStatus::Completed => STATUS_COMPLETED,
-        SprintStatus::Cancelled => STATUS_CANCELLED,
+        SprintStatus::Active => theme.status_active,
+        SprintStatus::Planning => theme.status_planning,
+        SprintStatus::Completed => theme.status_completed,
+        SprintStatus::Cancelled => theme.status_cancelled,
     };
     Style::default().fg(color)
 }
 
 pub fn popup_bg() -> Style {
-    Style::default().bg(POPUP_BG)
+    Style::default().bg(current().popup_bg)
 }
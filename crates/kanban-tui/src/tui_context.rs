@@ -2,8 +2,10 @@ use crate::state::SaveCoordinator;
 use kanban_domain::commands::Command;
 use kanban_domain::KanbanResult;
 use kanban_domain::{
-    ArchivedCard, Board, BoardUpdate, Card, CardListFilter, CardSummary, CardUpdate, Column,
-    ColumnUpdate, CreateCardOptions, GraphOperations, KanbanOperations, Sprint, SprintUpdate,
+    ArchivedCard, Attachment, AttachmentGcReport, Board, BoardExportFormat, BoardTemplate,
+    BoardUpdate, Card, CardListFilter, CardSummary, CardUpdate, ChecklistItem, CloneOptions,
+    Column, ColumnUpdate, CreateCardOptions, CsvImportSummary, CsvMapping, ForeignImportOptions,
+    ForeignImportReport, ForeignSource, GraphOperations, KanbanOperations, Sprint, SprintUpdate,
 };
 use kanban_service::backend::KanbanBackend;
 use kanban_service::KanbanContext;
@@ -175,6 +177,25 @@ impl KanbanOperations for TuiContext {
         self.with_flush(r)
     }
 
+    fn create_board_from_template(
+        &mut self,
+        name: String,
+        template: &BoardTemplate,
+    ) -> KanbanResult<Board> {
+        let r = self.inner.create_board_from_template(name, template);
+        self.with_flush(r)
+    }
+
+    fn board_template(
+        &self,
+        board_id: Uuid,
+        template_name: String,
+        include_cards: bool,
+    ) -> KanbanResult<BoardTemplate> {
+        self.inner
+            .board_template(board_id, template_name, include_cards)
+    }
+
     fn create_column(
         &mut self,
         board_id: Uuid,
@@ -295,6 +316,88 @@ impl KanbanOperations for TuiContext {
         self.inner.get_card_git_checkout(id)
     }
 
+    fn add_checklist_item(
+        &mut self,
+        card_id: Uuid,
+        text: String,
+        position: Option<usize>,
+    ) -> KanbanResult<ChecklistItem> {
+        let r = self.inner.add_checklist_item(card_id, text, position);
+        self.with_flush(r)
+    }
+
+    fn update_checklist_item(
+        &mut self,
+        card_id: Uuid,
+        item_id: Uuid,
+        text: Option<String>,
+        done: Option<bool>,
+    ) -> KanbanResult<ChecklistItem> {
+        let r = self
+            .inner
+            .update_checklist_item(card_id, item_id, text, done);
+        self.with_flush(r)
+    }
+
+    fn move_checklist_item(
+        &mut self,
+        card_id: Uuid,
+        item_id: Uuid,
+        position: usize,
+    ) -> KanbanResult<Card> {
+        let r = self.inner.move_checklist_item(card_id, item_id, position);
+        self.with_flush(r)
+    }
+
+    fn remove_checklist_item(&mut self, card_id: Uuid, item_id: Uuid) -> KanbanResult<Card> {
+        let r = self.inner.remove_checklist_item(card_id, item_id);
+        self.with_flush(r)
+    }
+
+    fn convert_checklist_item_to_card(
+        &mut self,
+        card_id: Uuid,
+        item_id: Uuid,
+    ) -> KanbanResult<Card> {
+        let r = self.inner.convert_checklist_item_to_card(card_id, item_id);
+        self.with_flush(r)
+    }
+
+    fn import_checklist_from_description(
+        &mut self,
+        card_id: Uuid,
+    ) -> KanbanResult<Vec<ChecklistItem>> {
+        let r = self.inner.import_checklist_from_description(card_id);
+        self.with_flush(r)
+    }
+
+    fn add_attachment(
+        &mut self,
+        card_id: Uuid,
+        name: String,
+        content: &[u8],
+    ) -> KanbanResult<Attachment> {
+        let r = self.inner.add_attachment(card_id, name, content);
+        self.with_flush(r)
+    }
+
+    fn read_attachment(
+        &self,
+        card_id: Uuid,
+        attachment_id: Uuid,
+    ) -> KanbanResult<(Attachment, Vec<u8>)> {
+        self.inner.read_attachment(card_id, attachment_id)
+    }
+
+    fn remove_attachment(&mut self, card_id: Uuid, attachment_id: Uuid) -> KanbanResult<Card> {
+        let r = self.inner.remove_attachment(card_id, attachment_id);
+        self.with_flush(r)
+    }
+
+    fn collect_attachment_garbage(&mut self, dry_run: bool) -> KanbanResult<AttachmentGcReport> {
+        self.inner.collect_attachment_garbage(dry_run)
+    }
+
     fn archive_cards(&mut self, ids: Vec<Uuid>) -> KanbanResult<usize> {
         let r = self.inner.archive_cards(ids);
         self.with_flush(r)
@@ -376,10 +479,53 @@ impl KanbanOperations for TuiContext {
         self.inner.export_board(board_id)
     }
 
+    fn export_board_as(
+        &self,
+        board_id: Option<Uuid>,
+        format: BoardExportFormat,
+    ) -> KanbanResult<String> {
+        self.inner.export_board_as(board_id, format)
+    }
+
     fn import_board(&mut self, data: &str) -> KanbanResult<Board> {
         let r = self.inner.import_board(data);
         self.with_flush(r)
     }
+
+    fn import_board_as_copy(&mut self, data: &str) -> KanbanResult<Board> {
+        let r = self.inner.import_board_as_copy(data);
+        self.with_flush(r)
+    }
+
+    fn clone_board(
+        &mut self,
+        board_id: Uuid,
+        name: String,
+        options: CloneOptions,
+    ) -> KanbanResult<Board> {
+        let r = self.inner.clone_board(board_id, name, options);
+        self.with_flush(r)
+    }
+
+    fn import_cards_csv(
+        &mut self,
+        board_id: Option<Uuid>,
+        data: &str,
+        mapping: &CsvMapping,
+    ) -> KanbanResult<CsvImportSummary> {
+        let r = self.inner.import_cards_csv(board_id, data, mapping);
+        self.with_flush(r)
+    }
+
+    fn import_foreign(
+        &mut self,
+        source: ForeignSource,
+        data: &str,
+        options: ForeignImportOptions,
+    ) -> KanbanResult<ForeignImportReport> {
+        let r = self.inner.import_foreign(source, data, options);
+        self.with_flush(r)
+    }
 }
 
 impl GraphOperations for TuiContext {
@@ -1,17 +1,21 @@
 use crate::app::{App, BoardFocus};
 use crate::components::*;
+use crate::markdown_renderer::write_hyperlinks;
 use crate::theme::*;
 use kanban_core::pagination::scroll_offset_to_keep_visible;
 use kanban_domain::{Sprint, SprintStatus};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::Paragraph,
     Frame,
 };
 
-pub(super) fn render_board_detail_view(app: &App, frame: &mut Frame, area: Rect) {
+/// Renders the board detail view and returns where the description text
+/// was drawn, for the mouse to find card references in.
+pub(super) fn render_board_detail_view(app: &App, frame: &mut Frame, area: Rect) -> Option<Rect> {
+    let mut description_area = None;
     if let Some(board_idx) = app.selection.board.get() {
         if let Some(board) = app.model.boards().get(board_idx) {
             let chunks = Layout::default()
@@ -26,12 +30,13 @@ pub(super) fn render_board_detail_view(app: &App, frame: &mut Frame, area: Rect)
                 .split(area);
 
             render_board_name_field(app, board, frame, chunks[0]);
-            render_board_description_field(app, board, frame, chunks[1]);
+            description_area = Some(render_board_description_field(app, board, frame, chunks[1]));
             render_board_settings_section(app, board, frame, chunks[2]);
             render_board_sprints_list(app, board, frame, chunks[3]);
             render_board_columns_list(app, board, frame, chunks[4]);
         }
     }
+    description_area
 }
 
 fn render_board_name_field(app: &App, board: &kanban_domain::Board, frame: &mut Frame, area: Rect) {
@@ -44,22 +49,22 @@ fn render_board_name_field(app: &App, board: &kanban_domain::Board, frame: &mut
     frame.render_widget(name, area);
 }
 
+/// Renders the description field. Returns the area inside its border.
 fn render_board_description_field(
     app: &App,
     board: &kanban_domain::Board,
     frame: &mut Frame,
     area: Rect,
-) {
+) -> Rect {
     let desc_config = FieldSectionConfig::new("Description")
         .with_focus_indicator("Description [2]")
         .focused(app.focus.board_focus == BoardFocus::Description);
-    let desc_lines = if let Some(desc_text) = &board.description {
-        crate::markdown_renderer::render_markdown(desc_text)
-    } else {
-        vec![Line::from(Span::styled("No description", label_text()))]
-    };
-    let desc = Paragraph::new(desc_lines).block(desc_config.block());
-    frame.render_widget(desc, area);
+    let block = desc_config.block();
+    let inner = block.inner(area);
+    let description = app.render_description(board.description.as_deref(), inner.width);
+    frame.render_widget(Paragraph::new(description.lines).block(block), area);
+    write_hyperlinks(frame.buffer_mut(), inner, &description.links);
+    inner
 }
 
 fn render_board_settings_section(
@@ -105,7 +110,7 @@ fn render_board_settings_section(
         settings_lines.push(metadata_line_styled(
             "Active Sprint Card Prefix",
             sprint_prefix,
-            Style::default().fg(Color::Cyan),
+            Style::default().fg(current().accent),
         ));
     }
 
@@ -139,8 +144,6 @@ fn render_board_sprints_list(
     frame: &mut Frame,
     area: Rect,
 ) {
-    use crate::theme::colors::SELECTED_BG;
-
     let sprints_config = FieldSectionConfig::new("Sprints")
         .with_focus_indicator("Sprints [4]")
         .focused(app.focus.board_focus == BoardFocus::Sprints);
@@ -184,7 +187,7 @@ fn render_board_sprints_list(
 
             let mut base_style = normal_text();
             if is_selected && is_focused {
-                base_style = base_style.bg(SELECTED_BG);
+                base_style = base_style.bg(current().selected_bg);
             }
 
             let mut spans = vec![
@@ -199,15 +202,17 @@ fn render_board_sprints_list(
             if is_active_sprint {
                 let mut active_style = active_item();
                 if is_selected && is_focused {
-                    active_style = active_style.bg(SELECTED_BG);
+                    active_style = active_style.bg(current().selected_bg);
                 }
                 spans.push(Span::styled(" Active", active_style));
             }
 
             if is_ended {
-                let mut ended_style = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+                let mut ended_style = Style::default()
+                    .fg(current().error)
+                    .add_modifier(Modifier::BOLD);
                 if is_selected && is_focused {
-                    ended_style = ended_style.bg(SELECTED_BG);
+                    ended_style = ended_style.bg(current().selected_bg);
                 }
                 spans.push(Span::styled(" Ended", ended_style));
             }
@@ -236,8 +241,6 @@ fn render_board_columns_list(
     frame: &mut Frame,
     area: Rect,
 ) {
-    use crate::theme::colors::SELECTED_BG;
-
     let columns_config = FieldSectionConfig::new("Columns")
         .with_focus_indicator("Columns [5]")
         .focused(app.focus.board_focus == BoardFocus::Columns);
@@ -270,7 +273,7 @@ fn render_board_columns_list(
 
             let mut base_style = normal_text();
             if is_selected && is_focused {
-                base_style = base_style.bg(SELECTED_BG);
+                base_style = base_style.bg(current().selected_bg);
             }
 
             let spans = vec![
@@ -1,5 +1,6 @@
 use crate::app::{App, CardFocus};
 use crate::components::*;
+use crate::markdown_renderer::write_hyperlinks;
 use crate::theme::*;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -10,6 +11,13 @@ use uuid::Uuid;
 
 const RELATIONSHIP_BOX_HEIGHT: u16 = 7;
 const RELATIONSHIP_VIEWPORT_BORDER_HEIGHT: usize = 2;
+const GIT_BOX_HEIGHT: u16 = 7;
+/// Checklist items shown before the box scrolls off into an overflow line.
+const CHECKLIST_VISIBLE_ITEMS: usize = 6;
+/// Attachments listed at once; the list scrolls with the selection.
+const ATTACHMENT_VISIBLE_ITEMS: usize = 4;
+/// Commits shown below the branch line, leaving room for the overflow line.
+const GIT_VISIBLE_COMMITS: usize = 3;
 
 pub(super) fn render_relationship_boxes(
     app: &App,
@@ -18,6 +26,7 @@ pub(super) fn render_relationship_boxes(
     parents: &[Uuid],
     children: &[Uuid],
     child_count: usize,
+    focus: Option<CardFocus>,
 ) {
     let relationship_chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -31,13 +40,13 @@ pub(super) fn render_relationship_boxes(
     // Render Parents section
     let parents_config = FieldSectionConfig::new("Parents")
         .with_focus_indicator("Parents [4]")
-        .focused(app.focus.card_focus == CardFocus::Parents);
+        .focused(focus == Some(CardFocus::Parents));
     let all_cards: Vec<kanban_domain::Card> = app.model.cards().to_vec();
     let parents_lines = render_relationship_section(
         parents,
         &all_cards,
         "Parents",
-        app.focus.card_focus == CardFocus::Parents,
+        focus == Some(CardFocus::Parents),
         &app.relationship.parents_list,
         viewport_height,
     );
@@ -49,12 +58,12 @@ pub(super) fn render_relationship_boxes(
     let children_title_focused = format!("Children ({}) [5]", child_count);
     let children_config = FieldSectionConfig::new(&children_title)
         .with_focus_indicator(&children_title_focused)
-        .focused(app.focus.card_focus == CardFocus::Children);
+        .focused(focus == Some(CardFocus::Children));
     let children_lines = render_relationship_section(
         children,
         &all_cards,
         "Children",
-        app.focus.card_focus == CardFocus::Children,
+        focus == Some(CardFocus::Children),
         &app.relationship.children_list,
         viewport_height,
     );
@@ -62,108 +71,204 @@ pub(super) fn render_relationship_boxes(
     frame.render_widget(children_widget, relationship_chunks[1]);
 }
 
-pub(super) fn render_card_detail_view(app: &App, frame: &mut Frame, area: Rect) {
-    if let Some(card) = app.get_card_for_detail_view() {
-        let card = &card;
-        if let Some(board_idx) = app.selection.active_board_index {
-            if let Some(board) = app.model.boards().get(board_idx) {
-                let has_sprint_logs = !card.sprint_logs.is_empty();
-                let card_id = card.id;
-
-                // Get parent and child information
-                let parents = app.model.graph().parents(card_id);
-                let children = app.model.graph().children(card_id);
-                let child_count = children.len();
-
-                let constraints = vec![
-                    Constraint::Length(5),                       // Title
-                    Constraint::Length(6),                       // Metadata
-                    Constraint::Min(5),                          // Description
-                    Constraint::Length(RELATIONSHIP_BOX_HEIGHT), // Relationships
-                ];
-
-                let chunks = Layout::default()
-                    .direction(Direction::Vertical)
-                    .constraints(constraints)
-                    .split(area);
-
-                // Render title section
-                let title_config = FieldSectionConfig::new("Task Title")
-                    .with_focus_indicator("Task Title [1]")
-                    .focused(app.focus.card_focus == CardFocus::Title);
-                let title = Paragraph::new(build_title_lines(card))
-                    .style(bold_highlight())
-                    .block(title_config.block());
-                frame.render_widget(title, chunks[0]);
-
-                if has_sprint_logs {
-                    let meta_chunks = Layout::default()
-                        .direction(Direction::Horizontal)
-                        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-                        .split(chunks[1]);
-
-                    // Render metadata
-                    let meta_config = FieldSectionConfig::new("Metadata")
-                        .with_focus_indicator("Metadata [2]")
-                        .focused(app.focus.card_focus == CardFocus::Metadata);
-                    let meta_lines =
-                        build_metadata_lines(card, board, app.model.sprints(), &app.app_config);
-                    let meta = Paragraph::new(meta_lines).block(meta_config.block());
-                    frame.render_widget(meta, meta_chunks[0]);
-
-                    // Render sprint logs
-                    let sprint_logs_config = FieldSectionConfig::new("Sprint History");
-                    let sprint_log_lines = build_sprint_logs_lines(card);
-                    let sprint_logs =
-                        Paragraph::new(sprint_log_lines).block(sprint_logs_config.block());
-                    frame.render_widget(sprint_logs, meta_chunks[1]);
-
-                    // Render description
-                    let desc_config = FieldSectionConfig::new("Description")
-                        .with_focus_indicator("Description [3]")
-                        .focused(app.focus.card_focus == CardFocus::Description);
-                    let desc_lines = build_description_lines(card);
-                    let desc = Paragraph::new(desc_lines).block(desc_config.block());
-                    frame.render_widget(desc, chunks[2]);
-
-                    // Render relationship boxes
-                    render_relationship_boxes(
-                        app,
-                        frame,
-                        chunks[3],
-                        &parents,
-                        &children,
-                        child_count,
-                    );
-                } else {
-                    // Render metadata section
-                    let meta_config = FieldSectionConfig::new("Metadata")
-                        .with_focus_indicator("Metadata [2]")
-                        .focused(app.focus.card_focus == CardFocus::Metadata);
-                    let meta_lines =
-                        build_metadata_lines(card, board, app.model.sprints(), &app.app_config);
-                    let meta = Paragraph::new(meta_lines).block(meta_config.block());
-                    frame.render_widget(meta, chunks[1]);
-
-                    // Render description section
-                    let desc_config = FieldSectionConfig::new("Description")
-                        .with_focus_indicator("Description [3]")
-                        .focused(app.focus.card_focus == CardFocus::Description);
-                    let desc_lines = build_description_lines(card);
-                    let desc = Paragraph::new(desc_lines).block(desc_config.block());
-                    frame.render_widget(desc, chunks[2]);
-
-                    // Render relationship boxes
-                    render_relationship_boxes(
-                        app,
-                        frame,
-                        chunks[3],
-                        &parents,
-                        &children,
-                        child_count,
-                    );
-                }
-            }
+/// Renders the card detail view and returns where the description text
+/// was drawn, for the mouse to find card references in.
+pub(super) fn render_card_detail_view(app: &App, frame: &mut Frame, area: Rect) -> Option<Rect> {
+    let card = app.get_card_for_detail_view()?;
+    let board = app.model.boards().get(app.selection.active_board_index?)?;
+    let focus = Some(app.focus.card_focus);
+    Some(render_card_detail(
+        app,
+        &card,
+        board,
+        focus,
+        app.selection.attachment,
+        frame,
+        area,
+    ))
+}
+
+/// Renders the card selected in the list into the preview pane, with no
+/// section focused.
+pub(super) fn render_card_preview(app: &App, frame: &mut Frame, area: Rect) {
+    let selected = app.get_selected_card_id().and_then(|id| {
+        let card = app.model.card(id)?;
+        let board_id = app.board_of_card(id)?;
+        let board = app.model.boards().iter().find(|b| b.id == board_id)?;
+        Some((card, board))
+    });
+    match selected {
+        Some((card, board)) => {
+            render_card_detail(app, card, board, None, 0, frame, area);
+        }
+        None => {
+            let config = FieldSectionConfig::new("Preview");
+            let empty = Paragraph::new("No card selected")
+                .style(label_text())
+                .block(config.block());
+            frame.render_widget(empty, area);
         }
     }
 }
+
+/// Renders a card's sections with `focus` highlighted. Returns the area
+/// inside the description's border.
+fn render_card_detail(
+    app: &App,
+    card: &kanban_domain::Card,
+    board: &kanban_domain::Board,
+    focus: Option<CardFocus>,
+    selected_attachment: usize,
+    frame: &mut Frame,
+    area: Rect,
+) -> Rect {
+    let description_area;
+    let has_sprint_logs = !card.sprint_logs.is_empty();
+    let card_id = card.id;
+
+    // Get parent and child information
+    let parents = app.model.graph().parents(card_id);
+    let children = app.model.graph().children(card_id);
+    let child_count = children.len();
+
+    let mut constraints = vec![
+        Constraint::Length(5), // Title
+        Constraint::Length(6), // Metadata
+        Constraint::Min(5),    // Description
+    ];
+    let checklist_lines = build_checklist_lines(card, CHECKLIST_VISIBLE_ITEMS);
+    if !checklist_lines.is_empty() {
+        // Checklist, plus borders
+        constraints.push(Constraint::Length(checklist_lines.len() as u16 + 2));
+    }
+    let attachment_lines =
+        build_attachment_lines(card, selected_attachment, ATTACHMENT_VISIBLE_ITEMS);
+    let attachments_idx = constraints.len();
+    if !attachment_lines.is_empty() {
+        // Attachments, plus borders
+        constraints.push(Constraint::Length(attachment_lines.len() as u16 + 2));
+    }
+    let relationships_idx = constraints.len();
+    constraints.push(Constraint::Length(RELATIONSHIP_BOX_HEIGHT)); // Relationships
+    let git_status = app
+        .git
+        .status
+        .as_ref()
+        .filter(|(id, _)| *id == card_id)
+        .map(|(_, status)| status);
+    if git_status.is_some() {
+        constraints.push(Constraint::Length(GIT_BOX_HEIGHT)); // Git
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(area);
+
+    // Render title section
+    let title_config = FieldSectionConfig::new("Task Title")
+        .with_focus_indicator("Task Title [1]")
+        .focused(focus == Some(CardFocus::Title));
+    let title = Paragraph::new(build_title_lines(card))
+        .style(bold_highlight())
+        .block(title_config.block());
+    frame.render_widget(title, chunks[0]);
+
+    if has_sprint_logs {
+        let meta_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[1]);
+
+        // Render metadata
+        let meta_config = FieldSectionConfig::new("Metadata")
+            .with_focus_indicator("Metadata [2]")
+            .focused(focus == Some(CardFocus::Metadata));
+        let meta_lines = build_metadata_lines(card, board, app.model.sprints(), &app.app_config);
+        let meta = Paragraph::new(meta_lines).block(meta_config.block());
+        frame.render_widget(meta, meta_chunks[0]);
+
+        // Render sprint logs
+        let sprint_logs_config = FieldSectionConfig::new("Sprint History");
+        let sprint_log_lines = build_sprint_logs_lines(card);
+        let sprint_logs = Paragraph::new(sprint_log_lines).block(sprint_logs_config.block());
+        frame.render_widget(sprint_logs, meta_chunks[1]);
+
+        // Render description
+        description_area = render_description_section(app, card, focus, frame, chunks[2]);
+
+        // Render relationship boxes
+        render_relationship_boxes(
+            app,
+            frame,
+            chunks[relationships_idx],
+            &parents,
+            &children,
+            child_count,
+            focus,
+        );
+    } else {
+        // Render metadata section
+        let meta_config = FieldSectionConfig::new("Metadata")
+            .with_focus_indicator("Metadata [2]")
+            .focused(focus == Some(CardFocus::Metadata));
+        let meta_lines = build_metadata_lines(card, board, app.model.sprints(), &app.app_config);
+        let meta = Paragraph::new(meta_lines).block(meta_config.block());
+        frame.render_widget(meta, chunks[1]);
+
+        // Render description section
+        description_area = render_description_section(app, card, focus, frame, chunks[2]);
+
+        // Render relationship boxes
+        render_relationship_boxes(
+            app,
+            frame,
+            chunks[relationships_idx],
+            &parents,
+            &children,
+            child_count,
+            focus,
+        );
+    }
+
+    if let Some(progress) = card.checklist_progress() {
+        let checklist_title = format!("Checklist ({})", progress);
+        let checklist_config = FieldSectionConfig::new(&checklist_title);
+        let checklist = Paragraph::new(checklist_lines).block(checklist_config.block());
+        frame.render_widget(checklist, chunks[3]);
+    }
+
+    if !attachment_lines.is_empty() {
+        let attachments_title = format!("Attachments ({})", card.attachments.len());
+        let attachments_config = FieldSectionConfig::new(&attachments_title);
+        let attachments = Paragraph::new(attachment_lines).block(attachments_config.block());
+        frame.render_widget(attachments, chunks[attachments_idx]);
+    }
+
+    if let Some(status) = git_status {
+        let git_config = FieldSectionConfig::new("Git");
+        let git_lines = build_git_lines(status, GIT_VISIBLE_COMMITS);
+        let git = Paragraph::new(git_lines).block(git_config.block());
+        frame.render_widget(git, chunks[relationships_idx + 1]);
+    }
+    description_area
+}
+
+/// Renders the description section. Returns the area inside its border.
+fn render_description_section(
+    app: &App,
+    card: &kanban_domain::Card,
+    focus: Option<CardFocus>,
+    frame: &mut Frame,
+    area: Rect,
+) -> Rect {
+    let desc_config = FieldSectionConfig::new("Description")
+        .with_focus_indicator("Description [3]")
+        .focused(focus == Some(CardFocus::Description));
+    let block = desc_config.block();
+    let inner = block.inner(area);
+    let description = app.render_description(card.description.as_deref(), inner.width);
+    frame.render_widget(Paragraph::new(description.lines).block(block), area);
+    write_hyperlinks(frame.buffer_mut(), inner, &description.links);
+    inner
+}
@@ -3,7 +3,7 @@ use crate::components::*;
 use crate::theme::*;
 use ratatui::{
     layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::Paragraph,
     Frame,
@@ -39,10 +39,10 @@ pub(crate) fn render_export_boards_popup(app: &App, frame: &mut Frame) {
                     };
                     let style = if i == dialog.cursor {
                         Style::default()
-                            .fg(Color::Yellow)
+                            .fg(current().highlight_text)
                             .add_modifier(Modifier::BOLD)
                     } else {
-                        Style::default().fg(Color::White)
+                        Style::default().fg(current().normal_text)
                     };
                     Line::from(Span::styled(format!("{}{}", checkbox, board.name), style))
                 })
@@ -53,7 +53,7 @@ pub(crate) fn render_export_boards_popup(app: &App, frame: &mut Frame) {
 
             let hint = Paragraph::new(Line::from(vec![Span::styled(
                 "Space: toggle | a: all | Enter: next | Esc: cancel",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(current().label_text),
             )]));
             frame.render_widget(hint, chunks[1]);
         }
@@ -73,49 +73,40 @@ pub(crate) fn render_export_boards_popup(app: &App, frame: &mut Frame) {
                 .split(inner);
 
             let filename_label = Paragraph::new(Line::from(vec![
-                Span::styled("Filename: ", Style::default().fg(Color::Cyan)),
-                Span::styled(&dialog.filename, Style::default().fg(Color::White)),
-                Span::styled("_", Style::default().fg(Color::Yellow)),
+                Span::styled("Filename: ", Style::default().fg(current().accent)),
+                Span::styled(&dialog.filename, Style::default().fg(current().normal_text)),
+                Span::styled("_", Style::default().fg(current().highlight_text)),
             ]));
             frame.render_widget(filename_label, chunks[0]);
 
             frame.render_widget(Paragraph::new(""), chunks[1]);
 
-            let json_style = if dialog.format == ExportFormat::Json {
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
-            } else {
-                Style::default().fg(Color::White)
-            };
-            let sqlite_style = if dialog.format == ExportFormat::Sqlite {
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
-            } else {
-                Style::default().fg(Color::White)
-            };
-            let json_radio = if dialog.format == ExportFormat::Json {
-                "(*)"
-            } else {
-                "( )"
-            };
-            let sqlite_radio = if dialog.format == ExportFormat::Sqlite {
-                "(*)"
-            } else {
-                "( )"
-            };
-
-            let format_line = Paragraph::new(Line::from(vec![
-                Span::styled("Format: ", Style::default().fg(Color::Cyan)),
-                Span::styled(format!("{} JSON  ", json_radio), json_style),
-                Span::styled(format!("{} SQLite", sqlite_radio), sqlite_style),
-            ]));
+            let mut format_spans = vec![Span::styled(
+                "Format: ",
+                Style::default().fg(current().accent),
+            )];
+            for format in ExportFormat::ALL {
+                let (radio, style) = if dialog.format == format {
+                    (
+                        "(*)",
+                        Style::default()
+                            .fg(current().highlight_text)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    ("( )", Style::default().fg(current().normal_text))
+                };
+                format_spans.push(Span::styled(
+                    format!("{} {}  ", radio, format.label()),
+                    style,
+                ));
+            }
+            let format_line = Paragraph::new(Line::from(format_spans));
             frame.render_widget(format_line, chunks[2]);
 
             let hint = Paragraph::new(Line::from(vec![Span::styled(
                 "Tab: format | Enter: export | Esc: back",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(current().label_text),
             )]));
             frame.render_widget(hint, chunks[3]);
         }
@@ -123,12 +114,26 @@ pub(crate) fn render_export_boards_popup(app: &App, frame: &mut Frame) {
 }
 
 pub(crate) fn render_create_board_popup(app: &App, frame: &mut Frame) {
-    render_input_popup(
+    let footer = app.dialog_input.selected_board_template().map(|name| {
+        Line::from(vec![
+            Span::styled("Template: ", Style::default().fg(current().accent)),
+            Span::styled(
+                format!("< {} >", name),
+                Style::default().fg(current().highlight_text),
+            ),
+            Span::styled(
+                " (Tab/Shift+Tab)",
+                Style::default().fg(current().label_text),
+            ),
+        ])
+    });
+    render_input_popup_with_footer(
         frame,
         "Create New Project",
         "Project Name:",
         app.input.as_str(),
         app.input.cursor_byte_offset(),
+        footer,
     );
 }
 
@@ -142,11 +147,62 @@ pub(crate) fn render_rename_board_popup(app: &App, frame: &mut Frame) {
     );
 }
 
+pub(crate) fn render_clone_board_popup(app: &App, frame: &mut Frame) {
+    let inner = render_popup_with_block(frame, "Clone Project", 60, 30);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(2),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+        ])
+        .split(inner);
+
+    let label = Paragraph::new("New Project Name:").style(highlight_text());
+    frame.render_widget(label, chunks[0]);
+
+    let cursor = app.input.cursor_byte_offset();
+    let (before, after) = app.input.as_str().split_at(cursor);
+    let name = Paragraph::new(Line::from(vec![
+        Span::styled(before, Style::default().fg(current().normal_text)),
+        Span::styled("_", Style::default().fg(current().highlight_text)),
+        Span::styled(after, Style::default().fg(current().normal_text)),
+    ]));
+    frame.render_widget(name, chunks[1]);
+
+    let options = &app.dialog_input.clone_options;
+    let checkbox = |checked: bool, text: &str| {
+        let mark = if checked { "[x] " } else { "[ ] " };
+        Paragraph::new(Line::from(Span::styled(
+            format!("{}{}", mark, text),
+            Style::default().fg(current().normal_text),
+        )))
+    };
+    frame.render_widget(
+        checkbox(options.include_cards, "Include cards (Tab)"),
+        chunks[2],
+    );
+    frame.render_widget(
+        checkbox(options.include_sprints, "Include sprints (Shift+Tab)"),
+        chunks[3],
+    );
+
+    let hint = Paragraph::new(Line::from(Span::styled(
+        "Columns are always copied | Enter: clone | Esc: cancel",
+        Style::default().fg(current().label_text),
+    )));
+    frame.render_widget(hint, chunks[4]);
+}
+
 pub(crate) fn render_export_board_popup(app: &App, frame: &mut Frame) {
     render_input_popup(
         frame,
         "Export Project",
-        "Filename:",
+        "Filename (.json, .md or .csv):",
         app.input.as_str(),
         app.input.cursor_byte_offset(),
     );
@@ -156,7 +212,7 @@ pub(crate) fn render_export_all_popup(app: &App, frame: &mut Frame) {
     render_input_popup(
         frame,
         "Export All Projects",
-        "Filename:",
+        "Filename (.json, .md or .csv):",
         app.input.as_str(),
         app.input.cursor_byte_offset(),
     );
@@ -171,7 +227,8 @@ pub(crate) fn render_import_board_popup(app: &App, frame: &mut Frame) {
         .constraints([Constraint::Length(1), Constraint::Min(0)])
         .split(inner);
 
-    let label = Paragraph::new("Select a JSON file to import:").style(highlight_text());
+    let label = Paragraph::new("Select a JSON or CSV file to import (c: import as a copy):")
+        .style(highlight_text());
     frame.render_widget(label, chunks[0]);
 
     if app.dialog_input.import_files.is_empty() {
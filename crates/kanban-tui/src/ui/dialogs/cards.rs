@@ -1,8 +1,9 @@
 use crate::app::App;
 use crate::components::*;
+use crate::theme::current;
 use ratatui::{
     layout::{Constraint, Direction, Layout},
-    style::{Color, Style},
+    style::Style,
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
@@ -30,7 +31,7 @@ pub(crate) fn render_create_card_popup(app: &App, frame: &mut Frame) {
     let block = Block::default()
         .title("Create New Task")
         .borders(Borders::ALL)
-        .style(Style::default().bg(Color::Black));
+        .style(Style::default().bg(current().popup_bg));
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -47,10 +48,10 @@ pub(crate) fn render_create_card_popup(app: &App, frame: &mut Frame) {
         .split(inner);
 
     let title_focused = app.dialog_input.create_card_focus_is_title();
-    let unfocused_border = Style::default().fg(Color::DarkGray);
+    let unfocused_border = Style::default().fg(current().label_text);
 
     frame.render_widget(
-        Paragraph::new("Task Title:").style(Style::default().fg(Color::Yellow)),
+        Paragraph::new("Task Title:").style(Style::default().fg(current().highlight_text)),
         chunks[0],
     );
 
@@ -73,7 +74,7 @@ pub(crate) fn render_create_card_popup(app: &App, frame: &mut Frame) {
     }
 
     frame.render_widget(
-        Paragraph::new("Sprint:").style(Style::default().fg(Color::Yellow)),
+        Paragraph::new("Sprint:").style(Style::default().fg(current().highlight_text)),
         chunks[2],
     );
 
@@ -105,6 +106,16 @@ pub(crate) fn render_set_card_points_popup(app: &App, frame: &mut Frame) {
     );
 }
 
+pub(crate) fn render_add_attachment_popup(app: &App, frame: &mut Frame) {
+    render_input_popup(
+        frame,
+        "Attach File",
+        "Path to file:",
+        app.input.as_str(),
+        app.input.cursor_byte_offset(),
+    );
+}
+
 pub(crate) fn render_set_card_priority_popup(app: &App, frame: &mut Frame) {
     use crate::components::{PriorityDialog, SelectionDialog};
     let dialog = PriorityDialog;
@@ -141,7 +152,7 @@ pub(crate) fn render_assign_multiple_cards_popup(app: &App, frame: &mut Frame) {
             app.multi_select.selected_cards.len()
         ))
         .borders(Borders::ALL)
-        .style(Style::default().bg(Color::Black));
+        .style(Style::default().bg(current().popup_bg));
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -153,7 +164,7 @@ pub(crate) fn render_assign_multiple_cards_popup(app: &App, frame: &mut Frame) {
         .split(inner);
 
     frame.render_widget(
-        Paragraph::new("Select sprint:").style(Style::default().fg(Color::Yellow)),
+        Paragraph::new("Select sprint:").style(Style::default().fg(current().highlight_text)),
         chunks[0],
     );
 
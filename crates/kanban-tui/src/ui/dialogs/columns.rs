@@ -3,7 +3,7 @@ use crate::components::*;
 use crate::theme::*;
 use ratatui::{
     layout::{Constraint, Direction, Layout},
-    style::{Color, Style},
+    style::Style,
     widgets::{Block, Borders, Clear, ListItem, Paragraph},
     Frame,
 };
@@ -35,7 +35,7 @@ pub(crate) fn render_delete_column_confirm_popup(_app: &App, frame: &mut Frame)
     let block = Block::default()
         .title("Delete Column")
         .borders(Borders::ALL)
-        .style(Style::default().bg(Color::Black));
+        .style(Style::default().bg(current().popup_bg));
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -47,7 +47,7 @@ pub(crate) fn render_delete_column_confirm_popup(_app: &App, frame: &mut Frame)
         .split(inner);
 
     let message = Paragraph::new("Are you sure you want to delete this column?\nAll cards will be moved to the first column.")
-        .style(Style::default().fg(Color::Yellow));
+        .style(Style::default().fg(current().highlight_text));
     frame.render_widget(message, chunks[0]);
 
     let confirm_text =
@@ -0,0 +1,72 @@
+use crate::app::App;
+use crate::components::*;
+use crate::theme::*;
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+pub(crate) fn render_command_palette_popup(app: &App, frame: &mut Frame) {
+    let palette = &app.ui_state.command_palette;
+    let inner = render_popup_with_block(frame, "Command Palette", 60, 60);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+        ])
+        .split(inner);
+
+    let prompt = Line::from(vec![
+        Span::styled(": ", highlight_text()),
+        Span::styled(palette.query(), normal_text()),
+    ]);
+    frame.render_widget(Paragraph::new(prompt), chunks[0]);
+    frame.set_cursor_position((
+        chunks[0].x + 2 + palette.input.cursor_byte_offset() as u16,
+        chunks[0].y,
+    ));
+
+    let list_area = chunks[2];
+    if palette.matches.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No matching commands").style(label_text()),
+            list_area,
+        );
+        return;
+    }
+
+    let height = list_area.height as usize;
+    let offset = (palette.selected + 1).saturating_sub(height);
+    let width = list_area.width as usize;
+    let lines: Vec<Line> = palette
+        .matches
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(height)
+        .filter_map(|(pos, &idx)| palette.entries.get(idx).map(|e| (pos, e)))
+        .map(|(pos, entry)| {
+            let selected = pos == palette.selected;
+            let key_width = entry.key.chars().count();
+            let label: String = entry
+                .label
+                .chars()
+                .take(width.saturating_sub(key_width + 3))
+                .collect();
+            let padding = width.saturating_sub(label.chars().count() + key_width + 2);
+            let base = selected_item(selected);
+            Line::from(vec![
+                Span::styled(format!(" {}", label), normal_text().patch(base)),
+                Span::styled(" ".repeat(padding), base),
+                Span::styled(format!("{} ", entry.key), highlight_text().patch(base)),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), list_area);
+}
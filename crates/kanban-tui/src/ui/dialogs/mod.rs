@@ -1,9 +1,13 @@
 mod boards;
 mod cards;
 mod columns;
+mod command_palette;
 mod sprints;
+mod text_editor;
 
 pub(super) use boards::*;
 pub(super) use cards::*;
 pub(super) use columns::*;
+pub(super) use command_palette::*;
 pub(super) use sprints::*;
+pub(super) use text_editor::*;
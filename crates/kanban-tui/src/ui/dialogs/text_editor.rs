@@ -0,0 +1,87 @@
+use crate::app::App;
+use crate::components::*;
+use crate::text_editor::{cursor_row_column, wrap_lines, ViMode};
+use crate::theme::*;
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+use std::ops::Range;
+
+pub(crate) fn render_text_editor_popup(app: &App, frame: &mut Frame) {
+    let editor = &app.ui_state.text_editor;
+    let Some(target) = editor.target else {
+        return;
+    };
+    let height_percent = if target.is_single_line() { 30 } else { 70 };
+    let inner = render_popup_with_block(frame, target.title(), 70, height_percent);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner);
+    let area = chunks[0];
+
+    // Leave a column free so the cursor fits after a full line.
+    let width = area.width.saturating_sub(1).max(1) as usize;
+    editor.wrap_width.set(width);
+    let text = editor.input.as_str();
+    let lines = wrap_lines(text, width);
+    let (row, column) = cursor_row_column(text, &lines, editor.input.cursor_byte_offset());
+
+    let height = (area.height as usize).max(1);
+    let mut scroll = editor.scroll.get().min(row);
+    if row >= scroll + height {
+        scroll = row + 1 - height;
+    }
+    editor.scroll.set(scroll);
+
+    let selection = editor.input.selection();
+    let visible: Vec<Line> = lines
+        .iter()
+        .skip(scroll)
+        .take(height)
+        .map(|line| styled_line(text, line.clone(), selection.clone()))
+        .collect();
+    frame.render_widget(Paragraph::new(visible), area);
+    frame.set_cursor_position((area.x + column as u16, area.y + (row - scroll) as u16));
+
+    let mode = match editor.vi {
+        Some(ViMode::Normal) => "NORMAL  ",
+        Some(ViMode::Insert) => "INSERT  ",
+        Some(ViMode::Visual) => "VISUAL  ",
+        None => "",
+    };
+    let line_number = text[..editor.input.cursor_byte_offset()]
+        .matches('\n')
+        .count()
+        + 1;
+    let status = Line::from(vec![
+        Span::styled(mode, highlight_text()),
+        Span::styled(
+            format!(
+                "Ln {}  Ctrl+s save  Esc cancel  Ctrl+o $EDITOR",
+                line_number
+            ),
+            label_text(),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(status), chunks[1]);
+}
+
+/// One visual line with the selected part highlighted.
+fn styled_line(text: &str, line: Range<usize>, selection: Option<Range<usize>>) -> Line<'_> {
+    let Some(selection) = selection else {
+        return Line::from(Span::styled(&text[line], normal_text()));
+    };
+    let start = selection.start.clamp(line.start, line.end);
+    let end = selection.end.clamp(line.start, line.end);
+    Line::from(vec![
+        Span::styled(&text[line.start..start], normal_text()),
+        Span::styled(&text[start..end], selected_item(true)),
+        Span::styled(&text[end..line.end], normal_text()),
+    ])
+}
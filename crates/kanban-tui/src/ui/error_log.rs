@@ -1,10 +1,11 @@
 use crate::app::App;
 use crate::components::centered_rect;
 use crate::error_log::LogLevel;
+use crate::theme::current;
 use kanban_persistence::PersistenceMetadata;
 use ratatui::{
     layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
@@ -136,7 +137,7 @@ pub fn render_error_log_popup(app: &App, frame: &mut Frame) {
     let block = Block::default()
         .title(" Diagnostics [F12] ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(Style::default().fg(current().accent));
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -163,7 +164,7 @@ pub fn render_error_log_popup(app: &App, frame: &mut Frame) {
         .map(|row| {
             let value_style = if row.warn {
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(current().highlight_text)
                     .add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
@@ -175,7 +176,7 @@ pub fn render_error_log_popup(app: &App, frame: &mut Frame) {
                 Span::styled(
                     format!(" {:<10}", row.label),
                     Style::default()
-                        .fg(Color::Cyan)
+                        .fg(current().accent)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(row.value, value_style),
@@ -189,11 +190,11 @@ pub fn render_error_log_popup(app: &App, frame: &mut Frame) {
     // entries below. The title carries the section header + entry count.
     let log_block = Block::default()
         .borders(Borders::TOP)
-        .border_style(Style::default().fg(Color::DarkGray))
+        .border_style(Style::default().fg(current().label_text))
         .title(Span::styled(
             format!(" Log entries ({total}) "),
             Style::default()
-                .fg(Color::Cyan)
+                .fg(current().accent)
                 .add_modifier(Modifier::BOLD),
         ));
     let log_inner = log_block.inner(chunks[2]);
@@ -211,8 +212,8 @@ pub fn render_error_log_popup(app: &App, frame: &mut Frame) {
             .take(viewport_height)
             .map(|entry| {
                 let (label, color) = match entry.level {
-                    LogLevel::Error => ("[ERROR]", Color::Red),
-                    LogLevel::Warn => (" [WARN]", Color::Yellow),
+                    LogLevel::Error => ("[ERROR]", current().error),
+                    LogLevel::Warn => (" [WARN]", current().highlight_text),
                 };
                 let ts = entry.timestamp.format("%H:%M:%S").to_string();
                 Line::from(vec![
@@ -235,13 +236,13 @@ pub fn render_error_log_popup(app: &App, frame: &mut Frame) {
     if items_above > 0 || items_below > 0 {
         footer_lines.push(Line::from(Span::styled(
             format!("↑ {items_above} above  ↓ {items_below} below"),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(current().label_text),
         )));
     }
     footer_lines.push(Line::from(Span::styled(
         "ESC/q: close | j/k: scroll",
         Style::default()
-            .fg(Color::DarkGray)
+            .fg(current().label_text)
             .add_modifier(Modifier::ITALIC),
     )));
     frame.render_widget(Paragraph::new(footer_lines), chunks[3]);
@@ -1,3 +1,4 @@
+use super::card_detail;
 use crate::app::{App, AppMode, Focus};
 use crate::components::*;
 use crate::theme::*;
@@ -10,6 +11,34 @@ use ratatui::{
 };
 
 pub(super) fn render_main(app: &mut App, frame: &mut Frame, area: Rect) {
+    let split = app
+        .view
+        .preview
+        .split(area)
+        .filter(|_| app.shows_card_preview());
+    match split {
+        Some((list_area, preview_area)) => {
+            render_lists(app, frame, list_area);
+            app.view.hit_areas.preview = Some(preview_area);
+            card_detail::render_card_preview(app, frame, preview_area);
+        }
+        None => render_lists(app, frame, area),
+    }
+}
+
+/// Renders the card detail view in the preview pane beside the card list
+/// it was opened from. Returns where the description text was drawn.
+pub(super) fn render_main_with_card_detail(
+    app: &mut App,
+    frame: &mut Frame,
+    area: Rect,
+) -> Option<Rect> {
+    let (list_area, detail_area) = app.view.preview.split(area)?;
+    render_lists(app, frame, list_area);
+    card_detail::render_card_detail_view(app, frame, detail_area)
+}
+
+fn render_lists(app: &mut App, frame: &mut Frame, area: Rect) {
     let is_kanban_view = if let Some(idx) = app.selection.active_board_index {
         if let Some(board) = app.model.boards().get(idx) {
             board.task_list_view == kanban_domain::TaskListView::ColumnView
@@ -22,6 +51,7 @@ pub(super) fn render_main(app: &mut App, frame: &mut Frame, area: Rect) {
 
     if is_kanban_view {
         app.view.viewport_height = area.height.saturating_sub(2) as usize;
+        app.view.hit_areas.tasks = Some(area);
         render_tasks(app, frame, area);
     } else {
         let chunks = Layout::default()
@@ -30,6 +60,8 @@ pub(super) fn render_main(app: &mut App, frame: &mut Frame, area: Rect) {
             .split(area);
 
         app.view.viewport_height = chunks[1].height.saturating_sub(2) as usize;
+        app.view.hit_areas.projects = Some(chunks[0]);
+        app.view.hit_areas.tasks = Some(chunks[1]);
         render_projects_panel(app, frame, chunks[0]);
         render_tasks(app, frame, chunks[1]);
     }
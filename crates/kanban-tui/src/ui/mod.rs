@@ -1,7 +1,8 @@
-use crate::app::{App, AppMode, DialogMode};
+use crate::app::{App, AppMode, DialogMode, HitAreas};
+use crate::theme::current;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::Paragraph,
     Frame,
@@ -29,8 +30,8 @@ fn render_save_error_bar(msg: &str, frame: &mut Frame, area: Rect) {
     let text = Line::from(vec![Span::styled(
         format!(" \u{26a0} Save error: {msg} "),
         Style::default()
-            .fg(Color::White)
-            .bg(Color::Red)
+            .fg(current().normal_text)
+            .bg(current().error)
             .add_modifier(Modifier::BOLD),
     )]);
     frame.render_widget(Paragraph::new(text), area);
@@ -39,6 +40,7 @@ fn render_save_error_bar(msg: &str, frame: &mut Frame, area: Rect) {
 pub fn render(app: &mut App, frame: &mut Frame) {
     // Check if we're in Help mode and render underlying view
     let is_help_mode = matches!(app.mode, AppMode::Help(_));
+    app.view.hit_areas = HitAreas::default();
 
     if !is_help_mode {
         let has_save_error = app.save_error.is_some();
@@ -65,8 +67,18 @@ pub fn render(app: &mut App, frame: &mut Frame) {
         // Phase 1: Render base view (from stack if in dialog mode)
         let base_mode = app.get_base_mode();
         match base_mode {
-            AppMode::CardDetail => card_detail::render_card_detail_view(app, frame, main_chunk),
-            AppMode::BoardDetail => board_detail::render_board_detail_view(app, frame, main_chunk),
+            AppMode::CardDetail if app.card_detail_in_preview() => {
+                app.view.hit_areas.description =
+                    main_view::render_main_with_card_detail(app, frame, main_chunk);
+            }
+            AppMode::CardDetail => {
+                app.view.hit_areas.description =
+                    card_detail::render_card_detail_view(app, frame, main_chunk);
+            }
+            AppMode::BoardDetail => {
+                app.view.hit_areas.description =
+                    board_detail::render_board_detail_view(app, frame, main_chunk);
+            }
             AppMode::SprintDetail => {
                 sprint_detail::render_sprint_detail_view(app, frame, main_chunk)
             }
@@ -80,6 +92,7 @@ pub fn render(app: &mut App, frame: &mut Frame) {
         }
 
         crate::components::render_footer(app, frame, footer_chunk);
+        app.view.hit_areas.footer = Some(footer_chunk);
 
         // Phase 2: Render dialog overlay if active
         if let AppMode::Dialog(ref dialog) = app.mode {
@@ -89,10 +102,12 @@ pub fn render(app: &mut App, frame: &mut Frame) {
                 DialogMode::CreateCard => dialogs::render_create_card_popup(app, frame),
                 DialogMode::CreateSprint => dialogs::render_create_sprint_popup(app, frame),
                 DialogMode::RenameBoard => dialogs::render_rename_board_popup(app, frame),
+                DialogMode::CloneBoard => dialogs::render_clone_board_popup(app, frame),
                 DialogMode::ExportBoard => dialogs::render_export_board_popup(app, frame),
                 DialogMode::ExportAll => dialogs::render_export_all_popup(app, frame),
                 DialogMode::ImportBoard => dialogs::render_import_board_popup(app, frame),
                 DialogMode::SetCardPoints => dialogs::render_set_card_points_popup(app, frame),
+                DialogMode::AddAttachment => dialogs::render_add_attachment_popup(app, frame),
                 DialogMode::SetCardPriority => dialogs::render_set_card_priority_popup(app, frame),
                 DialogMode::SetMultipleCardsPriority => {
                     dialogs::render_set_multiple_cards_priority_popup(app, frame)
@@ -117,6 +132,8 @@ pub fn render(app: &mut App, frame: &mut Frame) {
                 }
                 DialogMode::CarryOverSprint => dialogs::render_carry_over_sprint_popup(app, frame),
                 DialogMode::ExportBoards => dialogs::render_export_boards_popup(app, frame),
+                DialogMode::CommandPalette => dialogs::render_command_palette_popup(app, frame),
+                DialogMode::EditText => dialogs::render_text_editor_popup(app, frame),
                 // Component-based popups
                 DialogMode::FilterOptions => {
                     crate::components::render_filter_options_popup(app, frame)
@@ -143,9 +160,14 @@ pub fn render(app: &mut App, frame: &mut Frame) {
         // Help mode: render base view without footer, then help popup
         let base_mode = app.get_base_mode();
         match base_mode {
-            AppMode::CardDetail => card_detail::render_card_detail_view(app, frame, frame.area()),
+            AppMode::CardDetail if app.card_detail_in_preview() => {
+                main_view::render_main_with_card_detail(app, frame, frame.area());
+            }
+            AppMode::CardDetail => {
+                card_detail::render_card_detail_view(app, frame, frame.area());
+            }
             AppMode::BoardDetail => {
-                board_detail::render_board_detail_view(app, frame, frame.area())
+                board_detail::render_board_detail_view(app, frame, frame.area());
             }
             AppMode::SprintDetail => {
                 sprint_detail::render_sprint_detail_view(app, frame, frame.area())
@@ -1,7 +1,8 @@
 use crate::app::App;
+use crate::theme::current;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::Paragraph,
     Frame,
@@ -22,7 +23,13 @@ pub fn render_settings_view(app: &App, frame: &mut Frame, area: Rect) {
 
     render_settings_configuration(app, frame, left_sections[0], &config_location);
     render_settings_config_file(app, frame, left_sections[1], &config_location);
-    render_settings_storage(app, frame, columns[1]);
+    let right_sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(80), Constraint::Percentage(20)])
+        .split(columns[1]);
+
+    render_settings_storage(app, frame, right_sections[0]);
+    render_settings_appearance(app, frame, right_sections[1]);
 }
 
 fn render_settings_configuration(app: &App, frame: &mut Frame, area: Rect, config_location: &str) {
@@ -75,12 +82,12 @@ fn render_settings_configuration(app: &App, frame: &mut Frame, area: Rect, confi
                 config_lines.push(metadata_line_styled(
                     "Storage Backend",
                     &app.config_storage_backend,
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(current().label_text),
                 ));
                 config_lines.push(metadata_line_styled(
                     "Storage Location",
                     &app.config_storage_location,
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(current().label_text),
                 ));
             }
             config_lines.push(metadata_line_selectable(
@@ -153,7 +160,6 @@ fn render_settings_config_file(app: &App, frame: &mut Frame, area: Rect, config_
 fn render_settings_storage(app: &App, frame: &mut Frame, area: Rect) {
     use crate::app::SettingsFocus;
     use crate::components::detail_view::{metadata_line_selectable, FieldSectionConfig};
-    use crate::theme::colors::SELECTED_BG;
 
     let storage_focused = app.focus.settings_focus == SettingsFocus::Storage;
     let storage_section = FieldSectionConfig::new(" Storage ")
@@ -171,14 +177,18 @@ fn render_settings_storage(app: &App, frame: &mut Frame, area: Rect) {
     let instance_id = app.ctx.backend().instance_id().to_string();
     let export_selected = is_storage_selected(3);
     let export_checkbox_style = if export_selected {
-        Style::default().fg(Color::Yellow).bg(SELECTED_BG)
+        Style::default()
+            .fg(current().highlight_text)
+            .bg(current().selected_bg)
     } else {
-        Style::default().fg(Color::Yellow)
+        Style::default().fg(current().highlight_text)
     };
     let export_text_style = if export_selected {
-        Style::default().fg(Color::White).bg(SELECTED_BG)
+        Style::default()
+            .fg(current().normal_text)
+            .bg(current().selected_bg)
     } else {
-        Style::default().fg(Color::White)
+        Style::default().fg(current().normal_text)
     };
     let storage_lines = vec![
         metadata_line_selectable("File", file_path, is_storage_selected(0)),
@@ -193,3 +203,44 @@ fn render_settings_storage(app: &App, frame: &mut Frame, area: Rect) {
     let storage_paragraph = Paragraph::new(storage_lines).block(storage_block);
     frame.render_widget(storage_paragraph, area);
 }
+
+fn render_settings_appearance(app: &App, frame: &mut Frame, area: Rect) {
+    use crate::app::SettingsFocus;
+    use crate::components::detail_view::{
+        metadata_line, metadata_line_selectable, FieldSectionConfig,
+    };
+    use crate::theme::ColorDepth;
+
+    let appearance_focused = app.focus.settings_focus == SettingsFocus::Appearance;
+    let appearance_section = FieldSectionConfig::new(" Appearance ")
+        .with_focus_indicator(" Appearance [4] ")
+        .focused(appearance_focused);
+    let appearance_block = appearance_section.block();
+    let theme = current();
+    let mut swatch = vec![Span::styled(
+        "  Priorities: ",
+        Style::default().fg(theme.label_text),
+    )];
+    for (label, color) in [
+        ("Low", theme.priority_low),
+        ("Medium", theme.priority_medium),
+        ("High", theme.priority_high),
+        ("Critical", theme.priority_critical),
+    ] {
+        swatch.push(Span::styled(
+            format!("{} ", label),
+            Style::default().fg(color),
+        ));
+    }
+    let appearance_lines = vec![
+        metadata_line_selectable(
+            "Theme",
+            app.app_config.effective_theme(),
+            appearance_focused,
+        ),
+        metadata_line("Colors", ColorDepth::detect().label()),
+        Line::from(swatch),
+    ];
+    let appearance_paragraph = Paragraph::new(appearance_lines).block(appearance_block);
+    frame.render_widget(appearance_paragraph, area);
+}
@@ -4,7 +4,7 @@ use crate::theme::*;
 use kanban_domain::{Sprint, SprintStatus};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
@@ -88,7 +88,7 @@ fn sprint_date_lines(sprint: &Sprint) -> Vec<Line<'static>> {
     }
     if let Some(end) = sprint.end_date {
         let end_style = if sprint.is_ended(chrono::Utc::now()) {
-            Style::default().fg(Color::Red)
+            Style::default().fg(current().error)
         } else {
             normal_text()
         };
@@ -116,7 +116,7 @@ fn sprint_card_assignment_lines(
         "Cards Assigned",
         card_count.to_string(),
         Style::default()
-            .fg(Color::Cyan)
+            .fg(current().accent)
             .add_modifier(Modifier::BOLD),
     )];
     if board.active_sprint_id == Some(sprint.id) {
@@ -282,14 +282,14 @@ pub(super) fn render_sprint_task_panel_with_selection(
     lines.push(Line::from(Span::styled(
         format!("Points: {}", points),
         Style::default()
-            .fg(Color::Cyan)
+            .fg(current().accent)
             .add_modifier(Modifier::BOLD),
     )));
 
     let border_style = if is_focused {
         focused_border()
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(current().label_text)
     };
 
     let content = Paragraph::new(lines).block(
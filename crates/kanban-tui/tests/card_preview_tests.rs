@@ -0,0 +1,166 @@
+use kanban_core::{AppConfig, DEFAULT_PREVIEW_SIZE, PREVIEW_SIZE_RANGE};
+use kanban_domain::{
+    BoardUpdate, CardUpdate, CreateCardOptions, FieldUpdate, KanbanOperations, TaskListView,
+};
+use kanban_tui::app::focus::Focus;
+use kanban_tui::app::{AppMode, PreviewLayout, PreviewState};
+use kanban_tui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use uuid::Uuid;
+
+const WIDTH: u16 = 120;
+const HEIGHT: u16 = 40;
+
+/// A flat list of three cards, the first with a description.
+fn setup() -> (App, Vec<Uuid>) {
+    let mut app = App::test_default();
+    let board = app.ctx.create_board("Board".into(), None).unwrap();
+    let column = app
+        .ctx
+        .create_column(board.id, "Todo".into(), None)
+        .unwrap();
+    let cards: Vec<Uuid> = ["One", "Two", "Three"]
+        .iter()
+        .map(|title| {
+            app.ctx
+                .create_card(
+                    board.id,
+                    column.id,
+                    title.to_string(),
+                    CreateCardOptions::default(),
+                )
+                .unwrap()
+                .id
+        })
+        .collect();
+    app.ctx
+        .update_card(
+            cards[0],
+            CardUpdate {
+                description: FieldUpdate::Set("Preview me".into()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    app.ctx
+        .update_board(
+            board.id,
+            BoardUpdate {
+                task_list_view: Some(TaskListView::Flat),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    app.prepare_frame();
+    app.selection.board.set(Some(0));
+    app.selection.active_board_index = Some(0);
+    app.switch_view_strategy(TaskListView::Flat);
+    app.focus.active = Focus::Cards;
+    (app, cards)
+}
+
+fn draw(app: &mut App) -> String {
+    app.prepare_frame();
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    terminal
+        .draw(|frame| kanban_tui::ui::render(app, frame))
+        .unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..HEIGHT)
+        .map(|y| {
+            (0..WIDTH)
+                .map(|x| buffer.cell((x, y)).map(|c| c.symbol()).unwrap_or(" "))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn test_preview_is_off_by_default_and_read_from_config() {
+    assert_eq!(PreviewState::default().layout, PreviewLayout::Off);
+    assert_eq!(PreviewState::default().size, DEFAULT_PREVIEW_SIZE);
+
+    let config = AppConfig {
+        preview: Some("bottom".into()),
+        preview_size: Some(30),
+        ..Default::default()
+    };
+    let preview = PreviewState::from_config(&config);
+    assert_eq!(preview.layout, PreviewLayout::Bottom);
+    assert_eq!(preview.size, 30);
+}
+
+#[test]
+fn test_toggle_cycles_right_bottom_off() {
+    let (mut app, _) = setup();
+
+    app.handle_toggle_preview();
+    assert_eq!(app.view.preview.layout, PreviewLayout::Right);
+    app.handle_toggle_preview();
+    assert_eq!(app.view.preview.layout, PreviewLayout::Bottom);
+    app.handle_toggle_preview();
+    assert_eq!(app.view.preview.layout, PreviewLayout::Off);
+}
+
+#[test]
+fn test_resize_stays_within_bounds() {
+    let (mut app, _) = setup();
+
+    for _ in 0..20 {
+        app.handle_grow_preview();
+    }
+    assert_eq!(app.view.preview.size, *PREVIEW_SIZE_RANGE.end());
+    for _ in 0..20 {
+        app.handle_shrink_preview();
+    }
+    assert_eq!(app.view.preview.size, *PREVIEW_SIZE_RANGE.start());
+}
+
+#[test]
+fn test_preview_follows_the_list_selection() {
+    let (mut app, _) = setup();
+    assert!(!draw(&mut app).contains("Preview me"));
+
+    app.handle_toggle_preview();
+    let screen = draw(&mut app);
+    assert!(screen.contains("Preview me"), "{screen}");
+    assert!(screen.contains("Task Title"), "{screen}");
+    assert!(app.view.hit_areas.preview.is_some());
+
+    app.handle_navigation_down();
+    assert!(!draw(&mut app).contains("Preview me"));
+}
+
+#[test]
+fn test_focus_moves_into_the_preview_and_keeps_the_list() {
+    let (mut app, cards) = setup();
+
+    app.handle_focus_preview();
+    assert_eq!(app.mode, AppMode::Normal, "no preview, nothing to focus");
+
+    app.handle_toggle_preview();
+    draw(&mut app);
+    app.handle_focus_preview();
+    assert_eq!(app.mode, AppMode::CardDetail);
+    assert_eq!(app.selection.active_card_id, Some(cards[0]));
+    assert!(app.card_detail_in_preview());
+
+    let screen = draw(&mut app);
+    assert!(screen.contains("Three"), "list stays on screen:\n{screen}");
+    assert!(screen.contains("Task Title [1]"), "{screen}");
+
+    app.pop_mode();
+    assert_eq!(app.mode, AppMode::Normal);
+    assert!(app.shows_card_preview());
+}
+
+#[test]
+fn test_card_detail_fills_the_screen_without_preview() {
+    let (mut app, cards) = setup();
+    app.open_card_detail(cards[0]);
+
+    assert!(!app.card_detail_in_preview());
+    assert!(!draw(&mut app).contains("Three"));
+}
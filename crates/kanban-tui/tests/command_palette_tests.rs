@@ -0,0 +1,119 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use kanban_domain::{CreateCardOptions, KanbanOperations};
+use kanban_tui::app::focus::Focus;
+use kanban_tui::app::mode::{AppMode, DialogMode};
+use kanban_tui::command_palette::PaletteCommand;
+use kanban_tui::App;
+use uuid::Uuid;
+
+mod helpers;
+
+fn app_with_card() -> (App, Uuid, Uuid) {
+    let mut app = App::test_default();
+    app.ctx.create_board("Alpha".into(), None).unwrap();
+    let board = app.ctx.create_board("Roadmap".into(), None).unwrap();
+    let column = app
+        .ctx
+        .create_column(board.id, "Todo".into(), None)
+        .unwrap();
+    let card = app
+        .ctx
+        .create_card(
+            board.id,
+            column.id,
+            "Write release notes".into(),
+            CreateCardOptions::default(),
+        )
+        .unwrap();
+    app.prepare_frame();
+    app.focus.active = Focus::Boards;
+    app.selection.board.set(Some(0));
+    (app, board.id, card.id)
+}
+
+fn type_query(app: &mut App, query: &str) {
+    for c in query.chars() {
+        assert!(app.handle_command_palette_key(KeyCode::Char(c)).is_none());
+    }
+}
+
+#[test]
+fn test_palette_lists_context_actions_with_keys() {
+    let (mut app, _, _) = app_with_card();
+    app.open_command_palette();
+    assert_eq!(app.mode, AppMode::Dialog(DialogMode::CommandPalette));
+
+    let entry = app
+        .ui_state
+        .command_palette
+        .entries
+        .iter()
+        .find(|e| e.label == "Create new project")
+        .expect("boards panel actions are listed");
+    assert_eq!(entry.key, "n");
+    assert_eq!(
+        entry.command,
+        PaletteCommand::Keys(vec![KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE)])
+    );
+    assert!(app
+        .ui_state
+        .command_palette
+        .entries
+        .iter()
+        .all(|e| e.label != "Open command palette"));
+}
+
+#[test]
+fn test_palette_fuzzy_query_selects_jump_entry() {
+    let (mut app, board_id, _) = app_with_card();
+    app.open_command_palette();
+    type_query(&mut app, "go roadmap");
+
+    let command = app.handle_command_palette_key(KeyCode::Enter);
+    assert_eq!(command, Some(PaletteCommand::JumpToBoard(board_id)));
+    assert_eq!(app.mode, AppMode::Normal);
+    assert!(app.ui_state.command_palette.entries.is_empty());
+}
+
+#[test]
+fn test_palette_escape_restores_previous_mode() {
+    let (mut app, _, _) = app_with_card();
+    app.push_mode(AppMode::Settings);
+    app.open_command_palette();
+    assert!(app.handle_command_palette_key(KeyCode::Esc).is_none());
+    assert_eq!(app.mode, AppMode::Settings);
+}
+
+#[test]
+fn test_palette_not_opened_from_dialogs() {
+    let (mut app, _, _) = app_with_card();
+    app.push_mode(AppMode::Dialog(DialogMode::CreateBoard));
+    app.open_command_palette();
+    assert_eq!(app.mode, AppMode::Dialog(DialogMode::CreateBoard));
+}
+
+#[test]
+fn test_jump_to_card_opens_detail_on_its_board() {
+    let (mut app, _, card_id) = app_with_card();
+    app.push_mode(AppMode::Settings);
+    app.jump_to_card(card_id);
+
+    assert_eq!(app.mode, AppMode::CardDetail);
+    assert_eq!(app.selection.active_board_index, Some(1));
+    assert_eq!(app.selection.active_card_id, Some(card_id));
+    app.pop_mode();
+    assert_eq!(app.mode, AppMode::Normal);
+}
+
+#[test]
+fn test_palette_renders_matches_with_keys() {
+    let (mut app, _, _) = app_with_card();
+    app.open_command_palette();
+    type_query(&mut app, "release");
+
+    let output = helpers::render_widget_to_string(100, 30, |frame| {
+        kanban_tui::ui::render(&mut app, frame);
+    });
+    assert!(output.contains("Command Palette"));
+    assert!(output.contains("Go to card: task-1 Write release notes"));
+}
@@ -348,6 +348,131 @@ fn test_export_import_sprint_and_card_prefixes() {
     );
 }
 
+#[test]
+fn test_export_import_round_trip_preserves_dependency_edges() {
+    use kanban_domain::{GraphOperations, RelatesKind, Severity};
+
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test_edges.json");
+
+    let mut app = App::test_default();
+    let board = app.ctx.create_board("Graph".to_string(), None).unwrap();
+    let column = app
+        .ctx
+        .create_column(board.id, "Todo".to_string(), None)
+        .unwrap();
+    let mut card = |title: &str| {
+        app.ctx
+            .create_card(board.id, column.id, title.to_string(), Default::default())
+            .unwrap()
+            .id
+    };
+    let (parent, child, blocked, old) =
+        (card("Parent"), card("Child"), card("Blocked"), card("Old"));
+    app.ctx.attach_child(parent, child).unwrap();
+    app.ctx.block(parent, blocked, Severity::High).unwrap();
+    app.ctx.relate(child, old, RelatesKind::Duplicates).unwrap();
+    app.ctx.archive_card(old).unwrap();
+
+    app.input.set(file_path.to_str().unwrap().to_string());
+    app.prepare_frame();
+    app.export_all_boards_with_filename().unwrap();
+
+    let content = fs::read_to_string(&file_path).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert_eq!(parsed["version"], 3);
+
+    let mut app2 = App::test_default();
+    app2.import_board_from_file(file_path.to_str().unwrap())
+        .unwrap();
+    app2.prepare_frame();
+
+    assert_eq!(app2.model.graph(), app.model.graph());
+    assert_eq!(app2.model.graph().len(), 3);
+    assert_eq!(app2.model.archived_cards().len(), 1);
+}
+
+#[test]
+fn test_import_copy_twice_keeps_both_boards() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test_copy.json");
+
+    let mut app = App::test_default();
+    let board = app.ctx.create_board("Source".to_string(), None).unwrap();
+    let column = app
+        .ctx
+        .create_column(board.id, "Todo".to_string(), None)
+        .unwrap();
+    app.ctx
+        .create_card(board.id, column.id, "Task".to_string(), Default::default())
+        .unwrap();
+    app.selection.board.set(Some(0));
+    app.input.set(file_path.to_str().unwrap().to_string());
+    app.prepare_frame();
+    app.export_board_with_filename().unwrap();
+
+    let path = file_path.to_str().unwrap();
+    app.import_board_copy_from_file(path).unwrap();
+    app.prepare_frame();
+    app.import_board_copy_from_file(path).unwrap();
+    app.prepare_frame();
+
+    assert_eq!(app.model.boards().len(), 3);
+    assert_eq!(app.model.cards().len(), 3);
+    let mut ids: Vec<_> = app.model.cards().iter().map(|c| c.id).collect();
+    ids.dedup();
+    assert_eq!(ids.len(), 3);
+    assert!(app.model.cards().iter().all(|c| c.card_number == 1));
+    assert_eq!(app.selection.board.get(), Some(2));
+}
+
+#[test]
+fn test_clone_board_dialog_copies_cards_when_toggled() {
+    use crossterm::event::KeyCode;
+    use kanban_tui::app::focus::Focus;
+    use kanban_tui::app::mode::{AppMode, DialogMode};
+
+    let mut app = App::test_default();
+    let board = app.ctx.create_board("Source".to_string(), None).unwrap();
+    let column = app
+        .ctx
+        .create_column(board.id, "Todo".to_string(), None)
+        .unwrap();
+    app.ctx
+        .create_card(board.id, column.id, "Task".to_string(), Default::default())
+        .unwrap();
+    app.prepare_frame();
+    app.focus.active = Focus::Boards;
+    app.selection.board.set(Some(0));
+
+    app.handle_clone_board_key();
+    assert_eq!(app.mode, AppMode::Dialog(DialogMode::CloneBoard));
+    assert_eq!(app.input.as_str(), "Source (copy)");
+
+    app.handle_clone_board_dialog(KeyCode::Tab);
+    app.handle_clone_board_dialog(KeyCode::Enter);
+    app.prepare_frame();
+
+    assert_eq!(app.mode, AppMode::Normal);
+    let boards = app.model.boards();
+    assert_eq!(boards.len(), 2);
+    assert_eq!(boards[1].name, "Source (copy)");
+    assert_eq!(app.selection.board.get(), Some(1));
+    let cloned: Vec<_> = app
+        .model
+        .cards()
+        .iter()
+        .filter(|c| {
+            app.model
+                .columns()
+                .iter()
+                .any(|col| col.id == c.column_id && col.board_id == boards[1].id)
+        })
+        .collect();
+    assert_eq!(cloned.len(), 1);
+    assert_eq!(cloned[0].title, "Task");
+}
+
 #[test]
 fn test_backward_compat_old_export_format() {
     let dir = tempdir().unwrap();
@@ -429,3 +554,42 @@ fn test_backward_compat_old_export_format() {
     assert_eq!(app.model.cards().len(), 1);
     assert_eq!(app.model.cards()[0].title, "Old Card");
 }
+
+#[test]
+fn test_export_by_extension_and_csv_import_round_trip() {
+    let dir = tempdir().unwrap();
+    let md_path = dir.path().join("board.md");
+    let csv_path = dir.path().join("board.csv");
+
+    let mut app = App::test_default();
+    let board = app.ctx.create_board("Ops".to_string(), None).unwrap();
+    let column = app
+        .ctx
+        .create_column(board.id, "Todo".to_string(), None)
+        .unwrap();
+    app.ctx
+        .create_card(board.id, column.id, "Task".to_string(), Default::default())
+        .unwrap();
+    app.selection.board.set(Some(0));
+    app.prepare_frame();
+
+    app.input.set(md_path.to_str().unwrap().to_string());
+    app.export_board_with_filename().unwrap();
+    let md = std::fs::read_to_string(&md_path).unwrap();
+    assert!(
+        md.contains("## Todo\n\n- [ ] **task-1** Task · medium"),
+        "{}",
+        md
+    );
+
+    app.input.set(csv_path.to_str().unwrap().to_string());
+    app.export_board_with_filename().unwrap();
+    let csv = std::fs::read_to_string(&csv_path).unwrap();
+    std::fs::write(&csv_path, csv.replace(",Task,", ",Renamed task,")).unwrap();
+
+    app.import_board_from_file(csv_path.to_str().unwrap())
+        .unwrap();
+    app.prepare_frame();
+    assert_eq!(app.model.boards().len(), 1);
+    assert_eq!(app.model.cards()[0].title, "Renamed task");
+}
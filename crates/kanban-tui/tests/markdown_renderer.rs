@@ -1,4 +1,11 @@
-use kanban_tui::markdown_renderer::render_markdown;
+use kanban_tui::markdown_renderer::{
+    render_markdown, render_markdown_with, write_hyperlinks, LinkTarget, MarkdownOptions,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+use ratatui::widgets::{Paragraph, Widget};
+use uuid::Uuid;
 
 #[test]
 fn test_plain_text() {
@@ -62,3 +69,182 @@ fn test_mixed_formatting() {
     let lines = render_markdown(text);
     assert!(!lines.is_empty());
 }
+
+fn plain_lines(text: &str, width: usize) -> Vec<String> {
+    let options = MarkdownOptions {
+        width: Some(width),
+        ..Default::default()
+    };
+    render_markdown_with(text, &options)
+        .lines
+        .iter()
+        .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+        .collect()
+}
+
+#[test]
+fn test_lists_keep_markers_and_wrapped_indentation() {
+    let lines = plain_lines("- first item wraps here\n- second\n\n3. three\n4. four", 14);
+    assert_eq!(
+        lines,
+        vec![
+            "• first item",
+            "  wraps here",
+            "• second",
+            "",
+            "3. three",
+            "4. four",
+        ]
+    );
+}
+
+#[test]
+fn test_nested_lists_and_task_items() {
+    let lines = plain_lines("- [ ] todo\n- [x] done\n  - nested", 40);
+    assert_eq!(lines, vec!["[ ] todo", "[x] done", "    • nested"]);
+}
+
+#[test]
+fn test_headings_and_quotes() {
+    let rendered = render_markdown_with("# Title\n\n> quoted text", &MarkdownOptions::default());
+    let title = &rendered.lines[0].spans[0];
+    assert_eq!(title.content, "Title");
+    assert!(title.style.add_modifier.contains(Modifier::BOLD));
+    assert!(title.style.add_modifier.contains(Modifier::UNDERLINED));
+    assert_eq!(plain_lines("> quoted text", 40), vec!["│ quoted text"]);
+}
+
+#[test]
+fn test_tables_are_aligned() {
+    let lines = plain_lines("| Name | Points |\n|---|---:|\n| a | 3 |", 40);
+    assert_eq!(
+        lines,
+        vec!["Name │ Points", "─────┼───────", "a    │      3"]
+    );
+}
+
+#[test]
+fn test_code_blocks_are_highlighted_by_language() {
+    let rendered = render_markdown_with("```rust\nlet x = 1;\n```", &MarkdownOptions::default());
+    let spans = &rendered.lines[0].spans;
+    let keyword = spans.iter().find(|s| s.content == "let").unwrap();
+    assert!(keyword.style.add_modifier.contains(Modifier::BOLD));
+
+    let plain = render_markdown_with("```\nlet x = 1;\n```", &MarkdownOptions::default());
+    assert!(plain.lines[0].spans.iter().all(|s| s.content != "let"));
+}
+
+#[test]
+fn test_links_and_card_references() {
+    let card_id = Uuid::new_v4();
+    let resolve = |identifier: &str| (identifier == "KAN-7").then_some(card_id);
+    let options = MarkdownOptions {
+        width: None,
+        resolve_card: Some(&resolve),
+        selected_card: None,
+    };
+    let rendered = render_markdown_with(
+        "See [docs](https://example.com), KAN-7 and KAN-8 at https://x.org.",
+        &options,
+    );
+    let targets: Vec<_> = rendered.links.iter().map(|l| &l.target).collect();
+    assert_eq!(
+        targets,
+        vec![
+            &LinkTarget::Url("https://example.com".into()),
+            &LinkTarget::Card(card_id),
+            &LinkTarget::Url("https://x.org".into()),
+        ]
+    );
+    assert_eq!(rendered.link_at(0, 12), Some(&LinkTarget::Card(card_id)));
+    assert_eq!(
+        rendered.link_at(0, 4),
+        Some(&LinkTarget::Url("https://example.com".into()))
+    );
+}
+
+#[test]
+fn test_selected_card_reference_is_reversed() {
+    let card_id = Uuid::new_v4();
+    let resolve = |identifier: &str| (identifier == "KAN-7").then_some(card_id);
+    let options = MarkdownOptions {
+        width: None,
+        resolve_card: Some(&resolve),
+        selected_card: Some(card_id),
+    };
+    let rendered = render_markdown_with("See KAN-7", &options);
+    let reference = rendered.lines[0]
+        .spans
+        .iter()
+        .find(|s| s.content == "KAN-7")
+        .unwrap();
+    assert!(reference.style.add_modifier.contains(Modifier::REVERSED));
+}
+
+#[test]
+fn test_hyperlinks_are_written_to_the_buffer() {
+    let rendered = render_markdown_with("go https://example.com", &MarkdownOptions::default());
+    let area = Rect::new(0, 0, 30, 1);
+    let mut buffer = Buffer::empty(area);
+    Paragraph::new(rendered.lines.clone()).render(area, &mut buffer);
+    write_hyperlinks(&mut buffer, area, &rendered.links);
+
+    assert_eq!(buffer[(0, 0)].symbol(), "g");
+    assert_eq!(
+        buffer[(3, 0)].symbol(),
+        "\x1b]8;;https://example.com\x1b\\ht\x1b]8;;\x1b\\"
+    );
+    assert!(buffer[(4, 0)].skip);
+}
+
+/// Replays the updates of `Buffer::diff` onto a row of plain text, the
+/// way a terminal would draw them.
+fn drawn_row(previous: &Buffer, next: &Buffer, width: usize) -> String {
+    let mut row = vec![' '; width];
+    for (x, _, cell) in previous.diff(next) {
+        let mut visible = String::new();
+        let mut rest = cell.symbol();
+        while let Some(start) = rest.find("\x1b]8;;") {
+            visible.push_str(&rest[..start]);
+            let end = rest[start..].find("\x1b\\").unwrap() + start;
+            rest = &rest[end + 2..];
+        }
+        visible.push_str(rest);
+        for (offset, c) in visible.chars().enumerate() {
+            row[x as usize + offset] = c;
+        }
+    }
+    row.into_iter().collect()
+}
+
+#[test]
+fn test_hyperlinks_survive_buffer_diff() {
+    for text in [
+        "go https://example.com now",
+        "go https://example.org/a now",
+        "https://x.io",
+    ] {
+        let rendered = render_markdown_with(text, &MarkdownOptions::default());
+        let area = Rect::new(0, 0, 30, 1);
+        let mut buffer = Buffer::empty(area);
+        Paragraph::new(rendered.lines.clone()).render(area, &mut buffer);
+        write_hyperlinks(&mut buffer, area, &rendered.links);
+
+        let drawn = drawn_row(&Buffer::empty(area), &buffer, 30);
+        assert_eq!(drawn.trim_end(), text);
+    }
+}
+
+#[test]
+fn test_single_column_hyperlink_keeps_the_next_cell() {
+    let rendered = render_markdown_with("https://example.com x", &MarkdownOptions::default());
+    let mut links = rendered.links.clone();
+    links[0].width = 1;
+    let area = Rect::new(0, 0, 30, 1);
+    let mut buffer = Buffer::empty(area);
+    Paragraph::new(rendered.lines.clone()).render(area, &mut buffer);
+    write_hyperlinks(&mut buffer, area, &links);
+
+    let drawn = drawn_row(&Buffer::empty(area), &buffer, 30);
+    assert_eq!(drawn.trim_end(), "https://example.com x");
+}
@@ -0,0 +1,290 @@
+mod helpers;
+
+use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use kanban_domain::{
+    BoardUpdate, CardUpdate, CreateCardOptions, FieldUpdate, KanbanOperations, TaskListView,
+};
+use kanban_tui::app::focus::Focus;
+use kanban_tui::app::AppMode;
+use kanban_tui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use uuid::Uuid;
+
+const WIDTH: u16 = 100;
+const HEIGHT: u16 = 20;
+
+struct Fixture {
+    app: App,
+    todo: Uuid,
+    done: Uuid,
+    cards: Vec<Uuid>,
+}
+
+/// A board with "Todo" holding three cards and an empty "Done", opened in
+/// `view`.
+fn setup(view: TaskListView) -> Fixture {
+    let mut app = App::test_default();
+    let board = app.ctx.create_board("Board".into(), None).unwrap();
+    let todo = app
+        .ctx
+        .create_column(board.id, "Todo".into(), None)
+        .unwrap();
+    let done = app
+        .ctx
+        .create_column(board.id, "Done".into(), None)
+        .unwrap();
+    let cards = ["One", "Two", "Three"]
+        .iter()
+        .map(|title| {
+            app.ctx
+                .create_card(
+                    board.id,
+                    todo.id,
+                    title.to_string(),
+                    CreateCardOptions::default(),
+                )
+                .unwrap()
+                .id
+        })
+        .collect();
+    app.ctx
+        .update_board(
+            board.id,
+            BoardUpdate {
+                task_list_view: Some(view),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    app.prepare_frame();
+    app.selection.board.set(Some(0));
+    app.selection.active_board_index = Some(0);
+    app.switch_view_strategy(view);
+    app.focus.active = Focus::Cards;
+    Fixture {
+        app,
+        todo: todo.id,
+        done: done.id,
+        cards,
+    }
+}
+
+/// Renders a frame and returns it as lines of text.
+fn draw(app: &mut App) -> Vec<String> {
+    app.prepare_frame();
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    terminal
+        .draw(|frame| kanban_tui::ui::render(app, frame))
+        .unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..HEIGHT)
+        .map(|y| {
+            (0..WIDTH)
+                .map(|x| buffer.cell((x, y)).map(|c| c.symbol()).unwrap_or(" "))
+                .collect()
+        })
+        .collect()
+}
+
+/// Column and row of the first cell showing `text`.
+fn find(lines: &[String], text: &str) -> (u16, u16) {
+    lines
+        .iter()
+        .enumerate()
+        .find_map(|(y, line)| {
+            let byte = line.find(text)?;
+            Some((line[..byte].chars().count() as u16, y as u16))
+        })
+        .unwrap_or_else(|| panic!("{text:?} not on screen:\n{}", lines.join("\n")))
+}
+
+fn mouse(kind: MouseEventKind, (column, row): (u16, u16)) -> MouseEvent {
+    MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+fn click(app: &mut App, at: (u16, u16)) {
+    app.handle_mouse_event(mouse(MouseEventKind::Down(MouseButton::Left), at));
+    app.handle_mouse_event(mouse(MouseEventKind::Up(MouseButton::Left), at));
+}
+
+#[test]
+fn test_click_selects_the_card_under_the_pointer_in_grouped_view() {
+    let mut f = setup(TaskListView::GroupedByColumn);
+    f.app.focus.active = Focus::Boards;
+    let lines = draw(&mut f.app);
+
+    click(&mut f.app, find(&lines, "Three"));
+
+    assert_eq!(f.app.focus.active, Focus::Cards);
+    assert_eq!(f.app.get_selected_card_id(), Some(f.cards[2]));
+}
+
+#[test]
+fn test_click_on_a_column_header_selects_nothing_new() {
+    let mut f = setup(TaskListView::GroupedByColumn);
+    let lines = draw(&mut f.app);
+    let before = f.app.get_selected_card_id();
+
+    click(&mut f.app, find(&lines, "── Todo"));
+
+    assert_eq!(f.app.get_selected_card_id(), before);
+}
+
+#[test]
+fn test_click_in_projects_panel_focuses_boards() {
+    let mut f = setup(TaskListView::GroupedByColumn);
+    let lines = draw(&mut f.app);
+    let (x, y) = find(&lines, "Board");
+
+    click(&mut f.app, (x, y));
+
+    assert_eq!(f.app.focus.active, Focus::Boards);
+    assert_eq!(f.app.selection.board.get(), Some(0));
+}
+
+#[test]
+fn test_double_click_opens_the_card() {
+    let mut f = setup(TaskListView::Flat);
+    let lines = draw(&mut f.app);
+    let at = find(&lines, "Two");
+
+    click(&mut f.app, at);
+    assert_eq!(f.app.mode, AppMode::Normal);
+    click(&mut f.app, at);
+
+    assert_eq!(f.app.mode, AppMode::CardDetail);
+    assert_eq!(f.app.selection.active_card_id, Some(f.cards[1]));
+}
+
+#[test]
+fn test_wheel_moves_the_selection_in_the_list_under_the_pointer() {
+    let mut f = setup(TaskListView::Flat);
+    f.app.focus.active = Focus::Boards;
+    let lines = draw(&mut f.app);
+    let at = find(&lines, "One");
+    click(&mut f.app, at);
+    assert_eq!(f.app.get_selected_card_id(), Some(f.cards[0]));
+
+    f.app
+        .handle_mouse_event(mouse(MouseEventKind::ScrollDown, at));
+    f.app
+        .handle_mouse_event(mouse(MouseEventKind::ScrollDown, at));
+    assert_eq!(f.app.get_selected_card_id(), Some(f.cards[2]));
+
+    f.app
+        .handle_mouse_event(mouse(MouseEventKind::ScrollUp, at));
+    assert_eq!(f.app.get_selected_card_id(), Some(f.cards[1]));
+}
+
+#[test]
+fn test_wheel_outside_the_main_view_stands_for_arrow_keys() {
+    let mut f = setup(TaskListView::Flat);
+    f.app.open_card_detail(f.cards[0]);
+
+    let keys = f
+        .app
+        .handle_mouse_event(mouse(MouseEventKind::ScrollDown, (1, 1)));
+
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0].code, KeyCode::Down);
+}
+
+#[test]
+fn test_drag_moves_a_card_to_another_kanban_column() {
+    let mut f = setup(TaskListView::ColumnView);
+    let lines = draw(&mut f.app);
+    let from = find(&lines, "Two");
+    let to = find(&lines, "Done");
+
+    f.app
+        .handle_mouse_event(mouse(MouseEventKind::Down(MouseButton::Left), from));
+    f.app
+        .handle_mouse_event(mouse(MouseEventKind::Drag(MouseButton::Left), to));
+    f.app.handle_mouse_event(mouse(
+        MouseEventKind::Up(MouseButton::Left),
+        (to.0, to.1 + 2),
+    ));
+
+    let card = f.app.model.card(f.cards[1]).unwrap();
+    assert_eq!(card.column_id, f.done);
+    assert_eq!(f.app.get_selected_card_id(), Some(f.cards[1]));
+    let others = f.app.model.card(f.cards[0]).unwrap();
+    assert_eq!(others.column_id, f.todo);
+}
+
+#[test]
+fn test_release_in_the_same_column_moves_nothing() {
+    let mut f = setup(TaskListView::ColumnView);
+    let lines = draw(&mut f.app);
+    let from = find(&lines, "One");
+
+    f.app
+        .handle_mouse_event(mouse(MouseEventKind::Down(MouseButton::Left), from));
+    f.app.handle_mouse_event(mouse(
+        MouseEventKind::Up(MouseButton::Left),
+        find(&lines, "Three"),
+    ));
+
+    assert_eq!(f.app.model.card(f.cards[0]).unwrap().column_id, f.todo);
+}
+
+#[test]
+fn test_click_selects_a_card_in_another_kanban_column() {
+    let mut f = setup(TaskListView::ColumnView);
+    f.app
+        .ctx
+        .move_card(f.cards[2], f.done, None)
+        .expect("move card");
+    let lines = draw(&mut f.app);
+
+    click(&mut f.app, find(&lines, "Three"));
+
+    assert_eq!(f.app.get_selected_card_id(), Some(f.cards[2]));
+}
+
+#[test]
+fn test_footer_hint_click_returns_its_key() {
+    let mut f = setup(TaskListView::Flat);
+    let lines = draw(&mut f.app);
+    let (x, y) = find(&lines, "?: help");
+
+    let keys = f
+        .app
+        .handle_mouse_event(mouse(MouseEventKind::Down(MouseButton::Left), (x + 3, y)));
+
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0].code, KeyCode::Char('?'));
+}
+
+#[test]
+fn test_click_on_a_card_reference_opens_that_card() {
+    let mut f = setup(TaskListView::Flat);
+    let identifier = {
+        let board = &f.app.model.boards()[0];
+        let card = f.app.model.card(f.cards[2]).unwrap();
+        card.identifier(board, f.app.model.sprints(), "task")
+    };
+    f.app
+        .ctx
+        .update_card(
+            f.cards[0],
+            CardUpdate {
+                description: FieldUpdate::Set(format!("Blocked by {identifier}.")),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    f.app.open_card_detail(f.cards[0]);
+    let lines = draw(&mut f.app);
+
+    click(&mut f.app, find(&lines, &identifier));
+
+    assert_eq!(f.app.mode, AppMode::CardDetail);
+    assert_eq!(f.app.selection.active_card_id, Some(f.cards[2]));
+}
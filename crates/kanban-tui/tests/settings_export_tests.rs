@@ -219,3 +219,39 @@ fn test_export_boards_json_creates_file() {
     assert!(parsed["boards"].is_array());
     assert!(content.contains("ExportTest"));
 }
+
+#[test]
+fn test_export_boards_tab_cycles_to_markdown() {
+    use crossterm::event::KeyCode;
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let export_path = dir.path().join("test_export.json");
+
+    let mut app = App::test_default();
+    app.push_mode(AppMode::Settings);
+    let board = app.ctx.create_board("ExportTest".into(), None).unwrap();
+    app.ctx
+        .create_column(board.id, "Todo".into(), None)
+        .unwrap();
+    app.prepare_frame();
+    app.export_dialog = Some(ExportDialogState::new(1));
+    app.push_mode(AppMode::Dialog(DialogMode::ExportBoards));
+
+    app.handle_export_boards_dialog(KeyCode::Char(' '));
+    app.handle_export_boards_dialog(KeyCode::Enter);
+    app.export_dialog.as_mut().unwrap().filename = export_path.to_string_lossy().to_string();
+
+    app.handle_export_boards_dialog(KeyCode::Tab);
+    app.handle_export_boards_dialog(KeyCode::Tab);
+    let dialog = app.export_dialog.as_ref().unwrap();
+    assert_eq!(dialog.format, ExportFormat::Markdown);
+    assert!(dialog.filename.ends_with("test_export.md"));
+    app.handle_export_boards_dialog(KeyCode::BackTab);
+    app.handle_export_boards_dialog(KeyCode::Tab);
+
+    app.handle_export_boards_dialog(KeyCode::Enter);
+
+    let content = std::fs::read_to_string(dir.path().join("test_export.md")).unwrap();
+    assert!(content.starts_with("# ExportTest\n"));
+    assert!(content.contains("## Todo\n\n_No cards_"));
+}
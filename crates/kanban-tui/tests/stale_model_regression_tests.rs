@@ -62,6 +62,38 @@ fn test_create_board_selects_new_board() {
     assert_eq!(boards[selected.unwrap()].name, "Second");
 }
 
+#[test]
+fn test_create_board_dialog_uses_selected_template() {
+    use crossterm::event::KeyCode;
+
+    let mut app = App::test_default();
+    app.focus.active = Focus::Boards;
+
+    app.handle_create_board_key();
+    assert_eq!(app.dialog_input.selected_board_template(), Some("basic"));
+    app.handle_create_board_dialog(KeyCode::Tab);
+    app.handle_create_board_dialog(KeyCode::Tab);
+    assert_eq!(app.dialog_input.selected_board_template(), Some("scrum"));
+
+    app.input.set("Sprints".to_string());
+    app.handle_create_board_dialog(KeyCode::Enter);
+    app.prepare_frame();
+
+    assert_eq!(app.mode, AppMode::Normal);
+    let boards = app.model.boards();
+    assert_eq!(boards.len(), 1);
+    assert_eq!(boards[0].sprint_prefix.as_deref(), Some("sprint"));
+    let names: Vec<_> = app
+        .model
+        .columns()
+        .iter()
+        .filter(|c| c.board_id == boards[0].id)
+        .map(|c| c.name.clone())
+        .collect();
+    assert_eq!(names, ["Backlog", "To Do", "In Progress", "Review", "Done"]);
+    assert_eq!(app.selection.board.get(), Some(0));
+}
+
 #[test]
 fn test_create_card_selects_newly_created_card() {
     let mut app = setup_app_with_board();
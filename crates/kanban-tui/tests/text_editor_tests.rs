@@ -0,0 +1,176 @@
+mod helpers;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use kanban_domain::{CreateCardOptions, KanbanOperations};
+use kanban_tui::app::{AppMode, BoardField, CardField, DialogMode};
+use kanban_tui::text_editor::{TextTarget, ViMode};
+use kanban_tui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use uuid::Uuid;
+
+fn app_with_card() -> (App, Uuid, Uuid) {
+    let mut app = App::test_default();
+    let board = app.ctx.create_board("Roadmap".into(), None).unwrap();
+    let column = app
+        .ctx
+        .create_column(board.id, "Todo".into(), None)
+        .unwrap();
+    let card = app
+        .ctx
+        .create_card(
+            board.id,
+            column.id,
+            "Write notes".into(),
+            CreateCardOptions::default(),
+        )
+        .unwrap();
+    app.prepare_frame();
+    app.selection.board.set(Some(0));
+    app.open_card_detail(card.id);
+    (app, board.id, card.id)
+}
+
+fn press(app: &mut App, code: KeyCode, modifiers: KeyModifiers) -> bool {
+    app.handle_text_editor_key(KeyEvent::new(code, modifiers))
+}
+
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        press(app, KeyCode::Char(c), KeyModifiers::NONE);
+    }
+}
+
+fn description(app: &mut App, card_id: Uuid) -> Option<String> {
+    title_and_description(app, card_id).1
+}
+
+/// Loads the saved card, as the next frame would.
+fn title_and_description(app: &mut App, card_id: Uuid) -> (String, Option<String>) {
+    app.prepare_frame();
+    let card = app.model.card(card_id).unwrap();
+    (card.title.clone(), card.description.clone())
+}
+
+#[test]
+fn test_description_is_saved_with_ctrl_s() {
+    let (mut app, _, card_id) = app_with_card();
+    app.open_text_editor(TextTarget::Card(card_id, CardField::Description), "");
+    assert_eq!(app.mode, AppMode::Dialog(DialogMode::EditText));
+
+    type_text(&mut app, "first");
+    press(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+    type_text(&mut app, "second");
+    press(&mut app, KeyCode::Char('s'), KeyModifiers::CONTROL);
+
+    assert_eq!(app.mode, AppMode::CardDetail);
+    assert_eq!(
+        description(&mut app, card_id).as_deref(),
+        Some("first\nsecond")
+    );
+}
+
+#[test]
+fn test_escape_discards_the_edit() {
+    let (mut app, _, card_id) = app_with_card();
+    app.open_text_editor(TextTarget::Card(card_id, CardField::Description), "");
+    type_text(&mut app, "draft");
+    press(&mut app, KeyCode::Esc, KeyModifiers::NONE);
+
+    assert_eq!(app.mode, AppMode::CardDetail);
+    assert_eq!(description(&mut app, card_id), None);
+    assert!(app.ui_state.text_editor.target.is_none());
+}
+
+#[test]
+fn test_enter_saves_a_trimmed_title() {
+    let (mut app, _, card_id) = app_with_card();
+    app.open_text_editor(TextTarget::Card(card_id, CardField::Title), "Write notes");
+    type_text(&mut app, " now ");
+    press(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+
+    assert_eq!(
+        title_and_description(&mut app, card_id).0,
+        "Write notes now"
+    );
+}
+
+#[test]
+fn test_empty_title_is_ignored_and_empty_description_clears() {
+    let (mut app, _, card_id) = app_with_card();
+    app.apply_text_edit(
+        TextTarget::Card(card_id, CardField::Description),
+        "text".into(),
+    );
+    assert_eq!(description(&mut app, card_id).as_deref(), Some("text"));
+
+    app.open_text_editor(TextTarget::Card(card_id, CardField::Title), "Write notes");
+    press(&mut app, KeyCode::Char('a'), KeyModifiers::CONTROL);
+    press(&mut app, KeyCode::Backspace, KeyModifiers::NONE);
+    press(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+    assert_eq!(title_and_description(&mut app, card_id).0, "Write notes");
+
+    app.open_text_editor(TextTarget::Card(card_id, CardField::Description), "text");
+    press(&mut app, KeyCode::Char('w'), KeyModifiers::CONTROL);
+    press(&mut app, KeyCode::Char('s'), KeyModifiers::CONTROL);
+    assert_eq!(description(&mut app, card_id), None);
+}
+
+#[test]
+fn test_board_name_is_saved() {
+    let (mut app, board_id, _) = app_with_card();
+    app.open_text_editor(TextTarget::Board(board_id, BoardField::Name), "Roadmap");
+    type_text(&mut app, " 2027");
+    press(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+
+    app.prepare_frame();
+    assert_eq!(app.model.boards()[0].name, "Roadmap 2027");
+}
+
+#[test]
+fn test_vi_config_starts_in_normal_mode() {
+    let (mut app, _, card_id) = app_with_card();
+    app.app_config.text_editor = Some("vi".into());
+    app.open_text_editor(TextTarget::Card(card_id, CardField::Description), "text");
+    assert_eq!(app.ui_state.text_editor.vi, Some(ViMode::Normal));
+
+    type_text(&mut app, "0x");
+    assert_eq!(app.ui_state.text_editor.input.as_str(), "ext");
+    assert!(!app.uses_external_editor());
+}
+
+#[test]
+fn test_ctrl_o_asks_for_the_external_editor() {
+    let (mut app, _, card_id) = app_with_card();
+    app.open_text_editor(TextTarget::Card(card_id, CardField::Description), "");
+    assert!(press(&mut app, KeyCode::Char('o'), KeyModifiers::CONTROL));
+    assert_eq!(app.mode, AppMode::Dialog(DialogMode::EditText));
+
+    app.app_config.text_editor = Some("external".into());
+    assert!(app.uses_external_editor());
+}
+
+#[test]
+fn test_long_lines_are_soft_wrapped_in_the_popup() {
+    let (mut app, _, card_id) = app_with_card();
+    let text = "word ".repeat(30);
+    app.open_text_editor(TextTarget::Card(card_id, CardField::Description), &text);
+
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal
+        .draw(|frame| kanban_tui::ui::render(&mut app, frame))
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let rows_with_words = (0..24)
+        .filter(|&y| {
+            let line: String = (0..80)
+                .map(|x| buffer.cell((x, y)).map(|c| c.symbol()).unwrap_or(" "))
+                .collect();
+            line.contains("word word")
+        })
+        .count();
+    assert!(rows_with_words >= 3, "text should wrap over several rows");
+    let width = app.ui_state.text_editor.wrap_width.get();
+    assert!(width < text.len());
+}